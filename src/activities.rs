@@ -3,8 +3,11 @@
 //! This module defines the core activity types and preference enums used
 //! throughout the scoring engine and UI.
 
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
 /// Beach activities that users can select for recommendations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[allow(dead_code)]
 pub enum Activity {
     /// Swimming in the ocean
@@ -17,6 +20,12 @@ pub enum Activity {
     Sunset,
     /// Seeking peace and quiet
     Peace,
+    /// Walking a dog, only where dogs are allowed
+    DogWalk,
+    /// Surfing or bodyboarding, only at beaches open to swell
+    Surf,
+    /// Running or walking the seawall and paths behind the beach
+    RunWalk,
 }
 
 #[allow(dead_code)]
@@ -29,6 +38,9 @@ impl Activity {
             Activity::Sailing,
             Activity::Sunset,
             Activity::Peace,
+            Activity::DogWalk,
+            Activity::Surf,
+            Activity::RunWalk,
         ]
     }
 
@@ -40,9 +52,44 @@ impl Activity {
             Activity::Sailing => "Sailing",
             Activity::Sunset => "Sunset",
             Activity::Peace => "Peace & Quiet",
+            Activity::DogWalk => "Dog Walk",
+            Activity::Surf => "Surf/Bodyboard",
+            Activity::RunWalk => "Run/Walk",
+        }
+    }
+
+    /// Returns a lowercase name for the activity, used in MQTT topics,
+    /// e.g. "dog-walk"; `from_str` parses it back.
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Activity::Swimming => "swimming",
+            Activity::Sunbathing => "sunbathing",
+            Activity::Sailing => "sailing",
+            Activity::Sunset => "sunset",
+            Activity::Peace => "peace",
+            Activity::DogWalk => "dog-walk",
+            Activity::Surf => "surf",
+            Activity::RunWalk => "run-walk",
         }
     }
 
+    /// Whether the activity still makes sense after dark: watching the last
+    /// of the light, a quiet sit, or a walk with or without the dog
+    pub fn suits_evening(&self) -> bool {
+        matches!(
+            self,
+            Activity::Sunset | Activity::Peace | Activity::DogWalk | Activity::RunWalk
+        )
+    }
+
+    /// Whether the activity can be done at a beach at all
+    ///
+    /// Surfing needs a beach open to swell; everything else is offered
+    /// everywhere, if not always allowed (dogs are banned on most beaches).
+    pub fn offered_at(&self, beach_id: &str) -> bool {
+        *self != Activity::Surf || crate::data::wave_facing(beach_id).is_some()
+    }
+
     /// Parses user input into an Activity.
     ///
     /// Matching is case-insensitive and supports aliases:
@@ -51,6 +98,9 @@ impl Activity {
     /// - "sail" | "sailing" -> Sailing
     /// - "sunset" -> Sunset
     /// - "peace" | "quiet" -> Peace
+    /// - "dog" | "dogs" | "dogwalk" | "dog-walk" -> DogWalk
+    /// - "surf" | "surfing" | "bodyboard" | "bodyboarding" -> Surf
+    /// - "run" | "running" | "jog" | "walk" | "walking" | "run-walk" -> RunWalk
     ///
     /// Returns `None` if the input doesn't match any activity.
     #[allow(clippy::should_implement_trait)]
@@ -61,6 +111,11 @@ impl Activity {
             "sail" | "sailing" => Some(Activity::Sailing),
             "sunset" => Some(Activity::Sunset),
             "peace" | "quiet" => Some(Activity::Peace),
+            "dog" | "dogs" | "dogwalk" | "dog-walk" => Some(Activity::DogWalk),
+            "surf" | "surfing" | "bodyboard" | "bodyboarding" => Some(Activity::Surf),
+            "run" | "running" | "jog" | "walk" | "walking" | "run-walk" | "runwalk" => {
+                Some(Activity::RunWalk)
+            }
             _ => None,
         }
     }
@@ -98,7 +153,21 @@ pub enum UvPreference {
 // SCORING ENGINE - Activity profiles and scoring functions
 // ============================================================================
 
-use crate::data::WaterStatus;
+use std::sync::OnceLock;
+
+use chrono::{Duration, NaiveTime, Timelike, Utc};
+
+use crate::data::fire_bans::FireBans;
+use crate::data::{
+    is_king_tide, BeachConditions, HourlyForecast, WaterBody, WaterStatus, WeatherCondition,
+};
+use crate::time_format::{format_hour, format_time};
+
+/// Least UV weight for any activity in a heat warning
+pub const HEAT_UV_WEIGHT: f32 = 0.2;
+
+/// Least shade weight for any activity in a heat warning
+pub const HEAT_SHADE_WEIGHT: f32 = 0.2;
 
 /// Weights and preferences for scoring a time slot for a specific activity.
 #[derive(Debug, Clone)]
@@ -126,10 +195,72 @@ pub struct ActivityProfile {
     pub tide_preference: TidePreference,
     /// Weight for crowd scoring (higher = more crowd-averse)
     pub crowd_weight: f32,
+    /// Weight for direct sun on the sand (shade from cliffs/trees lowers the score)
+    pub shade_weight: f32,
+    /// Whether shade on the sand raises the score instead, as in a heat warning
+    pub prefers_shade: bool,
     /// Optional custom time-of-day scoring function
     pub time_of_day_scorer: Option<fn(u8) -> f32>,
 }
 
+/// Per-factor weight overrides for an activity profile.
+///
+/// Unset fields keep the preset weight. Each weight must be between 0.0 and 1.0;
+/// weights are normalized by their total when scoring, so they don't need to
+/// add up to exactly 1.0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FactorWeights {
+    /// Temperature weight
+    pub temperature: Option<f32>,
+    /// Water quality weight
+    pub water_quality: Option<f32>,
+    /// Wind weight
+    pub wind: Option<f32>,
+    /// UV weight
+    pub uv: Option<f32>,
+    /// Tide weight
+    pub tide: Option<f32>,
+    /// Crowd weight
+    pub crowd: Option<f32>,
+    /// Direct sun (shade) weight
+    pub shade: Option<f32>,
+}
+
+impl FactorWeights {
+    /// Returns each factor name with its override, in display order
+    pub fn entries(&self) -> [(&'static str, Option<f32>); 7] {
+        [
+            ("temperature", self.temperature),
+            ("water_quality", self.water_quality),
+            ("wind", self.wind),
+            ("uv", self.uv),
+            ("tide", self.tide),
+            ("crowd", self.crowd),
+            ("shade", self.shade),
+        ]
+    }
+}
+
+/// Personal comfort breakpoints for an activity.
+///
+/// Each one moves an end of the profile's ideal range, and the scoring curve
+/// moves with it: someone who finds 20°C too cold to swim sets
+/// `min_temperature: 22`, someone bothered by wind over 20 km/h on the sand
+/// sets `max_wind: 20`. Unset fields keep the preset range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ComfortBreakpoints {
+    /// Coldest temperature in Celsius that still feels ideal
+    pub min_temperature: Option<f32>,
+    /// Warmest temperature in Celsius that still feels ideal
+    pub max_temperature: Option<f32>,
+    /// Lightest wind in km/h that's still ideal, e.g. for sailing
+    pub min_wind: Option<f32>,
+    /// Strongest wind in km/h that's still ideal
+    pub max_wind: Option<f32>,
+}
+
 /// Individual factor scores (0.0-1.0) for a time slot.
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -148,6 +279,19 @@ pub struct ScoreFactors {
     pub crowd: f32,
     /// Time of day score (0.0-1.0)
     pub time_of_day: f32,
+    /// Direct sun exposure score (0.0 = shaded, 1.0 = full sun)
+    pub sun_exposure: f32,
+    /// Surfable waves score (0.0 = flat); 1.0 unless the activity needs waves
+    pub waves: f32,
+    /// Dry weather score (0.0 = rain certain); 1.0 unless the activity is
+    /// out on the path
+    pub rain: f32,
+    /// Air quality score (0.0 = hazardous); 1.0 unless the activity is out
+    /// on the path
+    pub air_quality: f32,
+    /// Clear horizon at sunset score (0.0 = hidden by low cloud); 1.0
+    /// unless the activity is Sunset
+    pub sky: f32,
 }
 
 /// Complete score for a time slot including all factors.
@@ -229,9 +373,10 @@ impl ActivityProfile {
     }
 
     /// Score tide based on preference.
-    /// Height is normalized to max_height.
+    /// Height is normalized to max_height; tides outside 0..max_height
+    /// (king tides, negative lows) score as the nearest end of the range.
     pub fn score_tide(&self, height: f32, max_height: f32) -> f32 {
-        let normalized = height / max_height; // 0.0 = low, 1.0 = high
+        let normalized = (height / max_height).clamp(0.0, 1.0); // 0.0 = low, 1.0 = high
         match self.tide_preference {
             TidePreference::High => normalized,
             TidePreference::Mid => 1.0 - (normalized - 0.5).abs() * 2.0,
@@ -268,37 +413,113 @@ impl ActivityProfile {
             tide: self.score_tide(tide_height, max_tide),
             crowd: self.score_crowd(crowd_level),
             time_of_day: self.time_of_day_scorer.map(|f| f(hour)).unwrap_or(1.0),
+            sun_exposure: 1.0,
+            waves: 1.0,
+            rain: 1.0,
+            air_quality: 1.0,
+            sky: 1.0,
         };
 
+        let score = self.combine_factors(&factors);
+
+        TimeSlotScore {
+            hour,
+            beach_id: beach_id.to_string(),
+            activity: self.activity,
+            score,
+            factors,
+            blocked: false,
+            block_reason: None,
+        }
+    }
+
+    /// Returns a copy of this profile with the given weight overrides applied.
+    pub fn with_weights(mut self, weights: &FactorWeights) -> Self {
+        self.temp_weight = weights.temperature.unwrap_or(self.temp_weight);
+        self.water_quality_weight = weights.water_quality.unwrap_or(self.water_quality_weight);
+        self.wind_weight = weights.wind.unwrap_or(self.wind_weight);
+        self.uv_weight = weights.uv.unwrap_or(self.uv_weight);
+        self.tide_weight = weights.tide.unwrap_or(self.tide_weight);
+        self.crowd_weight = weights.crowd.unwrap_or(self.crowd_weight);
+        self.shade_weight = weights.shade.unwrap_or(self.shade_weight);
+        self
+    }
+
+    /// Returns a copy of this profile with its ideal ranges moved to the given
+    /// comfort breakpoints.
+    pub fn with_comfort(mut self, comfort: &ComfortBreakpoints) -> Self {
+        let (temp_min, temp_max) = self.temp_ideal_range;
+        self.temp_ideal_range = (
+            comfort.min_temperature.unwrap_or(temp_min),
+            comfort.max_temperature.unwrap_or(temp_max),
+        );
+        let (wind_min, wind_max) = self.wind_ideal_range;
+        self.wind_ideal_range = (
+            comfort.min_wind.unwrap_or(wind_min),
+            comfort.max_wind.unwrap_or(wind_max),
+        );
+        self
+    }
+
+    /// Returns a copy of this profile for a heat warning: low UV and shade on
+    /// the sand count for an hour, with at least the heat weights.
+    pub fn in_heat(mut self) -> Self {
+        self.uv_preference = UvPreference::Low;
+        self.uv_weight = self.uv_weight.max(HEAT_UV_WEIGHT);
+        self.shade_weight = self.shade_weight.max(HEAT_SHADE_WEIGHT);
+        self.prefers_shade = true;
+        self
+    }
+
+    /// Sum of the condition factor weights (excluding the fixed time-of-day weight).
+    pub fn factor_weight_total(&self) -> f32 {
+        self.temp_weight
+            + self.water_quality_weight
+            + self.wind_weight
+            + self.uv_weight
+            + self.tide_weight
+            + self.crowd_weight
+            + self.shade_weight
+    }
+
+    /// Combine individual factor scores into a weighted 0-100 score.
+    fn combine_factors(&self, factors: &ScoreFactors) -> u8 {
         let weighted_sum = factors.temperature * self.temp_weight
             + factors.water_quality * self.water_quality_weight
             + factors.wind * self.wind_weight
             + factors.uv * self.uv_weight
             + factors.tide * self.tide_weight
             + factors.crowd * self.crowd_weight
+            + self.shade_score(factors.sun_exposure) * self.shade_weight
             + factors.time_of_day * 0.1; // Slight time preference
 
-        let total_weight = self.temp_weight
-            + self.water_quality_weight
-            + self.wind_weight
-            + self.uv_weight
-            + self.tide_weight
-            + self.crowd_weight
-            + 0.1;
+        let total_weight = self.factor_weight_total() + 0.1;
 
-        let score = ((weighted_sum / total_weight) * 100.0).clamp(0.0, 100.0) as u8;
+        ((weighted_sum / total_weight) * 100.0).clamp(0.0, 100.0) as u8
+    }
 
-        TimeSlotScore {
-            hour,
-            beach_id: beach_id.to_string(),
-            activity: self.activity,
-            score,
-            factors,
-            blocked: false,
-            block_reason: None,
+    /// Score for the sun on the sand: full sun scores best, unless the
+    /// profile prefers shade.
+    fn shade_score(&self, sun_exposure: f32) -> f32 {
+        if self.prefers_shade {
+            1.0 - sun_exposure
+        } else {
+            sun_exposure
         }
     }
 
+    /// Apply a sun exposure fraction (0.0 = shaded, 1.0 = full sun) to a score.
+    ///
+    /// Blocked scores are left untouched. Profiles with no shade weight are
+    /// unaffected since the factor contributes nothing to the total.
+    pub fn apply_sun_exposure(&self, score: &mut TimeSlotScore, exposure: f32) {
+        if score.blocked {
+            return;
+        }
+        score.factors.sun_exposure = exposure.clamp(0.0, 1.0);
+        score.score = self.combine_factors(&score.factors);
+    }
+
     /// Check weather sanity gates for the activity.
     ///
     /// Returns a blocking reason if the weather conditions make this activity
@@ -369,7 +590,15 @@ impl ActivityProfile {
                     ));
                 }
             }
-            Activity::Sunset | Activity::Peace => {
+            Activity::Surf => {
+                if wind > 50.0 {
+                    return Some(format!(
+                        "Wind speed {:.1} km/h is dangerously high for surfing (maximum 50 km/h)",
+                        wind
+                    ));
+                }
+            }
+            Activity::Sunset | Activity::Peace | Activity::DogWalk | Activity::RunWalk => {
                 // No additional blocks beyond universal ones
             }
         }
@@ -377,6 +606,32 @@ impl ActivityProfile {
         None
     }
 
+    /// Check whether conditions right now rule the activity out entirely.
+    ///
+    /// Applies the sanity gates to the current weather, and for swimming also
+    /// rules out water under an advisory or closed. Returns the first reason
+    /// found, or `None` if the activity is possible. Used by the beach list's
+    /// activity filter.
+    pub fn check_hard_constraints(
+        &self,
+        temp: f32,
+        wind: f32,
+        condition: WeatherCondition,
+        water_status: WaterStatus,
+    ) -> Option<String> {
+        if matches!(self.activity, Activity::Swimming | Activity::Surf) {
+            match water_status {
+                WaterStatus::Advisory => {
+                    return Some("Water quality advisory in effect".to_string());
+                }
+                WaterStatus::Closed => return Some("Beach is closed for swimming".to_string()),
+                WaterStatus::Safe | WaterStatus::Unknown => {}
+            }
+        }
+
+        self.check_sanity_gates(temp, wind, Some(condition_weather_code(condition)))
+    }
+
     /// Score a time slot with weather code for sanity gate checking.
     ///
     /// This method first checks sanity gates and returns a blocked score if
@@ -411,6 +666,11 @@ impl ActivityProfile {
                     tide: 0.0,
                     crowd: 0.0,
                     time_of_day: 0.0,
+                    sun_exposure: 0.0,
+                    waves: 0.0,
+                    rain: 0.0,
+                    air_quality: 0.0,
+                    sky: 0.0,
                 },
                 blocked: true,
                 block_reason: Some(reason),
@@ -432,9 +692,69 @@ impl ActivityProfile {
     }
 }
 
-/// Returns the preset ActivityProfile for a given activity.
+/// Weight overrides from the user's config, installed once at startup
+static WEIGHT_OVERRIDES: OnceLock<Vec<(Activity, FactorWeights)>> = OnceLock::new();
+
+/// Installs per-activity weight overrides used by `get_profile`.
+///
+/// Overrides can only be installed once; returns false if they were already set.
+#[allow(dead_code)]
+pub fn set_weight_overrides(overrides: Vec<(Activity, FactorWeights)>) -> bool {
+    WEIGHT_OVERRIDES.set(overrides).is_ok()
+}
+
+/// Comfort breakpoints from the user's config, installed once at startup
+static COMFORT_OVERRIDES: OnceLock<Vec<(Activity, ComfortBreakpoints)>> = OnceLock::new();
+
+/// Installs per-activity comfort breakpoints used by `get_profile`.
+///
+/// Breakpoints can only be installed once; returns false if they were already set.
+#[allow(dead_code)]
+pub fn set_comfort_overrides(overrides: Vec<(Activity, ComfortBreakpoints)>) -> bool {
+    COMFORT_OVERRIDES.set(overrides).is_ok()
+}
+
+/// A representative WMO weather code for a condition, for the sanity gates
+///
+/// Current weather keeps only the mapped condition, so gates are checked
+/// against a code from the matching range.
+fn condition_weather_code(condition: WeatherCondition) -> u8 {
+    match condition {
+        WeatherCondition::Clear => 0,
+        WeatherCondition::PartlyCloudy => 2,
+        WeatherCondition::Cloudy => 3,
+        WeatherCondition::Fog => 45,
+        WeatherCondition::Rain => 61,
+        WeatherCondition::Showers => 80,
+        WeatherCondition::Snow => 71,
+        WeatherCondition::Thunderstorm => 95,
+    }
+}
+
+/// Returns the ActivityProfile for a given activity, with any configured
+/// weight overrides and comfort breakpoints applied.
 #[allow(dead_code)]
 pub fn get_profile(activity: Activity) -> ActivityProfile {
+    let profile = preset_profile(activity);
+    let profile = match WEIGHT_OVERRIDES
+        .get()
+        .and_then(|overrides| overrides.iter().find(|(a, _)| *a == activity))
+    {
+        Some((_, weights)) => profile.with_weights(weights),
+        None => profile,
+    };
+    match COMFORT_OVERRIDES
+        .get()
+        .and_then(|overrides| overrides.iter().find(|(a, _)| *a == activity))
+    {
+        Some((_, comfort)) => profile.with_comfort(comfort),
+        None => profile,
+    }
+}
+
+/// Returns the built-in ActivityProfile for a given activity.
+#[allow(dead_code)]
+pub fn preset_profile(activity: Activity) -> ActivityProfile {
     match activity {
         Activity::Swimming => ActivityProfile {
             activity: Activity::Swimming,
@@ -448,6 +768,8 @@ pub fn get_profile(activity: Activity) -> ActivityProfile {
             tide_weight: 0.15,
             tide_preference: TidePreference::Mid,
             crowd_weight: 0.1,
+            shade_weight: 0.0,
+            prefers_shade: false,
             time_of_day_scorer: None,
         },
         Activity::Sunbathing => ActivityProfile {
@@ -462,6 +784,8 @@ pub fn get_profile(activity: Activity) -> ActivityProfile {
             tide_weight: 0.0,
             tide_preference: TidePreference::Any,
             crowd_weight: 0.15,
+            shade_weight: 0.15, // Shade from cliffs/trees ends a tanning session
+            prefers_shade: false,
             time_of_day_scorer: None,
         },
         Activity::Sailing => ActivityProfile {
@@ -476,6 +800,8 @@ pub fn get_profile(activity: Activity) -> ActivityProfile {
             tide_weight: 0.2,
             tide_preference: TidePreference::High,
             crowd_weight: 0.1,
+            shade_weight: 0.0,
+            prefers_shade: false,
             time_of_day_scorer: None,
         },
         Activity::Sunset => ActivityProfile {
@@ -490,6 +816,8 @@ pub fn get_profile(activity: Activity) -> ActivityProfile {
             tide_weight: 0.0,
             tide_preference: TidePreference::Any,
             crowd_weight: 0.15,
+            shade_weight: 0.0,
+            prefers_shade: false,
             time_of_day_scorer: Some(sunset_time_scorer),
         },
         Activity::Peace => ActivityProfile {
@@ -504,11 +832,141 @@ pub fn get_profile(activity: Activity) -> ActivityProfile {
             tide_weight: 0.0,
             tide_preference: TidePreference::Any,
             crowd_weight: 0.7, // Highly crowd-averse
+            shade_weight: 0.0,
+            prefers_shade: false,
             time_of_day_scorer: Some(peace_time_scorer),
         },
+        Activity::DogWalk => ActivityProfile {
+            activity: Activity::DogWalk,
+            temp_weight: 0.25,
+            temp_ideal_range: (8.0, 22.0), // Cool enough for the dog
+            water_quality_weight: 0.0,
+            wind_weight: 0.15,
+            wind_ideal_range: (0.0, 20.0),
+            uv_weight: 0.05,
+            uv_preference: UvPreference::Low,
+            tide_weight: 0.2,
+            tide_preference: TidePreference::Low, // More sand to run on
+            crowd_weight: 0.35,
+            shade_weight: 0.0,
+            prefers_shade: false,
+            time_of_day_scorer: None,
+        },
+        // Waves and the wind across them are scored by `surf_quality`; the
+        // profile only covers being in and out of the water
+        Activity::Surf => ActivityProfile {
+            activity: Activity::Surf,
+            temp_weight: 0.2,
+            temp_ideal_range: (12.0, 25.0), // Wetsuit weather is fine
+            water_quality_weight: 0.4,
+            wind_weight: 0.0,
+            wind_ideal_range: (0.0, 30.0),
+            uv_weight: 0.05,
+            uv_preference: UvPreference::Any,
+            tide_weight: 0.2,
+            tide_preference: TidePreference::Mid, // Low tide leaves flats, high tide backwash
+            crowd_weight: 0.05,
+            shade_weight: 0.0,
+            prefers_shade: false,
+            time_of_day_scorer: None,
+        },
+        // Rain and air quality are scored by `rain_score` and
+        // `air_quality_score`; the water and the tide don't matter on the path
+        Activity::RunWalk => ActivityProfile {
+            activity: Activity::RunWalk,
+            temp_weight: 0.4,
+            temp_ideal_range: (8.0, 18.0), // Cool enough to keep going
+            water_quality_weight: 0.0,
+            wind_weight: 0.3,
+            wind_ideal_range: (0.0, 15.0),
+            uv_weight: 0.1,
+            uv_preference: UvPreference::Low,
+            tide_weight: 0.0,
+            tide_preference: TidePreference::Any,
+            crowd_weight: 0.2, // The seawall gets busy on sunny afternoons
+            shade_weight: 0.0,
+            prefers_shade: false,
+            time_of_day_scorer: None,
+        },
+    }
+}
+
+/// How dry an hour is likely to stay (0.0-1.0) for a run or walk, from the
+/// forecast chance of precipitation
+///
+/// A 20% chance hardly matters; beyond it each point takes a point off,
+/// down to 0.2 when rain is certain, since a run in the rain is still a run.
+pub fn rain_score(precipitation_chance: u8) -> f32 {
+    let chance = f32::from(precipitation_chance.min(100)) / 100.0;
+    1.0 - (chance - 0.2).max(0.0)
+}
+
+/// How good the air is (0.0-1.0) for exercising outdoors, from the US Air
+/// Quality Index
+///
+/// Good air (up to 50) is ideal and moderate air (51-100) fine for most;
+/// from 101 sensitive groups should ease off, from 151 everyone should,
+/// and above 200 (wildfire smoke) it's no time for a run.
+pub fn air_quality_score(aqi: u16) -> f32 {
+    match aqi {
+        0..=50 => 1.0,
+        51..=100 => 0.8,
+        101..=150 => 0.5,
+        151..=200 => 0.2,
+        _ => 0.0,
     }
 }
 
+/// How well a sunset is likely to show (0.0-1.0), from the percentage of
+/// the sky covered by low cloud at the sunset hour
+///
+/// Low cloud sits on the horizon where the sun goes down: up to 30% leaves
+/// gaps for it, beyond that it's hidden a little more with each point, down
+/// to 0.3 under a full deck, since an afterglow on the clouds above may
+/// still show.
+pub fn sunset_sky_score(low_cloud: u8) -> f32 {
+    let cover = f32::from(low_cloud.min(100)) / 100.0;
+    1.0 - ((cover - 0.3) / 0.7).max(0.0) * 0.7
+}
+
+/// How surfable the waves are (0.0-1.0) for surfing or bodyboarding
+///
+/// Waves under 0.3m are flat (0.0); 0.6-1.5m is ideal, and anything over
+/// 2.5m is too big for a bodyboard. Short periods are wind chop, 8s or more
+/// is a proper swell. Wind over 10 km/h blowing onshore (from the direction
+/// the beach faces) crumbles the waves, while offshore wind grooms them.
+/// `wind_from` and `facing` are compass bearings in degrees; a wind without
+/// a direction counts as cross-shore.
+pub fn surf_quality(
+    wave_height: f32,
+    wave_period: Option<f32>,
+    wind: f32,
+    wind_from: Option<f32>,
+    facing: f32,
+) -> f32 {
+    let height = match wave_height {
+        h if h < 0.3 => 0.0,
+        h if h < 0.6 => (h - 0.3) / 0.3,
+        h if h <= 1.5 => 1.0,
+        h if h <= 2.5 => 1.0 - (h - 1.5) * 0.7,
+        _ => 0.3,
+    };
+    // Without a period, assume the short chop the Salish Sea usually has
+    let period = ((wave_period.unwrap_or(4.0) - 3.0) / 5.0).clamp(0.2, 1.0);
+    let wind_score = if wind <= 10.0 {
+        1.0
+    } else {
+        // 0.0 dead onshore, 1.0 dead offshore
+        let offshore = wind_from.map_or(0.5, |from| {
+            let off = (from - facing).rem_euclid(360.0);
+            off.min(360.0 - off) / 180.0
+        });
+        let strength = ((wind - 10.0) / 20.0).clamp(0.0, 1.0);
+        1.0 - strength * (1.0 - offshore) * 0.8
+    };
+    height * (0.5 * period + 0.5 * wind_score)
+}
+
 /// Custom time-of-day scorer for sunset activities.
 /// Peaks at evening hours (18-20).
 #[allow(dead_code)]
@@ -578,19 +1036,432 @@ pub fn peace_time_scorer(hour: u8) -> f32 {
     }
 }
 
+/// UV index above which sunscreen advice is given
+pub const SUNSCREEN_UV_THRESHOLD: f64 = 6.0;
+
+/// UV index from which the highest protection is recommended
+const SPF_50_UV: f64 = 8.0;
+
+/// UV index below which sunscreen no longer needs reapplying
+const UV_EASED: f64 = 3.0;
+
+/// Minutes between applications in the sun
+const REAPPLY_MINUTES: i64 = 120;
+
+/// Minutes between applications when swimming or sweating, the usual
+/// water-resistance rating of sunscreen
+const REAPPLY_WET_MINUTES: i64 = 80;
+
+/// Humidity (%) from which sweat is assumed to wear sunscreen off sooner
+const SWEATY_HUMIDITY: u8 = 80;
+
+/// Sunscreen advice for time in the sun starting now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SunscreenAdvice {
+    /// Recommended SPF (30 or 50)
+    pub spf: u8,
+    /// When to reapply, if the UV is still high by then
+    pub reapply_by: Option<NaiveTime>,
+    /// Hour the forecast UV drops below 3, if that comes before reapplying
+    pub eases_at: Option<u8>,
+}
+
+impl SunscreenAdvice {
+    /// One-line advice, e.g. "SPF 50 recommended, reapply by 14:30"
+    pub fn message(&self) -> String {
+        match (self.reapply_by, self.eases_at) {
+            (Some(at), _) => format!(
+                "SPF {} recommended, reapply by {}",
+                self.spf,
+                format_time(&at)
+            ),
+            (None, Some(hour)) => format!(
+                "SPF {} recommended until UV eases around {}",
+                self.spf,
+                format_hour(hour)
+            ),
+            (None, None) => format!("SPF {} recommended", self.spf),
+        }
+    }
+}
+
+/// Works out sunscreen advice from the UV now and its hourly trajectory
+///
+/// Advice is only given while the UV index is above
+/// `SUNSCREEN_UV_THRESHOLD`. Assumes sunscreen goes on at `now` (beach
+/// clock) and the sun stays on you: the SPF follows the highest UV until the
+/// next application, which is due after two hours, or 80 minutes when
+/// swimming or in humid, sweaty weather. If the forecast UV drops below 3
+/// before then, no reapplication is needed.
+pub fn sun_safety(
+    uv: f64,
+    humidity: u8,
+    hourly: &[HourlyForecast],
+    now: NaiveTime,
+    activity: Option<Activity>,
+) -> Option<SunscreenAdvice> {
+    if uv <= SUNSCREEN_UV_THRESHOLD {
+        return None;
+    }
+
+    let wet = activity == Some(Activity::Swimming) || humidity >= SWEATY_HUMIDITY;
+    let interval = Duration::minutes(if wet {
+        REAPPLY_WET_MINUTES
+    } else {
+        REAPPLY_MINUTES
+    });
+    let (reapply_at, wrapped) = now.overflowing_add_signed(interval);
+    // Forecast hours after this one that start before reapplying is due
+    let last_hour = if wrapped != 0 { 23 } else { reapply_at.hour() };
+    let upcoming: Vec<&HourlyForecast> = hourly
+        .iter()
+        .filter(|h| (h.hour as u32) > now.hour() && (h.hour as u32) <= last_hour)
+        .collect();
+
+    let peak = upcoming.iter().map(|h| h.uv).fold(uv, f64::max);
+    let eases_at = upcoming.iter().find(|h| h.uv < UV_EASED).map(|h| h.hour);
+    // Rounded down to five minutes so "reapply by" is never late
+    let reapply_by = (eases_at.is_none() && wrapped == 0)
+        .then(|| NaiveTime::from_hms_opt(reapply_at.hour(), reapply_at.minute() / 5 * 5, 0))
+        .flatten();
+
+    Some(SunscreenAdvice {
+        spf: if peak >= SPF_50_UV { 50 } else { 30 },
+        reapply_by,
+        eases_at,
+    })
+}
+
+/// Wind (km/h) from which a beach fire throws sparks and smoke around
+const FIRE_BREEZY_KMH: f64 = 20.0;
+
+/// Wind (km/h) from which a beach fire can't be kept under control
+const FIRE_MAX_WIND_KMH: f64 = 30.0;
+
+/// High tide (m) from which the water reaches the sand above the logs, so
+/// fires need building well back; a king tide floods the fire line
+const FIRE_TIDE_LINE_M: f64 = 4.2;
+
+/// Hours after sunset a beach fire is expected to burn
+const FIRE_HOURS_AFTER_SUNSET: i64 = 4;
+
+/// Answer to "Beach fire tonight?", from a single source or all of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FireVerdict {
+    /// Nothing stands in the way
+    Yes,
+    /// Possible with care, or a source couldn't be checked
+    Maybe,
+    /// Ruled out
+    No,
+}
+
+impl FireVerdict {
+    /// Display label, e.g. "Maybe"
+    pub fn label(&self) -> &'static str {
+        match self {
+            FireVerdict::Yes => "Yes",
+            FireVerdict::Maybe => "Maybe",
+            FireVerdict::No => "No",
+        }
+    }
+}
+
+/// One source's say in a beach fire advisory, e.g. (No, "Campfire ban in
+/// effect")
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FireFactor {
+    pub verdict: FireVerdict,
+    pub note: String,
+}
+
+/// "Beach fire tonight?" verdict with what each source said
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeachFireAdvisory {
+    /// The worst of the factors' verdicts
+    pub verdict: FireVerdict,
+    /// Fire ban, wind, tide and sunset, in that order
+    pub factors: Vec<FireFactor>,
+}
+
+/// High tide during the evening a fire burns, as far as is known
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+enum EveningTide {
+    #[default]
+    Unknown,
+    /// No high tide in the evening, or no tide at all (lakes)
+    Clear,
+    /// High tide of this height (m) at this time
+    High(f64, NaiveTime),
+}
+
+/// Builds a beach fire advisory from several sources
+///
+/// Each source is given separately and weighed on its own; the worst
+/// verdict wins, and a source that isn't given counts as a Maybe, since a
+/// fire shouldn't be lit on a guess.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BeachFireAdvisoryBuilder {
+    campfire_ban: Option<bool>,
+    wind: Option<f64>,
+    tide: EveningTide,
+    sunset: Option<NaiveTime>,
+}
+
+impl BeachFireAdvisoryBuilder {
+    /// Whether campfires are banned
+    pub fn campfire_ban(mut self, banned: bool) -> Self {
+        self.campfire_ban = Some(banned);
+        self
+    }
+
+    /// Forecast wind around sunset, in km/h
+    pub fn wind(mut self, km_h: f64) -> Self {
+        self.wind = Some(km_h);
+        self
+    }
+
+    /// The evening's high tide, in metres
+    pub fn evening_high_tide(mut self, height: f64, at: NaiveTime) -> Self {
+        self.tide = EveningTide::High(height, at);
+        self
+    }
+
+    /// No high tide reaches the beach in the evening
+    pub fn no_evening_high_tide(mut self) -> Self {
+        self.tide = EveningTide::Clear;
+        self
+    }
+
+    /// Tonight's sunset, on the beach's clock
+    pub fn sunset(mut self, sunset: NaiveTime) -> Self {
+        self.sunset = Some(sunset);
+        self
+    }
+
+    /// Weighs each source and combines them into the advisory
+    pub fn build(self) -> BeachFireAdvisory {
+        let factor = |verdict, note: String| FireFactor { verdict, note };
+        let factors = vec![
+            match self.campfire_ban {
+                Some(true) => factor(FireVerdict::No, "Campfire ban in effect".to_string()),
+                Some(false) => factor(FireVerdict::Yes, "No campfire ban".to_string()),
+                None => factor(FireVerdict::Maybe, "Fire ban status unknown".to_string()),
+            },
+            match self.wind {
+                Some(wind) if wind >= FIRE_MAX_WIND_KMH => {
+                    factor(FireVerdict::No, format!("Too windy: {:.0} km/h", wind))
+                }
+                Some(wind) if wind >= FIRE_BREEZY_KMH => factor(
+                    FireVerdict::Maybe,
+                    format!("Breezy: {:.0} km/h, keep it small", wind),
+                ),
+                Some(wind) => factor(FireVerdict::Yes, format!("Light wind: {:.0} km/h", wind)),
+                None => factor(FireVerdict::Maybe, "Wind unknown".to_string()),
+            },
+            match self.tide {
+                EveningTide::High(height, at) if is_king_tide(height) => factor(
+                    FireVerdict::No,
+                    format!(
+                        "King tide {:.1}m at {} floods the fire line",
+                        height,
+                        format_time(&at)
+                    ),
+                ),
+                EveningTide::High(height, at) if height >= FIRE_TIDE_LINE_M => factor(
+                    FireVerdict::Maybe,
+                    format!(
+                        "High tide {:.1}m at {}: build well above the logs",
+                        height,
+                        format_time(&at)
+                    ),
+                ),
+                EveningTide::High(height, at) => factor(
+                    FireVerdict::Yes,
+                    format!("High tide only {:.1}m at {}", height, format_time(&at)),
+                ),
+                EveningTide::Clear => {
+                    factor(FireVerdict::Yes, "No high tide this evening".to_string())
+                }
+                EveningTide::Unknown => factor(FireVerdict::Maybe, "Tide unknown".to_string()),
+            },
+            match self.sunset {
+                Some(sunset) => {
+                    factor(FireVerdict::Yes, format!("Sunset {}", format_time(&sunset)))
+                }
+                None => factor(FireVerdict::Maybe, "Sunset unknown".to_string()),
+            },
+        ];
+        BeachFireAdvisory {
+            verdict: factors
+                .iter()
+                .map(|factor| factor.verdict)
+                .max()
+                .unwrap_or(FireVerdict::Maybe),
+            factors,
+        }
+    }
+}
+
+/// "Beach fire tonight?" at a beach, from its conditions and the campfire
+/// bans in effect (if they could be fetched)
+///
+/// The wind is the forecast for the hour of sunset, and the tide the next
+/// high if it falls between an hour before sunset and when the fire dies
+/// down; lakes have no tide to worry about.
+pub fn beach_fire_tonight(
+    conditions: &BeachConditions,
+    fire_bans: Option<&FireBans>,
+) -> BeachFireAdvisory {
+    let mut builder = BeachFireAdvisoryBuilder::default();
+    if let Some(bans) = fire_bans {
+        builder = builder.campfire_ban(bans.bans_campfires());
+    }
+    let sunset = conditions.weather.as_ref().map(|weather| {
+        let wind = weather
+            .hourly
+            .iter()
+            .find(|hour| u32::from(hour.hour) == weather.sunset.hour())
+            .map_or(weather.wind, |hour| hour.wind);
+        builder = builder.wind(wind).sunset(weather.sunset);
+        weather.sunset
+    });
+    if conditions.beach.water_body != WaterBody::Ocean {
+        builder = builder.no_evening_high_tide();
+    } else if let (Some(sunset), Some(tides)) = (sunset, &conditions.tides) {
+        let evening_high = tides.next_high.as_ref().and_then(|high| {
+            let at = conditions
+                .beach
+                .timezone
+                .local(high.time.with_timezone(&Utc));
+            let sunset = at.date().and_time(sunset);
+            let burning =
+                sunset - Duration::hours(1)..=sunset + Duration::hours(FIRE_HOURS_AFTER_SUNSET);
+            burning.contains(&at).then_some((high.height, at.time()))
+        });
+        builder = match evening_high {
+            Some((height, at)) => builder.evening_high_tide(height, at),
+            None => builder.no_evening_high_tide(),
+        };
+    }
+    builder.build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data::DataQuality;
 
     #[test]
-    fn test_activity_all_returns_five_activities() {
+    fn test_activity_all_returns_eight_activities() {
         let activities = Activity::all();
-        assert_eq!(activities.len(), 5);
+        assert_eq!(activities.len(), 8);
         assert!(activities.contains(&Activity::Swimming));
         assert!(activities.contains(&Activity::Sunbathing));
         assert!(activities.contains(&Activity::Sailing));
         assert!(activities.contains(&Activity::Sunset));
         assert!(activities.contains(&Activity::Peace));
+        assert!(activities.contains(&Activity::DogWalk));
+        assert!(activities.contains(&Activity::Surf));
+        assert!(activities.contains(&Activity::RunWalk));
+    }
+
+    fn forecast(hour: u8, uv: f64) -> HourlyForecast {
+        HourlyForecast {
+            hour,
+            temperature: 25.0,
+            feels_like: 25.0,
+            condition: WeatherCondition::Clear,
+            wind: 5.0,
+            wind_direction: "W".to_string(),
+            uv,
+            precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        }
+    }
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_beach_fire_advisory_takes_the_worst_source() {
+        let sunset = NaiveTime::from_hms_opt(20, 45, 0).unwrap();
+        let high_at = NaiveTime::from_hms_opt(22, 10, 0).unwrap();
+        let clear = BeachFireAdvisoryBuilder::default()
+            .campfire_ban(false)
+            .wind(8.0)
+            .evening_high_tide(3.6, high_at)
+            .sunset(sunset)
+            .build();
+        assert_eq!(clear.verdict, FireVerdict::Yes);
+        assert_eq!(clear.factors.len(), 4);
+
+        let high_tide = BeachFireAdvisoryBuilder::default()
+            .campfire_ban(false)
+            .wind(8.0)
+            .evening_high_tide(4.4, high_at)
+            .sunset(sunset)
+            .build();
+        assert_eq!(high_tide.verdict, FireVerdict::Maybe);
+        assert!(high_tide.factors[2].note.starts_with("High tide 4.4m"));
+
+        let banned = BeachFireAdvisoryBuilder::default()
+            .campfire_ban(true)
+            .wind(25.0)
+            .no_evening_high_tide()
+            .sunset(sunset)
+            .build();
+        assert_eq!(banned.verdict, FireVerdict::No);
+        assert_eq!(banned.factors[0].note, "Campfire ban in effect");
+        assert_eq!(banned.factors[1].verdict, FireVerdict::Maybe);
+
+        // Sources that weren't given can't be relied on
+        let unknown = BeachFireAdvisoryBuilder::default().wind(5.0).build();
+        assert_eq!(unknown.verdict, FireVerdict::Maybe);
+        assert_eq!(unknown.factors[0].note, "Fire ban status unknown");
+    }
+
+    #[test]
+    fn test_sun_safety_only_above_the_uv_threshold() {
+        assert_eq!(sun_safety(6.0, 50, &[], time(12, 0), None), None);
+
+        let advice = sun_safety(7.0, 50, &[], time(12, 32), None).unwrap();
+        assert_eq!(advice.spf, 30);
+        assert_eq!(advice.message(), "SPF 30 recommended, reapply by 2:30 PM");
+    }
+
+    #[test]
+    fn test_sun_safety_follows_the_uv_trajectory() {
+        let hourly = [forecast(12, 7.0), forecast(13, 8.5), forecast(14, 7.0)];
+        let advice = sun_safety(7.0, 50, &hourly, time(12, 10), None).unwrap();
+        assert_eq!(advice.spf, 50, "UV peaks at 8.5 before reapplying");
+
+        // Swimming or sweating wears sunscreen off sooner
+        let advice = sun_safety(7.0, 50, &hourly, time(12, 10), Some(Activity::Swimming));
+        assert_eq!(advice.unwrap().reapply_by, Some(time(13, 30)));
+        let advice = sun_safety(7.0, 85, &hourly, time(12, 10), None);
+        assert_eq!(advice.unwrap().reapply_by, Some(time(13, 30)));
+
+        // UV easing before reapplying is due means no second coat
+        let hourly = [forecast(16, 4.0), forecast(17, 2.0)];
+        let advice = sun_safety(6.5, 50, &hourly, time(15, 30), None).unwrap();
+        assert_eq!(advice.reapply_by, None);
+        assert_eq!(
+            advice.message(),
+            "SPF 30 recommended until UV eases around 5:00 PM"
+        );
+    }
+
+    #[test]
+    fn test_activity_slug_parses_back() {
+        for activity in Activity::all() {
+            assert_eq!(Activity::from_str(activity.slug()), Some(*activity));
+        }
+        assert_eq!(Activity::DogWalk.slug(), "dog-walk");
     }
 
     #[test]
@@ -656,11 +1527,108 @@ mod tests {
         assert_eq!(Activity::from_str("QUIET"), Some(Activity::Peace));
     }
 
+    #[test]
+    fn test_from_str_dog_walk_aliases() {
+        assert_eq!(Activity::from_str("dog"), Some(Activity::DogWalk));
+        assert_eq!(Activity::from_str("Dogs"), Some(Activity::DogWalk));
+        assert_eq!(Activity::from_str("dog-walk"), Some(Activity::DogWalk));
+    }
+
+    #[test]
+    fn test_from_str_surf_aliases() {
+        assert_eq!(Activity::from_str("surf"), Some(Activity::Surf));
+        assert_eq!(Activity::from_str("Bodyboard"), Some(Activity::Surf));
+        assert_eq!(Activity::Surf.label(), "Surf/Bodyboard");
+    }
+
+    #[test]
+    fn test_surf_offered_only_at_wave_exposed_beaches() {
+        assert!(Activity::Surf.offered_at("wreck"));
+        assert!(!Activity::Surf.offered_at("english-bay"));
+        assert!(Activity::DogWalk.offered_at("english-bay"));
+    }
+
+    #[test]
+    fn test_from_str_run_walk_aliases() {
+        assert_eq!(Activity::from_str("run"), Some(Activity::RunWalk));
+        assert_eq!(Activity::from_str("Walking"), Some(Activity::RunWalk));
+        assert_eq!(Activity::from_str("jog"), Some(Activity::RunWalk));
+        assert_eq!(Activity::RunWalk.label(), "Run/Walk");
+        assert!(Activity::RunWalk.offered_at("english-bay"));
+    }
+
+    #[test]
+    fn test_run_walk_ignores_the_water_and_the_tide() {
+        let profile = preset_profile(Activity::RunWalk);
+        let score = |water_status, tide_height| {
+            profile
+                .score_time_slot(
+                    9,
+                    "kitsilano",
+                    14.0,
+                    5.0,
+                    3.0,
+                    water_status,
+                    tide_height,
+                    4.8,
+                    0.2,
+                )
+                .score
+        };
+        assert_eq!(
+            score(WaterStatus::Safe, 4.5),
+            score(WaterStatus::Closed, 0.3)
+        );
+        assert!(score(WaterStatus::Safe, 2.4) >= 80);
+    }
+
+    #[test]
+    fn test_rain_and_air_quality_scores() {
+        assert_eq!(rain_score(0), 1.0);
+        assert_eq!(rain_score(20), 1.0);
+        assert!((rain_score(60) - 0.6).abs() < 0.001);
+        assert!((rain_score(100) - 0.2).abs() < 0.001);
+
+        assert_eq!(air_quality_score(35), 1.0);
+        assert_eq!(air_quality_score(120), 0.5);
+        assert_eq!(air_quality_score(180), 0.2);
+        assert_eq!(air_quality_score(260), 0.0);
+    }
+
+    #[test]
+    fn test_sunset_sky_score_fades_under_low_cloud() {
+        assert_eq!(sunset_sky_score(0), 1.0);
+        assert_eq!(sunset_sky_score(30), 1.0);
+        assert!((sunset_sky_score(65) - 0.65).abs() < 0.001);
+        assert!((sunset_sky_score(100) - 0.3).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_surf_quality_wants_waves_and_offshore_wind() {
+        // Beach facing west (270°)
+        assert_eq!(surf_quality(0.2, Some(10.0), 5.0, None, 270.0), 0.0, "flat");
+
+        let glassy = surf_quality(1.0, Some(9.0), 5.0, None, 270.0);
+        assert!(glassy > 0.95, "{glassy}");
+
+        let offshore = surf_quality(1.0, Some(9.0), 30.0, Some(90.0), 270.0);
+        let onshore = surf_quality(1.0, Some(9.0), 30.0, Some(270.0), 270.0);
+        assert!(offshore > 0.95, "{offshore}");
+        assert!(onshore < 0.7, "{onshore}");
+
+        let chop = surf_quality(1.0, Some(3.0), 5.0, None, 270.0);
+        assert!(chop < glassy);
+        assert!(
+            surf_quality(3.5, Some(12.0), 5.0, None, 270.0) < glassy,
+            "too big"
+        );
+    }
+
     #[test]
     fn test_from_str_invalid_input() {
         assert_eq!(Activity::from_str("invalid"), None);
         assert_eq!(Activity::from_str(""), None);
-        assert_eq!(Activity::from_str("running"), None);
+        assert_eq!(Activity::from_str("skiing"), None);
         assert_eq!(Activity::from_str("beach"), None);
     }
 
@@ -949,6 +1917,8 @@ mod tests {
         // High tide preference
         assert_eq!(profile.score_tide(4.8, 4.8), 1.0); // Full high tide
         assert_eq!(profile.score_tide(0.0, 4.8), 0.0); // Low tide
+        assert_eq!(profile.score_tide(5.2, 4.8), 1.0); // King tide
+        assert_eq!(profile.score_tide(-0.3, 4.8), 0.0); // Below chart datum
     }
 
     #[test]
@@ -1323,6 +2293,42 @@ mod tests {
         assert!(score.score > 0);
     }
 
+    #[test]
+    fn test_hard_constraints_rule_out_unsafe_water_only_for_swimming() {
+        let swimming = get_profile(Activity::Swimming);
+        let sailing = get_profile(Activity::Sailing);
+        let clear = WeatherCondition::Clear;
+
+        assert!(swimming
+            .check_hard_constraints(22.0, 10.0, clear, WaterStatus::Advisory)
+            .is_some());
+        assert!(swimming
+            .check_hard_constraints(22.0, 10.0, clear, WaterStatus::Closed)
+            .is_some());
+        assert!(swimming
+            .check_hard_constraints(22.0, 10.0, clear, WaterStatus::Unknown)
+            .is_none());
+        assert!(sailing
+            .check_hard_constraints(22.0, 10.0, clear, WaterStatus::Closed)
+            .is_none());
+
+        // Weather gates still apply through the current condition
+        assert!(sailing
+            .check_hard_constraints(22.0, 45.0, clear, WaterStatus::Safe)
+            .is_some());
+        assert!(swimming
+            .check_hard_constraints(22.0, 10.0, WeatherCondition::Rain, WaterStatus::Safe)
+            .is_some());
+        assert!(get_profile(Activity::Peace)
+            .check_hard_constraints(
+                22.0,
+                10.0,
+                WeatherCondition::Thunderstorm,
+                WaterStatus::Safe
+            )
+            .is_some());
+    }
+
     #[test]
     fn test_sanity_gate_block_reason_contains_temperature() {
         let profile = get_profile(Activity::Swimming);
@@ -1399,6 +2405,58 @@ mod tests {
         assert!(score.score > 0);
     }
 
+    #[test]
+    fn test_shade_lowers_sunbathing_score() {
+        let profile = get_profile(Activity::Sunbathing);
+        let sunny =
+            profile.score_time_slot(14, "test", 26.0, 5.0, 8.0, WaterStatus::Safe, 2.4, 4.8, 0.3);
+        let mut shaded = sunny.clone();
+        profile.apply_sun_exposure(&mut shaded, 0.0);
+
+        assert_eq!(shaded.factors.sun_exposure, 0.0);
+        assert!(
+            shaded.score < sunny.score,
+            "Shaded={} should be lower than sunny={}",
+            shaded.score,
+            sunny.score
+        );
+    }
+
+    #[test]
+    fn test_heat_favors_shade_and_low_uv() {
+        let profile = get_profile(Activity::Sunbathing).in_heat();
+        assert_eq!(profile.uv_preference, UvPreference::Low);
+        // Weights already above the heat weights are kept
+        assert_eq!(profile.uv_weight, 0.25);
+        assert_eq!(profile.shade_weight, HEAT_SHADE_WEIGHT);
+
+        let sunny =
+            profile.score_time_slot(14, "test", 31.0, 5.0, 8.0, WaterStatus::Safe, 2.4, 4.8, 0.3);
+        let mut shaded = sunny.clone();
+        profile.apply_sun_exposure(&mut shaded, 0.0);
+        assert!(
+            shaded.score > sunny.score,
+            "Shaded={} should beat sunny={} in the heat",
+            shaded.score,
+            sunny.score
+        );
+
+        // Swimmers get the shade weight too
+        let swimming = get_profile(Activity::Swimming).in_heat();
+        assert_eq!(swimming.shade_weight, HEAT_SHADE_WEIGHT);
+    }
+
+    #[test]
+    fn test_shade_does_not_affect_swimming() {
+        let profile = get_profile(Activity::Swimming);
+        let sunny =
+            profile.score_time_slot(14, "test", 24.0, 5.0, 5.0, WaterStatus::Safe, 2.4, 4.8, 0.3);
+        let mut shaded = sunny.clone();
+        profile.apply_sun_exposure(&mut shaded, 0.0);
+
+        assert_eq!(shaded.score, sunny.score);
+    }
+
     #[test]
     fn test_score_time_slot_sets_blocked_false() {
         // Verify that the original score_time_slot method sets blocked=false
@@ -1458,4 +2516,57 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_with_weights_overrides_only_set_factors() {
+        let preset = preset_profile(Activity::Sailing);
+        let weights = FactorWeights {
+            wind: Some(0.9),
+            tide: Some(0.0),
+            ..FactorWeights::default()
+        };
+        let profile = preset.clone().with_weights(&weights);
+
+        assert_eq!(profile.wind_weight, 0.9);
+        assert_eq!(profile.tide_weight, 0.0);
+        assert_eq!(profile.temp_weight, preset.temp_weight);
+        assert_eq!(profile.crowd_weight, preset.crowd_weight);
+    }
+
+    #[test]
+    fn test_heavier_wind_weight_rewards_windy_sailing() {
+        // Ideal sailing wind but cold and a low tide
+        let score = |profile: &ActivityProfile| {
+            profile
+                .score_time_slot(14, "test", 8.0, 20.0, 3.0, WaterStatus::Safe, 0.5, 4.8, 0.5)
+                .score
+        };
+        let preset = preset_profile(Activity::Sailing);
+        let windsurfer = preset.clone().with_weights(&FactorWeights {
+            wind: Some(1.0),
+            ..FactorWeights::default()
+        });
+
+        assert!(score(&windsurfer) > score(&preset));
+    }
+
+    #[test]
+    fn test_comfort_breakpoints_shift_the_scoring_curves() {
+        let preset = preset_profile(Activity::Swimming);
+        let chilly = preset.clone().with_comfort(&ComfortBreakpoints {
+            min_temperature: Some(23.0),
+            max_wind: Some(8.0),
+            ..ComfortBreakpoints::default()
+        });
+
+        assert_eq!(chilly.temp_ideal_range, (23.0, preset.temp_ideal_range.1));
+        assert_eq!(chilly.wind_ideal_range, (preset.wind_ideal_range.0, 8.0));
+        // 21°C is ideal by the preset but a bit cold for this swimmer
+        assert_eq!(preset.score_temperature(21.0), 1.0);
+        assert!((chilly.score_temperature(21.0) - 0.6).abs() < 1e-6);
+        // 12 km/h bothers them; 4 km/h doesn't
+        assert_eq!(preset.score_wind(12.0), 1.0);
+        assert!(chilly.score_wind(12.0) < 1.0);
+        assert_eq!(chilly.score_wind(4.0), 1.0);
+    }
 }
@@ -0,0 +1,218 @@
+//! Alerts for watched conditions
+//!
+//! Best window reminders and water quality advisory changes show as a toast.
+//! With `--alert`, they also ring the terminal bell or flash the screen, so a
+//! TUI left in a background tmux pane still gets noticed (tmux marks a
+//! window that rang the bell in its status line).
+//!
+//! The config's `notifications` section can turn each kind of alert off, and
+//! set quiet hours during which alerts only show their toast.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use chrono::NaiveTime;
+
+use crate::app::App;
+use crate::config::Notifications;
+use crate::data::{all_beaches, BeachId, WaterStatus};
+use crate::journal::advisory_change;
+
+/// How long the screen stays inverted for a flash
+pub const FLASH_MILLIS: i64 = 300;
+
+/// What an alert is about, each of which can be turned off in the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertKind {
+    /// A water quality advisory issued or lifted
+    AdvisoryChange,
+    /// A best window about to start
+    BestWindow,
+    /// A weather warning, such as a sun session's UV dose nearing the limit
+    WeatherWarning,
+    /// Rain forecast for a pinned plan, with a dry hour to go instead
+    RainDelay,
+}
+
+/// How an alert gets through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// Not at all: its kind is turned off
+    Off,
+    /// Toast only, during quiet hours
+    Quiet,
+    /// Toast plus whatever `--alert` and `--notify` ask for
+    Loud,
+}
+
+/// How an alert of `kind` raised at `time` gets through
+pub fn delivery(notifications: &Notifications, kind: AlertKind, time: NaiveTime) -> Delivery {
+    let enabled = match kind {
+        AlertKind::AdvisoryChange => notifications.advisory_changes,
+        AlertKind::BestWindow => notifications.best_window_reminders,
+        AlertKind::WeatherWarning => notifications.weather_warnings,
+        AlertKind::RainDelay => notifications.rain_delays,
+    };
+    if !enabled {
+        Delivery::Off
+    } else if notifications
+        .quiet_hours
+        .is_some_and(|quiet| quiet.contains(time))
+    {
+        Delivery::Quiet
+    } else {
+        Delivery::Loud
+    }
+}
+
+/// Remembers each beach's water quality status to spot advisories being
+/// issued or lifted between refreshes
+#[derive(Debug, Default)]
+pub struct AdvisoryWatch {
+    statuses: HashMap<BeachId, WaterStatus>,
+}
+
+impl AdvisoryWatch {
+    /// Messages for the beaches whose advisory status changed since the last
+    /// check
+    ///
+    /// A beach's first known status is its baseline rather than a change, and
+    /// a status turning unknown (a stale or missing sample) isn't announced.
+    pub fn changes(&mut self, app: &App) -> Vec<String> {
+        all_beaches()
+            .iter()
+            .filter_map(|beach| {
+                let status = app
+                    .get_conditions(&beach.id)?
+                    .water_quality
+                    .as_ref()?
+                    .effective_status();
+                if status == WaterStatus::Unknown {
+                    return None;
+                }
+                let previous = self.statuses.insert(beach.id.clone(), status)?;
+                change_message(previous, status).map(|change| format!("{}: {}", beach.name, change))
+            })
+            .collect()
+    }
+}
+
+/// How a status change is announced, or `None` if it isn't worth an alert
+fn change_message(previous: WaterStatus, status: WaterStatus) -> Option<&'static str> {
+    advisory_change(previous, status).map(|kind| kind.change())
+}
+
+/// Rings the terminal bell; failures are ignored
+pub fn ring_bell() {
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, Utc};
+
+    use crate::config::QuietHours;
+    use crate::data::{BeachConditions, WaterQuality};
+
+    fn set_status(app: &mut App, beach_index: usize, status: WaterStatus) {
+        let beach = all_beaches()[beach_index].clone();
+        app.beach_conditions.insert(
+            beach.id.clone(),
+            BeachConditions {
+                beach,
+                weather: None,
+                tides: None,
+                water_quality: Some(WaterQuality {
+                    status,
+                    ecoli_count: None,
+                    sample_date: Local::now().date_naive(),
+                    advisory_reason: None,
+                    fetched_at: Utc::now(),
+                    no_samples_station: None,
+                    sampling: None,
+                }),
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+    }
+
+    #[test]
+    fn test_advisory_changes_are_announced_after_the_baseline() {
+        let mut app = App::new();
+        let mut watch = AdvisoryWatch::default();
+        set_status(&mut app, 0, WaterStatus::Safe);
+        set_status(&mut app, 1, WaterStatus::Advisory);
+
+        // First sight of a beach is its baseline
+        assert!(watch.changes(&app).is_empty());
+        assert!(watch.changes(&app).is_empty());
+
+        set_status(&mut app, 0, WaterStatus::Advisory);
+        set_status(&mut app, 1, WaterStatus::Safe);
+        assert_eq!(
+            watch.changes(&app),
+            vec![
+                format!("{}: water quality advisory issued", all_beaches()[0].name),
+                format!("{}: water quality advisory lifted", all_beaches()[1].name),
+            ]
+        );
+        assert!(watch.changes(&app).is_empty());
+    }
+
+    #[test]
+    fn test_delivery_follows_toggles_and_quiet_hours() {
+        let at = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+        let mut notifications = Notifications {
+            quiet_hours: Some(QuietHours {
+                start: at(22),
+                end: at(7),
+            }),
+            ..Notifications::default()
+        };
+        assert_eq!(
+            delivery(&notifications, AlertKind::BestWindow, at(12)),
+            Delivery::Loud
+        );
+        assert_eq!(
+            delivery(&notifications, AlertKind::AdvisoryChange, at(23)),
+            Delivery::Quiet
+        );
+
+        notifications.advisory_changes = false;
+        assert_eq!(
+            delivery(&notifications, AlertKind::AdvisoryChange, at(12)),
+            Delivery::Off
+        );
+        notifications.rain_delays = false;
+        assert_eq!(
+            delivery(&notifications, AlertKind::RainDelay, at(12)),
+            Delivery::Off
+        );
+        assert_eq!(
+            delivery(&notifications, AlertKind::WeatherWarning, at(12)),
+            Delivery::Loud
+        );
+    }
+
+    #[test]
+    fn test_unknown_status_is_not_a_change() {
+        assert_eq!(
+            change_message(WaterStatus::Safe, WaterStatus::Unknown),
+            None
+        );
+        assert_eq!(
+            change_message(WaterStatus::Closed, WaterStatus::Safe),
+            Some("reopened for swimming")
+        );
+        assert_eq!(
+            change_message(WaterStatus::Advisory, WaterStatus::Closed),
+            Some("closed for swimming")
+        );
+    }
+}
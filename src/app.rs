@@ -3,17 +3,89 @@
 //! This module contains the main application state, handling keyboard input,
 //! data loading, and state transitions between different views.
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, Timelike, Utc};
 use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
 use std::collections::HashMap;
-
-use crate::activities::Activity;
-use crate::cache::CacheManager;
-use crate::cli::StartupConfig;
+use std::sync::{Arc, Mutex};
+
+use crate::activities::{get_profile, Activity};
+use crate::alerts::{self, AlertKind, Delivery, FLASH_MILLIS};
+use crate::beach_order::{self, BeachOrder};
+use crate::cache::{CacheManager, CacheStats};
+use crate::cli::{AlertStyle, StartupConfig};
+use crate::comparison_sets::{self, ComparisonSet};
+use crate::config::{self, Notifications, DEFAULT_REFRESH_COOLDOWN_SECS, DEFAULT_UV_DOSE_LIMIT};
+use crate::data::anomaly;
 use crate::data::{
-    all_beaches, get_beach_by_id, Beach, BeachConditions, TidesClient, WaterQuality,
-    WaterQualityClient, WaterQualityError, Weather, WeatherClient, WeatherError,
+    all_beaches, check_tides, check_weather, dog_rule, fetch_weather_by_cell, get_beach_by_id,
+    nearest_observation, wave_facing, AirQuality, AirQualityClient, Anomaly, Beach,
+    BeachConditions, BeachId, ClosuresClient, DebugLog, FireBans, FireBansClient, Fountain,
+    FountainsClient, MarineClient, MetNorwayClient, Metrics, ModelWinds, ObservationsClient,
+    RateLimiter, Reliability, TideInfo, TidesClient, WaterQualityClient, WeatherClient,
+    WeatherFailover, WeatherSource, WindModelsClient, WindObservation,
 };
+use crate::event_loop::DataSource;
+use crate::events::{AppEvent, BeachFetch, DataLoad, Feed, FetchedBeach};
+use crate::favorites;
+use crate::history::{self, DayHistory, HourSnapshot};
+use crate::journal::JournalEntry;
+use crate::keymap::{self, Action, View};
+use crate::plan_scores::{MatrixKey, ScoreMatrix};
+use crate::plans::{self, PinnedPlan};
+use crate::power::{PowerSaving, POWER_SAVING_STRETCH};
+use crate::rain_delay::RainDelay;
+use crate::ui::theme;
+use crate::uv_exposure::{self, SunSession, UvExposure, UvLevel};
+
+/// How often the dashboard refreshes its data while left running
+pub const DASHBOARD_REFRESH_MINUTES: i64 = 15;
+
+/// How long a toast stays up unless a key dismisses it
+pub const TOAST_MINUTES: i64 = 5;
+
+/// Deferred beaches loaded per frame after the first paint
+const DEFERRED_BATCH: usize = 3;
+
+/// How long a quick jump waits for the next digit before going to the
+/// beach typed so far
+const JUMP_TIMEOUT_MILLIS: i64 = 1000;
+
+/// Lines PgUp/PgDn scroll the help overlay by
+const HELP_PAGE_LINES: u16 = 10;
+
+/// Quarantined values kept for the debug view, the latest ones
+const ANOMALY_HISTORY: usize = 50;
+
+/// Short-lived message shown over the current view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toast {
+    /// Text to show
+    pub message: String,
+    /// When the toast disappears on its own
+    pub expires_at: DateTime<Local>,
+}
+
+/// A quick jump to a beach by its number in the list, typed as `g` and then
+/// the number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickJump {
+    /// Digits typed so far
+    pub digits: String,
+    /// When the jump goes ahead with the digits typed so far
+    pub expires_at: DateTime<Local>,
+}
+
+/// Search and scroll position of the help overlay
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HelpView {
+    /// Text the key reference is filtered by
+    pub query: String,
+    /// Whether keys are being typed into the search
+    pub searching: bool,
+    /// Lines scrolled past at the top
+    pub scroll: u16,
+}
 
 /// Application state enum representing the current view
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -23,9 +95,15 @@ pub enum AppState {
     /// List view showing all beaches
     BeachList,
     /// Detail view for a specific beach
-    BeachDetail(String),
+    BeachDetail(BeachId),
     /// Plan trip view showing beach/hour grid for activity optimization
     PlanTrip,
+    /// Auto-refreshing grid of condition cards for favorite beaches
+    Dashboard,
+    /// Every water quality monitoring station's latest result
+    WaterQualityOverview,
+    /// Notable condition changes logged so far, newest first
+    Journal,
 }
 
 /// Main application struct managing state and data
@@ -35,59 +113,313 @@ pub struct App {
     /// Index of currently selected beach in list view
     pub selected_index: usize,
     /// Cached beach conditions data keyed by beach ID
-    pub beach_conditions: HashMap<String, BeachConditions>,
+    pub beach_conditions: HashMap<BeachId, BeachConditions>,
+    /// Latest wind reported by the stations around the beaches
+    pub wind_observations: Vec<WindObservation>,
+    /// Public drinking fountains, for heat warnings
+    pub fountains: Vec<Fountain>,
+    /// Campfire bans along the shoreline, for the beach fire advisory;
+    /// `None` until they've been fetched
+    pub fire_bans: Option<FireBans>,
+    /// Each weather model's wind for the shoreline (`--wind-models`)
+    pub wind_models: Option<ModelWinds>,
     /// Flag indicating the application should quit
     pub should_quit: bool,
     /// Currently selected activity for scoring/filtering
     pub current_activity: Option<Activity>,
+    /// Second activity combined with the current one (e.g. Swimming + Sunset)
+    pub secondary_activity: Option<Activity>,
+    /// Whether activity keys add a second activity instead of replacing the current one
+    pub multi_select: bool,
     /// Cursor position in PlanTrip grid (beach_index, hour_index)
     pub plan_cursor: (usize, usize),
-    /// Visible hour range in PlanTrip screen (start_hour, end_hour), default 6am-9pm
+    /// Visible hour range in PlanTrip screen (start_hour, end_hour), the
+    /// configured hour range (6am-9pm by default)
     pub plan_time_range: (u8, u8),
+    /// First hour column shown in the PlanTrip grid, when the terminal is
+    /// too narrow for every hour
+    pub plan_column_offset: usize,
+    /// How many hour columns the PlanTrip grid last had room for (0 before
+    /// it's drawn), for panning a screen at a time
+    pub plan_visible_hours: usize,
+    /// Whether the PlanTrip grid shows a row of predicted crowds under each
+    /// beach
+    pub plan_show_crowds: bool,
     /// Flag to transition to PlanTrip after data loads (from --plan CLI flag)
     pub pending_plan_trip: bool,
     /// Timestamp of last data refresh
     pub last_refresh: Option<DateTime<Local>>,
     /// Flag indicating a refresh has been requested
     pub refresh_requested: bool,
+    /// One feed to refetch for one beach (`W`, `T`, `Q` in the detail view)
+    pub feed_refresh: Option<(&'static Beach, Feed)>,
+    /// How long `r` is ignored after a refresh, so repeated presses don't
+    /// stack up refreshes
+    pub refresh_cooldown: Duration,
+    /// When the dashboard's auto-refresh slows down to save power (config)
+    pub power_saving: PowerSaving,
+    /// Whether the machine is running on battery, as last probed
+    pub on_battery: bool,
+    /// Whether the terminal has focus; terminals that don't report focus
+    /// changes stay focused
+    pub focused: bool,
     /// Flag to show help overlay
     pub show_help: bool,
+    /// Flag to show the directions overlay over a beach's details
+    pub show_directions: bool,
+    /// Search and scroll position of the help overlay
+    pub help: HelpView,
     /// Scroll offset for beach detail view
     pub detail_scroll_offset: u16,
     /// Whether tide chart is expanded in detail view
     pub tide_chart_expanded: bool,
-    /// Weather API client
-    weather_client: WeatherClient,
+    /// Beach/hour cells pinned as plans from the PlanTrip grid
+    pub pinned_plans: Vec<PinnedPlan>,
+    /// Pinned plans rain is forecast for, with a dry hour to go instead
+    pub rain_delays: Vec<RainDelay>,
+    /// Today's sun sessions; at most one is running
+    pub sun_sessions: Vec<SunSession>,
+    /// Daily UV dose in SED that sun sessions warn about (config)
+    pub uv_dose_limit: f64,
+    /// Level of the day's UV dose when last checked, so each level warns once
+    uv_level: UvLevel,
+    /// Beaches marked as favorites, shown on the dashboard
+    pub favorites: Vec<BeachId>,
+    /// Custom order for the beach list, and whether it's in use
+    pub beach_order: BeachOrder,
+    /// Named groups of beaches the dashboard can show instead of the
+    /// favorites: those from the config first, then those saved
+    pub comparison_sets: Vec<ComparisonSet>,
+    /// Index of the comparison set the dashboard shows; None for favorites
+    pub active_set: Option<usize>,
+    /// Name being typed for a new comparison set on the dashboard
+    pub set_name: Option<String>,
+    /// Conditions recorded a week ago today, keyed by beach ID
+    last_week: HashMap<BeachId, DayHistory>,
+    /// Days recorded earlier this week, keyed by beach ID
+    week_so_far: HashMap<BeachId, Vec<DayHistory>>,
+    /// Flag to transition to Dashboard after data loads (from --watch CLI flag)
+    pub pending_dashboard: bool,
+    /// Beach whose detail view opens after data loads (from --beach CLI flag)
+    pub pending_beach: Option<BeachId>,
+    /// Beaches left to load after the first paint, most important first
+    pub deferred_load: Vec<&'static Beach>,
+    /// Beaches loaded and the total while some are still loading
+    pub load_progress: Option<(usize, usize)>,
+    /// Where each beach still to arrive in the current load is; a loaded
+    /// beach has no entry
+    pub load_states: HashMap<BeachId, LoadState>,
+    /// Minute of the last time-derived recompute (see `tick`)
+    last_tick_minute: Option<NaiveDateTime>,
+    /// Bumped whenever beach data changes, so derived caches know to recompute
+    data_version: u64,
+    /// PlanTrip score matrix, kept until its key no longer matches
+    plan_scores: Mutex<Option<Arc<ScoreMatrix>>>,
+    /// Whether beaches closed for swimming are hidden from the list
+    pub hide_closed: bool,
+    /// Whether beaches ruled out for the selected activity are hidden from the list
+    pub hide_unsuitable: bool,
+    /// Whether the water quality overview puts the worst water first instead
+    /// of following the beach list
+    pub water_quality_by_status: bool,
+    /// Journal entries, oldest first, as loaded at startup plus any logged since
+    pub journal: Vec<JournalEntry>,
+    /// Entries scrolled past at the top of the journal screen
+    pub journal_scroll: u16,
+    /// Recent raw API responses, kept only with `--verbose`
+    pub debug_log: Option<DebugLog>,
+    /// Flag to show the raw API response viewer
+    pub show_debug_view: bool,
+    /// Scroll offset for the raw API response viewer
+    pub debug_scroll_offset: u16,
+    /// Implausible values whose responses were quarantined, oldest first
+    pub anomalies: Vec<Anomaly>,
+    /// Whether tides are described in words instead of charts (`--plain`)
+    pub plain: bool,
+    /// Whether best window reminders also go to the desktop (`--notify`)
+    pub notify: bool,
+    /// Whether borders and headers take an accent from the focused beach's
+    /// conditions (`--ambient`)
+    pub ambient: bool,
+    /// Whether the screen dims after sunset at the focused beach (config)
+    pub night_dim: bool,
+    /// Activity selected after sunset in place of a daytime one (config)
+    pub night_activity: Option<Activity>,
+    /// Whether it was night at the focused beach as of the last tick
+    pub night: bool,
+    /// Activity replaced at sunset, put back at sunrise unless another has
+    /// been picked since
+    night_replaced: Option<Option<Activity>>,
+    /// Whether clients request less data and keep cached data longer
+    /// (`--low-bandwidth`)
+    pub low_bandwidth: bool,
+    /// How alerts get attention beyond their toast (`--alert`)
+    pub alert: Option<AlertStyle>,
+    /// Which kinds of alert are raised and when they stay quiet (config)
+    pub notifications: Notifications,
+    /// When the screen flash for an alert ends
+    pub flash_until: Option<DateTime<Local>>,
+    /// Message shown over the current view, such as a best window reminder
+    pub toast: Option<Toast>,
+    /// Quick jump being typed on the beach list
+    pub jump: Option<QuickJump>,
+    /// Request, timing and cache counters, kept only in serve mode
+    pub metrics: Option<Metrics>,
+    /// Webcam snapshot URLs keyed by beach ID, from the config
+    pub webcams: HashMap<BeachId, String>,
+    /// Whether webcam snapshots are drawn in place (`graphics` feature on a
+    /// terminal that supports it) rather than shown as a URL
+    pub webcam_graphics: bool,
+    /// Where the detail view left room for the webcam snapshot this frame
+    pub webcam_area: Option<Rect>,
+    /// Where pinned plans and favorites are persisted (None disables saving)
+    user_store: Option<CacheManager>,
+    /// Weather API client (Open-Meteo with MET Norway fallback)
+    weather_client: WeatherFailover,
     /// Tides API client
     tides_client: TidesClient,
     /// Water quality API client
     water_quality_client: WaterQualityClient,
+    /// Park Board closures API client
+    closures_client: ClosuresClient,
+    /// Marine API client, for waves at beaches open to swell
+    marine_client: MarineClient,
+    /// Airport and buoy wind observations client
+    observations_client: ObservationsClient,
+    /// Drinking fountains client
+    fountains_client: FountainsClient,
+    /// BC Wildfire Service fire bans client
+    fire_bans_client: FireBansClient,
+    /// Multi-model wind client, only with `--wind-models`
+    wind_models_client: Option<WindModelsClient>,
+    /// Air quality client, for running and walking
+    air_quality_client: AirQualityClient,
+    /// Per-host request budgets shared by every client
+    rate_limiter: RateLimiter,
 }
 
 impl App {
     /// Creates a new App instance with default state
     pub fn new() -> Self {
         let cache = CacheManager::new();
-        Self {
+        if let Some(cache) = &cache {
+            crate::data::migrate_cache(cache);
+        }
+        let mut app = Self {
             state: AppState::Loading,
             selected_index: 0,
             beach_conditions: HashMap::new(),
+            wind_observations: Vec::new(),
+            fountains: Vec::new(),
+            fire_bans: None,
+            wind_models: None,
             should_quit: false,
             current_activity: None,
+            secondary_activity: None,
+            multi_select: false,
             plan_cursor: (0, 0),
-            plan_time_range: (6, 21),
+            plan_time_range: config::hour_range(),
+            plan_column_offset: 0,
+            plan_visible_hours: 0,
+            plan_show_crowds: false,
             pending_plan_trip: false,
             last_refresh: None,
             refresh_requested: false,
+            feed_refresh: None,
+            refresh_cooldown: Duration::seconds(DEFAULT_REFRESH_COOLDOWN_SECS as i64),
+            power_saving: PowerSaving::default(),
+            on_battery: false,
+            focused: true,
             show_help: false,
+            show_directions: false,
+            help: HelpView::default(),
             detail_scroll_offset: 0,
             tide_chart_expanded: false,
-            weather_client: WeatherClient::new(),
+            pinned_plans: cache
+                .as_ref()
+                .map(|c| plans::load_plans(c, Local::now().date_naive()))
+                .unwrap_or_default(),
+            rain_delays: Vec::new(),
+            sun_sessions: cache
+                .as_ref()
+                .map(|c| uv_exposure::load_sessions(c, Local::now().date_naive()))
+                .unwrap_or_default(),
+            uv_dose_limit: DEFAULT_UV_DOSE_LIMIT,
+            uv_level: UvLevel::default(),
+            favorites: cache
+                .as_ref()
+                .map(favorites::load_favorites)
+                .unwrap_or_default(),
+            beach_order: cache
+                .as_ref()
+                .map(beach_order::load_beach_order)
+                .unwrap_or_default(),
+            comparison_sets: cache
+                .as_ref()
+                .map(comparison_sets::load_comparison_sets)
+                .unwrap_or_default(),
+            active_set: None,
+            set_name: None,
+            last_week: HashMap::new(),
+            week_so_far: HashMap::new(),
+            pending_dashboard: false,
+            pending_beach: None,
+            deferred_load: Vec::new(),
+            load_states: HashMap::new(),
+            load_progress: None,
+            hide_closed: false,
+            hide_unsuitable: false,
+            water_quality_by_status: false,
+            journal: Vec::new(),
+            journal_scroll: 0,
+            debug_log: None,
+            show_debug_view: false,
+            debug_scroll_offset: 0,
+            anomalies: Vec::new(),
+            plain: false,
+            notify: false,
+            ambient: false,
+            night_dim: false,
+            night_activity: None,
+            night: false,
+            night_replaced: None,
+            low_bandwidth: false,
+            alert: None,
+            notifications: Notifications::default(),
+            flash_until: None,
+            toast: None,
+            jump: None,
+            metrics: None,
+            last_tick_minute: None,
+            data_version: 0,
+            plan_scores: Mutex::new(None),
+            webcams: HashMap::new(),
+            webcam_graphics: false,
+            webcam_area: None,
+            user_store: cache.clone(),
+            weather_client: WeatherFailover::default(),
             tides_client: TidesClient::new(cache.clone()),
             water_quality_client: cache
+                .clone()
                 .map(WaterQualityClient::with_cache)
                 .unwrap_or_default(),
-        }
+            closures_client: cache
+                .clone()
+                .map(ClosuresClient::with_cache)
+                .unwrap_or_default(),
+            marine_client: MarineClient::new(),
+            observations_client: ObservationsClient::new(),
+            fountains_client: cache
+                .clone()
+                .map(FountainsClient::with_cache)
+                .unwrap_or_default(),
+            fire_bans_client: cache.map(FireBansClient::with_cache).unwrap_or_default(),
+            wind_models_client: None,
+            air_quality_client: AirQualityClient::new(),
+            rate_limiter: RateLimiter::new(),
+        };
+        app.instrument_clients();
+        app
     }
 
     /// Creates a new App instance with the given startup configuration.
@@ -104,13 +436,125 @@ impl App {
             // Set a flag to transition to PlanTrip after data loads
             app.pending_plan_trip = true;
         }
+        if config.start_in_dashboard {
+            // Set a flag to transition to Dashboard after data loads
+            app.pending_dashboard = true;
+        }
+        if let Some(beach_id) = config.initial_beach {
+            // Set the beach whose detail view opens after data loads
+            app.pending_beach = Some(beach_id);
+        }
         if let Some(activity) = config.initial_activity {
             app.current_activity = Some(activity);
         }
+        if config.verbose {
+            app.enable_debug_log();
+        }
+        if config.low_bandwidth {
+            app.enable_low_bandwidth();
+        }
+        if config.wind_models {
+            app.enable_wind_models();
+        }
+        app.plain = config.plain;
+        app.notify = config.notify;
+        app.ambient = config.ambient;
+        app.alert = config.alert;
 
         app
     }
 
+    /// Starts recording raw API responses from every client, enabling the
+    /// debug view
+    pub fn enable_debug_log(&mut self) {
+        self.debug_log = Some(DebugLog::default());
+        self.instrument_clients();
+    }
+
+    /// Has the clients request fewer weather fields and days, skip wave
+    /// forecasts and keep cached data longer
+    pub fn enable_low_bandwidth(&mut self) {
+        self.low_bandwidth = true;
+        self.instrument_clients();
+    }
+
+    /// Also fetches the wind from several weather models, to rate how far
+    /// the wind forecast can be trusted
+    pub fn enable_wind_models(&mut self) {
+        self.wind_models_client = Some(WindModelsClient::new());
+        self.instrument_clients();
+    }
+
+    /// Starts counting requests, fetch times and cache lookups from every
+    /// client, for the serve mode `/metrics` endpoint
+    pub fn enable_metrics(&mut self) {
+        self.metrics = Some(Metrics::default());
+        self.instrument_clients();
+    }
+
+    /// Rebuilds the clients to share the rate limiter and report to the debug
+    /// log and metrics, where enabled
+    fn instrument_clients(&mut self) {
+        let mut open_meteo = WeatherClient::new()
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_low_bandwidth(self.low_bandwidth);
+        let mut met_norway = MetNorwayClient::new().with_rate_limiter(self.rate_limiter.clone());
+        let mut water_quality = self
+            .water_quality_client
+            .clone()
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_low_bandwidth(self.low_bandwidth);
+        let mut closures = self
+            .closures_client
+            .clone()
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_low_bandwidth(self.low_bandwidth);
+        let mut tides = self.tides_client.clone();
+        if let Some(debug_log) = &self.debug_log {
+            open_meteo = open_meteo.with_debug_log(debug_log.clone());
+            met_norway = met_norway.with_debug_log(debug_log.clone());
+            water_quality = water_quality.with_debug_log(debug_log.clone());
+            closures = closures.with_debug_log(debug_log.clone());
+        }
+        if let Some(metrics) = &self.metrics {
+            open_meteo = open_meteo.with_metrics(metrics.clone());
+            met_norway = met_norway.with_metrics(metrics.clone());
+            water_quality = water_quality.with_metrics(metrics.clone());
+            closures = closures.with_metrics(metrics.clone());
+            tides = tides.with_metrics(metrics.clone());
+        }
+        self.weather_client = WeatherFailover::new(open_meteo, met_norway);
+        self.water_quality_client = water_quality;
+        self.closures_client = closures;
+        self.tides_client = tides;
+        self.fountains_client = self
+            .fountains_client
+            .clone()
+            .with_rate_limiter(self.rate_limiter.clone());
+        self.fire_bans_client = self
+            .fire_bans_client
+            .clone()
+            .with_rate_limiter(self.rate_limiter.clone());
+        self.wind_models_client = self
+            .wind_models_client
+            .take()
+            .map(|client| client.with_rate_limiter(self.rate_limiter.clone()));
+        self.air_quality_client = self
+            .air_quality_client
+            .clone()
+            .with_rate_limiter(self.rate_limiter.clone());
+    }
+
+    /// The beach the user is looking at: the open beach in the detail view,
+    /// the cursor row in PlanTrip, otherwise the list selection
+    pub fn focused_beach(&self) -> Option<&'static Beach> {
+        match &self.state {
+            AppState::BeachDetail(beach_id) => get_beach_by_id(beach_id),
+            AppState::PlanTrip => all_beaches().get(self.plan_cursor.0),
+            _ => self.selected_beach(),
+        }
+    }
+
     /// Creates a new App instance with custom clients (for testing)
     #[cfg(test)]
     pub fn with_clients(
@@ -122,19 +566,90 @@ impl App {
             state: AppState::Loading,
             selected_index: 0,
             beach_conditions: HashMap::new(),
+            wind_observations: Vec::new(),
+            fountains: Vec::new(),
+            fire_bans: None,
+            wind_models: None,
             should_quit: false,
             current_activity: None,
+            secondary_activity: None,
+            multi_select: false,
             plan_cursor: (0, 0),
-            plan_time_range: (6, 21),
+            plan_time_range: config::hour_range(),
+            plan_column_offset: 0,
+            plan_visible_hours: 0,
+            plan_show_crowds: false,
             pending_plan_trip: false,
             last_refresh: None,
             refresh_requested: false,
+            feed_refresh: None,
+            refresh_cooldown: Duration::seconds(DEFAULT_REFRESH_COOLDOWN_SECS as i64),
+            power_saving: PowerSaving::default(),
+            on_battery: false,
+            focused: true,
             show_help: false,
+            show_directions: false,
+            help: HelpView::default(),
             detail_scroll_offset: 0,
             tide_chart_expanded: false,
-            weather_client,
+            pinned_plans: Vec::new(),
+            rain_delays: Vec::new(),
+            sun_sessions: Vec::new(),
+            uv_dose_limit: DEFAULT_UV_DOSE_LIMIT,
+            uv_level: UvLevel::default(),
+            favorites: Vec::new(),
+            beach_order: BeachOrder::default(),
+            comparison_sets: Vec::new(),
+            active_set: None,
+            set_name: None,
+            last_week: HashMap::new(),
+            week_so_far: HashMap::new(),
+            pending_dashboard: false,
+            pending_beach: None,
+            deferred_load: Vec::new(),
+            load_states: HashMap::new(),
+            load_progress: None,
+            hide_closed: false,
+            hide_unsuitable: false,
+            water_quality_by_status: false,
+            journal: Vec::new(),
+            journal_scroll: 0,
+            debug_log: None,
+            show_debug_view: false,
+            debug_scroll_offset: 0,
+            anomalies: Vec::new(),
+            plain: false,
+            notify: false,
+            ambient: false,
+            night_dim: false,
+            night_activity: None,
+            night: false,
+            night_replaced: None,
+            low_bandwidth: false,
+            alert: None,
+            notifications: Notifications::default(),
+            flash_until: None,
+            toast: None,
+            jump: None,
+            metrics: None,
+            last_tick_minute: None,
+            data_version: 0,
+            plan_scores: Mutex::new(None),
+            webcams: HashMap::new(),
+            webcam_graphics: false,
+            webcam_area: None,
+            user_store: None,
+            weather_client: WeatherFailover::new(weather_client, MetNorwayClient::new()),
             tides_client,
             water_quality_client,
+            closures_client: ClosuresClient::default(),
+            marine_client: MarineClient::new(),
+            observations_client: ObservationsClient::new(),
+            fountains_client: FountainsClient::new(),
+            fire_bans_client: FireBansClient::new(),
+            wind_models_client: None,
+            air_quality_client: AirQualityClient::new(),
+            rate_limiter: RateLimiter::new(),
         }
     }
 
@@ -145,80 +660,701 @@ impl App {
 
     /// Returns the currently selected beach, if any
     pub fn selected_beach(&self) -> Option<&'static Beach> {
-        all_beaches().get(self.selected_index)
+        self.visible_beaches().get(self.selected_index).copied()
+    }
+
+    /// Beaches shown in the list, in registry order or the custom order when
+    /// it's on, leaving out closed beaches while `hide_closed` is on and
+    /// beaches ruled out for the selected activity while `hide_unsuitable`
+    /// is on
+    pub fn visible_beaches(&self) -> Vec<&'static Beach> {
+        let beaches = if self.beach_order.custom {
+            self.beach_order.arrange()
+        } else {
+            all_beaches().iter().collect()
+        };
+        beaches
+            .into_iter()
+            .filter(|beach| !self.hide_closed || !self.is_closed(&beach.id))
+            .filter(|beach| !self.hide_unsuitable || self.unsuitable_reason(&beach.id).is_none())
+            .collect()
+    }
+
+    /// Number of beaches the activity filter currently hides
+    pub fn unsuitable_hidden_count(&self) -> usize {
+        if !self.hide_unsuitable {
+            return 0;
+        }
+        all_beaches()
+            .iter()
+            .filter(|beach| self.unsuitable_reason(&beach.id).is_some())
+            .count()
+    }
+
+    /// Why the selected activity is ruled out at a beach right now, if it is
+    ///
+    /// Checks the activity's hard constraints against current weather and
+    /// water quality; a closed beach is ruled out for every activity. Beaches
+    /// without data are never ruled out, and nothing is ruled out without a
+    /// selected activity.
+    pub fn unsuitable_reason(&self, beach_id: &str) -> Option<String> {
+        let activity = self.current_activity?;
+        let conditions = self.get_conditions(beach_id)?;
+        if let Some(closure) = &conditions.closure {
+            return Some(format!("Beach closed: {}", closure.reason));
+        }
+        if !activity.offered_at(beach_id) {
+            return Some("No surf here: sheltered from open swell".to_string());
+        }
+        if let Some(closure) = conditions
+            .seawall_closure
+            .as_ref()
+            .filter(|_| activity == Activity::RunWalk)
+        {
+            return Some(format!("Seawall closed: {}", closure.reason));
+        }
+        if activity == Activity::DogWalk {
+            let rule = dog_rule(beach_id, conditions.beach.timezone.now().date());
+            if !rule.access.allows_dogs() {
+                return Some(rule.note.to_string());
+            }
+        }
+        let water_status = conditions
+            .water_quality
+            .as_ref()
+            .map_or(crate::data::WaterStatus::Unknown, |wq| {
+                wq.effective_status()
+            });
+        let profile = get_profile(activity);
+
+        match &conditions.weather {
+            Some(weather) => profile.check_hard_constraints(
+                weather.temperature as f32,
+                weather.wind as f32,
+                weather.condition,
+                water_status,
+            ),
+            // Without weather only the water can rule a beach out
+            None => profile.check_hard_constraints(
+                f32::MAX,
+                0.0,
+                crate::data::WeatherCondition::Clear,
+                water_status,
+            ),
+        }
+    }
+
+    /// Returns true if the beach is closed by the Park Board or its
+    /// effective water status is Closed
+    fn is_closed(&self, beach_id: &str) -> bool {
+        self.get_conditions(beach_id).is_some_and(|c| {
+            c.closure.is_some()
+                || c.water_quality
+                    .as_ref()
+                    .is_some_and(|wq| wq.effective_status() == crate::data::WaterStatus::Closed)
+        })
+    }
+
+    /// Shows or hides closed beaches in the list, keeping the selected beach
+    /// selected when it stays visible
+    pub fn toggle_hide_closed(&mut self) {
+        let selected = self.selected_beach().map(|beach| beach.id.clone());
+        self.hide_closed = !self.hide_closed;
+        self.reselect(selected.as_ref());
+    }
+
+    /// Shows or hides beaches ruled out for the selected activity, keeping
+    /// the selected beach selected when it stays visible
+    pub fn toggle_hide_unsuitable(&mut self) {
+        let selected = self.selected_beach().map(|beach| beach.id.clone());
+        self.hide_unsuitable = !self.hide_unsuitable;
+        self.reselect(selected.as_ref());
+    }
+
+    /// Swaps the selected beach with the visible one below (or above) it,
+    /// switching to the custom order first, and saves the order
+    ///
+    /// Hidden beaches are stepped over, so each press moves the beach one
+    /// place on screen.
+    pub fn move_selected_beach(&mut self, down: bool) {
+        let Some(beach) = self.selected_beach() else {
+            return;
+        };
+        if !self.beach_order.custom {
+            self.beach_order.custom = true;
+            self.reselect(Some(&beach.id));
+        }
+        let visible = self.visible_beaches();
+        let neighbor = if down {
+            visible.get(self.selected_index + 1)
+        } else {
+            self.selected_index
+                .checked_sub(1)
+                .and_then(|index| visible.get(index))
+        };
+        if let Some(neighbor) = neighbor {
+            self.beach_order.swap(&beach.id, &neighbor.id);
+            self.reselect(Some(&beach.id));
+        }
+        self.save_beach_order();
+    }
+
+    /// Switches the list between the custom order and the registry's,
+    /// keeping the selected beach selected
+    pub fn toggle_custom_order(&mut self) {
+        let selected = self.selected_beach().map(|beach| beach.id.clone());
+        self.beach_order.custom = !self.beach_order.custom;
+        self.reselect(selected.as_ref());
+        self.save_beach_order();
+    }
+
+    fn save_beach_order(&self) {
+        if let Some(store) = &self.user_store {
+            // Saving is best-effort; the order still applies for this session
+            let _ = beach_order::save_beach_order(store, &self.beach_order);
+        }
+    }
+
+    /// Points `selected_index` at the given beach in the visible list, or
+    /// clamps it if that beach is hidden
+    fn reselect(&mut self, beach_id: Option<&BeachId>) {
+        let visible = self.visible_beaches();
+        self.selected_index = beach_id
+            .and_then(|id| visible.iter().position(|beach| beach.id == *id))
+            .unwrap_or_else(|| self.selected_index.min(visible.len().saturating_sub(1)));
     }
 
     /// Loads all beach data concurrently
     ///
-    /// Fetches weather for all beaches, tides (shared), and water quality for each beach.
-    /// Transitions to BeachList state when complete.
-    pub async fn load_all_data(&mut self) {
-        let beaches = all_beaches();
+    /// Fetches weather for all beaches, tides (shared), and water quality for
+    /// each beach from `data` (`LiveData` outside tests). Transitions to
+    /// BeachList state when complete.
+    pub async fn load_all_data(&mut self, data: &mut impl DataSource) {
+        let beaches: Vec<&'static Beach> = all_beaches().iter().collect();
+        let fetch = data.fetch(self, &beaches).await;
+        self.update(AppEvent::DataLoaded(Box::new(DataLoad::Full(fetch))));
+    }
+
+    /// Loads just the given beaches, fetched concurrently with the clients'
+    /// shared rate limiter, for exports that don't need every beach
+    pub async fn load_beaches(&mut self, data: &mut impl DataSource, beaches: &[&'static Beach]) {
+        let fetch = data.fetch(self, beaches).await;
+        self.apply_fetch(fetch);
+        self.data_version += 1;
+    }
+
+    /// Loads the beaches the first screen shows and leaves the loading state,
+    /// queueing the rest in `deferred_load`
+    ///
+    /// With a cold cache, fetching every beach before the first paint keeps
+    /// the user waiting on beaches they can't see yet. `visible_rows` is how
+    /// many beaches fit in the list; see `first_paint_order` for what comes
+    /// first.
+    pub async fn load_first_paint(&mut self, data: &mut impl DataSource, visible_rows: usize) {
+        let (first, deferred) = self.first_paint_order(visible_rows);
+        let fetch = data.fetch(self, &first).await;
+        self.update(AppEvent::DataLoaded(Box::new(DataLoad::FirstPaint {
+            fetch,
+            deferred,
+        })));
+        self.update(self.load_progress());
+    }
+
+    /// Loads the next `DEFERRED_BATCH` beaches of the deferred queue
+    ///
+    /// Called between frames, so keys are still handled while the off-screen
+    /// beaches arrive. History is recorded once the queue is empty.
+    pub async fn load_deferred(&mut self, data: &mut impl DataSource) {
+        let batch = self.next_deferred_batch();
+        if batch.is_empty() {
+            return;
+        }
+        self.update(AppEvent::FetchStarted(batch.clone()));
+        let fetch = data.fetch(self, &batch).await;
+        self.update(AppEvent::DataLoaded(Box::new(DataLoad::Deferred(fetch))));
+        self.update(self.load_progress());
+    }
+
+    /// The beaches `load_deferred` fetches next
+    pub fn next_deferred_batch(&self) -> Vec<&'static Beach> {
+        self.deferred_load
+            .iter()
+            .take(DEFERRED_BATCH)
+            .copied()
+            .collect()
+    }
+
+    /// Queues every beach to load again a batch per frame, keeping the
+    /// current screen and data until each arrives; the beaches on screen go
+    /// first
+    fn queue_background_refresh(&mut self) {
+        let visible = self.visible_beaches();
+        let queue: Vec<&'static Beach> = visible
+            .iter()
+            .copied()
+            .chain(
+                all_beaches()
+                    .iter()
+                    .filter(|beach| !visible.iter().any(|shown| shown.id == beach.id)),
+            )
+            .collect();
+        self.load_states = queue
+            .iter()
+            .map(|beach| (beach.id.clone(), LoadState::Queued))
+            .collect();
+        self.load_progress = Some((0, queue.len()));
+        self.deferred_load = queue;
+    }
+
+    /// Progress of the load so far, as an event
+    fn load_progress(&self) -> AppEvent {
+        let total = all_beaches().len();
+        AppEvent::RefreshProgress {
+            loaded: total - self.deferred_load.len(),
+            total,
+        }
+    }
+
+    /// Stores a finished fetch, and leaves the loading state or works
+    /// through the deferred queue as the load calls for
+    fn apply_load(&mut self, load: DataLoad) {
+        match load {
+            DataLoad::Full(fetch) => {
+                self.deferred_load.clear();
+                self.load_states.clear();
+                self.apply_fetch(fetch);
+                self.update_history(Local::now());
+                self.finish_loading();
+            }
+            DataLoad::FirstPaint { fetch, deferred } => {
+                self.apply_fetch(fetch);
+                self.load_states = deferred
+                    .iter()
+                    .map(|beach| (beach.id.clone(), LoadState::Queued))
+                    .collect();
+                self.deferred_load = deferred;
+                if self.deferred_load.is_empty() {
+                    self.update_history(Local::now());
+                }
+                self.finish_loading();
+            }
+            DataLoad::Deferred(fetch) => {
+                self.deferred_load
+                    .retain(|queued| !fetch.beaches.iter().any(|f| f.beach.id == queued.id));
+                self.apply_fetch(fetch);
+                self.data_version += 1;
+                if self.deferred_load.is_empty() {
+                    self.update_history(Local::now());
+                    self.last_refresh = Some(Local::now());
+                }
+            }
+            DataLoad::Feed {
+                feed,
+                fetch,
+                requested_at,
+            } => {
+                let mut message = feed_refresh_message(feed, &fetch, requested_at);
+                let beach = fetch.beaches.first().map(|f| f.beach);
+                if !self.apply_fetch(fetch) {
+                    message = format!(
+                        "Ignored implausible {} for {}; showing what was loaded before",
+                        feed.label(),
+                        beach.map_or("this beach", |b| b.name.as_str())
+                    );
+                }
+                self.data_version += 1;
+                self.show_toast(message, Local::now());
+            }
+        }
+    }
+
+    /// Applies an event to the app's state
+    ///
+    /// All state changes in the event loop come through here; see the
+    /// `events` module.
+    pub fn update(&mut self, event: AppEvent) {
+        match event {
+            AppEvent::Key(key_event) => self.handle_key(key_event),
+            AppEvent::DataLoaded(load) => self.apply_load(*load),
+            AppEvent::Tick(now) => {
+                self.tick(now);
+                self.expire_jump(now);
+                self.check_uv_dose(now);
+                self.update_night(now);
+                // The dashboard refreshes itself once its data goes stale
+                if self.needs_auto_refresh(now) {
+                    self.refresh_requested = true;
+                }
+            }
+            AppEvent::RefreshInBackground => self.queue_background_refresh(),
+            AppEvent::FetchStarted(beaches) => {
+                for beach in beaches {
+                    self.load_states
+                        .insert(beach.id.clone(), LoadState::InFlight);
+                }
+            }
+            AppEvent::RefreshProgress { loaded, total } => {
+                self.load_progress = (loaded < total).then_some((loaded, total));
+            }
+            AppEvent::Alert {
+                message,
+                now,
+                quiet,
+            } => {
+                if !quiet && self.alert == Some(AlertStyle::Flash) {
+                    self.flash(now);
+                }
+                self.show_toast(message, now);
+            }
+            AppEvent::Focus(focused) => self.focused = focused,
+            AppEvent::OnBattery(on_battery) => self.on_battery = on_battery,
+        }
+    }
+
+    /// Beaches to load before the first paint, and the rest in list order
+    ///
+    /// The first paint covers the beach opened with `--beach`, the dashboard's
+    /// beaches with `--watch`, the selected beach and the top `visible_rows`
+    /// beaches of the list.
+    fn first_paint_order(&self, visible_rows: usize) -> (Vec<&'static Beach>, Vec<&'static Beach>) {
+        let mut first: Vec<&'static Beach> = Vec::new();
+        let pending = self
+            .pending_beach
+            .as_ref()
+            .and_then(|id| get_beach_by_id(id));
+        let dashboard = if self.pending_dashboard {
+            self.dashboard_beaches()
+        } else {
+            Vec::new()
+        };
+        let visible = self.visible_beaches();
+        for beach in pending
+            .into_iter()
+            .chain(dashboard)
+            .chain(self.selected_beach())
+            .chain(visible.into_iter().take(visible_rows))
+        {
+            if !first.iter().any(|b| b.id == beach.id) {
+                first.push(beach);
+            }
+        }
+
+        let deferred = all_beaches()
+            .iter()
+            .filter(|beach| !first.iter().any(|b| b.id == beach.id))
+            .collect();
+        (first, deferred)
+    }
 
-        // Fetch tides once (same for all beaches)
-        let tides_result = self.tides_client.fetch_tides().await.ok();
+    /// Fetches conditions for the given beaches, leaving the app as it is
+    pub async fn fetch_beaches(&self, beaches: &[&'static Beach]) -> BeachFetch {
+        // Fetch tides, closures, wind observations, drinking fountains, fire
+        // bans and air quality once (each covers every beach); low-bandwidth
+        // mode goes without observations and air quality
+        let tides = self.tides_client.fetch_tides().await.ok();
+        let closures = self
+            .closures_client
+            .fetch_closures(all_beaches())
+            .await
+            .ok();
+        let seawall_closures = self
+            .closures_client
+            .fetch_seawall_closures(all_beaches())
+            .await
+            .ok();
+        let (observations, air_quality) = if self.low_bandwidth {
+            (None, None)
+        } else {
+            (
+                self.observations_client.fetch_observations().await.ok(),
+                fetch_shoreline_air_quality(&self.air_quality_client).await,
+            )
+        };
+        let fountains = self.fountains_client.fetch_fountains().await.ok();
+        let fire_bans = fetch_shoreline_fire_bans(&self.fire_bans_client).await;
+        let wind_models = match &self.wind_models_client {
+            Some(client) => fetch_shoreline_wind_models(client).await,
+            None => None,
+        };
 
-        // Fetch weather and water quality for all beaches concurrently
-        let mut weather_futures = Vec::new();
+        // Fetch weather, water quality and waves for all beaches concurrently
         let mut water_quality_futures = Vec::new();
+        let mut wave_futures = Vec::new();
 
         for beach in beaches {
-            weather_futures.push(
-                self.weather_client
-                    .fetch_weather(beach.latitude, beach.longitude),
-            );
-            if let Some(wq_id) = beach.water_quality_id {
+            if let Some(wq_id) = &beach.water_quality_id {
                 water_quality_futures.push(self.water_quality_client.fetch_water_quality(wq_id));
             }
+            // Only beaches open to swell have waves worth surfing, and
+            // they're an extra low-bandwidth mode goes without
+            if self.fetches_waves(beach) {
+                wave_futures.push(self.marine_client.fetch_waves(beach));
+            }
+        }
+
+        // Nearby beaches share a weather grid cell, so weather is fetched once per cell
+        let locations: Vec<_> = beaches
+            .iter()
+            .map(|beach| (beach.latitude, beach.longitude, &beach.timezone))
+            .collect();
+        let (weather_results, water_quality_results, wave_results) = futures::future::join3(
+            fetch_weather_by_cell(&self.weather_client, &locations),
+            futures::future::join_all(water_quality_futures),
+            futures::future::join_all(wave_futures),
+        )
+        .await;
+
+        let mut water_quality_results = water_quality_results.into_iter();
+        let mut wave_results = wave_results.into_iter();
+        let beaches = beaches
+            .iter()
+            .zip(weather_results)
+            .map(|(&beach, weather)| FetchedBeach {
+                beach,
+                weather,
+                water_quality: beach
+                    .water_quality_id
+                    .as_ref()
+                    .and_then(|_| water_quality_results.next())
+                    .and_then(Result::ok),
+                waves: self
+                    .fetches_waves(beach)
+                    .then(|| wave_results.next())
+                    .flatten()
+                    .and_then(Result::ok),
+            })
+            .collect();
+
+        BeachFetch {
+            tides,
+            closures,
+            seawall_closures,
+            observations,
+            fountains,
+            fire_bans,
+            wind_models,
+            air_quality,
+            beaches,
         }
+    }
 
-        // Wait for all weather requests concurrently
-        let weather_results: Vec<Result<Weather, WeatherError>> =
-            futures::future::join_all(weather_futures).await;
+    /// Refetches one feed for one beach, expiring its cached copy first so
+    /// the API is asked again; the stale copy is still the fallback
+    ///
+    /// Weather isn't cached, so it's simply fetched again.
+    pub async fn fetch_feed(&self, beach: &'static Beach, feed: Feed) -> BeachFetch {
+        let mut fetched = FetchedBeach {
+            beach,
+            weather: None,
+            water_quality: None,
+            waves: None,
+        };
+        let mut fetch = BeachFetch::default();
+        match feed {
+            Feed::Weather => {
+                let location = [(beach.latitude, beach.longitude, &beach.timezone)];
+                fetched.weather = fetch_weather_by_cell(&self.weather_client, &location)
+                    .await
+                    .pop()
+                    .flatten();
+            }
+            Feed::Tides => {
+                self.tides_client.expire_cache();
+                fetch.tides = self.tides_client.fetch_tides().await.ok();
+            }
+            Feed::WaterQuality => {
+                if let Some(station) = &beach.water_quality_id {
+                    self.water_quality_client.expire_cache(station);
+                    fetched.water_quality = self
+                        .water_quality_client
+                        .fetch_water_quality(station)
+                        .await
+                        .ok();
+                }
+            }
+        }
+        fetch.beaches.push(fetched);
+        fetch
+    }
 
-        // Wait for all water quality requests concurrently
-        let water_quality_results: Vec<Result<WaterQuality, WaterQualityError>> =
-            futures::future::join_all(water_quality_futures).await;
+    /// Whether refreshes fetch waves for a beach: only those open to swell,
+    /// and none in low-bandwidth mode
+    fn fetches_waves(&self, beach: &Beach) -> bool {
+        !self.low_bandwidth && wave_facing(&beach.id).is_some()
+    }
 
-        // Build beach conditions for each beach
-        let mut wq_index = 0;
-        for (i, beach) in beaches.iter().enumerate() {
+    /// Stores fetched conditions
+    ///
+    /// Data that failed to fetch keeps the beach's last known value, and so
+    /// does data with implausible values, so those never reach the screen;
+    /// returns false if any were quarantined.
+    fn apply_fetch(&mut self, fetch: BeachFetch) -> bool {
+        let mut sane = true;
+        let fetched_tides = fetch.tides.filter(|tides| {
+            let passed = self.passes_checks(check_tides(tides));
+            sane &= passed;
+            passed
+        });
+        for fetched in &fetch.beaches {
+            self.load_states.remove(&fetched.beach.id);
+        }
+        if let Some(fountains) = fetch.fountains {
+            self.fountains = fountains;
+        }
+        if fetch.fire_bans.is_some() {
+            self.fire_bans = fetch.fire_bans;
+        }
+        if fetch.wind_models.is_some() {
+            self.wind_models = fetch.wind_models;
+        }
+        if let Some(observations) = fetch.observations {
+            self.wind_observations = observations;
+        }
+        for fetched in fetch.beaches {
+            let beach = fetched.beach;
+            let weather = fetched.weather.filter(|weather| {
+                let passed = self.passes_checks(check_weather(weather, &beach.id));
+                sane &= passed;
+                passed
+            });
             // Get existing conditions to preserve stale data on fetch failure
-            let existing = self.beach_conditions.get(beach.id);
-
-            // Use new weather data if available, otherwise preserve existing
-            let new_weather = weather_results
-                .get(i)
-                .and_then(|r| r.as_ref().ok().cloned());
-            let weather = new_weather.or_else(|| existing.and_then(|e| e.weather.clone()));
-
-            // Use new water quality data if available, otherwise preserve existing
-            let new_water_quality = if beach.water_quality_id.is_some() {
-                let result = water_quality_results
-                    .get(wq_index)
-                    .and_then(|r| r.as_ref().ok().cloned());
-                wq_index += 1;
-                result
-            } else {
-                None
-            };
-            let water_quality =
-                new_water_quality.or_else(|| existing.and_then(|e| e.water_quality.clone()));
+            let existing = self.beach_conditions.get(beach.id.as_str());
 
-            // Use new tides if available, otherwise preserve existing
-            let tides = tides_result
+            let weather = weather.or_else(|| existing.and_then(|e| e.weather.clone()));
+            let water_quality = fetched
+                .water_quality
+                .or_else(|| existing.and_then(|e| e.water_quality.clone()));
+            let waves = fetched
+                .waves
+                .or_else(|| existing.and_then(|e| e.waves.clone()));
+            let tides = fetched_tides
                 .clone()
                 .or_else(|| existing.and_then(|e| e.tides.clone()));
 
+            // A fetched feed is authoritative; without one, keep the last known closure
+            let closure = match &fetch.closures {
+                Some(closures) => closures.get(beach.id.as_str()).cloned(),
+                None => existing.and_then(|e| e.closure.clone()),
+            };
+            let seawall_closure = match &fetch.seawall_closures {
+                Some(closures) => closures.get(beach.id.as_str()).cloned(),
+                None => existing.and_then(|e| e.seawall_closure.clone()),
+            };
+            let air_quality = fetch
+                .air_quality
+                .clone()
+                .or_else(|| existing.and_then(|e| e.air_quality.clone()));
+
             let conditions = BeachConditions {
-                beach: *beach,
+                beach: beach.clone(),
                 weather,
                 tides,
                 water_quality,
+                closure,
+                waves,
+                air_quality,
+                seawall_closure,
             };
 
-            self.beach_conditions
-                .insert(beach.id.to_string(), conditions);
+            self.beach_conditions.insert(beach.id.clone(), conditions);
+        }
+        sane
+    }
+
+    /// Whether a response's checks found nothing; otherwise its anomalies are
+    /// kept for the debug view and logged to the cache directory
+    fn passes_checks(&mut self, anomalies: Vec<Anomaly>) -> bool {
+        if anomalies.is_empty() {
+            return true;
+        }
+        // Logging is best-effort; the response is quarantined either way
+        if let Some(dir) = self.user_store.as_ref().and_then(CacheManager::dir) {
+            let _ = anomaly::append_log(dir, &anomalies);
+        }
+        self.anomalies.extend(anomalies);
+        let excess = self.anomalies.len().saturating_sub(ANOMALY_HISTORY);
+        self.anomalies.drain(..excess);
+        false
+    }
+
+    /// Hit and miss counts of the cache manager's in-memory layer, shared by
+    /// the data clients and the user store
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.user_store.as_ref().map(CacheManager::stats)
+    }
+
+    /// Records the current hour's conditions and loads last week's and the
+    /// week so far's for comparison
+    ///
+    /// Only live loads are recorded; replayed snapshots would rewrite history.
+    fn update_history(&mut self, now: DateTime<Local>) {
+        let Some(store) = &self.user_store else {
+            return;
+        };
+        for (beach_id, conditions) in &self.beach_conditions {
+            // Recording is best-effort; the comparison just stays empty next week
+            let _ = history::record(store, conditions, now);
+            let today = conditions
+                .beach
+                .timezone
+                .local(now.with_timezone(&Utc))
+                .date();
+            match history::load_last_week(store, beach_id, today) {
+                Some(day) => self.last_week.insert(beach_id.clone(), day),
+                None => self.last_week.remove(beach_id),
+            };
+            self.week_so_far.insert(
+                beach_id.clone(),
+                history::load_week_so_far(store, beach_id, today),
+            );
+        }
+    }
+
+    /// Conditions recorded at the same hour a week before `now`, on the beach's clock
+    pub fn last_week_snapshot(
+        &self,
+        beach_id: &str,
+        now: DateTime<Local>,
+    ) -> Option<&HourSnapshot> {
+        let conditions = self.beach_conditions.get(beach_id)?;
+        let beach_now = conditions.beach.timezone.local(now.with_timezone(&Utc));
+        let day = self.last_week.get(beach_id)?;
+        (day.date + Duration::days(7) == beach_now.date())
+            .then(|| day.hours.get(&(beach_now.hour() as u8)))
+            .flatten()
+    }
+
+    /// The best day for an activity earlier this week, across every beach:
+    /// the beach, the day and its top recorded score
+    ///
+    /// Ties go to the earlier beach in the registry.
+    pub fn best_day_this_week(&self, activity: Activity) -> Option<(&Beach, NaiveDate, u8)> {
+        all_beaches()
+            .iter()
+            .filter_map(|beach| {
+                let days = self.week_so_far.get(&beach.id)?;
+                let (date, score) = history::best_day(days, activity)?;
+                Some((beach, date, score))
+            })
+            .rev()
+            .max_by_key(|&(_, _, score)| score)
+    }
+
+    /// Replaces all beach data with a recorded snapshot, as if it had just been fetched
+    ///
+    /// Used by session replay so recorded data is shown instead of live API data.
+    pub fn apply_snapshot(&mut self, conditions: HashMap<BeachId, BeachConditions>) {
+        self.beach_conditions = conditions;
+        self.finish_loading();
+    }
+
+    /// Records the refresh time and leaves the loading state
+    fn finish_loading(&mut self) {
+        self.data_version += 1;
+
+        // New data can close or reopen beaches, which changes the visible list
+        if self.hide_closed || self.hide_unsuitable {
+            self.reselect(None);
         }
 
         // Record refresh time
@@ -228,6 +1364,13 @@ impl App {
         if self.pending_plan_trip {
             self.state = AppState::PlanTrip;
             self.pending_plan_trip = false;
+        } else if self.pending_dashboard {
+            self.state = AppState::Dashboard;
+            self.pending_dashboard = false;
+        } else if let Some(beach_id) = self.pending_beach.take() {
+            // Backing out of the detail view lands on the beach in the list
+            self.reselect(Some(&beach_id));
+            self.state = AppState::BeachDetail(beach_id);
         } else {
             self.state = AppState::BeachList;
         }
@@ -246,7 +1389,7 @@ impl App {
         // Fetch weather
         let weather = self
             .weather_client
-            .fetch_weather(beach.latitude, beach.longitude)
+            .fetch_weather(beach.latitude, beach.longitude, &beach.timezone)
             .await
             .ok();
 
@@ -254,7 +1397,7 @@ impl App {
         let tides = self.tides_client.fetch_tides().await.ok();
 
         // Fetch water quality
-        let water_quality = if let Some(wq_id) = beach.water_quality_id {
+        let water_quality = if let Some(wq_id) = &beach.water_quality_id {
             self.water_quality_client
                 .fetch_water_quality(wq_id)
                 .await
@@ -263,15 +1406,32 @@ impl App {
             None
         };
 
+        // Closures and air quality are fetched for every beach at once by
+        // `load_all_data`
+        let existing = self.beach_conditions.get(beach_id);
+        let closure = existing.and_then(|existing| existing.closure.clone());
+        let seawall_closure = existing.and_then(|existing| existing.seawall_closure.clone());
+        let air_quality = existing.and_then(|existing| existing.air_quality.clone());
+
+        let waves = if self.fetches_waves(beach) {
+            self.marine_client.fetch_waves(beach).await.ok()
+        } else {
+            None
+        };
+
         let conditions = BeachConditions {
-            beach: *beach,
+            beach: beach.clone(),
             weather,
             tides,
             water_quality,
+            closure,
+            waves,
+            air_quality,
+            seawall_closure,
         };
 
-        self.beach_conditions
-            .insert(beach_id.to_string(), conditions);
+        self.beach_conditions.insert(beach.id.clone(), conditions);
+        self.data_version += 1;
     }
 
     /// Handles keyboard input and updates state accordingly
@@ -279,181 +1439,839 @@ impl App {
     /// # Arguments
     /// * `key_event` - The keyboard event to handle
     ///
-    /// # Key Bindings
-    /// - `q` or `Esc` (in BeachList): Quit the application
-    /// - `Up`/`k`: Move selection up in list
-    /// - `Down`/`j`: Move selection down in list
-    /// - `Enter`: Select current beach (go to detail view)
-    /// - `p`: Open PlanTrip view (from BeachList or BeachDetail)
-    /// - `1`-`5`: Set current activity (in BeachDetail)
-    /// - `Esc` (in BeachDetail): Go back to list view
-    /// - `Esc` (in PlanTrip): Go back to list view
+    /// What each key does in each view is listed in `keymap::BINDINGS`; this
+    /// works out the current view, looks the key up there and carries out
+    /// the action. The help overlay and the raw API response viewer capture
+    /// every key while they're shown.
     pub fn handle_key(&mut self, key_event: KeyEvent) {
-        // Handle help overlay - intercepts all keys when shown
-        if self.show_help {
-            match key_event.code {
-                KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => {
-                    self.show_help = false;
-                }
-                _ => {} // Ignore other keys when help is shown
-            }
+        // Any key dismisses a toast, then works as usual
+        self.toast = None;
+
+        if self.show_help && self.help.searching {
+            self.handle_help_search_key(key_event.code);
+            return;
+        }
+        if self.set_name.is_some() && self.state == AppState::Dashboard {
+            self.handle_set_name_key(key_event.code);
+            return;
+        }
+        let view = self.key_view();
+        let action = keymap::action_for(view, key_event.code);
+        // The raw response viewer only opens with `--verbose`
+        if action == Some(Action::ToggleDebugView)
+            && view != View::Debug
+            && self.debug_log.is_none()
+        {
+            return;
+        }
+        // A quick jump takes the digits typed after `g`
+        if view == View::List && self.jump.is_some() && action != Some(Action::ToggleDebugView) {
+            self.handle_jump_key(key_event.code, Local::now());
             return;
         }
+        if let Some(action) = action {
+            self.perform(view, action);
+        }
+    }
 
+    /// The view keys are looked up in, with overlays taking precedence
+    pub(crate) fn key_view(&self) -> View {
+        if self.show_help {
+            return View::Help;
+        }
+        if self.show_debug_view {
+            return View::Debug;
+        }
+        if self.show_directions {
+            return View::Directions;
+        }
         match self.state {
-            AppState::Loading => {
-                // Only quit is allowed during loading
-                if key_event.code == KeyCode::Char('q') {
-                    self.should_quit = true;
-                }
+            AppState::Loading => View::Loading,
+            AppState::BeachList => View::List,
+            AppState::BeachDetail(_) => View::Detail,
+            AppState::PlanTrip => View::PlanTrip,
+            AppState::Dashboard => View::Dashboard,
+            AppState::WaterQualityOverview => View::WaterQuality,
+            AppState::Journal => View::Journal,
+        }
+    }
+
+    /// Carries out a key binding's action in the view it was pressed in
+    fn perform(&mut self, view: View, action: Action) {
+        match (view, action) {
+            (View::Help, Action::Back | Action::ToggleHelp) => self.show_help = false,
+            (View::Help, Action::Up) => self.help.scroll = self.help.scroll.saturating_sub(1),
+            (View::Help, Action::Down) => self.scroll_help_to(self.help.scroll.saturating_add(1)),
+            (View::Help, Action::PageUp) => {
+                self.help.scroll = self.help.scroll.saturating_sub(HELP_PAGE_LINES)
             }
-            AppState::BeachList => match key_event.code {
-                KeyCode::Char('q') | KeyCode::Esc => {
-                    self.should_quit = true;
-                }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    self.move_selection_up();
-                }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    self.move_selection_down();
-                }
-                KeyCode::Enter => {
-                    if let Some(beach) = self.selected_beach() {
-                        self.state = AppState::BeachDetail(beach.id.to_string());
-                    }
+            (View::Help, Action::PageDown) => {
+                self.scroll_help_to(self.help.scroll.saturating_add(HELP_PAGE_LINES))
+            }
+            (View::Help, Action::Top) => self.help.scroll = 0,
+            (View::Help, Action::Bottom) => self.scroll_help_to(u16::MAX),
+            (View::Help, Action::SearchHelp) => {
+                self.help.searching = true;
+                self.help.query.clear();
+                self.help.scroll = 0;
+            }
+
+            (View::Debug, Action::Back | Action::ToggleDebugView) => self.show_debug_view = false,
+            (View::Debug, Action::Up) => {
+                self.debug_scroll_offset = self.debug_scroll_offset.saturating_sub(1)
+            }
+            (View::Debug, Action::Down) => {
+                self.debug_scroll_offset = self.debug_scroll_offset.saturating_add(1)
+            }
+            (View::Debug, Action::PageUp) => {
+                self.debug_scroll_offset = self.debug_scroll_offset.saturating_sub(10)
+            }
+            (View::Debug, Action::PageDown) => {
+                self.debug_scroll_offset = self.debug_scroll_offset.saturating_add(10)
+            }
+            (View::Debug, Action::Top) => self.debug_scroll_offset = 0,
+            (View::Directions, Action::Back | Action::ToggleDirections) => {
+                self.show_directions = false
+            }
+            (_, Action::ToggleDebugView) => {
+                self.show_debug_view = true;
+                self.debug_scroll_offset = 0;
+            }
+
+            (View::List, Action::Back) | (_, Action::Quit) => self.should_quit = true,
+            (View::Detail, Action::Back) => {
+                self.reset_detail_view_state();
+                self.state = AppState::BeachList;
+            }
+            (_, Action::Back) => self.state = AppState::BeachList,
+            (_, Action::ToggleHelp) => self.open_help(),
+
+            (View::List, Action::Up) => self.move_selection_up(),
+            (View::List, Action::Down) => self.move_selection_down(),
+            (View::List, Action::Open) => {
+                if let Some(beach) = self.selected_beach() {
+                    self.state = AppState::BeachDetail(beach.id.clone());
                 }
-                KeyCode::Char('p') => {
-                    self.state = AppState::PlanTrip;
+            }
+            (View::List, Action::StartJump) => {
+                self.jump = Some(QuickJump {
+                    digits: String::new(),
+                    expires_at: Local::now() + Duration::milliseconds(JUMP_TIMEOUT_MILLIS),
+                });
+            }
+            (View::List, Action::OpenDashboard) => self.state = AppState::Dashboard,
+            (View::Dashboard, Action::NextComparisonSet) => self.cycle_comparison_set(),
+            (View::Dashboard, Action::SaveComparisonSet) => self.set_name = Some(String::new()),
+            (View::Dashboard, Action::DeleteComparisonSet) => self.delete_comparison_set(),
+            (View::List, Action::OpenWaterQuality) => {
+                self.state = AppState::WaterQualityOverview;
+            }
+            (View::List, Action::OpenJournal) => {
+                self.journal_scroll = 0;
+                self.state = AppState::Journal;
+            }
+            (View::List, Action::ToggleFavorite) => self.toggle_favorite_selected(),
+            (View::List, Action::MoveBeachUp) => self.move_selected_beach(false),
+            (View::List, Action::MoveBeachDown) => self.move_selected_beach(true),
+            (View::List, Action::ToggleCustomOrder) => self.toggle_custom_order(),
+            (View::List, Action::HideClosed) => self.toggle_hide_closed(),
+            (View::List, Action::HideUnsuitable) => self.toggle_hide_unsuitable(),
+            (View::WaterQuality, Action::SortByStatus) => {
+                self.water_quality_by_status = !self.water_quality_by_status;
+            }
+
+            (View::Journal, Action::Up) => {
+                self.journal_scroll = self.journal_scroll.saturating_sub(1)
+            }
+            (View::Journal, Action::Down) => {
+                self.scroll_journal_to(self.journal_scroll.saturating_add(1))
+            }
+            (View::Journal, Action::Top) => self.journal_scroll = 0,
+            (View::Journal, Action::Bottom) => self.scroll_journal_to(u16::MAX),
+
+            (View::Detail, Action::Up) => self.scroll_up(),
+            (View::Detail, Action::Down) => self.scroll_down(),
+            (View::Detail, Action::Top) => self.scroll_to_top(),
+            (View::Detail, Action::Bottom) => self.scroll_to_bottom(),
+            (View::Detail, Action::OpenPlanTrip) => {
+                self.reset_detail_view_state();
+                self.state = AppState::PlanTrip;
+            }
+            (View::Detail, Action::ToggleTideChart) => self.toggle_tide_chart(),
+            (View::Detail, Action::CombineActivities) => self.toggle_multi_select(),
+            (View::Detail, Action::ToggleSunSession) => self.toggle_sun_session(Local::now()),
+            (View::Detail, Action::ToggleDirections) => self.show_directions = true,
+            (View::Detail, Action::RefreshFeed(feed)) => {
+                self.request_feed_refresh(feed, Local::now())
+            }
+
+            (View::PlanTrip, Action::Up) => self.move_plan_cursor_up(),
+            (View::PlanTrip, Action::Down) => self.move_plan_cursor_down(),
+            (View::PlanTrip, Action::Left) => self.move_plan_cursor_left(),
+            (View::PlanTrip, Action::Right) => self.move_plan_cursor_right(),
+            (View::PlanTrip, Action::PageUp) => self.pan_plan_hours(false),
+            (View::PlanTrip, Action::PageDown) => self.pan_plan_hours(true),
+            (View::PlanTrip, Action::Top) => self.plan_cursor.1 = 0,
+            (View::PlanTrip, Action::Bottom) => {
+                self.plan_cursor.1 = self.plan_hour_count().saturating_sub(1)
+            }
+            (View::PlanTrip, Action::NextActivity) => self.cycle_activity(),
+            (View::PlanTrip, Action::PinPlan) => self.toggle_pin_at_cursor(),
+            (View::PlanTrip, Action::ToggleCrowds) => {
+                self.plan_show_crowds = !self.plan_show_crowds;
+            }
+            (View::PlanTrip, Action::Open) => {
+                if let Some(beach) = all_beaches().get(self.plan_cursor.0) {
+                    self.state = AppState::BeachDetail(beach.id.clone());
                 }
-                // Activity selection (1-5)
-                KeyCode::Char('1') => {
-                    self.current_activity = Some(Activity::Swimming);
-                }
-                KeyCode::Char('2') => {
-                    self.current_activity = Some(Activity::Sunbathing);
-                }
-                KeyCode::Char('3') => {
-                    self.current_activity = Some(Activity::Sailing);
-                }
-                KeyCode::Char('4') => {
-                    self.current_activity = Some(Activity::Sunset);
-                }
-                KeyCode::Char('5') => {
-                    self.current_activity = Some(Activity::Peace);
-                }
-                KeyCode::Char('r') => {
-                    self.refresh_requested = true;
-                }
-                KeyCode::Char('?') => {
-                    self.show_help = true;
-                }
-                _ => {}
-            },
-            AppState::BeachDetail(_) => match key_event.code {
-                KeyCode::Char('q') => {
-                    self.should_quit = true;
-                }
-                KeyCode::Esc => {
-                    self.reset_detail_view_state();
-                    self.state = AppState::BeachList;
-                }
-                KeyCode::Char('p') => {
-                    self.reset_detail_view_state();
-                    self.state = AppState::PlanTrip;
-                }
-                // Scroll navigation
-                KeyCode::Char('j') | KeyCode::Down => {
-                    self.scroll_down();
-                }
-                KeyCode::Char('k') | KeyCode::Up => {
-                    self.scroll_up();
-                }
-                KeyCode::Char('g') => {
-                    self.scroll_to_top();
-                }
-                KeyCode::Char('G') => {
-                    self.scroll_to_bottom();
-                }
-                // Activity selection
-                KeyCode::Char('1') => {
-                    self.current_activity = Some(Activity::Swimming);
-                }
-                KeyCode::Char('2') => {
-                    self.current_activity = Some(Activity::Sunbathing);
-                }
-                KeyCode::Char('3') => {
-                    self.current_activity = Some(Activity::Sailing);
-                }
-                KeyCode::Char('4') => {
-                    self.current_activity = Some(Activity::Sunset);
-                }
-                KeyCode::Char('5') => {
-                    self.current_activity = Some(Activity::Peace);
-                }
-                KeyCode::Char('r') => {
-                    self.refresh_requested = true;
+            }
+
+            (_, Action::OpenPlanTrip) => self.state = AppState::PlanTrip,
+            // The detail view only offers what its beach has
+            (View::Detail, Action::SelectActivity(activity)) if matches!(&self.state, AppState::BeachDetail(id) if !activity.offered_at(id)) =>
+                {}
+            (_, Action::SelectActivity(activity)) => self.select_activity(activity),
+            (_, Action::Refresh) => self.request_refresh(Local::now()),
+            _ => {}
+        }
+    }
+
+    /// Scrolls the journal screen, stopping with its oldest entry on screen
+    fn scroll_journal_to(&mut self, scroll: u16) {
+        let last = self.journal.len().saturating_sub(1);
+        self.journal_scroll = scroll.min(u16::try_from(last).unwrap_or(u16::MAX));
+    }
+
+    /// Opens the help overlay with no search and scrolled to the top
+    pub fn open_help(&mut self) {
+        self.show_help = true;
+        self.help = HelpView::default();
+    }
+
+    /// Handles a key typed into the help search
+    ///
+    /// `Enter` keeps the filter and goes back to scrolling; `Esc` clears it.
+    fn handle_help_search_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char(c) => self.help.query.push(c),
+            KeyCode::Backspace => {
+                self.help.query.pop();
+            }
+            KeyCode::Enter => self.help.searching = false,
+            KeyCode::Esc => {
+                self.help.searching = false;
+                self.help.query.clear();
+            }
+            _ => return,
+        }
+        self.help.scroll = 0;
+    }
+
+    /// Scrolls the help overlay, stopping at its last line
+    fn scroll_help_to(&mut self, offset: u16) {
+        let last = keymap::reference_len(&self.help.query).saturating_sub(1);
+        self.help.scroll = offset.min(u16::try_from(last).unwrap_or(u16::MAX));
+    }
+
+    /// Selects an activity in response to a number key.
+    ///
+    /// Normally this replaces the current activity and clears any combined one.
+    /// In multi-select mode it toggles `activity` as the secondary activity
+    /// alongside the current one instead.
+    pub fn select_activity(&mut self, activity: Activity) {
+        let primary = match self.current_activity {
+            Some(primary) if self.multi_select => primary,
+            _ => {
+                self.replace_activity(Some(activity));
+                return;
+            }
+        };
+
+        if activity == primary {
+            return;
+        }
+        if self.secondary_activity == Some(activity) {
+            self.secondary_activity = None;
+        } else {
+            self.secondary_activity = Some(activity);
+        }
+    }
+
+    /// Makes `activity` the only one selected
+    fn replace_activity(&mut self, activity: Option<Activity>) {
+        let selected = self.selected_beach().map(|beach| beach.id.clone());
+        self.current_activity = activity;
+        self.secondary_activity = None;
+        // A different activity can rule out different beaches
+        if self.hide_unsuitable {
+            self.reselect(selected.as_ref());
+        }
+    }
+
+    /// Whether frames are drawn in the dimmer night palette
+    pub fn dims_for_night(&self) -> bool {
+        self.night_dim && self.night
+    }
+
+    /// Follows sunset and sunrise at the focused beach
+    ///
+    /// At sunset the screen dims and a daytime activity gives way to the
+    /// configured evening one; at sunrise the replaced activity comes back,
+    /// unless another has been picked in the meantime. Without weather to
+    /// say when the sun sets, nothing changes.
+    fn update_night(&mut self, now: DateTime<Local>) {
+        if !self.night_dim && self.night_activity.is_none() {
+            return;
+        }
+        let Some(beach) = self.focused_beach() else {
+            return;
+        };
+        let Some(night) = self
+            .get_conditions(&beach.id)
+            .and_then(|conditions| theme::is_night(conditions, now.with_timezone(&Utc)))
+        else {
+            return;
+        };
+        if night == self.night {
+            return;
+        }
+        self.night = night;
+
+        if night {
+            let Some(activity) = self.night_activity else {
+                return;
+            };
+            if self.current_activity.is_some_and(|a| a.suits_evening()) {
+                return;
+            }
+            self.night_replaced = Some(self.current_activity);
+            self.replace_activity(Some(activity));
+            self.show_toast(
+                format!("Dark at {}: switched to {}", beach.name, activity.label()),
+                now,
+            );
+        } else if let Some(replaced) = self.night_replaced.take() {
+            if self.current_activity != self.night_activity {
+                return;
+            }
+            self.replace_activity(replaced);
+            if let Some(activity) = replaced {
+                self.show_toast(
+                    format!("Sun's up at {}: back to {}", beach.name, activity.label()),
+                    now,
+                );
+            }
+        }
+    }
+
+    /// Toggles multi-select mode, dropping the secondary activity when turned off
+    pub fn toggle_multi_select(&mut self) {
+        self.multi_select = !self.multi_select;
+        if !self.multi_select {
+            self.secondary_activity = None;
+        }
+    }
+
+    /// Switches to the next activity in order, wrapping around
+    pub fn cycle_activity(&mut self) {
+        let activities = Activity::all();
+        let next = match self.current_activity {
+            Some(current) => {
+                let idx = activities.iter().position(|a| *a == current).unwrap_or(0);
+                activities[(idx + 1) % activities.len()]
+            }
+            None => activities[0],
+        };
+        self.select_activity(next);
+    }
+
+    /// Returns true if the given beach and hour today is pinned as a plan
+    pub fn is_pinned(&self, beach_id: &str, hour: u8) -> bool {
+        let today = Local::now().date_naive();
+        self.pinned_plans
+            .iter()
+            .any(|plan| plan.matches(beach_id, today, hour))
+    }
+
+    /// Pins or unpins the PlanTrip cell under the cursor and saves the plans
+    pub fn toggle_pin_at_cursor(&mut self) {
+        let Some(beach) = all_beaches().get(self.plan_cursor.0) else {
+            return;
+        };
+        let hour = self.plan_time_range.0 + self.plan_cursor.1 as u8;
+        if hour > self.plan_time_range.1 {
+            return;
+        }
+
+        let today = Local::now().date_naive();
+        let before = self.pinned_plans.len();
+        self.pinned_plans
+            .retain(|plan| !plan.matches(&beach.id, today, hour));
+        if self.pinned_plans.len() == before {
+            self.pinned_plans.push(PinnedPlan::new(
+                beach.id.clone(),
+                today,
+                hour,
+                self.current_activity,
+            ));
+        }
+        // An unpinned plan's rain delay goes with it
+        let pinned = &self.pinned_plans;
+        self.rain_delays
+            .retain(|delay| pinned.contains(&delay.plan));
+
+        if let Some(store) = &self.user_store {
+            // Saving is best-effort; the pin still applies for this session
+            let _ = plans::save_plans(store, &self.pinned_plans);
+        }
+    }
+
+    /// The sun session that's running, if any
+    pub fn running_sun_session(&self) -> Option<&SunSession> {
+        self.sun_sessions
+            .iter()
+            .find(|session| session.is_running())
+    }
+
+    /// Stops the running sun session, or starts one at the beach in the
+    /// detail view, and saves the sessions
+    pub fn toggle_sun_session(&mut self, now: DateTime<Local>) {
+        if let Some(running) = self.sun_sessions.iter_mut().find(|s| s.is_running()) {
+            running.ended_at = Some(now);
+        } else if let AppState::BeachDetail(beach_id) = &self.state {
+            self.sun_sessions
+                .push(SunSession::start(beach_id.clone(), now));
+        } else {
+            return;
+        }
+
+        if let Some(store) = &self.user_store {
+            // Saving is best-effort; the session still counts for this run
+            let _ = uv_exposure::save_sessions(store, &self.sun_sessions);
+        }
+    }
+
+    /// The day's UV dose over every sun session, None before the first one
+    ///
+    /// Each session's dose comes from its beach's hourly UV index, so it
+    /// counts for nothing until that beach's weather has loaded.
+    pub fn uv_exposure(&self, now: DateTime<Local>) -> Option<UvExposure> {
+        if self.sun_sessions.is_empty() {
+            return None;
+        }
+        let dose = self
+            .sun_sessions
+            .iter()
+            .filter_map(|session| {
+                let weather = self
+                    .beach_conditions
+                    .get(&session.beach_id)?
+                    .weather
+                    .as_ref()?;
+                Some(session.dose(&weather.hourly, now))
+            })
+            .sum();
+        Some(UvExposure {
+            dose,
+            limit: self.uv_dose_limit,
+            running_for: self.running_sun_session().map(|s| s.elapsed(now)),
+        })
+    }
+
+    /// Shows a toast when a running session's dose first approaches the
+    /// limit, and again when it reaches it, unless weather warnings are
+    /// turned off
+    fn check_uv_dose(&mut self, now: DateTime<Local>) {
+        let Some(exposure) = self.uv_exposure(now) else {
+            return;
+        };
+        let level = exposure.level();
+        let enabled = alerts::delivery(&self.notifications, AlertKind::WeatherWarning, now.time())
+            != Delivery::Off;
+        if enabled && level > self.uv_level && exposure.running_for.is_some() {
+            if let Some(warning) = exposure.warning() {
+                self.show_toast(warning, now);
+            }
+        }
+        self.uv_level = level;
+    }
+
+    /// Returns true if the given beach is marked as a favorite
+    pub fn is_favorite(&self, beach_id: &str) -> bool {
+        self.favorites.iter().any(|id| id == beach_id)
+    }
+
+    /// Adds or removes the selected beach from favorites and saves them
+    pub fn toggle_favorite_selected(&mut self) {
+        let Some(beach) = self.selected_beach() else {
+            return;
+        };
+
+        if self.is_favorite(&beach.id) {
+            self.favorites.retain(|id| *id != beach.id);
+        } else {
+            self.favorites.push(beach.id.clone());
+        }
+
+        if let Some(store) = &self.user_store {
+            // Saving is best-effort; the favorite still applies for this session
+            let _ = favorites::save_favorites(store, &self.favorites);
+        }
+    }
+
+    /// Adds the comparison sets named in the config ahead of the saved ones,
+    /// which give way to a configured set of the same name
+    pub fn add_configured_sets(&mut self, sets: Vec<(String, Vec<BeachId>)>) {
+        self.comparison_sets
+            .retain(|saved| !sets.iter().any(|(name, _)| *name == saved.name));
+        let configured = sets
+            .into_iter()
+            .map(|(name, beaches)| ComparisonSet::configured(name, beaches));
+        self.comparison_sets.splice(0..0, configured);
+        self.active_set = None;
+    }
+
+    /// The comparison set the dashboard shows, if it isn't showing favorites
+    pub fn active_comparison_set(&self) -> Option<&ComparisonSet> {
+        self.comparison_sets.get(self.active_set?)
+    }
+
+    /// Moves the dashboard on to the next comparison set, back to the
+    /// favorites after the last
+    fn cycle_comparison_set(&mut self) {
+        self.active_set = match self.active_set {
+            None if !self.comparison_sets.is_empty() => Some(0),
+            Some(index) if index + 1 < self.comparison_sets.len() => Some(index + 1),
+            _ => None,
+        };
+    }
+
+    /// Handles a key typed while naming a comparison set
+    ///
+    /// `Enter` saves the dashboard's beaches under the name; `Esc` cancels.
+    fn handle_set_name_key(&mut self, code: KeyCode) {
+        let Some(name) = &mut self.set_name else {
+            return;
+        };
+        match code {
+            KeyCode::Char(c) => name.push(c),
+            KeyCode::Backspace => {
+                name.pop();
+            }
+            KeyCode::Enter => {
+                let name = self.set_name.take().unwrap_or_default();
+                self.save_comparison_set(name.trim(), Local::now());
+            }
+            KeyCode::Esc => self.set_name = None,
+            _ => {}
+        }
+    }
+
+    /// Saves the beaches the dashboard shows as a comparison set, replacing
+    /// a saved set of the same name, and shows it
+    ///
+    /// A blank name saves nothing, and sets from the config can't be
+    /// replaced.
+    pub fn save_comparison_set(&mut self, name: &str, now: DateTime<Local>) {
+        if name.is_empty() {
+            return;
+        }
+        let beaches = self
+            .dashboard_beaches()
+            .iter()
+            .map(|beach| beach.id.clone())
+            .collect();
+        let index = match self.comparison_sets.iter().position(|set| set.name == name) {
+            Some(index) if self.comparison_sets[index].configured => {
+                self.show_toast(format!("'{}' is set in the config", name), now);
+                return;
+            }
+            Some(index) => {
+                self.comparison_sets[index].beaches = beaches;
+                index
+            }
+            None => {
+                self.comparison_sets.push(ComparisonSet::new(name, beaches));
+                self.comparison_sets.len() - 1
+            }
+        };
+        self.active_set = Some(index);
+        self.save_comparison_sets();
+        self.show_toast(format!("Saved comparison set '{}'", name), now);
+    }
+
+    /// Deletes the saved comparison set the dashboard shows, going back to
+    /// the favorites; sets from the config stay
+    fn delete_comparison_set(&mut self) {
+        let Some(index) = self.active_set else {
+            return;
+        };
+        if self.comparison_sets[index].configured {
+            return;
+        }
+        self.comparison_sets.remove(index);
+        self.active_set = None;
+        self.save_comparison_sets();
+    }
+
+    /// Persists the saved comparison sets
+    fn save_comparison_sets(&self) {
+        if let Some(store) = &self.user_store {
+            // Saving is best-effort; the set still applies for this session
+            let _ = comparison_sets::save_comparison_sets(store, &self.comparison_sets);
+        }
+    }
+
+    /// Beaches shown on the dashboard: the active comparison set's in the
+    /// order they were added, otherwise favorites in list order, or every
+    /// beach when no favorites have been chosen yet
+    pub fn dashboard_beaches(&self) -> Vec<&'static Beach> {
+        if let Some(set) = self.active_comparison_set() {
+            return set
+                .beaches
+                .iter()
+                .filter_map(|id| get_beach_by_id(id))
+                .collect();
+        }
+        let beaches = all_beaches();
+        if self.favorites.is_empty() {
+            return beaches.iter().collect();
+        }
+        beaches
+            .iter()
+            .filter(|beach| self.is_favorite(&beach.id))
+            .collect()
+    }
+
+    /// Handles a key typed during a quick jump
+    ///
+    /// Digits build up the beach number. The jump goes ahead as soon as no
+    /// more digits could name another beach, on `Enter`, or when the timeout
+    /// runs out (see `expire_jump`). A number no beach has, or any other
+    /// key, cancels the jump.
+    fn handle_jump_key(&mut self, code: KeyCode, now: DateTime<Local>) {
+        let Some(mut jump) = self.jump.take() else {
+            return;
+        };
+        match code {
+            KeyCode::Char(digit @ '0'..='9') => {
+                jump.digits.push(digit);
+                let count = self.visible_beaches().len();
+                let number: usize = jump.digits.parse().unwrap_or(0);
+                if number == 0 || number > count {
+                    return;
                 }
-                KeyCode::Char('?') => {
-                    self.show_help = true;
+                if number * 10 > count {
+                    self.jump_to_beach(number);
+                } else {
+                    jump.expires_at = now + Duration::milliseconds(JUMP_TIMEOUT_MILLIS);
+                    self.jump = Some(jump);
                 }
-                KeyCode::Char('t') => {
-                    self.toggle_tide_chart();
+            }
+            KeyCode::Enter => {
+                if let Ok(number) = jump.digits.parse() {
+                    self.jump_to_beach(number);
                 }
-                _ => {}
-            },
-            AppState::PlanTrip => {
-                match key_event.code {
-                    KeyCode::Char('q') => {
-                        self.should_quit = true;
-                    }
-                    KeyCode::Esc => {
-                        self.state = AppState::BeachList;
-                    }
-                    // Horizontal navigation (hours): h/Left and l/Right
-                    KeyCode::Char('h') | KeyCode::Left => {
-                        self.move_plan_cursor_left();
-                    }
-                    KeyCode::Char('l') | KeyCode::Right => {
-                        self.move_plan_cursor_right();
-                    }
-                    // Vertical navigation (beaches): k/Up and j/Down
-                    KeyCode::Char('k') | KeyCode::Up => {
-                        self.move_plan_cursor_up();
-                    }
-                    KeyCode::Char('j') | KeyCode::Down => {
-                        self.move_plan_cursor_down();
-                    }
-                    // Activity selection (1-5)
-                    KeyCode::Char('1') => {
-                        self.current_activity = Some(Activity::Swimming);
-                    }
-                    KeyCode::Char('2') => {
-                        self.current_activity = Some(Activity::Sunbathing);
-                    }
-                    KeyCode::Char('3') => {
-                        self.current_activity = Some(Activity::Sailing);
-                    }
-                    KeyCode::Char('4') => {
-                        self.current_activity = Some(Activity::Sunset);
-                    }
-                    KeyCode::Char('5') => {
-                        self.current_activity = Some(Activity::Peace);
-                    }
-                    // Enter navigates to beach detail
-                    KeyCode::Enter => {
-                        if let Some(beach) = all_beaches().get(self.plan_cursor.0) {
-                            self.state = AppState::BeachDetail(beach.id.to_string());
-                        }
-                    }
-                    _ => {}
+            }
+            _ => {}
+        }
+    }
+
+    /// Goes ahead with a quick jump once it has waited long enough for
+    /// another digit, or drops it if no digit was typed
+    pub fn expire_jump(&mut self, now: DateTime<Local>) {
+        let Some(jump) = self.jump.take_if(|jump| now >= jump.expires_at) else {
+            return;
+        };
+        if let Ok(number) = jump.digits.parse() {
+            self.jump_to_beach(number);
+        }
+    }
+
+    /// Opens the detail view of the beach numbered `number` (from 1) in the
+    /// list as it's shown
+    fn jump_to_beach(&mut self, number: usize) {
+        let Some(beach) = number
+            .checked_sub(1)
+            .and_then(|index| self.visible_beaches().get(index).copied())
+        else {
+            return;
+        };
+        self.selected_index = number - 1;
+        self.state = AppState::BeachDetail(beach.id.clone());
+    }
+
+    /// Moves the list selection to a beach, switching the detail view to it
+    /// if one is open; false if the beach is hidden from the list
+    pub fn select_beach(&mut self, beach_id: &BeachId) -> bool {
+        let Some(index) = self
+            .visible_beaches()
+            .iter()
+            .position(|beach| beach.id == *beach_id)
+        else {
+            return false;
+        };
+        self.selected_index = index;
+        if matches!(self.state, AppState::BeachDetail(_)) {
+            self.state = AppState::BeachDetail(beach_id.clone());
+            self.reset_detail_view_state();
+        }
+        true
+    }
+
+    /// Shows a toast for `TOAST_MINUTES` or until the next key press
+    pub fn show_toast(&mut self, message: String, now: DateTime<Local>) {
+        self.toast = Some(Toast {
+            message,
+            expires_at: now + Duration::minutes(TOAST_MINUTES),
+        });
+    }
+
+    /// The toast's message, while it hasn't expired
+    pub fn active_toast(&self, now: DateTime<Local>) -> Option<&str> {
+        self.toast
+            .as_ref()
+            .filter(|toast| now < toast.expires_at)
+            .map(|toast| toast.message.as_str())
+    }
+
+    /// Inverts the screen for `FLASH_MILLIS`
+    pub fn flash(&mut self, now: DateTime<Local>) {
+        self.flash_until = Some(now + Duration::milliseconds(FLASH_MILLIS));
+    }
+
+    /// Whether the screen is mid-flash
+    pub fn is_flashing(&self, now: DateTime<Local>) -> bool {
+        self.flash_until.is_some_and(|until| now < until)
+    }
+
+    /// Asks for a refresh, unless the last one finished less than
+    /// `refresh_cooldown` ago
+    pub fn request_refresh(&mut self, now: DateTime<Local>) {
+        if self.refresh_cooldown_remaining(now).is_none() {
+            self.refresh_requested = true;
+        }
+    }
+
+    /// Asks for one feed of the beach on screen to be refetched, or says why
+    /// there's nothing to refetch
+    fn request_feed_refresh(&mut self, feed: Feed, now: DateTime<Local>) {
+        let AppState::BeachDetail(id) = &self.state else {
+            return;
+        };
+        let Some(beach) = get_beach_by_id(id) else {
+            return;
+        };
+        if feed == Feed::WaterQuality && beach.water_quality_id.is_none() {
+            self.show_toast(format!("{} has no water quality station", beach.name), now);
+            return;
+        }
+        self.feed_refresh = Some((beach, feed));
+        self.show_toast(format!("Refreshing {}...", feed.label()), now);
+    }
+
+    /// Whole seconds (rounded up) until `r` refreshes again, while the
+    /// cooldown after the last refresh is running
+    pub fn refresh_cooldown_remaining(&self, now: DateTime<Local>) -> Option<i64> {
+        let remaining = self.last_refresh? + self.refresh_cooldown - now;
+        (remaining > Duration::zero()).then(|| (remaining.num_milliseconds() + 999) / 1000)
+    }
+
+    /// Whether background refreshes are stretched to save power: on battery
+    /// or while the terminal is unfocused, unless the config says otherwise
+    pub fn saving_power(&self) -> bool {
+        match self.power_saving {
+            PowerSaving::Auto => self.on_battery || !self.focused,
+            PowerSaving::Always => true,
+            PowerSaving::Never => false,
+        }
+    }
+
+    /// Minutes between the dashboard's automatic refreshes
+    pub fn auto_refresh_minutes(&self) -> i64 {
+        if self.saving_power() {
+            DASHBOARD_REFRESH_MINUTES * POWER_SAVING_STRETCH
+        } else {
+            DASHBOARD_REFRESH_MINUTES
+        }
+    }
+
+    /// Returns true when the dashboard's data is old enough to refresh itself
+    pub fn needs_auto_refresh(&self, now: DateTime<Local>) -> bool {
+        if self.state != AppState::Dashboard || self.refresh_requested {
+            return false;
+        }
+        match self.last_refresh {
+            Some(last) => now - last >= Duration::minutes(self.auto_refresh_minutes()),
+            None => true,
+        }
+    }
+
+    /// Recomputes time-derived data once per minute without refetching
+    ///
+    /// Tide height, state and the next high/low come from static predictions,
+    /// so they can follow the clock between data refreshes. Everything else
+    /// time-based (current-hour highlights, countdowns, "now" markers) is
+    /// computed at render time. Returns true if anything was recomputed.
+    pub fn tick(&mut self, now: DateTime<Local>) -> bool {
+        let minute = now.date_naive().and_hms_opt(now.hour(), now.minute(), 0);
+        if minute.is_none() || minute == self.last_tick_minute {
+            return false;
+        }
+        self.last_tick_minute = minute;
+
+        let Ok(tides) = self.tides_client.tide_info_at(now) else {
+            // Outside the prediction table: keep whatever was last loaded
+            return false;
+        };
+        for conditions in self.beach_conditions.values_mut() {
+            if let Some(existing) = &conditions.tides {
+                conditions.tides = Some(TideInfo {
+                    fetched_at: existing.fetched_at,
+                    ..tides.clone()
+                });
+            }
+        }
+        self.data_version += 1;
+        true
+    }
+
+    /// Predicted tide height in metres at an hour, from the static predictions
+    pub fn tide_height_at(&self, date: NaiveDate, hour: u8) -> Option<f32> {
+        self.tides_client.get_height_at_hour(date, hour)
+    }
+
+    /// Reacts to the system clock jumping (sleep/wake, manual or timezone change)
+    ///
+    /// If the date changed since the last refresh, hourly forecasts no longer
+    /// describe today and are cleared so best windows and hourly views don't
+    /// show yesterday's hours, and plans and sun sessions for past days are
+    /// dropped. A refresh
+    /// is always requested.
+    pub fn handle_clock_jump(&mut self, now: DateTime<Local>) {
+        let today = now.date_naive();
+        let data_is_from_another_day = self
+            .last_refresh
+            .is_some_and(|last| last.date_naive() != today);
+
+        if data_is_from_another_day {
+            for conditions in self.beach_conditions.values_mut() {
+                if let Some(weather) = conditions.weather.as_mut() {
+                    weather.hourly.clear();
                 }
             }
+            self.data_version += 1;
+            self.pinned_plans.retain(|plan| plan.date >= today);
+            self.sun_sessions
+                .retain(|session| session.started_at.date_naive() >= today);
         }
+
+        self.refresh_requested = true;
     }
 
     /// Moves the selection up in the list, wrapping to bottom if at top
     fn move_selection_up(&mut self) {
-        let count = self.beach_count();
+        let count = self.visible_beaches().len();
         if count == 0 {
             return;
         }
@@ -466,7 +2284,7 @@ impl App {
 
     /// Moves the selection down in the list, wrapping to top if at bottom
     fn move_selection_down(&mut self) {
-        let count = self.beach_count();
+        let count = self.visible_beaches().len();
         if count == 0 {
             return;
         }
@@ -495,9 +2313,14 @@ impl App {
         self.plan_cursor.0 = (self.plan_cursor.0 + 1) % count;
     }
 
+    /// Number of hour columns in the PlanTrip grid
+    fn plan_hour_count(&self) -> usize {
+        (self.plan_time_range.1 - self.plan_time_range.0 + 1) as usize
+    }
+
     /// Moves the plan cursor left (to previous hour), wrapping at start
     fn move_plan_cursor_left(&mut self) {
-        let hour_count = (self.plan_time_range.1 - self.plan_time_range.0 + 1) as usize;
+        let hour_count = self.plan_hour_count();
         if hour_count == 0 {
             return;
         }
@@ -510,28 +2333,115 @@ impl App {
 
     /// Moves the plan cursor right (to next hour), wrapping at end
     fn move_plan_cursor_right(&mut self) {
-        let hour_count = (self.plan_time_range.1 - self.plan_time_range.0 + 1) as usize;
+        let hour_count = self.plan_hour_count();
         if hour_count == 0 {
             return;
         }
         self.plan_cursor.1 = (self.plan_cursor.1 + 1) % hour_count;
     }
 
-    /// Gets the beach conditions for a specific beach ID
-    pub fn get_conditions(&self, beach_id: &str) -> Option<&BeachConditions> {
-        self.beach_conditions.get(beach_id)
+    /// Pans the PlanTrip grid a screen of hours right (or left), taking the
+    /// cursor along so it stays in the same column on screen; at either end
+    /// the cursor moves to the first or last hour instead
+    fn pan_plan_hours(&mut self, right: bool) {
+        let hour_count = self.plan_hour_count();
+        let page = self.plan_visible_hours.clamp(1, hour_count.max(1));
+        let max_offset = hour_count - page;
+        let offset = if right {
+            (self.plan_column_offset + page).min(max_offset)
+        } else {
+            self.plan_column_offset.saturating_sub(page)
+        };
+        if offset == self.plan_column_offset {
+            self.plan_cursor.1 = if right { hour_count - 1 } else { 0 };
+        } else {
+            let column = self.plan_cursor.1.saturating_sub(self.plan_column_offset);
+            self.plan_cursor.1 = (offset + column).min(hour_count - 1);
+            self.plan_column_offset = offset;
+        }
     }
 
-    /// Gets the conditions for the currently selected beach
-    #[allow(dead_code)]
-    pub fn get_selected_conditions(&self) -> Option<&BeachConditions> {
-        self.selected_beach()
-            .and_then(|beach| self.beach_conditions.get(beach.id))
+    /// Fits the PlanTrip grid's hours to `visible` columns, scrolling just
+    /// far enough to keep the cursor in view
+    ///
+    /// Called as the grid is drawn, since only then is its width known.
+    pub fn scroll_plan_hours(&mut self, visible: usize) {
+        let hour_count = self.plan_hour_count();
+        let visible = visible.clamp(1, hour_count.max(1));
+        self.plan_visible_hours = visible;
+        let cursor = self.plan_cursor.1;
+        if cursor < self.plan_column_offset {
+            self.plan_column_offset = cursor;
+        } else if cursor >= self.plan_column_offset + visible {
+            self.plan_column_offset = cursor + 1 - visible;
+        }
+        self.plan_column_offset = self.plan_column_offset.min(hour_count - visible);
     }
 
-    /// Scrolls up in the detail view with bounds checking
+    /// Scores for the PlanTrip grid under the current activity and hours
     ///
-    /// Decreases scroll offset by 1, stopping at 0.
+    /// The matrix is computed on first use and reused until the data, the
+    /// activity, the visible hours or the day change.
+    pub fn plan_scores(&self) -> Arc<ScoreMatrix> {
+        let key = MatrixKey {
+            activity: self.current_activity,
+            hours: self.plan_time_range,
+            data_version: self.data_version,
+            date: Local::now().date_naive(),
+        };
+        let mut cached = self
+            .plan_scores
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match cached.as_ref() {
+            Some(matrix) if matrix.key == key => Arc::clone(matrix),
+            _ => {
+                let matrix = Arc::new(ScoreMatrix::compute(&self.beach_conditions, key));
+                *cached = Some(Arc::clone(&matrix));
+                matrix
+            }
+        }
+    }
+
+    /// Counter bumped whenever the beach data changes, to tell when
+    /// anything derived from it is out of date
+    pub fn data_version(&self) -> u64 {
+        self.data_version
+    }
+
+    /// Gets the beach conditions for a specific beach ID
+    pub fn get_conditions(&self, beach_id: &str) -> Option<&BeachConditions> {
+        self.beach_conditions.get(beach_id)
+    }
+
+    /// Wind observed now at the station nearest a beach, if one reported
+    /// recently
+    pub fn observed_wind(&self, beach_id: &str, now: DateTime<Local>) -> Option<&WindObservation> {
+        let beach = get_beach_by_id(beach_id)?;
+        nearest_observation(&self.wind_observations, beach, now.with_timezone(&Utc))
+    }
+
+    /// How far the weather models agree on the wind in the current hour on
+    /// a beach's clock, with `--wind-models`
+    pub fn wind_reliability(&self, beach_id: &str, now: DateTime<Local>) -> Option<Reliability> {
+        let beach = get_beach_by_id(beach_id)?;
+        let local = beach.timezone.local(now.with_timezone(&Utc));
+        self.wind_models
+            .as_ref()?
+            .agreement_at(local.date(), local.hour() as u8)
+            .map(|agreement| agreement.reliability())
+    }
+
+    /// Gets the conditions for the currently selected beach
+    #[allow(dead_code)]
+    pub fn get_selected_conditions(&self) -> Option<&BeachConditions> {
+        self.selected_beach()
+            .and_then(|beach| self.beach_conditions.get(beach.id.as_str()))
+    }
+
+    /// Scrolls up in the detail view with bounds checking
+    ///
+    /// Decreases scroll offset by 1, stopping at 0.
     pub fn scroll_up(&mut self) {
         self.detail_scroll_offset = self.detail_scroll_offset.saturating_sub(1);
     }
@@ -579,24 +2489,29 @@ impl App {
 
     /// Finds the best beach for the current activity right now
     ///
-    /// Returns the best beach with a score >= 70, or None if no good options exist.
+    /// Returns the best beach with a score >= 70, or None if no good options
+    /// exist or it's outside the scored hours.
     pub fn find_best_beach_now(&self) -> Option<BestBeachNow> {
-        use crate::activities::get_profile;
-        use crate::crowd::estimate_crowd;
-        use chrono::{Datelike, Timelike};
-
         let activity = self.current_activity?;
         let now = chrono::Local::now();
         let current_hour = now.hour() as u8;
+        // Nowhere is worth heading to in the middle of the night
+        if crate::config::OutsideHours::of(current_hour).is_some() {
+            return None;
+        }
 
-        let profile = get_profile(activity);
         let beaches = all_beaches();
 
         let mut best: Option<BestBeachNow> = None;
         let mut best_score: u8 = 70; // Minimum threshold
 
         for beach in beaches {
-            let conditions = self.beach_conditions.get(beach.id)?;
+            let conditions = self.beach_conditions.get(beach.id.as_str())?;
+
+            // A closed beach scores zero, so it's never the best
+            if conditions.closure.is_some() {
+                continue;
+            }
 
             // Skip if water quality is stale for swimming
             if activity == crate::activities::Activity::Swimming {
@@ -609,8 +2524,12 @@ impl App {
 
             let weather = conditions.weather.as_ref()?;
             let temp = weather.temperature as f32;
-            let wind = weather.wind as f32;
+            let wind = conditions
+                .beach
+                .effective_wind(weather.wind, weather.wind_direction) as f32;
             let uv = weather.uv as f32;
+            let heat_warning = crate::heat::warning_high(conditions).is_some();
+            let profile = crate::heat::profile_for(activity, heat_warning);
 
             let water_status = conditions
                 .water_quality
@@ -624,11 +2543,17 @@ impl App {
                 .map(|t| (t.current_height as f32, 4.8f32))
                 .unwrap_or((2.4, 4.8));
 
-            let crowd = estimate_crowd(now.month(), now.weekday(), now.hour());
+            let crowd = crate::crowd::predict_at(
+                &beach.id,
+                now.date_naive(),
+                current_hour,
+                Some((weather.condition, weather.temperature)),
+            )
+            .level;
 
-            let score_result = profile.score_time_slot(
+            let mut score_result = profile.score_time_slot(
                 current_hour,
-                beach.id,
+                &beach.id,
                 temp,
                 wind,
                 uv,
@@ -638,6 +2563,11 @@ impl App {
                 crowd,
             );
 
+            if profile.shade_weight > 0.0 {
+                let exposure = crate::sun::sun_exposure(beach, now.with_timezone(&chrono::Utc));
+                profile.apply_sun_exposure(&mut score_result, exposure);
+            }
+
             if score_result.score > best_score {
                 best_score = score_result.score;
 
@@ -652,7 +2582,7 @@ impl App {
 
                 best = Some(BestBeachNow {
                     beach_name: beach.name.to_string(),
-                    beach_id: beach.id.to_string(),
+                    beach_id: beach.id.clone(),
                     score: score_result.score,
                     reasons,
                 });
@@ -663,6 +2593,75 @@ impl App {
     }
 }
 
+/// The middle of the shoreline, which stands in for all the beaches, with
+/// their timezone: (latitude, longitude, timezone)
+fn shoreline_center() -> Option<(f64, f64, &'static str)> {
+    let beaches = all_beaches();
+    let first = beaches.first()?;
+    let count = beaches.len() as f64;
+    let latitude = beaches.iter().map(|beach| beach.latitude).sum::<f64>() / count;
+    let longitude = beaches.iter().map(|beach| beach.longitude).sum::<f64>() / count;
+    Some((latitude, longitude, &first.timezone.name))
+}
+
+/// Fetches every model's wind for the middle of the shoreline
+async fn fetch_shoreline_wind_models(client: &WindModelsClient) -> Option<ModelWinds> {
+    let (latitude, longitude, timezone) = shoreline_center()?;
+    client
+        .fetch_wind_models(latitude, longitude, timezone)
+        .await
+        .ok()
+}
+
+/// Fetches the campfire bans in effect in the middle of the shoreline
+async fn fetch_shoreline_fire_bans(client: &FireBansClient) -> Option<FireBans> {
+    let (latitude, longitude, _) = shoreline_center()?;
+    client.fetch_fire_bans(latitude, longitude).await.ok()
+}
+
+/// Toast saying how refetching one feed went: refreshed, or left as it was
+/// when the API couldn't be reached and the fetch fell back on the old data
+fn feed_refresh_message(feed: Feed, fetch: &BeachFetch, requested_at: DateTime<Utc>) -> String {
+    let fetched = fetch.beaches.first();
+    let fetched_at = match feed {
+        Feed::Weather => fetched
+            .and_then(|f| f.weather.as_ref())
+            .map(|w| w.fetched_at),
+        Feed::Tides => fetch.tides.as_ref().map(|t| t.fetched_at),
+        Feed::WaterQuality => fetched
+            .and_then(|f| f.water_quality.as_ref())
+            .map(|wq| wq.fetched_at),
+    };
+    let beach = fetched.map_or("this beach", |f| f.beach.name.as_str());
+    if fetched_at.is_some_and(|at| at >= requested_at) {
+        format!("Refreshed {} for {}", feed.label(), beach)
+    } else {
+        format!(
+            "Couldn't refresh {} for {}; showing what was loaded before",
+            feed.label(),
+            beach
+        )
+    }
+}
+
+/// Fetches the hourly air quality for the middle of the shoreline
+async fn fetch_shoreline_air_quality(client: &AirQualityClient) -> Option<AirQuality> {
+    let (latitude, longitude, timezone) = shoreline_center()?;
+    client
+        .fetch_air_quality(latitude, longitude, timezone)
+        .await
+        .ok()
+}
+
+/// Where a beach's data is in a load that's still going
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadState {
+    /// Waiting its turn in the deferred queue
+    Queued,
+    /// Being fetched now
+    InFlight,
+}
+
 /// Information about the best beach right now
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -670,199 +2669,1568 @@ pub struct BestBeachNow {
     /// Name of the beach
     pub beach_name: String,
     /// ID of the beach
-    pub beach_id: String,
+    pub beach_id: BeachId,
     /// Activity score (0-100)
     pub score: u8,
     /// Reasons why this beach is recommended
     pub reasons: Vec<String>,
 }
 
-impl Default for App {
-    fn default() -> Self {
-        Self::new()
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activities::Activity;
+    use crate::data::{TideState, WaterQuality, Weather, WeatherClient};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    /// Helper to create a KeyEvent for testing
+    fn key_event(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    // ========================================================================
+    // PlanTrip State and Activity Tests (Task 017)
+    // ========================================================================
+
+    #[test]
+    fn test_app_state_plan_trip_variant_exists() {
+        // AppState::PlanTrip should exist as a variant
+        let state = AppState::PlanTrip;
+        assert_eq!(state, AppState::PlanTrip);
+    }
+
+    #[test]
+    fn test_app_has_current_activity_field() {
+        let app = App::new();
+        // current_activity should be None initially
+        assert!(app.current_activity.is_none());
+    }
+
+    #[test]
+    fn test_app_has_plan_cursor_field() {
+        let app = App::new();
+        // plan_cursor should be (0, 0) initially - (beach_index, hour_index)
+        assert_eq!(app.plan_cursor, (0, 0));
+    }
+
+    #[test]
+    fn test_app_has_plan_time_range_field() {
+        let app = App::new();
+        // plan_time_range should be (6, 21) by default - 6am to 9pm
+        assert_eq!(app.plan_time_range, (6, 21));
+    }
+
+    #[test]
+    fn test_key_p_in_beach_list_transitions_to_plan_trip() {
+        let mut app = App::new();
+        app.state = AppState::BeachList;
+
+        app.handle_key(key_event(KeyCode::Char('p')));
+
+        assert_eq!(app.state, AppState::PlanTrip);
+    }
+
+    #[test]
+    fn test_key_p_in_beach_detail_transitions_to_plan_trip() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        app.handle_key(key_event(KeyCode::Char('p')));
+
+        assert_eq!(app.state, AppState::PlanTrip);
+    }
+
+    #[test]
+    fn test_key_1_in_beach_detail_sets_swimming_activity() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        app.handle_key(key_event(KeyCode::Char('1')));
+
+        assert_eq!(app.current_activity, Some(Activity::Swimming));
+    }
+
+    #[test]
+    fn test_key_2_in_beach_detail_sets_sunbathing_activity() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        app.handle_key(key_event(KeyCode::Char('2')));
+
+        assert_eq!(app.current_activity, Some(Activity::Sunbathing));
+    }
+
+    #[test]
+    fn test_key_3_in_beach_detail_sets_sailing_activity() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        app.handle_key(key_event(KeyCode::Char('3')));
+
+        assert_eq!(app.current_activity, Some(Activity::Sailing));
+    }
+
+    #[test]
+    fn test_key_4_in_beach_detail_sets_sunset_activity() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        app.handle_key(key_event(KeyCode::Char('4')));
+
+        assert_eq!(app.current_activity, Some(Activity::Sunset));
+    }
+
+    #[test]
+    fn test_key_5_in_beach_detail_sets_peace_activity() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        app.handle_key(key_event(KeyCode::Char('5')));
+
+        assert_eq!(app.current_activity, Some(Activity::Peace));
+    }
+
+    #[test]
+    fn test_key_6_in_beach_detail_sets_dog_walk_activity() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        app.handle_key(key_event(KeyCode::Char('6')));
+
+        assert_eq!(app.current_activity, Some(Activity::DogWalk));
+    }
+
+    #[test]
+    fn test_quick_jump_opens_the_numbered_beach() {
+        let mut app = App::new();
+        app.state = AppState::BeachList;
+        let beaches = all_beaches();
+
+        // No beach number starts with 3 and another digit, so it goes at once
+        app.handle_key(key_event(KeyCode::Char('g')));
+        app.handle_key(key_event(KeyCode::Char('3')));
+        assert_eq!(app.state, AppState::BeachDetail(beaches[2].id.clone()));
+        assert_eq!(app.selected_index, 2);
+        assert_eq!(app.jump, None);
+
+        // 1 could be the start of 10-12: two digits go at once
+        app.state = AppState::BeachList;
+        app.handle_key(key_event(KeyCode::Char('g')));
+        app.handle_key(key_event(KeyCode::Char('1')));
+        assert_eq!(app.state, AppState::BeachList);
+        assert_eq!(app.current_activity, None);
+        app.handle_key(key_event(KeyCode::Char('2')));
+        assert_eq!(app.state, AppState::BeachDetail(beaches[11].id.clone()));
+
+        // ...and one digit goes once the timeout runs out
+        app.state = AppState::BeachList;
+        app.handle_key(key_event(KeyCode::Char('g')));
+        app.handle_key(key_event(KeyCode::Char('1')));
+        let expires_at = app.jump.as_ref().unwrap().expires_at;
+        app.expire_jump(expires_at - Duration::milliseconds(1));
+        assert_eq!(app.state, AppState::BeachList);
+        app.expire_jump(expires_at);
+        assert_eq!(app.state, AppState::BeachDetail(beaches[0].id.clone()));
+        assert_eq!(app.jump, None);
+    }
+
+    #[test]
+    fn test_quick_jump_is_cancelled_by_other_keys_and_unknown_numbers() {
+        let mut app = App::new();
+        app.state = AppState::BeachList;
+        app.selected_index = 4;
+
+        app.handle_key(key_event(KeyCode::Char('g')));
+        app.handle_key(key_event(KeyCode::Esc));
+        assert_eq!(app.jump, None);
+        assert!(!app.should_quit);
+
+        app.handle_key(key_event(KeyCode::Char('g')));
+        app.handle_key(key_event(KeyCode::Char('0')));
+        assert_eq!(app.jump, None);
+
+        app.handle_key(key_event(KeyCode::Char('g')));
+        app.handle_key(key_event(KeyCode::Char('1')));
+        app.handle_key(key_event(KeyCode::Char('9')));
+        assert_eq!(app.jump, None);
+        assert_eq!(app.state, AppState::BeachList);
+        assert_eq!(app.selected_index, 4);
+
+        // With nothing typed the timeout just drops the jump
+        app.handle_key(key_event(KeyCode::Char('g')));
+        app.expire_jump(Local::now() + Duration::seconds(2));
+        assert_eq!(app.jump, None);
+        assert_eq!(app.state, AppState::BeachList);
+    }
+
+    #[test]
+    fn test_multi_select_adds_secondary_activity() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        app.handle_key(key_event(KeyCode::Char('1')));
+        app.handle_key(key_event(KeyCode::Char('m')));
+        assert!(app.multi_select);
+
+        app.handle_key(key_event(KeyCode::Char('4')));
+        assert_eq!(app.current_activity, Some(Activity::Swimming));
+        assert_eq!(app.secondary_activity, Some(Activity::Sunset));
+
+        // Pressing the same key again removes it
+        app.handle_key(key_event(KeyCode::Char('4')));
+        assert_eq!(app.secondary_activity, None);
+    }
+
+    #[test]
+    fn test_multi_select_off_replaces_and_clears_secondary() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+        app.current_activity = Some(Activity::Swimming);
+        app.secondary_activity = Some(Activity::Sunset);
+        app.multi_select = true;
+
+        app.handle_key(key_event(KeyCode::Char('m')));
+        assert!(!app.multi_select);
+        assert_eq!(app.secondary_activity, None);
+
+        app.secondary_activity = Some(Activity::Sunset);
+        app.handle_key(key_event(KeyCode::Char('3')));
+        assert_eq!(app.current_activity, Some(Activity::Sailing));
+        assert_eq!(app.secondary_activity, None);
+    }
+
+    #[test]
+    fn test_multi_select_without_primary_sets_primary() {
+        let mut app = App::new();
+        app.multi_select = true;
+
+        app.select_activity(Activity::Peace);
+
+        assert_eq!(app.current_activity, Some(Activity::Peace));
+        assert_eq!(app.secondary_activity, None);
+    }
+
+    #[test]
+    fn test_esc_in_plan_trip_returns_to_beach_list() {
+        let mut app = App::new();
+        app.state = AppState::PlanTrip;
+
+        app.handle_key(key_event(KeyCode::Esc));
+
+        assert_eq!(app.state, AppState::BeachList);
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_activity_persists_when_navigating_to_plan_trip() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        // Set activity
+        app.handle_key(key_event(KeyCode::Char('1')));
+        assert_eq!(app.current_activity, Some(Activity::Swimming));
+
+        // Navigate to PlanTrip
+        app.handle_key(key_event(KeyCode::Char('p')));
+        assert_eq!(app.state, AppState::PlanTrip);
+
+        // Activity should still be set
+        assert_eq!(app.current_activity, Some(Activity::Swimming));
+    }
+
+    #[test]
+    fn test_activity_persists_when_returning_from_plan_trip() {
+        let mut app = App::new();
+        app.current_activity = Some(Activity::Sailing);
+        app.state = AppState::PlanTrip;
+
+        // Go back to beach list
+        app.handle_key(key_event(KeyCode::Esc));
+
+        // Activity should still be set
+        assert_eq!(app.current_activity, Some(Activity::Sailing));
+    }
+
+    #[test]
+    fn test_activity_persists_when_navigating_between_beaches() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+        app.current_activity = Some(Activity::Sunbathing);
+
+        // Go back to list
+        app.handle_key(key_event(KeyCode::Esc));
+        assert_eq!(app.current_activity, Some(Activity::Sunbathing));
+
+        // Select another beach
+        app.handle_key(key_event(KeyCode::Down));
+        app.handle_key(key_event(KeyCode::Enter));
+
+        // Activity should persist
+        assert_eq!(app.current_activity, Some(Activity::Sunbathing));
+    }
+
+    #[test]
+    fn test_c_toggles_crowd_rows_in_plan_trip() {
+        let mut app = App::new();
+        app.state = AppState::PlanTrip;
+        assert!(!app.plan_show_crowds);
+
+        app.handle_key(key_event(KeyCode::Char('c')));
+        assert!(app.plan_show_crowds);
+        app.handle_key(key_event(KeyCode::Char('c')));
+        assert!(!app.plan_show_crowds);
+    }
+
+    #[test]
+    fn test_q_quits_from_plan_trip() {
+        let mut app = App::new();
+        app.state = AppState::PlanTrip;
+        assert!(!app.should_quit);
+
+        app.handle_key(key_event(KeyCode::Char('q')));
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_tab_cycles_activity_in_plan_trip() {
+        let mut app = App::new();
+        app.state = AppState::PlanTrip;
+
+        app.handle_key(key_event(KeyCode::Tab));
+        assert_eq!(app.current_activity, Some(Activity::Swimming));
+
+        app.current_activity = Some(Activity::Peace);
+        app.handle_key(key_event(KeyCode::Tab));
+        assert_eq!(app.current_activity, Some(Activity::DogWalk));
+        app.handle_key(key_event(KeyCode::Tab));
+        assert_eq!(app.current_activity, Some(Activity::Surf));
+        app.handle_key(key_event(KeyCode::Tab));
+        assert_eq!(app.current_activity, Some(Activity::RunWalk));
+        app.handle_key(key_event(KeyCode::Tab));
+        assert_eq!(app.current_activity, Some(Activity::Swimming));
+        assert_eq!(app.state, AppState::PlanTrip);
+    }
+
+    #[test]
+    fn test_low_bandwidth_skips_waves() {
+        let mut app = App::new();
+        let wreck = get_beach_by_id("wreck").unwrap();
+        assert!(app.fetches_waves(wreck));
+        assert!(!app.fetches_waves(get_beach_by_id("kitsilano").unwrap()));
+
+        app.enable_low_bandwidth();
+        assert!(app.low_bandwidth);
+        assert!(!app.fetches_waves(wreck));
+    }
+
+    #[test]
+    fn test_surf_is_only_offered_at_beaches_open_to_swell() {
+        let mut app = App::new();
+        for id in ["wreck", "kitsilano"] {
+            let beach = get_beach_by_id(id).unwrap().clone();
+            app.beach_conditions.insert(
+                beach.id.clone(),
+                BeachConditions {
+                    beach,
+                    weather: None,
+                    tides: None,
+                    water_quality: None,
+                    closure: None,
+                    waves: None,
+                    air_quality: None,
+                    seawall_closure: None,
+                },
+            );
+        }
+        app.current_activity = Some(Activity::Surf);
+        assert_eq!(app.unsuitable_reason("wreck"), None);
+        assert!(app
+            .unsuitable_reason("kitsilano")
+            .unwrap()
+            .contains("sheltered"));
+
+        // The detail view's selector leaves Surf out where there's none
+        app.current_activity = None;
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+        app.handle_key(key_event(KeyCode::Char('7')));
+        assert_eq!(app.current_activity, None);
+        app.state = AppState::BeachDetail(BeachId::new("wreck"));
+        app.handle_key(key_event(KeyCode::Char('7')));
+        assert_eq!(app.current_activity, Some(Activity::Surf));
+    }
+
+    #[test]
+    fn test_space_pins_and_unpins_cell_in_plan_trip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let mut app = App::new();
+        app.user_store = Some(store.clone());
+        app.pinned_plans.clear();
+        app.state = AppState::PlanTrip;
+        app.current_activity = Some(Activity::Sunset);
+        app.plan_cursor = (0, 3); // First beach, 9am
+
+        app.handle_key(key_event(KeyCode::Char(' ')));
+        let beach_id = all_beaches()[0].id.to_string();
+        assert!(app.is_pinned(&beach_id, 9));
+        assert_eq!(app.pinned_plans[0].activity, Some(Activity::Sunset));
+
+        // Pinned plans are persisted
+        let saved = plans::load_plans(&store, Local::now().date_naive());
+        assert_eq!(saved, app.pinned_plans);
+
+        app.handle_key(key_event(KeyCode::Char(' ')));
+        assert!(!app.is_pinned(&beach_id, 9));
+        assert!(plans::load_plans(&store, Local::now().date_naive()).is_empty());
+    }
+
+    #[test]
+    fn test_u_starts_and_stops_a_sun_session() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let mut app = App::new();
+        app.user_store = Some(store.clone());
+        app.sun_sessions.clear();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        app.handle_key(key_event(KeyCode::Char('u')));
+        let running = app.running_sun_session().unwrap();
+        assert_eq!(running.beach_id, "kitsilano");
+
+        // Sessions are persisted for the day
+        let saved = uv_exposure::load_sessions(&store, Local::now().date_naive());
+        assert_eq!(saved, app.sun_sessions);
+
+        app.handle_key(key_event(KeyCode::Char('u')));
+        assert!(app.running_sun_session().is_none());
+        assert_eq!(app.sun_sessions.len(), 1);
+        let saved = uv_exposure::load_sessions(&store, Local::now().date_naive());
+        assert!(saved[0].ended_at.is_some());
+    }
+
+    #[test]
+    fn test_uv_dose_warns_once_per_level() {
+        use crate::data::{
+            DataQuality, HourlyForecast, Weather, WeatherCondition, WeatherProvider,
+        };
+        use chrono::{NaiveTime, TimeZone};
+
+        let mut app = App::new();
+        app.user_store = None;
+        let today = Local::now().date_naive();
+        let at = |hour, minute| {
+            Local
+                .from_local_datetime(&today.and_hms_opt(hour, minute, 0).unwrap())
+                .earliest()
+                .unwrap()
+        };
+        let beach = all_beaches()[0].clone();
+        let hourly = (0..24)
+            .map(|hour| HourlyForecast {
+                hour,
+                temperature: 25.0,
+                feels_like: 25.0,
+                condition: WeatherCondition::Clear,
+                wind: 5.0,
+                wind_direction: "W".to_string(),
+                uv: 1.0,
+                precipitation_chance: 0,
+                dew_point: None,
+                wind_gusts: None,
+                cloud_cover: None,
+                data_quality: DataQuality::Reported,
+            })
+            .collect();
+        app.beach_conditions.insert(
+            beach.id.clone(),
+            BeachConditions {
+                beach: beach.clone(),
+                weather: Some(Weather {
+                    temperature: 25.0,
+                    feels_like: 25.0,
+                    condition: WeatherCondition::Clear,
+                    humidity: 50,
+                    wind: 5.0,
+                    wind_direction: None,
+                    uv: 1.0,
+                    sunrise: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+                    sunset: NaiveTime::from_hms_opt(20, 0, 0).unwrap(),
+                    fetched_at: chrono::Utc::now(),
+                    hourly,
+                    tomorrow: Vec::new(),
+                    source: WeatherProvider::OpenMeteo,
+                    parse_warnings: Vec::new(),
+                    weather_code: None,
+                }),
+                tides: None,
+                water_quality: None,
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+        app.sun_sessions = vec![SunSession::start(beach.id.clone(), at(12, 0))];
+
+        // 2h at UV 1 is 1.8 SED, under 80% of the 2.5 SED limit
+        app.update(AppEvent::Tick(at(14, 0)));
+        assert!(app.toast.is_none());
+
+        app.update(AppEvent::Tick(at(14, 15)));
+        assert!(app.toast.take().unwrap().message.contains("2.0 of 2.5 SED"));
+        app.update(AppEvent::Tick(at(14, 30)));
+        assert!(app.toast.is_none());
+
+        app.update(AppEvent::Tick(at(14, 50)));
+        assert!(app.toast.take().unwrap().message.contains("reached"));
+        let exposure = app.uv_exposure(at(14, 50)).unwrap();
+        assert_eq!(exposure.running_for, Some(Duration::minutes(170)));
+    }
+
+    #[test]
+    fn test_last_week_snapshot_is_loaded_for_the_current_hour() {
+        use crate::data::{WeatherCondition, WeatherProvider};
+        use chrono::NaiveTime;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let mut app = App::new();
+        app.user_store = Some(store.clone());
+        let beach = all_beaches()[0].clone();
+        let mut conditions = BeachConditions {
+            beach: beach.clone(),
+            weather: Some(Weather {
+                temperature: 17.0,
+                feels_like: 17.0,
+                condition: WeatherCondition::Clear,
+                humidity: 50,
+                wind: 5.0,
+                wind_direction: None,
+                uv: 4.0,
+                sunrise: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(20, 0, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
+            }),
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        };
+        let now = Local::now();
+        history::record(&store, &conditions, now - Duration::days(7)).unwrap();
+
+        conditions.weather.as_mut().unwrap().temperature = 24.0;
+        app.beach_conditions.insert(beach.id.clone(), conditions);
+        app.update_history(now);
+
+        let snapshot = app.last_week_snapshot(&beach.id, now).unwrap();
+        assert_eq!(snapshot.temperature, Some(17.0));
+        assert!(app
+            .last_week_snapshot(&beach.id, now + Duration::hours(1))
+            .is_none());
+        assert!(app.last_week_snapshot(&all_beaches()[1].id, now).is_none());
+    }
+
+    #[test]
+    fn test_best_day_this_week_looks_across_beaches() {
+        let mut app = App::new();
+        let beaches = all_beaches();
+        assert!(app.best_day_this_week(Activity::Swimming).is_none());
+
+        let tuesday = NaiveDate::from_ymd_opt(2026, 7, 14).unwrap();
+        for (beach, score) in [(&beaches[0], 72), (&beaches[1], 91), (&beaches[2], 91)] {
+            let snapshot = HourSnapshot {
+                temperature: None,
+                water_status: None,
+                scores: vec![(Activity::Swimming, score)],
+            };
+            let day = DayHistory {
+                date: tuesday,
+                hours: [(14, snapshot)].into(),
+            };
+            app.week_so_far.insert(beach.id.clone(), vec![day]);
+        }
+
+        let (beach, date, score) = app.best_day_this_week(Activity::Swimming).unwrap();
+        assert_eq!(beach.id, beaches[1].id, "ties go to the earlier beach");
+        assert_eq!((date, score), (tuesday, 91));
+        assert!(app.best_day_this_week(Activity::Sailing).is_none());
+    }
+
+    #[test]
+    fn test_f_toggles_favorite_and_d_opens_dashboard() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let mut app = App::new();
+        app.user_store = Some(store.clone());
+        app.favorites.clear();
+        app.state = AppState::BeachList;
+        app.selected_index = 1;
+
+        app.handle_key(key_event(KeyCode::Char('f')));
+        let beach = all_beaches()[1].clone();
+        assert!(app.is_favorite(&beach.id));
+        assert_eq!(favorites::load_favorites(&store), vec![beach.id.clone()]);
+
+        app.handle_key(key_event(KeyCode::Char('d')));
+        assert_eq!(app.state, AppState::Dashboard);
+        let shown: Vec<_> = app.dashboard_beaches().iter().map(|b| &b.id).collect();
+        assert_eq!(shown, vec![&beach.id]);
+
+        app.handle_key(key_event(KeyCode::Esc));
+        assert_eq!(app.state, AppState::BeachList);
+        app.handle_key(key_event(KeyCode::Char('f')));
+        assert!(!app.is_favorite(&beach.id));
+    }
+
+    #[test]
+    fn test_journal_opens_from_the_list_and_scrolls_to_the_oldest_entry() {
+        let mut app = App::new();
+        app.state = AppState::BeachList;
+        let entry = crate::journal::JournalEntry {
+            at: Utc::now(),
+            day: Local::now().date_naive(),
+            kind: crate::journal::JournalKind::HeatWarning,
+            beach_id: None,
+            message: "Heat warning".to_string(),
+        };
+        app.journal = vec![entry.clone(), entry.clone(), entry];
+
+        app.handle_key(key_event(KeyCode::Char('L')));
+        assert_eq!(app.state, AppState::Journal);
+        app.handle_key(key_event(KeyCode::Char('G')));
+        assert_eq!(app.journal_scroll, 2);
+        app.handle_key(key_event(KeyCode::Down));
+        assert_eq!(app.journal_scroll, 2, "stops at the oldest entry");
+        app.handle_key(key_event(KeyCode::Char('k')));
+        assert_eq!(app.journal_scroll, 1);
+
+        app.handle_key(key_event(KeyCode::Esc));
+        assert_eq!(app.state, AppState::BeachList);
+        app.handle_key(key_event(KeyCode::Char('L')));
+        assert_eq!(app.journal_scroll, 0, "reopens at the newest entry");
+    }
+
+    #[test]
+    fn test_shift_j_and_k_move_the_selected_beach_in_a_custom_order() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let mut app = App::new();
+        app.user_store = Some(store.clone());
+        app.beach_order = BeachOrder::default();
+        app.state = AppState::BeachList;
+        let beaches = all_beaches();
+        let shown = |app: &App| -> Vec<BeachId> {
+            app.visible_beaches()
+                .iter()
+                .take(3)
+                .map(|beach| beach.id.clone())
+                .collect()
+        };
+
+        app.handle_key(key_event(KeyCode::Char('J')));
+        assert!(app.beach_order.custom);
+        assert_eq!(
+            shown(&app),
+            vec![
+                beaches[1].id.clone(),
+                beaches[0].id.clone(),
+                beaches[2].id.clone()
+            ]
+        );
+        assert_eq!(app.selected_index, 1, "the moved beach stays selected");
+        app.handle_key(key_event(KeyCode::Char('J')));
+        assert_eq!(app.selected_index, 2);
+        assert_eq!(beach_order::load_beach_order(&store), app.beach_order);
+
+        // Back to the registry order and again, keeping the selection
+        app.handle_key(key_event(KeyCode::Char('o')));
+        assert_eq!(
+            shown(&app)[..2],
+            [beaches[0].id.clone(), beaches[1].id.clone()]
+        );
+        assert_eq!(app.selected_beach().unwrap().id, beaches[0].id);
+        app.handle_key(key_event(KeyCode::Char('o')));
+        assert_eq!(app.selected_index, 2);
+
+        app.handle_key(key_event(KeyCode::Char('K')));
+        app.handle_key(key_event(KeyCode::Char('K')));
+        app.handle_key(key_event(KeyCode::Char('K')));
+        assert_eq!(app.selected_index, 0, "stops at the top");
+        assert_eq!(shown(&app)[0], beaches[0].id);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::activities::Activity;
-    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    #[test]
+    fn test_debug_view_needs_verbose_and_captures_keys() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(all_beaches()[2].id.clone());
 
-    /// Helper to create a KeyEvent for testing
-    fn key_event(code: KeyCode) -> KeyEvent {
-        KeyEvent::new(code, KeyModifiers::NONE)
-    }
+        app.handle_key(key_event(KeyCode::Char('D')));
+        assert!(!app.show_debug_view, "D does nothing without --verbose");
 
-    // ========================================================================
-    // PlanTrip State and Activity Tests (Task 017)
-    // ========================================================================
+        app.enable_debug_log();
+        app.handle_key(key_event(KeyCode::Char('D')));
+        assert!(app.show_debug_view);
+        assert_eq!(app.focused_beach().unwrap().id, all_beaches()[2].id);
 
-    #[test]
-    fn test_app_state_plan_trip_variant_exists() {
-        // AppState::PlanTrip should exist as a variant
-        let state = AppState::PlanTrip;
-        assert_eq!(state, AppState::PlanTrip);
+        app.handle_key(key_event(KeyCode::Char('j')));
+        assert_eq!(app.debug_scroll_offset, 1);
+        assert_eq!(
+            app.detail_scroll_offset, 0,
+            "scrolls the viewer, not the page"
+        );
+
+        app.handle_key(key_event(KeyCode::Esc));
+        assert!(!app.show_debug_view);
+        assert!(matches!(app.state, AppState::BeachDetail(_)));
     }
 
     #[test]
-    fn test_app_has_current_activity_field() {
-        let app = App::new();
-        // current_activity should be None initially
-        assert!(app.current_activity.is_none());
+    fn test_help_search_filters_and_captures_keys() {
+        let mut app = App::new();
+        app.state = AppState::BeachList;
+
+        app.handle_key(key_event(KeyCode::Char('?')));
+        assert!(app.show_help);
+        app.handle_key(key_event(KeyCode::Char('j')));
+        assert_eq!(app.help.scroll, 1);
+
+        app.handle_key(key_event(KeyCode::Char('/')));
+        for c in "quit".chars() {
+            app.handle_key(key_event(KeyCode::Char(c)));
+        }
+        assert!(app.show_help, "q is typed into the search");
+        assert!(!app.should_quit);
+        assert_eq!(app.help.query, "quit");
+        assert_eq!(app.help.scroll, 0);
+
+        app.handle_key(key_event(KeyCode::Enter));
+        assert!(!app.help.searching);
+        app.handle_key(key_event(KeyCode::Char('G')));
+        assert_eq!(
+            app.help.scroll as usize,
+            keymap::reference_len("quit") - 1,
+            "stops at the last line"
+        );
+
+        app.handle_key(key_event(KeyCode::Esc));
+        assert!(!app.show_help);
+        app.handle_key(key_event(KeyCode::Char('?')));
+        assert_eq!(app.help, HelpView::default(), "reopens unfiltered");
     }
 
     #[test]
-    fn test_app_has_plan_cursor_field() {
-        let app = App::new();
-        // plan_cursor should be (0, 0) initially - (beach_index, hour_index)
-        assert_eq!(app.plan_cursor, (0, 0));
+    fn test_tick_recomputes_tides_once_per_minute() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let at = |hour, minute, second| {
+            Local
+                .from_local_datetime(
+                    &NaiveDate::from_ymd_opt(2026, 1, 1)
+                        .unwrap()
+                        .and_hms_opt(hour, minute, second)
+                        .unwrap(),
+                )
+                .single()
+                .unwrap()
+        };
+        let fetched_at = Utc::now() - Duration::hours(3);
+
+        let mut app = App::new();
+        let beach = all_beaches()[0].clone();
+        app.beach_conditions.insert(
+            beach.id.clone(),
+            BeachConditions {
+                beach: beach.clone(),
+                weather: None,
+                tides: Some(TideInfo {
+                    current_height: 2.0,
+                    tide_state: TideState::Rising,
+                    next_high: None,
+                    next_low: None,
+                    fetched_at,
+                }),
+                water_quality: None,
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+
+        assert!(app.tick(at(5, 0, 10)));
+        let tides = app
+            .get_conditions(&beach.id)
+            .unwrap()
+            .tides
+            .clone()
+            .unwrap();
+        assert_eq!(tides.tide_state, TideState::Falling);
+        assert!(tides.next_low.is_some());
+        assert_eq!(tides.fetched_at, fetched_at, "tick is not a refresh");
+
+        // Same minute: nothing to do
+        assert!(!app.tick(at(5, 0, 50)));
+        assert!(app.tick(at(5, 1, 0)));
+        let later = app
+            .get_conditions(&beach.id)
+            .unwrap()
+            .tides
+            .clone()
+            .unwrap();
+        assert!(later.current_height < tides.current_height);
     }
 
     #[test]
-    fn test_app_has_plan_time_range_field() {
-        let app = App::new();
-        // plan_time_range should be (6, 21) by default - 6am to 9pm
-        assert_eq!(app.plan_time_range, (6, 21));
+    fn test_clock_jump_to_a_new_day_clears_hourly_data_and_old_plans() {
+        use crate::data::{
+            DataQuality, HourlyForecast, Weather, WeatherCondition, WeatherProvider,
+        };
+        use chrono::NaiveTime;
+
+        let mut app = App::new();
+        let now = Local::now();
+        let yesterday = now - Duration::days(1);
+        let beach = all_beaches()[0].clone();
+        app.beach_conditions.insert(
+            beach.id.clone(),
+            BeachConditions {
+                beach: beach.clone(),
+                weather: Some(Weather {
+                    temperature: 20.0,
+                    feels_like: 20.0,
+                    condition: WeatherCondition::Clear,
+                    humidity: 50,
+                    wind: 5.0,
+                    wind_direction: None,
+                    uv: 4.0,
+                    sunrise: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+                    sunset: NaiveTime::from_hms_opt(20, 0, 0).unwrap(),
+                    fetched_at: yesterday.with_timezone(&chrono::Utc),
+                    hourly: vec![HourlyForecast {
+                        hour: 12,
+                        temperature: 20.0,
+                        feels_like: 20.0,
+                        condition: WeatherCondition::Clear,
+                        wind: 5.0,
+                        wind_direction: "W".to_string(),
+                        uv: 4.0,
+                        precipitation_chance: 0,
+                        dew_point: None,
+                        wind_gusts: None,
+                        cloud_cover: None,
+                        data_quality: DataQuality::Reported,
+                    }],
+                    tomorrow: Vec::new(),
+                    source: WeatherProvider::OpenMeteo,
+                    parse_warnings: Vec::new(),
+                    weather_code: None,
+                }),
+                tides: None,
+                water_quality: None,
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+        app.pinned_plans = vec![
+            PinnedPlan::new(beach.id.clone(), yesterday.date_naive(), 12, None),
+            PinnedPlan::new(beach.id.clone(), now.date_naive(), 12, None),
+        ];
+
+        // Same-day jump only asks for a refresh
+        app.last_refresh = Some(now);
+        app.handle_clock_jump(now);
+        assert!(app.refresh_requested);
+        assert_eq!(app.pinned_plans.len(), 2);
+
+        app.refresh_requested = false;
+        app.last_refresh = Some(yesterday);
+        app.handle_clock_jump(now);
+
+        assert!(app.refresh_requested);
+        let weather = app.get_conditions(&beach.id).unwrap().weather.as_ref();
+        assert!(weather.unwrap().hourly.is_empty());
+        assert_eq!(app.pinned_plans.len(), 1);
+        assert_eq!(app.pinned_plans[0].date, now.date_naive());
     }
 
     #[test]
-    fn test_key_p_in_beach_list_transitions_to_plan_trip() {
+    fn test_c_hides_closed_beaches_and_keeps_selection() {
+        use crate::data::WaterStatus;
+
         let mut app = App::new();
         app.state = AppState::BeachList;
+        let beaches = all_beaches();
+        app.beach_conditions.insert(
+            beaches[1].id.clone(),
+            BeachConditions {
+                beach: beaches[1].clone(),
+                weather: None,
+                tides: None,
+                water_quality: Some(WaterQuality {
+                    status: WaterStatus::Closed,
+                    ecoli_count: Some(900),
+                    sample_date: Local::now().date_naive(),
+                    advisory_reason: None,
+                    fetched_at: chrono::Utc::now(),
+                    no_samples_station: None,
+                    sampling: None,
+                }),
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+        app.selected_index = 2;
 
-        app.handle_key(key_event(KeyCode::Char('p')));
+        app.handle_key(key_event(KeyCode::Char('c')));
+        assert!(app.hide_closed);
+        assert_eq!(app.visible_beaches().len(), beaches.len() - 1);
+        assert_eq!(app.selected_beach().unwrap().id, beaches[2].id);
 
-        assert_eq!(app.state, AppState::PlanTrip);
+        // Navigation skips the hidden beach
+        app.handle_key(key_event(KeyCode::Up));
+        assert_eq!(app.selected_beach().unwrap().id, beaches[0].id);
+        app.handle_key(key_event(KeyCode::Down));
+        assert_eq!(app.selected_beach().unwrap().id, beaches[2].id);
+
+        app.handle_key(key_event(KeyCode::Char('c')));
+        assert!(!app.hide_closed);
+        assert_eq!(app.selected_beach().unwrap().id, beaches[2].id);
     }
 
     #[test]
-    fn test_key_p_in_beach_detail_transitions_to_plan_trip() {
+    fn test_s_hides_beaches_unsuitable_for_the_activity() {
+        use crate::data::WaterStatus;
+
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachList;
+        let beaches = all_beaches();
+        app.beach_conditions.insert(
+            beaches[1].id.clone(),
+            BeachConditions {
+                beach: beaches[1].clone(),
+                weather: None,
+                tides: None,
+                water_quality: Some(WaterQuality {
+                    status: WaterStatus::Advisory,
+                    ecoli_count: Some(300),
+                    sample_date: Local::now().date_naive(),
+                    advisory_reason: None,
+                    fetched_at: chrono::Utc::now(),
+                    no_samples_station: None,
+                    sampling: None,
+                }),
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+        app.selected_index = 2;
 
-        app.handle_key(key_event(KeyCode::Char('p')));
+        // Without an activity nothing is ruled out
+        app.handle_key(key_event(KeyCode::Char('s')));
+        assert!(app.hide_unsuitable);
+        assert_eq!(app.visible_beaches().len(), beaches.len());
+        assert_eq!(app.unsuitable_hidden_count(), 0);
 
-        assert_eq!(app.state, AppState::PlanTrip);
+        app.handle_key(key_event(KeyCode::Char('1')));
+        assert_eq!(app.visible_beaches().len(), beaches.len() - 1);
+        assert_eq!(app.unsuitable_hidden_count(), 1);
+        assert_eq!(app.selected_beach().unwrap().id, beaches[2].id);
+
+        // An advisory doesn't rule out sailing
+        app.handle_key(key_event(KeyCode::Char('3')));
+        assert_eq!(app.visible_beaches().len(), beaches.len());
+        assert_eq!(app.selected_beach().unwrap().id, beaches[2].id);
+
+        app.handle_key(key_event(KeyCode::Char('1')));
+        app.handle_key(key_event(KeyCode::Char('s')));
+        assert!(!app.hide_unsuitable);
+        assert_eq!(app.visible_beaches().len(), beaches.len());
+        assert_eq!(app.selected_beach().unwrap().id, beaches[2].id);
     }
 
     #[test]
-    fn test_key_1_in_beach_detail_sets_swimming_activity() {
+    fn test_park_board_closure_rules_out_every_activity() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
-
-        app.handle_key(key_event(KeyCode::Char('1')));
+        let beach = all_beaches()[0].clone();
+        app.beach_conditions.insert(
+            beach.id.clone(),
+            BeachConditions {
+                beach: beach.clone(),
+                weather: None,
+                tides: None,
+                water_quality: None,
+                closure: Some(crate::data::Closure {
+                    reason: "Oil sheen".to_string(),
+                    until: None,
+                    fetched_at: chrono::Utc::now(),
+                }),
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
 
-        assert_eq!(app.current_activity, Some(Activity::Swimming));
+        assert!(app.is_closed(&beach.id));
+        for activity in Activity::all() {
+            app.current_activity = Some(*activity);
+            assert_eq!(
+                app.unsuitable_reason(&beach.id).as_deref(),
+                Some("Beach closed: Oil sheen")
+            );
+        }
     }
 
     #[test]
-    fn test_key_2_in_beach_detail_sets_sunbathing_activity() {
+    fn test_comparison_sets_are_named_cycled_and_deleted_on_the_dashboard() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = CacheManager::with_dir(temp_dir.path().to_path_buf());
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.user_store = Some(store.clone());
+        app.comparison_sets.clear();
+        let beaches = all_beaches();
+        app.favorites = vec![beaches[0].id.clone(), beaches[2].id.clone()];
+        app.add_configured_sets(vec![(
+            "Wreck only".to_string(),
+            vec![BeachId::new("wreck")],
+        )]);
+        app.state = AppState::Dashboard;
+
+        app.handle_key(key_event(KeyCode::Char('S')));
+        for c in "West".chars() {
+            app.handle_key(key_event(KeyCode::Char(c)));
+        }
+        // While naming, `q` is part of the name rather than quitting
+        app.handle_key(key_event(KeyCode::Char('q')));
+        app.handle_key(key_event(KeyCode::Backspace));
+        app.handle_key(key_event(KeyCode::Enter));
+        assert!(!app.should_quit);
+        assert_eq!(app.active_comparison_set().unwrap().name, "West");
+        let west: Vec<_> = app.dashboard_beaches().iter().map(|b| &b.id).collect();
+        assert_eq!(west, [&beaches[0].id, &beaches[2].id]);
+        assert_eq!(
+            comparison_sets::load_comparison_sets(&store),
+            vec![ComparisonSet::new(
+                "West",
+                vec![beaches[0].id.clone(), beaches[2].id.clone()]
+            )]
+        );
 
-        app.handle_key(key_event(KeyCode::Char('2')));
+        // Tab goes through the sets and back to the favorites
+        app.favorites.clear();
+        app.handle_key(key_event(KeyCode::Tab));
+        assert_eq!(app.active_set, None);
+        assert_eq!(app.dashboard_beaches().len(), app.beach_count());
+        app.handle_key(key_event(KeyCode::Tab));
+        let wreck: Vec<_> = app.dashboard_beaches().iter().map(|b| &b.id).collect();
+        assert_eq!(wreck, [&BeachId::new("wreck")]);
+
+        // A configured set is neither replaced nor deleted
+        app.save_comparison_set("Wreck only", Local::now());
+        assert_eq!(app.comparison_sets[0].beaches, vec![BeachId::new("wreck")]);
+        app.handle_key(key_event(KeyCode::Char('x')));
+        assert_eq!(app.comparison_sets.len(), 2);
+
+        app.handle_key(key_event(KeyCode::Tab));
+        app.handle_key(key_event(KeyCode::Char('x')));
+        assert_eq!(app.comparison_sets.len(), 1);
+        assert_eq!(app.active_set, None);
+        assert!(comparison_sets::load_comparison_sets(&store).is_empty());
+    }
 
-        assert_eq!(app.current_activity, Some(Activity::Sunbathing));
+    #[test]
+    fn test_dashboard_shows_all_beaches_without_favorites() {
+        let mut app = App::new();
+        app.favorites.clear();
+        assert_eq!(app.dashboard_beaches().len(), app.beach_count());
     }
 
     #[test]
-    fn test_key_3_in_beach_detail_sets_sailing_activity() {
+    fn test_dashboard_auto_refreshes_when_data_is_stale() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        let now = Local::now();
+        app.state = AppState::Dashboard;
+        app.last_refresh = Some(now - Duration::minutes(5));
+        assert!(!app.needs_auto_refresh(now));
 
-        app.handle_key(key_event(KeyCode::Char('3')));
+        app.last_refresh = Some(now - Duration::minutes(DASHBOARD_REFRESH_MINUTES));
+        assert!(app.needs_auto_refresh(now));
 
-        assert_eq!(app.current_activity, Some(Activity::Sailing));
+        // Other views never refresh on their own
+        app.state = AppState::BeachList;
+        assert!(!app.needs_auto_refresh(now));
     }
 
     #[test]
-    fn test_key_4_in_beach_detail_sets_sunset_activity() {
+    fn test_auto_refresh_slows_down_to_save_power() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        let now = Local::now();
+        app.state = AppState::Dashboard;
+        app.last_refresh = Some(now - Duration::minutes(DASHBOARD_REFRESH_MINUTES));
+        assert!(app.needs_auto_refresh(now));
 
-        app.handle_key(key_event(KeyCode::Char('4')));
+        app.update(AppEvent::OnBattery(true));
+        assert_eq!(
+            app.auto_refresh_minutes(),
+            DASHBOARD_REFRESH_MINUTES * POWER_SAVING_STRETCH
+        );
+        assert!(!app.needs_auto_refresh(now));
+
+        app.update(AppEvent::OnBattery(false));
+        app.update(AppEvent::Focus(false));
+        assert!(app.saving_power());
+        assert!(!app.needs_auto_refresh(now));
+
+        // The config can turn power saving off, or on for good
+        app.power_saving = PowerSaving::Never;
+        assert!(app.needs_auto_refresh(now));
+        app.update(AppEvent::Focus(true));
+        app.power_saving = PowerSaving::Always;
+        assert!(!app.needs_auto_refresh(now));
+    }
 
-        assert_eq!(app.current_activity, Some(Activity::Sunset));
+    #[test]
+    fn test_toast_expires_or_is_dismissed_by_a_key() {
+        let mut app = App::new();
+        app.state = AppState::BeachList;
+        let now = Local::now();
+
+        app.show_toast("Sunset soon".to_string(), now);
+        assert_eq!(app.active_toast(now), Some("Sunset soon"));
+        assert_eq!(
+            app.active_toast(now + Duration::minutes(TOAST_MINUTES)),
+            None
+        );
+
+        // The key still does its usual job
+        app.handle_key(key_event(KeyCode::Char('j')));
+        assert_eq!(app.active_toast(now), None);
+        assert_eq!(app.selected_index, 1);
     }
 
     #[test]
-    fn test_key_5_in_beach_detail_sets_peace_activity() {
+    fn test_plan_scores_are_reused_until_activity_or_data_change() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.current_activity = Some(Activity::Swimming);
 
-        app.handle_key(key_event(KeyCode::Char('5')));
+        let first = app.plan_scores();
+        assert!(Arc::ptr_eq(&first, &app.plan_scores()));
 
-        assert_eq!(app.current_activity, Some(Activity::Peace));
+        app.current_activity = Some(Activity::Sunset);
+        let sunset = app.plan_scores();
+        assert!(!Arc::ptr_eq(&first, &sunset));
+        assert_eq!(sunset.key.activity, Some(Activity::Sunset));
+
+        app.apply_snapshot(HashMap::new());
+        assert!(!Arc::ptr_eq(&sunset, &app.plan_scores()));
     }
 
     #[test]
-    fn test_esc_in_plan_trip_returns_to_beach_list() {
+    fn test_r_is_ignored_during_the_refresh_cooldown() {
         let mut app = App::new();
-        app.state = AppState::PlanTrip;
+        app.state = AppState::BeachList;
+        let now = Local::now();
+
+        app.last_refresh = Some(now - Duration::seconds(10));
+        app.handle_key(key_event(KeyCode::Char('r')));
+        assert!(!app.refresh_requested);
+        assert_eq!(app.refresh_cooldown_remaining(now), Some(20));
+
+        app.last_refresh = Some(now - app.refresh_cooldown);
+        assert_eq!(app.refresh_cooldown_remaining(now), None);
+        app.handle_key(key_event(KeyCode::Char('r')));
+        assert!(app.refresh_requested);
+
+        // A zero cooldown never ignores a press
+        app.refresh_requested = false;
+        app.refresh_cooldown = Duration::zero();
+        app.last_refresh = Some(Local::now());
+        app.handle_key(key_event(KeyCode::Char('r')));
+        assert!(app.refresh_requested);
+    }
 
-        app.handle_key(key_event(KeyCode::Esc));
+    #[test]
+    fn test_feed_refresh_says_whether_fresh_data_arrived() {
+        let mut app = App::new();
+        app.state = AppState::BeachList;
+        app.handle_key(key_event(KeyCode::Char('W')));
+        assert!(app.feed_refresh.is_none());
+
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+        app.handle_key(key_event(KeyCode::Char('W')));
+        let (beach, feed) = app.feed_refresh.take().unwrap();
+        assert_eq!((beach.id.as_str(), feed), ("kitsilano", Feed::Weather));
+
+        let requested_at = Utc::now();
+        let load = |fetched_at| {
+            AppEvent::DataLoaded(Box::new(DataLoad::Feed {
+                feed: Feed::Tides,
+                fetch: BeachFetch {
+                    tides: Some(TideInfo {
+                        current_height: 2.4,
+                        tide_state: TideState::Rising,
+                        next_high: None,
+                        next_low: None,
+                        fetched_at,
+                    }),
+                    beaches: vec![FetchedBeach {
+                        beach,
+                        weather: None,
+                        water_quality: None,
+                        waves: None,
+                    }],
+                    ..BeachFetch::default()
+                },
+                requested_at,
+            }))
+        };
 
-        assert_eq!(app.state, AppState::BeachList);
-        assert!(!app.should_quit);
+        app.update(load(requested_at));
+        assert_eq!(
+            app.toast.as_ref().unwrap().message,
+            "Refreshed tides for Kitsilano Beach"
+        );
+        assert!(app.get_conditions("kitsilano").unwrap().tides.is_some());
+
+        // Old data back means the fetch fell back on the cache
+        app.update(load(requested_at - Duration::hours(1)));
+        assert!(app
+            .toast
+            .as_ref()
+            .unwrap()
+            .message
+            .starts_with("Couldn't refresh tides for Kitsilano Beach"));
     }
 
     #[test]
-    fn test_activity_persists_when_navigating_to_plan_trip() {
+    fn test_sunset_dims_and_swaps_in_the_evening_activity_until_sunrise() {
+        use crate::data::{WeatherCondition, WeatherProvider};
+        use chrono::{NaiveTime, TimeZone};
+
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachList;
+        app.night_dim = true;
+        app.night_activity = Some(Activity::Peace);
+        app.current_activity = Some(Activity::Swimming);
+        let beach = app.selected_beach().unwrap();
+        app.beach_conditions.insert(
+            beach.id.clone(),
+            BeachConditions {
+                beach: beach.clone(),
+                weather: Some(Weather {
+                    temperature: 18.0,
+                    feels_like: 18.0,
+                    condition: WeatherCondition::Clear,
+                    humidity: 60,
+                    wind: 5.0,
+                    wind_direction: None,
+                    uv: 0.0,
+                    sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                    sunset: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+                    fetched_at: Utc::now(),
+                    hourly: Vec::new(),
+                    tomorrow: Vec::new(),
+                    source: WeatherProvider::OpenMeteo,
+                    parse_warnings: Vec::new(),
+                    weather_code: None,
+                }),
+                tides: None,
+                water_quality: None,
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+        // 14:00 and 21:30 PDT, then 07:00 the next morning
+        let at = |hour, minute| {
+            Utc.with_ymd_and_hms(2026, 7, 15, hour, minute, 0)
+                .unwrap()
+                .with_timezone(&Local)
+        };
+        let afternoon = at(21, 0);
+        let after_sunset = at(4, 30) + Duration::days(1);
+        let next_morning = at(14, 0) + Duration::days(1);
 
-        // Set activity
-        app.handle_key(key_event(KeyCode::Char('1')));
+        app.update(AppEvent::Tick(afternoon));
+        assert!(!app.dims_for_night());
         assert_eq!(app.current_activity, Some(Activity::Swimming));
 
-        // Navigate to PlanTrip
-        app.handle_key(key_event(KeyCode::Char('p')));
-        assert_eq!(app.state, AppState::PlanTrip);
-
-        // Activity should still be set
+        app.update(AppEvent::Tick(after_sunset));
+        assert!(app.dims_for_night());
+        assert_eq!(app.current_activity, Some(Activity::Peace));
+        assert!(app
+            .toast
+            .as_ref()
+            .unwrap()
+            .message
+            .ends_with("switched to Peace & Quiet"));
+
+        app.update(AppEvent::Tick(next_morning));
+        assert!(!app.dims_for_night());
         assert_eq!(app.current_activity, Some(Activity::Swimming));
+
+        // A pick made after dark isn't undone at sunrise
+        app.update(AppEvent::Tick(after_sunset + Duration::days(1)));
+        app.select_activity(Activity::Sailing);
+        app.update(AppEvent::Tick(next_morning + Duration::days(1)));
+        assert_eq!(app.current_activity, Some(Activity::Sailing));
+
+        // An evening activity is left alone
+        app.select_activity(Activity::RunWalk);
+        app.update(AppEvent::Tick(after_sunset + Duration::days(2)));
+        assert!(app.dims_for_night());
+        assert_eq!(app.current_activity, Some(Activity::RunWalk));
     }
 
     #[test]
-    fn test_activity_persists_when_returning_from_plan_trip() {
+    fn test_implausible_data_is_quarantined_and_last_good_data_kept() {
+        use crate::data::{WeatherCondition, WeatherProvider};
+        use chrono::NaiveTime;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
         let mut app = App::new();
-        app.current_activity = Some(Activity::Sailing);
-        app.state = AppState::PlanTrip;
+        app.user_store = Some(CacheManager::with_dir(temp_dir.path().to_path_buf()));
+        let beach = get_beach_by_id("kitsilano").unwrap();
+        let tides = |current_height| TideInfo {
+            current_height,
+            tide_state: TideState::Rising,
+            next_high: None,
+            next_low: None,
+            fetched_at: Utc::now(),
+        };
+        let weather = |temperature| Weather {
+            temperature,
+            feels_like: 17.0,
+            condition: WeatherCondition::Clear,
+            humidity: 50,
+            wind: 5.0,
+            wind_direction: None,
+            uv: 4.0,
+            sunrise: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+            sunset: NaiveTime::from_hms_opt(20, 0, 0).unwrap(),
+            fetched_at: Utc::now(),
+            hourly: Vec::new(),
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+            weather_code: None,
+        };
+        let fetch = |tides, weather| BeachFetch {
+            tides: Some(tides),
+            beaches: vec![FetchedBeach {
+                beach,
+                weather: Some(weather),
+                water_quality: None,
+                waves: None,
+            }],
+            ..BeachFetch::default()
+        };
 
-        // Go back to beach list
-        app.handle_key(key_event(KeyCode::Esc));
+        assert!(app.apply_fetch(fetch(tides(2.4), weather(21.0))));
+        assert!(app.anomalies.is_empty());
+
+        assert!(!app.apply_fetch(fetch(tides(9.5), weather(61.0))));
+        let conditions = app.get_conditions("kitsilano").unwrap();
+        assert_eq!(conditions.tides.as_ref().unwrap().current_height, 2.4);
+        assert_eq!(conditions.weather.as_ref().unwrap().temperature, 21.0);
+        let fields: Vec<&str> = app.anomalies.iter().map(|a| a.field.as_str()).collect();
+        assert_eq!(fields, vec!["current_height", "temperature"]);
+
+        // A feed refresh that comes back implausible says so
+        app.update(AppEvent::DataLoaded(Box::new(DataLoad::Feed {
+            feed: Feed::Weather,
+            fetch: fetch(tides(2.5), weather(-45.0)),
+            requested_at: Utc::now() - Duration::minutes(1),
+        })));
+        assert_eq!(
+            app.toast.as_ref().unwrap().message,
+            "Ignored implausible weather for Kitsilano Beach; showing what was loaded before"
+        );
+    }
 
-        // Activity should still be set
-        assert_eq!(app.current_activity, Some(Activity::Sailing));
+    #[test]
+    fn test_pending_dashboard_from_startup_config() {
+        let config = StartupConfig {
+            start_in_dashboard: true,
+            ..StartupConfig::default()
+        };
+        let app = App::with_startup_config(config);
+        assert!(app.pending_dashboard);
+        assert!(!app.pending_plan_trip);
     }
 
     #[test]
-    fn test_activity_persists_when_navigating_between_beaches() {
+    fn test_pending_beach_opens_detail_view_after_load() {
+        let config = StartupConfig {
+            initial_beach: Some(BeachId::new("jericho")),
+            ..StartupConfig::default()
+        };
+        let mut app = App::with_startup_config(config);
+        assert_eq!(app.state, AppState::Loading);
+
+        app.apply_snapshot(HashMap::new());
+        assert_eq!(app.state, AppState::BeachDetail(BeachId::new("jericho")));
+        assert_eq!(app.selected_beach().unwrap().id, "jericho");
+        assert!(app.pending_beach.is_none());
+    }
+
+    #[test]
+    fn test_first_paint_loads_what_the_screen_shows_first() {
+        let beaches = all_beaches();
+        let ids = |list: &[&Beach]| list.iter().map(|b| b.id.to_string()).collect::<Vec<_>>();
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
-        app.current_activity = Some(Activity::Sunbathing);
+        app.favorites.clear();
 
-        // Go back to list
-        app.handle_key(key_event(KeyCode::Esc));
-        assert_eq!(app.current_activity, Some(Activity::Sunbathing));
+        let (first, deferred) = app.first_paint_order(3);
+        assert_eq!(
+            ids(&first),
+            ids(&beaches.iter().take(3).collect::<Vec<_>>())
+        );
+        assert_eq!(deferred.len(), beaches.len() - 3);
 
-        // Select another beach
-        app.handle_key(key_event(KeyCode::Down));
-        app.handle_key(key_event(KeyCode::Enter));
+        // The beach opened with --beach comes first, the dashboard's next
+        app.pending_beach = Some(beaches[7].id.clone());
+        app.pending_dashboard = true;
+        app.favorites = vec![beaches[5].id.clone()];
+        let (first, deferred) = app.first_paint_order(2);
+        assert_eq!(
+            ids(&first),
+            vec![
+                beaches[7].id.to_string(),
+                beaches[5].id.to_string(),
+                beaches[0].id.to_string(),
+                beaches[1].id.to_string(),
+            ]
+        );
+        assert_eq!(first.len() + deferred.len(), beaches.len());
+        assert!(deferred.iter().all(|b| !first.iter().any(|f| f.id == b.id)));
+    }
 
-        // Activity should persist
-        assert_eq!(app.current_activity, Some(Activity::Sunbathing));
+    #[test]
+    fn test_data_loaded_events_apply_fetches_without_io() {
+        let beaches: Vec<&'static Beach> = all_beaches().iter().collect();
+        let fetch = |batch: &[&'static Beach], closures| BeachFetch {
+            tides: None,
+            closures,
+            seawall_closures: None,
+            observations: None,
+            fountains: None,
+            fire_bans: None,
+            wind_models: None,
+            air_quality: None,
+            beaches: batch
+                .iter()
+                .map(|&beach| FetchedBeach {
+                    beach,
+                    weather: None,
+                    water_quality: None,
+                    waves: None,
+                })
+                .collect(),
+        };
+        let mut app = App::new();
+        app.user_store = None;
+        let closed = HashMap::from([(
+            beaches[0].id.clone(),
+            crate::data::Closure {
+                reason: "Oil sheen".to_string(),
+                until: None,
+                fetched_at: Utc::now(),
+            },
+        )]);
+
+        app.update(AppEvent::DataLoaded(Box::new(DataLoad::FirstPaint {
+            fetch: fetch(&beaches[..2], Some(closed)),
+            deferred: beaches[2..].to_vec(),
+        })));
+        app.update(AppEvent::RefreshProgress {
+            loaded: 2,
+            total: beaches.len(),
+        });
+        assert_eq!(app.state, AppState::BeachList);
+        assert_eq!(app.beach_conditions.len(), 2);
+        assert_eq!(app.deferred_load.len(), beaches.len() - 2);
+        assert_eq!(app.load_progress, Some((2, beaches.len())));
+
+        // A failed closures fetch keeps the closure last known
+        app.update(AppEvent::DataLoaded(Box::new(DataLoad::Deferred(fetch(
+            &beaches[..3],
+            None,
+        )))));
+        assert!(app.is_closed(&beaches[0].id));
+        assert_eq!(app.deferred_load.len(), beaches.len() - 3);
+
+        app.update(AppEvent::DataLoaded(Box::new(DataLoad::Deferred(fetch(
+            &beaches[3..],
+            None,
+        )))));
+        app.update(AppEvent::RefreshProgress {
+            loaded: beaches.len(),
+            total: beaches.len(),
+        });
+        assert!(app.deferred_load.is_empty());
+        assert_eq!(app.load_progress, None);
+        assert_eq!(app.beach_conditions.len(), beaches.len());
     }
 
     #[test]
-    fn test_q_quits_from_plan_trip() {
+    fn test_tick_and_alert_events() {
         let mut app = App::new();
-        app.state = AppState::PlanTrip;
-        assert!(!app.should_quit);
+        let now = Local::now();
+        app.state = AppState::Dashboard;
+        app.last_refresh = Some(now - Duration::minutes(DASHBOARD_REFRESH_MINUTES));
+
+        app.update(AppEvent::Tick(now));
+        assert!(app.refresh_requested);
+
+        app.alert = Some(AlertStyle::Flash);
+        app.update(AppEvent::Alert {
+            message: "Advisory lifted at Kitsilano Beach".to_string(),
+            now,
+            quiet: false,
+        });
+        assert!(app.is_flashing(now));
+        assert_eq!(
+            app.active_toast(now),
+            Some("Advisory lifted at Kitsilano Beach")
+        );
 
-        app.handle_key(key_event(KeyCode::Char('q')));
-        assert!(app.should_quit);
+        // A key dismisses the toast, going through the same reducer
+        app.update(AppEvent::Key(key_event(KeyCode::Char('?'))));
+        assert_eq!(app.active_toast(now), None);
+
+        // Quiet alerts still toast, but don't flash
+        let later = now + Duration::seconds(1);
+        app.update(AppEvent::Alert {
+            message: "Advisory issued at Sunset Beach".to_string(),
+            now: later,
+            quiet: true,
+        });
+        assert!(!app.is_flashing(later));
+        assert_eq!(
+            app.active_toast(later),
+            Some("Advisory issued at Sunset Beach")
+        );
     }
 
     #[test]
@@ -914,7 +4282,7 @@ mod tests {
     #[test]
     fn test_state_transition_detail_to_beach_list() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
 
         // Press Esc to go back
         app.handle_key(key_event(KeyCode::Esc));
@@ -1002,7 +4370,7 @@ mod tests {
     #[test]
     fn test_q_quits_from_beach_detail() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
         assert!(!app.should_quit);
 
         app.handle_key(key_event(KeyCode::Char('q')));
@@ -1022,7 +4390,7 @@ mod tests {
     #[test]
     fn test_esc_goes_back_from_detail() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
 
         app.handle_key(key_event(KeyCode::Esc));
         assert_eq!(app.state, AppState::BeachList);
@@ -1088,12 +4456,12 @@ mod tests {
         assert_eq!(AppState::Loading, AppState::Loading);
         assert_eq!(AppState::BeachList, AppState::BeachList);
         assert_eq!(
-            AppState::BeachDetail("test".to_string()),
-            AppState::BeachDetail("test".to_string())
+            AppState::BeachDetail(BeachId::new("test")),
+            AppState::BeachDetail(BeachId::new("test"))
         );
         assert_ne!(
-            AppState::BeachDetail("test1".to_string()),
-            AppState::BeachDetail("test2".to_string())
+            AppState::BeachDetail(BeachId::new("test1")),
+            AppState::BeachDetail(BeachId::new("test2"))
         );
         assert_ne!(AppState::Loading, AppState::BeachList);
     }
@@ -1203,6 +4571,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_plan_trip_hours_scroll_to_keep_the_cursor_in_view() {
+        let mut app = App::new();
+        app.state = AppState::PlanTrip;
+        app.plan_time_range = (6, 21);
+
+        // Room for 5 of the 16 hours: moving past the edge scrolls a column
+        app.scroll_plan_hours(5);
+        for _ in 0..5 {
+            app.handle_key(key_event(KeyCode::Right));
+        }
+        app.scroll_plan_hours(5);
+        assert_eq!((app.plan_cursor.1, app.plan_column_offset), (5, 1));
+
+        // Wrapping to the first hour scrolls back to the start
+        app.plan_cursor.1 = 15;
+        app.scroll_plan_hours(5);
+        assert_eq!(app.plan_column_offset, 11);
+        app.handle_key(key_event(KeyCode::Right));
+        app.scroll_plan_hours(5);
+        assert_eq!((app.plan_cursor.1, app.plan_column_offset), (0, 0));
+
+        // A wide terminal shows every hour
+        app.plan_cursor.1 = 15;
+        app.scroll_plan_hours(40);
+        assert_eq!((app.plan_visible_hours, app.plan_column_offset), (16, 0));
+    }
+
+    #[test]
+    fn test_plan_trip_pans_a_screen_of_hours() {
+        let mut app = App::new();
+        app.state = AppState::PlanTrip;
+        app.plan_time_range = (6, 21);
+        app.plan_cursor.1 = 1;
+        app.scroll_plan_hours(5);
+
+        // The cursor keeps its column on screen
+        app.handle_key(key_event(KeyCode::Char('L')));
+        assert_eq!((app.plan_cursor.1, app.plan_column_offset), (6, 5));
+        app.handle_key(key_event(KeyCode::PageDown));
+        app.handle_key(key_event(KeyCode::PageDown));
+        assert_eq!((app.plan_cursor.1, app.plan_column_offset), (12, 11));
+        // At the end, the cursor goes to the last hour
+        app.handle_key(key_event(KeyCode::PageDown));
+        assert_eq!((app.plan_cursor.1, app.plan_column_offset), (15, 11));
+
+        app.handle_key(key_event(KeyCode::Char('H')));
+        assert_eq!((app.plan_cursor.1, app.plan_column_offset), (10, 6));
+        app.handle_key(key_event(KeyCode::Home));
+        assert_eq!(app.plan_cursor.1, 0);
+        app.handle_key(key_event(KeyCode::End));
+        assert_eq!(app.plan_cursor.1, 15);
+    }
+
     #[test]
     fn test_plan_trip_activity_selection() {
         let mut app = App::new();
@@ -1274,6 +4696,7 @@ mod tests {
         let config = StartupConfig {
             start_in_plan_trip: true,
             initial_activity: None,
+            ..StartupConfig::default()
         };
         let app = App::with_startup_config(config);
         assert_eq!(app.state, AppState::Loading);
@@ -1286,6 +4709,7 @@ mod tests {
         let config = StartupConfig {
             start_in_plan_trip: true,
             initial_activity: Some(Activity::Swimming),
+            ..StartupConfig::default()
         };
         let app = App::with_startup_config(config);
         assert_eq!(app.state, AppState::Loading);
@@ -1298,6 +4722,7 @@ mod tests {
         let config = StartupConfig {
             start_in_plan_trip: true,
             initial_activity: None,
+            ..StartupConfig::default()
         };
         let mut app = App::with_startup_config(config);
         assert!(app.pending_plan_trip);
@@ -1426,7 +4851,7 @@ mod tests {
     #[test]
     fn test_detail_view_state_resets_on_esc() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
         app.detail_scroll_offset = 5;
         app.tide_chart_expanded = true;
 
@@ -1440,7 +4865,7 @@ mod tests {
     #[test]
     fn test_detail_view_state_resets_on_plan_trip_navigation() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
         app.detail_scroll_offset = 7;
         app.tide_chart_expanded = true;
 
@@ -1490,7 +4915,7 @@ mod tests {
     #[test]
     fn test_detail_view_j_scrolls_down() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
         app.detail_scroll_offset = 0;
 
         app.handle_key(key_event(KeyCode::Char('j')));
@@ -1501,7 +4926,7 @@ mod tests {
     #[test]
     fn test_detail_view_k_scrolls_up() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
         app.detail_scroll_offset = 5;
 
         app.handle_key(key_event(KeyCode::Char('k')));
@@ -1512,7 +4937,7 @@ mod tests {
     #[test]
     fn test_detail_view_down_arrow_scrolls_down() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
         app.detail_scroll_offset = 0;
 
         app.handle_key(key_event(KeyCode::Down));
@@ -1523,7 +4948,7 @@ mod tests {
     #[test]
     fn test_detail_view_up_arrow_scrolls_up() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
         app.detail_scroll_offset = 5;
 
         app.handle_key(key_event(KeyCode::Up));
@@ -1534,7 +4959,7 @@ mod tests {
     #[test]
     fn test_detail_view_g_scrolls_to_top() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
         app.detail_scroll_offset = 25;
 
         app.handle_key(key_event(KeyCode::Char('g')));
@@ -1545,7 +4970,7 @@ mod tests {
     #[test]
     fn test_detail_view_capital_g_scrolls_to_bottom() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
         app.detail_scroll_offset = 0;
 
         app.handle_key(key_event(KeyCode::Char('G')));
@@ -1556,7 +4981,7 @@ mod tests {
     #[test]
     fn test_scroll_keys_dont_change_state() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
 
         app.handle_key(key_event(KeyCode::Char('j')));
         assert!(matches!(app.state, AppState::BeachDetail(_)));
@@ -1578,7 +5003,7 @@ mod tests {
     #[test]
     fn test_t_key_toggles_tide_chart_in_beach_detail() {
         let mut app = App::new();
-        app.state = AppState::BeachDetail("kitsilano".to_string());
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
         assert!(!app.tide_chart_expanded, "Should start collapsed");
 
         app.handle_key(key_event(KeyCode::Char('t')));
@@ -1618,4 +5043,31 @@ mod tests {
             "t key should not toggle in PlanTrip"
         );
     }
+
+    #[test]
+    fn test_o_key_shows_directions_over_beach_detail() {
+        let mut app = App::new();
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+
+        app.handle_key(key_event(KeyCode::Char('o')));
+        assert!(app.show_directions);
+
+        // The overlay takes the keys: q and Esc close it without quitting
+        // or leaving the beach
+        app.handle_key(key_event(KeyCode::Char('q')));
+        assert!(!app.show_directions);
+        assert!(!app.should_quit);
+        app.handle_key(key_event(KeyCode::Char('o')));
+        app.handle_key(key_event(KeyCode::Esc));
+        assert!(!app.show_directions);
+        assert_eq!(app.state, AppState::BeachDetail(BeachId::new("kitsilano")));
+
+        // On the list `o` is the custom order switch instead
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        app.user_store = Some(CacheManager::with_dir(temp_dir.path().to_path_buf()));
+        app.state = AppState::BeachList;
+        app.handle_key(key_event(KeyCode::Char('o')));
+        assert!(!app.show_directions);
+        assert!(app.beach_order.custom);
+    }
 }
@@ -0,0 +1,207 @@
+//! Weekly community events at beaches
+//!
+//! Events come from the `events` section of the config, e.g. a Saturday 8am
+//! polar dip at English Bay or a Tuesday evening volleyball league at
+//! Kitsilano. The detail view lists a beach's next few in an EVENTS section,
+//! and crowd predictions count each one as extra people in the hours it runs.
+//! Times are on the beach's clock.
+
+use std::sync::OnceLock;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+
+use crate::config::RecurringEvent;
+use crate::data::BeachId;
+
+/// Events installed from the config by `set_events`
+static EVENTS: OnceLock<Vec<(BeachId, RecurringEvent)>> = OnceLock::new();
+
+/// Installs the events from the config, each with its beach
+///
+/// The events can only be installed once; returns false if they were
+/// already set.
+pub fn set_events(events: Vec<(BeachId, RecurringEvent)>) -> bool {
+    EVENTS.set(events).is_ok()
+}
+
+/// Every installed event with its beach
+fn events() -> &'static [(BeachId, RecurringEvent)] {
+    EVENTS.get().map(Vec::as_slice).unwrap_or_default()
+}
+
+/// One time an event is on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence<'a> {
+    pub event: &'a RecurringEvent,
+    pub start: NaiveDateTime,
+}
+
+impl Occurrence<'_> {
+    /// When this occurrence finishes
+    pub fn end(&self) -> NaiveDateTime {
+        self.start + Duration::minutes(self.event.minutes.into())
+    }
+
+    /// Whether it's on at `now`
+    pub fn is_on(&self, now: NaiveDateTime) -> bool {
+        self.start <= now && now < self.end()
+    }
+}
+
+/// A beach's events still to finish from `now` to `days` days out, soonest
+/// first; one already on is included
+pub fn upcoming(beach_id: &str, now: NaiveDateTime, days: i64) -> Vec<Occurrence<'static>> {
+    upcoming_in(events(), beach_id, now, days)
+}
+
+fn upcoming_in<'a>(
+    events: &'a [(BeachId, RecurringEvent)],
+    beach_id: &str,
+    now: NaiveDateTime,
+    days: i64,
+) -> Vec<Occurrence<'a>> {
+    let until = now + Duration::days(days);
+    let mut occurrences: Vec<Occurrence> = occurrences_between(
+        events,
+        beach_id,
+        now.date() - Duration::days(1),
+        until.date(),
+    )
+    .into_iter()
+    .filter(|occurrence| occurrence.end() > now && occurrence.start < until)
+    .collect();
+    occurrences.sort_by_key(|occurrence| occurrence.start);
+    occurrences
+}
+
+/// How much a beach's events add to the crowd at some point in `hour` on
+/// `date`, with their names; at most 1.0
+pub fn crowd_during(beach_id: &str, date: NaiveDate, hour: u8) -> (f32, Vec<String>) {
+    crowd_during_in(events(), beach_id, date, hour)
+}
+
+fn crowd_during_in(
+    events: &[(BeachId, RecurringEvent)],
+    beach_id: &str,
+    date: NaiveDate,
+    hour: u8,
+) -> (f32, Vec<String>) {
+    let Some(hour_start) = date.and_hms_opt(hour.into(), 0, 0) else {
+        return (0.0, Vec::new());
+    };
+    let hour_end = hour_start + Duration::hours(1);
+    // An event the evening before can run past midnight
+    occurrences_between(events, beach_id, date - Duration::days(1), date)
+        .into_iter()
+        .filter(|occurrence| occurrence.start < hour_end && occurrence.end() > hour_start)
+        .fold((0.0_f32, Vec::new()), |(crowd, mut names), occurrence| {
+            names.push(occurrence.event.name.clone());
+            ((crowd + occurrence.event.crowd).min(1.0), names)
+        })
+}
+
+/// Every occurrence of a beach's events on the days from `first` to `last`
+fn occurrences_between<'a>(
+    events: &'a [(BeachId, RecurringEvent)],
+    beach_id: &str,
+    first: NaiveDate,
+    last: NaiveDate,
+) -> Vec<Occurrence<'a>> {
+    first
+        .iter_days()
+        .take_while(|date| *date <= last)
+        .flat_map(|date| {
+            events
+                .iter()
+                .filter(move |(id, event)| id == beach_id && event.day == date.weekday())
+                .map(move |(_, event)| Occurrence {
+                    event,
+                    start: date.and_time(event.start),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveTime, Weekday};
+
+    fn event(name: &str, day: Weekday, start: (u32, u32), minutes: u32) -> RecurringEvent {
+        RecurringEvent {
+            name: name.to_string(),
+            day,
+            start: NaiveTime::from_hms_opt(start.0, start.1, 0).unwrap(),
+            minutes,
+            crowd: 0.3,
+        }
+    }
+
+    fn events() -> Vec<(BeachId, RecurringEvent)> {
+        vec![
+            (
+                BeachId::new("english-bay"),
+                event("Polar dip", Weekday::Sat, (8, 0), 60),
+            ),
+            (
+                BeachId::new("english-bay"),
+                event("Night swim", Weekday::Fri, (23, 30), 60),
+            ),
+            (
+                BeachId::new("kitsilano"),
+                event("Volleyball league", Weekday::Tue, (18, 30), 120),
+            ),
+        ]
+    }
+
+    /// 2026-07-18 is a Saturday
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 7, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_upcoming_lists_the_next_occurrences_soonest_first() {
+        let events = events();
+        // Thursday noon
+        let upcoming = upcoming_in(&events, "english-bay", at(16, 12, 0), 7);
+        let starts: Vec<_> = upcoming
+            .iter()
+            .map(|o| (o.event.name.as_str(), o.start))
+            .collect();
+        assert_eq!(
+            starts,
+            vec![("Night swim", at(17, 23, 30)), ("Polar dip", at(18, 8, 0))]
+        );
+
+        // Saturday 8:30 the dip is on; the night swim has finished
+        let upcoming = upcoming_in(&events, "english-bay", at(18, 8, 30), 7);
+        assert_eq!(upcoming[0].event.name, "Polar dip");
+        assert!(upcoming[0].is_on(at(18, 8, 30)));
+        assert_eq!(upcoming[1].start, at(24, 23, 30));
+        // The week ahead ends after next Saturday's dip starts
+        assert_eq!(upcoming[2].start, at(25, 8, 0));
+        assert_eq!(upcoming.len(), 3);
+    }
+
+    #[test]
+    fn test_crowd_during_counts_every_hour_an_event_touches() {
+        let events = events();
+        let tuesday = NaiveDate::from_ymd_opt(2026, 7, 21).unwrap();
+        let saturday = NaiveDate::from_ymd_opt(2026, 7, 18).unwrap();
+
+        // 6:30 to 8:30 PM covers the 6, 7 and 8 PM hours
+        for hour in [18, 19, 20] {
+            assert_eq!(
+                crowd_during_in(&events, "kitsilano", tuesday, hour),
+                (0.3, vec!["Volleyball league".to_string()])
+            );
+        }
+        assert_eq!(crowd_during_in(&events, "kitsilano", tuesday, 17).0, 0.0);
+        assert_eq!(crowd_during_in(&events, "english-bay", tuesday, 18).0, 0.0);
+        // Friday's late swim runs into Saturday's first hour
+        assert_eq!(crowd_during_in(&events, "english-bay", saturday, 0).0, 0.3);
+    }
+}
@@ -0,0 +1,141 @@
+//! Custom beach list order
+//!
+//! `J` and `K` on the beach list move the selected beach down and up,
+//! switching the list to a custom order that replaces the registry's; `o`
+//! switches between the two without losing the arrangement. Both the order
+//! and whether it's in use are saved through the cache manager, like
+//! favorites, so they survive restarts.
+
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::cache::{CacheManager, StalenessPolicy};
+use crate::data::{all_beaches, Beach, BeachId};
+
+/// Cache key under which the beach order is stored
+pub const BEACH_ORDER_CACHE_KEY: &str = "beach_order";
+
+/// The order is a user setting rather than fetched data, so keep it for a year
+const BEACH_ORDER_TTL: Duration = Duration::days(365);
+
+/// How the beach list is arranged
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeachOrder {
+    /// Whether the list follows `beaches` rather than the registry order
+    pub custom: bool,
+    /// Beach ids as last arranged, first to last
+    pub beaches: Vec<BeachId>,
+}
+
+impl BeachOrder {
+    /// Every beach, arranged ones first in their order and any the
+    /// arrangement doesn't have yet (new to the registry) after them in
+    /// registry order
+    pub fn arrange(&self) -> Vec<&'static Beach> {
+        let mut beaches: Vec<&'static Beach> = all_beaches().iter().collect();
+        // Stable, so beaches left out keep registry order among themselves
+        beaches.sort_by_key(|beach| {
+            self.beaches
+                .iter()
+                .position(|id| *id == beach.id)
+                .unwrap_or(usize::MAX)
+        });
+        beaches
+    }
+
+    /// Swaps two beaches, first writing out the full arrangement so beaches
+    /// it didn't have yet keep their places
+    pub fn swap(&mut self, a: &BeachId, b: &BeachId) {
+        self.beaches = self
+            .arrange()
+            .into_iter()
+            .map(|beach| beach.id.clone())
+            .collect();
+        let position = |id: &BeachId| self.beaches.iter().position(|b| b == id);
+        if let (Some(a), Some(b)) = (position(a), position(b)) {
+            self.beaches.swap(a, b);
+        }
+    }
+}
+
+/// Loads the saved order, dropping any IDs that are no longer known beaches
+pub fn load_beach_order(cache: &CacheManager) -> BeachOrder {
+    let mut order = cache
+        .get_with_policy::<BeachOrder>(BEACH_ORDER_CACHE_KEY, StalenessPolicy::AnyAge)
+        .data()
+        .unwrap_or_default();
+    order
+        .beaches
+        .retain(|id| all_beaches().iter().any(|b| b.id == *id));
+    order
+}
+
+/// Saves the order to the cache
+pub fn save_beach_order(cache: &CacheManager, order: &BeachOrder) -> std::io::Result<()> {
+    cache.put(BEACH_ORDER_CACHE_KEY, order, BEACH_ORDER_TTL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn ids(beaches: &[&Beach]) -> Vec<BeachId> {
+        beaches.iter().map(|beach| beach.id.clone()).collect()
+    }
+
+    #[test]
+    fn test_arrangement_puts_beaches_it_lacks_last_in_registry_order() {
+        let registry = ids(&all_beaches().iter().collect::<Vec<_>>());
+        assert_eq!(ids(&BeachOrder::default().arrange()), registry);
+
+        let order = BeachOrder {
+            custom: true,
+            beaches: vec![registry[2].clone(), registry[0].clone()],
+        };
+        let arranged = ids(&order.arrange());
+        assert_eq!(
+            arranged[..3],
+            [
+                registry[2].clone(),
+                registry[0].clone(),
+                registry[1].clone()
+            ]
+        );
+        assert_eq!(arranged.len(), registry.len());
+    }
+
+    #[test]
+    fn test_swap_writes_out_the_full_arrangement() {
+        let registry = ids(&all_beaches().iter().collect::<Vec<_>>());
+        let mut order = BeachOrder::default();
+        order.swap(&registry[0], &registry[1]);
+
+        assert_eq!(order.beaches.len(), registry.len());
+        assert_eq!(
+            order.beaches[..2],
+            [registry[1].clone(), registry[0].clone()]
+        );
+        assert_eq!(order.beaches[2..], registry[2..]);
+    }
+
+    #[test]
+    fn test_save_and_load_drops_unknown_beaches() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let order = BeachOrder {
+            custom: true,
+            beaches: vec![BeachId::new("kitsilano"), BeachId::new("atlantis")],
+        };
+
+        save_beach_order(&cache, &order).unwrap();
+
+        assert_eq!(
+            load_beach_order(&cache),
+            BeachOrder {
+                custom: true,
+                beaches: vec![BeachId::new("kitsilano")],
+            }
+        );
+    }
+}
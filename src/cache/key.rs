@@ -0,0 +1,91 @@
+//! Cache keys that carry the query they were fetched with
+//!
+//! A key is the entry's name followed by a short hash of the request's query
+//! parameters, e.g. `beach_closures.5d0b1a2c`. Asking an endpoint for other
+//! fields or another number of days changes the hash, so a payload cached
+//! for the old request shape is never read back as the new one.
+//! `CacheManager::invalidate_other_versions` clears out the old entries.
+
+/// Query parameters as (name, value) pairs
+pub type QueryParams<'a> = [(&'a str, String)];
+
+/// Cache key for `name` fetched with `params`
+///
+/// `name` must not contain a `.`; the version follows the last one.
+pub fn query_key(name: &str, params: &QueryParams) -> String {
+    format!("{}.{}", name, query_version(params))
+}
+
+/// Stable 8-digit hex hash of query parameters, whatever their order
+///
+/// FNV-1a rather than `DefaultHasher`, whose output may change between Rust
+/// releases and would invalidate every entry on upgrade.
+pub fn query_version(params: &QueryParams) -> String {
+    let mut sorted: Vec<_> = params.iter().collect();
+    sorted.sort();
+    let canonical = sorted
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect::<Vec<_>>()
+        .join("&");
+    let hash = canonical.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    });
+    format!("{:08x}", hash)
+}
+
+/// The parameters as a URL query string, in the order given
+///
+/// Building the URL from the same parameters as the key keeps the two from
+/// drifting apart.
+pub fn query_string(params: &QueryParams) -> String {
+    params
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(pairs: &[(&'static str, &str)]) -> Vec<(&'static str, String)> {
+        pairs
+            .iter()
+            .map(|&(name, value)| (name, value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_query_key_depends_on_every_parameter_but_not_order() {
+        let two_days = params(&[("hourly", "temperature_2m"), ("forecast_days", "2")]);
+        let key = query_key("weather", &two_days);
+        assert!(key.starts_with("weather."));
+        assert_eq!(key.len(), "weather.".len() + 8);
+
+        let reordered = params(&[("forecast_days", "2"), ("hourly", "temperature_2m")]);
+        assert_eq!(query_key("weather", &reordered), key);
+
+        let three_days = params(&[("hourly", "temperature_2m"), ("forecast_days", "3")]);
+        assert_ne!(query_key("weather", &three_days), key);
+        let more_fields = params(&[
+            ("hourly", "temperature_2m,uv_index"),
+            ("forecast_days", "2"),
+        ]);
+        assert_ne!(query_key("weather", &more_fields), key);
+    }
+
+    #[test]
+    fn test_query_version_is_stable() {
+        // Changing the hash would invalidate every cached entry
+        assert_eq!(query_version(&[]), "811c9dc5");
+        assert_eq!(query_version(&params(&[("limit", "100")])), "9c03c67e");
+    }
+
+    #[test]
+    fn test_query_string_keeps_order() {
+        let query = params(&[("order_by", "sample_date desc"), ("limit", "30")]);
+        assert_eq!(query_string(&query), "order_by=sample_date desc&limit=30");
+    }
+}
@@ -2,12 +2,25 @@
 //!
 //! Provides a `CacheManager` that stores serializable data to JSON files with
 //! expiry timestamps, supporting graceful degradation when APIs are unavailable.
+//!
+//! Entries read or written during a session are also kept in a small
+//! in-memory LRU layer shared by every clone of the manager, so repeated
+//! lookups of the same key skip the disk and the JSON parse. Under the
+//! memory-only policy (`--no-cache`) that layer is all there is: it never
+//! evicts, and nothing touches the disk.
 
 use chrono::{DateTime, Duration, Utc};
-use directories::ProjectDirs;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use super::policy::{self, CachePolicy};
+
+/// Most entries kept in memory before the least recently used is dropped
+const MEMORY_CAPACITY: usize = 64;
 
 /// Wrapper struct for cached data stored on disk
 #[derive(Debug, Serialize, Deserialize)]
@@ -20,110 +33,314 @@ struct CacheEntry<T> {
     expires_at: DateTime<Utc>,
 }
 
-/// Result of reading from cache, including metadata about cache freshness
-#[derive(Debug)]
-pub struct CachedData<T> {
-    /// The cached data
-    pub data: T,
-    /// When the data was originally cached
+/// Which expired entries a lookup still accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StalenessPolicy {
+    /// Only entries within their TTL; an expired one is a miss
+    FreshOnly,
+    /// Expired entries of any age, for settings and fallbacks that are
+    /// better than nothing
+    AnyAge,
+    /// Expired entries up to this long after they were cached
+    #[allow(dead_code)]
+    MaxAge(Duration),
+}
+
+/// What a lookup found
+#[derive(Debug, Clone, PartialEq)]
+pub enum CacheLookup<T> {
+    /// Within its TTL
+    Fresh(T),
+    /// Past its TTL, with how long ago it was cached
+    Stale(T, Duration),
+    /// Nothing the policy accepts
+    Miss,
+}
+
+impl<T> CacheLookup<T> {
+    /// The data, fresh or stale
+    pub fn data(self) -> Option<T> {
+        match self {
+            CacheLookup::Fresh(data) | CacheLookup::Stale(data, _) => Some(data),
+            CacheLookup::Miss => None,
+        }
+    }
+
+    /// The data, only if it's fresh
     #[allow(dead_code)]
-    pub cached_at: DateTime<Utc>,
-    /// Whether the cache entry has expired
-    pub is_expired: bool,
+    pub fn fresh(self) -> Option<T> {
+        match self {
+            CacheLookup::Fresh(data) => Some(data),
+            _ => None,
+        }
+    }
+}
+
+/// Hit and miss counts of the in-memory layer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Reads answered from memory
+    pub hits: u64,
+    /// Reads that had to go to disk
+    pub misses: u64,
+    /// Entries dropped to stay within capacity
+    pub evictions: u64,
+    /// Entries currently held in memory
+    pub entries: usize,
+}
+
+/// Parsed cache entries, least recently used at the front
+#[derive(Debug)]
+struct MemoryCache {
+    entries: VecDeque<(String, Value)>,
+    stats: CacheStats,
+    /// Most entries held before evicting
+    capacity: usize,
+}
+
+impl MemoryCache {
+    /// An empty cache holding at most `capacity` entries
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            stats: CacheStats::default(),
+            capacity,
+        }
+    }
+
+    /// Looks up an entry, marking it most recently used
+    fn get(&mut self, key: &str) -> Option<Value> {
+        let Some(index) = self.entries.iter().position(|(k, _)| k == key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        self.stats.hits += 1;
+        let entry = self.entries.remove(index)?;
+        let value = entry.1.clone();
+        self.entries.push_back(entry);
+        Some(value)
+    }
+
+    /// Stores an entry as most recently used, evicting the least recently
+    /// used one when full
+    fn insert(&mut self, key: &str, value: Value) {
+        self.entries.retain(|(k, _)| k != key);
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+            self.stats.evictions += 1;
+        }
+        self.entries.push_back((key.to_string(), value));
+    }
 }
 
 /// Manages reading and writing cached data to disk
 ///
 /// The cache manager stores data as JSON files in an XDG-compliant cache directory
-/// (`~/.cache/vanbeach/` on Linux). Each cache entry includes an expiry timestamp,
-/// and expired entries are still returned (with `is_expired = true`) to support
-/// graceful degradation.
+/// (`~/.cache/vanbeach/` on Linux). Each cache entry includes an expiry timestamp;
+/// lookups say whether what they found is fresh or stale, and the
+/// `StalenessPolicy` they're given decides which stale entries are still worth
+/// returning for graceful degradation.
 #[derive(Debug, Clone)]
 pub struct CacheManager {
-    /// Directory where cache files are stored
-    cache_dir: PathBuf,
+    /// Directory where cache files are stored, None when memory-only
+    cache_dir: Option<PathBuf>,
+    /// Recently used entries, shared between clones
+    memory: Arc<Mutex<MemoryCache>>,
 }
 
 impl CacheManager {
-    /// Creates a new CacheManager using XDG-compliant cache directory
+    /// Creates a new CacheManager following the installed cache policy
     ///
-    /// Uses `~/.cache/vanbeach/` on Linux, or equivalent XDG path on other platforms.
-    /// Returns `None` if the cache directory cannot be determined (e.g., no home directory).
+    /// By default uses `$BEACH_CLI_CACHE_DIR` if set, otherwise
+    /// `~/.cache/vanbeach/` on Linux, or equivalent XDG path on other
+    /// platforms. Returns `None` if the cache directory cannot be determined
+    /// (e.g., no home directory).
     pub fn new() -> Option<Self> {
-        let project_dirs = ProjectDirs::from("", "", "vanbeach")?;
-        let cache_dir = project_dirs.cache_dir().to_path_buf();
-        Some(Self { cache_dir })
+        Self::with_policy(policy::policy())
+    }
+
+    /// Creates a new CacheManager for the given policy
+    pub fn with_policy(policy: &CachePolicy) -> Option<Self> {
+        match policy {
+            CachePolicy::Default => Some(Self::with_dir(policy::default_dir()?)),
+            CachePolicy::Dir(dir) => Some(Self::with_dir(dir.clone())),
+            CachePolicy::MemoryOnly => Some(Self::memory_only()),
+        }
     }
 
     /// Creates a new CacheManager with a custom cache directory
     ///
     /// Useful for testing or when a specific cache location is needed.
-    #[allow(dead_code)]
     pub fn with_dir(cache_dir: PathBuf) -> Self {
-        Self { cache_dir }
+        Self {
+            cache_dir: Some(cache_dir),
+            memory: Arc::new(Mutex::new(MemoryCache::with_capacity(MEMORY_CAPACITY))),
+        }
     }
 
-    /// Returns the path to a cache file for the given key
-    fn cache_path(&self, key: &str) -> PathBuf {
-        self.cache_dir.join(format!("{}.json", key))
+    /// Creates a new CacheManager that never touches the disk
+    ///
+    /// Entries are never evicted, since memory is the only copy.
+    pub fn memory_only() -> Self {
+        Self {
+            cache_dir: None,
+            memory: Arc::new(Mutex::new(MemoryCache::with_capacity(usize::MAX))),
+        }
+    }
+
+    /// Returns true if entries outlive the process
+    pub fn is_persistent(&self) -> bool {
+        self.cache_dir.is_some()
+    }
+
+    /// Directory entries are stored in, unless memory-only
+    pub fn dir(&self) -> Option<&Path> {
+        self.cache_dir.as_deref()
+    }
+
+    /// Hit and miss counts of the in-memory layer, across all clones
+    pub fn stats(&self) -> CacheStats {
+        let memory = self.memory.lock().unwrap_or_else(|e| e.into_inner());
+        CacheStats {
+            entries: memory.entries.len(),
+            ..memory.stats
+        }
+    }
+
+    /// Runs `f` on the in-memory layer
+    fn with_memory<R>(&self, f: impl FnOnce(&mut MemoryCache) -> R) -> R {
+        f(&mut self.memory.lock().unwrap_or_else(|e| e.into_inner()))
     }
 
-    /// Ensures the cache directory exists
-    fn ensure_dir(&self) -> std::io::Result<()> {
-        fs::create_dir_all(&self.cache_dir)
+    /// Returns the path to a cache file for the given key, None when
+    /// memory-only
+    fn cache_path(&self, key: &str) -> Option<PathBuf> {
+        Some(self.cache_dir.as_ref()?.join(format!("{}.json", key)))
     }
 
-    /// Writes data to the cache with a specified TTL (time-to-live) in hours
+    /// Writes data to the cache, fresh for `ttl`
     ///
-    /// # Arguments
-    /// * `key` - Unique identifier for the cache entry (e.g., "tides_kitsilano")
-    /// * `data` - The data to cache (must implement Serialize)
-    /// * `ttl_hours` - How long the cache entry should be considered fresh
+    /// Entries are still kept once the TTL runs out; it only decides when a
+    /// lookup calls them stale. Short TTLs suit negative results (a station
+    /// with no recent samples), which should be retried sooner than real
+    /// data but not on every refresh.
     ///
-    /// # Returns
-    /// * `Ok(())` on success
-    /// * `Err` if directory creation or file writing fails
-    pub fn write<T: Serialize>(&self, key: &str, data: &T, ttl_hours: u64) -> std::io::Result<()> {
-        self.ensure_dir()?;
-
+    /// # Errors
+    /// Fails if the directory can't be created or the file written.
+    pub fn put<T: Serialize>(&self, key: &str, data: &T, ttl: Duration) -> std::io::Result<()> {
         let now = Utc::now();
-        let entry = CacheEntry {
-            data,
-            cached_at: now,
-            expires_at: now + Duration::hours(ttl_hours as i64),
+        self.store(
+            key,
+            &CacheEntry {
+                data,
+                cached_at: now,
+                expires_at: now + ttl,
+            },
+        )
+    }
+
+    /// Marks an entry stale, so the next lookup that wants fresh data
+    /// fetches it again while the old data is still there to fall back on
+    ///
+    /// Returns whether there was an entry to expire.
+    ///
+    /// # Errors
+    /// Fails if the file can't be written.
+    pub fn expire(&self, key: &str) -> std::io::Result<bool> {
+        let Some(mut entry) = self.read_entry::<Value>(key) else {
+            return Ok(false);
         };
+        entry.expires_at = entry.cached_at;
+        self.store(key, &entry)?;
+        Ok(true)
+    }
 
-        let json = serde_json::to_string_pretty(&entry)
+    /// Writes an entry to disk, when persistent, and to memory
+    fn store<T: Serialize>(&self, key: &str, entry: &CacheEntry<T>) -> std::io::Result<()> {
+        let value = serde_json::to_value(entry)
             .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
 
-        fs::write(self.cache_path(key), json)
+        if let Some(dir) = &self.cache_dir {
+            let json = serde_json::to_string_pretty(&value)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+            fs::create_dir_all(dir)?;
+            fs::write(dir.join(format!("{}.json", key)), json)?;
+        }
+        self.with_memory(|memory| memory.insert(key, value));
+        Ok(())
     }
 
-    /// Reads data from the cache
-    ///
-    /// Returns `None` if the cache entry doesn't exist or cannot be parsed.
-    /// Returns `Some(CachedData)` with `is_expired = true` if the entry exists but has expired,
-    /// allowing for graceful degradation when APIs are unavailable.
+    /// Looks up an entry, telling fresh data from stale
     ///
-    /// # Arguments
-    /// * `key` - The cache key to read
-    ///
-    /// # Returns
-    /// * `Some(CachedData<T>)` if the entry exists and can be parsed
-    /// * `None` if the entry doesn't exist or parsing fails
-    pub fn read<T: DeserializeOwned>(&self, key: &str) -> Option<CachedData<T>> {
-        let path = self.cache_path(key);
-        let content = fs::read_to_string(path).ok()?;
-        let entry: CacheEntry<T> = serde_json::from_str(&content).ok()?;
-
+    /// Entries held in memory are used without touching the disk; others are
+    /// read from disk and kept in memory for next time. A stale entry the
+    /// policy doesn't accept, a missing one and one that can't be parsed are
+    /// all a `Miss`.
+    pub fn get_with_policy<T: DeserializeOwned>(
+        &self,
+        key: &str,
+        policy: StalenessPolicy,
+    ) -> CacheLookup<T> {
+        let Some(entry) = self.read_entry::<T>(key) else {
+            return CacheLookup::Miss;
+        };
         let now = Utc::now();
-        let is_expired = now > entry.expires_at;
+        if now <= entry.expires_at {
+            return CacheLookup::Fresh(entry.data);
+        }
+        let age = now - entry.cached_at;
+        match policy {
+            StalenessPolicy::FreshOnly => CacheLookup::Miss,
+            StalenessPolicy::AnyAge => CacheLookup::Stale(entry.data, age),
+            StalenessPolicy::MaxAge(max) if age <= max => CacheLookup::Stale(entry.data, age),
+            StalenessPolicy::MaxAge(_) => CacheLookup::Miss,
+        }
+    }
 
-        Some(CachedData {
-            data: entry.data,
-            cached_at: entry.cached_at,
-            is_expired,
-        })
+    /// Reads an entry from memory or, failing that, from disk
+    fn read_entry<T: DeserializeOwned>(&self, key: &str) -> Option<CacheEntry<T>> {
+        let value = match self.with_memory(|memory| memory.get(key)) {
+            Some(value) => value,
+            None => {
+                let content = fs::read_to_string(self.cache_path(key)?).ok()?;
+                let value: Value = serde_json::from_str(&content).ok()?;
+                self.with_memory(|memory| memory.insert(key, value.clone()));
+                value
+            }
+        };
+        serde_json::from_value(value).ok()
+    }
+
+    /// Deletes entries cached under `key`'s name with another query version,
+    /// or with none (from before keys carried one)
+    ///
+    /// `key` comes from `query_key`. Returns how many entries were deleted.
+    pub fn invalidate_other_versions(&self, key: &str) -> usize {
+        let Some((name, _)) = key.rsplit_once('.') else {
+            return 0;
+        };
+        let Some(Ok(dir)) = self.cache_dir.as_ref().map(fs::read_dir) else {
+            return 0;
+        };
+        let stale: Vec<String> = dir
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let file_name = entry.file_name();
+                let stem = file_name.to_str()?.strip_suffix(".json")?;
+                let same_name = stem == name
+                    || stem
+                        .strip_prefix(name)
+                        .and_then(|rest| rest.strip_prefix('.'))
+                        .is_some_and(|version| !version.contains('.'));
+                (same_name && stem != key).then(|| stem.to_string())
+            })
+            .collect();
+
+        for path in stale.iter().filter_map(|stem| self.cache_path(stem)) {
+            let _ = fs::remove_file(path);
+        }
+        self.with_memory(|memory| memory.entries.retain(|(k, _)| !stale.contains(k)));
+        stale.len()
     }
 }
 
@@ -156,7 +373,7 @@ mod tests {
         };
 
         cache
-            .write("test_key", &data, 24)
+            .put("test_key", &data, Duration::hours(24))
             .expect("Write should succeed");
 
         let expected_path = temp_dir.path().join("test_key.json");
@@ -171,16 +388,17 @@ mod tests {
     }
 
     #[test]
-    fn test_read_returns_none_for_missing_key() {
+    fn test_get_misses_missing_key() {
         let (cache, _temp_dir) = create_test_cache();
 
-        let result: Option<CachedData<TestData>> = cache.read("nonexistent_key");
+        let result: CacheLookup<TestData> =
+            cache.get_with_policy("nonexistent_key", StalenessPolicy::AnyAge);
 
-        assert!(result.is_none(), "Should return None for missing key");
+        assert_eq!(result, CacheLookup::Miss, "Should miss a missing key");
     }
 
     #[test]
-    fn test_read_returns_data_with_is_expired_false_for_fresh_cache() {
+    fn test_get_returns_fresh_data_within_ttl() {
         let (cache, _temp_dir) = create_test_cache();
         let data = TestData {
             name: "fresh".to_string(),
@@ -188,38 +406,75 @@ mod tests {
         };
 
         cache
-            .write("fresh_key", &data, 24)
+            .put("fresh_key", &data, Duration::hours(24))
             .expect("Write should succeed");
 
-        let result: CachedData<TestData> =
-            cache.read("fresh_key").expect("Should read fresh cache");
+        let result = cache.get_with_policy("fresh_key", StalenessPolicy::FreshOnly);
 
-        assert_eq!(result.data, data);
-        assert!(!result.is_expired, "Fresh cache should not be expired");
+        assert_eq!(result, CacheLookup::Fresh(data));
     }
 
     #[test]
-    fn test_read_returns_data_with_is_expired_true_for_expired_cache() {
+    fn test_expired_entries_follow_the_staleness_policy() {
         let (cache, _temp_dir) = create_test_cache();
         let data = TestData {
             name: "expired".to_string(),
             value: 0,
         };
 
-        // Write with 0 hour TTL - should expire immediately
+        // Put with a zero TTL - should expire immediately
         cache
-            .write("expired_key", &data, 0)
+            .put("expired_key", &data, Duration::zero())
             .expect("Write should succeed");
 
         // Small delay to ensure expiry
         thread::sleep(StdDuration::from_millis(10));
 
-        let result: CachedData<TestData> = cache
-            .read("expired_key")
-            .expect("Should read expired cache");
+        let result = cache.get_with_policy::<TestData>("expired_key", StalenessPolicy::AnyAge);
+        assert!(
+            matches!(&result, CacheLookup::Stale(stale, _) if *stale == data),
+            "Cache with 0 TTL should be stale"
+        );
+        assert_eq!(
+            cache.get_with_policy::<TestData>("expired_key", StalenessPolicy::FreshOnly),
+            CacheLookup::Miss
+        );
+        assert!(matches!(
+            cache.get_with_policy::<TestData>(
+                "expired_key",
+                StalenessPolicy::MaxAge(Duration::hours(1))
+            ),
+            CacheLookup::Stale(..)
+        ));
+        assert_eq!(
+            cache.get_with_policy::<TestData>(
+                "expired_key",
+                StalenessPolicy::MaxAge(Duration::zero())
+            ),
+            CacheLookup::Miss
+        );
+    }
+
+    #[test]
+    fn test_put_uses_sub_hour_expiry() {
+        let (cache, _temp_dir) = create_test_cache();
+        let data = TestData {
+            name: "negative".to_string(),
+            value: 0,
+        };
+
+        cache
+            .put("short_key", &data, Duration::minutes(30))
+            .expect("Write should succeed");
+        cache
+            .put("gone_key", &data, Duration::zero())
+            .expect("Write should succeed");
+        thread::sleep(StdDuration::from_millis(10));
 
-        assert_eq!(result.data, data);
-        assert!(result.is_expired, "Cache with 0 TTL should be expired");
+        let short = cache.get_with_policy::<TestData>("short_key", StalenessPolicy::AnyAge);
+        assert!(matches!(short, CacheLookup::Fresh(_)));
+        let gone = cache.get_with_policy::<TestData>("gone_key", StalenessPolicy::AnyAge);
+        assert!(matches!(gone, CacheLookup::Stale(..)));
     }
 
     #[test]
@@ -231,12 +486,16 @@ mod tests {
         };
 
         cache
-            .write("roundtrip_key", &original, 24)
+            .put("roundtrip_key", &original, Duration::hours(24))
             .expect("Write should succeed");
 
-        let result: CachedData<TestData> = cache.read("roundtrip_key").expect("Should read cache");
+        let result = cache.get_with_policy("roundtrip_key", StalenessPolicy::AnyAge);
 
-        assert_eq!(result.data, original, "Data should survive roundtrip");
+        assert_eq!(
+            result,
+            CacheLookup::Fresh(original),
+            "Data should survive roundtrip"
+        );
     }
 
     #[test]
@@ -251,7 +510,7 @@ mod tests {
         };
 
         cache
-            .write("nested_key", &data, 24)
+            .put("nested_key", &data, Duration::hours(24))
             .expect("Write should succeed");
 
         assert!(nested_path.exists(), "Nested directory should be created");
@@ -262,7 +521,7 @@ mod tests {
     }
 
     #[test]
-    fn test_cached_at_timestamp_is_recorded() {
+    fn test_stale_entries_report_their_age() {
         let (cache, _temp_dir) = create_test_cache();
         let data = TestData {
             name: "timestamp".to_string(),
@@ -271,26 +530,50 @@ mod tests {
 
         let before = Utc::now();
         cache
-            .write("timestamp_key", &data, 24)
+            .put("timestamp_key", &data, Duration::zero())
             .expect("Write should succeed");
-        let after = Utc::now();
-
-        let result: CachedData<TestData> = cache.read("timestamp_key").expect("Should read cache");
+        thread::sleep(StdDuration::from_millis(10));
 
+        let CacheLookup::Stale(_, age) =
+            cache.get_with_policy::<TestData>("timestamp_key", StalenessPolicy::AnyAge)
+        else {
+            panic!("Cache with 0 TTL should be stale");
+        };
         assert!(
-            result.cached_at >= before,
-            "cached_at should be after write started"
-        );
-        assert!(
-            result.cached_at <= after,
-            "cached_at should be before write finished"
+            age >= Duration::milliseconds(10),
+            "age counts from the write"
         );
+        assert!(age <= Utc::now() - before, "age can't predate the write");
+    }
+
+    #[test]
+    fn test_expired_entries_go_stale_but_keep_their_data() {
+        let (cache, temp_dir) = create_test_cache();
+        let data = TestData {
+            name: "forced".to_string(),
+            value: 7,
+        };
+        cache
+            .put("forced_key", &data, Duration::hours(1))
+            .expect("Write should succeed");
+        thread::sleep(StdDuration::from_millis(5));
+
+        assert!(cache.expire("forced_key").unwrap());
+        assert!(!cache.expire("missing_key").unwrap());
+
+        // Both the memory layer and a fresh reader of the file see it stale
+        let reopened = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        for cache in [&cache, &reopened] {
+            let lookup = cache.get_with_policy::<TestData>("forced_key", StalenessPolicy::AnyAge);
+            assert!(matches!(&lookup, CacheLookup::Stale(stale, _) if *stale == data));
+        }
     }
 
     #[test]
     fn test_new_creates_xdg_compliant_path() {
-        if let Some(cache) = CacheManager::new() {
-            let path_str = cache.cache_dir.to_string_lossy();
+        if let Some(cache) = CacheManager::with_policy(&CachePolicy::Default) {
+            let cache_dir = cache.cache_dir.expect("default policy caches to disk");
+            let path_str = cache_dir.to_string_lossy();
             assert!(
                 path_str.contains("vanbeach"),
                 "Cache path should contain project name"
@@ -299,6 +582,41 @@ mod tests {
         // Test passes if new() returns None (e.g., no home directory in CI)
     }
 
+    #[test]
+    fn test_policies_pick_where_entries_go() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let dir = temp_dir.path().join("custom");
+        let cache = CacheManager::with_policy(&CachePolicy::Dir(dir.clone())).unwrap();
+        assert!(cache.is_persistent());
+        cache
+            .put("custom_key", &1, Duration::hours(24))
+            .expect("Write should succeed");
+        assert!(dir.join("custom_key.json").exists());
+
+        let cache = CacheManager::with_policy(&CachePolicy::MemoryOnly).unwrap();
+        assert!(!cache.is_persistent());
+        cache
+            .put("memory_key", &2, Duration::hours(24))
+            .expect("Write should succeed");
+        let result = cache.get_with_policy("memory_key", StalenessPolicy::AnyAge);
+        assert_eq!(result, CacheLookup::Fresh(2));
+        assert_eq!(cache.invalidate_other_versions("memory_key.v2"), 0);
+    }
+
+    #[test]
+    fn test_memory_only_never_evicts() {
+        let cache = CacheManager::memory_only();
+        for i in 0..MEMORY_CAPACITY + 1 {
+            cache
+                .put(&format!("key_{}", i), &i, Duration::hours(24))
+                .expect("Write should succeed");
+        }
+
+        let first = cache.get_with_policy("key_0", StalenessPolicy::AnyAge);
+        assert_eq!(first, CacheLookup::Fresh(0usize), "Should still be held");
+        assert_eq!(cache.stats().evictions, 0);
+    }
+
     #[test]
     fn test_overwrite_existing_cache() {
         let (cache, _temp_dir) = create_test_cache();
@@ -312,14 +630,128 @@ mod tests {
         };
 
         cache
-            .write("overwrite_key", &data1, 24)
+            .put("overwrite_key", &data1, Duration::hours(24))
             .expect("First write should succeed");
         cache
-            .write("overwrite_key", &data2, 24)
+            .put("overwrite_key", &data2, Duration::hours(24))
             .expect("Second write should succeed");
 
-        let result: CachedData<TestData> = cache.read("overwrite_key").expect("Should read cache");
+        let result = cache.get_with_policy("overwrite_key", StalenessPolicy::AnyAge);
+
+        assert_eq!(
+            result,
+            CacheLookup::Fresh(data2),
+            "Cache should contain latest data"
+        );
+    }
+
+    #[test]
+    fn test_repeated_reads_are_served_from_memory() {
+        let (cache, temp_dir) = create_test_cache();
+        let data = TestData {
+            name: "memory".to_string(),
+            value: 7,
+        };
+        cache.put("memory_key", &data, Duration::hours(24)).unwrap();
+        let shared = cache.clone();
+
+        // Gone from disk, but the write is still held in memory
+        fs::remove_file(temp_dir.path().join("memory_key.json")).unwrap();
+        let result = shared.get_with_policy("memory_key", StalenessPolicy::AnyAge);
+        assert_eq!(result, CacheLookup::Fresh(data));
+        assert_eq!(
+            cache.get_with_policy::<TestData>("missing_key", StalenessPolicy::AnyAge),
+            CacheLookup::Miss
+        );
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_least_recently_used_entry_is_evicted() {
+        let (cache, _temp_dir) = create_test_cache();
+        for value in 0..MEMORY_CAPACITY as i32 {
+            let data = TestData {
+                name: "entry".to_string(),
+                value,
+            };
+            cache
+                .put(&format!("key_{}", value), &data, Duration::hours(24))
+                .unwrap();
+        }
+        // Touch the oldest entry so the second oldest is evicted instead
+        cache
+            .get_with_policy::<TestData>("key_0", StalenessPolicy::AnyAge)
+            .data()
+            .unwrap();
+        cache
+            .put(
+                "key_new",
+                &TestData {
+                    name: "new".to_string(),
+                    value: -1,
+                },
+                Duration::hours(24),
+            )
+            .unwrap();
+
+        let stats = cache.stats();
+        assert_eq!(stats.entries, MEMORY_CAPACITY);
+        assert_eq!(stats.evictions, 1);
+        assert_eq!(stats.hits, 1);
+        // The evicted entry is read back from disk
+        cache
+            .get_with_policy::<TestData>("key_1", StalenessPolicy::AnyAge)
+            .data()
+            .unwrap();
+        assert_eq!(cache.stats().misses, 1);
+        cache
+            .get_with_policy::<TestData>("key_0", StalenessPolicy::AnyAge)
+            .data()
+            .unwrap();
+        assert_eq!(cache.stats().hits, 2);
+    }
+
+    #[test]
+    fn test_invalidate_other_versions_keeps_only_current_key() {
+        let (cache, _temp_dir) = create_test_cache();
+        let data = TestData {
+            name: "entry".to_string(),
+            value: 1,
+        };
+        for key in [
+            "closures",
+            "closures.00000001",
+            "closures.00000002",
+            "closures_archive",
+            "closures_archive.00000001",
+        ] {
+            cache.put(key, &data, Duration::hours(24)).unwrap();
+        }
 
-        assert_eq!(result.data, data2, "Cache should contain latest data");
+        assert_eq!(cache.invalidate_other_versions("closures.00000002"), 2);
+
+        assert!(cache
+            .get_with_policy::<TestData>("closures", StalenessPolicy::AnyAge)
+            .data()
+            .is_none());
+        assert!(cache
+            .get_with_policy::<TestData>("closures.00000001", StalenessPolicy::AnyAge)
+            .data()
+            .is_none());
+        assert!(cache
+            .get_with_policy::<TestData>("closures.00000002", StalenessPolicy::AnyAge)
+            .data()
+            .is_some());
+        assert!(cache
+            .get_with_policy::<TestData>("closures_archive", StalenessPolicy::AnyAge)
+            .data()
+            .is_some());
+        assert!(cache
+            .get_with_policy::<TestData>("closures_archive.00000001", StalenessPolicy::AnyAge)
+            .data()
+            .is_some());
+        assert_eq!(cache.invalidate_other_versions("closures.00000002"), 0);
     }
 }
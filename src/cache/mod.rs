@@ -1,10 +1,16 @@
 //! Cache module for storing API responses to disk
 //!
 //! This module provides a cache manager that persists API responses to the filesystem
-//! with configurable TTL (time-to-live) values. It supports graceful degradation by
-//! returning expired cache entries with an `is_expired` flag, allowing the application
-//! to use stale data when APIs are unavailable.
+//! with a TTL (time-to-live) per entry. Lookups come back `Fresh`, `Stale` with
+//! the entry's age, or a `Miss`, and a `StalenessPolicy` says which stale entries
+//! count, so every client degrades to stale data the same way when its API is
+//! unavailable. Where entries are kept follows the `CachePolicy` installed at
+//! startup.
 
+mod key;
 mod manager;
+mod policy;
 
-pub use manager::CacheManager;
+pub use key::{query_key, query_string};
+pub use manager::{CacheLookup, CacheManager, CacheStats, StalenessPolicy};
+pub use policy::{set_policy, CachePolicy};
@@ -0,0 +1,85 @@
+//! Where cached data is kept
+//!
+//! By default cached responses, favorites, plans and the rest are written
+//! under the XDG cache directory, or under `$BEACH_CLI_CACHE_DIR` if set, for
+//! sandboxed runs, tests and separate profiles. On a shared machine that
+//! leaves a record of which beaches were looked at, so `--cache-dir` moves the
+//! cache somewhere else (an encrypted volume, a tmpfs) and `--no-cache` keeps
+//! everything in memory for the session only. Both flags win over the
+//! environment variable.
+//!
+//! The policy is installed once at startup with `set_policy`, and every
+//! `CacheManager::new` after that follows it.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use directories::ProjectDirs;
+
+use crate::config::env_dir;
+
+/// Environment variable naming a cache directory to use instead of the XDG
+/// one
+pub const CACHE_DIR_ENV: &str = "BEACH_CLI_CACHE_DIR";
+
+/// How cached data is stored
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum CachePolicy {
+    /// Files under the XDG cache directory (`~/.cache/vanbeach/` on Linux),
+    /// or `$BEACH_CLI_CACHE_DIR` if set
+    #[default]
+    Default,
+    /// Files under the given directory (`--cache-dir`)
+    Dir(PathBuf),
+    /// Nothing written to disk; the cache lasts as long as the process
+    /// (`--no-cache`)
+    MemoryOnly,
+}
+
+impl CachePolicy {
+    /// Policy for the `--no-cache` and `--cache-dir` flags
+    pub fn from_flags(no_cache: bool, cache_dir: Option<PathBuf>) -> Self {
+        match (no_cache, cache_dir) {
+            (true, _) => CachePolicy::MemoryOnly,
+            (false, Some(dir)) => CachePolicy::Dir(dir),
+            (false, None) => CachePolicy::Default,
+        }
+    }
+}
+
+/// Directory the default policy writes to: `$BEACH_CLI_CACHE_DIR` if set,
+/// otherwise the XDG cache directory if a home directory is available
+pub fn default_dir() -> Option<PathBuf> {
+    env_dir(CACHE_DIR_ENV).or_else(|| {
+        ProjectDirs::from("", "", "vanbeach").map(|dirs| dirs.cache_dir().to_path_buf())
+    })
+}
+
+/// Policy installed at startup
+static POLICY: OnceLock<CachePolicy> = OnceLock::new();
+
+/// Installs the cache policy, returning false if one was already installed
+pub fn set_policy(policy: CachePolicy) -> bool {
+    POLICY.set(policy).is_ok()
+}
+
+/// The installed cache policy, or the default if none was installed
+pub fn policy() -> &'static CachePolicy {
+    static DEFAULT: CachePolicy = CachePolicy::Default;
+    POLICY.get().unwrap_or(&DEFAULT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_from_flags() {
+        assert_eq!(CachePolicy::from_flags(false, None), CachePolicy::Default);
+        assert_eq!(
+            CachePolicy::from_flags(false, Some(PathBuf::from("/tmp/beach"))),
+            CachePolicy::Dir(PathBuf::from("/tmp/beach"))
+        );
+        assert_eq!(CachePolicy::from_flags(true, None), CachePolicy::MemoryOnly);
+    }
+}
@@ -0,0 +1,386 @@
+//! iCalendar export of good windows at favorite beaches
+//!
+//! `vanbeach calendar > beach.ics` writes one tentative event per favorite
+//! beach, activity and day that has a window scoring at least the minimum:
+//! the day's best run of consecutive hours above it. The calendar covers as
+//! far ahead as the hourly forecast goes, today and tomorrow.
+//!
+//! Each event's UID is built from its date, beach and activity, so running
+//! the export again (say hourly from cron) gives the same UIDs, and a
+//! calendar that imports or subscribes to the file updates its events in
+//! place, moving a window that shifted instead of adding a second one.
+
+use std::fmt::Write as _;
+
+use chrono::{DateTime, Duration, NaiveDate, Timelike, Utc};
+
+use crate::activities::Activity;
+use crate::data::BeachConditions;
+use crate::scoring::ActivityScorer;
+
+/// Domain part of every event UID
+pub(crate) const UID_DOMAIN: &str = "vanbeach";
+
+/// A day's best window for an activity at a beach
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CalendarWindow {
+    date: NaiveDate,
+    /// First hour of the window
+    start_hour: u8,
+    /// Hour the window ends, exclusive
+    end_hour: u8,
+    /// Score of the window's best hour
+    peak: u8,
+}
+
+/// Renders the calendar of windows scoring at least `min_score`
+///
+/// Beaches, activities and days come out in the order given, so the file
+/// only changes when the windows do (and in each event's DTSTAMP).
+pub fn export_calendar(
+    beaches: &[&BeachConditions],
+    activities: &[Activity],
+    min_score: u8,
+    now: DateTime<Utc>,
+) -> String {
+    let mut out = String::new();
+    begin_calendar(&mut out, "Beach windows");
+
+    for conditions in beaches {
+        let beach = &conditions.beach;
+        for &activity in activities {
+            if !activity.offered_at(&beach.id) {
+                continue;
+            }
+            for window in best_windows(conditions, activity, min_score, now) {
+                write_event(&mut out, conditions, activity, &window, now);
+            }
+        }
+    }
+
+    line(&mut out, "END:VCALENDAR");
+    out
+}
+
+/// Today's best window from the beach's current hour on, then tomorrow's
+fn best_windows(
+    conditions: &BeachConditions,
+    activity: Activity,
+    min_score: u8,
+    now: DateTime<Utc>,
+) -> Vec<CalendarWindow> {
+    let local = conditions.beach.timezone.local(now);
+    let today = local.date();
+    let days = [
+        (
+            today,
+            ActivityScorer::hourly_from(conditions, activity, local.hour() as u8)
+                .map(|(hour, slot)| (hour, slot.score))
+                .collect::<Vec<_>>(),
+        ),
+        (
+            today + Duration::days(1),
+            ActivityScorer::tomorrow(conditions, activity)
+                .map(|(hour, slot)| (hour, slot.score))
+                .collect(),
+        ),
+    ];
+    days.into_iter()
+        .filter_map(|(date, scores)| best_run(&scores, min_score).map(|w| w.on(date)))
+        .collect()
+}
+
+/// A run of consecutive hours at or above a score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Run {
+    /// First hour of the run
+    pub(crate) start_hour: u8,
+    /// Hour the run ends, exclusive
+    pub(crate) end_hour: u8,
+    /// Score of the run's best hour
+    pub(crate) peak: u8,
+}
+
+impl Run {
+    fn on(self, date: NaiveDate) -> CalendarWindow {
+        CalendarWindow {
+            date,
+            start_hour: self.start_hour,
+            end_hour: self.end_hour,
+            peak: self.peak,
+        }
+    }
+}
+
+/// The best run of consecutive hours scoring at least `min_score`: the
+/// highest peak, then the longest, then the earliest
+pub(crate) fn best_run(scores: &[(u8, u8)], min_score: u8) -> Option<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+    for &(hour, score) in scores {
+        if score < min_score {
+            continue;
+        }
+        match runs.last_mut() {
+            Some(run) if run.end_hour == hour => {
+                run.end_hour = hour + 1;
+                run.peak = run.peak.max(score);
+            }
+            _ => runs.push(Run {
+                start_hour: hour,
+                end_hour: hour + 1,
+                peak: score,
+            }),
+        }
+    }
+    // max_by_key keeps the last of equals, so search from the end
+    runs.into_iter()
+        .rev()
+        .max_by_key(|run| (run.peak, run.end_hour - run.start_hour))
+}
+
+/// Writes one window as a tentative, free-time event
+fn write_event(
+    out: &mut String,
+    conditions: &BeachConditions,
+    activity: Activity,
+    window: &CalendarWindow,
+    now: DateTime<Utc>,
+) {
+    let beach = &conditions.beach;
+    let at = |hour: u8| {
+        let local = window.date.and_hms_opt(0, 0, 0).unwrap() + Duration::hours(hour as i64);
+        beach.timezone.to_utc(local)
+    };
+
+    line(out, "BEGIN:VEVENT");
+    line(
+        out,
+        &format!(
+            "UID:{}@{}",
+            event_uid(window.date, &beach.id, activity),
+            UID_DOMAIN
+        ),
+    );
+    line(out, &format!("DTSTAMP:{}", ical_utc(now)));
+    line(out, &format!("DTSTART:{}", ical_utc(at(window.start_hour))));
+    line(out, &format!("DTEND:{}", ical_utc(at(window.end_hour))));
+    line(
+        out,
+        &format!(
+            "SUMMARY:{}",
+            escape_text(&format!(
+                "{} at {} (score {})",
+                activity.label(),
+                beach.name,
+                window.peak
+            ))
+        ),
+    );
+    line(out, &format!("LOCATION:{}", escape_text(&beach.name)));
+    line(
+        out,
+        &format!("GEO:{:.5};{:.5}", beach.latitude, beach.longitude),
+    );
+    line(
+        out,
+        &format!(
+            "DESCRIPTION:{}",
+            escape_text(&format!(
+                "Forecast window for {}, peaking at {}/100. Updated each time the calendar is exported.",
+                activity.label().to_lowercase(),
+                window.peak
+            ))
+        ),
+    );
+    line(out, "STATUS:TENTATIVE");
+    line(out, "TRANSP:TRANSPARENT");
+    line(out, "END:VEVENT");
+}
+
+/// The part of an event's UID before the `@`, e.g.
+/// "20260715-kitsilano-swimming"
+pub(crate) fn event_uid(date: NaiveDate, beach_id: &str, activity: Activity) -> String {
+    format!(
+        "{}-{}-{}",
+        date.format("%Y%m%d"),
+        beach_id,
+        format!("{:?}", activity).to_lowercase()
+    )
+}
+
+/// A UTC time in iCalendar form, e.g. "20260715T170000Z"
+pub(crate) fn ical_utc(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes backslashes, semicolons, commas and newlines in a TEXT value
+pub(crate) fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Starts a published calendar named `name`, up to its first event
+pub(crate) fn begin_calendar(out: &mut String, name: &str) {
+    line(out, "BEGIN:VCALENDAR");
+    line(out, "VERSION:2.0");
+    line(out, &format!("PRODID:-//vanbeach//{}//EN", name));
+    line(out, "CALSCALE:GREGORIAN");
+    line(out, "METHOD:PUBLISH");
+    line(out, &format!("X-WR-CALNAME:{}", name));
+}
+
+/// Appends a content line; iCalendar lines end in CRLF
+pub(crate) fn line(out: &mut String, content: &str) {
+    let _ = write!(out, "{}\r\n", content);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{
+        get_beach_by_id, DataQuality, HourlyForecast, Weather, WeatherCondition, WeatherProvider,
+    };
+    use chrono::{NaiveTime, TimeZone};
+
+    fn forecast(hour: u8) -> HourlyForecast {
+        HourlyForecast {
+            hour,
+            temperature: 26.0,
+            feels_like: 26.0,
+            condition: WeatherCondition::Clear,
+            wind: 5.0,
+            wind_direction: "W".to_string(),
+            uv: 4.0,
+            precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        }
+    }
+
+    fn conditions() -> BeachConditions {
+        BeachConditions {
+            beach: get_beach_by_id("kitsilano").unwrap().clone(),
+            weather: Some(Weather {
+                temperature: 26.0,
+                feels_like: 26.0,
+                condition: WeatherCondition::Clear,
+                weather_code: None,
+                humidity: 50,
+                wind: 5.0,
+                wind_direction: Some(270.0),
+                uv: 4.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 10, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: (0..24).map(forecast).collect(),
+                tomorrow: (0..24).map(forecast).collect(),
+                source: WeatherProvider::default(),
+                parse_warnings: Vec::new(),
+            }),
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    /// 10am in Vancouver in July
+    fn morning() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, 15, 17, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_best_run_prefers_the_peak_then_the_longest() {
+        let scores = [
+            (9, 60),
+            (10, 75),
+            (11, 80),
+            (12, 40),
+            (13, 80),
+            (14, 72),
+            (15, 71),
+        ];
+        assert_eq!(
+            best_run(&scores, 70),
+            Some(Run {
+                start_hour: 13,
+                end_hour: 16,
+                peak: 80
+            })
+        );
+        assert_eq!(
+            best_run(&scores[..3], 70),
+            Some(Run {
+                start_hour: 10,
+                end_hour: 12,
+                peak: 80
+            })
+        );
+        assert_eq!(best_run(&scores, 90), None);
+    }
+
+    #[test]
+    fn test_calendar_has_one_tentative_event_per_day_with_stable_uids() {
+        let conditions = conditions();
+        let ics = export_calendar(&[&conditions], &[Activity::Sunbathing], 0, morning());
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2, "today and tomorrow");
+        assert!(ics.contains("UID:20260715-kitsilano-sunbathing@vanbeach\r\n"));
+        assert!(ics.contains("UID:20260716-kitsilano-sunbathing@vanbeach\r\n"));
+        assert_eq!(ics.matches("STATUS:TENTATIVE").count(), 2);
+        assert!(ics.contains("SUMMARY:Sunbathing at Kitsilano Beach (score "));
+
+        // Today's window can't start before the current hour
+        let start = ics
+            .lines()
+            .find(|line| line.starts_with("DTSTART:20260715"))
+            .unwrap();
+        assert!(start >= "DTSTART:20260715T170000Z", "{}", start);
+
+        // A rerun later gives the same events, bar the timestamp
+        let later = export_calendar(
+            &[&conditions],
+            &[Activity::Sunbathing],
+            0,
+            morning() + Duration::minutes(5),
+        );
+        let without_stamp = |ics: &str| -> Vec<String> {
+            ics.lines()
+                .filter(|l| !l.starts_with("DTSTAMP"))
+                .map(String::from)
+                .collect()
+        };
+        assert_eq!(without_stamp(&ics), without_stamp(&later));
+    }
+
+    #[test]
+    fn test_calendar_leaves_out_windows_below_the_threshold() {
+        let conditions = conditions();
+        let ics = export_calendar(&[&conditions], &[Activity::Sunbathing], 101, morning());
+        assert!(!ics.contains("BEGIN:VEVENT"));
+    }
+
+    #[test]
+    fn test_text_values_are_escaped() {
+        assert_eq!(
+            escape_text("Peace & Quiet, a; b\\c"),
+            "Peace & Quiet\\, a\\; b\\\\c"
+        );
+        assert_eq!(
+            event_uid(
+                NaiveDate::from_ymd_opt(2026, 7, 15).unwrap(),
+                "english-bay",
+                Activity::DogWalk
+            ),
+            "20260715-english-bay-dogwalk"
+        );
+    }
+}
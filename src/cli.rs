@@ -1,12 +1,27 @@
 //! Command-line interface parsing for Vancouver Beach CLI
 //!
 //! This module handles parsing of CLI arguments using clap, including the
-//! --plan flag for direct Plan Trip mode access with optional activity selection.
+//! --plan flag for direct Plan Trip mode access with optional activity selection,
+//! the --watch flag for the auto-refreshing dashboard, the --beach flag for a
+//! beach's detail view, and the subcommands. Activity names and beach IDs are
+//! checked by clap, so they show up in `--help`, shell completions and the man
+//! page.
 
-use clap::Parser;
+use std::io::{self, Write};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use clap::builder::{PossibleValue, PossibleValuesParser, TypedValueParser};
+use clap::{ArgGroup, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
 use thiserror::Error;
 
 use crate::activities::Activity;
+use crate::cache::CachePolicy;
+use crate::data::beach::all_beaches;
+use crate::data::recording::HttpRecording;
+use crate::data::BeachId;
 
 /// Error types for CLI argument parsing
 #[derive(Debug, Error)]
@@ -21,6 +36,7 @@ pub enum CliError {
 #[command(name = "vanbeach")]
 #[command(about = "Vancouver beach conditions and trip planning")]
 #[command(version)]
+#[command(group(ArgGroup::new("view").args(["plan", "watch", "beach"])))]
 pub struct Cli {
     /// Open directly in plan mode, optionally with a pre-selected activity
     ///
@@ -30,8 +46,659 @@ pub struct Cli {
     ///   vanbeach --plan sunset   # Open in Plan Trip mode with Sunset selected
     ///
     /// Valid activities: swim, sun, sail, sunset, peace, quiet
-    #[arg(long, value_name = "ACTIVITY")]
+    #[arg(long, value_name = "ACTIVITY", ignore_case = true, value_parser = activity_parser())]
     pub plan: Option<Option<String>>,
+
+    /// Open the auto-refreshing dashboard of favorite beaches
+    ///
+    /// Shows a compact card per favorite beach (or every beach if none are
+    /// marked) and refreshes itself, so it can be left running on a spare screen.
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Open directly in a beach's detail view, e.g. `--beach kitsilano`
+    #[arg(long, value_name = "BEACH_ID", value_parser = beach_id_parser())]
+    pub beach: Option<BeachId>,
+
+    /// Print the scoring weights (including config overrides) and each
+    /// beach's current activity scores, then exit without starting the TUI
+    #[arg(long)]
+    pub score_dump: bool,
+
+    /// Record key presses, data and state changes to a session file for bug reports
+    #[arg(long, value_name = "FILE", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// Replay a recorded session file instead of fetching live data
+    #[arg(long, value_name = "FILE")]
+    pub replay: Option<PathBuf>,
+
+    /// Activity to select on startup, overriding `default_activity` in the config
+    ///
+    /// Also applies to --score-dump and export-site, which then report just
+    /// that activity. Valid activities: swim, sun, sail, sunset, peace, quiet
+    #[arg(
+        long,
+        global = true,
+        value_name = "ACTIVITY",
+        ignore_case = true,
+        value_parser = activity_parser()
+    )]
+    pub activity: Option<String>,
+
+    /// Keep recent raw API responses and enable the debug view (`D`)
+    #[arg(long, short)]
+    pub verbose: bool,
+
+    /// Describe tides in words instead of drawing charts, for screen readers
+    /// and terminals without Unicode block characters
+    #[arg(long, visible_alias = "ascii")]
+    pub plain: bool,
+
+    /// Also send a desktop notification (notify-send or osascript) 30
+    /// minutes before the best window for the selected activity
+    #[arg(long)]
+    pub notify: bool,
+
+    /// Tint borders and headers with the selected beach's conditions:
+    /// blue-grey in the rain, warm gold around sunset
+    #[arg(long)]
+    pub ambient: bool,
+
+    /// Save data on tethered connections: request fewer hourly weather
+    /// fields and today only, skip wave forecasts, and keep cached water
+    /// quality and closures longer
+    #[arg(long)]
+    pub low_bandwidth: bool,
+
+    /// Also fetch the wind from several weather models and badge Sailing
+    /// scores with how far they agree
+    #[arg(long)]
+    pub wind_models: bool,
+
+    /// Cut redraw traffic over slow SSH: draw only when something on screen
+    /// changes and use the eight basic colors
+    #[arg(long)]
+    pub slow_link: bool,
+
+    /// Print each screen as plain lines of text, top to bottom, then only
+    /// the lines that change, instead of drawing in place; for terminal
+    /// screen readers. Tides are described in words, as with --plain
+    #[arg(long)]
+    pub linear: bool,
+
+    /// Ring the terminal bell or flash the screen for best window reminders
+    /// and water quality advisory changes, e.g. in a background tmux pane
+    #[arg(long, value_name = "STYLE")]
+    pub alert: Option<AlertStyle>,
+
+    /// Listen on a Unix domain socket for commands that drive the TUI, one
+    /// per line: `select <beach>`, `activity <name>` and `screenshot`, for
+    /// demos, integration tests and accessibility tooling
+    #[arg(long, value_name = "SOCKET")]
+    pub control: Option<PathBuf>,
+
+    /// Colors for color vision deficiencies; statuses and score grades also
+    /// get letters so they don't rely on hue alone
+    #[arg(long, value_name = "PALETTE", default_value = "standard")]
+    pub palette: Palette,
+
+    /// How the top three best windows are marked: medal emoji, or colored
+    /// "1." to "3." for fonts that draw the medals double-width
+    #[arg(long, value_name = "MARKERS", default_value = "medals")]
+    pub ranks: RankMarkers,
+
+    /// Keep cached data in memory only, writing nothing to disk, e.g. on a
+    /// shared computer; favorites, plans and history last for the session
+    #[arg(long, global = true, conflicts_with = "cache_dir")]
+    pub no_cache: bool,
+
+    /// Store cached data under this directory instead of the default cache
+    /// directory
+    #[arg(long, global = true, value_name = "DIR")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Save every HTTP request and the response it got under this
+    /// directory, to replay later with --replay-http
+    #[arg(
+        long,
+        global = true,
+        value_name = "DIR",
+        conflicts_with = "replay_http"
+    )]
+    pub record_http: Option<PathBuf>,
+
+    /// Answer HTTP requests from responses saved with --record-http instead
+    /// of the network, for offline development and repeatable CI runs;
+    /// requests with nothing recorded fail
+    #[arg(long, global = true, value_name = "DIR")]
+    pub replay_http: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// How an alert gets attention beyond its toast
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertStyle {
+    /// Ring the terminal bell
+    Bell,
+    /// Briefly invert the screen
+    Flash,
+}
+
+/// Color palette the TUI is drawn in
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Palette {
+    /// The regular red/yellow/green palette
+    #[default]
+    Standard,
+    /// Blue and orange in place of green and red, for deuteranopia and
+    /// protanopia
+    RedGreen,
+    /// Pink in place of yellow, for tritanopia
+    BlueYellow,
+}
+
+impl Palette {
+    /// Whether this is one of the colorblind-safe palettes
+    pub fn is_colorblind(self) -> bool {
+        self != Palette::Standard
+    }
+}
+
+/// How first, second and third place are marked in rankings
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RankMarkers {
+    /// Gold, silver and bronze medal emoji
+    #[default]
+    Medals,
+    /// "1.", "2." and "3." in gold, silver and bronze
+    Numerals,
+}
+
+/// Format of a headless export
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// A JSON array
+    Json,
+}
+
+/// What `vanbeach crowd` exports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrowdExport {
+    /// Beach to predict for
+    pub beach_id: BeachId,
+    /// Day to predict, today if not given
+    pub date: Option<NaiveDate>,
+    /// Output format
+    pub format: ExportFormat,
+}
+
+/// What `vanbeach statusline` prints
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatuslineExport {
+    /// Beach to show
+    pub beach_id: BeachId,
+    /// Whether to mark stale fields and fail on very stale sources
+    pub staleness: bool,
+}
+
+/// What `vanbeach conditions` prints
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionsExport {
+    /// Beaches to print, in order
+    pub beach_ids: Vec<BeachId>,
+    /// Whether to print a JSON array instead of a status line per beach
+    pub json: bool,
+}
+
+/// Score a window needs to make the calendar, unless --min-score says otherwise
+pub const DEFAULT_CALENDAR_MIN_SCORE: u8 = 70;
+
+/// What `vanbeach calendar` exports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarExport {
+    /// Lowest score a window needs to become an event
+    pub min_score: u8,
+}
+
+/// Hours spent at each beach of a crawl, unless --stay says otherwise
+pub const DEFAULT_CRAWL_STAY_HOURS: u8 = 2;
+
+/// A beach to visit on a crawl and what to do there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrawlStop {
+    pub beach_id: BeachId,
+    pub activity: Activity,
+}
+
+/// Parses a crawl stop written as BEACH_ID:ACTIVITY, e.g. "jericho:sail"
+fn parse_crawl_stop(arg: &str) -> Result<CrawlStop, String> {
+    let (beach, activity) = arg.split_once(':').ok_or_else(|| {
+        format!(
+            "expected BEACH_ID:ACTIVITY, e.g. jericho:sail, got '{}'",
+            arg
+        )
+    })?;
+    let beach = all_beaches()
+        .iter()
+        .find(|known| known.id == beach)
+        .ok_or_else(|| format!("unknown beach '{}'", beach))?;
+    let activity = parse_activity_arg(activity).map_err(|e| e.to_string())?;
+    if !activity.offered_at(&beach.id) {
+        return Err(format!(
+            "{} isn't offered at {}",
+            activity.label(),
+            beach.name
+        ));
+    }
+    Ok(CrawlStop {
+        beach_id: beach.id.clone(),
+        activity,
+    })
+}
+
+/// What `vanbeach crawl` plans
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrawlExport {
+    /// Beaches to visit with what to do at each, in order of preference
+    pub stops: Vec<CrawlStop>,
+    /// Hours to spend at each beach
+    pub stay_hours: u8,
+    /// Whether to plan tomorrow instead of the rest of today
+    pub tomorrow: bool,
+    /// Whether to print an iCalendar file instead of the itinerary
+    pub ics: bool,
+}
+
+/// Journal entries `vanbeach log` prints, unless --limit says otherwise
+pub const DEFAULT_LOG_LIMIT: usize = 20;
+
+/// What `vanbeach log` prints
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogExport {
+    /// Most entries to print, the latest ones
+    pub limit: usize,
+    /// Only entries about this beach, plus shoreline-wide ones
+    pub beach_id: Option<BeachId>,
+}
+
+/// Past days `vanbeach backfill` fetches, unless --days says otherwise
+pub const DEFAULT_BACKFILL_DAYS: u16 = 14;
+
+/// What `vanbeach backfill` fetches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackfillExport {
+    /// Past days to fetch, ending yesterday
+    pub days: u16,
+    /// Beaches to fetch for, every beach if empty
+    pub beach_ids: Vec<BeachId>,
+}
+
+/// Where `vanbeach post` posts
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostTarget {
+    /// A Mastodon account, through its instance's API
+    Mastodon,
+    /// A Bluesky account, through its PDS
+    Bluesky,
+}
+
+/// What `vanbeach post` sends
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostExport {
+    /// Accounts to post to, in order
+    pub targets: Vec<PostTarget>,
+    /// Whether to print the post instead of sending it
+    pub dry_run: bool,
+}
+
+/// Format of the condition table export
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    /// A fixed-width grid for the terminal
+    Text,
+    /// A standalone HTML page
+    Html,
+}
+
+/// What `vanbeach table` exports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableExport {
+    /// Beach to tabulate
+    pub beach_id: BeachId,
+    /// Output format
+    pub format: TableFormat,
+}
+
+/// Subcommands that run once and exit instead of starting the TUI
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Write a static HTML site with current conditions for every beach
+    ///
+    /// Creates index.html plus one page per beach. Run it from cron to keep a
+    /// self-hosted conditions page up to date.
+    ExportSite {
+        /// Directory to write the site into (created if missing)
+        #[arg(long, value_name = "DIR", default_value = "site")]
+        out: PathBuf,
+    },
+
+    /// Keep beach data refreshed and expose Prometheus metrics at /metrics
+    ///
+    /// Reports API request counts and durations, cache hit rates and how old
+    /// each beach's data is, for monitoring a self-hosted setup.
+    Serve {
+        /// Address to listen on
+        #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:9187")]
+        listen: SocketAddr,
+    },
+
+    /// Print the JSON Schema of the serialized output types
+    ///
+    /// Covers cached beach conditions, session recordings and pinned plans,
+    /// so integrators can validate against a stable contract.
+    Schema,
+
+    /// Print the crowd model's hourly predictions for a beach
+    ///
+    /// Lists every hour of the day with the inputs used (weekday, holiday,
+    /// seasonal, hourly and weather factors), for research use, e.g.
+    ///   vanbeach crowd --beach kitsilano --format csv > kits.csv
+    Crowd {
+        /// Beach to predict for
+        #[arg(long, value_name = "BEACH_ID", value_parser = beach_id_parser())]
+        beach: BeachId,
+
+        /// Day to predict (YYYY-MM-DD), today if not given; only today and
+        /// tomorrow are adjusted for the forecast
+        #[arg(long, value_name = "DATE")]
+        date: Option<NaiveDate>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "csv")]
+        format: ExportFormat,
+    },
+
+    /// Print a condition table for a beach, hours across and conditions down
+    ///
+    /// A dense Windguru-style grid of the rest of today and tomorrow: air
+    /// temperature, wind, gusts, wave height, tide and the score for the
+    /// activity from --activity (swimming if none is set), e.g.
+    ///   vanbeach --activity sail table --beach jericho --format html > jericho.html
+    Table {
+        /// Beach to tabulate
+        #[arg(long, value_name = "BEACH_ID", value_parser = beach_id_parser())]
+        beach: BeachId,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        format: TableFormat,
+    },
+
+    /// Print a one-line status of a beach for terminal status bars
+    ///
+    /// Shows temperature, wind, water quality and tide, e.g.
+    ///   Kitsilano Beach 22°C W 12km/h Q:Safe T:2.5m↑
+    /// for tmux, polybar and the like.
+    Statusline {
+        /// Beach to show
+        #[arg(long, value_name = "BEACH_ID", value_parser = beach_id_parser())]
+        beach: BeachId,
+
+        /// Mark fields from stale data with `*` (`!` when very stale) and
+        /// exit with code 3 if any source is very stale
+        #[arg(long)]
+        staleness: bool,
+    },
+
+    /// Print current conditions for one or more beaches
+    ///
+    /// The beaches are fetched together, sharing one rate limit, so scripts
+    /// can get several in one call, e.g.
+    ///   vanbeach conditions kitsilano jericho spanish-banks --json
+    Conditions {
+        /// Beaches to print, in order
+        #[arg(value_name = "BEACH_ID", value_parser = beach_id_parser(),
+              required_unless_present = "all", conflicts_with = "all")]
+        beaches: Vec<BeachId>,
+
+        /// Print every beach
+        #[arg(long)]
+        all: bool,
+
+        /// Print a JSON array of condition objects instead of a status line
+        /// per beach
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Print an iCalendar file of good windows at favorite beaches
+    ///
+    /// Each beach, activity and day with a window scoring at least
+    /// --min-score becomes a tentative event for its best window, with a UID
+    /// that stays the same between runs so calendars update it in place, e.g.
+    ///   vanbeach calendar > ~/beach-windows.ics
+    /// Only the activity from --activity is covered if one is set.
+    Calendar {
+        /// Lowest score a window needs to become an event
+        #[arg(long, value_name = "SCORE", default_value_t = DEFAULT_CALENDAR_MIN_SCORE,
+              value_parser = clap::value_parser!(u8).range(0..=100))]
+        min_score: u8,
+    },
+
+    /// Plan a day visiting two or three beaches, each for its own activity
+    ///
+    /// Fits a stay at each beach into the day at the times that score best
+    /// overall, leaving time to walk, bike or drive between them, e.g.
+    ///   vanbeach crawl kitsilano:swim jericho:sail spanish-banks-east:sunset
+    /// With --ics the plan prints as an iCalendar file to import instead.
+    Crawl {
+        /// Stops as BEACH_ID:ACTIVITY
+        #[arg(value_name = "BEACH_ID:ACTIVITY", value_parser = parse_crawl_stop,
+              num_args = 2..=3, required = true)]
+        stops: Vec<CrawlStop>,
+
+        /// Hours to spend at each beach
+        #[arg(long, value_name = "HOURS", default_value_t = DEFAULT_CRAWL_STAY_HOURS,
+              value_parser = clap::value_parser!(u8).range(1..=6))]
+        stay: u8,
+
+        /// Plan tomorrow instead of the rest of today
+        #[arg(long)]
+        tomorrow: bool,
+
+        /// Print an iCalendar file of the plan instead of the itinerary
+        #[arg(long)]
+        ics: bool,
+    },
+
+    /// Post a summary of today's beach conditions to Mastodon or Bluesky
+    ///
+    /// The best beach for --activity (swimming if none is set) and its
+    /// window today, plus any water quality advisories and closures, in one
+    /// post short enough for either, so a community beach bot can run from
+    /// cron, e.g.
+    ///   vanbeach --activity sunset post --to mastodon --to bluesky
+    /// Credentials come from the environment: BEACH_CLI_MASTODON_URL and
+    /// BEACH_CLI_MASTODON_TOKEN, or BEACH_CLI_BLUESKY_HANDLE and
+    /// BEACH_CLI_BLUESKY_APP_PASSWORD.
+    Post {
+        /// Account to post to; repeat to post to both
+        #[arg(
+            long,
+            value_enum,
+            value_name = "SERVICE",
+            required_unless_present = "dry_run"
+        )]
+        to: Vec<PostTarget>,
+
+        /// Print the post instead of sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Print the latest entries of the condition change journal
+    ///
+    /// While the TUI runs it notes advisories issued or lifted, heat
+    /// warnings and king tides in a journal, oldest first, e.g.
+    ///   2026-07-15  2:30 PM  Advisory   Kitsilano Beach: water quality advisory issued
+    Log {
+        /// Most entries to print
+        #[arg(long, value_name = "N", default_value_t = DEFAULT_LOG_LIMIT)]
+        limit: usize,
+
+        /// Only entries about this beach, plus heat warnings and king tides
+        #[arg(long, value_name = "BEACH_ID", value_parser = beach_id_parser())]
+        beach: Option<BeachId>,
+    },
+
+    /// Fetch the weather beaches actually had over the past days
+    ///
+    /// Hourly temperature, weather, wind and precipitation from the
+    /// Open-Meteo archive are kept per beach next to the score history, up
+    /// to a year of days, for checking how forecasts and best windows held
+    /// up. The archive lags a few days, so the latest days may be missing,
+    /// e.g.
+    ///   vanbeach backfill --days 30 --beach kitsilano --beach jericho
+    Backfill {
+        /// Past days to fetch, ending yesterday
+        #[arg(long, value_name = "DAYS", default_value_t = DEFAULT_BACKFILL_DAYS,
+              value_parser = clap::value_parser!(u16).range(1..=92))]
+        days: u16,
+
+        /// Beach to fetch for; repeat for more, every beach if none
+        #[arg(long, value_name = "BEACH_ID", value_parser = beach_id_parser())]
+        beach: Vec<BeachId>,
+    },
+
+    /// Import a CSV dump of water quality sample results
+    ///
+    /// Reads a season's results as downloaded from the city's beach water
+    /// quality dataset and merges them into a local archive. The archive
+    /// fills in each station's sample history and is used when the API is
+    /// unreachable and nothing is cached.
+    ImportWq {
+        /// CSV file to import (comma- or semicolon-separated)
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+    },
+
+    /// Download the city's beach list to replace the built-in one
+    ///
+    /// Fetches the beaches dataset from Vancouver Open Data (names,
+    /// coordinates and amenities) and saves it to the data directory. From
+    /// then on the saved list is used instead of the beaches built into the
+    /// app; run it again to pick up changes.
+    UpdateBeaches,
+
+    /// Capture live API responses as parser test fixtures
+    ///
+    /// Fetches one beach's weather (Open-Meteo and MET Norway), water quality
+    /// and the Park Board closures, bypassing the cache, and writes each
+    /// response, sanitized, to a JSON file the parser tests load. Run it from
+    /// the repository root when an API changes, e.g.
+    ///   vanbeach gen-fixtures --out tests/fixtures/
+    GenFixtures {
+        /// Directory to write the fixtures into (created if missing)
+        #[arg(long, value_name = "DIR", default_value = "tests/fixtures")]
+        out: PathBuf,
+    },
+
+    /// Print a shell completion script
+    ///
+    /// For example, for bash:
+    ///   vanbeach completions bash > ~/.local/share/bash-completion/completions/vanbeach
+    Completions {
+        /// Shell to generate completions for
+        shell: Shell,
+    },
+
+    /// Print the man page (roff), e.g. `vanbeach man > vanbeach.1`
+    Man,
+
+    /// Show the local feature usage counts
+    ///
+    /// With `"usage_metrics": true` in the config, the TUI counts how often
+    /// each screen and overlay is opened and each activity picked. The counts
+    /// stay on this machine; nothing is sent anywhere. Export them to share
+    /// by hand, e.g. in an issue.
+    Metrics {
+        #[command(subcommand)]
+        action: MetricsAction,
+    },
+
+    /// Work with the data files built into the binary
+    ///
+    /// The beach list, theme colors and WMO weather code descriptions are
+    /// built in. A file of the same name in /etc/vanbeach/assets, then in
+    /// the user config directory's assets folder, is layered over each, key
+    /// by key. Dump the built-in files to start an override from, e.g.
+    ///   vanbeach assets dump
+    Assets {
+        #[command(subcommand)]
+        action: AssetsAction,
+    },
+}
+
+/// What `vanbeach assets` does with the built-in data files
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum AssetsAction {
+    /// Write the built-in files out for customizing
+    Dump {
+        /// Directory to write into (created if missing); the user config
+        /// directory's assets folder by default
+        #[arg(long, value_name = "DIR")]
+        dir: Option<PathBuf>,
+        /// Overwrite files already there
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// What `vanbeach metrics` does with the usage counts
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricsAction {
+    /// Print the counts, most used first, and whether counting is on
+    Show,
+    /// Print the counts as JSON
+    Export,
+}
+
+/// Accepts the activity names and aliases `Activity::from_str` understands,
+/// listing them for help and completions
+fn activity_parser() -> PossibleValuesParser {
+    PossibleValuesParser::new([
+        PossibleValue::new("swim").aliases(["swimming"]),
+        PossibleValue::new("sun").aliases(["sunbathing", "sunbathe"]),
+        PossibleValue::new("sail").aliases(["sailing"]),
+        PossibleValue::new("sunset"),
+        PossibleValue::new("peace").aliases(["quiet"]),
+        PossibleValue::new("dog").aliases(["dogs", "dogwalk", "dog-walk"]),
+    ])
+}
+
+/// Accepts the IDs of the beaches in the registry
+fn beach_id_parser() -> impl TypedValueParser<Value = BeachId> {
+    PossibleValuesParser::new(
+        all_beaches()
+            .iter()
+            .map(|beach| PossibleValue::new(beach.id.as_str()).help(beach.name.as_str())),
+    )
+    .map(|id| BeachId::new(&id))
+}
+
+/// Writes the completion script for `shell` to `out`
+pub fn write_completions(shell: Shell, out: &mut dyn Write) {
+    clap_complete::generate(shell, &mut Cli::command(), "vanbeach", out);
+}
+
+/// Writes the man page, covering every option and subcommand, to `out`
+pub fn write_man_page(out: &mut dyn Write) -> io::Result<()> {
+    clap_mangen::Man::new(Cli::command()).render(out)
 }
 
 /// Configuration derived from CLI arguments for application startup
@@ -41,6 +708,82 @@ pub struct StartupConfig {
     pub start_in_plan_trip: bool,
     /// Initial activity to select (if specified)
     pub initial_activity: Option<Activity>,
+    /// Whether to start directly in the Dashboard state
+    pub start_in_dashboard: bool,
+    /// Whether to print the score dump and exit instead of starting the TUI
+    pub score_dump: bool,
+    /// Session file to record to (if specified)
+    pub record_path: Option<PathBuf>,
+    /// Session file to replay (if specified)
+    pub replay_path: Option<PathBuf>,
+    /// Directory to export the static site to, instead of starting the TUI
+    pub export_site_dir: Option<PathBuf>,
+    /// Address to serve metrics on, instead of starting the TUI
+    pub serve_addr: Option<SocketAddr>,
+    /// Whether to print the output JSON Schema instead of starting the TUI
+    pub print_schema: bool,
+    /// Shell to print a completion script for, instead of starting the TUI
+    pub completions: Option<Shell>,
+    /// Whether to print the man page instead of starting the TUI
+    pub print_man: bool,
+    /// Crowd predictions to print, instead of starting the TUI
+    pub crowd_export: Option<CrowdExport>,
+    /// Condition table to print, instead of starting the TUI
+    pub table_export: Option<TableExport>,
+    /// Status line to print, instead of starting the TUI
+    pub statusline: Option<StatuslineExport>,
+    /// Beach conditions to print, instead of starting the TUI
+    pub conditions: Option<ConditionsExport>,
+    /// Calendar of good windows to print, instead of starting the TUI
+    pub calendar: Option<CalendarExport>,
+    /// Beach crawl to plan and print, instead of starting the TUI
+    pub crawl: Option<CrawlExport>,
+    /// Past weather to fetch and store, instead of starting the TUI
+    pub backfill: Option<BackfillExport>,
+    /// Journal entries to print, instead of starting the TUI
+    pub log: Option<LogExport>,
+    /// Daily summary to post, instead of starting the TUI
+    pub post: Option<PostExport>,
+    /// Water quality CSV dump to import, instead of starting the TUI
+    pub import_wq_path: Option<PathBuf>,
+    /// Whether to download the beach registry, instead of starting the TUI
+    pub update_beaches: bool,
+    /// Directory to write parser test fixtures to, instead of starting the TUI
+    pub gen_fixtures_dir: Option<PathBuf>,
+    /// What to do with the usage counts, instead of starting the TUI
+    pub usage_metrics: Option<MetricsAction>,
+    /// What to do with the built-in data files, instead of starting the TUI
+    pub assets: Option<AssetsAction>,
+    /// Beach whose detail view to open once data loads (from --beach)
+    pub initial_beach: Option<BeachId>,
+    /// Whether to keep raw API responses for the debug view
+    pub verbose: bool,
+    /// Whether to describe tides in words instead of charts
+    pub plain: bool,
+    /// Whether best window reminders also go to the desktop
+    pub notify: bool,
+    /// Whether borders and headers follow the selected beach's conditions
+    pub ambient: bool,
+    /// Whether to request less data and keep cached data longer
+    pub low_bandwidth: bool,
+    /// Whether the wind is also fetched from several weather models
+    pub wind_models: bool,
+    /// Whether frames are only drawn when something changed, in basic colors
+    pub slow_link: bool,
+    /// Whether frames are printed as lines of text instead of drawn in place
+    pub linear: bool,
+    /// How alerts get attention beyond their toast, if at all
+    pub alert: Option<AlertStyle>,
+    /// Socket to take commands on, if any
+    pub control_path: Option<PathBuf>,
+    /// Palette the TUI is drawn in
+    pub palette: Palette,
+    /// How the top three of a ranking are marked
+    pub rank_markers: RankMarkers,
+    /// Where cached data is kept
+    pub cache_policy: CachePolicy,
+    /// Whether HTTP traffic is recorded or replayed, if either
+    pub http_recording: Option<HttpRecording>,
 }
 
 /// Parses an activity string argument into an Activity enum.
@@ -65,27 +808,157 @@ impl StartupConfig {
     /// * `Ok(StartupConfig)` with appropriate settings
     /// * `Err(CliError)` if an invalid activity was specified
     pub fn from_cli(cli: &Cli) -> Result<Self, CliError> {
-        match &cli.plan {
+        let mut config = match &cli.plan {
             None => {
-                // No --plan flag: normal startup
-                Ok(StartupConfig::default())
+                // No --plan flag: normal startup, or the dashboard with --watch
+                StartupConfig {
+                    start_in_dashboard: cli.watch,
+                    ..StartupConfig::default()
+                }
             }
             Some(None) => {
                 // --plan flag without activity: start in PlanTrip
-                Ok(StartupConfig {
+                StartupConfig {
                     start_in_plan_trip: true,
-                    initial_activity: None,
-                })
+                    ..StartupConfig::default()
+                }
             }
             Some(Some(activity_str)) => {
-                // --plan <activity>: start in PlanTrip with activity
+                // --plan <activity>: start in PlanTrip with activity, which
+                // takes precedence over --activity
                 let activity = parse_activity_arg(activity_str)?;
-                Ok(StartupConfig {
+                StartupConfig {
                     start_in_plan_trip: true,
                     initial_activity: Some(activity),
+                    ..StartupConfig::default()
+                }
+            }
+        };
+        if config.initial_activity.is_none() {
+            config.initial_activity = cli
+                .activity
+                .as_deref()
+                .map(parse_activity_arg)
+                .transpose()?;
+        }
+        config.score_dump = cli.score_dump;
+        config.record_path = cli.record.clone();
+        config.replay_path = cli.replay.clone();
+        config.verbose = cli.verbose;
+        // Linear output reads charts no better than a screen reader does
+        config.plain = cli.plain || cli.linear;
+        config.linear = cli.linear;
+        config.notify = cli.notify;
+        config.ambient = cli.ambient;
+        config.low_bandwidth = cli.low_bandwidth;
+        config.wind_models = cli.wind_models;
+        config.slow_link = cli.slow_link;
+        config.alert = cli.alert;
+        config.control_path = cli.control.clone();
+        config.palette = cli.palette;
+        config.rank_markers = cli.ranks;
+        config.cache_policy = CachePolicy::from_flags(cli.no_cache, cli.cache_dir.clone());
+        config.http_recording =
+            HttpRecording::from_flags(cli.record_http.clone(), cli.replay_http.clone());
+        config.initial_beach = cli.beach.clone();
+        match &cli.command {
+            Some(Command::ExportSite { out }) => config.export_site_dir = Some(out.clone()),
+            Some(Command::Serve { listen }) => config.serve_addr = Some(*listen),
+            Some(Command::Schema) => config.print_schema = true,
+            Some(Command::Completions { shell }) => config.completions = Some(*shell),
+            Some(Command::Man) => config.print_man = true,
+            Some(Command::ImportWq { file }) => config.import_wq_path = Some(file.clone()),
+            Some(Command::UpdateBeaches) => config.update_beaches = true,
+            Some(Command::GenFixtures { out }) => config.gen_fixtures_dir = Some(out.clone()),
+            Some(Command::Metrics { action }) => config.usage_metrics = Some(*action),
+            Some(Command::Assets { action }) => config.assets = Some(action.clone()),
+            Some(Command::Statusline { beach, staleness }) => {
+                config.statusline = Some(StatuslineExport {
+                    beach_id: beach.clone(),
+                    staleness: *staleness,
+                })
+            }
+            Some(Command::Conditions { beaches, all, json }) => {
+                let mut beach_ids = if *all {
+                    all_beaches().iter().map(|beach| beach.id.clone()).collect()
+                } else {
+                    beaches.clone()
+                };
+                // A beach named twice is printed once, where it was first named
+                let mut seen = Vec::new();
+                beach_ids.retain(|id| {
+                    let first = !seen.contains(id);
+                    seen.push(id.clone());
+                    first
+                });
+                config.conditions = Some(ConditionsExport {
+                    beach_ids,
+                    json: *json,
                 })
             }
+            Some(Command::Calendar { min_score }) => {
+                config.calendar = Some(CalendarExport {
+                    min_score: *min_score,
+                })
+            }
+            Some(Command::Crawl {
+                stops,
+                stay,
+                tomorrow,
+                ics,
+            }) => {
+                config.crawl = Some(CrawlExport {
+                    stops: stops.clone(),
+                    stay_hours: *stay,
+                    tomorrow: *tomorrow,
+                    ics: *ics,
+                })
+            }
+            Some(Command::Backfill { days, beach }) => {
+                config.backfill = Some(BackfillExport {
+                    days: *days,
+                    beach_ids: beach.clone(),
+                })
+            }
+            Some(Command::Post { to, dry_run }) => {
+                // A service named twice is posted to once
+                let mut targets = Vec::new();
+                for target in to {
+                    if !targets.contains(target) {
+                        targets.push(*target);
+                    }
+                }
+                config.post = Some(PostExport {
+                    targets,
+                    dry_run: *dry_run,
+                })
+            }
+            Some(Command::Log { limit, beach }) => {
+                config.log = Some(LogExport {
+                    limit: *limit,
+                    beach_id: beach.clone(),
+                })
+            }
+            Some(Command::Table { beach, format }) => {
+                config.table_export = Some(TableExport {
+                    beach_id: beach.clone(),
+                    format: *format,
+                })
+            }
+            Some(Command::Crowd {
+                beach,
+                date,
+                format,
+            }) => {
+                config.crowd_export = Some(CrowdExport {
+                    beach_id: beach.clone(),
+                    date: *date,
+                    format: *format,
+                })
+            }
+            None => {}
         }
+        Ok(config)
     }
 }
 
@@ -129,6 +1002,12 @@ mod tests {
         assert_eq!(parse_activity_arg("quiet").unwrap(), Activity::Peace);
     }
 
+    #[test]
+    fn test_parse_activity_arg_dog_walk_aliases() {
+        assert_eq!(parse_activity_arg("dog").unwrap(), Activity::DogWalk);
+        assert_eq!(parse_activity_arg("dogwalk").unwrap(), Activity::DogWalk);
+    }
+
     #[test]
     fn test_parse_activity_arg_invalid() {
         let result = parse_activity_arg("invalid");
@@ -190,9 +1069,673 @@ mod tests {
     }
 
     #[test]
-    fn test_startup_config_from_cli_invalid_activity() {
-        let cli = Cli::parse_from(["vanbeach", "--plan", "invalid"]);
-        let result = StartupConfig::from_cli(&cli);
+    fn test_cli_rejects_invalid_activity() {
+        let result = Cli::try_parse_from(["vanbeach", "--plan", "invalid"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cli_accepts_activity_aliases_in_any_case() {
+        for alias in ["swimming", "SUNBATHE", "Quiet"] {
+            let cli = Cli::parse_from(["vanbeach", "--activity", alias]);
+            assert!(StartupConfig::from_cli(&cli)
+                .unwrap()
+                .initial_activity
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_activity() {
+        let cli = Cli::parse_from(["vanbeach", "--activity", "sail"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert!(!config.start_in_plan_trip);
+        assert_eq!(config.initial_activity, Some(Activity::Sailing));
+
+        // Applies after a subcommand too
+        let cli = Cli::parse_from(["vanbeach", "export-site", "--activity", "swim"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.initial_activity, Some(Activity::Swimming));
+
+        // --plan <activity> wins
+        let cli = Cli::parse_from(["vanbeach", "--plan", "sunset", "--activity", "sail"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.initial_activity, Some(Activity::Sunset));
+
+        assert!(Cli::try_parse_from(["vanbeach", "--activity", "kayak"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_watch() {
+        let cli = Cli::parse_from(["vanbeach", "--watch"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert!(config.start_in_dashboard);
+        assert!(!config.start_in_plan_trip);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_score_dump() {
+        let cli = Cli::parse_from(["vanbeach", "--score-dump"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert!(config.score_dump);
+        assert!(!config.start_in_plan_trip);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_record_and_replay() {
+        let cli = Cli::parse_from(["vanbeach", "--record", "session.json"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.record_path, Some(PathBuf::from("session.json")));
+        assert!(config.replay_path.is_none());
+
+        let result = Cli::try_parse_from(["vanbeach", "--record", "a.json", "--replay", "b.json"]);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_startup_config_from_cli_export_site() {
+        let cli = Cli::parse_from(["vanbeach", "export-site", "--out", "/tmp/beaches"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.export_site_dir, Some(PathBuf::from("/tmp/beaches")));
+
+        let cli = Cli::parse_from(["vanbeach", "export-site"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.export_site_dir, Some(PathBuf::from("site")));
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(config.export_site_dir.is_none());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_serve() {
+        let cli = Cli::parse_from(["vanbeach", "serve"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.serve_addr, Some("127.0.0.1:9187".parse().unwrap()));
+        assert!(config.export_site_dir.is_none());
+
+        let cli = Cli::parse_from(["vanbeach", "serve", "--listen", "0.0.0.0:9000"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.serve_addr, Some("0.0.0.0:9000".parse().unwrap()));
+
+        assert!(Cli::try_parse_from(["vanbeach", "serve", "--listen", "nowhere"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_schema() {
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "schema"])).unwrap();
+        assert!(config.print_schema);
+        assert!(config.serve_addr.is_none());
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(!config.print_schema);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_update_beaches() {
+        let cli = Cli::parse_from(["vanbeach", "update-beaches"]);
+        assert!(StartupConfig::from_cli(&cli).unwrap().update_beaches);
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(!config.update_beaches);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_gen_fixtures() {
+        let cli = Cli::parse_from(["vanbeach", "gen-fixtures", "--out", "/tmp/fixtures"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(
+            config.gen_fixtures_dir,
+            Some(PathBuf::from("/tmp/fixtures"))
+        );
+
+        let cli = Cli::parse_from(["vanbeach", "gen-fixtures"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(
+            config.gen_fixtures_dir,
+            Some(PathBuf::from("tests/fixtures"))
+        );
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(config.gen_fixtures_dir.is_none());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_import_wq() {
+        let cli = Cli::parse_from(["vanbeach", "import-wq", "samples-2025.csv"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(
+            config.import_wq_path,
+            Some(PathBuf::from("samples-2025.csv"))
+        );
+
+        assert!(Cli::try_parse_from(["vanbeach", "import-wq"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_crowd() {
+        let cli = Cli::parse_from(["vanbeach", "crowd", "--beach", "kitsilano"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(
+            config.crowd_export,
+            Some(CrowdExport {
+                beach_id: BeachId::new("kitsilano"),
+                date: None,
+                format: ExportFormat::Csv,
+            })
+        );
+
+        let cli = Cli::parse_from([
+            "vanbeach",
+            "crowd",
+            "--beach",
+            "jericho",
+            "--date",
+            "2026-08-03",
+            "--format",
+            "json",
+        ]);
+        let export = StartupConfig::from_cli(&cli).unwrap().crowd_export.unwrap();
+        assert_eq!(export.date, NaiveDate::from_ymd_opt(2026, 8, 3));
+        assert_eq!(export.format, ExportFormat::Json);
+
+        assert!(Cli::try_parse_from(["vanbeach", "crowd"]).is_err());
+        assert!(Cli::try_parse_from(["vanbeach", "crowd", "--beach", "nowhere"]).is_err());
+        assert!(
+            Cli::try_parse_from(["vanbeach", "crowd", "--beach", "jericho", "--date", "soon"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_calendar() {
+        let cli = Cli::parse_from(["vanbeach", "calendar"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().calendar,
+            Some(CalendarExport {
+                min_score: DEFAULT_CALENDAR_MIN_SCORE,
+            })
+        );
+
+        let cli = Cli::parse_from(["vanbeach", "calendar", "--min-score", "85"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().calendar,
+            Some(CalendarExport { min_score: 85 })
+        );
+        assert!(Cli::try_parse_from(["vanbeach", "calendar", "--min-score", "101"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_post() {
+        let cli = Cli::parse_from([
+            "vanbeach", "post", "--to", "bluesky", "--to", "mastodon", "--to", "bluesky",
+        ]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().post,
+            Some(PostExport {
+                targets: vec![PostTarget::Bluesky, PostTarget::Mastodon],
+                dry_run: false,
+            })
+        );
+
+        let cli = Cli::parse_from(["vanbeach", "post", "--dry-run"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().post,
+            Some(PostExport {
+                targets: Vec::new(),
+                dry_run: true,
+            })
+        );
+        assert!(Cli::try_parse_from(["vanbeach", "post"]).is_err());
+        assert!(Cli::try_parse_from(["vanbeach", "post", "--to", "twitter"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_backfill() {
+        let cli = Cli::parse_from(["vanbeach", "backfill"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().backfill,
+            Some(BackfillExport {
+                days: DEFAULT_BACKFILL_DAYS,
+                beach_ids: Vec::new(),
+            })
+        );
+
+        let cli = Cli::parse_from([
+            "vanbeach",
+            "backfill",
+            "--days",
+            "30",
+            "--beach",
+            "kitsilano",
+            "--beach",
+            "jericho",
+        ]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().backfill,
+            Some(BackfillExport {
+                days: 30,
+                beach_ids: vec![BeachId::new("kitsilano"), BeachId::new("jericho")],
+            })
+        );
+        assert!(Cli::try_parse_from(["vanbeach", "backfill", "--days", "0"]).is_err());
+        assert!(Cli::try_parse_from(["vanbeach", "backfill", "--days", "93"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_crawl() {
+        let cli = Cli::parse_from(["vanbeach", "crawl", "kitsilano:swim", "jericho:sail"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().crawl,
+            Some(CrawlExport {
+                stops: vec![
+                    CrawlStop {
+                        beach_id: BeachId::new("kitsilano"),
+                        activity: Activity::Swimming,
+                    },
+                    CrawlStop {
+                        beach_id: BeachId::new("jericho"),
+                        activity: Activity::Sailing,
+                    },
+                ],
+                stay_hours: DEFAULT_CRAWL_STAY_HOURS,
+                tomorrow: false,
+                ics: false,
+            })
+        );
+
+        let cli = Cli::parse_from([
+            "vanbeach",
+            "crawl",
+            "kitsilano:sun",
+            "jericho:peace",
+            "--stay",
+            "3",
+            "--tomorrow",
+            "--ics",
+        ]);
+        let crawl = StartupConfig::from_cli(&cli).unwrap().crawl.unwrap();
+        assert_eq!(
+            (crawl.stay_hours, crawl.tomorrow, crawl.ics),
+            (3, true, true)
+        );
+
+        // One stop isn't a crawl, four is too many, and stops need an activity
+        assert!(Cli::try_parse_from(["vanbeach", "crawl", "kitsilano:swim"]).is_err());
+        assert!(Cli::try_parse_from([
+            "vanbeach",
+            "crawl",
+            "kitsilano:swim",
+            "jericho:swim",
+            "locarno:swim",
+            "third:swim"
+        ])
+        .is_err());
+        assert!(Cli::try_parse_from(["vanbeach", "crawl", "kitsilano", "jericho:sail"]).is_err());
+        assert!(
+            Cli::try_parse_from(["vanbeach", "crawl", "nowhere:swim", "jericho:sail"]).is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["vanbeach", "crawl", "trout-lake:surf", "jericho:sail"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_conditions() {
+        let cli = Cli::parse_from([
+            "vanbeach",
+            "conditions",
+            "kitsilano",
+            "jericho",
+            "kitsilano",
+            "--json",
+        ]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().conditions,
+            Some(ConditionsExport {
+                beach_ids: vec![BeachId::new("kitsilano"), BeachId::new("jericho")],
+                json: true,
+            })
+        );
+
+        let cli = Cli::parse_from(["vanbeach", "conditions", "--all"]);
+        let export = StartupConfig::from_cli(&cli).unwrap().conditions.unwrap();
+        assert_eq!(export.beach_ids.len(), all_beaches().len());
+        assert!(!export.json);
+
+        assert!(Cli::try_parse_from(["vanbeach", "conditions"]).is_err());
+        assert!(Cli::try_parse_from(["vanbeach", "conditions", "--all", "jericho"]).is_err());
+        assert!(Cli::try_parse_from(["vanbeach", "conditions", "no-such-beach"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_log() {
+        let cli = Cli::parse_from(["vanbeach", "log"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().log,
+            Some(LogExport {
+                limit: DEFAULT_LOG_LIMIT,
+                beach_id: None,
+            })
+        );
+
+        let cli = Cli::parse_from(["vanbeach", "log", "--limit", "5", "--beach", "kitsilano"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().log,
+            Some(LogExport {
+                limit: 5,
+                beach_id: Some(BeachId::new("kitsilano")),
+            })
+        );
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_metrics() {
+        let cli = Cli::parse_from(["vanbeach", "metrics", "export"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().usage_metrics,
+            Some(MetricsAction::Export)
+        );
+        assert!(Cli::try_parse_from(["vanbeach", "metrics"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_assets() {
+        let cli = Cli::parse_from(["vanbeach", "assets", "dump"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().assets,
+            Some(AssetsAction::Dump {
+                dir: None,
+                force: false
+            })
+        );
+
+        let cli = Cli::parse_from(["vanbeach", "assets", "dump", "--dir", "/tmp/a", "--force"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().assets,
+            Some(AssetsAction::Dump {
+                dir: Some(PathBuf::from("/tmp/a")),
+                force: true
+            })
+        );
+        assert!(Cli::try_parse_from(["vanbeach", "assets"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_statusline() {
+        let cli = Cli::parse_from(["vanbeach", "statusline", "--beach", "kitsilano"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().statusline,
+            Some(StatuslineExport {
+                beach_id: BeachId::new("kitsilano"),
+                staleness: false,
+            })
+        );
+
+        let cli = Cli::parse_from([
+            "vanbeach",
+            "statusline",
+            "--beach",
+            "kitsilano",
+            "--staleness",
+        ]);
+        assert!(
+            StartupConfig::from_cli(&cli)
+                .unwrap()
+                .statusline
+                .unwrap()
+                .staleness
+        );
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_table() {
+        let cli = Cli::parse_from(["vanbeach", "table", "--beach", "jericho"]);
+        assert_eq!(
+            StartupConfig::from_cli(&cli).unwrap().table_export,
+            Some(TableExport {
+                beach_id: BeachId::new("jericho"),
+                format: TableFormat::Text,
+            })
+        );
+
+        let cli = Cli::parse_from([
+            "vanbeach",
+            "--activity",
+            "sail",
+            "table",
+            "--beach",
+            "jericho",
+            "--format",
+            "html",
+        ]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.table_export.unwrap().format, TableFormat::Html);
+        assert_eq!(config.initial_activity, Some(Activity::Sailing));
+
+        assert!(Cli::try_parse_from(["vanbeach", "table"]).is_err());
+        assert!(Cli::try_parse_from(["vanbeach", "table", "--beach", "nowhere"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_verbose() {
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "-v"])).unwrap();
+        assert!(config.verbose);
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(!config.verbose);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_plain_and_ascii_alias() {
+        for flag in ["--plain", "--ascii"] {
+            let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach", flag])).unwrap();
+            assert!(config.plain, "{} should turn on plain mode", flag);
+        }
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(!config.plain);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_ambient() {
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "--ambient"])).unwrap();
+        assert!(config.ambient);
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(!config.ambient);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_palette() {
+        let config =
+            StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "--palette", "red-green"]))
+                .unwrap();
+        assert_eq!(config.palette, Palette::RedGreen);
+        assert!(config.palette.is_colorblind());
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert_eq!(config.palette, Palette::Standard);
+        assert!(Cli::try_parse_from(["vanbeach", "--palette", "sepia"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_rank_markers() {
+        let config =
+            StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "--ranks", "numerals"])).unwrap();
+        assert_eq!(config.rank_markers, RankMarkers::Numerals);
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert_eq!(config.rank_markers, RankMarkers::Medals);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_alert() {
+        let config =
+            StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "--alert", "bell"])).unwrap();
+        assert_eq!(config.alert, Some(AlertStyle::Bell));
+
+        let config =
+            StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "--alert", "flash"])).unwrap();
+        assert_eq!(config.alert, Some(AlertStyle::Flash));
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert_eq!(config.alert, None);
+        assert!(Cli::try_parse_from(["vanbeach", "--alert", "siren"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_low_bandwidth() {
+        let config =
+            StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "--low-bandwidth"])).unwrap();
+        assert!(config.low_bandwidth);
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(!config.low_bandwidth);
+        assert!(!config.wind_models);
+
+        let config =
+            StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "--wind-models"])).unwrap();
+        assert!(config.wind_models);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_slow_link() {
+        let config =
+            StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "--slow-link"])).unwrap();
+        assert!(config.slow_link);
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(!config.slow_link);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_linear_describes_tides_in_words() {
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "--linear"])).unwrap();
+        assert!(config.linear);
+        assert!(config.plain);
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(!config.linear);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_control_socket() {
+        let cli = Cli::parse_from(["vanbeach", "--control", "/tmp/vanbeach.sock"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(
+            config.control_path,
+            Some(PathBuf::from("/tmp/vanbeach.sock"))
+        );
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert_eq!(config.control_path, None);
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_cache_policy() {
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert_eq!(config.cache_policy, CachePolicy::Default);
+
+        let cli = Cli::parse_from(["vanbeach", "table", "--beach", "kitsilano", "--no-cache"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.cache_policy, CachePolicy::MemoryOnly);
+
+        let cli = Cli::parse_from(["vanbeach", "--cache-dir", "/mnt/private/beach"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(
+            config.cache_policy,
+            CachePolicy::Dir(PathBuf::from("/mnt/private/beach"))
+        );
+
+        assert!(Cli::try_parse_from(["vanbeach", "--no-cache", "--cache-dir", "/tmp"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_http_recording() {
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert_eq!(config.http_recording, None);
+
+        let cli = Cli::parse_from([
+            "vanbeach",
+            "table",
+            "--beach",
+            "kitsilano",
+            "--record-http",
+            "recordings",
+        ]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(
+            config.http_recording,
+            Some(HttpRecording::Record(PathBuf::from("recordings")))
+        );
+
+        assert!(Cli::try_parse_from([
+            "vanbeach",
+            "--record-http",
+            "recordings",
+            "--replay-http",
+            "recordings"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_notify() {
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "--notify"])).unwrap();
+        assert!(config.notify);
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach"])).unwrap();
+        assert!(!config.notify);
+    }
+
+    #[test]
+    fn test_cli_watch_conflicts_with_plan() {
+        let result = Cli::try_parse_from(["vanbeach", "--watch", "--plan"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_beach() {
+        let cli = Cli::parse_from(["vanbeach", "--beach", "kitsilano"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.initial_beach, Some(BeachId::new("kitsilano")));
+
+        assert!(Cli::try_parse_from(["vanbeach", "--beach", "atlantis"]).is_err());
+        assert!(Cli::try_parse_from(["vanbeach", "--beach", "kitsilano", "--watch"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_config_from_cli_completions_and_man() {
+        let cli = Cli::parse_from(["vanbeach", "completions", "zsh"]);
+        let config = StartupConfig::from_cli(&cli).unwrap();
+        assert_eq!(config.completions, Some(Shell::Zsh));
+        assert!(!config.print_man);
+
+        let config = StartupConfig::from_cli(&Cli::parse_from(["vanbeach", "man"])).unwrap();
+        assert!(config.print_man);
+        assert!(Cli::try_parse_from(["vanbeach", "completions", "tcsh"]).is_err());
+    }
+
+    #[test]
+    fn test_completions_list_subcommands_beaches_and_activities() {
+        let mut out = Vec::new();
+        write_completions(Shell::Bash, &mut out);
+        let script = String::from_utf8(out).unwrap();
+
+        assert!(script.contains("export-site"));
+        assert!(script.contains("completions"));
+        assert!(script.contains("kitsilano"));
+        assert!(script.contains("sunset"));
+    }
+
+    #[test]
+    fn test_man_page_covers_options_and_subcommands() {
+        let mut out = Vec::new();
+        write_man_page(&mut out).unwrap();
+        let page = String::from_utf8(out).unwrap();
+
+        assert!(page.starts_with(".ie"), "{}", &page[..40]);
+        assert!(page.contains(".TH vanbeach 1"));
+        assert!(page.contains("\\-\\-activity"));
+        assert!(page.contains("export\\-site"));
+    }
 }
@@ -0,0 +1,75 @@
+//! Wall-clock jump detection
+//!
+//! The monotonic clock stops while a laptop sleeps but the wall clock keeps
+//! going, so after waking up the two disagree. Comparing them on every pass of
+//! the event loop catches sleep/wake as well as manual clock or timezone
+//! changes, which would otherwise leave yesterday's forecasts on screen.
+
+use std::time::Instant;
+
+use chrono::{DateTime, Duration, Local};
+
+/// How far the wall clock may drift from the monotonic clock before it counts as a jump
+pub const CLOCK_JUMP_THRESHOLD_SECS: i64 = 120;
+
+/// Watches for the wall clock moving differently from elapsed time
+pub struct ClockMonitor {
+    last_wall: DateTime<Local>,
+    last_instant: Instant,
+}
+
+impl ClockMonitor {
+    /// Starts monitoring from the current time
+    pub fn new() -> Self {
+        Self {
+            last_wall: Local::now(),
+            last_instant: Instant::now(),
+        }
+    }
+
+    /// Returns true if the wall clock jumped since the last check
+    pub fn check(&mut self) -> bool {
+        self.check_at(Local::now(), Instant::now())
+    }
+
+    fn check_at(&mut self, wall: DateTime<Local>, instant: Instant) -> bool {
+        let wall_elapsed = wall - self.last_wall;
+        let real_elapsed = Duration::from_std(instant.duration_since(self.last_instant))
+            .unwrap_or_else(|_| Duration::zero());
+
+        self.last_wall = wall;
+        self.last_instant = instant;
+
+        (wall_elapsed - real_elapsed).num_seconds().abs() > CLOCK_JUMP_THRESHOLD_SECS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_ticks_are_not_jumps() {
+        let mut monitor = ClockMonitor::new();
+        let wall = monitor.last_wall + Duration::seconds(5);
+        let instant = monitor.last_instant + std::time::Duration::from_secs(5);
+        assert!(!monitor.check_at(wall, instant));
+    }
+
+    #[test]
+    fn test_sleep_and_clock_changes_are_jumps() {
+        let mut monitor = ClockMonitor::new();
+
+        // Laptop slept overnight: wall clock moved, monotonic clock didn't
+        let wall = monitor.last_wall + Duration::hours(10);
+        let instant = monitor.last_instant + std::time::Duration::from_millis(100);
+        assert!(monitor.check_at(wall, instant));
+
+        // Clock set back an hour
+        let wall = wall - Duration::hours(1);
+        assert!(monitor.check_at(wall, instant));
+
+        // Next regular tick is fine again
+        assert!(!monitor.check_at(wall, instant));
+    }
+}
@@ -0,0 +1,103 @@
+//! Saved comparison sets
+//!
+//! A comparison set is a named group of beaches, such as "West side trio",
+//! that the dashboard can show in place of the favorites. Sets come from the
+//! config or are saved from the dashboard; saved ones are stored through the
+//! cache manager next to the favorites so they survive restarts.
+
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::cache::{CacheManager, StalenessPolicy};
+use crate::data::{all_beaches, BeachId};
+
+/// Cache key under which saved comparison sets are stored
+pub const COMPARISON_SETS_CACHE_KEY: &str = "comparison_sets";
+
+/// Comparison sets are user settings like favorites, so keep them for a year
+const COMPARISON_SETS_TTL: Duration = Duration::days(365);
+
+/// A named group of beaches to compare
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComparisonSet {
+    /// Name shown in the dashboard title, e.g. "West side trio"
+    pub name: String,
+    /// The beaches, in the order they were added
+    pub beaches: Vec<BeachId>,
+    /// Whether the set comes from the config, which is never saved over
+    #[serde(skip)]
+    pub configured: bool,
+}
+
+impl ComparisonSet {
+    /// A set saved from the dashboard
+    pub fn new(name: impl Into<String>, beaches: Vec<BeachId>) -> Self {
+        Self {
+            name: name.into(),
+            beaches,
+            configured: false,
+        }
+    }
+
+    /// A set named in the config
+    pub fn configured(name: impl Into<String>, beaches: Vec<BeachId>) -> Self {
+        Self {
+            configured: true,
+            ..Self::new(name, beaches)
+        }
+    }
+}
+
+/// Loads saved comparison sets, dropping beaches that are no longer known
+/// and any set left without one
+pub fn load_comparison_sets(cache: &CacheManager) -> Vec<ComparisonSet> {
+    cache
+        .get_with_policy::<Vec<ComparisonSet>>(COMPARISON_SETS_CACHE_KEY, StalenessPolicy::AnyAge)
+        .data()
+        .map(|data| {
+            data.into_iter()
+                .filter_map(|mut set| {
+                    set.beaches
+                        .retain(|id| all_beaches().iter().any(|b| b.id == *id));
+                    (!set.beaches.is_empty()).then_some(set)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Saves the sets that didn't come from the config
+pub fn save_comparison_sets(cache: &CacheManager, sets: &[ComparisonSet]) -> std::io::Result<()> {
+    let saved: Vec<&ComparisonSet> = sets.iter().filter(|set| !set.configured).collect();
+    cache.put(COMPARISON_SETS_CACHE_KEY, &saved, COMPARISON_SETS_TTL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_save_and_load_skips_configured_sets_and_unknown_beaches() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let sets = vec![
+            ComparisonSet::configured("From config", vec![BeachId::new("jericho")]),
+            ComparisonSet::new(
+                "West side trio",
+                vec![BeachId::new("kitsilano"), BeachId::new("atlantis")],
+            ),
+            ComparisonSet::new("Gone", vec![BeachId::new("atlantis")]),
+        ];
+
+        save_comparison_sets(&cache, &sets).unwrap();
+
+        assert_eq!(
+            load_comparison_sets(&cache),
+            vec![ComparisonSet::new(
+                "West side trio",
+                vec![BeachId::new("kitsilano")]
+            )]
+        );
+    }
+}
@@ -0,0 +1,486 @@
+//! Windguru-style condition table
+//!
+//! `vanbeach table --beach kitsilano` prints a dense grid of the coming hours
+//! at one beach: one column per hour for the rest of today and tomorrow, with
+//! rows for air temperature, wind, gusts, wave height, tide and the activity
+//! score. It's the layout windsport forecasts use, so it reads at a glance for
+//! anyone used to them. Hours with a king tide are marked in the header. The grid is plain text for a terminal or an HTML
+//! table for a page.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use chrono::{Duration, NaiveDate};
+
+use crate::activities::Activity;
+use crate::cli::TableFormat;
+use crate::config::hour_range;
+use crate::data::{
+    is_king_tide, BeachConditions, WaterBody, WaveForecast, KING_TIDE_MARKER, KING_TIDE_NOTE,
+};
+use crate::scoring::ActivityScorer;
+use crate::site::escape_html;
+use crate::time_format::hour_compact;
+
+/// Width of each hour's column in the text table
+const CELL_WIDTH: usize = 4;
+
+/// Width of the row labels in the text table
+const LABEL_WIDTH: usize = 11;
+
+/// Scores at or above these are good and fair, as in the beach list
+const GOOD_SCORE: u8 = 80;
+const FAIR_SCORE: u8 = 60;
+
+/// Row labels, top to bottom
+const ROWS: [&str; 7] = [
+    "Temp °C",
+    "Wind km/h",
+    "Direction",
+    "Gusts km/h",
+    "Wave m",
+    "Tide m",
+    "Score",
+];
+
+/// Index of the tide row in `ROWS`
+const TIDE_ROW: usize = 5;
+
+/// Conditions for one hour of the table
+#[derive(Debug, Clone, PartialEq)]
+struct TableColumn {
+    date: NaiveDate,
+    hour: u8,
+    temperature: f64,
+    wind: f64,
+    wind_direction: String,
+    gusts: Option<f64>,
+    wave: Option<f64>,
+    tide: Option<f32>,
+    score: Option<u8>,
+}
+
+impl TableColumn {
+    /// The column's cells, one per row in `ROWS`; "-" where there's no data
+    fn cells(&self) -> [String; 7] {
+        let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+        [
+            format!("{:.0}", self.temperature),
+            format!("{:.0}", self.wind),
+            self.wind_direction.clone(),
+            or_dash(self.gusts.map(|gusts| format!("{:.0}", gusts))),
+            or_dash(self.wave.map(|wave| format!("{:.1}", wave))),
+            or_dash(self.tide.map(|tide| format!("{:.1}", tide))),
+            or_dash(self.score.map(|score| score.to_string())),
+        ]
+    }
+
+    /// Whether the tide this hour is high enough to be a king tide
+    fn is_king_tide(&self) -> bool {
+        self.tide.is_some_and(|tide| is_king_tide(f64::from(tide)))
+    }
+
+    /// The hour heading, marked during a king tide
+    fn hour_label(&self) -> String {
+        if self.is_king_tide() {
+            format!("{}{}", KING_TIDE_MARKER, hour_compact(self.hour))
+        } else {
+            hour_compact(self.hour)
+        }
+    }
+}
+
+/// The king tide footnote, if any hour in the table has one
+fn king_tide_note(columns: &[TableColumn]) -> Option<String> {
+    columns
+        .iter()
+        .any(TableColumn::is_king_tide)
+        .then(|| format!("{} King tide: {}", KING_TIDE_MARKER, KING_TIDE_NOTE))
+}
+
+/// Renders the table for a beach, or a note if there's no forecast
+///
+/// Hours run from `current_hour` today through tomorrow, within the
+/// configured hour range. `tide_at` gives the predicted tide height at an
+/// hour; tides and waves are left out at lakes.
+pub fn export_table(
+    conditions: &BeachConditions,
+    activity: Activity,
+    waves: &WaveForecast,
+    tide_at: impl Fn(NaiveDate, u8) -> Option<f32>,
+    today: NaiveDate,
+    current_hour: u8,
+    format: TableFormat,
+) -> String {
+    let columns = build_columns(conditions, activity, waves, tide_at, today, current_hour);
+    let title = format!("{} \u{2014} {}", conditions.beach.name, activity.label());
+    match format {
+        TableFormat::Text if columns.is_empty() => format!("{}\nNo forecast available\n", title),
+        TableFormat::Text => render_text(&title, &columns),
+        TableFormat::Html => render_html(&title, &columns),
+    }
+}
+
+/// Collects the hours to show, scoring each for the activity
+fn build_columns(
+    conditions: &BeachConditions,
+    activity: Activity,
+    waves: &WaveForecast,
+    tide_at: impl Fn(NaiveDate, u8) -> Option<f32>,
+    today: NaiveDate,
+    current_hour: u8,
+) -> Vec<TableColumn> {
+    let Some(weather) = &conditions.weather else {
+        return Vec::new();
+    };
+    let tomorrow = today + Duration::days(1);
+    let (first_hour, last_hour) = hour_range();
+    let today_scores: HashMap<u8, u8> =
+        ActivityScorer::hourly_from(conditions, activity, current_hour)
+            .map(|(hour, slot)| (hour, slot.score))
+            .collect();
+    let tomorrow_scores: HashMap<u8, u8> = ActivityScorer::tomorrow(conditions, activity)
+        .map(|(hour, slot)| (hour, slot.score))
+        .collect();
+    let tidal = conditions.beach.water_body == WaterBody::Ocean;
+
+    let today_hours = weather
+        .hourly
+        .iter()
+        .filter(|forecast| forecast.hour >= current_hour)
+        .map(|forecast| (today, forecast, &today_scores));
+    let tomorrow_hours = weather
+        .tomorrow
+        .iter()
+        .map(|forecast| (tomorrow, forecast, &tomorrow_scores));
+    today_hours
+        .chain(tomorrow_hours)
+        .filter(|(_, forecast, _)| (first_hour..=last_hour).contains(&forecast.hour))
+        .map(|(date, forecast, scores)| TableColumn {
+            date,
+            hour: forecast.hour,
+            temperature: forecast.temperature,
+            wind: forecast.wind,
+            wind_direction: forecast.wind_direction.clone(),
+            gusts: forecast.wind_gusts,
+            wave: waves.height_at(date, forecast.hour).filter(|_| tidal),
+            tide: tidal.then(|| tide_at(date, forecast.hour)).flatten(),
+            score: scores.get(&forecast.hour).copied(),
+        })
+        .collect()
+}
+
+/// Lays the columns out as a fixed-width text grid with a day header
+fn render_text(title: &str, columns: &[TableColumn]) -> String {
+    let mut out = format!("{}\n", title);
+
+    // Day labels sit over the first hour of each day
+    let mut days = String::new();
+    let mut hours = format!("{:<LABEL_WIDTH$}", "Hour");
+    for (i, column) in columns.iter().enumerate() {
+        if i == 0 || columns[i - 1].date != column.date {
+            // A label may run into the next columns, so it's placed at its
+            // column rather than padded to a cell
+            let start = LABEL_WIDTH + i * CELL_WIDTH;
+            let padding = start.saturating_sub(days.len()).max(1);
+            days.push_str(&" ".repeat(padding));
+            let _ = write!(days, "{}", column.date.format("%a %d"));
+        }
+        let _ = write!(hours, "{:>CELL_WIDTH$}", column.hour_label());
+    }
+    let _ = writeln!(out, "{}", days);
+    let _ = writeln!(out, "{}", hours);
+
+    let cells: Vec<[String; 7]> = columns.iter().map(TableColumn::cells).collect();
+    for (row, label) in ROWS.iter().enumerate() {
+        let mut line = format!("{:<LABEL_WIDTH$}", label);
+        for column in &cells {
+            let _ = write!(line, "{:>CELL_WIDTH$}", column[row]);
+        }
+        let _ = writeln!(out, "{}", line);
+    }
+    if let Some(note) = king_tide_note(columns) {
+        let _ = writeln!(out, "{}", note);
+    }
+    out
+}
+
+/// Renders the columns as a standalone HTML page with a coloured score row
+fn render_html(title: &str, columns: &[TableColumn]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{title}</title>\n<style>body{{font-family:system-ui,sans-serif}}\
+         table{{border-collapse:collapse;font-size:.85rem}}\
+         th,td{{text-align:center;padding:.2rem .35rem;border:1px solid #dde4ea}}\
+         th[scope=row]{{text-align:left;white-space:nowrap}}\
+         .good{{background:#aceebb}}.fair{{background:#fff3b0}}.poor{{background:#ffc1c0}}\
+         .king{{background:#c8d9ff;font-weight:bold}}\
+         </style>\n</head>\n<body>\n<h1>{title}</h1>",
+        title = escape_html(title)
+    );
+    if columns.is_empty() {
+        out.push_str("<p>No forecast available</p>\n</body>\n</html>\n");
+        return out;
+    }
+
+    out.push_str("<table>\n<tr><th></th>");
+    let mut i = 0;
+    while i < columns.len() {
+        let span = columns[i..]
+            .iter()
+            .take_while(|column| column.date == columns[i].date)
+            .count();
+        let _ = write!(
+            out,
+            "<th colspan=\"{}\">{}</th>",
+            span,
+            columns[i].date.format("%a %d")
+        );
+        i += span;
+    }
+    out.push_str("</tr>\n<tr><th scope=\"row\">Hour</th>");
+    for column in columns {
+        let _ = write!(out, "<th>{}</th>", column.hour_label());
+    }
+    out.push_str("</tr>\n");
+
+    let cells: Vec<[String; 7]> = columns.iter().map(TableColumn::cells).collect();
+    for (row, label) in ROWS.iter().enumerate() {
+        let _ = write!(out, "<tr><th scope=\"row\">{}</th>", label);
+        for (column, cell) in columns.iter().zip(&cells) {
+            let class = if row == TIDE_ROW && column.is_king_tide() {
+                Some("king")
+            } else {
+                column
+                    .score
+                    .filter(|_| row == ROWS.len() - 1)
+                    .map(score_class)
+            };
+            match class {
+                Some(class) => {
+                    let _ = write!(out, "<td class=\"{}\">{}</td>", class, cell[row]);
+                }
+                None => {
+                    let _ = write!(out, "<td>{}</td>", escape_html(&cell[row]));
+                }
+            }
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</table>\n");
+    if let Some(note) = king_tide_note(columns) {
+        let _ = writeln!(out, "<p class=\"king\">{}</p>", escape_html(&note));
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// CSS class colouring a score cell
+fn score_class(score: u8) -> &'static str {
+    if score >= GOOD_SCORE {
+        "good"
+    } else if score >= FAIR_SCORE {
+        "fair"
+    } else {
+        "poor"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{
+        get_beach_by_id, DataQuality, HourlyForecast, Weather, WeatherCondition, WeatherProvider,
+    };
+    use chrono::{NaiveTime, Utc};
+
+    fn forecast(hour: u8, gusts: Option<f64>) -> HourlyForecast {
+        HourlyForecast {
+            hour,
+            temperature: 20.0 + hour as f64 / 10.0,
+            feels_like: 20.0,
+            condition: WeatherCondition::Clear,
+            wind: 12.0,
+            wind_direction: "SW".to_string(),
+            uv: 5.0,
+            precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: gusts,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        }
+    }
+
+    fn conditions(beach_id: &str) -> BeachConditions {
+        BeachConditions {
+            beach: get_beach_by_id(beach_id).unwrap().clone(),
+            weather: Some(Weather {
+                temperature: 22.0,
+                feels_like: 22.0,
+                condition: WeatherCondition::Clear,
+                weather_code: None,
+                humidity: 60,
+                wind: 10.0,
+                wind_direction: Some(225.0),
+                uv: 5.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 10, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: (10..=23).map(|hour| forecast(hour, Some(25.0))).collect(),
+                tomorrow: (8..=23).map(|hour| forecast(hour, None)).collect(),
+                source: WeatherProvider::default(),
+                parse_warnings: Vec::new(),
+            }),
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    fn waves() -> WaveForecast {
+        WaveForecast::default()
+    }
+
+    #[test]
+    fn test_text_table_has_one_column_per_hour() {
+        let conditions = conditions("english-bay");
+        let today = NaiveDate::from_ymd_opt(2024, 7, 13).unwrap();
+        let table = export_table(
+            &conditions,
+            Activity::Swimming,
+            &waves(),
+            |_, hour| Some(hour as f32 / 10.0),
+            today,
+            20,
+            TableFormat::Text,
+        );
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines[0], "English Bay Beach \u{2014} Swimming");
+        assert_eq!(lines[1].find("Sat 13"), Some(LABEL_WIDTH));
+        // 20:00-21:00 today, then tomorrow's 08:00-21:00 in the default range
+        let hours: Vec<&str> = lines[2].split_whitespace().skip(1).collect();
+        assert_eq!(hours.len(), 2 + 14);
+        assert_eq!(&hours[..3], ["8p", "9p", "8a"]);
+
+        let row = |label: &str| lines.iter().find(|line| line.starts_with(label)).unwrap();
+        assert!(
+            row("Gusts km/h").contains("  25  25   -"),
+            "{}",
+            row("Gusts")
+        );
+        assert!(row("Tide m").contains(" 2.0 2.1 0.8"), "{}", row("Tide"));
+        assert!(row("Wave m").trim_end().ends_with('-'));
+        // Every row lines up under the hours
+        let width = lines[2].chars().count();
+        assert!(lines[2..].iter().all(|line| line.chars().count() == width));
+        assert_eq!(lines[1].find("Sun 14"), Some(LABEL_WIDTH + 2 * CELL_WIDTH));
+    }
+
+    #[test]
+    fn test_king_tide_hours_are_marked_with_a_note() {
+        let conditions = conditions("english-bay");
+        let today = NaiveDate::from_ymd_opt(2024, 7, 13).unwrap();
+        let tide_at = |date: NaiveDate, _: u8| Some(if date == today { 4.8 } else { 3.0 });
+
+        let table = export_table(
+            &conditions,
+            Activity::Swimming,
+            &waves(),
+            tide_at,
+            today,
+            20,
+            TableFormat::Text,
+        );
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[2].starts_with(&format!(
+            "{:<LABEL_WIDTH$} \u{265B}8p \u{265B}9p  8a",
+            "Hour"
+        )));
+        assert_eq!(
+            lines.last().copied(),
+            Some("\u{265B} King tide: seawall may flood around high tide")
+        );
+
+        let html = export_table(
+            &conditions,
+            Activity::Swimming,
+            &waves(),
+            tide_at,
+            today,
+            20,
+            TableFormat::Html,
+        );
+        assert!(html.contains("<th>\u{265B}9p</th><th>8a</th>"));
+        assert!(html.contains("<td class=\"king\">4.8</td><td>3.0</td>"));
+        assert!(html.contains("<p class=\"king\">"));
+
+        let calm = export_table(
+            &conditions,
+            Activity::Swimming,
+            &waves(),
+            |_, _| Some(3.0),
+            today,
+            20,
+            TableFormat::Text,
+        );
+        assert!(!calm.contains(KING_TIDE_MARKER));
+    }
+
+    #[test]
+    fn test_lake_table_leaves_out_tide_and_waves() {
+        let conditions = conditions("trout-lake");
+        let today = NaiveDate::from_ymd_opt(2024, 7, 13).unwrap();
+        let columns = build_columns(
+            &conditions,
+            Activity::Swimming,
+            &waves(),
+            |_, _| Some(3.0),
+            today,
+            12,
+        );
+
+        assert!(columns.iter().all(|column| column.tide.is_none()));
+        assert!(columns.iter().all(|column| column.score.is_some()));
+    }
+
+    #[test]
+    fn test_html_table_colours_scores() {
+        let conditions = conditions("english-bay");
+        let today = NaiveDate::from_ymd_opt(2024, 7, 13).unwrap();
+        let html = export_table(
+            &conditions,
+            Activity::Swimming,
+            &waves(),
+            |_, _| None,
+            today,
+            20,
+            TableFormat::Html,
+        );
+
+        assert!(html.contains("<th colspan=\"2\">Sat 13</th><th colspan=\"14\">Sun 14</th>"));
+        assert!(html.contains("<th scope=\"row\">Gusts km/h</th><td>25</td>"));
+        assert!(html.contains("class=\"good\"") || html.contains("class=\"fair\""));
+        assert_eq!(score_class(40), "poor");
+
+        let empty = BeachConditions {
+            weather: None,
+            ..conditions
+        };
+        let html = export_table(
+            &empty,
+            Activity::Swimming,
+            &waves(),
+            |_, _| None,
+            today,
+            20,
+            TableFormat::Html,
+        );
+        assert!(html.contains("No forecast available"));
+    }
+}
@@ -0,0 +1,1180 @@
+//! User configuration for Vancouver Beach CLI
+//!
+//! Settings are read from `config.json` in the XDG config directory
+//! (`~/.config/vanbeach/` on Linux), or in `$BEACH_CLI_CONFIG_DIR` if set, so
+//! sandboxed runs, tests and separate profiles can each keep their own. A
+//! missing file means defaults. The config
+//! lets users pick the activity selected on startup, tune the scoring weights
+//! and comfortable temperature and wind per activity, add webcam snapshot URLs
+//! and weekly community events per beach, change the hours that
+//! are scored and charted, say when background refreshes save power and set
+//! the daily UV dose sun sessions warn about, quiet alerts overnight or
+//! turn kinds of alert off, write times on a 12-hour or 24-hour clock,
+//! name sets of beaches to compare on the dashboard, publish conditions to
+//! an MQTT broker for home automation, leave the terminal title alone,
+//! count locally which features get used, mark the scores that are good
+//! enough to go and say what changes after sunset, e.g.:
+//!
+//! ```json
+//! {
+//!   "default_activity": "swimming",
+//!   "scoring": { "sailing": { "wind": 0.8, "tide": 0.1 } },
+//!   "comfort": { "swimming": { "min_temperature": 22, "max_wind": 20 } },
+//!   "webcams": { "english-bay": "https://example.com/english-bay.jpg" },
+//!   "events": {
+//!     "english-bay": [{ "name": "Polar dip", "day": "saturday", "start": "08:00" }]
+//!   },
+//!   "refresh_cooldown_secs": 60,
+//!   "hours": { "start": 5, "end": 23 },
+//!   "power_saving": "auto",
+//!   "uv_dose_limit": 2.5,
+//!   "notifications": {
+//!     "quiet_hours": { "start": "22:00", "end": "07:00" },
+//!     "weather_warnings": false
+//!   },
+//!   "clock": "24h",
+//!   "comparison_sets": { "West side trio": ["kitsilano", "jericho", "locarno"] },
+//!   "mqtt": { "host": "homeassistant.local", "username": "beach", "password": "secret" },
+//!   "terminal_title": false,
+//!   "usage_metrics": true,
+//!   "good_score": 70,
+//!   "night": { "dim": true, "activity": "peace" }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use chrono::{NaiveTime, Weekday};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::activities::{preset_profile, Activity, ComfortBreakpoints, FactorWeights};
+use crate::data::beach::get_beach_by_id;
+use crate::data::BeachId;
+use crate::power::PowerSaving;
+use crate::time_format::ClockFormat;
+
+/// Name of the config file inside the config directory
+pub const CONFIG_FILE_NAME: &str = "config.json";
+
+/// Environment variable naming a config directory to use instead of the
+/// XDG one
+pub const CONFIG_DIR_ENV: &str = "BEACH_CLI_CONFIG_DIR";
+
+/// Seconds after a refresh before `r` refreshes again, unless configured
+pub const DEFAULT_REFRESH_COOLDOWN_SECS: u64 = 30;
+
+/// First and last hour scored and charted, unless configured: 6am to 9pm
+pub const DEFAULT_HOUR_RANGE: (u8, u8) = (6, 21);
+
+/// How long a community event runs, in minutes, unless configured
+pub const DEFAULT_EVENT_MINUTES: u32 = 60;
+
+/// How much a community event adds to the crowd while it's on, unless
+/// configured
+pub const DEFAULT_EVENT_CROWD: f32 = 0.3;
+
+/// Activity selected after sunset in place of a daytime one, unless
+/// configured
+pub const DEFAULT_NIGHT_ACTIVITY: &str = "peace";
+
+/// Daily UV dose in SED that sun sessions warn about, unless configured:
+/// roughly what reddens fair skin that burns easily
+pub const DEFAULT_UV_DOSE_LIMIT: f64 = 2.5;
+
+/// Hour range installed from the config by `set_hour_range`
+static HOUR_RANGE: OnceLock<(u8, u8)> = OnceLock::new();
+
+/// Marker next to scores at or above the configured good score
+pub const GOOD_SCORE_MARKER: &str = "\u{2713}"; // ✓
+
+/// Good score installed from the config by `set_good_score`
+static GOOD_SCORE: OnceLock<u8> = OnceLock::new();
+
+/// The directory named by an environment variable, if it's set and not empty
+pub fn env_dir(name: &str) -> Option<PathBuf> {
+    dir_from(std::env::var_os(name))
+}
+
+/// A directory override's value as a path; empty counts as unset, so
+/// `BEACH_CLI_CACHE_DIR= vanbeach` runs with the defaults
+fn dir_from(value: Option<OsString>) -> Option<PathBuf> {
+    value.filter(|value| !value.is_empty()).map(PathBuf::from)
+}
+
+/// Installs the hour range used by scoring, tide charts and the PlanTrip axis.
+///
+/// The range can only be installed once; returns false if it was already set.
+pub fn set_hour_range(range: (u8, u8)) -> bool {
+    HOUR_RANGE.set(range).is_ok()
+}
+
+/// First and last hour (inclusive) scored and charted
+pub fn hour_range() -> (u8, u8) {
+    HOUR_RANGE.get().copied().unwrap_or(DEFAULT_HOUR_RANGE)
+}
+
+/// Installs the score counted as good enough to go, marked in the beach
+/// list, Plan Trip grid, score dump and exported site.
+///
+/// The score can only be installed once; returns false if it was already set.
+pub fn set_good_score(score: u8) -> bool {
+    GOOD_SCORE.set(score).is_ok()
+}
+
+/// Score counted as good enough to go, if one is configured
+pub fn good_score() -> Option<u8> {
+    GOOD_SCORE.get().copied()
+}
+
+/// Whether a score reaches the configured good score; nothing does unless
+/// one is set
+pub fn is_good_score(score: u8) -> bool {
+    meets_good_score(score, good_score())
+}
+
+/// Whether a score reaches `good`, if there is one
+fn meets_good_score(score: u8, good: Option<u8>) -> bool {
+    good.is_some_and(|good| score >= good)
+}
+
+/// Which side of the hour range an hour falls on, for widgets that only
+/// cover the range and have no current hour to mark outside it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutsideHours {
+    /// Before the first hour, e.g. 3am with the default range
+    BeforeStart,
+    /// After the last hour, e.g. 11pm with the default range
+    AfterEnd,
+}
+
+impl OutsideHours {
+    /// Where `hour` falls outside the hour range, `None` within it
+    pub fn of(hour: u8) -> Option<Self> {
+        let (first_hour, last_hour) = hour_range();
+        if hour < first_hour {
+            Some(OutsideHours::BeforeStart)
+        } else if hour > last_hour {
+            Some(OutsideHours::AfterEnd)
+        } else {
+            None
+        }
+    }
+}
+
+/// Error types for loading and validating the config file
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    /// The config file exists but couldn't be read
+    #[error("Failed to read config: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The config file isn't valid JSON or has unknown fields
+    #[error("Failed to parse config: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    /// A scoring section names an activity that doesn't exist
+    #[error("Unknown activity in scoring config: '{0}'")]
+    UnknownActivity(String),
+
+    /// `default_activity` names an activity that doesn't exist
+    #[error("Unknown default_activity in config: '{0}'")]
+    UnknownDefaultActivity(String),
+
+    /// The night section names an activity that doesn't exist
+    #[error("Unknown night activity in config: '{0}'")]
+    UnknownNightActivity(String),
+
+    /// A comfort section names an activity that doesn't exist
+    #[error("Unknown activity in comfort config: '{0}'")]
+    UnknownComfortActivity(String),
+
+    /// A comfort range ends up empty, e.g. a minimum temperature above the
+    /// maximum, or allows negative wind
+    #[error("Invalid {factor} comfort range for {activity}: {min} to {max} (min must be below max, and wind at least 0)")]
+    InvalidComfortRange {
+        activity: String,
+        factor: &'static str,
+        min: f32,
+        max: f32,
+    },
+
+    /// The webcams section names a beach that doesn't exist
+    #[error("Unknown beach in webcams config: '{0}'")]
+    UnknownBeach(String),
+
+    /// The events section names a beach that doesn't exist
+    #[error("Unknown beach in events config: '{0}'")]
+    UnknownEventBeach(String),
+
+    /// A comparison set names a beach that doesn't exist
+    #[error("Unknown beach in comparison set '{set}': '{beach}'")]
+    UnknownComparisonBeach { set: String, beach: String },
+
+    /// A comparison set has no beaches
+    #[error("Comparison set '{0}' has no beaches")]
+    EmptyComparisonSet(String),
+
+    /// An event lasts no time or more than a day, or adds a crowd outside
+    /// 0.0 to 1.0
+    #[error("Invalid event '{0}' in config (minutes must be 1-1440, crowd between 0.0 and 1.0)")]
+    InvalidEvent(String),
+
+    /// A weight is negative, above 1.0 or not a number
+    #[error("Invalid {factor} weight for {activity}: {value} (must be between 0.0 and 1.0)")]
+    InvalidWeight {
+        activity: String,
+        factor: &'static str,
+        value: f32,
+    },
+
+    /// Every factor for an activity was weighted to zero
+    #[error("All scoring weights for {0} are zero")]
+    ZeroWeights(String),
+
+    /// The hours section is out of order or past 23
+    #[error("Invalid hours in config: {start} to {end} (start must be before end, both 0-23)")]
+    InvalidHourRange { start: u8, end: u8 },
+
+    /// The good score is zero or above 100
+    #[error("Invalid good_score in config: {0} (must be 1-100)")]
+    InvalidGoodScore(u8),
+
+    /// The UV dose limit is zero, negative or not a number
+    #[error("Invalid uv_dose_limit in config: {0} (must be above 0)")]
+    InvalidUvDoseLimit(f64),
+
+    /// Quiet hours start and end at the same time
+    #[error("Invalid quiet_hours in config: {0} to {0} (start and end must differ)")]
+    EmptyQuietHours(NaiveTime),
+
+    /// The MQTT section has no broker host or a prefix topics can't use
+    #[error("Invalid mqtt in config: {0}")]
+    InvalidMqtt(&'static str),
+}
+
+/// Topic prefix for MQTT publishing, unless configured
+pub const DEFAULT_MQTT_TOPIC_PREFIX: &str = "beachcli";
+
+/// MQTT broker port, unless configured
+pub const DEFAULT_MQTT_PORT: u16 = 1883;
+
+/// Hours of the day to score and chart, both inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HourRange {
+    /// First hour (0-23), e.g. 5 for early birds
+    pub start: u8,
+    /// Last hour (0-23), e.g. 23 for late sunsets
+    pub end: u8,
+}
+
+/// What changes once the sun sets at the beach on screen, until it rises
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NightMode {
+    /// Whether the screen switches to a dimmer palette
+    pub dim: bool,
+    /// Activity selected in place of a daytime one, e.g. "peace"; null keeps
+    /// the selected activity
+    pub activity: Option<String>,
+}
+
+impl Default for NightMode {
+    fn default() -> Self {
+        Self {
+            dim: true,
+            activity: Some(DEFAULT_NIGHT_ACTIVITY.to_string()),
+        }
+    }
+}
+
+/// Time of day alerts stay quiet, wrapping past midnight when the start is
+/// after the end (22:00 to 07:00)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuietHours {
+    /// When quiet hours start, e.g. "22:00"
+    pub start: NaiveTime,
+    /// When they end, e.g. "07:00"
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    /// Returns true if `time` falls in quiet hours; the start is included
+    /// and the end isn't
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// A community event that repeats every week at a beach, such as a
+/// Saturday polar dip or a Tuesday volleyball league
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecurringEvent {
+    /// What's on, e.g. "Polar dip"
+    pub name: String,
+    /// Day of the week it's on, e.g. "saturday" or "sat"
+    pub day: Weekday,
+    /// When it starts, e.g. "08:00"
+    pub start: NaiveTime,
+    /// How long it runs, an hour unless set
+    #[serde(default = "default_event_minutes")]
+    pub minutes: u32,
+    /// How much it adds to the crowd while it's on, from 0.0 to 1.0
+    #[serde(default = "default_event_crowd")]
+    pub crowd: f32,
+}
+
+/// An MQTT broker to publish each beach's conditions and scores to, such as
+/// the one Home Assistant runs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MqttSettings {
+    /// Broker host name or address, e.g. "homeassistant.local"
+    pub host: String,
+    /// Broker port, 1883 unless set
+    #[serde(default = "default_mqtt_port")]
+    pub port: u16,
+    /// First level of every topic, "beachcli" unless set
+    #[serde(default = "default_mqtt_topic_prefix")]
+    pub topic_prefix: String,
+    /// User name, for brokers that require a login
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Password that goes with the user name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+fn default_mqtt_port() -> u16 {
+    DEFAULT_MQTT_PORT
+}
+
+fn default_mqtt_topic_prefix() -> String {
+    DEFAULT_MQTT_TOPIC_PREFIX.to_string()
+}
+
+fn default_event_minutes() -> u32 {
+    DEFAULT_EVENT_MINUTES
+}
+
+fn default_event_crowd() -> f32 {
+    DEFAULT_EVENT_CROWD
+}
+
+/// Which alerts are raised, and when they stay quiet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Notifications {
+    /// When alerts only show their toast, without the bell, flash or desktop
+    /// notification
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quiet_hours: Option<QuietHours>,
+    /// Water quality advisories issued or lifted
+    pub advisory_changes: bool,
+    /// Best windows about to start
+    pub best_window_reminders: bool,
+    /// Weather warnings, such as a sun session's UV dose nearing the limit
+    pub weather_warnings: bool,
+    /// Rain forecast for a pinned plan, with a dry hour to go instead
+    pub rain_delays: bool,
+}
+
+impl Default for Notifications {
+    fn default() -> Self {
+        Self {
+            quiet_hours: None,
+            advisory_changes: true,
+            best_window_reminders: true,
+            weather_warnings: true,
+            rain_delays: true,
+        }
+    }
+}
+
+/// User configuration loaded from disk
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Activity selected on startup (e.g. "swimming"), unless one is given
+    /// on the command line
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_activity: Option<String>,
+
+    /// Scoring weight overrides keyed by activity name (e.g. "sailing", "swim")
+    #[serde(default)]
+    pub scoring: BTreeMap<String, FactorWeights>,
+
+    /// Comfort breakpoints keyed by activity name, moving the temperature
+    /// and wind each activity scores as ideal
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub comfort: BTreeMap<String, ComfortBreakpoints>,
+
+    /// Webcam snapshot (JPEG or PNG) URLs keyed by beach id
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub webcams: BTreeMap<String, String>,
+
+    /// Weekly community events keyed by beach id
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, Vec<RecurringEvent>>,
+
+    /// Seconds after a refresh before `r` refreshes again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_cooldown_secs: Option<u64>,
+
+    /// Hours scored and charted, 6am to 9pm unless set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<HourRange>,
+
+    /// When background refreshes slow down to save power: "auto" (on
+    /// battery or while the terminal is unfocused), "always" or "never"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub power_saving: Option<PowerSaving>,
+
+    /// Daily UV dose in SED (standard erythemal doses) that sun sessions
+    /// warn about, 2.5 unless set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uv_dose_limit: Option<f64>,
+
+    /// Quiet hours and which kinds of alert are raised, all of them at any
+    /// time unless set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<Notifications>,
+
+    /// Whether times are written on a 12-hour ("12h") or 24-hour ("24h")
+    /// clock, following the locale unless set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clock: Option<ClockFormat>,
+
+    /// Named sets of beach ids to compare on the dashboard, e.g.
+    /// "West side trio"
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub comparison_sets: BTreeMap<String, Vec<String>>,
+
+    /// MQTT broker to publish conditions and scores to, nothing published
+    /// unless set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mqtt: Option<MqttSettings>,
+
+    /// Whether the terminal title follows the screen and beach, true unless
+    /// set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_title: Option<bool>,
+
+    /// Whether the TUI counts which screens, overlays and activities are
+    /// used, on this machine only; false unless set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_metrics: Option<bool>,
+
+    /// Score (1-100) from which beaches and hours are marked good enough to
+    /// go, nothing marked unless set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub good_score: Option<u8>,
+
+    /// Whether the screen dims and which activity is selected after sunset,
+    /// dimmed and "peace" unless set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub night: Option<NightMode>,
+}
+
+impl Config {
+    /// Returns the default config file path: in `$BEACH_CLI_CONFIG_DIR` if
+    /// set, otherwise in the XDG config directory if a home directory is
+    /// available
+    pub fn default_path() -> Option<PathBuf> {
+        let dir = match env_dir(CONFIG_DIR_ENV) {
+            Some(dir) => dir,
+            None => ProjectDirs::from("", "", "vanbeach")?
+                .config_dir()
+                .to_path_buf(),
+        };
+        Some(dir.join(CONFIG_FILE_NAME))
+    }
+
+    /// Loads the config from the default path, falling back to defaults if
+    /// there is no config file
+    pub fn load() -> Result<Self, ConfigError> {
+        match Self::default_path() {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Loads the config from a specific path; a missing file gives defaults
+    pub fn load_from(path: &Path) -> Result<Self, ConfigError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Resolves `default_activity` into an activity, if one is set
+    pub fn default_activity(&self) -> Result<Option<Activity>, ConfigError> {
+        self.default_activity
+            .as_deref()
+            .map(|name| {
+                Activity::from_str(name)
+                    .ok_or_else(|| ConfigError::UnknownDefaultActivity(name.to_string()))
+            })
+            .transpose()
+    }
+
+    /// How long `r` is ignored after a refresh, at most a day
+    pub fn refresh_cooldown(&self) -> chrono::Duration {
+        let secs = self
+            .refresh_cooldown_secs
+            .unwrap_or(DEFAULT_REFRESH_COOLDOWN_SECS);
+        chrono::Duration::seconds(secs.min(86_400) as i64)
+    }
+
+    /// When background refreshes save power, "auto" unless set
+    pub fn power_saving(&self) -> PowerSaving {
+        self.power_saving.unwrap_or_default()
+    }
+
+    /// Validates the UV dose limit, `DEFAULT_UV_DOSE_LIMIT` unless set
+    pub fn uv_dose_limit(&self) -> Result<f64, ConfigError> {
+        match self.uv_dose_limit {
+            None => Ok(DEFAULT_UV_DOSE_LIMIT),
+            Some(limit) if limit > 0.0 && limit.is_finite() => Ok(limit),
+            Some(limit) => Err(ConfigError::InvalidUvDoseLimit(limit)),
+        }
+    }
+
+    /// Validates the night section into whether to dim and the activity to
+    /// select after sunset
+    pub fn night_mode(&self) -> Result<(bool, Option<Activity>), ConfigError> {
+        let night = self.night.clone().unwrap_or_default();
+        let activity = night
+            .activity
+            .map(|name| Activity::from_str(&name).ok_or(ConfigError::UnknownNightActivity(name)))
+            .transpose()?;
+        Ok((night.dim, activity))
+    }
+
+    /// Validates the good score, if one is set
+    pub fn good_score(&self) -> Result<Option<u8>, ConfigError> {
+        match self.good_score {
+            Some(score) if score == 0 || score > 100 => Err(ConfigError::InvalidGoodScore(score)),
+            score => Ok(score),
+        }
+    }
+
+    /// Whether the TUI sets the terminal title, which it does unless set
+    pub fn terminal_title(&self) -> bool {
+        self.terminal_title.unwrap_or(true)
+    }
+
+    /// Whether feature usage is counted, which it isn't unless set
+    pub fn usage_metrics(&self) -> bool {
+        self.usage_metrics.unwrap_or(false)
+    }
+
+    /// Clock format for times, the locale's unless set
+    pub fn clock_format(&self) -> ClockFormat {
+        self.clock.unwrap_or_else(ClockFormat::from_locale)
+    }
+
+    /// Validates the notifications section, every alert at any time unless set
+    pub fn notifications(&self) -> Result<Notifications, ConfigError> {
+        let notifications = self.notifications.unwrap_or_default();
+        match notifications.quiet_hours {
+            Some(QuietHours { start, end }) if start == end => {
+                Err(ConfigError::EmptyQuietHours(start))
+            }
+            _ => Ok(notifications),
+        }
+    }
+
+    /// Validates the hours section into a (start, end) range
+    pub fn hour_range(&self) -> Result<(u8, u8), ConfigError> {
+        match self.hours {
+            None => Ok(DEFAULT_HOUR_RANGE),
+            Some(HourRange { start, end }) if start < end && end <= 23 => Ok((start, end)),
+            Some(HourRange { start, end }) => Err(ConfigError::InvalidHourRange { start, end }),
+        }
+    }
+
+    /// Validates the webcams section, returning (beach id, URL) pairs
+    pub fn webcam_urls(&self) -> Result<Vec<(BeachId, String)>, ConfigError> {
+        self.webcams
+            .iter()
+            .map(|(id, url)| match get_beach_by_id(id) {
+                Some(beach) => Ok((beach.id.clone(), url.clone())),
+                None => Err(ConfigError::UnknownBeach(id.clone())),
+            })
+            .collect()
+    }
+
+    /// Validates the comparison sets, returning each name with its beaches
+    pub fn comparison_sets(&self) -> Result<Vec<(String, Vec<BeachId>)>, ConfigError> {
+        self.comparison_sets
+            .iter()
+            .map(|(name, ids)| {
+                if ids.is_empty() {
+                    return Err(ConfigError::EmptyComparisonSet(name.clone()));
+                }
+                let beaches = ids
+                    .iter()
+                    .map(|id| {
+                        get_beach_by_id(id)
+                            .map(|beach| beach.id.clone())
+                            .ok_or_else(|| ConfigError::UnknownComparisonBeach {
+                                set: name.clone(),
+                                beach: id.clone(),
+                            })
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok((name.clone(), beaches))
+            })
+            .collect()
+    }
+
+    /// Validates the MQTT section, if there is one
+    pub fn mqtt(&self) -> Result<Option<MqttSettings>, ConfigError> {
+        let Some(mqtt) = &self.mqtt else {
+            return Ok(None);
+        };
+        if mqtt.host.trim().is_empty() {
+            return Err(ConfigError::InvalidMqtt("host is empty"));
+        }
+        let prefix = mqtt.topic_prefix.trim_matches('/');
+        if prefix.is_empty() || prefix.contains(['+', '#']) {
+            return Err(ConfigError::InvalidMqtt(
+                "topic_prefix must be non-empty, without + or # wildcards",
+            ));
+        }
+        Ok(Some(MqttSettings {
+            topic_prefix: prefix.to_string(),
+            ..mqtt.clone()
+        }))
+    }
+
+    /// Validates the events section, returning each event with its beach
+    pub fn beach_events(&self) -> Result<Vec<(BeachId, RecurringEvent)>, ConfigError> {
+        let mut events = Vec::new();
+        for (id, beach_events) in &self.events {
+            let beach =
+                get_beach_by_id(id).ok_or_else(|| ConfigError::UnknownEventBeach(id.clone()))?;
+            for event in beach_events {
+                if !(1..=1440).contains(&event.minutes) || !(0.0..=1.0).contains(&event.crowd) {
+                    return Err(ConfigError::InvalidEvent(event.name.clone()));
+                }
+                events.push((beach.id.clone(), event.clone()));
+            }
+        }
+        Ok(events)
+    }
+
+    /// Validates the comfort section and resolves it into per-activity
+    /// breakpoints
+    ///
+    /// Each range is checked once merged with the preset, so `max_wind: 5`
+    /// is rejected for sailing, whose ideal wind starts at 15 km/h.
+    pub fn comfort_overrides(&self) -> Result<Vec<(Activity, ComfortBreakpoints)>, ConfigError> {
+        let mut overrides = Vec::with_capacity(self.comfort.len());
+
+        for (name, comfort) in &self.comfort {
+            let activity = Activity::from_str(name)
+                .ok_or_else(|| ConfigError::UnknownComfortActivity(name.clone()))?;
+
+            let profile = preset_profile(activity).with_comfort(comfort);
+            let ranges = [
+                ("temperature", profile.temp_ideal_range, f32::NEG_INFINITY),
+                ("wind", profile.wind_ideal_range, 0.0),
+            ];
+            for (factor, (min, max), lowest) in ranges {
+                // Written so a NaN fails too
+                if !(min < max && min >= lowest) {
+                    return Err(ConfigError::InvalidComfortRange {
+                        activity: activity.label().to_string(),
+                        factor,
+                        min,
+                        max,
+                    });
+                }
+            }
+
+            overrides.push((activity, *comfort));
+        }
+
+        Ok(overrides)
+    }
+
+    /// Validates the scoring section and resolves it into per-activity overrides
+    pub fn weight_overrides(&self) -> Result<Vec<(Activity, FactorWeights)>, ConfigError> {
+        let mut overrides = Vec::with_capacity(self.scoring.len());
+
+        for (name, weights) in &self.scoring {
+            let activity = Activity::from_str(name)
+                .ok_or_else(|| ConfigError::UnknownActivity(name.clone()))?;
+
+            for (factor, value) in weights.entries() {
+                if let Some(value) = value {
+                    if !(0.0..=1.0).contains(&value) {
+                        return Err(ConfigError::InvalidWeight {
+                            activity: activity.label().to_string(),
+                            factor,
+                            value,
+                        });
+                    }
+                }
+            }
+
+            let profile = preset_profile(activity).with_weights(weights);
+            if profile.factor_weight_total() <= 0.0 {
+                return Err(ConfigError::ZeroWeights(activity.label().to_string()));
+            }
+
+            overrides.push((activity, *weights));
+        }
+
+        Ok(overrides)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_missing_file_gives_default_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = Config::load_from(&temp_dir.path().join(CONFIG_FILE_NAME)).unwrap();
+        assert!(config.scoring.is_empty());
+    }
+
+    #[test]
+    fn test_dir_overrides_ignore_empty_values() {
+        assert_eq!(
+            dir_from(Some(OsString::from("/tmp/profile-b"))),
+            Some(PathBuf::from("/tmp/profile-b"))
+        );
+        assert_eq!(dir_from(Some(OsString::new())), None);
+        assert_eq!(dir_from(None), None);
+    }
+
+    #[test]
+    fn test_load_scoring_overrides() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(CONFIG_FILE_NAME);
+        fs::write(
+            &path,
+            r#"{"scoring": {"sail": {"wind": 0.8, "tide": 0.1}}}"#,
+        )
+        .unwrap();
+
+        let config = Config::load_from(&path).unwrap();
+        let overrides = config.weight_overrides().unwrap();
+
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].0, Activity::Sailing);
+        assert_eq!(overrides[0].1.wind, Some(0.8));
+        assert_eq!(overrides[0].1.crowd, None);
+    }
+
+    #[test]
+    fn test_default_activity() {
+        let config: Config = serde_json::from_str(r#"{"default_activity": "swim"}"#).unwrap();
+        assert_eq!(config.default_activity().unwrap(), Some(Activity::Swimming));
+
+        assert_eq!(Config::default().default_activity().unwrap(), None);
+
+        let config: Config = serde_json::from_str(r#"{"default_activity": "kayak"}"#).unwrap();
+        assert!(matches!(
+            config.default_activity(),
+            Err(ConfigError::UnknownDefaultActivity(name)) if name == "kayak"
+        ));
+    }
+
+    #[test]
+    fn test_refresh_cooldown() {
+        assert_eq!(
+            Config::default().refresh_cooldown(),
+            chrono::Duration::seconds(DEFAULT_REFRESH_COOLDOWN_SECS as i64)
+        );
+
+        let config: Config = serde_json::from_str(r#"{"refresh_cooldown_secs": 0}"#).unwrap();
+        assert_eq!(config.refresh_cooldown(), chrono::Duration::zero());
+    }
+
+    #[test]
+    fn test_power_saving() {
+        assert_eq!(Config::default().power_saving(), PowerSaving::Auto);
+
+        let config: Config = serde_json::from_str(r#"{"power_saving": "always"}"#).unwrap();
+        assert_eq!(config.power_saving(), PowerSaving::Always);
+
+        assert!(serde_json::from_str::<Config>(r#"{"power_saving": "sometimes"}"#).is_err());
+    }
+
+    #[test]
+    fn test_clock_format() {
+        let config: Config = serde_json::from_str(r#"{"clock": "24h"}"#).unwrap();
+        assert_eq!(config.clock_format(), ClockFormat::TwentyFourHour);
+
+        let config: Config = serde_json::from_str(r#"{"clock": "12h"}"#).unwrap();
+        assert_eq!(config.clock_format(), ClockFormat::TwelveHour);
+
+        assert!(serde_json::from_str::<Config>(r#"{"clock": "am/pm"}"#).is_err());
+    }
+
+    #[test]
+    fn test_uv_dose_limit() {
+        assert_eq!(
+            Config::default().uv_dose_limit().unwrap(),
+            DEFAULT_UV_DOSE_LIMIT
+        );
+
+        let config: Config = serde_json::from_str(r#"{"uv_dose_limit": 4.0}"#).unwrap();
+        assert_eq!(config.uv_dose_limit().unwrap(), 4.0);
+
+        for limit in [0.0, -1.0, f64::NAN] {
+            let config = Config {
+                uv_dose_limit: Some(limit),
+                ..Config::default()
+            };
+            assert!(matches!(
+                config.uv_dose_limit(),
+                Err(ConfigError::InvalidUvDoseLimit(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_night_mode() {
+        assert_eq!(
+            Config::default().night_mode().unwrap(),
+            (true, Some(Activity::Peace))
+        );
+
+        let config: Config =
+            serde_json::from_str(r#"{"night": {"dim": false, "activity": "sunset"}}"#).unwrap();
+        assert_eq!(
+            config.night_mode().unwrap(),
+            (false, Some(Activity::Sunset))
+        );
+
+        // A missing activity keeps the default, null turns switching off
+        let config: Config = serde_json::from_str(r#"{"night": {"dim": false}}"#).unwrap();
+        assert_eq!(config.night_mode().unwrap(), (false, Some(Activity::Peace)));
+        let config: Config = serde_json::from_str(r#"{"night": {"activity": null}}"#).unwrap();
+        assert_eq!(config.night_mode().unwrap(), (true, None));
+
+        let config: Config = serde_json::from_str(r#"{"night": {"activity": "karaoke"}}"#).unwrap();
+        assert!(matches!(
+            config.night_mode(),
+            Err(ConfigError::UnknownNightActivity(name)) if name == "karaoke"
+        ));
+    }
+
+    #[test]
+    fn test_good_score() {
+        assert_eq!(Config::default().good_score().unwrap(), None);
+
+        let config: Config = serde_json::from_str(r#"{"good_score": 70}"#).unwrap();
+        assert_eq!(config.good_score().unwrap(), Some(70));
+
+        for score in [0, 101] {
+            let config = Config {
+                good_score: Some(score),
+                ..Config::default()
+            };
+            assert!(matches!(
+                config.good_score(),
+                Err(ConfigError::InvalidGoodScore(_))
+            ));
+        }
+
+        assert!(meets_good_score(70, Some(70)));
+        assert!(!meets_good_score(69, Some(70)));
+        assert!(!meets_good_score(100, None));
+    }
+
+    #[test]
+    fn test_notifications() {
+        assert_eq!(
+            Config::default().notifications().unwrap(),
+            Notifications::default()
+        );
+
+        let config: Config = serde_json::from_str(
+            r#"{"notifications": {"quiet_hours": {"start": "22:00", "end": "07:00"}, "weather_warnings": false}}"#,
+        )
+        .unwrap();
+        let notifications = config.notifications().unwrap();
+        assert!(notifications.advisory_changes);
+        assert!(!notifications.weather_warnings);
+
+        let quiet = notifications.quiet_hours.unwrap();
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        assert!(quiet.contains(at(22, 0)));
+        assert!(quiet.contains(at(3, 30)));
+        assert!(!quiet.contains(at(7, 0)));
+        assert!(!quiet.contains(at(12, 0)));
+
+        let daytime = QuietHours {
+            start: at(13, 0),
+            end: at(15, 0),
+        };
+        assert!(daytime.contains(at(14, 0)));
+        assert!(!daytime.contains(at(22, 0)));
+
+        let config: Config = serde_json::from_str(
+            r#"{"notifications": {"quiet_hours": {"start": "22:00", "end": "22:00"}}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            config.notifications(),
+            Err(ConfigError::EmptyQuietHours(_))
+        ));
+
+        assert!(serde_json::from_str::<Config>(
+            r#"{"notifications": {"quiet_hours": {"start": "late", "end": "07:00"}}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_outside_hours_at_the_range_boundaries() {
+        let (first_hour, last_hour) = DEFAULT_HOUR_RANGE;
+        assert_eq!(OutsideHours::of(0), Some(OutsideHours::BeforeStart));
+        assert_eq!(
+            OutsideHours::of(first_hour - 1),
+            Some(OutsideHours::BeforeStart)
+        );
+        assert_eq!(OutsideHours::of(first_hour), None);
+        assert_eq!(OutsideHours::of(last_hour), None);
+        assert_eq!(
+            OutsideHours::of(last_hour + 1),
+            Some(OutsideHours::AfterEnd)
+        );
+        assert_eq!(OutsideHours::of(23), Some(OutsideHours::AfterEnd));
+    }
+
+    #[test]
+    fn test_hour_range() {
+        assert_eq!(Config::default().hour_range().unwrap(), DEFAULT_HOUR_RANGE);
+
+        let config: Config = serde_json::from_str(r#"{"hours": {"start": 5, "end": 23}}"#).unwrap();
+        assert_eq!(config.hour_range().unwrap(), (5, 23));
+
+        for (start, end) in [(12, 12), (20, 8), (6, 24)] {
+            let config = Config {
+                hours: Some(HourRange { start, end }),
+                ..Config::default()
+            };
+            assert!(matches!(
+                config.hour_range(),
+                Err(ConfigError::InvalidHourRange { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_webcam_urls() {
+        let config: Config =
+            serde_json::from_str(r#"{"webcams": {"kitsilano": "https://example.com/kits.jpg"}}"#)
+                .unwrap();
+        assert_eq!(
+            config.webcam_urls().unwrap(),
+            vec![(
+                BeachId::new("kitsilano"),
+                "https://example.com/kits.jpg".to_string()
+            )]
+        );
+
+        let config: Config =
+            serde_json::from_str(r#"{"webcams": {"waikiki": "https://example.com/w.jpg"}}"#)
+                .unwrap();
+        assert!(matches!(
+            config.webcam_urls(),
+            Err(ConfigError::UnknownBeach(id)) if id == "waikiki"
+        ));
+    }
+
+    #[test]
+    fn test_comparison_sets() {
+        let config: Config = serde_json::from_str(
+            r#"{"comparison_sets": {"West side trio": ["kitsilano", "jericho", "locarno"]}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.comparison_sets().unwrap(),
+            vec![(
+                "West side trio".to_string(),
+                vec![
+                    BeachId::new("kitsilano"),
+                    BeachId::new("jericho"),
+                    BeachId::new("locarno")
+                ]
+            )]
+        );
+
+        let config: Config =
+            serde_json::from_str(r#"{"comparison_sets": {"Away": ["kitsilano", "waikiki"]}}"#)
+                .unwrap();
+        assert!(matches!(
+            config.comparison_sets(),
+            Err(ConfigError::UnknownComparisonBeach { set, beach }) if set == "Away" && beach == "waikiki"
+        ));
+        let config: Config = serde_json::from_str(r#"{"comparison_sets": {"None": []}}"#).unwrap();
+        assert!(matches!(
+            config.comparison_sets(),
+            Err(ConfigError::EmptyComparisonSet(name)) if name == "None"
+        ));
+    }
+
+    #[test]
+    fn test_beach_events() {
+        let config: Config = serde_json::from_str(
+            r#"{"events": {"kitsilano": [
+                {"name": "Polar dip", "day": "saturday", "start": "08:00"},
+                {"name": "Volleyball league", "day": "Tue", "start": "18:30", "minutes": 120, "crowd": 0.5}
+            ]}}"#,
+        )
+        .unwrap();
+        let events = config.beach_events().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].0, BeachId::new("kitsilano"));
+        assert_eq!(
+            events[0].1,
+            RecurringEvent {
+                name: "Polar dip".to_string(),
+                day: Weekday::Sat,
+                start: NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+                minutes: DEFAULT_EVENT_MINUTES,
+                crowd: DEFAULT_EVENT_CROWD,
+            }
+        );
+        assert_eq!(events[1].1.day, Weekday::Tue);
+
+        let config: Config = serde_json::from_str(
+            r#"{"events": {"waikiki": [{"name": "Luau", "day": "fri", "start": "19:00"}]}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            config.beach_events(),
+            Err(ConfigError::UnknownEventBeach(id)) if id == "waikiki"
+        ));
+
+        let config: Config = serde_json::from_str(
+            r#"{"events": {"kitsilano": [{"name": "Swarm", "day": "sun", "start": "12:00", "crowd": 2}]}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            config.beach_events(),
+            Err(ConfigError::InvalidEvent(name)) if name == "Swarm"
+        ));
+    }
+
+    #[test]
+    fn test_mqtt_settings() {
+        assert_eq!(Config::default().mqtt().unwrap(), None);
+
+        let config: Config = serde_json::from_str(
+            r#"{"mqtt": {"host": "ha.local", "topic_prefix": "home/beach/"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.mqtt().unwrap(),
+            Some(MqttSettings {
+                host: "ha.local".to_string(),
+                port: DEFAULT_MQTT_PORT,
+                topic_prefix: "home/beach".to_string(),
+                username: None,
+                password: None,
+            })
+        );
+
+        let config: Config =
+            serde_json::from_str(r##"{"mqtt": {"host": "ha.local", "topic_prefix": "beach/#"}}"##)
+                .unwrap();
+        assert!(matches!(config.mqtt(), Err(ConfigError::InvalidMqtt(_))));
+        let config: Config = serde_json::from_str(r#"{"mqtt": {"host": " "}}"#).unwrap();
+        assert!(matches!(config.mqtt(), Err(ConfigError::InvalidMqtt(_))));
+    }
+
+    #[test]
+    fn test_unknown_factor_is_parse_error() {
+        let result: Result<Config, _> =
+            serde_json::from_str(r#"{"scoring": {"sailing": {"waves": 0.5}}}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_comfort_overrides() {
+        let config: Config = serde_json::from_str(
+            r#"{"comfort": {"swim": {"min_temperature": 22, "max_wind": 20}}}"#,
+        )
+        .unwrap();
+        let overrides = config.comfort_overrides().unwrap();
+        assert_eq!(overrides[0].0, Activity::Swimming);
+        assert_eq!(overrides[0].1.min_temperature, Some(22.0));
+
+        let config: Config =
+            serde_json::from_str(r#"{"comfort": {"kayak": {"max_wind": 10}}}"#).unwrap();
+        assert!(matches!(
+            config.comfort_overrides(),
+            Err(ConfigError::UnknownComfortActivity(name)) if name == "kayak"
+        ));
+
+        // Sailing's ideal wind starts at 15 km/h
+        let config: Config =
+            serde_json::from_str(r#"{"comfort": {"sailing": {"max_wind": 5}}}"#).unwrap();
+        let err = config.comfort_overrides().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("wind comfort range for Sailing: 15 to 5"));
+
+        let config: Config =
+            serde_json::from_str(r#"{"comfort": {"sunset": {"min_wind": -5}}}"#).unwrap();
+        assert!(config.comfort_overrides().is_err());
+
+        let result: Result<Config, _> =
+            serde_json::from_str(r#"{"comfort": {"swimming": {"min_tide": 1}}}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_unknown_activity_is_rejected() {
+        let config: Config = serde_json::from_str(r#"{"scoring": {"kayak": {}}}"#).unwrap();
+        assert!(matches!(
+            config.weight_overrides(),
+            Err(ConfigError::UnknownActivity(name)) if name == "kayak"
+        ));
+    }
+
+    #[test]
+    fn test_out_of_range_weight_is_rejected() {
+        let config: Config =
+            serde_json::from_str(r#"{"scoring": {"swimming": {"temperature": 1.5}}}"#).unwrap();
+        let err = config.weight_overrides().unwrap_err();
+        assert!(err.to_string().contains("temperature weight for Swimming"));
+    }
+
+    #[test]
+    fn test_all_zero_weights_are_rejected() {
+        let config: Config = serde_json::from_str(
+            r#"{"scoring": {"sunset": {"temperature": 0, "wind": 0, "crowd": 0}}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            config.weight_overrides(),
+            Err(ConfigError::ZeroWeights(_))
+        ));
+    }
+}
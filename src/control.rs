@@ -0,0 +1,281 @@
+//! Control socket for driving the TUI from outside (`--control`)
+//!
+//! With `--control <PATH>` the running TUI listens on a Unix domain socket
+//! for one command per line, so demos, integration tests and accessibility
+//! tooling can drive it:
+//!
+//! - `select <beach>` moves the list selection to a beach, by id or name,
+//!   and switches the detail view to it if one is open
+//! - `activity <name>` selects an activity, as its number key does
+//! - `screenshot` returns the next frame as text
+//!
+//! Each command is answered with `ok`, or `error: <reason>`. A screenshot's
+//! `ok` carries the number of lines that follow it, e.g. `ok 24`.
+//!
+//! Connections are accepted in the background; the event loop applies the
+//! commands that arrived once per pass, between its other work, so a command
+//! never races a key press.
+
+use std::io;
+use std::os::unix::net::UnixStream as StdUnixStream;
+use std::path::{Path, PathBuf};
+
+use ratatui::buffer::Buffer;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+use crate::activities::Activity;
+use crate::app::App;
+use crate::data::{all_beaches, get_beach_by_id, Beach};
+
+/// A command read from the socket
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Select a beach in the list
+    Select(&'static Beach),
+    /// Select an activity
+    Activity(Activity),
+    /// Send the next frame as text
+    Screenshot,
+}
+
+/// Parses one line from the socket
+pub fn parse_command(line: &str) -> Result<Command, String> {
+    let line = line.trim();
+    let (name, argument) = line
+        .split_once(char::is_whitespace)
+        .map_or((line, ""), |(name, argument)| (name, argument.trim()));
+    match (name, argument) {
+        ("select", beach) if !beach.is_empty() => find_beach(beach)
+            .map(Command::Select)
+            .ok_or_else(|| format!("unknown beach '{}'", beach)),
+        ("activity", activity) => Activity::from_str(activity)
+            .map(Command::Activity)
+            .ok_or_else(|| format!("unknown activity '{}'", activity)),
+        ("screenshot", "") => Ok(Command::Screenshot),
+        _ => Err(format!(
+            "unknown command '{}'; expected select <beach>, activity <name> or screenshot",
+            line
+        )),
+    }
+}
+
+/// A beach by id, or by name ignoring case
+fn find_beach(beach: &str) -> Option<&'static Beach> {
+    get_beach_by_id(beach).or_else(|| {
+        all_beaches()
+            .iter()
+            .find(|candidate| candidate.name.eq_ignore_ascii_case(beach))
+    })
+}
+
+/// A command waiting for the event loop, with where to send its answer
+struct Request {
+    command: Command,
+    reply: oneshot::Sender<String>,
+}
+
+/// The listening socket and the commands that arrived on it
+pub struct ControlSocket {
+    path: PathBuf,
+    requests: mpsc::UnboundedReceiver<Request>,
+    /// Screenshots to answer with the next frame
+    screenshots: Vec<oneshot::Sender<String>>,
+    accept: JoinHandle<()>,
+}
+
+impl ControlSocket {
+    /// Listens on `path`, replacing a socket left behind by a TUI that's no
+    /// longer running
+    pub fn bind(path: &Path) -> io::Result<Self> {
+        let listener = match UnixListener::bind(path) {
+            Err(e)
+                if e.kind() == io::ErrorKind::AddrInUse
+                    && StdUnixStream::connect(path).is_err() =>
+            {
+                std::fs::remove_file(path)?;
+                UnixListener::bind(path)?
+            }
+            bound => bound?,
+        };
+        let (sender, requests) = mpsc::unbounded_channel();
+        Ok(Self {
+            path: path.to_path_buf(),
+            requests,
+            screenshots: Vec::new(),
+            accept: tokio::spawn(accept(listener, sender)),
+        })
+    }
+
+    /// Applies the commands that arrived since the last call; true if any did
+    pub fn apply(&mut self, app: &mut App) -> bool {
+        let mut applied = false;
+        while let Ok(Request { command, reply }) = self.requests.try_recv() {
+            applied = true;
+            let answer = match command {
+                Command::Select(beach) if app.select_beach(&beach.id) => "ok".to_string(),
+                Command::Select(beach) => format!("error: {} is hidden from the list", beach.name),
+                Command::Activity(activity) => {
+                    app.select_activity(activity);
+                    "ok".to_string()
+                }
+                Command::Screenshot => {
+                    self.screenshots.push(reply);
+                    continue;
+                }
+            };
+            // A client that hung up doesn't need its answer
+            let _ = reply.send(answer);
+        }
+        applied
+    }
+
+    /// Answers waiting screenshots with a frame that was just drawn
+    pub fn frame_drawn(&mut self, buffer: &Buffer) {
+        if self.screenshots.is_empty() {
+            return;
+        }
+        let lines = frame_text(buffer);
+        let answer = format!("ok {}\n{}", lines.len(), lines.join("\n"));
+        for reply in self.screenshots.drain(..) {
+            let _ = reply.send(answer.clone());
+        }
+    }
+}
+
+impl Drop for ControlSocket {
+    fn drop(&mut self) {
+        self.accept.abort();
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// A frame's rows as text, without trailing spaces
+fn frame_text(buffer: &Buffer) -> Vec<String> {
+    let width = buffer.area.width as usize;
+    buffer
+        .content()
+        .chunks(width.max(1))
+        .map(|row| {
+            let line: String = row.iter().map(|cell| cell.symbol()).collect();
+            line.trim_end().to_string()
+        })
+        .collect()
+}
+
+/// Accepts connections until the socket is dropped
+async fn accept(listener: UnixListener, requests: mpsc::UnboundedSender<Request>) {
+    loop {
+        // A failed connection shouldn't stop the others
+        if let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(handle_connection(stream, requests.clone()));
+        }
+    }
+}
+
+/// Answers a connection's commands one line at a time until it closes or
+/// the TUI quits
+async fn handle_connection(
+    stream: UnixStream,
+    requests: mpsc::UnboundedSender<Request>,
+) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let answer = match parse_command(&line) {
+            Ok(command) => {
+                let (reply, answer) = oneshot::channel();
+                if requests.send(Request { command, reply }).is_err() {
+                    break;
+                }
+                match answer.await {
+                    Ok(answer) => answer,
+                    Err(_) => break,
+                }
+            }
+            Err(e) => format!("error: {}", e),
+        };
+        writer.write_all(format!("{}\n", answer).as_bytes()).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::AppState;
+    use ratatui::{backend::TestBackend, widgets::Paragraph, Terminal};
+
+    #[test]
+    fn test_parse_command() {
+        let beach = &all_beaches()[0];
+        assert_eq!(
+            parse_command(&format!("select {}", beach.id.as_str())),
+            Ok(Command::Select(beach))
+        );
+        assert_eq!(
+            parse_command(&format!("select  {}\n", beach.name.to_uppercase())),
+            Ok(Command::Select(beach))
+        );
+        assert_eq!(
+            parse_command("activity sail"),
+            Ok(Command::Activity(Activity::Sailing))
+        );
+        assert_eq!(parse_command("screenshot"), Ok(Command::Screenshot));
+        assert!(parse_command("select atlantis").is_err());
+        assert!(parse_command("activity").is_err());
+        assert!(parse_command("screenshot now").is_err());
+        assert!(parse_command("quit").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_commands_drive_the_app_over_the_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("control.sock");
+        let mut control = ControlSocket::bind(&path).unwrap();
+        let mut app = App::new();
+        app.state = AppState::BeachList;
+        let beach = &all_beaches()[2];
+
+        let script = format!(
+            "activity sail\nselect {}\nfly away\nscreenshot\n",
+            beach.id.as_str()
+        );
+        let client = tokio::spawn(async move {
+            let mut stream = UnixStream::connect(&path).await.unwrap();
+            stream.write_all(script.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+            let mut lines = BufReader::new(stream).lines();
+            let mut answers = Vec::new();
+            while let Some(line) = lines.next_line().await.unwrap() {
+                answers.push(line);
+            }
+            answers
+        });
+
+        // The event loop's part: apply commands and draw until the client
+        // has its answers
+        let mut terminal = Terminal::new(TestBackend::new(20, 2)).unwrap();
+        while !client.is_finished() {
+            if control.apply(&mut app) {
+                let frame = terminal
+                    .draw(|f| f.render_widget(Paragraph::new("Beaches"), f.area()))
+                    .unwrap();
+                control.frame_drawn(frame.buffer);
+            }
+            tokio::task::yield_now().await;
+        }
+
+        assert_eq!(app.current_activity, Some(Activity::Sailing));
+        assert_eq!(app.selected_beach(), Some(beach));
+        let answers = client.await.unwrap();
+        assert_eq!(answers[..2], ["ok", "ok"]);
+        assert!(answers[2].starts_with("error: unknown command 'fly away'"));
+        assert_eq!(answers[3..], ["ok 2", "Beaches", ""]);
+    }
+}
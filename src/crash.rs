@@ -0,0 +1,216 @@
+//! Crash reports
+//!
+//! If the app panics, the panic hook restores the terminal and then writes a
+//! crash report to the cache directory: the panic message and backtrace, a
+//! summary of what the app was showing, the latest journal entries and the
+//! versions involved, all in one text file to attach to a bug report. With
+//! `--no-cache` nothing is written to disk, so the report goes to stderr.
+//!
+//! The event loop notes the app's state with `note_state` each time it
+//! draws, since the hook can't reach the app itself.
+
+use std::backtrace::Backtrace;
+use std::fmt::Write as _;
+use std::fs;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Local};
+
+use crate::app::App;
+use crate::journal::Journal;
+
+/// Journal entries included in a report, the latest ones
+const JOURNAL_LINES: usize = 20;
+
+/// What the app was showing when last drawn
+static STATE: Mutex<String> = Mutex::new(String::new());
+
+/// Notes the app's state for a crash report
+pub fn note_state(app: &App) {
+    let summary = state_summary(app);
+    if let Ok(mut state) = STATE.lock() {
+        *state = summary;
+    }
+}
+
+/// A few lines on what the app was showing and what it had loaded
+fn state_summary(app: &App) -> String {
+    let mut summary = format!("State: {:?}\n", app.state);
+    let overlays: Vec<&str> = [
+        (app.show_help, "help"),
+        (app.show_debug_view, "raw responses"),
+        (app.show_directions, "directions"),
+    ]
+    .into_iter()
+    .filter_map(|(shown, name)| shown.then_some(name))
+    .collect();
+    if !overlays.is_empty() {
+        let _ = writeln!(summary, "Overlays: {}", overlays.join(", "));
+    }
+    let _ = writeln!(
+        summary,
+        "Activity: {}",
+        app.current_activity
+            .map_or("none", |activity| activity.label())
+    );
+    let _ = writeln!(summary, "Selected: {}", app.selected_index);
+    let _ = writeln!(summary, "Beaches loaded: {}", app.beach_conditions.len());
+    let _ = writeln!(
+        summary,
+        "Last refresh: {}",
+        app.last_refresh
+            .map_or("never".to_string(), |at| at.to_rfc3339())
+    );
+    summary
+}
+
+/// Writes a crash report for a panic, returning where it went; `None` if
+/// there's no cache directory to write it to or writing failed, in which
+/// case it's printed to stderr instead
+pub fn report(info: &PanicHookInfo) -> Option<PathBuf> {
+    let state = STATE
+        .try_lock()
+        .map(|state| state.clone())
+        .unwrap_or_default();
+    let journal = Journal::open_default()
+        .map(|journal| journal.load())
+        .unwrap_or_default();
+    let journal_lines: Vec<String> = journal[journal.len().saturating_sub(JOURNAL_LINES)..]
+        .iter()
+        .map(|entry| entry.log_line())
+        .collect();
+    let now = Local::now();
+    let bundle = bundle(
+        &panic_message(info),
+        &Backtrace::force_capture().to_string(),
+        &state,
+        &journal_lines,
+        now,
+    );
+
+    let written = crate::cache::CacheManager::new()
+        .and_then(|cache| cache.dir().map(Path::to_path_buf))
+        .and_then(|dir| write_bundle(&dir, &bundle, now).ok());
+    if written.is_none() {
+        eprintln!("{}", bundle);
+    }
+    written
+}
+
+/// The panic's message and where it happened
+fn panic_message(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("(no message)");
+    match info.location() {
+        Some(location) => format!("{} at {}", message, location),
+        None => message.to_string(),
+    }
+}
+
+/// The report's text
+fn bundle(
+    message: &str,
+    backtrace: &str,
+    state: &str,
+    journal_lines: &[String],
+    now: DateTime<Local>,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "vanbeach crash report, {}", now.to_rfc3339());
+    let _ = writeln!(out);
+    let _ = writeln!(out, "== Versions ==");
+    let _ = writeln!(out, "vanbeach {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(out, "{} {}", std::env::consts::OS, std::env::consts::ARCH);
+    let features: Vec<&str> = [
+        (cfg!(feature = "graphics"), "graphics"),
+        (cfg!(feature = "qr"), "qr"),
+    ]
+    .into_iter()
+    .filter_map(|(enabled, name)| enabled.then_some(name))
+    .collect();
+    let _ = writeln!(
+        out,
+        "Features: {}",
+        if features.is_empty() {
+            "none".to_string()
+        } else {
+            features.join(", ")
+        }
+    );
+    let _ = writeln!(out);
+    let _ = writeln!(out, "== Panic ==");
+    let _ = writeln!(out, "{}", message);
+    let _ = writeln!(out);
+    let _ = writeln!(out, "== App state ==");
+    if state.is_empty() {
+        let _ = writeln!(out, "Not drawn yet");
+    } else {
+        out.push_str(state);
+    }
+    let _ = writeln!(out);
+    let _ = writeln!(out, "== Recent journal entries ==");
+    if journal_lines.is_empty() {
+        let _ = writeln!(out, "None");
+    }
+    for line in journal_lines {
+        let _ = writeln!(out, "{}", line);
+    }
+    let _ = writeln!(out);
+    let _ = writeln!(out, "== Backtrace ==");
+    let _ = writeln!(out, "{}", backtrace.trim_end());
+    out
+}
+
+/// Writes a report into `dir` as crash-<timestamp>.txt
+fn write_bundle(dir: &Path, bundle: &str, now: DateTime<Local>) -> std::io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!("crash-{}.txt", now.format("%Y%m%d-%H%M%S")));
+    fs::write(&path, bundle)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    use crate::activities::Activity;
+    use crate::app::AppState;
+
+    #[test]
+    fn test_bundle_has_every_section_in_one_file() {
+        let mut app = App::new();
+        app.state = AppState::Dashboard;
+        app.current_activity = Some(Activity::Sailing);
+        app.show_help = true;
+        let now = Local.with_ymd_and_hms(2026, 7, 15, 14, 30, 5).unwrap();
+
+        let bundle = bundle(
+            "index out of bounds at src/app.rs:10:5",
+            "0: vanbeach::main\n",
+            &state_summary(&app),
+            &[
+                "2026-07-15  2:00 PM  Advisory   Kitsilano Beach: water quality advisory issued"
+                    .to_string(),
+            ],
+            now,
+        );
+        assert!(bundle.contains(&format!("vanbeach {}", env!("CARGO_PKG_VERSION"))));
+        assert!(bundle.contains("== Panic ==\nindex out of bounds at src/app.rs:10:5\n"));
+        assert!(bundle.contains("State: Dashboard\nOverlays: help\nActivity: Sailing\n"));
+        assert!(bundle.contains("Kitsilano Beach: water quality advisory issued"));
+        assert!(bundle.ends_with("== Backtrace ==\n0: vanbeach::main\n"));
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_bundle(&temp_dir.path().join("cache"), &bundle, now).unwrap();
+        assert!(path.ends_with("crash-20260715-143005.txt"));
+        assert_eq!(fs::read_to_string(path).unwrap(), bundle);
+    }
+}
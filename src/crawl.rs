@@ -0,0 +1,525 @@
+//! Beach crawl planner
+//!
+//! `vanbeach crawl kitsilano:swim jericho:sail spanish-banks-east:sunset`
+//! fits two or three visits into one day: each stays a few consecutive
+//! hours at its beach, in the order and at the times that score best
+//! overall, with time between them to get from one beach to the next.
+//!
+//! Beaches joined by the seawall and paths are walked, or cycled if the
+//! walk would be long; anywhere else is a drive, estimated from the
+//! straight-line distance. The plan prints as an itinerary or, with
+//! `--ics`, as an iCalendar file of one event per visit.
+
+use std::fmt::Write as _;
+
+use chrono::{DateTime, Duration, NaiveDate, Timelike, Utc};
+
+use crate::activities::Activity;
+use crate::calendar::{begin_calendar, escape_text, event_uid, ical_utc, line, UID_DOMAIN};
+use crate::data::{
+    path_distance, Beach, BeachConditions, CYCLING_METRES_PER_MINUTE, WALKING_METRES_PER_MINUTE,
+};
+use crate::scoring::ActivityScorer;
+use crate::time_format::format_hour;
+
+/// Longest walk between stops before cycling instead, in minutes
+const MAX_WALK_MINUTES: u32 = 30;
+
+/// Longest ride between stops before driving instead, in minutes
+const MAX_BIKE_MINUTES: u32 = 40;
+
+/// Average driving speed across town, in metres per minute (24 km/h)
+const DRIVING_METRES_PER_MINUTE: u32 = 400;
+
+/// How much longer the drive is than the straight line between beaches
+const ROAD_DETOUR: f64 = 1.4;
+
+/// Minutes added to every drive to find parking and walk to the sand
+const PARKING_MINUTES: u32 = 10;
+
+/// How to get from one stop to the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TravelMode {
+    Walk,
+    Bike,
+    Drive,
+}
+
+impl TravelMode {
+    fn verb(self) -> &'static str {
+        match self {
+            TravelMode::Walk => "walk",
+            TravelMode::Bike => "bike",
+            TravelMode::Drive => "drive about",
+        }
+    }
+}
+
+/// Getting from one stop to the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Travel {
+    pub mode: TravelMode,
+    pub minutes: u32,
+}
+
+/// One stop of the crawl
+#[derive(Debug, Clone)]
+pub struct Visit<'a> {
+    pub conditions: &'a BeachConditions,
+    pub activity: Activity,
+    /// First hour at the beach
+    pub start_hour: u8,
+    /// Hour the visit ends, exclusive
+    pub end_hour: u8,
+    /// Mean score of the visit's hours
+    pub score: u8,
+    /// Getting here from the previous stop, `None` for the first
+    pub travel: Option<Travel>,
+}
+
+/// A day's stops, in the order to visit them
+#[derive(Debug, Clone)]
+pub struct Crawl<'a> {
+    pub date: NaiveDate,
+    pub visits: Vec<Visit<'a>>,
+}
+
+/// Plans a crawl of `stay_hours` at each stop, today from the current hour
+/// or tomorrow, or `None` if the stops don't all fit in the day
+///
+/// Of the orders and times that fit, the plan takes the highest total
+/// score, then the least travel, then the order the stops were given in.
+pub fn plan_crawl<'a>(
+    stops: &[(&'a BeachConditions, Activity)],
+    stay_hours: u8,
+    tomorrow: bool,
+    now: DateTime<Utc>,
+) -> Option<Crawl<'a>> {
+    let (first, _) = stops.first()?;
+    let local = first.beach.timezone.local(now);
+    let date = if tomorrow {
+        local.date() + Duration::days(1)
+    } else {
+        local.date()
+    };
+
+    let windows: Vec<Vec<(u8, u8)>> = stops
+        .iter()
+        .map(|&(conditions, activity)| {
+            let scores: Vec<(u8, u8)> = if tomorrow {
+                ActivityScorer::tomorrow(conditions, activity)
+                    .map(|(hour, slot)| (hour, slot.score))
+                    .collect()
+            } else {
+                ActivityScorer::hourly_from(conditions, activity, local.hour() as u8)
+                    .map(|(hour, slot)| (hour, slot.score))
+                    .collect()
+            };
+            stay_windows(&scores, stay_hours)
+        })
+        .collect();
+    let travel: Vec<Vec<Travel>> = stops
+        .iter()
+        .map(|(from, _)| {
+            stops
+                .iter()
+                .map(|(to, _)| travel_between(&from.beach, &to.beach))
+                .collect()
+        })
+        .collect();
+    let minutes: Vec<Vec<u32>> = travel
+        .iter()
+        .map(|row| row.iter().map(|travel| travel.minutes).collect())
+        .collect();
+
+    let plan = best_plan(&windows, &minutes, stay_hours)?;
+    let mut previous: Option<usize> = None;
+    let visits = plan
+        .into_iter()
+        .map(|(stop, start_hour)| {
+            let (conditions, activity) = stops[stop];
+            let score = windows[stop]
+                .iter()
+                .find(|&&(start, _)| start == start_hour)
+                .map_or(0, |&(_, score)| score);
+            let visit = Visit {
+                conditions,
+                activity,
+                start_hour,
+                end_hour: start_hour + stay_hours,
+                score,
+                travel: previous.map(|from| travel[from][stop]),
+            };
+            previous = Some(stop);
+            visit
+        })
+        .collect();
+    Some(Crawl { date, visits })
+}
+
+/// Every run of `stay_hours` consecutive scored hours, as its first hour
+/// and the rounded mean of its scores
+fn stay_windows(scores: &[(u8, u8)], stay_hours: u8) -> Vec<(u8, u8)> {
+    if stay_hours == 0 {
+        return Vec::new();
+    }
+    scores
+        .windows(stay_hours as usize)
+        .filter(|run| run[run.len() - 1].0 - run[0].0 == stay_hours - 1)
+        .map(|run| {
+            let total: u32 = run.iter().map(|&(_, score)| score as u32).sum();
+            let mean = (total as f64 / run.len() as f64).round() as u8;
+            (run[0].0, mean)
+        })
+        .collect()
+}
+
+/// The stops in visiting order, each with its first hour
+///
+/// `windows[i]` are stop i's possible visits and `minutes[i][j]` the travel
+/// time from stop i to stop j. A stop starts on the first whole hour after
+/// arriving from the one before.
+fn best_plan(
+    windows: &[Vec<(u8, u8)>],
+    minutes: &[Vec<u32>],
+    stay_hours: u8,
+) -> Option<Vec<(usize, u8)>> {
+    let mut best: Option<Candidate> = None;
+    for order in orders(windows.len()) {
+        let mut search = Search {
+            windows,
+            minutes,
+            stay_hours,
+            order: &order,
+            picked: Vec::new(),
+        };
+        search.extend(0, 0, 0, &mut best);
+    }
+    best.map(|candidate| candidate.visits)
+}
+
+/// A full plan with what it's ranked on
+struct Candidate {
+    visits: Vec<(usize, u8)>,
+    total_score: u32,
+    travel_minutes: u32,
+}
+
+/// Depth-first search over the start hours of one visiting order
+struct Search<'s> {
+    windows: &'s [Vec<(u8, u8)>],
+    minutes: &'s [Vec<u32>],
+    stay_hours: u8,
+    order: &'s [usize],
+    picked: Vec<(usize, u8)>,
+}
+
+impl Search<'_> {
+    /// Tries every start for the `depth`th stop of the order that's no
+    /// earlier than `ready` minutes past midnight
+    fn extend(&mut self, depth: usize, ready: u32, travel: u32, best: &mut Option<Candidate>) {
+        let Some(&stop) = self.order.get(depth) else {
+            let total_score = self
+                .picked
+                .iter()
+                .map(|&(stop, start)| {
+                    self.windows[stop]
+                        .iter()
+                        .find(|&&(hour, _)| hour == start)
+                        .map_or(0, |&(_, score)| score as u32)
+                })
+                .sum();
+            // Strictly better only, so ties keep the earlier order and times
+            let better = best.as_ref().is_none_or(|best| {
+                (total_score, std::cmp::Reverse(travel))
+                    > (best.total_score, std::cmp::Reverse(best.travel_minutes))
+            });
+            if better {
+                *best = Some(Candidate {
+                    visits: self.picked.clone(),
+                    total_score,
+                    travel_minutes: travel,
+                });
+            }
+            return;
+        };
+        let (ready, travel) = match self.picked.last() {
+            Some(&(previous, _)) => {
+                let leg = self.minutes[previous][stop];
+                (ready + leg, travel + leg)
+            }
+            None => (ready, travel),
+        };
+        for &(start, _) in &self.windows[stop] {
+            if start as u32 * 60 < ready {
+                continue;
+            }
+            self.picked.push((stop, start));
+            let end = (start + self.stay_hours) as u32 * 60;
+            self.extend(depth + 1, end, travel, best);
+            self.picked.pop();
+        }
+    }
+}
+
+/// Every order of `n` stops, starting with the order given
+fn orders(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![Vec::new()];
+    }
+    let mut all = Vec::new();
+    for first in 0..n {
+        for rest in orders(n - 1) {
+            let mut order = vec![first];
+            order.extend(rest.into_iter().map(|i| if i >= first { i + 1 } else { i }));
+            all.push(order);
+        }
+    }
+    all
+}
+
+/// Walking, cycling or driving from one beach to another
+fn travel_between(from: &Beach, to: &Beach) -> Travel {
+    if let Some(metres) = path_distance(from.id.as_str(), to.id.as_str()) {
+        let walk = metres.div_ceil(WALKING_METRES_PER_MINUTE);
+        if walk <= MAX_WALK_MINUTES {
+            return Travel {
+                mode: TravelMode::Walk,
+                minutes: walk,
+            };
+        }
+        let bike = metres.div_ceil(CYCLING_METRES_PER_MINUTE);
+        if bike <= MAX_BIKE_MINUTES {
+            return Travel {
+                mode: TravelMode::Bike,
+                minutes: bike,
+            };
+        }
+    }
+    let road = straight_line_metres(from, to) * ROAD_DETOUR;
+    Travel {
+        mode: TravelMode::Drive,
+        minutes: (road / DRIVING_METRES_PER_MINUTE as f64).ceil() as u32 + PARKING_MINUTES,
+    }
+}
+
+/// Distance between two beaches as the crow flies, in metres; flat-earth,
+/// which is plenty across one city
+fn straight_line_metres(from: &Beach, to: &Beach) -> f64 {
+    const EARTH_RADIUS_M: f64 = 6_371_000.0;
+    let mean_latitude = ((from.latitude + to.latitude) / 2.0).to_radians();
+    let east = (to.longitude - from.longitude).to_radians() * mean_latitude.cos();
+    let north = (to.latitude - from.latitude).to_radians();
+    EARTH_RADIUS_M * east.hypot(north)
+}
+
+/// The crawl as an itinerary, a line per visit with the travel between
+pub fn itinerary(crawl: &Crawl) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "Beach crawl for {}", crawl.date.format("%A, %B %-d"));
+    let mut travel_minutes = 0;
+    for visit in &crawl.visits {
+        let beach = &visit.conditions.beach;
+        if let Some(travel) = visit.travel {
+            travel_minutes += travel.minutes;
+            if travel.minutes > 0 {
+                let _ = writeln!(
+                    out,
+                    "{:>8}  then {} {} min to {}",
+                    "",
+                    travel.mode.verb(),
+                    travel.minutes,
+                    beach.name
+                );
+            }
+        }
+        let _ = writeln!(
+            out,
+            "{:>8}  {} at {} until {} (score {})",
+            format_hour(visit.start_hour),
+            visit.activity.label(),
+            beach.name,
+            format_hour(visit.end_hour % 24),
+            visit.score
+        );
+    }
+    let _ = writeln!(out, "Travel between stops: {} min", travel_minutes);
+    out
+}
+
+/// The crawl as an iCalendar file, one busy event per visit
+pub fn export_ics(crawl: &Crawl, now: DateTime<Utc>) -> String {
+    let mut out = String::new();
+    begin_calendar(&mut out, "Beach crawl");
+    for visit in &crawl.visits {
+        let beach = &visit.conditions.beach;
+        let at = |hour: u8| {
+            let local = crawl.date.and_hms_opt(0, 0, 0).unwrap() + Duration::hours(hour as i64);
+            beach.timezone.to_utc(local)
+        };
+        line(&mut out, "BEGIN:VEVENT");
+        line(
+            &mut out,
+            &format!(
+                "UID:crawl-{}@{}",
+                event_uid(crawl.date, &beach.id, visit.activity),
+                UID_DOMAIN
+            ),
+        );
+        line(&mut out, &format!("DTSTAMP:{}", ical_utc(now)));
+        line(
+            &mut out,
+            &format!("DTSTART:{}", ical_utc(at(visit.start_hour))),
+        );
+        line(&mut out, &format!("DTEND:{}", ical_utc(at(visit.end_hour))));
+        line(
+            &mut out,
+            &format!(
+                "SUMMARY:{}",
+                escape_text(&format!("{} at {}", visit.activity.label(), beach.name))
+            ),
+        );
+        line(&mut out, &format!("LOCATION:{}", escape_text(&beach.name)));
+        line(
+            &mut out,
+            &format!("GEO:{:.5};{:.5}", beach.latitude, beach.longitude),
+        );
+        let mut description = format!("Beach crawl stop, scoring {}/100.", visit.score);
+        if let Some(travel) = visit.travel.filter(|travel| travel.minutes > 0) {
+            let _ = write!(
+                description,
+                " Leave the last stop {} min early to {} here.",
+                travel.minutes,
+                travel.mode.verb().trim_end_matches(" about")
+            );
+        }
+        line(
+            &mut out,
+            &format!("DESCRIPTION:{}", escape_text(&description)),
+        );
+        line(&mut out, "STATUS:TENTATIVE");
+        line(&mut out, "END:VEVENT");
+    }
+    line(&mut out, "END:VCALENDAR");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::get_beach_by_id;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_travel_walks_then_bikes_then_drives() {
+        let beach = |id| get_beach_by_id(id).unwrap();
+        assert_eq!(
+            travel_between(beach("jericho"), beach("locarno")),
+            Travel {
+                mode: TravelMode::Walk,
+                minutes: 15
+            }
+        );
+        // 5.4 km along the path is over an hour's walk
+        assert_eq!(
+            travel_between(beach("kitsilano"), beach("spanish-banks-east")),
+            Travel {
+                mode: TravelMode::Bike,
+                minutes: 22
+            }
+        );
+        let drive = travel_between(beach("kitsilano"), beach("trout-lake"));
+        assert_eq!(drive.mode, TravelMode::Drive);
+        assert!((25..=45).contains(&drive.minutes), "{}", drive.minutes);
+    }
+
+    #[test]
+    fn test_stay_windows_need_consecutive_hours() {
+        let scores = [(10, 60), (11, 80), (12, 70), (14, 90), (15, 90)];
+        assert_eq!(stay_windows(&scores, 2), vec![(10, 70), (11, 75), (14, 90)]);
+        assert!(stay_windows(&scores, 4).is_empty());
+    }
+
+    #[test]
+    fn test_best_plan_orders_stops_by_their_windows_with_travel_between() {
+        // Stop 0 is best in the afternoon, stop 1 in the morning
+        let windows = vec![
+            vec![(10, 40), (11, 50), (14, 90)],
+            vec![(9, 85), (10, 80), (13, 30)],
+        ];
+        let close = vec![vec![0, 20], vec![20, 0]];
+        assert_eq!(best_plan(&windows, &close, 2), Some(vec![(1, 9), (0, 14)]));
+
+        // Stop 1 ends at 11, and 20 minutes on foot misses an 11:00 start
+        let windows = vec![vec![(11, 90), (12, 80)], vec![(9, 90)]];
+        assert_eq!(best_plan(&windows, &close, 2), Some(vec![(1, 9), (0, 12)]));
+
+        // Nothing fits if a stop has no window
+        assert_eq!(best_plan(&[vec![(9, 90)], vec![]], &close, 2), None);
+    }
+
+    fn conditions(id: &str) -> BeachConditions {
+        BeachConditions {
+            beach: get_beach_by_id(id).unwrap().clone(),
+            weather: None,
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    #[test]
+    fn test_crawl_prints_as_itinerary_and_calendar() {
+        let kitsilano = conditions("kitsilano");
+        let jericho = conditions("jericho");
+        let crawl = Crawl {
+            date: NaiveDate::from_ymd_opt(2026, 7, 15).unwrap(),
+            visits: vec![
+                Visit {
+                    conditions: &kitsilano,
+                    activity: Activity::Swimming,
+                    start_hour: 10,
+                    end_hour: 12,
+                    score: 82,
+                    travel: None,
+                },
+                Visit {
+                    conditions: &jericho,
+                    activity: Activity::Sailing,
+                    start_hour: 13,
+                    end_hour: 15,
+                    score: 75,
+                    travel: Some(Travel {
+                        mode: TravelMode::Walk,
+                        minutes: 24,
+                    }),
+                },
+            ],
+        };
+
+        let text = itinerary(&crawl);
+        assert!(text.starts_with("Beach crawl for Wednesday, July 15\n"));
+        assert!(text.contains("Swimming at Kitsilano Beach until "));
+        assert!(text.contains("then walk 24 min to Jericho Beach\n"));
+        assert!(text.ends_with("Travel between stops: 24 min\n"));
+
+        let now = Utc.with_ymd_and_hms(2026, 7, 15, 16, 0, 0).unwrap();
+        let ics = export_ics(&crawl, now);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("UID:crawl-20260715-jericho-sailing@vanbeach\r\n"));
+        // 10am in Vancouver in July
+        assert!(ics.contains("DTSTART:20260715T170000Z\r\n"));
+        assert!(ics.contains("Leave the last stop 24 min early to walk here."));
+    }
+
+    #[test]
+    fn test_orders_start_with_the_given_one() {
+        assert_eq!(orders(2), vec![vec![0, 1], vec![1, 0]]);
+        let three = orders(3);
+        assert_eq!(three.len(), 6);
+        assert_eq!(three[0], vec![0, 1, 2]);
+    }
+}
@@ -1,11 +1,21 @@
 //! Crowd estimation heuristics for Vancouver beaches
 //!
 //! This module provides functions to estimate beach crowd levels based on
-//! time of day, day of week, and season.
+//! time of day, day of week, and season. `predict` adds BC statutory
+//! holidays, which fill beaches like a weekend, and the weather, and reports
+//! every input it used; `predict_at` also counts the beach's community
+//! events from the config. `vanbeach crowd` exports its hourly predictions
+//! for research.
 
 #![allow(dead_code)]
 
-use chrono::Weekday;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::beach_events;
+use crate::cli::ExportFormat;
+use crate::data::{BeachConditions, Weather, WeatherCondition};
 
 /// Estimates the crowd level at a beach based on temporal factors.
 ///
@@ -43,6 +53,321 @@ pub fn estimate_crowd(month: u32, weekday: Weekday, hour: u32) -> f32 {
     crowd.clamp(0.0, 1.0)
 }
 
+/// A crowd prediction for one hour, with the inputs that went into it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CrowdPrediction {
+    /// Day predicted for, on the beach's clock
+    pub date: NaiveDate,
+    /// Hour of the day (0-23)
+    pub hour: u8,
+    /// Day of the week
+    pub weekday: Weekday,
+    /// Whether the day is a BC statutory holiday
+    pub holiday: bool,
+    /// Seasonal factor for the month
+    pub season_factor: f32,
+    /// Day-of-week factor, a weekend's on holidays
+    pub day_factor: f32,
+    /// Hour-of-day factor
+    pub hour_factor: f32,
+    /// Weather condition the hour was adjusted for, if known
+    pub condition: Option<WeatherCondition>,
+    /// Temperature in Celsius the hour was adjusted for, if known
+    pub temperature: Option<f64>,
+    /// Multiplier for the weather, 1.0 without any
+    pub weather_adjustment: f32,
+    /// Community events at the beach during the hour
+    pub events: Vec<String>,
+    /// Crowd the events add, 0.0 without any
+    pub event_crowd: f32,
+    /// Predicted crowd level, 0.0 (empty) to 1.0 (packed)
+    pub level: f32,
+}
+
+/// How busy a beach is predicted to be, for showing a crowd level at a glance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrowdBucket {
+    /// Under a quarter full
+    Quiet,
+    /// A quarter to half full
+    Moderate,
+    /// Half to three quarters full
+    Busy,
+    /// Three quarters full or more
+    Packed,
+}
+
+impl CrowdBucket {
+    /// The bucket a predicted crowd level (0.0-1.0) falls in
+    pub fn from_level(level: f32) -> Self {
+        match level {
+            l if l < 0.25 => CrowdBucket::Quiet,
+            l if l < 0.5 => CrowdBucket::Moderate,
+            l if l < 0.75 => CrowdBucket::Busy,
+            _ => CrowdBucket::Packed,
+        }
+    }
+
+    /// Every bucket, emptiest first
+    pub fn all() -> [CrowdBucket; 4] {
+        [
+            CrowdBucket::Quiet,
+            CrowdBucket::Moderate,
+            CrowdBucket::Busy,
+            CrowdBucket::Packed,
+        ]
+    }
+
+    /// Circle filling up with the crowd: ○ ◔ ◑ ●
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CrowdBucket::Quiet => "\u{25CB}",
+            CrowdBucket::Moderate => "\u{25D4}",
+            CrowdBucket::Busy => "\u{25D1}",
+            CrowdBucket::Packed => "\u{25CF}",
+        }
+    }
+
+    /// Returns a human-readable label for the bucket
+    pub fn label(&self) -> &'static str {
+        match self {
+            CrowdBucket::Quiet => "Quiet",
+            CrowdBucket::Moderate => "Moderate",
+            CrowdBucket::Busy => "Busy",
+            CrowdBucket::Packed => "Packed",
+        }
+    }
+}
+
+/// Predicts the crowd at `hour` on `date`, adjusted for holidays and, when
+/// given, the weather as (condition, temperature in Celsius)
+pub fn predict(
+    date: NaiveDate,
+    hour: u8,
+    weather: Option<(WeatherCondition, f64)>,
+) -> CrowdPrediction {
+    let holiday = is_bc_holiday(date);
+    let season_factor = calculate_season_factor(date.month());
+    let day_factor = if holiday {
+        calculate_day_factor(Weekday::Sun)
+    } else {
+        calculate_day_factor(date.weekday())
+    };
+    let hour_factor = calculate_hour_factor(hour as u32);
+    let weather_adjustment = weather
+        .map(|(condition, temperature)| calculate_weather_adjustment(condition, temperature))
+        .unwrap_or(1.0);
+
+    CrowdPrediction {
+        date,
+        hour,
+        weekday: date.weekday(),
+        holiday,
+        season_factor,
+        day_factor,
+        hour_factor,
+        condition: weather.map(|(condition, _)| condition),
+        temperature: weather.map(|(_, temperature)| temperature),
+        weather_adjustment,
+        events: Vec::new(),
+        event_crowd: 0.0,
+        level: (season_factor * day_factor * hour_factor * weather_adjustment).clamp(0.0, 1.0),
+    }
+}
+
+/// Predicts the crowd at a beach like `predict`, adding the beach's
+/// community events during the hour
+pub fn predict_at(
+    beach_id: &str,
+    date: NaiveDate,
+    hour: u8,
+    weather: Option<(WeatherCondition, f64)>,
+) -> CrowdPrediction {
+    let (event_crowd, events) = beach_events::crowd_during(beach_id, date, hour);
+    with_events(predict(date, hour, weather), event_crowd, events)
+}
+
+/// Adds the crowd events draw to a prediction
+fn with_events(
+    mut prediction: CrowdPrediction,
+    event_crowd: f32,
+    events: Vec<String>,
+) -> CrowdPrediction {
+    prediction.level = (prediction.level + event_crowd).clamp(0.0, 1.0);
+    prediction.event_crowd = event_crowd;
+    prediction.events = events;
+    prediction
+}
+
+/// Predicts every hour of `date` at a beach, counting its events
+///
+/// Hours are adjusted for the hourly forecast when `date` is today or
+/// tomorrow on the beach's clock (`today`); today's hours without a forecast
+/// use the current conditions. Other days get no weather adjustment.
+pub fn predict_day(
+    beach_id: &str,
+    date: NaiveDate,
+    today: NaiveDate,
+    weather: Option<&Weather>,
+) -> Vec<CrowdPrediction> {
+    let forecasts = weather.map(|w| {
+        if date == today {
+            w.hourly.as_slice()
+        } else if Some(date) == today.succ_opt() {
+            w.tomorrow.as_slice()
+        } else {
+            &[]
+        }
+    });
+    (0..24)
+        .map(|hour| {
+            let forecast = forecasts
+                .unwrap_or_default()
+                .iter()
+                .find(|f| f.hour == hour)
+                .map(|f| (f.condition, f.temperature));
+            let current = weather
+                .filter(|_| date == today)
+                .map(|w| (w.condition, w.temperature));
+            predict_at(beach_id, date, hour, forecast.or(current))
+        })
+        .collect()
+}
+
+/// Whether `date` is a BC statutory holiday
+///
+/// Covers New Year's Day, Family Day, Good Friday, Victoria Day, Canada Day,
+/// BC Day, Labour Day, the National Day for Truth and Reconciliation,
+/// Thanksgiving, Remembrance Day and Christmas Day. Canada Day falling on a
+/// Sunday is observed on the Monday.
+pub fn is_bc_holiday(date: NaiveDate) -> bool {
+    let year = date.year();
+    let day = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    let nth_monday = |month, n| NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Mon, n);
+
+    let canada_day = day(7, 1).map(|d| {
+        if d.weekday() == Weekday::Sun {
+            d + Duration::days(1)
+        } else {
+            d
+        }
+    });
+    // Victoria Day is the last Monday before May 25
+    let victoria_day =
+        day(5, 24).map(|d| d - Duration::days(d.weekday().num_days_from_monday() as i64));
+    let good_friday = easter_sunday(year).map(|d| d - Duration::days(2));
+
+    [
+        day(1, 1),
+        nth_monday(2, 3),
+        good_friday,
+        victoria_day,
+        canada_day,
+        nth_monday(8, 1),
+        nth_monday(9, 1),
+        day(9, 30),
+        nth_monday(10, 2),
+        day(11, 11),
+        day(12, 25),
+    ]
+    .contains(&Some(date))
+}
+
+/// Easter Sunday for a year (anonymous Gregorian algorithm)
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// Calculates the weather multiplier for crowd estimation.
+///
+/// Rain empties the beach and cloud thins it out; a hot, clear day draws
+/// extra people and a cold one keeps them away.
+fn calculate_weather_adjustment(condition: WeatherCondition, temperature: f64) -> f32 {
+    let condition_factor = match condition {
+        WeatherCondition::Rain | WeatherCondition::Showers | WeatherCondition::Thunderstorm => 0.4,
+        WeatherCondition::Snow => 0.2,
+        WeatherCondition::Cloudy | WeatherCondition::Fog => 0.8,
+        WeatherCondition::PartlyCloudy => 0.9,
+        WeatherCondition::Clear => 1.0,
+    };
+    let temperature_factor = match temperature {
+        t if t >= 25.0 => 1.2,
+        t if t >= 18.0 => 1.0,
+        t if t >= 12.0 => 0.8,
+        _ => 0.6,
+    };
+    condition_factor * temperature_factor
+}
+
+/// Writes hourly predictions as CSV, one row per hour with every input
+pub fn predictions_csv(beach_id: &str, predictions: &[CrowdPrediction]) -> String {
+    let mut out = String::from(
+        "beach,date,hour,weekday,holiday,season_factor,day_factor,hour_factor,weather,temperature,weather_adjustment,event,event_crowd,crowd\n",
+    );
+    for p in predictions {
+        let condition = p.condition.map(|c| c.label()).unwrap_or_default();
+        let temperature = p
+            .temperature
+            .map(|t| format!("{:.1}", t))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "{},{},{},{},{},{:.2},{:.2},{:.2},{},{},{:.2},{},{:.2},{:.3}\n",
+            beach_id,
+            p.date,
+            p.hour,
+            p.weekday,
+            p.holiday,
+            p.season_factor,
+            p.day_factor,
+            p.hour_factor,
+            condition,
+            temperature,
+            p.weather_adjustment,
+            p.events.join("; "),
+            p.event_crowd,
+            p.level
+        ));
+    }
+    out
+}
+
+/// Exports a beach's hourly predictions for `date` (today on the beach's
+/// clock if not given) in the requested format
+pub fn export_predictions(
+    conditions: &BeachConditions,
+    date: Option<NaiveDate>,
+    format: ExportFormat,
+) -> String {
+    let today = conditions.beach.timezone.now().date();
+    let predictions = predict_day(
+        &conditions.beach.id,
+        date.unwrap_or(today),
+        today,
+        conditions.weather.as_ref(),
+    );
+    match format {
+        ExportFormat::Csv => predictions_csv(&conditions.beach.id, &predictions),
+        ExportFormat::Json => format!(
+            "{:#}\n",
+            json!({ "beach": conditions.beach.id, "predictions": predictions })
+        ),
+    }
+}
+
 /// Calculates the seasonal factor for crowd estimation.
 ///
 /// Summer months (June-August) have the highest factor, with shoulder
@@ -86,6 +411,26 @@ fn calculate_hour_factor(hour: u32) -> f32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_crowd_buckets_split_the_range_in_quarters() {
+        let buckets: Vec<CrowdBucket> = [0.0, 0.24, 0.25, 0.5, 0.74, 0.75, 1.0]
+            .into_iter()
+            .map(CrowdBucket::from_level)
+            .collect();
+        assert_eq!(
+            buckets,
+            vec![
+                CrowdBucket::Quiet,
+                CrowdBucket::Quiet,
+                CrowdBucket::Moderate,
+                CrowdBucket::Busy,
+                CrowdBucket::Busy,
+                CrowdBucket::Packed,
+                CrowdBucket::Packed,
+            ]
+        );
+    }
+
     #[test]
     fn test_july_saturday_2pm_returns_high_crowd() {
         // July (month 7), Saturday, 2pm (hour 14)
@@ -268,6 +613,99 @@ mod tests {
         assert_eq!(calculate_day_factor(Weekday::Thu), 0.4);
     }
 
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_bc_holidays() {
+        // Good Friday 2026 is April 3; Easter is April 5
+        assert!(is_bc_holiday(date(4, 3)));
+        assert!(!is_bc_holiday(date(4, 5)));
+        // Victoria Day is May 18, BC Day August 3, Labour Day September 7
+        assert!(is_bc_holiday(date(5, 18)));
+        assert!(!is_bc_holiday(date(5, 25)));
+        assert!(is_bc_holiday(date(8, 3)));
+        assert!(is_bc_holiday(date(9, 7)));
+        assert!(is_bc_holiday(date(7, 1)));
+        assert!(!is_bc_holiday(date(7, 2)));
+        // Canada Day 2029 is a Sunday, observed on Monday
+        assert!(is_bc_holiday(NaiveDate::from_ymd_opt(2029, 7, 2).unwrap()));
+    }
+
+    #[test]
+    fn test_prediction_adjusts_for_holidays_and_weather() {
+        // BC Day, a Monday, is as busy as a weekend
+        let holiday = predict(date(8, 3), 14, None);
+        assert!(holiday.holiday);
+        assert_eq!(holiday.level, estimate_crowd(8, Weekday::Sat, 14));
+
+        let rain = predict(date(8, 1), 14, Some((WeatherCondition::Rain, 20.0)));
+        assert_eq!(rain.weather_adjustment, 0.4);
+        let heat = predict(date(8, 1), 14, Some((WeatherCondition::Clear, 28.0)));
+        assert!(heat.level > rain.level);
+        assert_eq!(heat.level, 1.0, "clamped");
+    }
+
+    #[test]
+    fn test_events_add_to_the_prediction() {
+        // A Tuesday morning in July is quiet until the swim club shows up
+        let quiet = predict(date(7, 21), 8, None);
+        let busy = with_events(quiet.clone(), 0.3, vec!["Swim club".to_string()]);
+        assert!((busy.level - (quiet.level + 0.3)).abs() < 1e-6);
+        assert_eq!(busy.events, vec!["Swim club".to_string()]);
+
+        let packed = with_events(predict(date(7, 18), 14, None), 0.5, Vec::new());
+        assert_eq!(packed.level, 1.0, "clamped");
+    }
+
+    #[test]
+    fn test_predictions_csv_lists_inputs_for_every_hour() {
+        let predictions: Vec<_> = (0..24)
+            .map(|hour| {
+                predict(
+                    date(7, 18),
+                    hour,
+                    (hour == 14).then_some((WeatherCondition::Cloudy, 19.0)),
+                )
+            })
+            .collect();
+        let csv = predictions_csv("kitsilano", &predictions);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 25);
+        assert!(lines[0].starts_with("beach,date,hour,weekday,holiday"));
+        assert_eq!(
+            lines[15],
+            "kitsilano,2026-07-18,14,Sat,false,1.00,1.00,1.00,Cloudy,19.0,0.80,,0.00,0.800"
+        );
+        assert!(lines[1].ends_with(",,,1.00,,0.00,0.100"));
+    }
+
+    #[test]
+    fn test_json_export_follows_todays_forecast() {
+        let beach = crate::data::all_beaches()[0].clone();
+        let conditions = BeachConditions {
+            beach,
+            weather: None,
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        };
+
+        let json = export_predictions(&conditions, Some(date(8, 3)), ExportFormat::Json);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["beach"], "kitsilano");
+        let predictions = value["predictions"].as_array().unwrap();
+        assert_eq!(predictions.len(), 24);
+        assert_eq!(predictions[14]["holiday"], true);
+        assert_eq!(predictions[14]["weather_adjustment"], 1.0);
+        assert!(predictions[14]["condition"].is_null());
+    }
+
     #[test]
     fn test_hour_factor_values() {
         // Peak afternoon
@@ -0,0 +1,187 @@
+//! Air quality forecasts from Open-Meteo
+//!
+//! Wildfire smoke can settle over Vancouver for days in late summer, and
+//! it matters more to a run along the seawall than to an afternoon on the
+//! sand. The hourly US Air Quality Index is asked once for the middle of
+//! the shoreline, since smoke and haze are regional rather than beach by
+//! beach.
+
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, NaiveDateTime, Timelike};
+use reqwest::Client;
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::rate_limit::RateLimiter;
+use super::recording;
+
+/// Base URL for the Open-Meteo air quality API
+const AIR_QUALITY_BASE_URL: &str = "https://air-quality-api.open-meteo.com/v1/air-quality";
+
+/// Errors that can occur when fetching air quality
+#[derive(Debug, Error)]
+pub enum AirQualityError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The response isn't the expected JSON
+    #[error("Failed to parse air quality: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    /// The client-side request budget for the API's host is used up
+    #[error("Request budget for {0} used up; try again shortly")]
+    Throttled(String),
+}
+
+/// Hourly US Air Quality Index, by local date and hour
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AirQuality {
+    aqi: BTreeMap<(NaiveDate, u8), u16>,
+}
+
+impl AirQuality {
+    /// The US AQI forecast for an hour, if there is one
+    pub fn aqi_at(&self, date: NaiveDate, hour: u8) -> Option<u16> {
+        self.aqi.get(&(date, hour)).copied()
+    }
+
+    /// Sets the AQI for an hour
+    #[allow(dead_code)]
+    pub fn with_aqi(mut self, date: NaiveDate, hour: u8, aqi: u16) -> Self {
+        self.aqi.insert((date, hour), aqi);
+        self
+    }
+}
+
+/// Short description of a US AQI value, e.g. "moderate"
+pub fn aqi_category(aqi: u16) -> &'static str {
+    match aqi {
+        0..=50 => "good",
+        51..=100 => "moderate",
+        101..=150 => "unhealthy for sensitive groups",
+        151..=200 => "unhealthy",
+        _ => "very unhealthy",
+    }
+}
+
+/// Client for the Open-Meteo air quality API
+#[derive(Debug, Clone)]
+pub struct AirQualityClient {
+    client: Client,
+    base_url: String,
+    /// Request budgets shared with the other clients
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl Default for AirQualityClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AirQualityClient {
+    /// Creates a client for the public API
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+            base_url: AIR_QUALITY_BASE_URL.to_string(),
+            rate_limiter: None,
+        }
+    }
+
+    /// Checks every request against the given shared rate limiter
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Points the client at another server
+    #[allow(dead_code)]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Fetches today's and tomorrow's hourly US AQI at a point, in the given
+    /// timezone's local time
+    pub async fn fetch_air_quality(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        timezone: &str,
+    ) -> Result<AirQuality, AirQualityError> {
+        let url = format!(
+            "{}?latitude={:.4}&longitude={:.4}&hourly=us_aqi&forecast_days=2&timezone={}",
+            self.base_url, latitude, longitude, timezone
+        );
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire(&url)
+                .map_err(AirQualityError::Throttled)?;
+        }
+        let text = recording::send(self.client.get(&url))
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        parse_air_quality(&text)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AirQualityResponse {
+    hourly: HourlyAirQuality,
+}
+
+#[derive(Debug, Deserialize)]
+struct HourlyAirQuality {
+    time: Vec<String>,
+    us_aqi: Vec<Option<f64>>,
+}
+
+/// Reads the hourly US AQI, skipping hours the model leaves empty
+fn parse_air_quality(text: &str) -> Result<AirQuality, AirQualityError> {
+    let response: AirQualityResponse = serde_json::from_str(text)?;
+    let mut air_quality = AirQuality::default();
+    for (time, aqi) in response.hourly.time.iter().zip(response.hourly.us_aqi) {
+        let (Ok(time), Some(aqi)) = (NaiveDateTime::parse_from_str(time, "%Y-%m-%dT%H:%M"), aqi)
+        else {
+            continue;
+        };
+        air_quality.aqi.insert(
+            (time.date(), time.hour() as u8),
+            aqi.round().max(0.0) as u16,
+        );
+    }
+    Ok(air_quality)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_air_quality() {
+        let air_quality = parse_air_quality(
+            r#"{
+                "latitude": 49.28,
+                "longitude": -123.17,
+                "hourly": {
+                    "time": ["2026-08-20T13:00", "2026-08-20T14:00", "2026-08-20T15:00"],
+                    "us_aqi": [42.0, 158.4, null]
+                }
+            }"#,
+        )
+        .unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 8, 20).unwrap();
+
+        assert_eq!(air_quality.aqi_at(date, 13), Some(42));
+        assert_eq!(air_quality.aqi_at(date, 14), Some(158));
+        assert_eq!(air_quality.aqi_at(date, 15), None);
+        assert_eq!(aqi_category(42), "good");
+        assert_eq!(aqi_category(158), "unhealthy");
+        assert!(parse_air_quality(r#"{"error": true}"#).is_err());
+    }
+}
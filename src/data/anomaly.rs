@@ -0,0 +1,282 @@
+//! Sanity checks on parsed values
+//!
+//! A provider now and then sends a reading no beach could have: 60°C air,
+//! negative wind, a 9m tide. Rather than render it, the app quarantines the
+//! whole response, keeps showing the last good data, and appends one JSON
+//! line per bad value to `anomalies.jsonl` in the cache directory so the
+//! response can be looked into later.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::tides::TIDES_SOURCE;
+use super::{HourlyForecast, TideInfo, Weather};
+
+/// Name of the anomaly log in the cache directory
+pub const ANOMALY_LOG_FILE_NAME: &str = "anomalies.jsonl";
+
+/// Air temperatures (and feels-like) a beach here can plausibly see, in °C
+pub const TEMPERATURE_RANGE_C: (f64, f64) = (-30.0, 45.0);
+
+/// Highest plausible tide, in meters; the biggest king tides reach about 5m
+pub const MAX_TIDE_HEIGHT_M: f64 = 6.0;
+
+/// A parsed value outside what's plausible
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Anomaly {
+    /// When the value was caught
+    pub detected_at: DateTime<Utc>,
+    /// Data source that sent it, e.g. "Open-Meteo"
+    pub source: String,
+    /// Beach the response was for, if it was for one beach
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beach: Option<String>,
+    /// Field holding the value, e.g. "temperature" or "next_high.height"
+    pub field: String,
+    /// Forecast hour the value was for, if it came from an hourly forecast
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hour: Option<u8>,
+    /// The value as parsed
+    pub value: f64,
+    /// What a plausible value looks like, e.g. "-30 to 45"
+    pub expected: String,
+}
+
+impl Anomaly {
+    /// Short description for the debug view, e.g. "temperature at 14:00: 61.0
+    /// (expected -30 to 45)"
+    pub fn summary(&self) -> String {
+        let field = match self.hour {
+            Some(hour) => format!("{} at {:02}:00", self.field, hour),
+            None => self.field.clone(),
+        };
+        format!("{}: {:.1} (expected {})", field, self.value, self.expected)
+    }
+}
+
+/// Collects the values of one response that fail their checks
+struct Checker {
+    detected_at: DateTime<Utc>,
+    source: String,
+    beach: Option<String>,
+    anomalies: Vec<Anomaly>,
+}
+
+impl Checker {
+    fn new(source: &str, beach: Option<&str>) -> Self {
+        Self {
+            detected_at: Utc::now(),
+            source: source.to_string(),
+            beach: beach.map(str::to_string),
+            anomalies: Vec::new(),
+        }
+    }
+
+    /// Notes `value` unless `plausible` holds; NaN never does
+    fn check(
+        &mut self,
+        field: &str,
+        hour: Option<u8>,
+        value: f64,
+        plausible: bool,
+        expected: &str,
+    ) {
+        if !plausible || value.is_nan() {
+            self.anomalies.push(Anomaly {
+                detected_at: self.detected_at,
+                source: self.source.clone(),
+                beach: self.beach.clone(),
+                field: field.to_string(),
+                hour,
+                value,
+                expected: expected.to_string(),
+            });
+        }
+    }
+
+    fn temperature(&mut self, field: &str, hour: Option<u8>, value: f64) {
+        let (min, max) = TEMPERATURE_RANGE_C;
+        let expected = format!("{} to {}", min, max);
+        self.check(field, hour, value, (min..=max).contains(&value), &expected);
+    }
+
+    fn wind(&mut self, field: &str, hour: Option<u8>, value: f64) {
+        self.check(field, hour, value, value >= 0.0, "0 or more");
+    }
+
+    fn tide(&mut self, field: &str, value: f64) {
+        let expected = format!("at most {}", MAX_TIDE_HEIGHT_M);
+        self.check(field, None, value, value <= MAX_TIDE_HEIGHT_M, &expected);
+    }
+
+    fn hourly(&mut self, hourly: &[HourlyForecast]) {
+        for forecast in hourly {
+            let hour = Some(forecast.hour);
+            self.temperature("temperature", hour, forecast.temperature);
+            self.temperature("feels_like", hour, forecast.feels_like);
+            self.wind("wind", hour, forecast.wind);
+            if let Some(gusts) = forecast.wind_gusts {
+                self.wind("wind_gusts", hour, gusts);
+            }
+        }
+    }
+}
+
+/// Checks a beach's weather: temperatures within `TEMPERATURE_RANGE_C` and
+/// no negative wind, now and in every forecast hour
+pub fn check_weather(weather: &Weather, beach: &str) -> Vec<Anomaly> {
+    let mut checker = Checker::new(weather.source.name(), Some(beach));
+    checker.temperature("temperature", None, weather.temperature);
+    checker.temperature("feels_like", None, weather.feels_like);
+    checker.wind("wind", None, weather.wind);
+    checker.hourly(&weather.hourly);
+    checker.hourly(&weather.tomorrow);
+    checker.anomalies
+}
+
+/// Checks the tides: no height above `MAX_TIDE_HEIGHT_M`
+pub fn check_tides(tides: &TideInfo) -> Vec<Anomaly> {
+    let mut checker = Checker::new(TIDES_SOURCE, None);
+    checker.tide("current_height", tides.current_height);
+    if let Some(high) = &tides.next_high {
+        checker.tide("next_high.height", high.height);
+    }
+    if let Some(low) = &tides.next_low {
+        checker.tide("next_low.height", low.height);
+    }
+    checker.anomalies
+}
+
+/// Appends anomalies to the log in `dir`, one JSON object per line
+pub fn append_log(dir: &Path, anomalies: &[Anomaly]) -> io::Result<()> {
+    if anomalies.is_empty() {
+        return Ok(());
+    }
+    fs::create_dir_all(dir)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(ANOMALY_LOG_FILE_NAME))?;
+    let mut lines = String::new();
+    for anomaly in anomalies {
+        lines.push_str(&serde_json::to_string(anomaly).map_err(io::Error::other)?);
+        lines.push('\n');
+    }
+    file.write_all(lines.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{DataQuality, TideEvent, TideState, WeatherCondition, WeatherProvider};
+    use chrono::{Local, NaiveTime};
+
+    fn weather(temperature: f64, wind: f64) -> Weather {
+        Weather {
+            temperature,
+            feels_like: temperature,
+            condition: WeatherCondition::Clear,
+            weather_code: None,
+            humidity: 50,
+            wind,
+            wind_direction: None,
+            uv: 5.0,
+            sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+            sunset: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+            fetched_at: Utc::now(),
+            hourly: vec![HourlyForecast {
+                hour: 14,
+                temperature: 22.0,
+                feels_like: 22.0,
+                condition: WeatherCondition::Clear,
+                wind: -3.0,
+                wind_direction: "W".to_string(),
+                uv: 6.0,
+                precipitation_chance: 0,
+                dew_point: None,
+                wind_gusts: None,
+                cloud_cover: None,
+                data_quality: DataQuality::Reported,
+            }],
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+        }
+    }
+
+    fn tides(current_height: f64, high: f64) -> TideInfo {
+        TideInfo {
+            current_height,
+            tide_state: TideState::Rising,
+            next_high: Some(TideEvent {
+                time: Local::now(),
+                height: high,
+            }),
+            next_low: None,
+            fetched_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_weather_is_caught_field_by_field() {
+        let anomalies = check_weather(&weather(61.0, 10.0), "kitsilano");
+        let fields: Vec<(&str, Option<u8>)> = anomalies
+            .iter()
+            .map(|a| (a.field.as_str(), a.hour))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("temperature", None),
+                ("feels_like", None),
+                ("wind", Some(14))
+            ]
+        );
+        assert_eq!(anomalies[0].source, "Open-Meteo");
+        assert_eq!(anomalies[0].beach.as_deref(), Some("kitsilano"));
+        assert_eq!(
+            anomalies[2].summary(),
+            "wind at 14:00: -3.0 (expected 0 or more)"
+        );
+
+        let mut plausible = weather(-30.0, 0.0);
+        plausible.hourly.clear();
+        assert!(check_weather(&plausible, "kitsilano").is_empty());
+
+        let mut missing = weather(f64::NAN, 0.0);
+        missing.hourly.clear();
+        assert_eq!(check_weather(&missing, "kitsilano").len(), 2);
+    }
+
+    #[test]
+    fn test_tides_above_six_meters_are_caught() {
+        assert!(check_tides(&tides(2.5, 4.8)).is_empty());
+
+        let anomalies = check_tides(&tides(2.5, 9.1));
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].field, "next_high.height");
+        assert_eq!(anomalies[0].source, TIDES_SOURCE);
+        assert_eq!(anomalies[0].beach, None);
+    }
+
+    #[test]
+    fn test_log_appends_one_json_line_per_anomaly() {
+        let dir = tempfile::tempdir().unwrap();
+        let anomalies = check_weather(&weather(61.0, 10.0), "kitsilano");
+        append_log(dir.path(), &anomalies).unwrap();
+        append_log(dir.path(), &anomalies[..1]).unwrap();
+
+        let log = fs::read_to_string(dir.path().join(ANOMALY_LOG_FILE_NAME)).unwrap();
+        let logged: Vec<Anomaly> = log
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(logged.len(), 4);
+        assert_eq!(logged[0], anomalies[0]);
+        assert_eq!(logged[3], anomalies[0]);
+    }
+}
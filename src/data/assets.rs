@@ -0,0 +1,396 @@
+//! Built-in data files and their overrides
+//!
+//! The beach list, the WMO weather code descriptions and the theme colors
+//! are JSON files compiled into the binary, so a release is a single file
+//! that runs without anything installed next to it. Each can be changed
+//! without a rebuild: a file of the same name in the system config directory
+//! (`/etc/vanbeach/assets`) is layered over the built-in one, and one in the
+//! user config directory (`~/.config/vanbeach/assets` on Linux) over that.
+//! Layers merge object by object, so an override only needs the keys it
+//! changes; anything else, a list included, replaces what's under it.
+//! `vanbeach assets dump` writes the built-in files out to start from.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use directories::ProjectDirs;
+use ratatui::style::Color;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use thiserror::Error;
+
+use super::beach::BeachDefinition;
+
+/// System-wide override directory, under the built-in files
+pub const SYSTEM_ASSETS_DIR: &str = "/etc/vanbeach/assets";
+
+/// A data file compiled into the binary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Asset {
+    /// Beach names, coordinates and water quality stations
+    Beaches,
+    /// Ambient accents and palette colors
+    Themes,
+    /// WMO weather code descriptions
+    Wmo,
+}
+
+impl Asset {
+    /// Every asset, in the order they're loaded and dumped
+    pub fn all() -> [Asset; 3] {
+        [Asset::Beaches, Asset::Themes, Asset::Wmo]
+    }
+
+    /// Name of the asset's file, built in and in the override directories
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Asset::Beaches => "beaches.json",
+            Asset::Themes => "themes.json",
+            Asset::Wmo => "wmo.json",
+        }
+    }
+
+    /// The built-in file
+    pub fn embedded(self) -> &'static str {
+        match self {
+            Asset::Beaches => include_str!("../../assets/beaches.json"),
+            Asset::Themes => include_str!("../../assets/themes.json"),
+            Asset::Wmo => include_str!("../../assets/wmo.json"),
+        }
+    }
+}
+
+/// Errors that can occur when loading or dumping assets
+#[derive(Debug, Error)]
+pub enum AssetError {
+    /// An override file couldn't be read
+    #[error("Failed to read {path}: {source}")]
+    Read { path: PathBuf, source: io::Error },
+
+    /// An override file isn't JSON, or the merged asset isn't what the app
+    /// expects (`path` is the last file layered on)
+    #[error("Failed to parse {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+
+    /// The merged beach list is empty
+    #[error("{0} leaves no beaches")]
+    NoBeaches(PathBuf),
+
+    /// A dumped file would overwrite one already there
+    #[error("{0} already exists; pass --force to overwrite it")]
+    Exists(PathBuf),
+
+    /// A dumped file couldn't be written
+    #[error("Failed to write {path}: {source}")]
+    Write { path: PathBuf, source: io::Error },
+}
+
+/// The beach list asset
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BeachesAsset {
+    pub beaches: Vec<BeachDefinition>,
+}
+
+/// The theme colors asset
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ThemesAsset {
+    pub ambient: AmbientColors,
+    /// Color replacements by palette name (e.g. "red-green"), from the color
+    /// a screen draws in to the one the palette shows
+    pub palettes: HashMap<String, HashMap<AssetColor, AssetColor>>,
+}
+
+/// Accents for `--ambient`
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct AmbientColors {
+    /// While it's raining at the beach
+    pub rain: AssetColor,
+    /// Around sunset
+    pub sunset: AssetColor,
+}
+
+/// A color written as a name ("light-green") or hex ("#0072b2")
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AssetColor(pub Color);
+
+impl<'de> Deserialize<'de> for AssetColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse()
+            .map(AssetColor)
+            .map_err(|_| serde::de::Error::custom(format!("unknown color \"{}\"", text)))
+    }
+}
+
+/// The WMO code descriptions asset
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WmoAsset {
+    /// Description by code
+    pub descriptions: HashMap<u8, String>,
+}
+
+/// Every asset, merged and checked
+#[derive(Debug, Clone, PartialEq)]
+struct Assets {
+    beaches: BeachesAsset,
+    themes: ThemesAsset,
+    wmo: WmoAsset,
+}
+
+impl Assets {
+    /// Layers the files in `dirs`, lowest first, over the built-in assets
+    fn load(dirs: &[PathBuf]) -> Result<Self, AssetError> {
+        let beaches: BeachesAsset = load(Asset::Beaches, dirs)?;
+        if beaches.beaches.is_empty() {
+            return Err(AssetError::NoBeaches(last_source(Asset::Beaches, dirs)));
+        }
+        Ok(Self {
+            beaches,
+            themes: load(Asset::Themes, dirs)?,
+            wmo: load(Asset::Wmo, dirs)?,
+        })
+    }
+}
+
+/// Assets in use, with the overrides installed at startup
+static ASSETS: OnceLock<Assets> = OnceLock::new();
+
+/// Override directories, lowest first: the system one, then the user's
+pub fn search_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![PathBuf::from(SYSTEM_ASSETS_DIR)];
+    dirs.extend(user_dir());
+    dirs
+}
+
+/// The user's override directory, if a home directory is available
+pub fn user_dir() -> Option<PathBuf> {
+    let project_dirs = ProjectDirs::from("", "", "vanbeach")?;
+    Some(project_dirs.config_dir().join("assets"))
+}
+
+/// Loads the overrides in `dirs` over the built-in assets
+///
+/// Must be called before an asset is first used. On an error the built-in
+/// assets stay in use. Returns `Ok(false)` if assets were already loaded.
+pub fn install(dirs: &[PathBuf]) -> Result<bool, AssetError> {
+    let assets = Assets::load(dirs)?;
+    Ok(ASSETS.set(assets).is_ok())
+}
+
+/// Assets in use, the built-in ones unless overrides were installed
+fn assets() -> &'static Assets {
+    ASSETS.get_or_init(|| Assets::load(&[]).expect("built-in assets are valid"))
+}
+
+/// The beach list
+pub fn beaches() -> &'static BeachesAsset {
+    &assets().beaches
+}
+
+/// The theme colors
+pub fn themes() -> &'static ThemesAsset {
+    &assets().themes
+}
+
+/// The WMO code descriptions
+pub fn wmo() -> &'static WmoAsset {
+    &assets().wmo
+}
+
+/// Merges the files for `asset` in `dirs` over the built-in one and reads
+/// the result
+fn load<T: DeserializeOwned>(asset: Asset, dirs: &[PathBuf]) -> Result<T, AssetError> {
+    let mut merged: Value =
+        serde_json::from_str(asset.embedded()).map_err(|source| AssetError::Parse {
+            path: PathBuf::from(asset.file_name()),
+            source,
+        })?;
+    for dir in dirs {
+        let path = dir.join(asset.file_name());
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(source) => return Err(AssetError::Read { path, source }),
+        };
+        let layer = serde_json::from_str(&text).map_err(|source| AssetError::Parse {
+            path: path.clone(),
+            source,
+        })?;
+        merge(&mut merged, layer);
+    }
+    serde_json::from_value(merged).map_err(|source| AssetError::Parse {
+        path: last_source(asset, dirs),
+        source,
+    })
+}
+
+/// The last file layered onto `asset`, or its built-in name without overrides
+fn last_source(asset: Asset, dirs: &[PathBuf]) -> PathBuf {
+    dirs.iter()
+        .rev()
+        .map(|dir| dir.join(asset.file_name()))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| PathBuf::from(asset.file_name()))
+}
+
+/// Layers `layer` over `base`: objects merge key by key, anything else
+/// replaces what's there
+fn merge(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Object(base), Value::Object(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+/// Writes the built-in assets into `dir`, creating it if needed, and returns
+/// the files written
+///
+/// Nothing is written if a file is already there, unless `force` is set.
+pub fn dump(dir: &Path, force: bool) -> Result<Vec<PathBuf>, AssetError> {
+    let paths: Vec<(Asset, PathBuf)> = Asset::all()
+        .into_iter()
+        .map(|asset| (asset, dir.join(asset.file_name())))
+        .collect();
+    if !force {
+        if let Some((_, path)) = paths.iter().find(|(_, path)| path.exists()) {
+            return Err(AssetError::Exists(path.clone()));
+        }
+    }
+    fs::create_dir_all(dir).map_err(|source| AssetError::Write {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+    for (asset, path) in &paths {
+        fs::write(path, asset.embedded()).map_err(|source| AssetError::Write {
+            path: path.clone(),
+            source,
+        })?;
+    }
+    Ok(paths.into_iter().map(|(_, path)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_builtin_assets_load() {
+        let assets = Assets::load(&[]).unwrap();
+        assert_eq!(assets.beaches.beaches.len(), 12);
+        assert_eq!(assets.wmo.descriptions.len(), 100);
+        assert_eq!(
+            assets.themes.palettes["red-green"][&AssetColor(Color::Green)],
+            AssetColor(Color::Rgb(0, 114, 178))
+        );
+    }
+
+    #[test]
+    fn test_user_overrides_layer_over_system_and_builtin() {
+        let system = TempDir::new().unwrap();
+        let user = TempDir::new().unwrap();
+        fs::write(
+            system.path().join("wmo.json"),
+            r#"{"descriptions": {"0": "Sunny", "1": "Mostly sunny"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            user.path().join("wmo.json"),
+            r#"{"descriptions": {"0": "Bluebird day"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            user.path().join("themes.json"),
+            r#"{"ambient": {"rain": "dark-gray"}}"#,
+        )
+        .unwrap();
+
+        let dirs = [system.path().to_path_buf(), user.path().to_path_buf()];
+        let assets = Assets::load(&dirs).unwrap();
+        let descriptions = &assets.wmo.descriptions;
+        assert_eq!(descriptions[&0], "Bluebird day");
+        assert_eq!(descriptions[&1], "Mostly sunny");
+        assert_eq!(descriptions[&2], "Partly cloudy");
+        assert_eq!(assets.themes.ambient.rain, AssetColor(Color::DarkGray));
+        assert_eq!(
+            assets.themes.ambient.sunset,
+            AssetColor(Color::Rgb(230, 170, 70))
+        );
+        // Lists replace the one under them
+        assert_eq!(assets.beaches.beaches.len(), 12);
+    }
+
+    #[test]
+    fn test_broken_overrides_name_the_file() {
+        let dir = TempDir::new().unwrap();
+        let dirs = [dir.path().to_path_buf()];
+
+        fs::write(dir.path().join("themes.json"), "{").unwrap();
+        assert!(matches!(
+            Assets::load(&dirs),
+            Err(AssetError::Parse { path, .. }) if path == dir.path().join("themes.json")
+        ));
+
+        fs::write(
+            dir.path().join("themes.json"),
+            r#"{"ambient": {"rain": "plaid"}}"#,
+        )
+        .unwrap();
+        let error = Assets::load(&dirs).unwrap_err();
+        assert!(
+            error.to_string().contains("unknown color \"plaid\""),
+            "{}",
+            error
+        );
+
+        fs::remove_file(dir.path().join("themes.json")).unwrap();
+        fs::write(dir.path().join("beaches.json"), r#"{"beaches": []}"#).unwrap();
+        assert!(matches!(
+            Assets::load(&dirs),
+            Err(AssetError::NoBeaches(path)) if path == dir.path().join("beaches.json")
+        ));
+    }
+
+    #[test]
+    fn test_dump_writes_builtin_files_without_overwriting() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("assets");
+
+        let written = dump(&dir, false).unwrap();
+        assert_eq!(written.len(), 3);
+        for asset in Asset::all() {
+            let text = fs::read_to_string(dir.join(asset.file_name())).unwrap();
+            assert_eq!(text, asset.embedded());
+        }
+        // Dumped files load back as the built-in assets
+        assert_eq!(
+            Assets::load(std::slice::from_ref(&dir)).unwrap(),
+            Assets::load(&[]).unwrap()
+        );
+
+        fs::write(dir.join("wmo.json"), "{}").unwrap();
+        assert!(matches!(dump(&dir, false), Err(AssetError::Exists(_))));
+        assert_eq!(fs::read_to_string(dir.join("wmo.json")).unwrap(), "{}");
+        dump(&dir, true).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("wmo.json")).unwrap(),
+            Asset::Wmo.embedded()
+        );
+    }
+}
@@ -1,101 +1,509 @@
 //! Static beach data for Vancouver beaches
 //!
-//! This module contains the static list of all Vancouver beaches with their
-//! geographic coordinates and water quality monitoring station IDs.
+//! This module builds the list of all Vancouver beaches, with their
+//! geographic coordinates and water quality monitoring station IDs from the
+//! beaches asset (see `assets`), and what's known about each one.
 
-use super::Beach;
+use std::sync::OnceLock;
 
-/// Static array of all Vancouver beaches
+use chrono::{Datelike, NaiveDate};
+
+use serde::Deserialize;
+
+use super::assets;
+use super::beach_registry::BeachRecord;
+use super::{
+    Beach, BeachId, Connection, DogAccess, DogRule, Hazard, HazardKind, ShadeObstruction,
+    ShadeProfile, WaterBody, WindExposure, WindShelter,
+};
+
+/// A beach as the built-in beach list (or its override) defines it
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BeachDefinition {
+    pub id: BeachId,
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Water quality station ID, matching Vancouver Open Data naming
+    #[serde(default)]
+    pub water_quality_id: Option<String>,
+}
+
+/// The beach list from `assets/beaches.json`, with any overrides
 ///
-/// Contains 12 beaches from the Vancouver area with accurate coordinates
+/// Ships with 12 beaches from the Vancouver area with accurate coordinates
 /// and water quality monitoring station IDs matching Vancouver Open Data naming.
-pub static BEACHES: [Beach; 12] = [
-    Beach {
-        id: "kitsilano",
-        name: "Kitsilano Beach",
-        latitude: 49.2743,
-        longitude: -123.1544,
-        water_quality_id: Some("kitsilano-beach"),
-    },
-    Beach {
-        id: "english-bay",
-        name: "English Bay Beach",
-        latitude: 49.2863,
-        longitude: -123.1432,
-        water_quality_id: Some("english-bay"),
-    },
-    Beach {
-        id: "jericho",
-        name: "Jericho Beach",
-        latitude: 49.2726,
-        longitude: -123.1967,
-        water_quality_id: Some("jericho-beach"),
-    },
-    Beach {
-        id: "spanish-banks-east",
-        name: "Spanish Banks East",
-        latitude: 49.2756,
-        longitude: -123.2089,
-        water_quality_id: Some("spanish-banks-east"),
-    },
-    Beach {
-        id: "spanish-banks-west",
-        name: "Spanish Banks West",
-        latitude: 49.2769,
-        longitude: -123.2244,
-        water_quality_id: Some("spanish-banks-west"),
-    },
-    Beach {
-        id: "locarno",
-        name: "Locarno Beach",
-        latitude: 49.2768,
-        longitude: -123.2167,
-        water_quality_id: Some("locarno-beach"),
-    },
-    Beach {
-        id: "wreck",
-        name: "Wreck Beach",
-        latitude: 49.2621,
-        longitude: -123.2617,
-        water_quality_id: Some("wreck-beach"),
-    },
-    Beach {
-        id: "second",
-        name: "Second Beach",
-        latitude: 49.2912,
-        longitude: -123.1513,
-        water_quality_id: Some("second-beach"),
-    },
-    Beach {
-        id: "third",
-        name: "Third Beach",
-        latitude: 49.2989,
-        longitude: -123.1588,
-        water_quality_id: Some("third-beach"),
-    },
-    Beach {
-        id: "sunset",
-        name: "Sunset Beach",
-        latitude: 49.2799,
-        longitude: -123.1339,
-        water_quality_id: Some("sunset-beach"),
-    },
-    Beach {
-        id: "trout-lake",
-        name: "Trout Lake Beach",
-        latitude: 49.2555,
-        longitude: -123.0644,
-        water_quality_id: Some("trout-lake"),
-    },
-    Beach {
-        id: "new-brighton",
-        name: "New Brighton Beach",
-        latitude: 49.2930,
-        longitude: -123.0365,
-        water_quality_id: Some("new-brighton"),
-    },
+fn beach_definitions() -> &'static [BeachDefinition] {
+    &assets::beaches().beaches
+}
+
+/// Beaches the Park Board staffs with lifeguards from Victoria Day to Labour Day
+///
+/// Wreck Beach and New Brighton are unguarded.
+const LIFEGUARDED_BEACHES: [&str; 10] = [
+    "kitsilano",
+    "english-bay",
+    "jericho",
+    "spanish-banks-east",
+    "spanish-banks-west",
+    "locarno",
+    "second",
+    "third",
+    "sunset",
+    "trout-lake",
+];
+
+/// Seawall and path connections between neighbouring beaches:
+/// (beach id, beach id, metres along the path, path name)
+///
+/// Routes go both ways. Distances follow the path, not a straight line.
+const CONNECTIONS: [(&str, &str, u32, &str); 7] = [
+    ("kitsilano", "jericho", 3300, "Point Grey Road greenway"),
+    ("jericho", "locarno", 1200, "Spanish Banks seaside path"),
+    (
+        "locarno",
+        "spanish-banks-east",
+        900,
+        "Spanish Banks seaside path",
+    ),
+    (
+        "spanish-banks-east",
+        "spanish-banks-west",
+        1300,
+        "Spanish Banks seaside path",
+    ),
+    ("sunset", "english-bay", 900, "Seawall"),
+    ("english-bay", "second", 1000, "Seawall"),
+    ("second", "third", 1300, "Stanley Park Seawall"),
+];
+
+/// Known hazards at each beach: (beach id, kind, description)
+///
+/// Listed in the order they're shown. Trout Lake has none worth listing.
+const HAZARDS: [(&str, HazardKind, &str); 19] = [
+    (
+        "kitsilano",
+        HazardKind::Currents,
+        "Outflow currents from False Creek off Kitsilano Point",
+    ),
+    (
+        "kitsilano",
+        HazardKind::Logs,
+        "Submerged logs drift in after storms",
+    ),
+    (
+        "english-bay",
+        HazardKind::Logs,
+        "Submerged logs drift in after storms",
+    ),
+    (
+        "english-bay",
+        HazardKind::Boats,
+        "Anchored freighters and tenders beyond the swim buoys",
+    ),
+    (
+        "jericho",
+        HazardKind::Boats,
+        "Sailboats and paddlers launching from the sailing centre",
+    ),
+    (
+        "jericho",
+        HazardKind::Logs,
+        "Logs wash up along the tide line after storms",
+    ),
+    (
+        "spanish-banks-east",
+        HazardKind::Tide,
+        "A rising tide floods the sand flats quickly; watch your way back",
+    ),
+    (
+        "spanish-banks-west",
+        HazardKind::Tide,
+        "A rising tide floods the sand flats quickly; watch your way back",
+    ),
+    (
+        "locarno",
+        HazardKind::Tide,
+        "A rising tide floods the sand flats quickly; watch your way back",
+    ),
+    (
+        "wreck",
+        HazardKind::Currents,
+        "Strong Fraser River currents off the point",
+    ),
+    (
+        "wreck",
+        HazardKind::Rocks,
+        "Boulders along the shore are hidden at high tide",
+    ),
+    (
+        "wreck",
+        HazardKind::Logs,
+        "Large logs roll in the surf on windy days",
+    ),
+    (
+        "second",
+        HazardKind::Rocks,
+        "Rocky shore either side of the swim area",
+    ),
+    (
+        "third",
+        HazardKind::DropOff,
+        "The beach shelves steeply into deep, cold water",
+    ),
+    (
+        "third",
+        HazardKind::Rocks,
+        "Rocks below the seawall towards Siwash Rock",
+    ),
+    (
+        "sunset",
+        HazardKind::Currents,
+        "Strong currents near the Burrard Bridge outflow",
+    ),
+    (
+        "sunset",
+        HazardKind::Boats,
+        "Boat traffic in and out of False Creek",
+    ),
+    (
+        "new-brighton",
+        HazardKind::Boats,
+        "Wakes from ships in the Second Narrows channel",
+    ),
+    (
+        "new-brighton",
+        HazardKind::DropOff,
+        "The bottom drops off sharply past the end of the swim area",
+    ),
 ];
 
+/// Where to find shade at each beach in the heat: (beach id, description)
+const SHADE_SPOTS: [(&str, &str); 13] = [
+    (
+        "kitsilano",
+        "Trees along the park lawn behind the beach; none on the sand",
+    ),
+    (
+        "english-bay",
+        "Elms along Beach Avenue and the seawall; none on the sand",
+    ),
+    ("jericho", "Trees across Jericho Park behind the beach"),
+    (
+        "spanish-banks-east",
+        "The escarpment shades the beach from mid-afternoon",
+    ),
+    (
+        "spanish-banks-west",
+        "The escarpment shades the beach from mid-afternoon; trees by the parking lot",
+    ),
+    (
+        "locarno",
+        "Trees on the grass behind the beach; escarpment shade late in the day",
+    ),
+    (
+        "wreck",
+        "Forest at the foot of the stairs; the cliffs shade the sand until late morning",
+    ),
+    (
+        "second",
+        "Stanley Park forest behind the beach and the pool",
+    ),
+    (
+        "third",
+        "Stanley Park forest right behind the sand, shaded through the morning",
+    ),
+    (
+        "sunset",
+        "Trees on the lawn by the Aquatic Centre; the sand is open",
+    ),
+    ("trout-lake", "Big trees around the lawn on the west shore"),
+    (
+        "new-brighton",
+        "Trees around the park and pool; the sand is open",
+    ),
+    ("crab-park", "A few trees on the lawn; the beach is open"),
+];
+
+/// Days of the year a dog rule covers, as (month, day) of its first and last
+/// day, or `None` for year-round; seasons don't wrap past New Year
+type DogSeason = Option<((u32, u32), (u32, u32))>;
+
+/// Dog rules per beach: (beach id, season, access, note)
+///
+/// A beach's first rule whose season contains the day applies. The city
+/// keeps dogs off every beach but its designated areas, so a beach without a
+/// rule bans them.
+const DOG_RULES: [(&str, DogSeason, DogAccess, &str); 7] = [
+    (
+        "spanish-banks-west",
+        Some(((5, 1), (9, 30))),
+        DogAccess::Banned,
+        "Off-leash area closed for the summer, May to September",
+    ),
+    (
+        "spanish-banks-west",
+        None,
+        DogAccess::OffLeash,
+        "Off-leash area at the west end",
+    ),
+    (
+        "spanish-banks-east",
+        Some(((5, 1), (9, 30))),
+        DogAccess::Banned,
+        "No dogs on the sand May to September",
+    ),
+    (
+        "spanish-banks-east",
+        None,
+        DogAccess::OnLeash,
+        "On leash outside the swim season",
+    ),
+    (
+        "sunset",
+        None,
+        DogAccess::OffLeash,
+        "Off-leash area at the east end, away from the swim area",
+    ),
+    (
+        "trout-lake",
+        Some(((5, 1), (9, 30))),
+        DogAccess::Banned,
+        "No dogs on the beach May to September; off-leash area north of the lake",
+    ),
+    (
+        "trout-lake",
+        None,
+        DogAccess::OnLeash,
+        "On leash; off-leash area north of the lake",
+    ),
+];
+
+/// Note for beaches that never allow dogs
+const NO_DOGS_NOTE: &str = "Dogs aren't allowed on the beach";
+
+/// Beaches open to swell from the Strait of Georgia: (beach id, compass
+/// bearing in degrees the beach faces out to sea)
+///
+/// Everywhere else sits behind Point Grey or inside English Bay and False
+/// Creek, where waves rarely build past a ripple. Tower Beach, just north of
+/// Wreck on the same shore, isn't in the registry.
+const WAVE_EXPOSED: [(&str, f64); 1] = [("wreck", 250.0)];
+
+/// Open water the wind crosses before reaching each beach: (beach id,
+/// direction_from, direction_to, fetch in km) for wind blowing from that
+/// sector, rough distances to the far shore
+///
+/// Wind from anywhere else comes off the land and barely ruffles the water
+/// by the beach (`OFFSHORE_FETCH_KM`).
+const WIND_FETCH: [(&str, f64, f64, f64); 20] = [
+    // Straight up the Strait of Georgia past Point Grey
+    ("english-bay", 200.0, 300.0, 40.0),
+    ("english-bay", 300.0, 30.0, 6.0),
+    ("sunset", 200.0, 300.0, 40.0),
+    ("sunset", 300.0, 30.0, 6.0),
+    ("second", 200.0, 300.0, 40.0),
+    ("second", 300.0, 30.0, 6.0),
+    ("third", 200.0, 300.0, 40.0),
+    ("third", 300.0, 30.0, 6.0),
+    // Across English Bay, or out past Point Atkinson to the open strait
+    ("kitsilano", 240.0, 330.0, 30.0),
+    ("kitsilano", 330.0, 30.0, 5.0),
+    ("jericho", 280.0, 340.0, 25.0),
+    ("jericho", 340.0, 60.0, 6.0),
+    ("locarno", 280.0, 340.0, 25.0),
+    ("locarno", 340.0, 60.0, 6.0),
+    ("spanish-banks-east", 280.0, 340.0, 25.0),
+    ("spanish-banks-east", 340.0, 60.0, 6.0),
+    ("spanish-banks-west", 260.0, 60.0, 25.0),
+    ("wreck", 190.0, 330.0, 40.0),
+    // The length of the lake
+    ("trout-lake", 0.0, 360.0, 0.4),
+    // Along Burrard Inlet from Second Narrows and Indian Arm
+    ("new-brighton", 30.0, 110.0, 8.0),
+];
+
+/// Fetch in km for wind blowing off the land behind a beach
+const OFFSHORE_FETCH_KM: f64 = 0.3;
+
+/// Lazily-initialized registry of beaches built from `beach_definitions`,
+/// or from the downloaded registry when one is installed
+static BEACHES: OnceLock<Vec<Beach>> = OnceLock::new();
+
+/// Beaches from the downloaded registry, replacing the built-in list
+static OVERRIDES: OnceLock<Vec<BeachRecord>> = OnceLock::new();
+
+/// Replaces the built-in beach list with beaches from the downloaded registry
+///
+/// Must be called before the registry is first used. Returns `false` if
+/// overrides were already installed or the registry is already built.
+pub fn set_beach_overrides(records: Vec<BeachRecord>) -> bool {
+    BEACHES.get().is_none() && OVERRIDES.set(records).is_ok()
+}
+
+/// Returns the beach registry, building it on first access
+fn registry() -> &'static [Beach] {
+    BEACHES.get_or_init(|| build_registry(OVERRIDES.get().map(Vec::as_slice)))
+}
+
+/// Builds the beaches from downloaded records, or the built-in list without
+fn build_registry(records: Option<&[BeachRecord]>) -> Vec<Beach> {
+    let Some(records) = records else {
+        return beach_definitions()
+            .iter()
+            .map(|definition| {
+                enrich(Beach::new(
+                    definition.id.clone(),
+                    definition.name.as_str(),
+                    definition.latitude,
+                    definition.longitude,
+                    definition.water_quality_id.as_deref(),
+                ))
+            })
+            .collect();
+    };
+    records
+        .iter()
+        .map(|record| {
+            // A beach the app already knows keeps its water quality station
+            let wq_id = beach_definitions()
+                .iter()
+                .find(|definition| definition.id == record.id)
+                .and_then(|definition| definition.water_quality_id.as_deref());
+            let beach = Beach::new(
+                record.id.clone(),
+                record.name.as_str(),
+                record.latitude,
+                record.longitude,
+                wq_id,
+            )
+            .with_amenities(record.amenities.clone());
+            enrich(beach)
+        })
+        .collect()
+}
+
+/// Adds what's known about a beach by its ID: shade, wind, tide and lifeguards
+fn enrich(mut beach: Beach) -> Beach {
+    let id = beach.id.clone();
+    let id = id.as_str();
+    if let Some(profile) = shade_profile_for(id) {
+        beach = beach.with_shade_profile(profile);
+    }
+    if let Some(exposure) = wind_exposure_for(id) {
+        beach = beach.with_wind_exposure(exposure);
+    }
+    if let Some(coefficient) = bathymetry_coefficient_for(id) {
+        beach = beach.with_bathymetry_coefficient(coefficient);
+    }
+    if id == "trout-lake" {
+        beach = beach.with_water_body(WaterBody::Lake);
+    }
+    if LIFEGUARDED_BEACHES.contains(&id) {
+        beach = beach.with_lifeguards();
+    }
+    beach
+}
+
+/// ID of the built-in beach with this name, ignoring case
+pub fn builtin_id_for_name(name: &str) -> Option<BeachId> {
+    beach_definitions()
+        .iter()
+        .find(|definition| definition.name.eq_ignore_ascii_case(name.trim()))
+        .map(|definition| definition.id.clone())
+}
+
+/// Approximate shade profiles for beaches backed by cliffs, forest, or trees
+///
+/// Each obstruction is (azimuth_from, azimuth_to, elevation) in degrees. The
+/// values are rough estimates of the skyline as seen from the sand.
+fn shade_profile_for(id: &str) -> Option<ShadeProfile> {
+    let obstructions: &[(f64, f64, f64)] = match id {
+        // Park trees along the south-west edge of the lawn
+        "kitsilano" => &[(200.0, 260.0, 8.0)],
+        // Point Grey escarpment rises behind the beach to the south
+        "spanish-banks-east" | "spanish-banks-west" | "locarno" => &[(120.0, 240.0, 12.0)],
+        // Tall cliffs behind the beach block the morning sun
+        "wreck" => &[(30.0, 200.0, 35.0)],
+        // Stanley Park forest behind the beach
+        "second" => &[(90.0, 200.0, 15.0)],
+        "third" => &[(0.0, 180.0, 25.0)],
+        // Tree line around the west side of the lake
+        "trout-lake" => &[(220.0, 300.0, 15.0)],
+        _ => return None,
+    };
+
+    Some(ShadeProfile {
+        obstructions: obstructions
+            .iter()
+            .map(|&(azimuth_from, azimuth_to, elevation)| ShadeObstruction {
+                azimuth_from,
+                azimuth_to,
+                elevation,
+            })
+            .collect(),
+    })
+}
+
+/// Approximate wind exposure profiles
+///
+/// Each shelter is (direction_from, direction_to, factor): wind blowing from
+/// that sector is felt at `factor` of its forecast speed on the sand.
+fn wind_exposure_for(id: &str) -> Option<WindExposure> {
+    let shelters: &[(f64, f64, f64)] = match id {
+        // Stanley Park and Siwash Rock break the westerlies; forest behind
+        "second" => &[(240.0, 300.0, 0.5), (45.0, 135.0, 0.6)],
+        "third" => &[(45.0, 160.0, 0.4)],
+        // Downtown towers block easterlies
+        "english-bay" | "sunset" => &[(45.0, 160.0, 0.5)],
+        // Kitsilano Point and the houses behind the beach
+        "kitsilano" => &[(120.0, 200.0, 0.6)],
+        // Point Grey ridge blocks southerlies along the north shore of the point
+        "jericho" | "locarno" | "spanish-banks-east" | "spanish-banks-west" => {
+            &[(135.0, 225.0, 0.6)]
+        }
+        // Cliffs behind the beach block easterlies
+        "wreck" => &[(30.0, 150.0, 0.4)],
+        // Trees ring the lake
+        "trout-lake" => &[(0.0, 360.0, 0.7)],
+        "new-brighton" => &[(150.0, 230.0, 0.6)],
+        _ => return None,
+    };
+
+    Some(WindExposure {
+        shelters: shelters
+            .iter()
+            .map(|&(direction_from, direction_to, factor)| WindShelter {
+                direction_from,
+                direction_to,
+                factor,
+            })
+            .collect(),
+    })
+}
+
+/// Approximate bathymetry coefficients: metres of sand uncovered per metre of
+/// tide drop below high water
+///
+/// Derived from how far the low-tide line sits from the high-water line at a
+/// 0.5m tide. Spanish Banks and Locarno sit on wide, nearly flat sand flats;
+/// the city beaches shelve steeply.
+fn bathymetry_coefficient_for(id: &str) -> Option<f64> {
+    let coefficient = match id {
+        "spanish-banks-east" | "spanish-banks-west" => 230.0,
+        "locarno" => 200.0,
+        "jericho" => 120.0,
+        "wreck" => 45.0,
+        "kitsilano" => 35.0,
+        "english-bay" | "sunset" => 25.0,
+        "second" | "third" => 20.0,
+        "new-brighton" => 12.0,
+        // Trout Lake has no tide
+        _ => return None,
+    };
+    Some(coefficient)
+}
+
 /// Get a beach by its ID
 ///
 /// # Arguments
@@ -117,7 +525,124 @@ pub static BEACHES: [Beach; 12] = [
 /// ```
 #[allow(dead_code)]
 pub fn get_beach_by_id(id: &str) -> Option<&'static Beach> {
-    BEACHES.iter().find(|beach| beach.id == id)
+    registry().iter().find(|beach| beach.id == id)
+}
+
+/// Routes from a beach to its neighbours along the seawall or paths,
+/// nearest first
+pub fn connections(id: &str) -> Vec<Connection> {
+    let mut routes: Vec<Connection> = CONNECTIONS
+        .iter()
+        .filter_map(|&(a, b, distance_m, path)| {
+            let to = if a == id {
+                b
+            } else if b == id {
+                a
+            } else {
+                return None;
+            };
+            Some(Connection {
+                to,
+                distance_m,
+                path,
+            })
+        })
+        .collect();
+    routes.sort_by_key(|route| route.distance_m);
+    routes
+}
+
+/// Shortest distance in metres from one beach to another along the seawall
+/// and paths, through the beaches between, or `None` if no path joins them
+///
+/// A beach is no distance from itself.
+pub fn path_distance(from: &str, to: &str) -> Option<u32> {
+    // Dijkstra over a handful of beaches: settle the nearest unsettled one
+    let mut distances: Vec<(&str, u32)> = vec![(from, 0)];
+    let mut settled: Vec<&str> = Vec::new();
+    loop {
+        let (id, distance) = distances
+            .iter()
+            .filter(|(id, _)| !settled.contains(id))
+            .min_by_key(|(_, distance)| *distance)
+            .copied()?;
+        if id == to {
+            return Some(distance);
+        }
+        settled.push(id);
+        for route in connections(id) {
+            let via = distance + route.distance_m;
+            match distances.iter_mut().find(|(known, _)| *known == route.to) {
+                Some(known) if known.1 <= via => {}
+                Some(known) => known.1 = via,
+                None => distances.push((route.to, via)),
+            }
+        }
+    }
+}
+
+/// Known hazards at a beach, in the order they're listed
+pub fn hazards(id: &str) -> Vec<Hazard> {
+    HAZARDS
+        .iter()
+        .filter(|(beach, _, _)| *beach == id)
+        .map(|&(_, kind, description)| Hazard { kind, description })
+        .collect()
+}
+
+/// Where to find shade at a beach, for heat warnings
+pub fn shade_spots(id: &str) -> Option<&'static str> {
+    SHADE_SPOTS
+        .iter()
+        .find(|(beach, _)| *beach == id)
+        .map(|&(_, description)| description)
+}
+
+/// Bearing a wave-exposed beach faces out to sea, or `None` for a beach too
+/// sheltered to surf
+pub fn wave_facing(id: &str) -> Option<f64> {
+    WAVE_EXPOSED
+        .iter()
+        .find(|(beach, _)| *beach == id)
+        .map(|&(_, facing)| facing)
+}
+
+/// Open water in km the wind crosses before reaching a beach when it blows
+/// from `direction` (degrees), or `None` for a beach without a fetch profile
+pub fn wind_fetch(id: &str, direction: f64) -> Option<f64> {
+    let direction = direction.rem_euclid(360.0);
+    let mut sectors = WIND_FETCH
+        .iter()
+        .filter(|(beach, ..)| *beach == id)
+        .peekable();
+    sectors.peek()?;
+    let fetch = sectors
+        .find(|&&(_, from, to, _)| {
+            if from <= to {
+                (from..=to).contains(&direction)
+            } else {
+                // Sector wraps through north
+                direction >= from || direction <= to
+            }
+        })
+        .map(|&(.., fetch)| fetch)
+        .unwrap_or(OFFSHORE_FETCH_KM);
+    Some(fetch)
+}
+
+/// The dog rule in effect at a beach on `date`
+pub fn dog_rule(id: &str, date: NaiveDate) -> DogRule {
+    let day = (date.month(), date.day());
+    DOG_RULES
+        .iter()
+        .find(|(beach, season, _, _)| {
+            *beach == id && season.is_none_or(|(first, last)| (first..=last).contains(&day))
+        })
+        .map(|&(_, _, access, note)| DogRule { access, note })
+        .unwrap_or(DogRule {
+            access: DogAccess::Banned,
+            note: NO_DOGS_NOTE,
+        })
 }
 
 /// Get all available beaches
@@ -136,7 +661,7 @@ pub fn get_beach_by_id(id: &str) -> Option<&'static Beach> {
 /// }
 /// ```
 pub fn all_beaches() -> &'static [Beach] {
-    &BEACHES
+    registry()
 }
 
 #[cfg(test)]
@@ -144,8 +669,52 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_beaches_array_has_12_entries() {
-        assert_eq!(BEACHES.len(), 12);
+    fn test_downloaded_records_replace_the_builtin_beaches() {
+        let records = vec![
+            BeachRecord {
+                id: BeachId::new("kitsilano"),
+                name: "Kits Beach".to_string(),
+                latitude: 49.2745,
+                longitude: -123.1550,
+                amenities: vec!["Washrooms".to_string()],
+            },
+            BeachRecord {
+                id: BeachId::new("crab-park"),
+                name: "CRAB Park Beach".to_string(),
+                latitude: 49.2855,
+                longitude: -123.1018,
+                amenities: Vec::new(),
+            },
+        ];
+
+        let beaches = build_registry(Some(&records));
+
+        assert_eq!(beaches.len(), 2);
+        assert_eq!(beaches[0].name, "Kits Beach");
+        assert_eq!(
+            beaches[0].water_quality_id.as_deref(),
+            Some("kitsilano-beach")
+        );
+        assert!(beaches[0].lifeguarded);
+        assert_eq!(beaches[0].amenities, vec!["Washrooms"]);
+        assert_eq!(beaches[1].water_quality_id, None);
+        assert!(!beaches[1].lifeguarded);
+        assert_eq!(
+            builtin_id_for_name(" wreck beach "),
+            Some(BeachId::new("wreck"))
+        );
+    }
+
+    #[test]
+    fn test_beach_definitions_has_12_entries() {
+        assert_eq!(beach_definitions().len(), 12);
+    }
+
+    #[test]
+    fn test_registry_returns_same_instances() {
+        let first = get_beach_by_id("kitsilano").unwrap();
+        let second = get_beach_by_id("kitsilano").unwrap();
+        assert!(std::ptr::eq(first, second), "Registry should be built once");
     }
 
     #[test]
@@ -215,9 +784,68 @@ mod tests {
         assert!(get_beach_by_id("KITSILANO").is_none()); // Case sensitive
     }
 
+    #[test]
+    fn test_shaded_beaches_have_profiles() {
+        assert!(get_beach_by_id("wreck").unwrap().shade_profile.is_some());
+        assert!(get_beach_by_id("trout-lake")
+            .unwrap()
+            .shade_profile
+            .is_some());
+        assert!(get_beach_by_id("english-bay")
+            .unwrap()
+            .shade_profile
+            .is_none());
+    }
+
+    #[test]
+    fn test_exposed_sand_width_from_bathymetry() {
+        let spanish_banks = get_beach_by_id("spanish-banks-east").unwrap();
+        let kitsilano = get_beach_by_id("kitsilano").unwrap();
+
+        let flats = spanish_banks.exposed_sand_width(0.5).unwrap();
+        assert!(
+            flats > 900.0,
+            "Spanish Banks flats should be ~1km: {}",
+            flats
+        );
+        assert!(kitsilano.exposed_sand_width(0.5).unwrap() < flats);
+        assert_eq!(spanish_banks.exposed_sand_width(5.0), Some(0.0));
+
+        // No tide on the lake
+        assert!(get_beach_by_id("trout-lake")
+            .unwrap()
+            .exposed_sand_width(1.0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_second_beach_is_sheltered_from_westerlies() {
+        let second = get_beach_by_id("second").unwrap();
+        let kitsilano = get_beach_by_id("kitsilano").unwrap();
+
+        assert_eq!(second.effective_wind(12.0, Some(270.0)), 6.0);
+        assert_eq!(kitsilano.effective_wind(12.0, Some(270.0)), 12.0);
+        // Unknown direction is never adjusted
+        assert_eq!(second.effective_wind(12.0, None), 12.0);
+    }
+
+    #[test]
+    fn test_lifeguards_and_water_bodies() {
+        assert!(get_beach_by_id("kitsilano").unwrap().lifeguarded);
+        assert!(!get_beach_by_id("wreck").unwrap().lifeguarded);
+        assert_eq!(
+            get_beach_by_id("trout-lake").unwrap().water_body,
+            WaterBody::Lake
+        );
+        assert_eq!(
+            get_beach_by_id("english-bay").unwrap().water_body,
+            WaterBody::Ocean
+        );
+    }
+
     #[test]
     fn test_all_beaches_have_unique_ids() {
-        let mut ids: Vec<&str> = all_beaches().iter().map(|b| b.id).collect();
+        let mut ids: Vec<&str> = all_beaches().iter().map(|b| b.id.as_str()).collect();
         ids.sort();
         let original_len = ids.len();
         ids.dedup();
@@ -254,7 +882,7 @@ mod tests {
         ];
 
         for (id, expected_lat, expected_lon) in test_cases {
-            let beach = get_beach_by_id(id).expect(&format!("Beach {} not found", id));
+            let beach = get_beach_by_id(id).unwrap_or_else(|| panic!("Beach {} not found", id));
             assert!(
                 (beach.latitude - expected_lat).abs() < 0.0001,
                 "Beach {} latitude mismatch: expected {}, got {}",
@@ -271,4 +899,127 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_connections_go_both_ways_nearest_first() {
+        let jericho = connections("jericho");
+        assert_eq!(
+            jericho.iter().map(|c| c.to).collect::<Vec<_>>(),
+            vec!["locarno", "kitsilano"]
+        );
+        assert_eq!(jericho[0].walk_minutes(), 15);
+        assert_eq!(jericho[0].bike_minutes(), 5);
+
+        assert!(connections("trout-lake").is_empty());
+    }
+
+    #[test]
+    fn test_path_distance_adds_up_the_beaches_between() {
+        assert_eq!(path_distance("kitsilano", "kitsilano"), Some(0));
+        assert_eq!(path_distance("jericho", "locarno"), Some(1200));
+        // Kits → Jericho → Locarno → Spanish Banks East, either way
+        assert_eq!(path_distance("kitsilano", "spanish-banks-east"), Some(5400));
+        assert_eq!(path_distance("spanish-banks-east", "kitsilano"), Some(5400));
+        // False Creek splits the seawall from the Point Grey paths
+        assert_eq!(path_distance("kitsilano", "sunset"), None);
+        assert_eq!(path_distance("trout-lake", "third"), None);
+    }
+
+    #[test]
+    fn test_connections_name_real_beaches() {
+        for (a, b, distance_m, _) in CONNECTIONS {
+            assert!(get_beach_by_id(a).is_some(), "Unknown beach {}", a);
+            assert!(get_beach_by_id(b).is_some(), "Unknown beach {}", b);
+            assert!(distance_m > 0);
+        }
+    }
+
+    #[test]
+    fn test_hazards_are_listed_per_beach() {
+        let sunset = hazards("sunset");
+        assert_eq!(sunset.len(), 2);
+        assert_eq!(sunset[0].kind, HazardKind::Currents);
+        assert!(sunset[0].description.contains("Burrard Bridge"));
+
+        assert!(hazards("trout-lake").is_empty());
+        for (id, _, description) in HAZARDS {
+            assert!(get_beach_by_id(id).is_some(), "Unknown beach {}", id);
+            assert!(!description.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_every_beach_has_shade_spots() {
+        for beach in all_beaches() {
+            assert!(
+                shade_spots(&beach.id).is_some(),
+                "No shade for {}",
+                beach.id
+            );
+        }
+        assert_eq!(shade_spots("nowhere"), None);
+    }
+
+    #[test]
+    fn test_dog_rules_follow_the_season() {
+        let day = |month, day| NaiveDate::from_ymd_opt(2026, month, day).unwrap();
+
+        // Spanish Banks West's off-leash area closes for the summer
+        assert_eq!(
+            dog_rule("spanish-banks-west", day(4, 30)).access,
+            DogAccess::OffLeash
+        );
+        assert_eq!(
+            dog_rule("spanish-banks-west", day(5, 1)).access,
+            DogAccess::Banned
+        );
+        assert_eq!(
+            dog_rule("spanish-banks-west", day(9, 30)).access,
+            DogAccess::Banned
+        );
+        assert_eq!(
+            dog_rule("spanish-banks-east", day(10, 1)).access,
+            DogAccess::OnLeash
+        );
+        assert_eq!(dog_rule("sunset", day(7, 15)).access, DogAccess::OffLeash);
+
+        // Beaches without a rule never allow dogs
+        let kits = dog_rule("kitsilano", day(1, 10));
+        assert!(!kits.access.allows_dogs());
+        assert_eq!(kits.note, NO_DOGS_NOTE);
+
+        for (id, _, _, note) in DOG_RULES {
+            assert!(get_beach_by_id(id).is_some(), "Unknown beach {}", id);
+            assert!(!note.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_wave_facing_only_for_exposed_beaches() {
+        assert_eq!(wave_facing("wreck"), Some(250.0));
+        assert_eq!(wave_facing("english-bay"), None);
+        assert_eq!(wave_facing("trout-lake"), None);
+        for (id, _) in WAVE_EXPOSED {
+            assert!(get_beach_by_id(id).is_some(), "Unknown beach {}", id);
+        }
+    }
+
+    #[test]
+    fn test_wind_fetch_is_long_only_from_open_water() {
+        // A westerly runs up the strait into English Bay; an easterly comes
+        // off downtown
+        assert_eq!(wind_fetch("english-bay", 270.0), Some(40.0));
+        assert_eq!(wind_fetch("english-bay", 90.0), Some(OFFSHORE_FETCH_KM));
+        // Sectors wrapping through north
+        assert_eq!(wind_fetch("kitsilano", 350.0), Some(5.0));
+        assert_eq!(wind_fetch("kitsilano", -10.0), Some(5.0));
+        assert_eq!(wind_fetch("trout-lake", 270.0), Some(0.4));
+        assert_eq!(wind_fetch("crab-park", 270.0), None);
+        for beach in all_beaches() {
+            assert!(wind_fetch(&beach.id, 0.0).is_some(), "{}", beach.id);
+        }
+        for (id, ..) in WIND_FETCH {
+            assert!(get_beach_by_id(id).is_some(), "Unknown beach {}", id);
+        }
+    }
 }
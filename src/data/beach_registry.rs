@@ -0,0 +1,257 @@
+//! Beach registry refreshed from Vancouver Open Data
+//!
+//! The beach list is compiled in, so a renamed beach or a moved swim area
+//! would need a new release. `vanbeach update-beaches` fetches the city's
+//! beaches dataset and writes it to a data file; on startup the file's
+//! beaches replace the built-in list. Beaches the city names like a built-in
+//! one keep the built-in ID and everything known about it (water quality
+//! station, shade, hazards, dog rules); new ones get an ID from their name.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use directories::ProjectDirs;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use super::beach::builtin_id_for_name;
+use super::recording;
+use super::BeachId;
+
+/// Beaches dataset on Vancouver Open Data
+const BEACHES_URL: &str =
+    "https://opendata.vancouver.ca/api/explore/v2.1/catalog/datasets/beaches/records";
+
+/// More records than the city has beaches
+const RECORD_LIMIT: usize = 100;
+
+/// Name of the data file in the data directory
+const DATA_FILE_NAME: &str = "beaches.json";
+
+/// Field names tried for each value, in order
+const NAME_FIELDS: [&str; 3] = ["name", "beach_name", "park_name"];
+const AMENITY_FIELDS: [&str; 2] = ["amenities", "facilities"];
+
+/// Errors that can occur when updating or loading the registry
+#[derive(Debug, Error)]
+pub enum RegistryError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The response or data file isn't the expected JSON
+    #[error("Failed to parse beaches: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    /// The data file couldn't be read or written
+    #[error("Failed to access the beaches file: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// No record had both a name and coordinates
+    #[error("The dataset has no beaches with a name and coordinates")]
+    Empty,
+}
+
+/// One beach as the city lists it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BeachRecord {
+    /// Built-in ID for a beach already known, otherwise one made from the name
+    pub id: BeachId,
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Facilities such as washrooms or a concession stand
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub amenities: Vec<String>,
+}
+
+/// Contents of the data file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BeachRegistryFile {
+    /// When the dataset was fetched
+    pub fetched_at: DateTime<Utc>,
+    pub beaches: Vec<BeachRecord>,
+}
+
+impl BeachRegistryFile {
+    /// Default location of the data file, if a home directory is available
+    pub fn default_path() -> Option<PathBuf> {
+        let project_dirs = ProjectDirs::from("", "", "vanbeach")?;
+        Some(project_dirs.data_dir().join(DATA_FILE_NAME))
+    }
+
+    /// Reads the data file; `Ok(None)` if there isn't one
+    pub fn load(path: &Path) -> Result<Option<Self>, RegistryError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Some(serde_json::from_str(&text)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Writes the data file, creating its directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), RegistryError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Fetches the beaches dataset
+    pub async fn fetch() -> Result<Self, RegistryError> {
+        let url = format!("{}?limit={}", BEACHES_URL, RECORD_LIMIT);
+        let text = recording::send(Client::new().get(&url))
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        Ok(Self {
+            fetched_at: Utc::now(),
+            beaches: parse_records(&text)?,
+        })
+    }
+}
+
+/// Reads the beaches out of a dataset response, skipping records without a
+/// name or coordinates
+fn parse_records(text: &str) -> Result<Vec<BeachRecord>, RegistryError> {
+    let response: Value = serde_json::from_str(text)?;
+    // A beach listed twice (one record per facility) is kept once
+    let mut seen = HashSet::new();
+    let beaches: Vec<BeachRecord> = response["results"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(parse_record)
+        .filter(|beach| seen.insert(beach.id.clone()))
+        .collect();
+    if beaches.is_empty() {
+        return Err(RegistryError::Empty);
+    }
+    Ok(beaches)
+}
+
+/// A record's beach, if it has a name and coordinates
+fn parse_record(record: &Value) -> Option<BeachRecord> {
+    let name = NAME_FIELDS
+        .iter()
+        .find_map(|field| record[field].as_str())
+        .map(str::trim)
+        .filter(|name| !name.is_empty())?;
+    let (latitude, longitude) = match &record["geo_point_2d"] {
+        Value::Object(point) => (point.get("lat")?.as_f64()?, point.get("lon")?.as_f64()?),
+        _ => (record["latitude"].as_f64()?, record["longitude"].as_f64()?),
+    };
+    let amenities = AMENITY_FIELDS
+        .iter()
+        .map(|field| &record[field])
+        .find(|value| !value.is_null())
+        .map(amenity_list)
+        .unwrap_or_default();
+
+    Some(BeachRecord {
+        id: builtin_id_for_name(name).or_else(|| slug(name))?,
+        name: name.to_string(),
+        latitude,
+        longitude,
+        amenities,
+    })
+}
+
+/// Amenities from either a list or a comma- or semicolon-separated string
+fn amenity_list(value: &Value) -> Vec<String> {
+    let items: Vec<&str> = match value {
+        Value::Array(items) => items.iter().filter_map(Value::as_str).collect(),
+        Value::String(text) => text.split([',', ';']).collect(),
+        _ => Vec::new(),
+    };
+    items
+        .into_iter()
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// ID for a beach the app doesn't know, e.g. "Crab Park Beach" -> "crab-park";
+/// `None` for a name with no ASCII letters or digits
+fn slug(name: &str) -> Option<BeachId> {
+    let words: Vec<String> = name
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect();
+    // "Beach" is in nearly every name; the built-in IDs leave it out
+    let slug = match words.split_last() {
+        Some((last, rest)) if last == "beach" && !rest.is_empty() => rest.join("-"),
+        _ => words.join("-"),
+    };
+    BeachId::parse(&slug).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const DATASET: &str = r#"{
+        "total_count": 4,
+        "results": [
+            {
+                "name": "Kitsilano Beach",
+                "geo_point_2d": {"lat": 49.2745, "lon": -123.1550},
+                "amenities": "Washrooms, Concession; Lifeguards"
+            },
+            {
+                "name": "CRAB Park Beach",
+                "latitude": 49.2855,
+                "longitude": -123.1018,
+                "facilities": ["Washrooms", " "]
+            },
+            {"name": "Kitsilano Beach", "geo_point_2d": {"lat": 49.2745, "lon": -123.1550}},
+            {"name": "Nowhere", "geo_point_2d": null},
+            {"beach_name": "  "}
+        ]
+    }"#;
+
+    #[test]
+    fn test_records_keep_builtin_ids_and_skip_incomplete_rows() {
+        let beaches = parse_records(DATASET).unwrap();
+
+        assert_eq!(beaches.len(), 2);
+        assert_eq!(beaches[0].id, "kitsilano");
+        assert!((beaches[0].longitude - -123.1550).abs() < 1e-9);
+        assert_eq!(
+            beaches[0].amenities,
+            vec!["Washrooms", "Concession", "Lifeguards"]
+        );
+        assert_eq!(beaches[1].id, "crab-park");
+        assert_eq!(beaches[1].amenities, vec!["Washrooms"]);
+
+        assert!(matches!(
+            parse_records(r#"{"results": []}"#),
+            Err(RegistryError::Empty)
+        ));
+    }
+
+    #[test]
+    fn test_data_file_round_trips() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested").join(DATA_FILE_NAME);
+        assert_eq!(BeachRegistryFile::load(&path).unwrap(), None);
+
+        let file = BeachRegistryFile {
+            fetched_at: Utc::now(),
+            beaches: parse_records(DATASET).unwrap(),
+        };
+        file.save(&path).unwrap();
+        assert_eq!(BeachRegistryFile::load(&path).unwrap(), Some(file));
+    }
+}
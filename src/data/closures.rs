@@ -0,0 +1,493 @@
+//! Beach closures from the Park Board advisories feed
+//!
+//! A beach is sometimes physically closed (construction, an oil sheen, a
+//! spill) whatever its latest water sample says. The Park Board publishes
+//! these advisories on Vancouver Open Data; this client fetches the closures
+//! in effect today and matches them to beaches by name.
+//!
+//! The same feed carries closures of the seawall near a beach. Those don't
+//! close the beach itself, so they're kept apart for runners and walkers.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use chrono::{NaiveDate, Utc};
+use reqwest::Client;
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::debug_log::DebugLog;
+use super::metrics::Metrics;
+use super::rate_limit::RateLimiter;
+use super::recording;
+use super::{Beach, BeachId, Closure, LOW_BANDWIDTH_TTL_FACTOR};
+use crate::cache::{query_key, query_string, CacheLookup, CacheManager, StalenessPolicy};
+
+/// Park Board beach advisories on Vancouver Open Data
+const ADVISORIES_URL: &str =
+    "https://opendata.vancouver.ca/api/explore/v2.1/catalog/datasets/park-board-beach-advisories/records";
+
+/// Most advisories requested at once; only a handful are ever active
+const ADVISORY_LIMIT: usize = 100;
+
+/// Name the active closures are cached under, before its query version
+const CACHE_NAME: &str = "beach_closures";
+
+/// Name the active seawall closures are cached under, before its query
+/// version
+const SEAWALL_CACHE_NAME: &str = "seawall_closures";
+
+/// Word that marks an advisory as being about the seawall rather than the
+/// beach
+const SEAWALL: &str = "seawall";
+
+/// How long cached closures stay fresh, short so a reopening shows soon
+const CACHE_TTL: chrono::Duration = chrono::Duration::hours(1);
+
+/// Reason shown when an advisory doesn't give one
+const DEFAULT_REASON: &str = "Closed by the Park Board";
+
+/// Reason shown when a seawall advisory doesn't give one
+const DEFAULT_SEAWALL_REASON: &str = "Seawall closed by the Park Board";
+
+/// Source name advisory responses are recorded under in the debug log
+pub const CLOSURES_SOURCE: &str = "Park Board advisories";
+
+/// Errors that can occur when fetching closures
+#[derive(Debug, Error)]
+pub enum ClosureError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    HttpError(#[from] reqwest::Error),
+
+    /// Failed to parse API response
+    #[error("Failed to parse API response: {0}")]
+    ParseError(String),
+
+    /// The client-side request budget for the API's host is used up
+    #[error("Request budget for {0} used up; try again shortly")]
+    Throttled(String),
+}
+
+/// Response from the advisories dataset
+#[derive(Debug, Deserialize)]
+struct ApiResponse {
+    results: Vec<AdvisoryRecord>,
+}
+
+/// A single advisory from the feed
+#[derive(Debug, Deserialize)]
+struct AdvisoryRecord {
+    /// Beach the advisory is for, as the Park Board names it
+    beach_name: Option<String>,
+    /// Kind of advisory, e.g. "Closure" or "Caution"
+    advisory_type: Option<String>,
+    /// Why the advisory was issued
+    reason: Option<String>,
+    /// First day of the advisory (YYYY-MM-DD)
+    start_date: Option<String>,
+    /// Last day of the advisory (YYYY-MM-DD), if known
+    end_date: Option<String>,
+}
+
+/// Client for fetching beach closures from the Park Board advisories feed
+#[derive(Debug, Clone)]
+pub struct ClosuresClient {
+    /// HTTP client for making requests
+    http_client: Client,
+    /// Cache manager for persisting responses
+    cache_manager: Option<CacheManager>,
+    /// Base URL for the API (allows override for testing)
+    base_url: String,
+    /// Where raw responses are recorded in verbose mode
+    debug_log: Option<DebugLog>,
+    /// Where requests and cache lookups are counted in serve mode
+    metrics: Option<Metrics>,
+    /// Request budgets shared with the other clients
+    rate_limiter: Option<RateLimiter>,
+    /// Whether cached closures are kept longer to save requests
+    low_bandwidth: bool,
+}
+
+impl ClosuresClient {
+    /// Creates a new ClosuresClient with default configuration
+    pub fn new() -> Self {
+        Self {
+            http_client: Client::new(),
+            cache_manager: CacheManager::new(),
+            base_url: ADVISORIES_URL.to_string(),
+            debug_log: None,
+            metrics: None,
+            rate_limiter: None,
+            low_bandwidth: false,
+        }
+    }
+
+    /// Creates a new ClosuresClient with a custom cache manager
+    pub fn with_cache(cache_manager: CacheManager) -> Self {
+        Self {
+            cache_manager: Some(cache_manager),
+            ..Self::new()
+        }
+    }
+
+    /// Always asks the API, never reading or writing the cache
+    pub fn without_cache(mut self) -> Self {
+        self.cache_manager = None;
+        self
+    }
+
+    /// Records raw response bodies to the given debug log
+    pub fn with_debug_log(mut self, debug_log: DebugLog) -> Self {
+        self.debug_log = Some(debug_log);
+        self
+    }
+
+    /// Counts requests and cache lookups in the given metrics registry
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Checks every request against the given shared rate limiter
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Keeps cached responses `LOW_BANDWIDTH_TTL_FACTOR` times longer
+    /// (`--low-bandwidth`)
+    pub fn with_low_bandwidth(mut self, low_bandwidth: bool) -> Self {
+        self.low_bandwidth = low_bandwidth;
+        self
+    }
+
+    /// How long cached closures stay fresh
+    fn cache_ttl(&self) -> chrono::Duration {
+        if self.low_bandwidth {
+            CACHE_TTL * LOW_BANDWIDTH_TTL_FACTOR
+        } else {
+            CACHE_TTL
+        }
+    }
+
+    /// Query parameters the advisories request sends
+    fn query_params() -> [(&'static str, String); 1] {
+        [("limit", ADVISORY_LIMIT.to_string())]
+    }
+
+    /// Cache key for the closures, versioned by the query they're fetched
+    /// with
+    pub fn cache_key() -> String {
+        query_key(CACHE_NAME, &Self::query_params())
+    }
+
+    /// Cache key for the seawall closures, versioned like `cache_key`
+    pub fn seawall_cache_key() -> String {
+        query_key(SEAWALL_CACHE_NAME, &Self::query_params())
+    }
+
+    /// Fetches the closures in effect today, keyed by beach ID
+    ///
+    /// Beaches that aren't closed are left out. Fresh cached closures are
+    /// used first; on API failure expired ones are returned if available.
+    pub async fn fetch_closures(
+        &self,
+        beaches: &[Beach],
+    ) -> Result<HashMap<BeachId, Closure>, ClosureError> {
+        self.fetch_cached(Self::cache_key(), beaches, Advisories::Beach)
+            .await
+    }
+
+    /// Fetches the seawall closures in effect today, keyed by the ID of the
+    /// beach they're near
+    ///
+    /// Cached apart from the beach closures, in the same way.
+    pub async fn fetch_seawall_closures(
+        &self,
+        beaches: &[Beach],
+    ) -> Result<HashMap<BeachId, Closure>, ClosureError> {
+        self.fetch_cached(Self::seawall_cache_key(), beaches, Advisories::Seawall)
+            .await
+    }
+
+    /// Fetches one kind of closure, from the cache when it's fresh
+    async fn fetch_cached(
+        &self,
+        cache_key: String,
+        beaches: &[Beach],
+        kind: Advisories,
+    ) -> Result<HashMap<BeachId, Closure>, ClosureError> {
+        let cached = match self.cache_manager {
+            Some(ref cache_manager) => {
+                let lookup = cache_manager.get_with_policy::<HashMap<BeachId, Closure>>(
+                    &cache_key,
+                    StalenessPolicy::AnyAge,
+                );
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_cache(CLOSURES_SOURCE, matches!(lookup, CacheLookup::Fresh(_)));
+                }
+                lookup
+            }
+            None => CacheLookup::Miss,
+        };
+        // Expired closures are kept back in case the API fails
+        let stale = match cached {
+            CacheLookup::Fresh(closures) => return Ok(closures),
+            lookup => lookup.data(),
+        };
+
+        match self.fetch_from_api(beaches, kind).await {
+            Ok(closures) => {
+                if let Some(ref cache_manager) = self.cache_manager {
+                    let _ = cache_manager.put(&cache_key, &closures, self.cache_ttl());
+                }
+                Ok(closures)
+            }
+            Err(api_error) => stale.ok_or(api_error),
+        }
+    }
+
+    /// Fetches the advisories directly from the API
+    async fn fetch_from_api(
+        &self,
+        beaches: &[Beach],
+        kind: Advisories,
+    ) -> Result<HashMap<BeachId, Closure>, ClosureError> {
+        let url = format!("{}?{}", self.base_url, query_string(&Self::query_params()));
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire(&url)
+                .map_err(ClosureError::Throttled)?;
+        }
+
+        let started = Instant::now();
+        let text = async {
+            recording::send(self.http_client.get(&url))
+                .await?
+                .text()
+                .await
+        }
+        .await;
+        if let Some(metrics) = &self.metrics {
+            metrics.record_request(CLOSURES_SOURCE, text.is_ok(), started.elapsed());
+        }
+        let text = text?;
+        if let Some(debug_log) = &self.debug_log {
+            debug_log.record(CLOSURES_SOURCE, "all beaches", &url, &text);
+        }
+        parse_closures(&text, beaches, Utc::now().date_naive(), kind)
+    }
+}
+
+impl Default for ClosuresClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Which advisories in the feed a fetch is after
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Advisories {
+    /// Closures of the beach itself
+    Beach,
+    /// Closures of the seawall near a beach
+    Seawall,
+}
+
+/// Picks the closures of one kind in effect on `today` out of an advisories
+/// response and matches them to beaches
+///
+/// Only advisories whose type mentions a closure count; cautions don't close
+/// a beach. An advisory is about the seawall when its type, beach name or
+/// reason mentions it. The advisory's beach name is matched, ignoring case,
+/// against the beach's name or its water quality station name; a seawall
+/// advisory's name need only contain the beach's, with or without "Beach",
+/// e.g. "Seawall at English Bay".
+fn parse_closures(
+    text: &str,
+    beaches: &[Beach],
+    today: NaiveDate,
+    kind: Advisories,
+) -> Result<HashMap<BeachId, Closure>, ClosureError> {
+    let response: ApiResponse =
+        serde_json::from_str(text).map_err(|e| ClosureError::ParseError(e.to_string()))?;
+    let date = |value: &Option<String>| {
+        value
+            .as_deref()
+            .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+    };
+
+    let mut closures = HashMap::new();
+    for record in response.results {
+        let is_closure = record
+            .advisory_type
+            .as_deref()
+            .is_some_and(|kind| kind.to_lowercase().contains("clos"));
+        let started = date(&record.start_date).is_none_or(|start| start <= today);
+        let until = date(&record.end_date);
+        let seawall = [&record.advisory_type, &record.beach_name, &record.reason]
+            .iter()
+            .any(|field| {
+                field
+                    .as_deref()
+                    .is_some_and(|field| field.to_lowercase().contains(SEAWALL))
+            });
+        if !is_closure
+            || !started
+            || until.is_some_and(|end| end < today)
+            || seawall != (kind == Advisories::Seawall)
+        {
+            continue;
+        }
+
+        let Some(name) = record.beach_name.as_deref().map(str::trim) else {
+            continue;
+        };
+        let lowercase_name = name.to_lowercase();
+        let Some(beach) = beaches.iter().find(|beach| {
+            beach.name.eq_ignore_ascii_case(name)
+                || beach
+                    .water_quality_id
+                    .as_deref()
+                    .is_some_and(|station| station.eq_ignore_ascii_case(name))
+                || (seawall && lowercase_name.contains(seawall_place(&beach.name).as_str()))
+        }) else {
+            continue;
+        };
+
+        let default_reason = match kind {
+            Advisories::Beach => DEFAULT_REASON,
+            Advisories::Seawall => DEFAULT_SEAWALL_REASON,
+        };
+        let reason = record
+            .reason
+            .as_deref()
+            .map(str::trim)
+            .filter(|reason| !reason.is_empty())
+            .unwrap_or(default_reason);
+        closures.insert(
+            beach.id.clone(),
+            Closure {
+                reason: reason.to_string(),
+                until,
+                fetched_at: Utc::now(),
+            },
+        );
+    }
+    Ok(closures)
+}
+
+/// A beach's name as a seawall advisory might give it: lowercase, without
+/// a trailing "Beach"
+fn seawall_place(beach_name: &str) -> String {
+    let name = beach_name.to_lowercase();
+    name.strip_suffix(" beach").unwrap_or(&name).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::all_beaches;
+
+    fn day(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_only_closures_in_effect_are_matched_to_beaches() {
+        let json = r#"{"results": [
+            {"beach_name": "kitsilano beach", "advisory_type": "Closure",
+             "reason": "Oil sheen on the water", "start_date": "2026-07-14",
+             "end_date": "2026-07-20"},
+            {"beach_name": "English Bay Beach", "advisory_type": "Caution",
+             "reason": "Jellyfish", "start_date": "2026-07-01"},
+            {"beach_name": "Jericho Beach", "advisory_type": "Full closure",
+             "reason": "Construction", "start_date": "2026-07-16"},
+            {"beach_name": "Second Beach", "advisory_type": "Closure",
+             "reason": "Storm damage", "start_date": "2026-07-01",
+             "end_date": "2026-07-10"},
+            {"beach_name": "Nowhere Beach", "advisory_type": "Closure",
+             "reason": "Not one of ours"}
+        ]}"#;
+
+        let closures =
+            parse_closures(json, all_beaches(), day("2026-07-15"), Advisories::Beach).unwrap();
+
+        assert_eq!(closures.len(), 1);
+        let closure = &closures["kitsilano"];
+        assert_eq!(closure.reason, "Oil sheen on the water");
+        assert_eq!(closure.until, Some(day("2026-07-20")));
+    }
+
+    #[test]
+    fn test_closure_without_reason_or_dates_uses_the_default_reason() {
+        let json = r#"{"results": [
+            {"beach_name": "Sunset Beach", "advisory_type": "CLOSED", "reason": " "}
+        ]}"#;
+
+        let closures =
+            parse_closures(json, all_beaches(), day("2026-07-15"), Advisories::Beach).unwrap();
+
+        assert_eq!(closures["sunset"].reason, DEFAULT_REASON);
+        assert_eq!(closures["sunset"].until, None);
+    }
+
+    #[test]
+    fn test_seawall_closures_are_kept_apart_from_beach_closures() {
+        let json = r#"{"results": [
+            {"beach_name": "Seawall at English Bay", "advisory_type": "Closure",
+             "reason": "Storm damage", "start_date": "2026-07-14"},
+            {"beach_name": "Kitsilano Beach", "advisory_type": "Closure",
+             "reason": "Seawall repairs behind the beach"},
+            {"beach_name": "Jericho Beach", "advisory_type": "Closure",
+             "reason": "Oil sheen on the water"},
+            {"beach_name": "Seawall at Sunset Beach", "advisory_type": "Caution",
+             "reason": "Slippery after rain"}
+        ]}"#;
+
+        let seawall =
+            parse_closures(json, all_beaches(), day("2026-07-15"), Advisories::Seawall).unwrap();
+        let beaches =
+            parse_closures(json, all_beaches(), day("2026-07-15"), Advisories::Beach).unwrap();
+
+        assert_eq!(seawall.len(), 2);
+        assert_eq!(seawall["english-bay"].reason, "Storm damage");
+        assert_eq!(
+            seawall["kitsilano"].reason,
+            "Seawall repairs behind the beach"
+        );
+        assert_eq!(beaches.len(), 1);
+        assert!(beaches.contains_key("jericho"));
+    }
+
+    #[test]
+    fn test_captured_fixture_parses() {
+        // Refreshed from the live API with `vanbeach gen-fixtures`
+        let json = include_str!("../../tests/fixtures/closures.json");
+        let response: ApiResponse = serde_json::from_str(json).unwrap();
+        // Match on the first advisory's own start date so a live capture
+        // with a closure in effect finds it
+        let start = response
+            .results
+            .iter()
+            .find_map(|record| record.start_date.as_deref())
+            .map(day)
+            .unwrap_or_else(|| Utc::now().date_naive());
+
+        let closures = parse_closures(json, all_beaches(), start, Advisories::Beach).unwrap();
+
+        assert!(closures.len() <= response.results.len());
+    }
+
+    #[test]
+    fn test_invalid_response_is_a_parse_error() {
+        let result = parse_closures(
+            "<rss/>",
+            all_beaches(),
+            day("2026-07-15"),
+            Advisories::Beach,
+        );
+        assert!(matches!(result, Err(ClosureError::ParseError(_))));
+    }
+}
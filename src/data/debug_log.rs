@@ -0,0 +1,178 @@
+//! Ring buffer of raw API responses for debugging
+//!
+//! When running with `--verbose`, clients record the body of every response
+//! they receive so the debug view can show exactly what a provider sent,
+//! which makes parse failures and schema drift quick to diagnose.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+
+/// Number of responses kept before the oldest are dropped
+pub const DEBUG_LOG_CAPACITY: usize = 64;
+
+/// A raw response body as received from a data source
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawResponse {
+    /// Data source name (e.g. "Open-Meteo")
+    pub source: String,
+    /// What the request was for: coordinates or a water quality site id
+    pub subject: String,
+    /// Request URL
+    pub url: String,
+    /// Response body, unmodified
+    pub body: String,
+    /// When the response arrived
+    pub received_at: DateTime<Utc>,
+}
+
+impl RawResponse {
+    /// The body pretty-printed as JSON, or as received if it isn't JSON
+    pub fn pretty_body(&self) -> String {
+        serde_json::from_str::<serde_json::Value>(&self.body)
+            .and_then(|value| serde_json::to_string_pretty(&value))
+            .unwrap_or_else(|_| self.body.clone())
+    }
+}
+
+/// Shared, bounded log of recent raw responses
+///
+/// Cloning shares the same buffer, so one log can be handed to every client.
+#[derive(Debug, Clone)]
+pub struct DebugLog {
+    entries: Arc<Mutex<VecDeque<RawResponse>>>,
+    capacity: usize,
+}
+
+impl Default for DebugLog {
+    fn default() -> Self {
+        Self::new(DEBUG_LOG_CAPACITY)
+    }
+}
+
+impl DebugLog {
+    /// Creates an empty log keeping at most `capacity` responses
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Records a response, dropping the oldest one when full
+    pub fn record(&self, source: &str, subject: &str, url: &str, body: &str) {
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(RawResponse {
+            source: source.to_string(),
+            subject: subject.to_string(),
+            url: url.to_string(),
+            body: body.to_string(),
+            received_at: Utc::now(),
+        });
+    }
+
+    /// The most recent response from `source` about `subject`
+    pub fn latest(&self, source: &str, subject: &str) -> Option<RawResponse> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .iter()
+            .rev()
+            .find(|entry| entry.source == source && entry.subject == subject)
+            .cloned()
+    }
+
+    /// Every response currently kept, oldest first
+    pub fn entries(&self) -> Vec<RawResponse> {
+        self.entries
+            .lock()
+            .map(|entries| entries.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Number of responses currently kept
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .map(|entries| entries.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns true if no responses have been recorded
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Subject used for responses about a location
+///
+/// Rounded to 4 decimals so every client keys the same beach the same way.
+pub fn coordinate_subject(lat: f64, lon: f64) -> String {
+    format!("{:.4},{:.4}", lat, lon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_drops_oldest_when_full() {
+        let log = DebugLog::new(2);
+        log.record("Open-Meteo", "a", "http://x", "1");
+        log.record("Open-Meteo", "b", "http://x", "2");
+        log.record("Open-Meteo", "c", "http://x", "3");
+
+        assert_eq!(log.len(), 2);
+        assert!(log.latest("Open-Meteo", "a").is_none());
+        assert_eq!(log.latest("Open-Meteo", "c").unwrap().body, "3");
+    }
+
+    #[test]
+    fn test_latest_matches_source_and_subject() {
+        let log = DebugLog::default();
+        let clone = log.clone();
+        log.record("Open-Meteo", "49.2743,-123.1544", "http://x", "old");
+        clone.record("MET Norway", "49.2743,-123.1544", "http://y", "met");
+        clone.record("Open-Meteo", "49.2743,-123.1544", "http://x", "new");
+
+        assert_eq!(
+            log.latest("Open-Meteo", "49.2743,-123.1544").unwrap().body,
+            "new"
+        );
+        assert_eq!(
+            log.latest("MET Norway", "49.2743,-123.1544").unwrap().url,
+            "http://y"
+        );
+        assert!(log.latest("Water quality", "49.2743,-123.1544").is_none());
+    }
+
+    #[test]
+    fn test_pretty_body_formats_json_and_keeps_other_text() {
+        let log = DebugLog::default();
+        log.record(
+            "Open-Meteo",
+            "s",
+            "u",
+            r#"{"current":{"temperature_2m":21.5}}"#,
+        );
+        log.record("Water quality", "s", "u", "<html>502</html>");
+
+        let pretty = log.latest("Open-Meteo", "s").unwrap().pretty_body();
+        assert!(pretty.contains("\n  \"current\": {"));
+        assert_eq!(
+            log.latest("Water quality", "s").unwrap().pretty_body(),
+            "<html>502</html>"
+        );
+        assert_eq!(
+            coordinate_subject(49.27431, -123.15449),
+            "49.2743,-123.1545"
+        );
+    }
+}
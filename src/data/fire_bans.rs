@@ -0,0 +1,213 @@
+//! Campfire bans from the BC Wildfire Service
+//!
+//! A beach fire is a campfire as far as the province is concerned, so when
+//! the Coastal Fire Centre bans campfires the "Beach fire tonight?" verdict
+//! is no, whatever the wind and tide say. The BC Wildfire Service publishes
+//! its bans and prohibitions as map areas; the one covering the middle of
+//! the shoreline is asked for and cached for a few hours, since bans are
+//! put in place or lifted at most once a day.
+
+use chrono::Duration;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::rate_limit::RateLimiter;
+use super::recording;
+use crate::cache::{CacheManager, StalenessPolicy};
+
+/// Feature layer of the BC Wildfire Service's fire bans and prohibitions
+const FIRE_BANS_URL: &str = "https://services6.arcgis.com/ubm4tcTYICKBpist/arcgis/rest/services/British_Columbia_Bans_and_Prohibition_Areas/FeatureServer/0/query";
+
+/// Name the bans are cached under
+const CACHE_KEY: &str = "fire_bans";
+
+/// How long the cached bans stay fresh
+const CACHE_TTL: Duration = Duration::hours(3);
+
+/// Errors that can occur when fetching fire bans
+#[derive(Debug, Error)]
+pub enum FireBanError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The response isn't the expected JSON
+    #[error("Failed to parse fire bans: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    /// The client-side request budget for the API's host is used up
+    #[error("Request budget for {0} used up; try again shortly")]
+    Throttled(String),
+}
+
+/// Fire bans in effect at a point
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FireBans {
+    /// Each ban or prohibition, e.g. "Campfire Ban" or "Category 2 Open
+    /// Fire Prohibition"
+    pub bans: Vec<String>,
+    /// Fire centre that issued them, e.g. "Coastal Fire Centre"
+    pub fire_centre: Option<String>,
+}
+
+impl FireBans {
+    /// Whether campfires, and so beach fires, are banned
+    pub fn bans_campfires(&self) -> bool {
+        self.bans
+            .iter()
+            .any(|ban| ban.to_lowercase().contains("campfire"))
+    }
+}
+
+/// Client for the BC Wildfire Service fire bans layer
+#[derive(Debug, Clone)]
+pub struct FireBansClient {
+    client: Client,
+    base_url: String,
+    /// Cache manager for keeping the bans between runs
+    cache_manager: Option<CacheManager>,
+    /// Request budgets shared with the other clients
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl Default for FireBansClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FireBansClient {
+    /// Creates a client for the public API that doesn't cache
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+            base_url: FIRE_BANS_URL.to_string(),
+            cache_manager: None,
+            rate_limiter: None,
+        }
+    }
+
+    /// Creates a client that keeps the bans in the given cache
+    pub fn with_cache(cache_manager: CacheManager) -> Self {
+        Self {
+            cache_manager: Some(cache_manager),
+            ..Self::new()
+        }
+    }
+
+    /// Checks every request against the given shared rate limiter
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Points the client at another server
+    #[allow(dead_code)]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Fetches the bans in effect at a point, from the cache while it's
+    /// fresh
+    ///
+    /// Unlike fountains, a stale cached answer isn't used when the API
+    /// fails: a ban lifted or put in place since would make it wrong.
+    pub async fn fetch_fire_bans(
+        &self,
+        latitude: f64,
+        longitude: f64,
+    ) -> Result<FireBans, FireBanError> {
+        if let Some(cached) = self.cache_manager.as_ref().and_then(|cache| {
+            cache
+                .get_with_policy::<FireBans>(CACHE_KEY, StalenessPolicy::FreshOnly)
+                .data()
+        }) {
+            return Ok(cached);
+        }
+
+        let url = format!(
+            "{}?geometry={:.4},{:.4}&geometryType=esriGeometryPoint&inSR=4326\
+             &spatialRel=esriSpatialRelIntersects&outFields=TYPE,FIRE_CENTRE_NAME\
+             &returnGeometry=false&f=json",
+            self.base_url, longitude, latitude
+        );
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire(&url)
+                .map_err(FireBanError::Throttled)?;
+        }
+        let text = recording::send(self.client.get(&url))
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        let bans = parse_fire_bans(&text)?;
+        if let Some(cache) = &self.cache_manager {
+            let _ = cache.put(CACHE_KEY, &bans, CACHE_TTL);
+        }
+        Ok(bans)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct QueryResponse {
+    features: Vec<Feature>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Feature {
+    attributes: BanAttributes,
+}
+
+#[derive(Debug, Deserialize)]
+struct BanAttributes {
+    #[serde(rename = "TYPE")]
+    kind: Option<String>,
+    #[serde(rename = "FIRE_CENTRE_NAME")]
+    fire_centre: Option<String>,
+}
+
+/// Reads the areas covering the point; an error object from the server has
+/// no `features` and fails to parse
+fn parse_fire_bans(text: &str) -> Result<FireBans, FireBanError> {
+    let response: QueryResponse = serde_json::from_str(text)?;
+    let mut bans = FireBans::default();
+    for feature in response.features {
+        if let Some(kind) = feature.attributes.kind.filter(|kind| !kind.is_empty()) {
+            bans.bans.push(kind);
+        }
+        if bans.fire_centre.is_none() {
+            bans.fire_centre = feature.attributes.fire_centre;
+        }
+    }
+    Ok(bans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fire_bans() {
+        let bans = parse_fire_bans(
+            r#"{
+                "objectIdFieldName": "OBJECTID",
+                "features": [
+                    {"attributes": {"TYPE": "Category 2 Open Fire Prohibition", "FIRE_CENTRE_NAME": "Coastal Fire Centre"}},
+                    {"attributes": {"TYPE": "Campfire Ban", "FIRE_CENTRE_NAME": "Coastal Fire Centre"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(bans.bans.len(), 2);
+        assert_eq!(bans.fire_centre.as_deref(), Some("Coastal Fire Centre"));
+        assert!(bans.bans_campfires());
+
+        let none = parse_fire_bans(r#"{"features": []}"#).unwrap();
+        assert!(!none.bans_campfires());
+        assert!(parse_fire_bans(r#"{"error": {"code": 400}}"#).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+//! Drinking fountains from Vancouver Open Data
+//!
+//! In a heat warning the beach detail view points to the nearest places to
+//! refill a water bottle. The city publishes its drinking fountains, with
+//! where they are and when they run, on Vancouver Open Data; the list rarely
+//! changes, so it's cached for a week.
+
+use chrono::Duration;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::rate_limit::RateLimiter;
+use super::{recording, Beach};
+use crate::cache::{CacheLookup, CacheManager, StalenessPolicy};
+
+/// Drinking fountains dataset export, every record in one response
+const FOUNTAINS_URL: &str =
+    "https://opendata.vancouver.ca/api/explore/v2.1/catalog/datasets/drinking-fountains/exports/json";
+
+/// Fields requested from the dataset
+const FOUNTAIN_FIELDS: &str = "name,location,in_operation,geo_point_2d";
+
+/// Name the fountains are cached under
+const CACHE_KEY: &str = "drinking_fountains";
+
+/// How long the cached fountains stay fresh; fountains are added or
+/// removed a few times a year
+const CACHE_TTL: Duration = Duration::weeks(1);
+
+/// Errors that can occur when fetching drinking fountains
+#[derive(Debug, Error)]
+pub enum FountainError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The response isn't the expected JSON
+    #[error("Failed to parse drinking fountains: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    /// The client-side request budget for the API's host is used up
+    #[error("Request budget for {0} used up; try again shortly")]
+    Throttled(String),
+}
+
+/// A public drinking fountain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fountain {
+    /// Name, e.g. "Kitsilano Beach Park"
+    pub name: String,
+    /// Where it is in the park, e.g. "near the washrooms"
+    pub location: Option<String>,
+    /// When it runs, e.g. "spring to fall" or "year round"
+    pub in_operation: Option<String>,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Fountain {
+    /// Walking-scale distance to a beach in metres, as the crow flies
+    pub fn distance_m(&self, beach: &Beach) -> f64 {
+        const EARTH_RADIUS_M: f64 = 6_371_000.0;
+        let mean_latitude = ((self.latitude + beach.latitude) / 2.0).to_radians();
+        let east = (beach.longitude - self.longitude).to_radians() * mean_latitude.cos();
+        let north = (beach.latitude - self.latitude).to_radians();
+        EARTH_RADIUS_M * east.hypot(north)
+    }
+}
+
+/// Up to `count` fountains nearest a beach, closest first, with their
+/// distance in metres
+pub fn nearest_fountains<'a>(
+    fountains: &'a [Fountain],
+    beach: &Beach,
+    count: usize,
+) -> Vec<(&'a Fountain, f64)> {
+    let mut nearest: Vec<_> = fountains
+        .iter()
+        .map(|fountain| (fountain, fountain.distance_m(beach)))
+        .collect();
+    nearest.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+    nearest.truncate(count);
+    nearest
+}
+
+/// Client for the drinking fountains dataset
+#[derive(Debug, Clone)]
+pub struct FountainsClient {
+    client: Client,
+    base_url: String,
+    /// Cache manager for keeping the list between runs
+    cache_manager: Option<CacheManager>,
+    /// Request budgets shared with the other clients
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl Default for FountainsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FountainsClient {
+    /// Creates a client for the public API that doesn't cache
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+            base_url: FOUNTAINS_URL.to_string(),
+            cache_manager: None,
+            rate_limiter: None,
+        }
+    }
+
+    /// Creates a client that keeps the list in the given cache
+    pub fn with_cache(cache_manager: CacheManager) -> Self {
+        Self {
+            cache_manager: Some(cache_manager),
+            ..Self::new()
+        }
+    }
+
+    /// Checks every request against the given shared rate limiter
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Points the client at another server
+    #[allow(dead_code)]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Fetches every drinking fountain, from the cache while it's fresh;
+    /// if the API fails, a stale cached list is better than none
+    pub async fn fetch_fountains(&self) -> Result<Vec<Fountain>, FountainError> {
+        let stale = match self
+            .cache_manager
+            .as_ref()
+            .map_or(CacheLookup::Miss, |cache| {
+                cache.get_with_policy::<Vec<Fountain>>(CACHE_KEY, StalenessPolicy::AnyAge)
+            }) {
+            CacheLookup::Fresh(fountains) => return Ok(fountains),
+            lookup => lookup.data(),
+        };
+
+        match self.fetch_from_api().await {
+            Ok(fountains) => {
+                if let Some(cache) = &self.cache_manager {
+                    let _ = cache.put(CACHE_KEY, &fountains, CACHE_TTL);
+                }
+                Ok(fountains)
+            }
+            Err(api_error) => stale.ok_or(api_error),
+        }
+    }
+
+    async fn fetch_from_api(&self) -> Result<Vec<Fountain>, FountainError> {
+        let url = format!("{}?select={}", self.base_url, FOUNTAIN_FIELDS);
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire(&url)
+                .map_err(FountainError::Throttled)?;
+        }
+        let text = recording::send(self.client.get(&url))
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        parse_fountains(&text)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FountainRecord {
+    name: Option<String>,
+    location: Option<String>,
+    in_operation: Option<String>,
+    geo_point_2d: Option<GeoPoint>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeoPoint {
+    lat: f64,
+    lon: f64,
+}
+
+/// Reads the export, skipping fountains without a position
+fn parse_fountains(text: &str) -> Result<Vec<Fountain>, FountainError> {
+    let records: Vec<FountainRecord> = serde_json::from_str(text)?;
+    let non_empty = |value: Option<String>| {
+        value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    Ok(records
+        .into_iter()
+        .filter_map(|record| {
+            let point = record.geo_point_2d?;
+            Some(Fountain {
+                name: non_empty(record.name).unwrap_or_else(|| "Drinking fountain".to_string()),
+                location: non_empty(record.location),
+                in_operation: non_empty(record.in_operation),
+                latitude: point.lat,
+                longitude: point.lon,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::get_beach_by_id;
+
+    #[test]
+    fn test_parse_fountains_and_find_the_nearest() {
+        let fountains = parse_fountains(
+            r#"[
+                {"name": "Kitsilano Beach Park", "location": "by the washrooms ",
+                 "in_operation": "spring to fall",
+                 "geo_point_2d": {"lon": -123.1545, "lat": 49.2745}},
+                {"name": "Jericho Beach Park", "location": "",
+                 "in_operation": "year round",
+                 "geo_point_2d": {"lon": -123.1990, "lat": 49.2723}},
+                {"name": "No position", "location": null, "in_operation": null,
+                 "geo_point_2d": null}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(fountains.len(), 2);
+        assert_eq!(fountains[0].location.as_deref(), Some("by the washrooms"));
+        assert_eq!(fountains[1].location, None);
+
+        let kits = get_beach_by_id("kitsilano").unwrap();
+        let nearest = nearest_fountains(&fountains, kits, 1);
+        assert_eq!(nearest.len(), 1);
+        assert_eq!(nearest[0].0.name, "Kitsilano Beach Park");
+        assert!(nearest[0].1 < 500.0);
+        assert!(parse_fountains(r#"{"error": true}"#).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+//! Open-Meteo Marine API client
+//!
+//! Fetches hourly significant wave height and period for a beach. Only the
+//! condition table export and beaches open to swell (for Surf/Bodyboard)
+//! need waves, so other beaches never ask for them.
+
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, NaiveDateTime, Timelike};
+use reqwest::Client;
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::{recording, Beach};
+
+/// Base URL for the Open-Meteo Marine API
+const MARINE_BASE_URL: &str = "https://marine-api.open-meteo.com/v1/marine";
+
+/// Errors that can occur when fetching wave data
+#[derive(Debug, Error)]
+pub enum MarineError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The response isn't the expected JSON
+    #[error("Failed to parse marine forecast: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// Hourly wave heights in metres and periods in seconds, by local date and
+/// hour
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WaveForecast {
+    heights: HashMap<(NaiveDate, u8), f64>,
+    periods: HashMap<(NaiveDate, u8), f64>,
+}
+
+impl WaveForecast {
+    /// Wave height at the start of an hour, if forecast
+    pub fn height_at(&self, date: NaiveDate, hour: u8) -> Option<f64> {
+        self.heights.get(&(date, hour)).copied()
+    }
+
+    /// Wave period at the start of an hour, if forecast
+    pub fn period_at(&self, date: NaiveDate, hour: u8) -> Option<f64> {
+        self.periods.get(&(date, hour)).copied()
+    }
+
+    /// Adds an hour's wave height and period, replacing any already there
+    #[allow(dead_code)]
+    pub fn with_hour(
+        mut self,
+        date: NaiveDate,
+        hour: u8,
+        height: f64,
+        period: Option<f64>,
+    ) -> Self {
+        self.heights.insert((date, hour), height);
+        match period {
+            Some(period) => self.periods.insert((date, hour), period),
+            None => self.periods.remove(&(date, hour)),
+        };
+        self
+    }
+}
+
+/// Client for the Open-Meteo Marine API
+#[derive(Debug, Clone)]
+pub struct MarineClient {
+    client: Client,
+    base_url: String,
+}
+
+impl Default for MarineClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarineClient {
+    /// Creates a client for the public API
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+            base_url: MARINE_BASE_URL.to_string(),
+        }
+    }
+
+    /// Points the client at another server
+    #[allow(dead_code)]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Fetches today's and tomorrow's wave heights and periods at a beach,
+    /// in the beach's local time
+    pub async fn fetch_waves(&self, beach: &Beach) -> Result<WaveForecast, MarineError> {
+        let url = format!(
+            "{}?latitude={}&longitude={}&hourly=wave_height,wave_period&forecast_days=2&timezone={}",
+            self.base_url, beach.latitude, beach.longitude, beach.timezone.name
+        );
+        let text = recording::send(self.client.get(&url))
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        parse_waves(&text)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct MarineResponse {
+    hourly: MarineHourly,
+}
+
+#[derive(Debug, Deserialize)]
+struct MarineHourly {
+    time: Vec<String>,
+    wave_height: Vec<Option<f64>>,
+    #[serde(default)]
+    wave_period: Vec<Option<f64>>,
+}
+
+/// Reads the hourly wave heights and periods, skipping hours without a value
+fn parse_waves(text: &str) -> Result<WaveForecast, MarineError> {
+    let response: MarineResponse = serde_json::from_str(text)?;
+    let hourly = response.hourly;
+    let by_hour = |values: Vec<Option<f64>>| -> HashMap<(NaiveDate, u8), f64> {
+        hourly
+            .time
+            .iter()
+            .zip(values)
+            .filter_map(|(time, value)| {
+                let time = NaiveDateTime::parse_from_str(time, "%Y-%m-%dT%H:%M").ok()?;
+                Some(((time.date(), time.hour() as u8), value?))
+            })
+            .collect()
+    };
+    Ok(WaveForecast {
+        heights: by_hour(hourly.wave_height),
+        periods: by_hour(hourly.wave_period),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_waves_skips_missing_hours() {
+        let waves = parse_waves(
+            r#"{
+                "latitude": 49.28,
+                "longitude": -123.16,
+                "hourly_units": {"time": "iso8601", "wave_height": "m"},
+                "hourly": {
+                    "time": ["2024-07-15T12:00", "2024-07-15T13:00", "bad"],
+                    "wave_height": [0.4, null, 0.6],
+                    "wave_period": [5.5, 6.0, null]
+                }
+            }"#,
+        )
+        .unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+
+        assert_eq!(waves.height_at(date, 12), Some(0.4));
+        assert_eq!(waves.height_at(date, 13), None);
+        assert_eq!(waves.heights.len(), 1);
+        assert_eq!(waves.period_at(date, 12), Some(5.5));
+        assert_eq!(waves.period_at(date, 13), Some(6.0));
+        assert!(parse_waves(r#"{"error": true}"#).is_err());
+    }
+}
@@ -0,0 +1,502 @@
+//! MET Norway Locationforecast API client
+//!
+//! This module provides a fallback weather source using the MET Norway
+//! Locationforecast 2.0 API. The API has no daily sunrise/sunset data, so those
+//! are computed from the sun's position instead.
+
+use chrono::{DateTime, NaiveDate, Timelike, Utc};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+use super::debug_log::{coordinate_subject, DebugLog};
+use super::metrics::Metrics;
+use super::rate_limit::RateLimiter;
+use super::recording;
+use super::solar::sunrise_sunset;
+use super::weather::{degrees_to_direction, uv_for_hour, WeatherError};
+use super::{
+    BeachTimezone, CloudCover, DataQuality, HourlyForecast, Weather, WeatherCondition,
+    WeatherProvider,
+};
+
+/// Base URL for the MET Norway Locationforecast API
+const MET_NORWAY_BASE_URL: &str = "https://api.met.no/weatherapi/locationforecast/2.0/complete";
+
+/// MET Norway requires an identifying User-Agent on every request
+const USER_AGENT: &str = concat!(
+    "vanbeach/",
+    env!("CARGO_PKG_VERSION"),
+    " github.com/Zxela/beach-cli"
+);
+
+/// Conversion factor from metres per second to kilometres per hour
+const MS_TO_KMH: f64 = 3.6;
+
+/// Client for fetching weather data from MET Norway
+#[derive(Debug, Clone)]
+pub struct MetNorwayClient {
+    client: Client,
+    debug_log: Option<DebugLog>,
+    metrics: Option<Metrics>,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl Default for MetNorwayClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetNorwayClient {
+    /// Create a new MetNorwayClient with default settings
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            client,
+            debug_log: None,
+            metrics: None,
+            rate_limiter: None,
+        }
+    }
+
+    /// Records raw response bodies to the given debug log
+    pub fn with_debug_log(mut self, debug_log: DebugLog) -> Self {
+        self.debug_log = Some(debug_log);
+        self
+    }
+
+    /// Counts and times requests in the given metrics registry
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Checks every request against the given shared rate limiter
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Fetch weather data for the given coordinates
+    ///
+    /// # Arguments
+    /// * `lat` - Latitude coordinate
+    /// * `lon` - Longitude coordinate
+    /// * `timezone` - Timezone to report the location's hours and sun times in
+    ///
+    /// # Returns
+    /// * `Ok(Weather)` - Weather data for the location including hourly forecasts for today
+    /// * `Err(WeatherError)` - If the request or parsing fails
+    pub async fn fetch_weather(
+        &self,
+        lat: f64,
+        lon: f64,
+        timezone: &BeachTimezone,
+    ) -> Result<Weather, WeatherError> {
+        // MET Norway asks clients to truncate coordinates to 4 decimals
+        let url = format!("{}?lat={:.4}&lon={:.4}", MET_NORWAY_BASE_URL, lat, lon);
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire(&url)
+                .map_err(WeatherError::Throttled)?;
+        }
+
+        let started = std::time::Instant::now();
+        let text = async {
+            let response = recording::send(self.client.get(&url)).await?;
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                return Err(WeatherError::RateLimited);
+            }
+            Ok(response.error_for_status()?.text().await?)
+        }
+        .await;
+        if let Some(metrics) = &self.metrics {
+            metrics.record_request(
+                WeatherProvider::MetNorway.name(),
+                text.is_ok(),
+                started.elapsed(),
+            );
+        }
+        let text = text?;
+        if let Some(debug_log) = &self.debug_log {
+            debug_log.record(
+                WeatherProvider::MetNorway.name(),
+                &coordinate_subject(lat, lon),
+                &url,
+                &text,
+            );
+        }
+        let api_response: MetNorwayResponse = serde_json::from_str(&text)?;
+
+        parse_response(api_response, lat, lon, timezone, timezone.now().date())
+    }
+}
+
+/// Parse a Locationforecast response into a Weather struct for the given date
+/// in the location's timezone
+fn parse_response(
+    response: MetNorwayResponse,
+    lat: f64,
+    lon: f64,
+    timezone: &BeachTimezone,
+    today: NaiveDate,
+) -> Result<Weather, WeatherError> {
+    let timeseries = response.properties.timeseries;
+    let current = timeseries
+        .first()
+        .ok_or_else(|| WeatherError::MissingField("timeseries".to_string()))?;
+    let details = &current.data.instant.details;
+
+    let temperature = details
+        .air_temperature
+        .ok_or_else(|| WeatherError::MissingField("air_temperature".to_string()))?;
+    let wind = details.wind_speed.unwrap_or(0.0) * MS_TO_KMH;
+    let humidity = details.relative_humidity.unwrap_or(0.0).round() as u8;
+
+    let condition = current
+        .symbol_code()
+        .map(symbol_code_to_condition)
+        .unwrap_or(WeatherCondition::Cloudy);
+
+    let (sunrise, sunset) = sunrise_sunset(lat, lon, today, timezone)
+        .ok_or_else(|| WeatherError::MissingField("sunrise".to_string()))?;
+
+    let forecasts_for = |day: NaiveDate| -> Vec<HourlyForecast> {
+        timeseries
+            .iter()
+            .filter_map(|entry| {
+                let time = timezone.local(
+                    DateTime::parse_from_rfc3339(&entry.time)
+                        .ok()?
+                        .with_timezone(&Utc),
+                );
+                if time.date() != day {
+                    return None;
+                }
+                let d = &entry.data.instant.details;
+                let temp = d.air_temperature?;
+                Some(HourlyForecast {
+                    hour: time.hour() as u8,
+                    temperature: temp,
+                    // No apparent temperature in Locationforecast
+                    feels_like: temp,
+                    condition: entry
+                        .symbol_code()
+                        .map(symbol_code_to_condition)
+                        .unwrap_or(WeatherCondition::Cloudy),
+                    wind: d.wind_speed.unwrap_or(0.0) * MS_TO_KMH,
+                    wind_direction: degrees_to_direction(d.wind_from_direction.unwrap_or(0.0)),
+                    uv: d.ultraviolet_index_clear_sky.unwrap_or(0.0),
+                    precipitation_chance: entry
+                        .data
+                        .next_1_hours
+                        .as_ref()
+                        .and_then(|n| n.details.as_ref())
+                        .and_then(|d| d.probability_of_precipitation)
+                        .map(|p| p.round() as u8)
+                        .unwrap_or(0),
+                    dew_point: d.dew_point_temperature,
+                    wind_gusts: d.wind_speed_of_gust.map(|gust| gust * MS_TO_KMH),
+                    cloud_cover: d.cloud_cover(),
+                    data_quality: DataQuality::Reported,
+                })
+            })
+            .collect()
+    };
+    let hourly = forecasts_for(today);
+    let tomorrow = today.succ_opt().map(forecasts_for).unwrap_or_default();
+
+    // Clear-sky UV is an upper bound, which matches Open-Meteo's daily max;
+    // the current UV is the first entry's hour, falling back to that max
+    let daily_max = hourly
+        .iter()
+        .map(|h| h.uv)
+        .fold(details.ultraviolet_index_clear_sky.unwrap_or(0.0), f64::max);
+    let current_hour = DateTime::parse_from_rfc3339(&current.time)
+        .map(|time| timezone.local(time.with_timezone(&Utc)).hour())
+        .unwrap_or_else(|_| timezone.now().hour());
+    let uv = uv_for_hour(&hourly, current_hour as u8, daily_max);
+
+    Ok(Weather {
+        temperature,
+        feels_like: temperature,
+        condition,
+        humidity,
+        wind,
+        wind_direction: details.wind_from_direction,
+        uv,
+        sunrise,
+        sunset,
+        fetched_at: Utc::now(),
+        hourly,
+        tomorrow,
+        source: WeatherProvider::MetNorway,
+        parse_warnings: Vec::new(),
+        weather_code: None,
+    })
+}
+
+/// Map a MET Norway symbol code (e.g. "lightrainshowers_day") to WeatherCondition
+fn symbol_code_to_condition(code: &str) -> WeatherCondition {
+    // Strip the _day/_night/_polartwilight variant suffix
+    let base = code.split('_').next().unwrap_or(code);
+
+    if base.contains("thunder") {
+        WeatherCondition::Thunderstorm
+    } else if base.contains("snow") || base.contains("sleet") {
+        WeatherCondition::Snow
+    } else if base.contains("showers") {
+        WeatherCondition::Showers
+    } else if base.contains("rain") {
+        WeatherCondition::Rain
+    } else {
+        match base {
+            "clearsky" => WeatherCondition::Clear,
+            "fair" | "partlycloudy" => WeatherCondition::PartlyCloudy,
+            "fog" => WeatherCondition::Fog,
+            _ => WeatherCondition::Cloudy,
+        }
+    }
+}
+
+// MET Norway API response structures
+
+#[derive(Debug, Deserialize)]
+struct MetNorwayResponse {
+    properties: Properties,
+}
+
+#[derive(Debug, Deserialize)]
+struct Properties {
+    timeseries: Vec<TimeseriesEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TimeseriesEntry {
+    time: String,
+    data: TimeseriesData,
+}
+
+impl TimeseriesEntry {
+    /// Symbol for the coming hour, falling back to the coming six hours
+    fn symbol_code(&self) -> Option<&str> {
+        self.data
+            .next_1_hours
+            .as_ref()
+            .or(self.data.next_6_hours.as_ref())
+            .and_then(|p| p.summary.as_ref())
+            .map(|s| s.symbol_code.as_str())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TimeseriesData {
+    instant: Instant,
+    next_1_hours: Option<Period>,
+    next_6_hours: Option<Period>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Instant {
+    details: InstantDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct InstantDetails {
+    air_temperature: Option<f64>,
+    relative_humidity: Option<f64>,
+    wind_speed: Option<f64>,
+    wind_from_direction: Option<f64>,
+    ultraviolet_index_clear_sky: Option<f64>,
+    dew_point_temperature: Option<f64>,
+    wind_speed_of_gust: Option<f64>,
+    cloud_area_fraction: Option<f64>,
+    cloud_area_fraction_low: Option<f64>,
+    cloud_area_fraction_medium: Option<f64>,
+    cloud_area_fraction_high: Option<f64>,
+}
+
+impl InstantDetails {
+    /// Cloud cover, if every layer is reported
+    fn cloud_cover(&self) -> Option<CloudCover> {
+        let percent = |fraction: Option<f64>| Some(fraction?.round().clamp(0.0, 100.0) as u8);
+        Some(CloudCover {
+            total: percent(self.cloud_area_fraction)?,
+            low: percent(self.cloud_area_fraction_low)?,
+            mid: percent(self.cloud_area_fraction_medium)?,
+            high: percent(self.cloud_area_fraction_high)?,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Period {
+    summary: Option<Summary>,
+    details: Option<PeriodDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Summary {
+    symbol_code: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PeriodDetails {
+    probability_of_precipitation: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_RESPONSE: &str = r#"{
+        "type": "Feature",
+        "properties": {
+            "meta": { "updated_at": "2026-06-21T17:30:00Z" },
+            "timeseries": [
+                {
+                    "time": "2026-06-21T18:00:00Z",
+                    "data": {
+                        "instant": { "details": {
+                            "air_temperature": 21.4,
+                            "relative_humidity": 62.3,
+                            "wind_speed": 3.0,
+                            "wind_from_direction": 270.0,
+                            "ultraviolet_index_clear_sky": 6.2
+                        }},
+                        "next_1_hours": {
+                            "summary": { "symbol_code": "partlycloudy_day" },
+                            "details": { "probability_of_precipitation": 4.0 }
+                        }
+                    }
+                },
+                {
+                    "time": "2026-06-21T19:00:00Z",
+                    "data": {
+                        "instant": { "details": {
+                            "air_temperature": 22.0,
+                            "relative_humidity": 60.0,
+                            "wind_speed": 4.0,
+                            "wind_from_direction": 225.0,
+                            "ultraviolet_index_clear_sky": 7.1
+                        }},
+                        "next_6_hours": {
+                            "summary": { "symbol_code": "lightrainshowers_day" }
+                        }
+                    }
+                }
+            ]
+        }
+    }"#;
+
+    fn parse_fixture() -> Weather {
+        let response: MetNorwayResponse = serde_json::from_str(VALID_RESPONSE).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+        parse_response(
+            response,
+            49.2743,
+            -123.1544,
+            &BeachTimezone::vancouver(),
+            today,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_captured_fixture_parses() {
+        // Refreshed from the live API with `vanbeach gen-fixtures`
+        let response: MetNorwayResponse =
+            serde_json::from_str(include_str!("../../tests/fixtures/met-norway.json")).unwrap();
+        let timezone = BeachTimezone::vancouver();
+        let first = &response.properties.timeseries[0].time;
+        let first_day = timezone
+            .local(
+                DateTime::parse_from_rfc3339(first)
+                    .unwrap()
+                    .with_timezone(&Utc),
+            )
+            .date();
+
+        let weather = parse_response(response, 49.2869, -123.1432, &timezone, first_day).unwrap();
+
+        assert!(!weather.hourly.is_empty());
+        assert_eq!(weather.source, WeatherProvider::MetNorway);
+    }
+
+    #[test]
+    fn test_parse_current_conditions() {
+        let weather = parse_fixture();
+
+        assert_eq!(weather.temperature, 21.4);
+        assert_eq!(weather.humidity, 62);
+        assert!((weather.wind - 10.8).abs() < 0.01, "3 m/s is 10.8 km/h");
+        assert_eq!(weather.wind_direction, Some(270.0));
+        assert_eq!(weather.condition, WeatherCondition::PartlyCloudy);
+        assert_eq!(weather.source, WeatherProvider::MetNorway);
+    }
+
+    #[test]
+    fn test_parse_uses_the_current_hour_uv_and_computes_sun_times() {
+        let weather = parse_fixture();
+
+        // The first entry's hour, not the day's clear-sky max of 7.1
+        assert_eq!(weather.uv, 6.2);
+        assert_ne!(weather.sunrise, weather.sunset);
+    }
+
+    #[test]
+    fn test_parse_hourly_with_six_hour_symbol_fallback() {
+        let weather = parse_fixture();
+
+        assert!(!weather.hourly.is_empty());
+        let last = weather.hourly.last().unwrap();
+        assert_eq!(last.condition, WeatherCondition::Showers);
+        assert_eq!(last.wind_direction, "SW");
+        assert_eq!(last.precipitation_chance, 0);
+    }
+
+    #[test]
+    fn test_parse_empty_timeseries_is_error() {
+        let response: MetNorwayResponse =
+            serde_json::from_str(r#"{"properties": {"timeseries": []}}"#).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+
+        let result = parse_response(
+            response,
+            49.2743,
+            -123.1544,
+            &BeachTimezone::vancouver(),
+            today,
+        );
+        assert!(matches!(result, Err(WeatherError::MissingField(_))));
+    }
+
+    #[test]
+    fn test_symbol_code_mapping() {
+        assert_eq!(
+            symbol_code_to_condition("clearsky_day"),
+            WeatherCondition::Clear
+        );
+        assert_eq!(
+            symbol_code_to_condition("fair_night"),
+            WeatherCondition::PartlyCloudy
+        );
+        assert_eq!(symbol_code_to_condition("cloudy"), WeatherCondition::Cloudy);
+        assert_eq!(symbol_code_to_condition("fog"), WeatherCondition::Fog);
+        assert_eq!(
+            symbol_code_to_condition("heavyrain"),
+            WeatherCondition::Rain
+        );
+        assert_eq!(
+            symbol_code_to_condition("rainshowersandthunder_day"),
+            WeatherCondition::Thunderstorm
+        );
+        assert_eq!(
+            symbol_code_to_condition("lightsleet"),
+            WeatherCondition::Snow
+        );
+    }
+}
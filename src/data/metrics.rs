@@ -0,0 +1,269 @@
+//! Data pipeline metrics in the Prometheus text format
+//!
+//! In `serve` mode the clients count their API requests, time them and
+//! record cache hits and misses here, and the `/metrics` endpoint renders the
+//! totals together with how old each beach's data is and how well the cache
+//! manager's in-memory layer is doing.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::cache::CacheStats;
+
+/// Counters collected from the data clients
+#[derive(Debug, Default)]
+struct Counters {
+    /// Requests by (source, outcome)
+    requests: BTreeMap<(String, &'static str), u64>,
+    /// Total request time in seconds and number of requests, by source
+    durations: BTreeMap<String, (f64, u64)>,
+    /// Cache lookups by (source, result)
+    cache_lookups: BTreeMap<(String, &'static str), u64>,
+}
+
+/// How old one kind of data is for a beach
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataAge {
+    /// Beach id
+    pub beach: String,
+    /// Kind of data: "weather", "tides" or "water_quality"
+    pub source: &'static str,
+    /// Seconds since the data was fetched
+    pub seconds: i64,
+}
+
+/// Shared metrics registry
+///
+/// Cloning shares the same counters, so one registry can be handed to every
+/// client.
+#[derive(Debug, Clone, Default)]
+pub struct Metrics {
+    counters: Arc<Mutex<Counters>>,
+}
+
+impl Metrics {
+    /// Records an API request to `source` and how long it took
+    pub fn record_request(&self, source: &str, success: bool, elapsed: Duration) {
+        let Ok(mut counters) = self.counters.lock() else {
+            return;
+        };
+        let outcome = if success { "success" } else { "error" };
+        *counters
+            .requests
+            .entry((source.to_string(), outcome))
+            .or_default() += 1;
+        let duration = counters.durations.entry(source.to_string()).or_default();
+        duration.0 += elapsed.as_secs_f64();
+        duration.1 += 1;
+    }
+
+    /// Records a cache lookup for `source`
+    pub fn record_cache(&self, source: &str, hit: bool) {
+        let Ok(mut counters) = self.counters.lock() else {
+            return;
+        };
+        let result = if hit { "hit" } else { "miss" };
+        *counters
+            .cache_lookups
+            .entry((source.to_string(), result))
+            .or_default() += 1;
+    }
+
+    /// Renders every metric in the Prometheus text exposition format
+    pub fn render(&self, ages: &[DataAge]) -> String {
+        let mut out = String::new();
+        let Ok(counters) = self.counters.lock() else {
+            return out;
+        };
+
+        write_header(
+            &mut out,
+            "vanbeach_api_requests_total",
+            "API requests by data source and outcome",
+            "counter",
+        );
+        for ((source, outcome), count) in &counters.requests {
+            let _ = writeln!(
+                out,
+                "vanbeach_api_requests_total{{source=\"{}\",outcome=\"{}\"}} {}",
+                escape_label(source),
+                outcome,
+                count
+            );
+        }
+
+        write_header(
+            &mut out,
+            "vanbeach_api_request_duration_seconds",
+            "Time spent on API requests by data source",
+            "summary",
+        );
+        for (source, (sum, count)) in &counters.durations {
+            let source = escape_label(source);
+            let _ = writeln!(
+                out,
+                "vanbeach_api_request_duration_seconds_sum{{source=\"{}\"}} {}",
+                source, sum
+            );
+            let _ = writeln!(
+                out,
+                "vanbeach_api_request_duration_seconds_count{{source=\"{}\"}} {}",
+                source, count
+            );
+        }
+
+        write_header(
+            &mut out,
+            "vanbeach_cache_lookups_total",
+            "Cache lookups by data source and result",
+            "counter",
+        );
+        for ((source, result), count) in &counters.cache_lookups {
+            let _ = writeln!(
+                out,
+                "vanbeach_cache_lookups_total{{source=\"{}\",result=\"{}\"}} {}",
+                escape_label(source),
+                result,
+                count
+            );
+        }
+
+        write_header(
+            &mut out,
+            "vanbeach_data_age_seconds",
+            "Seconds since each beach's data was fetched",
+            "gauge",
+        );
+        for age in ages {
+            let _ = writeln!(
+                out,
+                "vanbeach_data_age_seconds{{beach=\"{}\",source=\"{}\"}} {}",
+                escape_label(&age.beach),
+                age.source,
+                age.seconds
+            );
+        }
+
+        out
+    }
+}
+
+/// Renders the cache manager's in-memory layer counters, to follow `render`
+pub fn render_memory_cache(stats: &CacheStats) -> String {
+    let mut out = String::new();
+
+    write_header(
+        &mut out,
+        "vanbeach_memory_cache_lookups_total",
+        "In-memory cache lookups by result",
+        "counter",
+    );
+    let _ = writeln!(
+        out,
+        "vanbeach_memory_cache_lookups_total{{result=\"hit\"}} {}",
+        stats.hits
+    );
+    let _ = writeln!(
+        out,
+        "vanbeach_memory_cache_lookups_total{{result=\"miss\"}} {}",
+        stats.misses
+    );
+
+    write_header(
+        &mut out,
+        "vanbeach_memory_cache_evictions_total",
+        "Entries dropped from the in-memory cache to stay within capacity",
+        "counter",
+    );
+    let _ = writeln!(
+        out,
+        "vanbeach_memory_cache_evictions_total {}",
+        stats.evictions
+    );
+
+    write_header(
+        &mut out,
+        "vanbeach_memory_cache_entries",
+        "Entries held in the in-memory cache",
+        "gauge",
+    );
+    let _ = writeln!(out, "vanbeach_memory_cache_entries {}", stats.entries);
+
+    out
+}
+
+/// Writes the HELP and TYPE lines for a metric
+fn write_header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+/// Escapes a label value: backslash, double quote and newline
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_counts_requests_and_cache_lookups() {
+        let metrics = Metrics::default();
+        let clone = metrics.clone();
+        metrics.record_request("Open-Meteo", true, Duration::from_millis(250));
+        clone.record_request("Open-Meteo", false, Duration::from_millis(750));
+        metrics.record_cache("Vancouver Open Data", true);
+        metrics.record_cache("Vancouver Open Data", true);
+        metrics.record_cache("Vancouver Open Data", false);
+
+        let text = metrics.render(&[]);
+
+        assert!(text.contains("# TYPE vanbeach_api_requests_total counter"));
+        assert!(text
+            .contains("vanbeach_api_requests_total{source=\"Open-Meteo\",outcome=\"success\"} 1"));
+        assert!(
+            text.contains("vanbeach_api_requests_total{source=\"Open-Meteo\",outcome=\"error\"} 1")
+        );
+        assert!(text.contains("vanbeach_api_request_duration_seconds_sum{source=\"Open-Meteo\"} 1"));
+        assert!(
+            text.contains("vanbeach_api_request_duration_seconds_count{source=\"Open-Meteo\"} 2")
+        );
+        assert!(text.contains(
+            "vanbeach_cache_lookups_total{source=\"Vancouver Open Data\",result=\"hit\"} 2"
+        ));
+    }
+
+    #[test]
+    fn test_render_data_ages_and_escapes_labels() {
+        let text = Metrics::default().render(&[DataAge {
+            beach: "a\"b".to_string(),
+            source: "weather",
+            seconds: 90,
+        }]);
+
+        assert!(text.contains("# TYPE vanbeach_data_age_seconds gauge"));
+        assert!(text.contains("vanbeach_data_age_seconds{beach=\"a\\\"b\",source=\"weather\"} 90"));
+    }
+
+    #[test]
+    fn test_render_memory_cache_stats() {
+        let text = render_memory_cache(&CacheStats {
+            hits: 12,
+            misses: 3,
+            evictions: 1,
+            entries: 9,
+        });
+
+        assert!(text.contains("# TYPE vanbeach_memory_cache_lookups_total counter"));
+        assert!(text.contains("vanbeach_memory_cache_lookups_total{result=\"hit\"} 12"));
+        assert!(text.contains("vanbeach_memory_cache_lookups_total{result=\"miss\"} 3"));
+        assert!(text.contains("vanbeach_memory_cache_evictions_total 1"));
+        assert!(text.contains("vanbeach_memory_cache_entries 9"));
+    }
+}
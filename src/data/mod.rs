@@ -3,45 +3,492 @@
 //! This module contains all the data types used throughout the application
 //! for representing beaches, weather, tides, and water quality information.
 
+pub mod air_quality;
+pub mod anomaly;
+pub mod assets;
 pub mod beach;
+pub mod beach_registry;
+pub mod closures;
+pub mod debug_log;
+pub mod fire_bans;
+pub mod fountains;
+pub mod marine;
+pub mod met_norway;
+pub mod metrics;
+pub mod observations;
+pub mod rate_limit;
+pub mod recording;
+pub mod solar;
 pub mod tides;
+pub mod timezone;
 pub mod water_quality;
+pub mod water_quality_archive;
 pub mod weather;
-
-pub use beach::{all_beaches, get_beach_by_id};
-pub use tides::TidesClient;
-pub use water_quality::{WaterQualityClient, WaterQualityError};
+pub mod weather_archive;
+pub mod weather_source;
+pub mod wind_models;
+pub mod wmo;
+
+pub use air_quality::{aqi_category, AirQuality, AirQualityClient};
+pub use anomaly::{check_tides, check_weather, Anomaly};
+pub use beach::{
+    all_beaches, connections, dog_rule, get_beach_by_id, hazards, path_distance, shade_spots,
+    wave_facing, wind_fetch,
+};
+pub use closures::ClosuresClient;
+#[allow(unused_imports)]
+pub use debug_log::{coordinate_subject, DebugLog, RawResponse};
+pub use fire_bans::{FireBans, FireBansClient};
+pub use fountains::{nearest_fountains, Fountain, FountainsClient};
+pub use marine::{MarineClient, WaveForecast};
+#[allow(unused_imports)]
+pub use met_norway::MetNorwayClient;
+pub use metrics::{render_memory_cache, DataAge, Metrics};
+pub use observations::{nearest_observation, ObservationsClient, WindObservation};
+pub use rate_limit::RateLimiter;
+pub use tides::{
+    is_king_tide, reference_datum, TideDatum, TidesClient, KING_TIDE_MARKER, KING_TIDE_NOTE,
+};
+#[allow(unused_imports)]
+pub use timezone::{BeachTimezone, DstRule};
+pub use water_quality::WaterQualityClient;
+pub use water_quality_archive::WaterQualityArchive;
 #[allow(unused_imports)]
 pub use weather::{ApiHourlyForecast, WeatherClient, WeatherData, WeatherError};
+pub use weather_archive::{ArchiveClient, ObservedDay};
+pub use weather_source::{fetch_weather_by_cell, WeatherFailover, WeatherSource};
+pub use wind_models::{ModelWinds, Reliability, WindModelsClient};
 
-use chrono::{DateTime, Local, NaiveDate, NaiveTime, Timelike, Utc};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc, Weekday};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
-/// Represents a beach location in Vancouver
+use crate::cache::CacheManager;
+use crate::time_format::format_time;
+
+/// How many times longer cached responses stay fresh in low-bandwidth mode
+pub const LOW_BANDWIDTH_TTL_FACTOR: i32 = 4;
+
+/// Deletes cached responses fetched with a different query than the clients
+/// now send, and those from before cache keys carried a query version
 ///
-/// Uses `&'static str` for string fields to allow static initialization
-/// of the BEACHES array. For runtime-created Beach instances, use string
-/// literals or leak the strings.
+/// Run once at startup so a changed request shape can't leave incompatible
+/// payloads behind. Returns how many entries were deleted.
+pub fn migrate_cache(cache: &CacheManager) -> usize {
+    let mut keys = vec![
+        TidesClient::cache_key(),
+        ClosuresClient::cache_key(),
+        ClosuresClient::seawall_cache_key(),
+    ];
+    keys.extend(
+        all_beaches()
+            .iter()
+            .filter_map(|beach| beach.water_quality_id.as_deref())
+            .map(WaterQualityClient::cache_key),
+    );
+    keys.iter()
+        .map(|key| cache.invalidate_other_versions(key))
+        .sum()
+}
+
+/// Unique identifier for a beach (e.g., "kitsilano", "english-bay")
+///
+/// IDs are slugs: lowercase ASCII letters and digits in words joined by
+/// single hyphens. Parsing rejects anything else, so a typo in a flag, config
+/// file or cache entry is caught where the ID is made rather than turning
+/// into a beach that silently never matches. Serializes transparently as a
+/// plain string so cached and exported data stays human-readable.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, JsonSchema)]
+#[serde(transparent)]
+pub struct BeachId(String);
+
+/// Why a string isn't a valid beach ID
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BeachIdError {
+    #[error("beach ID is empty")]
+    Empty,
+    #[error("invalid beach ID '{0}': use lowercase letters, digits and single hyphens")]
+    Invalid(String),
+}
+
+impl BeachId {
+    /// Creates a BeachId from an ID known to be valid, such as a literal
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` isn't a valid slug; use `parse` for input.
+    pub fn new(id: &str) -> Self {
+        Self::parse(id).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Parses a BeachId, checking the slug rules
+    pub fn parse(id: &str) -> Result<Self, BeachIdError> {
+        if id.is_empty() {
+            return Err(BeachIdError::Empty);
+        }
+        let valid_words = id.split('-').all(|word| {
+            !word.is_empty()
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        });
+        if !valid_words {
+            return Err(BeachIdError::Invalid(id.to_string()));
+        }
+        Ok(Self(id.to_string()))
+    }
+
+    /// Returns the ID as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Cache key for this beach's `kind` of data, e.g. "history_kitsilano"
+    ///
+    /// Slugs are safe in file names, so no escaping is needed.
+    pub fn cache_key(&self, kind: &str) -> String {
+        format!("{}_{}", kind, self.0)
+    }
+}
+
+impl std::str::FromStr for BeachId {
+    type Err = BeachIdError;
+
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        Self::parse(id)
+    }
+}
+
+impl TryFrom<String> for BeachId {
+    type Error = BeachIdError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        Self::parse(&id)
+    }
+}
+
+impl<'de> Deserialize<'de> for BeachId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        Self::parse(&id).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::ops::Deref for BeachId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::borrow::Borrow<str> for BeachId {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for BeachId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl PartialEq<str> for BeachId {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for BeachId {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Represents a beach location in Vancouver
 ///
-/// Note: This struct only implements `Serialize` (not `Deserialize`) because
-/// the static string references cannot be safely deserialized. Use `get_beach_by_id`
-/// to look up beaches from deserialized beach IDs.
-#[derive(Debug, Clone, Copy, Serialize)]
+/// Beaches own their metadata so they can be deserialized from cache files or
+/// external sources. The built-in beaches live in a lazily-initialized registry
+/// (see `beach::all_beaches`) and are handed out as `&'static Beach` references.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Beach {
     /// Unique identifier for the beach
-    pub id: &'static str,
+    pub id: BeachId,
     /// Human-readable name of the beach
-    pub name: &'static str,
+    pub name: String,
     /// Latitude coordinate
     pub latitude: f64,
     /// Longitude coordinate
     pub longitude: f64,
     /// Optional identifier for water quality monitoring station
-    pub water_quality_id: Option<&'static str>,
+    pub water_quality_id: Option<String>,
+    /// Terrain and trees that shade the beach, if known
+    #[serde(default)]
+    pub shade_profile: Option<ShadeProfile>,
+    /// How sheltered the beach is from wind out of each direction, if known
+    #[serde(default)]
+    pub wind_exposure: Option<WindExposure>,
+    /// Metres of sand uncovered for each metre the tide drops below
+    /// `HIGH_WATER_HEIGHT`, or `None` where the tide doesn't matter (lakes)
+    #[serde(default)]
+    pub bathymetry_coefficient: Option<f64>,
+    /// Timezone the beach's local times (forecast hours, sun times) are in
+    #[serde(default)]
+    pub timezone: BeachTimezone,
+    /// Whether the beach is on the sea or a lake
+    #[serde(default)]
+    pub water_body: WaterBody,
+    /// Whether lifeguards patrol the beach during the summer season
+    #[serde(default)]
+    pub lifeguarded: bool,
+    /// Facilities the city lists for the beach, from `vanbeach update-beaches`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub amenities: Vec<String>,
+}
+
+/// The kind of water a beach fronts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WaterBody {
+    /// Tidal salt water
+    #[default]
+    Ocean,
+    /// Fresh water with no tide, warming faster in summer
+    Lake,
+}
+
+impl Beach {
+    /// Creates a new Beach with the given metadata
+    pub fn new(
+        id: BeachId,
+        name: impl Into<String>,
+        latitude: f64,
+        longitude: f64,
+        water_quality_id: Option<&str>,
+    ) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            latitude,
+            longitude,
+            water_quality_id: water_quality_id.map(str::to_string),
+            shade_profile: None,
+            wind_exposure: None,
+            bathymetry_coefficient: None,
+            timezone: BeachTimezone::default(),
+            water_body: WaterBody::default(),
+            lifeguarded: false,
+            amenities: Vec::new(),
+        }
+    }
+
+    /// Attaches a shade profile describing what blocks the sun at this beach
+    pub fn with_shade_profile(mut self, profile: ShadeProfile) -> Self {
+        self.shade_profile = Some(profile);
+        self
+    }
+
+    /// Attaches a wind exposure profile describing where the beach is sheltered
+    pub fn with_wind_exposure(mut self, exposure: WindExposure) -> Self {
+        self.wind_exposure = Some(exposure);
+        self
+    }
+
+    /// Wind speed felt on the sand for a forecast speed and the direction the
+    /// wind blows from (degrees); unknown directions aren't adjusted
+    pub fn effective_wind(&self, speed: f64, direction: Option<f64>) -> f64 {
+        match (&self.wind_exposure, direction) {
+            (Some(exposure), Some(direction)) => speed * exposure.factor_for(direction),
+            _ => speed,
+        }
+    }
+
+    /// Sets the timezone the beach's local times are expressed in
+    #[allow(dead_code)]
+    pub fn with_timezone(mut self, timezone: BeachTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Sets the kind of water the beach fronts
+    pub fn with_water_body(mut self, water_body: WaterBody) -> Self {
+        self.water_body = water_body;
+        self
+    }
+
+    /// Marks the beach as patrolled by lifeguards in season
+    pub fn with_lifeguards(mut self) -> Self {
+        self.lifeguarded = true;
+        self
+    }
+
+    /// Sets the facilities listed for the beach
+    pub fn with_amenities(mut self, amenities: Vec<String>) -> Self {
+        self.amenities = amenities;
+        self
+    }
+
+    /// Sets how quickly sand is uncovered as the tide falls
+    pub fn with_bathymetry_coefficient(mut self, coefficient: f64) -> Self {
+        self.bathymetry_coefficient = Some(coefficient);
+        self
+    }
+
+    /// Estimates how many metres of sand are exposed seaward of the high-water
+    /// line at the given tide height
+    ///
+    /// Returns `None` for beaches without a bathymetry coefficient.
+    pub fn exposed_sand_width(&self, tide_height: f64) -> Option<f64> {
+        let coefficient = self.bathymetry_coefficient?;
+        Some((HIGH_WATER_HEIGHT - tide_height).max(0.0) * coefficient)
+    }
+}
+
+/// Tide height (metres) at which the water reaches the top of the beach
+pub const HIGH_WATER_HEIGHT: f64 = 4.8;
+
+/// A feature behind the beach (cliff, tree line, buildings) that blocks the sun
+///
+/// The obstruction covers a compass sector from `azimuth_from` to `azimuth_to`
+/// (degrees clockwise from north) and blocks the sun whenever it sits below
+/// `elevation` degrees within that sector.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ShadeObstruction {
+    /// Start of the compass sector in degrees (0 = north, 90 = east)
+    pub azimuth_from: f64,
+    /// End of the compass sector in degrees
+    pub azimuth_to: f64,
+    /// Apparent height of the obstruction above the horizon in degrees
+    pub elevation: f64,
+}
+
+/// Average walking speed used for route times, in metres per minute (4.8 km/h)
+pub const WALKING_METRES_PER_MINUTE: u32 = 80;
+
+/// Average cycling speed used for route times, in metres per minute (15 km/h)
+pub const CYCLING_METRES_PER_MINUTE: u32 = 250;
+
+/// A walking and cycling route from a beach to a neighbouring one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Connection {
+    /// ID of the beach the route leads to
+    pub to: &'static str,
+    /// Length of the route in metres
+    pub distance_m: u32,
+    /// Seawall or path the route follows
+    pub path: &'static str,
+}
+
+impl Connection {
+    /// Minutes to walk the route, rounded up
+    pub fn walk_minutes(&self) -> u32 {
+        self.distance_m.div_ceil(WALKING_METRES_PER_MINUTE)
+    }
+
+    /// Minutes to cycle the route, rounded up
+    pub fn bike_minutes(&self) -> u32 {
+        self.distance_m.div_ceil(CYCLING_METRES_PER_MINUTE)
+    }
+}
+
+/// Kind of a known, permanent hazard at a beach
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HazardKind {
+    /// Strong or unexpected currents
+    Currents,
+    /// Drifting or submerged logs
+    Logs,
+    /// The bottom shelves suddenly into deep water
+    DropOff,
+    /// Rocks or boulders in or at the edge of the water
+    Rocks,
+    /// Boat, paddler or ship traffic near the swim area
+    Boats,
+    /// A rising tide floods the sand quickly
+    Tide,
+}
+
+impl HazardKind {
+    /// Short label shown before the hazard's description
+    pub fn label(&self) -> &'static str {
+        match self {
+            HazardKind::Currents => "Currents",
+            HazardKind::Logs => "Logs",
+            HazardKind::DropOff => "Drop-off",
+            HazardKind::Rocks => "Rocks",
+            HazardKind::Boats => "Boats",
+            HazardKind::Tide => "Tide",
+        }
+    }
+}
+
+/// A known hazard at a beach, e.g. submerged logs after storms
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hazard {
+    /// What kind of hazard it is
+    pub kind: HazardKind,
+    /// Where and when to watch out for it
+    pub description: &'static str,
+}
+
+/// Whether dogs may be on a beach
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DogAccess {
+    /// Dogs may run off leash in a designated area
+    OffLeash,
+    /// Dogs are allowed on leash
+    OnLeash,
+    /// Dogs aren't allowed on the beach
+    Banned,
+}
+
+impl DogAccess {
+    /// Whether a dog may be on the beach at all
+    pub fn allows_dogs(&self) -> bool {
+        *self != DogAccess::Banned
+    }
+}
+
+/// The dog rule in effect at a beach on a given day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DogRule {
+    /// Whether dogs may be on the beach
+    pub access: DogAccess,
+    /// Where or why, e.g. "Off-leash area at the west end"
+    pub note: &'static str,
+}
+
+/// Per-beach description of the horizon features that cast shade on the sand
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ShadeProfile {
+    /// Obstructions around the beach
+    pub obstructions: Vec<ShadeObstruction>,
+}
+
+impl ShadeProfile {
+    /// Returns true if the sun at the given azimuth/elevation is hidden behind
+    /// one of the obstructions. A sun below the horizon is not considered shade.
+    pub fn blocks(&self, azimuth: f64, elevation: f64) -> bool {
+        if elevation <= 0.0 {
+            return false;
+        }
+        let azimuth = azimuth.rem_euclid(360.0);
+        self.obstructions.iter().any(|o| {
+            let in_sector = if o.azimuth_from <= o.azimuth_to {
+                azimuth >= o.azimuth_from && azimuth <= o.azimuth_to
+            } else {
+                // Sector wraps through north
+                azimuth >= o.azimuth_from || azimuth <= o.azimuth_to
+            };
+            in_sector && elevation < o.elevation
+        })
+    }
 }
 
 /// Hourly weather forecast data for a single hour
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct HourlyForecast {
     /// Hour of the day (0-23)
     pub hour: u8,
@@ -59,10 +506,82 @@ pub struct HourlyForecast {
     pub uv: f64,
     /// Precipitation chance percentage (0-100)
     pub precipitation_chance: u8,
+    /// Dew point in Celsius, if the provider reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dew_point: Option<f64>,
+    /// Strongest gusts in km/h, if the provider reports them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wind_gusts: Option<f64>,
+    /// How much of the sky cloud covers, if the provider reports it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloud_cover: Option<CloudCover>,
+    /// Whether the provider reported every value this hour or some were
+    /// filled in from the hours around it
+    #[serde(default, skip_serializing_if = "DataQuality::is_reported")]
+    pub data_quality: DataQuality,
+}
+
+/// Share of the sky covered by cloud in an hour, in percent, overall and by
+/// altitude
+///
+/// Low cloud (under about 2 km) sits on the horizon and hides a sunset;
+/// mid and high cloud catch its colors instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CloudCover {
+    /// All cloud, 0-100
+    pub total: u8,
+    /// Low cloud and fog, 0-100
+    pub low: u8,
+    /// Mid-level cloud, 0-100
+    pub mid: u8,
+    /// High cloud, 0-100
+    pub high: u8,
+}
+
+/// Where an hour's forecast values came from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DataQuality {
+    /// Every value as the provider sent it
+    #[default]
+    Reported,
+    /// Some values were missing and were interpolated or carried forward
+    Filled,
+}
+
+impl DataQuality {
+    /// Whether the provider reported every value
+    pub fn is_reported(&self) -> bool {
+        *self == DataQuality::Reported
+    }
+}
+
+/// Dew point spread (air temperature minus dew point) at or under which fog
+/// can form, in Celsius
+const FOG_DEW_POINT_SPREAD: f64 = 1.5;
+
+/// Wind in km/h above which near-saturated air is mixed rather than left to
+/// settle into fog
+const FOG_MAX_WIND: f64 = 10.0;
+
+impl HourlyForecast {
+    /// Whether fog is likely this hour: the provider forecasts it, or the air
+    /// is within `FOG_DEW_POINT_SPREAD` of saturation with light wind
+    ///
+    /// English Bay fog rolls in on still, humid mornings and some days only
+    /// burns off late, so a clear-sky code alone isn't enough to go by.
+    pub fn fog_likely(&self) -> bool {
+        if self.condition == WeatherCondition::Fog {
+            return true;
+        }
+        self.dew_point.is_some_and(|dew_point| {
+            self.temperature - dew_point <= FOG_DEW_POINT_SPREAD && self.wind < FOG_MAX_WIND
+        })
+    }
 }
 
 /// Weather conditions at a specific time
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Weather {
     /// Current temperature in Celsius
     pub temperature: f64,
@@ -70,11 +589,18 @@ pub struct Weather {
     pub feels_like: f64,
     /// Current weather condition
     pub condition: WeatherCondition,
+    /// WMO weather code behind `condition`, when the provider reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weather_code: Option<u8>,
     /// Relative humidity percentage (0-100)
     pub humidity: u8,
     /// Wind speed in km/h
     pub wind: f64,
-    /// UV index
+    /// Direction the wind blows from in degrees, if the provider reports it
+    #[serde(default)]
+    pub wind_direction: Option<f64>,
+    /// UV index for the current hour, from the hourly forecast (the day's
+    /// max when the hour has no forecast)
     pub uv: f64,
     /// Sunrise time
     pub sunrise: NaiveTime,
@@ -85,10 +611,121 @@ pub struct Weather {
     /// Hourly forecasts for today
     #[serde(default)]
     pub hourly: Vec<HourlyForecast>,
+    /// Hourly forecasts for tomorrow, for finding the next good window once
+    /// today has none
+    #[serde(default)]
+    pub tomorrow: Vec<HourlyForecast>,
+    /// Provider this data came from
+    #[serde(default)]
+    pub source: WeatherProvider,
+    /// Problems found in the provider's response that were worked around
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parse_warnings: Vec<String>,
+}
+
+/// How many hours ahead temperature and wind trends look
+pub const TREND_HOURS: u8 = 3;
+
+/// Change in temperature (°C) below which it counts as steady
+const TEMPERATURE_TREND_THRESHOLD: f64 = 1.0;
+
+/// Change in wind speed (km/h) below which it counts as steady
+const WIND_TREND_THRESHOLD: f64 = 3.0;
+
+/// Which way a weather value is heading over the next few hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Steady,
+    Falling,
+}
+
+impl Trend {
+    /// Classifies the change from `now` to `later`, ignoring changes smaller
+    /// than `threshold`
+    fn between(now: f64, later: f64, threshold: f64) -> Self {
+        if later - now >= threshold {
+            Trend::Rising
+        } else if now - later >= threshold {
+            Trend::Falling
+        } else {
+            Trend::Steady
+        }
+    }
+
+    /// Arrow shown next to the value
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            Trend::Rising => "↑",
+            Trend::Steady => "→",
+            Trend::Falling => "↓",
+        }
+    }
+}
+
+impl Weather {
+    /// Finer-grained description of the current weather from its WMO code,
+    /// e.g. "Light drizzle", falling back to the condition's label
+    pub fn description(&self) -> &'static str {
+        self.weather_code
+            .and_then(wmo::description)
+            .unwrap_or_else(|| self.condition.label())
+    }
+
+    /// Where the temperature is heading over the next `TREND_HOURS` hours
+    ///
+    /// `None` when the hourly forecast has nothing after `current_hour`.
+    pub fn temperature_trend(&self, current_hour: u8) -> Option<Trend> {
+        let later = self.forecast_ahead(current_hour)?;
+        Some(Trend::between(
+            self.temperature,
+            later.temperature,
+            TEMPERATURE_TREND_THRESHOLD,
+        ))
+    }
+
+    /// Where the wind speed is heading over the next `TREND_HOURS` hours
+    ///
+    /// `None` when the hourly forecast has nothing after `current_hour`.
+    pub fn wind_trend(&self, current_hour: u8) -> Option<Trend> {
+        let later = self.forecast_ahead(current_hour)?;
+        Some(Trend::between(self.wind, later.wind, WIND_TREND_THRESHOLD))
+    }
+
+    /// The latest forecast hour at most `TREND_HOURS` after `current_hour`
+    ///
+    /// Late in the day the hourly forecast ends sooner, so a shorter look
+    /// ahead is used.
+    fn forecast_ahead(&self, current_hour: u8) -> Option<&HourlyForecast> {
+        self.hourly
+            .iter()
+            .filter(|f| f.hour > current_hour && f.hour <= current_hour + TREND_HOURS)
+            .max_by_key(|f| f.hour)
+    }
+}
+
+/// Weather data providers the app can fetch from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum WeatherProvider {
+    /// Open-Meteo (primary)
+    #[default]
+    OpenMeteo,
+    /// MET Norway Locationforecast (fallback)
+    MetNorway,
+}
+
+impl WeatherProvider {
+    /// Returns the display name for the provider
+    pub fn name(&self) -> &'static str {
+        match self {
+            WeatherProvider::OpenMeteo => "Open-Meteo",
+            WeatherProvider::MetNorway => "MET Norway",
+        }
+    }
 }
 
 /// Types of weather conditions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum WeatherCondition {
     Clear,
     PartlyCloudy,
@@ -100,8 +737,24 @@ pub enum WeatherCondition {
     Fog,
 }
 
+impl WeatherCondition {
+    /// Display label, e.g. "Partly cloudy"
+    pub fn label(&self) -> &'static str {
+        match self {
+            WeatherCondition::Clear => "Clear",
+            WeatherCondition::PartlyCloudy => "Partly cloudy",
+            WeatherCondition::Cloudy => "Cloudy",
+            WeatherCondition::Rain => "Rain",
+            WeatherCondition::Showers => "Showers",
+            WeatherCondition::Thunderstorm => "Thunderstorm",
+            WeatherCondition::Snow => "Snow",
+            WeatherCondition::Fog => "Fog",
+        }
+    }
+}
+
 /// Tide information including current state and upcoming events
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TideInfo {
     /// Current tide height in meters
     pub current_height: f64,
@@ -116,7 +769,7 @@ pub struct TideInfo {
 }
 
 /// A specific tide event (high or low tide)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TideEvent {
     /// Time of the tide event in local timezone
     pub time: DateTime<Local>,
@@ -125,7 +778,7 @@ pub struct TideEvent {
 }
 
 /// Current state of the tide
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum TideState {
     Rising,
     Falling,
@@ -134,15 +787,76 @@ pub enum TideState {
 }
 
 impl TideInfo {
-    /// Generates estimated tide heights for hours 6am-9pm (16 hours)
+    /// Describes the tide in words, for plain mode and screen readers
+    ///
+    /// e.g. "Rising from 1.2m to 3.8m between 14:00 and 20:15, high tide
+    /// 20:15, then low tide 0.9m at 02:30".
+    pub fn summary(&self, now: DateTime<Local>) -> String {
+        let time = |event: &TideEvent| format_time(&event.time);
+
+        match self.tide_state {
+            TideState::Rising | TideState::Falling => {
+                let (verb, toward, kind, after, after_kind) =
+                    if self.tide_state == TideState::Rising {
+                        ("Rising", &self.next_high, "high", &self.next_low, "low")
+                    } else {
+                        ("Falling", &self.next_low, "low", &self.next_high, "high")
+                    };
+                let Some(toward) = toward else {
+                    return format!("{}, now {:.1}m", verb, self.current_height);
+                };
+
+                let mut text = format!(
+                    "{} from {:.1}m to {:.1}m between {} and {}, {} tide {}",
+                    verb,
+                    self.current_height,
+                    toward.height,
+                    format_time(&now),
+                    time(toward),
+                    kind,
+                    time(toward)
+                );
+                if let Some(after) = after.as_ref().filter(|a| a.time > toward.time) {
+                    text.push_str(&format!(
+                        ", then {} tide {:.1}m at {}",
+                        after_kind,
+                        after.height,
+                        time(after)
+                    ));
+                }
+                text
+            }
+            TideState::High | TideState::Low => {
+                let (label, next, next_kind) = if self.tide_state == TideState::High {
+                    ("High", &self.next_low, "low")
+                } else {
+                    ("Low", &self.next_high, "high")
+                };
+                let mut text = format!("{} tide now at {:.1}m", label, self.current_height);
+                if let Some(next) = next {
+                    text.push_str(&format!(
+                        ", {} tide {:.1}m at {}",
+                        next_kind,
+                        next.height,
+                        time(next)
+                    ));
+                }
+                text
+            }
+        }
+    }
+
+    /// Generates estimated tide heights for the configured hour range
     ///
     /// Uses a simplified sinusoidal tide model based on the next high/low events.
-    /// Returns a vector of 16 heights, one per hour from 6am to 9pm.
+    /// Returns one height per hour of `config::hour_range()` (16 heights, 6am
+    /// to 9pm, by default).
     ///
     /// # Arguments
     /// * `max_height` - Maximum tide height for the region (e.g., 4.8m for Vancouver)
     pub fn hourly_heights(&self, max_height: f64) -> Vec<f64> {
-        let mut heights = Vec::with_capacity(16);
+        let (start_hour, end_hour) = crate::config::hour_range();
+        let mut heights = Vec::with_capacity((end_hour - start_hour + 1) as usize);
 
         // Get phase from next_high time if available
         let phase = self
@@ -151,7 +865,7 @@ impl TideInfo {
             .map(|h| h.time.hour() as f64)
             .unwrap_or(12.0);
 
-        for hour in 6..=21 {
+        for hour in start_hour..=end_hour {
             // Simple sine wave approximation (two tides per day)
             let t = (hour as f64 - phase) * std::f64::consts::PI / 6.0;
             let height = (max_height / 2.0) * (1.0 + t.cos());
@@ -162,8 +876,92 @@ impl TideInfo {
     }
 }
 
+/// A sector of wind directions the beach is partly sheltered from
+///
+/// Wind blowing from between `direction_from` and `direction_to` (degrees
+/// clockwise from north) is scaled by `factor` on the sand.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WindShelter {
+    /// Start of the sector in degrees (0 = from the north, 270 = westerly)
+    pub direction_from: f64,
+    /// End of the sector in degrees
+    pub direction_to: f64,
+    /// Fraction of the forecast wind felt on the beach (0.0-1.0)
+    pub factor: f64,
+}
+
+/// Per-beach description of which wind directions terrain and buildings block
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WindExposure {
+    /// Sheltered sectors; directions outside them are fully exposed
+    pub shelters: Vec<WindShelter>,
+}
+
+impl WindExposure {
+    /// Fraction of the wind felt on the beach when it blows from `direction`
+    pub fn factor_for(&self, direction: f64) -> f64 {
+        let direction = direction.rem_euclid(360.0);
+        self.shelters
+            .iter()
+            .filter(|s| {
+                if s.direction_from <= s.direction_to {
+                    direction >= s.direction_from && direction <= s.direction_to
+                } else {
+                    // Sector wraps through north
+                    direction >= s.direction_from || direction <= s.direction_to
+                }
+            })
+            .map(|s| s.factor)
+            .fold(1.0, f64::min)
+    }
+}
+
+/// Wave height (m) the wind has raised below which the water counts as calm
+const CALM_CHOP_M: f64 = 0.15;
+
+/// Wave height (m) at which the wind has raised whitecaps
+const WHITECAP_CHOP_M: f64 = 0.5;
+
+/// How choppy the wind has made the water in front of a beach
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chop {
+    Calm,
+    Light,
+    Whitecaps,
+}
+
+impl Chop {
+    /// Estimates the chop from the wind speed (km/h) over the water and the
+    /// fetch (km) it has blown across
+    ///
+    /// Uses the fetch-limited wind-sea height `0.0016 * U * sqrt(F / g)`
+    /// (metres, with U in m/s and F in metres), which assumes the wind has
+    /// blown long enough to build the sea; a gust that's just arrived
+    /// overstates it.
+    pub fn estimate(wind_kmh: f64, fetch_km: f64) -> Self {
+        let speed = wind_kmh.max(0.0) / 3.6;
+        let height = 0.0016 * speed * (fetch_km.max(0.0) * 1000.0 / 9.81).sqrt();
+        if height < CALM_CHOP_M {
+            Chop::Calm
+        } else if height < WHITECAP_CHOP_M {
+            Chop::Light
+        } else {
+            Chop::Whitecaps
+        }
+    }
+
+    /// Label shown in the weather section
+    pub fn label(&self) -> &'static str {
+        match self {
+            Chop::Calm => "calm",
+            Chop::Light => "light chop",
+            Chop::Whitecaps => "whitecaps",
+        }
+    }
+}
+
 /// Water quality information from monitoring stations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct WaterQuality {
     /// Current water quality status
     pub status: WaterStatus,
@@ -175,6 +973,48 @@ pub struct WaterQuality {
     pub advisory_reason: Option<String>,
     /// When this data was fetched
     pub fetched_at: DateTime<Utc>,
+    /// The monitoring station, when it had no recent samples to report
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_samples_station: Option<String>,
+    /// How often the station has been sampled recently, when the source
+    /// returned its sample history
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<SamplingHistory>,
+}
+
+/// Days of sample history `SamplingHistory` looks back over
+pub const SAMPLING_WINDOW_DAYS: i64 = 30;
+
+/// How often a water quality station has been sampled recently
+///
+/// The status comes from the latest sample alone; this tells users how much
+/// data sits behind it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SamplingHistory {
+    /// Samples taken in the last `SAMPLING_WINDOW_DAYS` days
+    pub recent_samples: u32,
+    /// Weekdays the station is usually sampled on, empty without a clear pattern
+    pub usual_days: Vec<Weekday>,
+    /// Days the station usually goes between samples, if there are enough
+    /// samples to tell
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cadence_days: Option<u32>,
+}
+
+impl SamplingHistory {
+    /// Describes the sampling, e.g. "8 samples in 30 days, usually Mon/Wed"
+    pub fn summary(&self) -> String {
+        let plural = if self.recent_samples == 1 { "" } else { "s" };
+        let mut text = format!(
+            "{} sample{} in {} days",
+            self.recent_samples, plural, SAMPLING_WINDOW_DAYS
+        );
+        if !self.usual_days.is_empty() {
+            let days: Vec<String> = self.usual_days.iter().map(|d| d.to_string()).collect();
+            text.push_str(&format!(", usually {}", days.join("/")));
+        }
+        text
+    }
 }
 
 impl WaterQuality {
@@ -186,13 +1026,32 @@ impl WaterQuality {
     }
 
     /// Returns true if the water quality data is very stale (sample > 7 days old)
-    #[allow(dead_code)]
     pub fn is_very_stale(&self) -> bool {
         let today = chrono::Local::now().date_naive();
         let days_old = (today - self.sample_date).num_days();
         days_old > 7
     }
 
+    /// Returns true if the station has missed its usual sampling: the
+    /// latest sample is more than twice its cadence old and at least two
+    /// days late
+    ///
+    /// Unlike staleness this follows the station's own rhythm, so a station
+    /// sampled weekly isn't flagged between visits. One quiet for longer than
+    /// `SAMPLING_WINDOW_DAYS` is taken to be out of season rather than down.
+    pub fn is_not_reporting(&self) -> bool {
+        self.not_reporting_on(chrono::Local::now().date_naive())
+    }
+
+    fn not_reporting_on(&self, today: NaiveDate) -> bool {
+        let Some(cadence) = self.sampling.as_ref().and_then(|s| s.cadence_days) else {
+            return false;
+        };
+        let cadence = i64::from(cadence);
+        let days_old = (today - self.sample_date).num_days();
+        days_old > (2 * cadence).max(cadence + 2) && days_old <= SAMPLING_WINDOW_DAYS
+    }
+
     /// Returns the effective status, downgrading to Unknown if stale
     pub fn effective_status(&self) -> WaterStatus {
         if self.is_stale() {
@@ -204,7 +1063,7 @@ impl WaterQuality {
 }
 
 /// Water quality status levels
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum WaterStatus {
     /// Safe for swimming
     Safe,
@@ -216,11 +1075,32 @@ pub enum WaterStatus {
     Unknown,
 }
 
-/// Combined beach conditions including all available data
+/// A beach closed by the Park Board, whatever the water quality says
 ///
-/// Note: This struct only implements `Serialize` (not `Deserialize`) because
-/// the Beach struct uses static string references.
-#[derive(Debug, Clone, Serialize)]
+/// Closures cover construction, oil sheens, spills and the like. A closed
+/// beach scores zero for every activity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Closure {
+    /// Why the beach is closed, as given in the advisory
+    pub reason: String,
+    /// Last day of the closure, if the advisory gives one
+    pub until: Option<NaiveDate>,
+    /// When this data was fetched
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl Closure {
+    /// Describes the closure, e.g. "Oil sheen on the water (until Jul 20)"
+    pub fn summary(&self) -> String {
+        match self.until {
+            Some(until) => format!("{} (until {})", self.reason, until.format("%b %-d")),
+            None => self.reason.clone(),
+        }
+    }
+}
+
+/// Combined beach conditions including all available data
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BeachConditions {
     /// The beach this data is for
     pub beach: Beach,
@@ -230,6 +1110,122 @@ pub struct BeachConditions {
     pub tides: Option<TideInfo>,
     /// Current water quality information, if available
     pub water_quality: Option<WaterQuality>,
+    /// Closure announced by the Park Board, if the beach is closed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closure: Option<Closure>,
+    /// Wave forecast, fetched only for beaches open to swell; not cached
+    /// or recorded
+    #[serde(skip)]
+    pub waves: Option<WaveForecast>,
+    /// Hourly air quality along the shoreline; not cached or recorded
+    #[serde(skip)]
+    pub air_quality: Option<AirQuality>,
+    /// Seawall closure announced by the Park Board near the beach
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seawall_closure: Option<Closure>,
+}
+
+impl BeachConditions {
+    /// Current wind speed adjusted for the beach's shelter, if weather is loaded
+    pub fn effective_wind(&self) -> Option<f64> {
+        let weather = self.weather.as_ref()?;
+        Some(
+            self.beach
+                .effective_wind(weather.wind, weather.wind_direction),
+        )
+    }
+
+    /// Chop on the water right now from the forecast wind, which the
+    /// shelter on the sand does nothing for
+    ///
+    /// Needs the wind's direction to know how much open water it crossed.
+    pub fn chop(&self) -> Option<Chop> {
+        let weather = self.weather.as_ref()?;
+        let fetch = wind_fetch(&self.beach.id, weather.wind_direction?)?;
+        Some(Chop::estimate(weather.wind, fetch))
+    }
+
+    /// The next tide extreme falling within an hour of sunset, if any
+    ///
+    /// Compares each upcoming high and low against sunset on the same day of
+    /// the beach's clock. Needs both weather (for sunset) and tide data.
+    pub fn sunset_tide(&self) -> Option<SunsetTide> {
+        let sunset = self.weather.as_ref()?.sunset;
+        let tides = self.tides.as_ref()?;
+        let timezone = &self.beach.timezone;
+
+        [
+            (SunsetTideKind::GoldenLow, tides.next_low.as_ref()),
+            (SunsetTideKind::SunsetHigh, tides.next_high.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(kind, event)| {
+            let event = event?;
+            let time = event.time.with_timezone(&Utc);
+            let local_time = timezone.local(time);
+            let minutes_from_sunset =
+                (local_time - local_time.date().and_time(sunset)).num_minutes();
+            (minutes_from_sunset.abs() <= SUNSET_TIDE_WINDOW_MINUTES).then_some(SunsetTide {
+                kind,
+                time,
+                local_time,
+                height: event.height,
+                minutes_from_sunset,
+            })
+        })
+        .min_by_key(|tide| tide.time)
+    }
+
+    /// The next high tide if it's a king tide
+    ///
+    /// Lakes have no tide to speak of, so only ocean beaches get one.
+    pub fn king_tide(&self) -> Option<&TideEvent> {
+        if self.beach.water_body != WaterBody::Ocean {
+            return None;
+        }
+        self.tides
+            .as_ref()?
+            .next_high
+            .as_ref()
+            .filter(|high| is_king_tide(high.height))
+    }
+}
+
+/// How close to sunset, either side, a tide extreme must fall to be highlighted
+pub const SUNSET_TIDE_WINDOW_MINUTES: i64 = 60;
+
+/// Which tide extreme lines up with sunset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SunsetTideKind {
+    /// Low tide near sunset: wide wet sand reflecting the sky
+    GoldenLow,
+    /// High tide near sunset: water right up to the logs
+    SunsetHigh,
+}
+
+impl SunsetTideKind {
+    /// Display label, e.g. "Golden low tide"
+    pub fn label(&self) -> &'static str {
+        match self {
+            SunsetTideKind::GoldenLow => "Golden low tide",
+            SunsetTideKind::SunsetHigh => "Sunset high tide",
+        }
+    }
+}
+
+/// A tide extreme within an hour of sunset, worth a photo or a walk
+#[derive(Debug, Clone, PartialEq)]
+pub struct SunsetTide {
+    /// Whether it's the low or the high tide
+    pub kind: SunsetTideKind,
+    /// When the tide turns
+    pub time: DateTime<Utc>,
+    /// The same instant on the beach's clock
+    pub local_time: NaiveDateTime,
+    /// Tide height in meters
+    pub height: f64,
+    /// Minutes after sunset (negative when before)
+    pub minutes_from_sunset: i64,
 }
 
 #[cfg(test)]
@@ -237,20 +1233,279 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_beach_creation() {
-        let beach = Beach {
-            id: "kitsilano",
-            name: "Kitsilano Beach",
-            latitude: 49.2743,
-            longitude: -123.1544,
-            water_quality_id: Some("kits-001"),
+    fn test_migrate_cache_drops_entries_for_other_queries() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let closures = ClosuresClient::cache_key();
+        for key in [
+            "beach_closures",
+            "beach_closures.00000000",
+            closures.as_str(),
+            "water_quality_archive",
+            "pinned_plans",
+        ] {
+            cache.put(key, &1, chrono::Duration::hours(24)).unwrap();
+        }
+        // A water quality entry from before keys were versioned
+        let water = all_beaches()
+            .iter()
+            .find_map(|beach| beach.water_quality_id.as_deref())
+            .map(WaterQualityClient::cache_key)
+            .unwrap();
+        let legacy_water = water.rsplit_once('.').unwrap().0;
+        cache
+            .put(legacy_water, &1, chrono::Duration::hours(24))
+            .unwrap();
+
+        assert_eq!(migrate_cache(&cache), 3);
+
+        let cached = |key: &str| {
+            cache
+                .get_with_policy::<u8>(key, crate::cache::StalenessPolicy::AnyAge)
+                .data()
+        };
+        assert!(cached("beach_closures").is_none());
+        assert!(cached("beach_closures.00000000").is_none());
+        assert!(cached(legacy_water).is_none());
+        assert!(cached(&closures).is_some());
+        assert!(cached("water_quality_archive").is_some());
+        assert!(cached("pinned_plans").is_some());
+    }
+
+    fn weather_with_hourly(temperature: f64, wind: f64, hourly: &[(u8, f64, f64)]) -> Weather {
+        Weather {
+            temperature,
+            feels_like: temperature,
+            condition: WeatherCondition::Clear,
+            humidity: 50,
+            wind,
+            wind_direction: None,
+            uv: 3.0,
+            sunrise: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+            sunset: NaiveTime::from_hms_opt(20, 0, 0).unwrap(),
+            fetched_at: Utc::now(),
+            hourly: hourly
+                .iter()
+                .map(|&(hour, temperature, wind)| HourlyForecast {
+                    hour,
+                    temperature,
+                    feels_like: temperature,
+                    condition: WeatherCondition::Clear,
+                    wind,
+                    wind_direction: "W".to_string(),
+                    uv: 3.0,
+                    precipitation_chance: 0,
+                    dew_point: None,
+                    wind_gusts: None,
+                    cloud_cover: None,
+                    data_quality: DataQuality::Reported,
+                })
+                .collect(),
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+            weather_code: None,
+        }
+    }
+
+    #[test]
+    fn test_tide_summary_describes_the_tide_in_words() {
+        use chrono::TimeZone;
+
+        let at = |h: u32, m: u32| Local.with_ymd_and_hms(2026, 7, 15, h, m, 0).unwrap();
+        let mut tides = TideInfo {
+            current_height: 1.2,
+            tide_state: TideState::Rising,
+            next_high: Some(TideEvent {
+                time: at(20, 15),
+                height: 3.8,
+            }),
+            next_low: Some(TideEvent {
+                time: at(23, 40),
+                height: 0.9,
+            }),
+            fetched_at: Utc::now(),
         };
 
+        assert_eq!(
+            tides.summary(at(14, 0)),
+            "Rising from 1.2m to 3.8m between 2:00 PM and 8:15 PM, high tide 8:15 PM, \
+             then low tide 0.9m at 11:40 PM"
+        );
+
+        tides.tide_state = TideState::High;
+        assert_eq!(
+            tides.summary(at(14, 0)),
+            "High tide now at 1.2m, low tide 0.9m at 11:40 PM"
+        );
+
+        tides.tide_state = TideState::Falling;
+        tides.next_low = None;
+        assert_eq!(tides.summary(at(14, 0)), "Falling, now 1.2m");
+    }
+
+    #[test]
+    fn test_sampling_summary() {
+        let history = SamplingHistory {
+            recent_samples: 8,
+            usual_days: vec![Weekday::Mon, Weekday::Wed],
+            cadence_days: Some(3),
+        };
+        assert_eq!(history.summary(), "8 samples in 30 days, usually Mon/Wed");
+
+        let sparse = SamplingHistory {
+            recent_samples: 1,
+            usual_days: Vec::new(),
+            cadence_days: None,
+        };
+        assert_eq!(sparse.summary(), "1 sample in 30 days");
+    }
+
+    #[test]
+    fn test_trends_compare_now_with_three_hours_ahead() {
+        let weather = weather_with_hourly(
+            20.0,
+            10.0,
+            &[
+                (14, 20.0, 10.0),
+                (15, 25.0, 2.0),
+                (17, 22.5, 4.0),
+                (18, 10.0, 30.0),
+            ],
+        );
+
+        // 17:00 is three hours after 14:00; 18:00 is too far ahead
+        assert_eq!(weather.temperature_trend(14), Some(Trend::Rising));
+        assert_eq!(weather.wind_trend(14), Some(Trend::Falling));
+
+        // Small changes are steady
+        let steady = weather_with_hourly(20.0, 10.0, &[(15, 20.5, 12.0)]);
+        assert_eq!(steady.temperature_trend(14), Some(Trend::Steady));
+        assert_eq!(steady.wind_trend(14), Some(Trend::Steady));
+    }
+
+    #[test]
+    fn test_trends_need_a_later_forecast_hour() {
+        let weather = weather_with_hourly(20.0, 10.0, &[(21, 18.0, 10.0), (22, 16.0, 8.0)]);
+
+        // Late in the day a shorter look ahead is used
+        assert_eq!(weather.temperature_trend(21), Some(Trend::Falling));
+        assert_eq!(weather.temperature_trend(22), None);
+        assert_eq!(weather.wind_trend(23), None);
+        assert_eq!(Trend::Rising.arrow(), "↑");
+    }
+
+    #[test]
+    fn test_beach_creation() {
+        let beach = Beach::new(
+            BeachId::new("kitsilano"),
+            "Kitsilano Beach",
+            49.2743,
+            -123.1544,
+            Some("kits-001"),
+        );
+
         assert_eq!(beach.id, "kitsilano");
         assert_eq!(beach.name, "Kitsilano Beach");
         assert!((beach.latitude - 49.2743).abs() < 0.0001);
         assert!((beach.longitude - (-123.1544)).abs() < 0.0001);
-        assert_eq!(beach.water_quality_id, Some("kits-001"));
+        assert_eq!(beach.water_quality_id.as_deref(), Some("kits-001"));
+    }
+
+    #[test]
+    fn test_beach_serialization_roundtrip() {
+        let beach = Beach::new(
+            BeachId::new("kitsilano"),
+            "Kitsilano Beach",
+            49.2743,
+            -123.1544,
+            Some("kitsilano-beach"),
+        );
+
+        let json = serde_json::to_string(&beach).expect("Failed to serialize Beach");
+        assert!(
+            json.contains(r#""id":"kitsilano""#),
+            "ID should serialize as a plain string"
+        );
+
+        let deserialized: Beach = serde_json::from_str(&json).expect("Failed to deserialize Beach");
+        assert_eq!(deserialized, beach);
+    }
+
+    #[test]
+    fn test_shade_profile_blocks_low_sun_in_sector() {
+        let profile = ShadeProfile {
+            obstructions: vec![ShadeObstruction {
+                azimuth_from: 200.0,
+                azimuth_to: 300.0,
+                elevation: 15.0,
+            }],
+        };
+
+        assert!(
+            profile.blocks(250.0, 10.0),
+            "Low sun behind trees is shaded"
+        );
+        assert!(
+            !profile.blocks(250.0, 20.0),
+            "Sun above trees is not shaded"
+        );
+        assert!(
+            !profile.blocks(150.0, 10.0),
+            "Sun outside sector is not shaded"
+        );
+        assert!(!profile.blocks(250.0, -2.0), "Set sun is not shade");
+    }
+
+    #[test]
+    fn test_shade_profile_sector_wraps_through_north() {
+        let profile = ShadeProfile {
+            obstructions: vec![ShadeObstruction {
+                azimuth_from: 330.0,
+                azimuth_to: 30.0,
+                elevation: 10.0,
+            }],
+        };
+
+        assert!(profile.blocks(350.0, 5.0));
+        assert!(profile.blocks(10.0, 5.0));
+        assert!(!profile.blocks(90.0, 5.0));
+    }
+
+    #[test]
+    fn test_beach_id_compares_with_str() {
+        let id = BeachId::new("english-bay");
+        assert_eq!(id, "english-bay");
+        assert_eq!(id.as_str(), "english-bay");
+        assert_eq!(id.to_string(), "english-bay");
+        assert_eq!("english-bay".parse::<BeachId>(), Ok(id));
+    }
+
+    #[test]
+    fn test_beach_id_rejects_anything_but_slugs() {
+        assert_eq!(
+            BeachId::parse("trout-lake-2").unwrap().cache_key("history"),
+            "history_trout-lake-2"
+        );
+        assert_eq!(BeachId::parse(""), Err(BeachIdError::Empty));
+        for typo in [
+            "Kitsilano",
+            "english bay",
+            "-jericho",
+            "wreck-",
+            "spanish--banks",
+        ] {
+            assert!(
+                matches!(BeachId::parse(typo), Err(BeachIdError::Invalid(_))),
+                "{}",
+                typo
+            );
+        }
+        assert!(serde_json::from_str::<BeachId>(r#""Third Beach""#).is_err());
+        assert_eq!(
+            serde_json::from_str::<BeachId>(r#""third""#).unwrap(),
+            "third"
+        );
     }
 
     #[test]
@@ -261,11 +1516,16 @@ mod tests {
             condition: WeatherCondition::PartlyCloudy,
             humidity: 65,
             wind: 12.5,
+            wind_direction: None,
             uv: 6.0,
             sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
             sunset: NaiveTime::from_hms_opt(21, 15, 0).unwrap(),
             fetched_at: Utc::now(),
             hourly: Vec::new(),
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+            weather_code: None,
         };
 
         // Serialize to JSON
@@ -389,6 +1649,8 @@ mod tests {
             sample_date: NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
             advisory_reason: None,
             fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
         };
 
         assert_eq!(water_quality.status, WaterStatus::Safe);
@@ -398,19 +1660,23 @@ mod tests {
 
     #[test]
     fn test_beach_conditions_creation() {
-        let beach = Beach {
-            id: "english-bay",
-            name: "English Bay Beach",
-            latitude: 49.2867,
-            longitude: -123.1422,
-            water_quality_id: None,
-        };
+        let beach = Beach::new(
+            BeachId::new("english-bay"),
+            "English Bay Beach",
+            49.2867,
+            -123.1422,
+            None,
+        );
 
         let conditions = BeachConditions {
             beach,
             weather: None,
             tides: None,
             water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
         };
 
         assert_eq!(conditions.beach.id, "english-bay");
@@ -419,6 +1685,59 @@ mod tests {
         assert!(conditions.water_quality.is_none());
     }
 
+    #[test]
+    fn test_beach_conditions_serialization_roundtrip() {
+        let beach = Beach::new(
+            BeachId::new("english-bay"),
+            "English Bay Beach",
+            49.2867,
+            -123.1422,
+            None,
+        );
+        let conditions = BeachConditions {
+            beach,
+            weather: None,
+            tides: Some(TideInfo {
+                current_height: 2.5,
+                tide_state: TideState::Rising,
+                next_high: Some(TideEvent {
+                    time: Local::now(),
+                    height: 4.2,
+                }),
+                next_low: None,
+                fetched_at: Utc::now(),
+            }),
+            water_quality: Some(WaterQuality {
+                status: WaterStatus::Safe,
+                ecoli_count: Some(20),
+                sample_date: NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
+                advisory_reason: None,
+                fetched_at: Utc::now(),
+                no_samples_station: None,
+                sampling: None,
+            }),
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        };
+
+        let json = serde_json::to_string(&conditions).expect("Failed to serialize conditions");
+        let deserialized: BeachConditions =
+            serde_json::from_str(&json).expect("Failed to deserialize conditions");
+
+        assert_eq!(deserialized.beach, conditions.beach);
+        assert!(deserialized.weather.is_none());
+        assert_eq!(
+            deserialized.tides.as_ref().map(|t| t.tide_state),
+            Some(TideState::Rising)
+        );
+        assert_eq!(
+            deserialized.water_quality.as_ref().map(|wq| wq.status),
+            Some(WaterStatus::Safe)
+        );
+    }
+
     #[test]
     fn test_water_quality_is_stale_after_48_hours() {
         let today = chrono::Local::now().date_naive();
@@ -429,6 +1748,8 @@ mod tests {
             sample_date: old_date,
             advisory_reason: None,
             fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
         };
 
         assert!(
@@ -437,6 +1758,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_station_not_reporting_follows_its_cadence() {
+        let today = NaiveDate::from_ymd_opt(2026, 7, 24).unwrap();
+        let sampled = |days_ago: i64, cadence_days: Option<u32>| WaterQuality {
+            status: WaterStatus::Safe,
+            ecoli_count: Some(50),
+            sample_date: today - chrono::Duration::days(days_ago),
+            advisory_reason: None,
+            fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: Some(SamplingHistory {
+                recent_samples: 4,
+                usual_days: Vec::new(),
+                cadence_days,
+            }),
+        };
+
+        // A weekly station is stale between visits but still reporting
+        assert!(!sampled(6, Some(7)).not_reporting_on(today));
+        assert!(!sampled(14, Some(7)).not_reporting_on(today));
+        assert!(sampled(15, Some(7)).not_reporting_on(today));
+        // A daily one is late after three days
+        assert!(!sampled(3, Some(1)).not_reporting_on(today));
+        assert!(sampled(4, Some(1)).not_reporting_on(today));
+        // Without a cadence, or long enough to be out of season, it's not an
+        // outage
+        assert!(!sampled(15, None).not_reporting_on(today));
+        assert!(!sampled(31, Some(7)).not_reporting_on(today));
+    }
+
     #[test]
     fn test_water_quality_not_stale_within_48_hours() {
         let today = chrono::Local::now().date_naive();
@@ -447,6 +1798,8 @@ mod tests {
             sample_date: recent_date,
             advisory_reason: None,
             fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
         };
 
         assert!(
@@ -465,6 +1818,8 @@ mod tests {
             sample_date: old_date,
             advisory_reason: None,
             fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
         };
 
         assert_eq!(
@@ -483,6 +1838,8 @@ mod tests {
             sample_date: today,
             advisory_reason: None,
             fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
         };
 
         assert_eq!(
@@ -547,6 +1904,10 @@ mod tests {
             wind_direction: "NW".to_string(),
             uv: 6.0,
             precipitation_chance: 20,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
         };
 
         assert_eq!(forecast.hour, 14);
@@ -559,6 +1920,38 @@ mod tests {
         assert_eq!(forecast.precipitation_chance, 20);
     }
 
+    #[test]
+    fn test_fog_likely_from_code_or_dew_point_spread() {
+        let mut forecast = HourlyForecast {
+            hour: 7,
+            temperature: 12.0,
+            feels_like: 12.0,
+            condition: WeatherCondition::Clear,
+            wind: 4.0,
+            wind_direction: "W".to_string(),
+            uv: 0.5,
+            precipitation_chance: 0,
+            dew_point: Some(11.0),
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        };
+        assert!(forecast.fog_likely());
+
+        // A breeze mixes the air before fog can settle
+        forecast.wind = 18.0;
+        assert!(!forecast.fog_likely());
+
+        forecast.wind = 4.0;
+        forecast.dew_point = Some(8.0);
+        assert!(!forecast.fog_likely());
+        forecast.dew_point = None;
+        assert!(!forecast.fog_likely());
+
+        forecast.condition = WeatherCondition::Fog;
+        assert!(forecast.fog_likely());
+    }
+
     #[test]
     fn test_hourly_forecast_serialization_roundtrip() {
         let forecast = HourlyForecast {
@@ -570,6 +1963,10 @@ mod tests {
             wind_direction: "E".to_string(),
             uv: 3.0,
             precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
         };
 
         // Serialize to JSON
@@ -601,6 +1998,10 @@ mod tests {
                 wind_direction: "N".to_string(),
                 uv: 5.0,
                 precipitation_chance: 0,
+                dew_point: None,
+                wind_gusts: None,
+                cloud_cover: None,
+                data_quality: DataQuality::Reported,
             },
             HourlyForecast {
                 hour: 11,
@@ -611,6 +2012,10 @@ mod tests {
                 wind_direction: "NE".to_string(),
                 uv: 6.0,
                 precipitation_chance: 10,
+                dew_point: None,
+                wind_gusts: None,
+                cloud_cover: None,
+                data_quality: DataQuality::Reported,
             },
         ];
 
@@ -620,11 +2025,16 @@ mod tests {
             condition: WeatherCondition::Clear,
             humidity: 60,
             wind: 10.0,
+            wind_direction: None,
             uv: 5.0,
             sunrise: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
             sunset: NaiveTime::from_hms_opt(20, 30, 0).unwrap(),
             fetched_at: Utc::now(),
             hourly: hourly_forecasts,
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+            weather_code: None,
         };
 
         assert_eq!(weather.hourly.len(), 2);
@@ -640,6 +2050,7 @@ mod tests {
             condition: WeatherCondition::PartlyCloudy,
             humidity: 65,
             wind: 12.5,
+            wind_direction: None,
             uv: 6.0,
             sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
             sunset: NaiveTime::from_hms_opt(21, 15, 0).unwrap(),
@@ -653,7 +2064,15 @@ mod tests {
                 wind_direction: "SW".to_string(),
                 uv: 7.0,
                 precipitation_chance: 5,
+                dew_point: None,
+                wind_gusts: None,
+                cloud_cover: None,
+                data_quality: DataQuality::Reported,
             }],
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+            weather_code: None,
         };
 
         // Serialize to JSON
@@ -693,4 +2112,109 @@ mod tests {
         assert!(weather.hourly.is_empty());
         assert!((weather.temperature - 22.5).abs() < 0.01);
     }
+
+    #[test]
+    fn test_sunset_tide_highlights_extremes_near_sunset() {
+        use chrono::TimeZone;
+
+        // Times on the beach's clock (PDT, UTC-7) on 2026-07-15
+        let pdt = |hour: u32, minute: u32| {
+            Utc.with_ymd_and_hms(2026, 7, 15, hour, minute, 0).unwrap() + chrono::Duration::hours(7)
+        };
+        let event = |at: DateTime<Utc>, height: f64| TideEvent {
+            time: at.with_timezone(&Local),
+            height,
+        };
+        let mut conditions = BeachConditions {
+            beach: beach::all_beaches()[0].clone(),
+            weather: Some(Weather {
+                temperature: 22.5,
+                feels_like: 24.0,
+                condition: WeatherCondition::Clear,
+                humidity: 65,
+                wind: 12.5,
+                wind_direction: None,
+                uv: 6.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 15, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
+            }),
+            tides: Some(TideInfo {
+                current_height: 2.5,
+                tide_state: TideState::Falling,
+                next_high: Some(event(pdt(14, 0), 4.2)),
+                next_low: Some(event(pdt(20, 45), 0.6)),
+                fetched_at: Utc::now(),
+            }),
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        };
+
+        let tide = conditions.sunset_tide().unwrap();
+        assert_eq!(tide.kind, SunsetTideKind::GoldenLow);
+        assert_eq!(tide.minutes_from_sunset, -30);
+        assert_eq!(tide.local_time.format("%H:%M").to_string(), "20:45");
+
+        // A low two hours before sunset doesn't count, but a high just after does
+        let tides = conditions.tides.as_mut().unwrap();
+        tides.next_low = Some(event(pdt(19, 0), 0.6));
+        tides.next_high = Some(event(pdt(22, 0), 4.4));
+        let tide = conditions.sunset_tide().unwrap();
+        assert_eq!(tide.kind, SunsetTideKind::SunsetHigh);
+        assert_eq!(tide.minutes_from_sunset, 45);
+
+        conditions.tides.as_mut().unwrap().next_high = None;
+        assert!(conditions.sunset_tide().is_none());
+    }
+
+    #[test]
+    fn test_king_tide_is_a_high_above_the_threshold_at_the_ocean() {
+        let high = |height: f64| TideEvent {
+            time: Local::now(),
+            height,
+        };
+        let mut conditions = BeachConditions {
+            beach: beach::all_beaches()[0].clone(),
+            weather: None,
+            tides: Some(TideInfo {
+                current_height: 2.5,
+                tide_state: TideState::Rising,
+                next_high: Some(high(4.8)),
+                next_low: None,
+                fetched_at: Utc::now(),
+            }),
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        };
+        assert_eq!(conditions.king_tide().map(|tide| tide.height), Some(4.8));
+
+        conditions.tides.as_mut().unwrap().next_high = Some(high(4.2));
+        assert!(conditions.king_tide().is_none());
+
+        conditions.tides.as_mut().unwrap().next_high = Some(high(4.8));
+        conditions.beach.water_body = WaterBody::Lake;
+        assert!(conditions.king_tide().is_none());
+    }
+
+    #[test]
+    fn test_chop_builds_with_wind_and_fetch() {
+        // 20 km/h up the strait whitecaps English Bay, but only chops the
+        // water across from the North Shore and leaves Trout Lake calm
+        assert_eq!(Chop::estimate(20.0, 40.0), Chop::Whitecaps);
+        assert_eq!(Chop::estimate(20.0, 5.0), Chop::Light);
+        assert_eq!(Chop::estimate(30.0, 0.4), Chop::Calm);
+        assert_eq!(Chop::estimate(5.0, 40.0), Chop::Calm);
+        assert_eq!(Chop::Light.label(), "light chop");
+    }
 }
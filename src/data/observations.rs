@@ -0,0 +1,439 @@
+//! Live wind observations from stations around the beaches
+//!
+//! Forecasts lag what's actually blowing, so refreshes also fetch the latest
+//! reports from nearby stations: METARs from the airports (aviationweather.gov)
+//! and the realtime feed of the Strait of Georgia weather buoys (NDBC, which
+//! relays Environment Canada's buoys). The detail view shows the nearest
+//! recent one next to the forecast wind and flags when they disagree.
+
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use reqwest::Client;
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::{recording, Beach};
+
+/// Base URL for the aviationweather.gov METAR API
+const METAR_BASE_URL: &str = "https://aviationweather.gov/api/data/metar";
+
+/// Base URL for NDBC's realtime buoy observations
+const BUOY_BASE_URL: &str = "https://www.ndbc.noaa.gov/data/realtime2";
+
+/// Kilometres per hour in a knot, METAR's wind unit
+const KMH_PER_KNOT: f64 = 1.852;
+
+/// Kilometres per hour in a metre per second, the buoys' wind unit
+const KMH_PER_METRE_PER_SECOND: f64 = 3.6;
+
+/// Farthest a station can be from a beach to stand in for its wind, in km
+pub const MAX_STATION_DISTANCE_KM: f64 = 50.0;
+
+/// Oldest an observation can be and still count as "now", in minutes
+pub const MAX_OBSERVATION_AGE_MINUTES: i64 = 90;
+
+/// How long fetched observations are reused, in minutes; refreshes fetch in
+/// batches of beaches, and stations only report every half hour or so
+const OBSERVATIONS_TTL_MINUTES: i64 = 10;
+
+/// Difference from the forecast wind, in km/h, that's flagged
+pub const WIND_DISCREPANCY_KMH: f64 = 10.0;
+
+/// Errors that can occur when fetching observations
+#[derive(Debug, Error)]
+pub enum ObservationError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The METAR response isn't the expected JSON
+    #[error("Failed to parse METARs: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    /// The buoy feed has no row with a wind reading
+    #[error("No wind reading from buoy {0}")]
+    NoReading(&'static str),
+}
+
+/// How a station reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StationKind {
+    /// An airport's METAR
+    Metar,
+    /// A weather buoy's realtime feed
+    Buoy,
+}
+
+/// A station reporting wind near the beaches
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObservationStation {
+    /// ICAO code for airports, WMO number for buoys
+    pub id: &'static str,
+    pub name: &'static str,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub kind: StationKind,
+}
+
+/// Stations observations are fetched from
+pub const STATIONS: &[ObservationStation] = &[
+    ObservationStation {
+        id: "CYHC",
+        name: "Vancouver Harbour",
+        latitude: 49.2944,
+        longitude: -123.1203,
+        kind: StationKind::Metar,
+    },
+    ObservationStation {
+        id: "CYVR",
+        name: "Vancouver Airport",
+        latitude: 49.1947,
+        longitude: -123.1839,
+        kind: StationKind::Metar,
+    },
+    ObservationStation {
+        id: "46304",
+        name: "English Bay buoy",
+        latitude: 49.3020,
+        longitude: -123.3550,
+        kind: StationKind::Buoy,
+    },
+    ObservationStation {
+        id: "46146",
+        name: "Halibut Bank",
+        latitude: 49.3400,
+        longitude: -123.7270,
+        kind: StationKind::Buoy,
+    },
+];
+
+/// The wind a station last reported
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindObservation {
+    pub station: &'static ObservationStation,
+    /// Sustained wind in km/h
+    pub speed: f64,
+    /// Direction the wind blows from, in degrees; `None` when variable
+    pub direction: Option<f64>,
+    /// Gusts in km/h, when reported
+    pub gusts: Option<f64>,
+    pub observed_at: DateTime<Utc>,
+}
+
+impl WindObservation {
+    /// Whether the observation is far enough from the forecast wind to flag
+    pub fn disagrees_with(&self, forecast: f64) -> bool {
+        (self.speed - forecast).abs() >= WIND_DISCREPANCY_KMH
+    }
+}
+
+/// The most recent observation from the station nearest a beach, if one is
+/// close enough and reported recently enough to go by
+pub fn nearest_observation<'a>(
+    observations: &'a [WindObservation],
+    beach: &Beach,
+    now: DateTime<Utc>,
+) -> Option<&'a WindObservation> {
+    observations
+        .iter()
+        .filter(|observation| {
+            now - observation.observed_at <= Duration::minutes(MAX_OBSERVATION_AGE_MINUTES)
+        })
+        .map(|observation| (observation, distance_km(observation.station, beach)))
+        .filter(|&(_, distance)| distance <= MAX_STATION_DISTANCE_KM)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(observation, _)| observation)
+}
+
+/// Distance from a station to a beach as the crow flies; flat-earth, which
+/// is plenty at this range
+fn distance_km(station: &ObservationStation, beach: &Beach) -> f64 {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+    let mean_latitude = ((station.latitude + beach.latitude) / 2.0).to_radians();
+    let east = (beach.longitude - station.longitude).to_radians() * mean_latitude.cos();
+    let north = (beach.latitude - station.latitude).to_radians();
+    EARTH_RADIUS_KM * east.hypot(north)
+}
+
+/// Observations fetched, and when
+type LastFetch = Option<(DateTime<Utc>, Vec<WindObservation>)>;
+
+/// Client for the METAR and buoy feeds
+#[derive(Debug, Clone)]
+pub struct ObservationsClient {
+    client: Client,
+    metar_base_url: String,
+    buoy_base_url: String,
+    /// The last observations fetched and when, shared by clones
+    last: Arc<Mutex<LastFetch>>,
+}
+
+impl Default for ObservationsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ObservationsClient {
+    /// Creates a client for the public feeds
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+            metar_base_url: METAR_BASE_URL.to_string(),
+            buoy_base_url: BUOY_BASE_URL.to_string(),
+            last: Arc::default(),
+        }
+    }
+
+    /// Points the client at other servers for METARs and buoy feeds
+    #[allow(dead_code)]
+    pub fn with_base_urls(
+        mut self,
+        metar_base_url: impl Into<String>,
+        buoy_base_url: impl Into<String>,
+    ) -> Self {
+        self.metar_base_url = metar_base_url.into();
+        self.buoy_base_url = buoy_base_url.into();
+        self
+    }
+
+    /// Fetches the latest wind from every station; stations that fail are
+    /// left out, and it's an error only if none answered
+    ///
+    /// Observations fetched in the last few minutes are reused.
+    pub async fn fetch_observations(&self) -> Result<Vec<WindObservation>, ObservationError> {
+        let now = Utc::now();
+        if let Some((fetched_at, observations)) = &*self.last.lock().unwrap() {
+            if now - *fetched_at < Duration::minutes(OBSERVATIONS_TTL_MINUTES) {
+                return Ok(observations.clone());
+            }
+        }
+
+        let buoys = STATIONS
+            .iter()
+            .filter(|station| station.kind == StationKind::Buoy)
+            .map(|station| self.fetch_buoy(station));
+        let (metars, buoys) =
+            futures::future::join(self.fetch_metars(), futures::future::join_all(buoys)).await;
+
+        let mut observations = Vec::new();
+        let mut error = None;
+        let buoys = buoys
+            .into_iter()
+            .map(|buoy| buoy.map(|reading| vec![reading]));
+        for result in std::iter::once(metars).chain(buoys) {
+            match result {
+                Ok(reported) => observations.extend(reported),
+                Err(e) => error = Some(e),
+            }
+        }
+        match error {
+            Some(e) if observations.is_empty() => Err(e),
+            _ => {
+                *self.last.lock().unwrap() = Some((now, observations.clone()));
+                Ok(observations)
+            }
+        }
+    }
+
+    /// Fetches the latest METAR from each airport station, in one request
+    async fn fetch_metars(&self) -> Result<Vec<WindObservation>, ObservationError> {
+        let ids: Vec<&str> = STATIONS
+            .iter()
+            .filter(|station| station.kind == StationKind::Metar)
+            .map(|station| station.id)
+            .collect();
+        let url = format!("{}?ids={}&format=json", self.metar_base_url, ids.join(","));
+        let text = recording::send(self.client.get(&url))
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        parse_metars(&text)
+    }
+
+    /// Fetches a buoy's latest wind reading
+    async fn fetch_buoy(
+        &self,
+        station: &'static ObservationStation,
+    ) -> Result<WindObservation, ObservationError> {
+        let url = format!("{}/{}.txt", self.buoy_base_url, station.id);
+        let text = recording::send(self.client.get(&url))
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        parse_buoy(station, &text)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Metar {
+    #[serde(rename = "icaoId")]
+    icao_id: String,
+    /// Unix time of the observation
+    #[serde(rename = "obsTime")]
+    obs_time: i64,
+    /// Degrees, or "VRB" for variable
+    #[serde(default)]
+    wdir: Option<serde_json::Value>,
+    /// Knots
+    #[serde(default)]
+    wspd: Option<f64>,
+    /// Knots
+    #[serde(default)]
+    wgst: Option<f64>,
+}
+
+/// Reads the wind from a list of METARs, skipping unknown stations and
+/// reports without a wind speed
+fn parse_metars(text: &str) -> Result<Vec<WindObservation>, ObservationError> {
+    let metars: Vec<Metar> = serde_json::from_str(text)?;
+    Ok(metars
+        .into_iter()
+        .filter_map(|metar| {
+            let station = STATIONS
+                .iter()
+                .find(|station| station.id == metar.icao_id)?;
+            Some(WindObservation {
+                station,
+                speed: metar.wspd? * KMH_PER_KNOT,
+                direction: metar.wdir.as_ref().and_then(serde_json::Value::as_f64),
+                gusts: metar.wgst.map(|gusts| gusts * KMH_PER_KNOT),
+                observed_at: Utc.timestamp_opt(metar.obs_time, 0).single()?,
+            })
+        })
+        .collect())
+}
+
+/// Reads the latest wind from a buoy's realtime feed: whitespace-separated
+/// columns under `#` headers, newest row first, in UTC, with `MM` for
+/// missing values
+fn parse_buoy(
+    station: &'static ObservationStation,
+    text: &str,
+) -> Result<WindObservation, ObservationError> {
+    let mut lines = text.lines();
+    let header: Vec<&str> = lines
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches('#')
+        .split_whitespace()
+        .collect();
+    let column = |name: &str| header.iter().position(|&column| column == name);
+    let (Some(direction), Some(speed), Some(gusts)) =
+        (column("WDIR"), column("WSPD"), column("GST"))
+    else {
+        return Err(ObservationError::NoReading(station.id));
+    };
+
+    lines
+        .filter(|line| !line.starts_with('#'))
+        .find_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let number = |i: usize| fields.get(i)?.parse::<f64>().ok();
+            let date_field = |i: usize| fields.get(i)?.parse::<u32>().ok();
+            let observed_at = Utc
+                .with_ymd_and_hms(
+                    date_field(0)? as i32,
+                    date_field(1)?,
+                    date_field(2)?,
+                    date_field(3)?,
+                    date_field(4)?,
+                    0,
+                )
+                .single()?;
+            Some(WindObservation {
+                station,
+                speed: number(speed)? * KMH_PER_METRE_PER_SECOND,
+                direction: number(direction),
+                gusts: number(gusts).map(|gusts| gusts * KMH_PER_METRE_PER_SECOND),
+                observed_at,
+            })
+        })
+        .ok_or(ObservationError::NoReading(station.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::get_beach_by_id;
+
+    fn station(id: &str) -> &'static ObservationStation {
+        STATIONS.iter().find(|station| station.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_parse_metars_converts_knots() {
+        let observations = parse_metars(
+            r#"[
+                {"icaoId": "CYVR", "obsTime": 1721052000, "wdir": 270, "wspd": 10, "wgst": 18},
+                {"icaoId": "CYHC", "obsTime": 1721052000, "wdir": "VRB", "wspd": 3},
+                {"icaoId": "KSEA", "obsTime": 1721052000, "wdir": 180, "wspd": 5}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(observations.len(), 2);
+        assert_eq!(observations[0].station.name, "Vancouver Airport");
+        assert!((observations[0].speed - 18.52).abs() < 0.01);
+        assert_eq!(observations[0].direction, Some(270.0));
+        assert!((observations[0].gusts.unwrap() - 33.336).abs() < 0.01);
+        assert_eq!(
+            observations[0].observed_at,
+            Utc.with_ymd_and_hms(2024, 7, 15, 14, 0, 0).unwrap()
+        );
+        // Variable wind has no direction
+        assert_eq!(observations[1].direction, None);
+        assert!(parse_metars(r#"{"error": true}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_buoy_takes_the_newest_row_with_wind() {
+        let text = "\
+#YY  MM DD hh mm WDIR WSPD GST  WVHT   DPD   APD MWD   PRES  ATMP  WTMP  DEWP  VIS PTDY  TIDE
+#yr  mo dy hr mn degT m/s  m/s     m   sec   sec degT   hPa  degC  degC  degC  nmi  hPa    ft
+2024 07 15 14 10  MM   MM   MM   0.6    MM    MM  MM 1013.2  18.0  17.5    MM   MM   MM    MM
+2024 07 15 14 00 290  6.0  8.0   0.6    MM    MM  MM 1013.2  18.0  17.5    MM   MM   MM    MM
+2024 07 15 13 50 280  5.0  7.0   0.5    MM    MM  MM 1013.3  18.0  17.5    MM   MM   MM    MM
+";
+        let observation = parse_buoy(station("46146"), text).unwrap();
+
+        assert_eq!(observation.station.name, "Halibut Bank");
+        assert!((observation.speed - 21.6).abs() < 0.01);
+        assert_eq!(observation.direction, Some(290.0));
+        assert!((observation.gusts.unwrap() - 28.8).abs() < 0.01);
+        assert_eq!(
+            observation.observed_at,
+            Utc.with_ymd_and_hms(2024, 7, 15, 14, 0, 0).unwrap()
+        );
+        assert!(parse_buoy(station("46146"), "Not found").is_err());
+    }
+
+    #[test]
+    fn test_nearest_observation_skips_stale_and_distant_stations() {
+        let now = Utc.with_ymd_and_hms(2024, 7, 15, 14, 30, 0).unwrap();
+        let observation = |id: &str, minutes_ago: i64| WindObservation {
+            station: station(id),
+            speed: 20.0,
+            direction: Some(270.0),
+            gusts: None,
+            observed_at: now - Duration::minutes(minutes_ago),
+        };
+        let kitsilano = get_beach_by_id("kitsilano").unwrap();
+
+        let observations = [observation("46146", 10), observation("CYHC", 20)];
+        let nearest = nearest_observation(&observations, kitsilano, now).unwrap();
+        assert_eq!(nearest.station.id, "CYHC");
+
+        // The harbour's report is too old, so the buoy stands in
+        let observations = [observation("46146", 10), observation("CYHC", 120)];
+        let nearest = nearest_observation(&observations, kitsilano, now).unwrap();
+        assert_eq!(nearest.station.id, "46146");
+        assert!(nearest.disagrees_with(8.0));
+        assert!(!nearest.disagrees_with(15.0));
+
+        assert!(nearest_observation(&[], kitsilano, now).is_none());
+    }
+}
@@ -0,0 +1,151 @@
+//! Client-side rate limiting shared by the data clients
+//!
+//! Every client checks the same `RateLimiter` before sending a request, so a
+//! burst of refreshes can't exceed a host's budget however the requests are
+//! spread across clients. Requests over budget fail right away instead of
+//! waiting, letting the clients fall back to cached data or another provider.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use reqwest::Url;
+
+/// How many requests a host may receive within a time window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    /// Requests allowed per window
+    pub requests: usize,
+    /// Length of the sliding window
+    pub window: Duration,
+}
+
+impl Budget {
+    /// A budget of `requests` per minute
+    pub const fn per_minute(requests: usize) -> Self {
+        Self {
+            requests,
+            window: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Default budgets, well below each provider's published limits
+const DEFAULT_BUDGETS: [(&str, Budget); 3] = [
+    ("api.open-meteo.com", Budget::per_minute(60)),
+    ("api.met.no", Budget::per_minute(20)),
+    ("opendata.vancouver.ca", Budget::per_minute(30)),
+];
+
+/// Shared per-host request budgets
+///
+/// Cloning shares the same request history, so one limiter can be handed to
+/// every client. Hosts without a budget are not limited.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    budgets: HashMap<String, Budget>,
+    sent: Arc<Mutex<HashMap<String, VecDeque<Instant>>>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RateLimiter {
+    /// Creates a limiter with the default budget for each data provider
+    pub fn new() -> Self {
+        Self {
+            budgets: DEFAULT_BUDGETS
+                .iter()
+                .map(|(host, budget)| (host.to_string(), *budget))
+                .collect(),
+            sent: Arc::default(),
+        }
+    }
+
+    /// Sets the budget for a host, replacing any default
+    #[allow(dead_code)]
+    pub fn with_budget(mut self, host: impl Into<String>, budget: Budget) -> Self {
+        self.budgets.insert(host.into(), budget);
+        self
+    }
+
+    /// Takes a request from the budget of the URL's host
+    ///
+    /// Returns the host's name as the error if its budget is used up.
+    pub fn acquire(&self, url: &str) -> Result<(), String> {
+        let host = Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_default();
+        if self.acquire_at(&host, Instant::now()) {
+            Ok(())
+        } else {
+            Err(host)
+        }
+    }
+
+    /// Takes a request from the host's budget at the given instant, returning
+    /// false if the budget is used up
+    fn acquire_at(&self, host: &str, now: Instant) -> bool {
+        let Some(budget) = self.budgets.get(host) else {
+            return true;
+        };
+        let Ok(mut sent) = self.sent.lock() else {
+            return true;
+        };
+        let history = sent.entry(host.to_string()).or_default();
+        while history
+            .front()
+            .is_some_and(|&at| now.duration_since(at) >= budget.window)
+        {
+            history.pop_front();
+        }
+        if history.len() >= budget.requests {
+            return false;
+        }
+        history.push_back(now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_is_shared_between_clones_and_frees_up_over_time() {
+        let limiter = RateLimiter::new().with_budget("example.com", Budget::per_minute(2));
+        let clone = limiter.clone();
+        let start = Instant::now();
+
+        assert!(limiter.acquire_at("example.com", start));
+        assert!(clone.acquire_at("example.com", start + Duration::from_secs(10)));
+        assert!(!limiter.acquire_at("example.com", start + Duration::from_secs(20)));
+
+        // The first request leaves the window after a minute
+        assert!(clone.acquire_at("example.com", start + Duration::from_secs(60)));
+        assert!(!clone.acquire_at("example.com", start + Duration::from_secs(61)));
+    }
+
+    #[test]
+    fn test_hosts_have_separate_budgets() {
+        let limiter = RateLimiter::new()
+            .with_budget("a.example", Budget::per_minute(1))
+            .with_budget("b.example", Budget::per_minute(1));
+
+        assert!(limiter.acquire("https://a.example/forecast?x=1").is_ok());
+        assert_eq!(
+            limiter.acquire("https://a.example/other"),
+            Err("a.example".to_string())
+        );
+        assert!(limiter.acquire("https://b.example/").is_ok());
+
+        // Hosts without a budget are never limited
+        for _ in 0..100 {
+            assert!(limiter.acquire("https://unlimited.example/").is_ok());
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! Recording and replaying HTTP traffic for offline development
+//!
+//! With `--record-http DIR` every request the data clients send is written
+//! under DIR with the response that came back; with `--replay-http DIR` the
+//! same requests are answered from those recordings without touching the
+//! network, so the app can be worked on offline and CI runs the real
+//! request and parse paths against the same responses every time.
+//!
+//! A request is matched on its method, URL and body. Each recording is two
+//! files named after the host and a hash of those: `<name>.json` with the
+//! request and the response status and headers, and `<name>.body` with the
+//! response body as received, so recordings can be read and hand-edited. A
+//! request replay has no recording for gets a 502 Bad Gateway.
+//!
+//! The mode is installed once at startup with `set_mode`, and every client
+//! sends through `send`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_LENGTH, TRANSFER_ENCODING};
+use reqwest::{Request, RequestBuilder, Response, ResponseBuilderExt, StatusCode};
+use serde::{Deserialize, Serialize};
+
+/// What happens to HTTP traffic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HttpRecording {
+    /// Requests go out as usual and each exchange is saved under the
+    /// directory (`--record-http`)
+    Record(PathBuf),
+    /// Requests are answered from the recordings under the directory
+    /// (`--replay-http`)
+    Replay(PathBuf),
+}
+
+impl HttpRecording {
+    /// Mode for the `--record-http` and `--replay-http` flags, if either
+    /// was given
+    pub fn from_flags(record: Option<PathBuf>, replay: Option<PathBuf>) -> Option<Self> {
+        record
+            .map(HttpRecording::Record)
+            .or(replay.map(HttpRecording::Replay))
+    }
+}
+
+/// Mode installed at startup
+static MODE: OnceLock<HttpRecording> = OnceLock::new();
+
+/// Installs the recording mode, returning false if one was already
+/// installed
+pub fn set_mode(mode: HttpRecording) -> bool {
+    MODE.set(mode).is_ok()
+}
+
+/// One request and the response it got, as kept in `<name>.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Exchange {
+    method: String,
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    request_body: Option<String>,
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+}
+
+/// Sends a request, or records or replays it under the installed mode
+pub async fn send(request: RequestBuilder) -> reqwest::Result<Response> {
+    let Some(mode) = MODE.get() else {
+        return request.send().await;
+    };
+    let (client, request) = request.build_split();
+    let request = request?;
+    match mode {
+        HttpRecording::Replay(dir) => Ok(replay(dir, &request)),
+        HttpRecording::Record(dir) => {
+            // A streaming body can't be kept; none of the clients send one
+            let Some(sent) = request.try_clone() else {
+                return client.execute(request).await;
+            };
+            let response = client.execute(sent).await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+            // A recording that can't be written shouldn't fail the fetch
+            let _ = save(dir, &request, status, &headers, &body);
+            Ok(build_response(&request, status, &headers, body))
+        }
+    }
+}
+
+/// The recorded response to a request, or a 502 if there's none
+fn replay(dir: &Path, request: &Request) -> Response {
+    match load(dir, request) {
+        Ok((exchange, body)) => {
+            let status =
+                StatusCode::from_u16(exchange.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            let headers = exchange
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((
+                        HeaderName::from_bytes(name.as_bytes()).ok()?,
+                        HeaderValue::from_str(value).ok()?,
+                    ))
+                })
+                .collect();
+            build_response(request, status, &headers, body)
+        }
+        Err(_) => build_response(
+            request,
+            StatusCode::BAD_GATEWAY,
+            &HeaderMap::new(),
+            format!("No recording for {} {}", request.method(), request.url()).into_bytes(),
+        ),
+    }
+}
+
+/// A response to `request` as if it came off the network
+fn build_response(
+    request: &Request,
+    status: StatusCode,
+    headers: &HeaderMap,
+    body: Vec<u8>,
+) -> Response {
+    let mut builder = http::Response::builder()
+        .status(status)
+        .url(request.url().clone());
+    for (name, value) in headers {
+        builder = builder.header(name, value);
+    }
+    builder
+        .body(body)
+        .map(Response::from)
+        .unwrap_or_else(|_| Response::from(http::Response::new(Vec::new())))
+}
+
+/// Writes an exchange's two files
+fn save(
+    dir: &Path,
+    request: &Request,
+    status: StatusCode,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let exchange = Exchange {
+        method: request.method().to_string(),
+        url: request.url().to_string(),
+        request_body: request_body(request).map(|body| String::from_utf8_lossy(body).into_owned()),
+        status: status.as_u16(),
+        // The body's length is left out so the body can be edited
+        headers: headers
+            .iter()
+            .filter(|(name, _)| *name != CONTENT_LENGTH && *name != TRANSFER_ENCODING)
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect(),
+    };
+    let stem = file_stem(request);
+    // The body goes first so a recording with its .json is always complete
+    fs::write(dir.join(format!("{}.body", stem)), body)?;
+    fs::write(
+        dir.join(format!("{}.json", stem)),
+        serde_json::to_string_pretty(&exchange)?,
+    )
+}
+
+/// Reads an exchange's two files
+fn load(dir: &Path, request: &Request) -> io::Result<(Exchange, Vec<u8>)> {
+    let stem = file_stem(request);
+    let exchange = serde_json::from_str(&fs::read_to_string(dir.join(format!("{}.json", stem)))?)?;
+    let body = fs::read(dir.join(format!("{}.body", stem)))?;
+    Ok((exchange, body))
+}
+
+fn request_body(request: &Request) -> Option<&[u8]> {
+    request.body().and_then(|body| body.as_bytes())
+}
+
+/// File name for a request's recording without the extension, e.g.
+/// "api.open-meteo.com-3f2a9c0d1e4b5a67"
+///
+/// The hash is FNV-1a rather than std's hasher, which isn't guaranteed to
+/// stay the same between Rust releases, so recordings outlive a toolchain
+/// update.
+fn file_stem(request: &Request) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let key = [
+        request.method().as_str().as_bytes(),
+        b" ",
+        request.url().as_str().as_bytes(),
+        b"\n",
+        request_body(request).unwrap_or_default(),
+    ];
+    for byte in key.concat() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!(
+        "{}-{:016x}",
+        request.url().host_str().unwrap_or("local"),
+        hash
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::{Client, Method};
+    use tempfile::TempDir;
+
+    fn request(url: &str) -> Request {
+        Request::new(Method::GET, url.parse().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_recorded_exchange_replays_for_the_same_request() {
+        let temp_dir = TempDir::new().unwrap();
+        let forecast = request("https://api.open-meteo.com/v1/forecast?latitude=49.27");
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type", HeaderValue::from_static("application/json"));
+        save(
+            temp_dir.path(),
+            &forecast,
+            StatusCode::OK,
+            &headers,
+            br#"{"current": {}}"#,
+        )
+        .unwrap();
+
+        let response = replay(temp_dir.path(), &forecast);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.url(), forecast.url());
+        assert_eq!(response.headers()["content-type"], "application/json");
+        assert_eq!(response.text().await.unwrap(), r#"{"current": {}}"#);
+
+        // Any other request has nothing recorded
+        let other = request("https://api.open-meteo.com/v1/forecast?latitude=49.28");
+        let response = replay(temp_dir.path(), &other);
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        assert!(response.error_for_status().is_err());
+    }
+
+    #[test]
+    fn test_file_stem_names_the_host_and_tells_requests_apart() {
+        let stem = file_stem(&request(
+            "https://aviationweather.gov/api/data/metar?ids=CYVR",
+        ));
+        assert!(stem.starts_with("aviationweather.gov-"));
+        assert_eq!(
+            stem,
+            file_stem(&request(
+                "https://aviationweather.gov/api/data/metar?ids=CYVR"
+            ))
+        );
+        assert_ne!(
+            stem,
+            file_stem(&request(
+                "https://aviationweather.gov/api/data/metar?ids=CYHC"
+            ))
+        );
+        let post = Client::new()
+            .post("https://aviationweather.gov/api/data/metar?ids=CYVR")
+            .build()
+            .unwrap();
+        assert_ne!(stem, file_stem(&post));
+    }
+
+    #[test]
+    fn test_from_flags() {
+        assert_eq!(HttpRecording::from_flags(None, None), None);
+        assert_eq!(
+            HttpRecording::from_flags(None, Some(PathBuf::from("fixtures/http"))),
+            Some(HttpRecording::Replay(PathBuf::from("fixtures/http")))
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Sunrise and sunset calculations
+//!
+//! This module computes sunrise, solar noon and sunset for a latitude/longitude
+//! on any date using the NOAA solar calculator equations, so sun times don't
+//! depend on a weather API and stay correct for future dates.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+
+use super::timezone::BeachTimezone;
+
+/// Zenith angle of the sun's centre at sunrise/sunset: 90° plus 0.833° for
+/// atmospheric refraction and the solar radius
+const SUNRISE_ZENITH: f64 = 90.833;
+
+/// Minutes in a day
+const MINUTES_PER_DAY: f64 = 1440.0;
+
+/// Sun events for one day at one location
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SunEvents {
+    /// When the top of the sun rises above the horizon
+    pub sunrise: DateTime<Utc>,
+    /// When the sun is highest in the sky
+    pub solar_noon: DateTime<Utc>,
+    /// When the top of the sun sets below the horizon
+    pub sunset: DateTime<Utc>,
+}
+
+impl SunEvents {
+    /// Sunrise and sunset as wall-clock times in a beach's timezone
+    pub fn local_times(&self, timezone: &BeachTimezone) -> (NaiveTime, NaiveTime) {
+        (
+            timezone.local(self.sunrise).time(),
+            timezone.local(self.sunset).time(),
+        )
+    }
+}
+
+/// Computes sunrise, solar noon and sunset for a location on a given date
+///
+/// # Arguments
+/// * `latitude` - Latitude in degrees (north positive)
+/// * `longitude` - Longitude in degrees (east positive)
+/// * `date` - The calendar date at the location
+///
+/// # Returns
+/// `None` during polar day or polar night, when the sun doesn't cross the horizon.
+pub fn sun_events(latitude: f64, longitude: f64, date: NaiveDate) -> Option<SunEvents> {
+    let midnight = Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?);
+
+    // First pass estimates solar noon from longitude alone; the second
+    // re-evaluates the sun's declination and equation of time at that noon
+    let mut noon_minutes = 720.0 - 4.0 * longitude;
+    let mut half_day_minutes = 0.0;
+    for _ in 0..2 {
+        let sun = SunAt::minutes_after(midnight, noon_minutes);
+        noon_minutes = 720.0 - 4.0 * longitude - sun.equation_of_time;
+        half_day_minutes = 4.0 * sunrise_hour_angle(latitude, sun.declination)?;
+    }
+
+    let at = |minutes: f64| midnight + Duration::seconds((minutes * 60.0).round() as i64);
+    Some(SunEvents {
+        sunrise: at(noon_minutes - half_day_minutes),
+        solar_noon: at(noon_minutes),
+        sunset: at(noon_minutes + half_day_minutes),
+    })
+}
+
+/// Computes sunrise and sunset for a location on a given date as wall-clock
+/// times in the location's timezone
+///
+/// Returns `None` during polar day or night.
+pub fn sunrise_sunset(
+    latitude: f64,
+    longitude: f64,
+    date: NaiveDate,
+    timezone: &BeachTimezone,
+) -> Option<(NaiveTime, NaiveTime)> {
+    sun_events(latitude, longitude, date).map(|events| events.local_times(timezone))
+}
+
+/// Hour angle (degrees) between solar noon and sunrise, or `None` if the sun
+/// never crosses the horizon
+fn sunrise_hour_angle(latitude: f64, declination: f64) -> Option<f64> {
+    let lat = latitude.to_radians();
+    let cos_hour_angle = SUNRISE_ZENITH.to_radians().cos() / (lat.cos() * declination.cos())
+        - lat.tan() * declination.tan();
+    (-1.0..=1.0)
+        .contains(&cos_hour_angle)
+        .then(|| cos_hour_angle.acos().to_degrees())
+}
+
+/// Solar declination and equation of time at an instant
+struct SunAt {
+    /// Declination in radians
+    declination: f64,
+    /// Equation of time in minutes
+    equation_of_time: f64,
+}
+
+impl SunAt {
+    fn minutes_after(midnight: DateTime<Utc>, minutes: f64) -> Self {
+        let julian_day =
+            midnight.timestamp() as f64 / 86_400.0 + 2_440_587.5 + minutes / MINUTES_PER_DAY;
+        let t = (julian_day - 2_451_545.0) / 36_525.0;
+
+        // Geometric mean longitude and anomaly of the sun, orbit eccentricity
+        let mean_longitude = (280.466_46 + t * (36_000.769_83 + t * 0.000_303_2)).rem_euclid(360.0);
+        let mean_anomaly = 357.529_11 + t * (35_999.050_29 - 0.000_153_7 * t);
+        let eccentricity = 0.016_708_634 - t * (0.000_042_037 + 0.000_000_126_7 * t);
+
+        let m = mean_anomaly.to_radians();
+        let center = m.sin() * (1.914_602 - t * (0.004_817 + 0.000_014 * t))
+            + (2.0 * m).sin() * (0.019_993 - 0.000_101 * t)
+            + (3.0 * m).sin() * 0.000_289;
+
+        // Apparent longitude, corrected for nutation and aberration
+        let omega = (125.04 - 1_934.136 * t).to_radians();
+        let apparent_longitude =
+            (mean_longitude + center - 0.005_69 - 0.004_78 * omega.sin()).to_radians();
+
+        // Obliquity of the ecliptic
+        let mean_obliquity =
+            23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.000_59 - t * 0.001_813))) / 60.0) / 60.0;
+        let obliquity = (mean_obliquity + 0.002_56 * omega.cos()).to_radians();
+
+        let declination = (obliquity.sin() * apparent_longitude.sin()).asin();
+
+        let y = (obliquity / 2.0).tan().powi(2);
+        let l0 = mean_longitude.to_radians();
+        let equation_of_time = 4.0
+            * (y * (2.0 * l0).sin() - 2.0 * eccentricity * m.sin()
+                + 4.0 * eccentricity * y * m.sin() * (2.0 * l0).cos()
+                - 0.5 * y * y * (4.0 * l0).sin()
+                - 1.25 * eccentricity * eccentricity * (2.0 * m).sin())
+            .to_degrees();
+
+        Self {
+            declination,
+            equation_of_time,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::timezone::DstRule;
+
+    const KITS_LAT: f64 = 49.2743;
+    const KITS_LON: f64 = -123.1544;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn assert_close(actual: DateTime<Utc>, expected: DateTime<Utc>) {
+        let diff = (actual - expected).num_minutes().abs();
+        assert!(diff <= 2, "Expected ~{}, got {}", expected, actual);
+    }
+
+    #[test]
+    fn test_summer_solstice_in_vancouver() {
+        let date = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+        let events = sun_events(KITS_LAT, KITS_LON, date).unwrap();
+
+        // 5:07 and 21:22 PDT
+        assert_close(events.sunrise, utc(2026, 6, 21, 12, 7));
+        assert_close(events.sunset, utc(2026, 6, 22, 4, 22));
+        assert!(events.sunrise < events.solar_noon && events.solar_noon < events.sunset);
+    }
+
+    #[test]
+    fn test_winter_solstice_in_vancouver() {
+        let date = NaiveDate::from_ymd_opt(2026, 12, 21).unwrap();
+        let events = sun_events(KITS_LAT, KITS_LON, date).unwrap();
+
+        // 8:05 and 16:16 PST
+        assert_close(events.sunrise, utc(2026, 12, 21, 16, 5));
+        assert_close(events.sunset, utc(2026, 12, 22, 0, 16));
+    }
+
+    #[test]
+    fn test_future_dates_shift_with_season() {
+        let june = NaiveDate::from_ymd_opt(2027, 6, 1).unwrap();
+        let september = NaiveDate::from_ymd_opt(2027, 9, 1).unwrap();
+
+        let day_length = |date| {
+            let events = sun_events(KITS_LAT, KITS_LON, date).unwrap();
+            events.sunset - events.sunrise
+        };
+        assert!(day_length(june) > day_length(september));
+    }
+
+    #[test]
+    fn test_polar_night_has_no_sun_events() {
+        let date = NaiveDate::from_ymd_opt(2026, 12, 21).unwrap();
+        assert!(sun_events(80.0, 15.0, date).is_none());
+    }
+
+    #[test]
+    fn test_sunrise_sunset_local_times_match_events() {
+        let date = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+        let events = sun_events(KITS_LAT, KITS_LON, date).unwrap();
+        let vancouver = BeachTimezone::vancouver();
+        let (sunrise, sunset) = sunrise_sunset(KITS_LAT, KITS_LON, date, &vancouver).unwrap();
+
+        assert_eq!(sunrise, vancouver.local(events.sunrise).time());
+        assert_eq!(sunset, vancouver.local(events.sunset).time());
+        // 05:07 and 21:21 PDT, whatever timezone the machine is in
+        assert_eq!(sunrise.format("%H:%M").to_string(), "05:07");
+        assert_eq!(sunset.format("%H:%M").to_string(), "21:21");
+
+        // The same instants read differently on a beach one zone east
+        let mountain = BeachTimezone::new("America/Edmonton", -7 * 60, DstRule::NorthAmerica);
+        let (sunrise_mt, _) = events.local_times(&mountain);
+        assert_eq!(sunrise_mt - sunrise, chrono::Duration::hours(1));
+    }
+}
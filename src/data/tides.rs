@@ -4,16 +4,105 @@
 //! as the reference station (Station ID: 7735). For the MVP, it uses pre-computed
 //! static tide predictions for January 2026.
 
-use crate::cache::CacheManager;
+use crate::cache::{query_key, CacheLookup, CacheManager, StalenessPolicy};
+use crate::data::metrics::Metrics;
 use crate::data::{TideEvent, TideInfo, TideState};
 use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime, TimeZone, Utc};
 use thiserror::Error;
 
-/// Cache key for tide data
-const TIDES_CACHE_KEY: &str = "tides_point_atkinson";
+/// Name tide data is cached under, before its query version
+const TIDES_CACHE_NAME: &str = "tides_point_atkinson";
 
-/// Cache TTL in hours (24 hours as per requirements)
-const TIDES_CACHE_TTL_HOURS: u64 = 24;
+/// Point Atkinson's station ID
+const STATION_ID: &str = "7735";
+
+/// Days of predictions each lookup covers, so the next high and low are
+/// found late in the day
+const PREDICTION_DAYS: i64 = 2;
+
+/// How long cached tide info stays fresh
+const TIDES_CACHE_TTL: chrono::Duration = chrono::Duration::hours(24);
+
+/// Source name tide cache lookups are counted under in the metrics
+pub const TIDES_SOURCE: &str = "Tide predictions";
+
+/// Heights in meters at or above which a high tide counts as a king tide,
+/// per station
+///
+/// Set near the top of each station's yearly range, where waves start
+/// washing over the seawall and low-lying paths.
+const KING_TIDE_THRESHOLDS_M: [(&str, f64); 1] = [(STATION_ID, 4.7)];
+
+/// Marker flagging a king tide wherever a tide height is shown
+pub const KING_TIDE_MARKER: &str = "\u{265B}"; // ♛
+
+/// What a king tide means for anyone on the shore
+pub const KING_TIDE_NOTE: &str = "seawall may flood around high tide";
+
+/// King tide threshold for a station, if it has one
+pub fn king_tide_threshold(station_id: &str) -> Option<f64> {
+    KING_TIDE_THRESHOLDS_M
+        .iter()
+        .find(|(id, _)| *id == station_id)
+        .map(|&(_, threshold)| threshold)
+}
+
+/// Whether a predicted height at the reference station is a king tide
+pub fn is_king_tide(height: f64) -> bool {
+    king_tide_threshold(STATION_ID).is_some_and(|threshold| height >= threshold)
+}
+
+/// Vertical reference a station's tide heights are measured from
+///
+/// Canadian predictions are heights above chart datum, a level close to the
+/// lowest tides, so they're never negative. Sources that give heights
+/// relative to mean sea level instead read lower by the datum's
+/// `mean_sea_level`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TideDatum {
+    /// Short name, e.g. "Chart datum"
+    pub name: &'static str,
+    /// Height of mean sea level above the datum, in meters
+    pub mean_sea_level: f64,
+}
+
+impl TideDatum {
+    /// A height above the datum as a height above (or below) mean sea level
+    pub fn to_mean_sea_level(self, height: f64) -> f64 {
+        height - self.mean_sea_level
+    }
+
+    /// A height above (or below) mean sea level as a height above the datum
+    #[allow(dead_code)]
+    pub fn above_datum(self, mean_sea_level_height: f64) -> f64 {
+        mean_sea_level_height + self.mean_sea_level
+    }
+}
+
+/// Datum of each station's predictions, as published with them
+///
+/// Point Atkinson's chart datum is lower low water, large tide.
+const STATION_DATUMS: [(&str, TideDatum); 1] = [(
+    STATION_ID,
+    TideDatum {
+        name: "Chart datum",
+        mean_sea_level: 3.1,
+    },
+)];
+
+/// Datum a station's predictions are given against, if it's known
+pub fn station_datum(station_id: &str) -> Option<TideDatum> {
+    STATION_DATUMS
+        .iter()
+        .find(|(id, _)| *id == station_id)
+        .map(|&(_, datum)| datum)
+}
+
+/// Datum the reference station's predictions, and so every height shown,
+/// are given against
+pub fn reference_datum() -> TideDatum {
+    station_datum(STATION_ID).expect("the reference station has a datum")
+}
 
 /// Errors that can occur when fetching tide data
 #[derive(Debug, Error)]
@@ -27,9 +116,10 @@ pub enum TidesError {
 ///
 /// Uses static tide predictions for Point Atkinson (Station 7735) in the
 /// Vancouver area. Integrates with CacheManager for 24-hour caching.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TidesClient {
     cache: Option<CacheManager>,
+    metrics: Option<Metrics>,
 }
 
 /// A single tide prediction point (high or low)
@@ -45,7 +135,35 @@ struct TidePrediction {
 impl TidesClient {
     /// Creates a new TidesClient with optional cache manager
     pub fn new(cache: Option<CacheManager>) -> Self {
-        Self { cache }
+        Self {
+            cache,
+            metrics: None,
+        }
+    }
+
+    /// Cache key for tide info, versioned by the station and days it covers
+    pub fn cache_key() -> String {
+        query_key(
+            TIDES_CACHE_NAME,
+            &[
+                ("station", STATION_ID.to_string()),
+                ("days", PREDICTION_DAYS.to_string()),
+            ],
+        )
+    }
+
+    /// Counts cache lookups in the given metrics registry
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Marks the cached tide data stale, so the next fetch works it out
+    /// again
+    pub fn expire_cache(&self) {
+        if let Some(cache) = &self.cache {
+            let _ = cache.expire(&Self::cache_key());
+        }
     }
 
     /// Fetches today's tide data
@@ -54,14 +172,23 @@ impl TidesClient {
     /// and next high/low tide events. Uses cached data if fresh, falls back to
     /// cached data on failure.
     pub async fn fetch_tides(&self) -> Result<TideInfo, TidesError> {
+        let cache_key = Self::cache_key();
+
         // Check cache first
-        if let Some(ref cache) = self.cache {
-            if let Some(cached) = cache.read::<TideInfo>(TIDES_CACHE_KEY) {
-                if !cached.is_expired {
-                    return Ok(cached.data);
+        let cached = match self.cache {
+            Some(ref cache) => {
+                let lookup = cache.get_with_policy::<TideInfo>(&cache_key, StalenessPolicy::AnyAge);
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_cache(TIDES_SOURCE, matches!(lookup, CacheLookup::Fresh(_)));
                 }
+                lookup
             }
-        }
+            None => CacheLookup::Miss,
+        };
+        let stale = match cached {
+            CacheLookup::Fresh(tide_info) => return Ok(tide_info),
+            lookup => lookup.data(),
+        };
 
         // Generate tide info from static predictions
         let result = self.generate_tide_info();
@@ -70,29 +197,29 @@ impl TidesClient {
             Ok(tide_info) => {
                 // Cache the successful result
                 if let Some(ref cache) = self.cache {
-                    let _ = cache.write(TIDES_CACHE_KEY, &tide_info, TIDES_CACHE_TTL_HOURS);
+                    let _ = cache.put(&cache_key, &tide_info, TIDES_CACHE_TTL);
                 }
                 Ok(tide_info)
             }
-            Err(e) => {
-                // Try to return cached data on failure (even if expired)
-                if let Some(ref cache) = self.cache {
-                    if let Some(cached) = cache.read::<TideInfo>(TIDES_CACHE_KEY) {
-                        return Ok(cached.data);
-                    }
-                }
-                Err(e)
-            }
+            // Return cached data on failure, even if expired
+            Err(e) => stale.ok_or(e),
         }
     }
 
     /// Generates tide info from static predictions for the current time
     fn generate_tide_info(&self) -> Result<TideInfo, TidesError> {
-        let now = Local::now();
+        self.tide_info_at(Local::now())
+    }
+
+    /// Computes tide info for a given time from the static predictions
+    ///
+    /// This never touches the cache or network, so it is cheap enough to call
+    /// every minute to keep the current height and next high/low up to date.
+    pub fn tide_info_at(&self, now: DateTime<Local>) -> Result<TideInfo, TidesError> {
         let today = now.date_naive();
 
         // Get predictions for today and tomorrow (for next tide events)
-        let predictions = self.get_predictions_for_date_range(today, 2);
+        let predictions = self.get_predictions_for_date_range(today, PREDICTION_DAYS);
 
         if predictions.is_empty() {
             return Err(TidesError::NoDataAvailable);
@@ -581,6 +708,26 @@ mod tests {
         (client, temp_dir)
     }
 
+    #[test]
+    fn test_king_tides_are_the_highest_highs() {
+        assert_eq!(king_tide_threshold(STATION_ID), Some(4.7));
+        assert_eq!(king_tide_threshold("0000"), None);
+
+        assert!(is_king_tide(4.8));
+        assert!(is_king_tide(4.7));
+        assert!(!is_king_tide(4.5));
+    }
+
+    #[test]
+    fn test_datum_converts_to_and_from_mean_sea_level() {
+        assert_eq!(station_datum("0000"), None);
+        let datum = reference_datum();
+        assert_eq!(datum.name, "Chart datum");
+        assert!((datum.to_mean_sea_level(4.8) - 1.7).abs() < 1e-9);
+        assert!((datum.to_mean_sea_level(0.5) - -2.6).abs() < 1e-9);
+        assert!((datum.above_datum(datum.to_mean_sea_level(2.3)) - 2.3).abs() < 1e-9);
+    }
+
     #[test]
     fn test_parse_tide_predictions_for_january_1() {
         let client = TidesClient::new(None);
@@ -733,9 +880,22 @@ mod tests {
 
     #[tokio::test]
     async fn test_cache_returns_cached_when_fresh() {
-        let (client, _temp_dir) = create_test_client();
+        let (client, temp_dir) = create_test_client();
+
+        // Seed the cache so the test doesn't depend on the static prediction window
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let seeded = TideInfo {
+            current_height: 3.1,
+            tide_state: TideState::Rising,
+            next_high: None,
+            next_low: None,
+            fetched_at: Utc::now(),
+        };
+        cache
+            .put(&TidesClient::cache_key(), &seeded, TIDES_CACHE_TTL)
+            .expect("Failed to seed cache");
 
-        // First fetch should populate cache
+        // First fetch should return the cached entry
         let result1 = client.fetch_tides().await;
         assert!(result1.is_ok(), "First fetch should succeed");
 
@@ -752,6 +912,7 @@ mod tests {
             (tide1.current_height - tide2.current_height).abs() < 0.01,
             "Cached data should return same height"
         );
+        assert!((tide1.current_height - 3.1).abs() < 0.01);
     }
 
     #[tokio::test]
@@ -799,6 +960,38 @@ mod tests {
         assert!((low.height - 1.2).abs() < 0.01);
     }
 
+    #[test]
+    fn test_tide_info_at_follows_the_clock() {
+        let client = TidesClient::new(None);
+        let at = |hour, minute| {
+            Local
+                .from_local_datetime(
+                    &NaiveDate::from_ymd_opt(2026, 1, 1)
+                        .unwrap()
+                        .and_hms_opt(hour, minute, 0)
+                        .unwrap(),
+                )
+                .single()
+                .unwrap()
+        };
+
+        let morning = client.tide_info_at(at(5, 0)).unwrap();
+        let later = client.tide_info_at(at(5, 1)).unwrap();
+        assert_eq!(morning.tide_state, TideState::Falling);
+        assert!(later.current_height < morning.current_height);
+
+        // Once the 8:45 low passes, the next low moves to the evening
+        let after_low = client.tide_info_at(at(11, 0)).unwrap();
+        assert_eq!(after_low.tide_state, TideState::Rising);
+        let next_low = |info: &TideInfo| info.next_low.as_ref().unwrap().time;
+        assert!(next_low(&after_low) > next_low(&morning));
+
+        assert!(matches!(
+            client.tide_info_at(at(5, 0) + chrono::Duration::days(60)),
+            Err(TidesError::NoDataAvailable)
+        ));
+    }
+
     #[test]
     fn test_no_data_for_other_months() {
         let client = TidesClient::new(None);
@@ -833,7 +1026,7 @@ mod tests {
 
         // Should be in reasonable range for Vancouver tides (typically 0-6m)
         assert!(
-            max_height >= 4.0 && max_height <= 6.0,
+            (4.0..=6.0).contains(&max_height),
             "Max tide height should be in reasonable range (4-6m)"
         );
     }
@@ -957,7 +1150,7 @@ mod tests {
             let h = height.unwrap();
             // All heights should be in reasonable range
             assert!(
-                h >= 0.0 && h <= 6.0,
+                (0.0..=6.0).contains(&h),
                 "Height at hour {} should be in reasonable range, got {}",
                 hour,
                 h
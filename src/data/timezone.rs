@@ -0,0 +1,192 @@
+//! Per-beach timezones
+//!
+//! Hourly forecasts, sun times and best windows are all expressed in the
+//! beach's own wall-clock time rather than the machine's, so someone planning
+//! from another timezone sees the hours that matter on the sand. Rules are
+//! built in (a standard UTC offset plus a daylight saving rule) instead of
+//! read from a tz database, which covers the regions we know about.
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// When a timezone switches to daylight saving time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DstRule {
+    /// No daylight saving
+    #[default]
+    None,
+    /// Second Sunday in March to the first Sunday in November, at 02:00 local
+    NorthAmerica,
+    /// Last Sunday in March to the last Sunday in October, at 01:00 UTC
+    Europe,
+}
+
+/// The timezone a beach's local times are expressed in
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct BeachTimezone {
+    /// IANA name, passed to weather APIs (e.g. "America/Vancouver")
+    pub name: String,
+    /// Offset from UTC outside daylight saving, in minutes
+    pub standard_offset_minutes: i32,
+    /// Daylight saving rule, adding one hour while in effect
+    #[serde(default)]
+    pub dst: DstRule,
+}
+
+impl Default for BeachTimezone {
+    fn default() -> Self {
+        Self::vancouver()
+    }
+}
+
+impl BeachTimezone {
+    /// Creates a timezone from its IANA name, standard offset and DST rule
+    pub fn new(name: impl Into<String>, standard_offset_minutes: i32, dst: DstRule) -> Self {
+        Self {
+            name: name.into(),
+            standard_offset_minutes,
+            dst,
+        }
+    }
+
+    /// Pacific time, used by every built-in beach
+    pub fn vancouver() -> Self {
+        Self::new("America/Vancouver", -8 * 60, DstRule::NorthAmerica)
+    }
+
+    /// Returns whether daylight saving is in effect at an instant
+    pub fn is_dst(&self, at: DateTime<Utc>) -> bool {
+        let year = at.year();
+        let standard = Duration::minutes(self.standard_offset_minutes as i64);
+        let (start, end) = match self.dst {
+            DstRule::None => return false,
+            DstRule::NorthAmerica => {
+                // 02:00 standard time to 02:00 daylight time (01:00 standard)
+                let start = nth_sunday(year, 3, 2).and_hms_opt(2, 0, 0);
+                let end = nth_sunday(year, 11, 1).and_hms_opt(1, 0, 0);
+                match (start, end) {
+                    (Some(start), Some(end)) => (start - standard, end - standard),
+                    _ => return false,
+                }
+            }
+            DstRule::Europe => match (
+                last_sunday(year, 3).and_hms_opt(1, 0, 0),
+                last_sunday(year, 10).and_hms_opt(1, 0, 0),
+            ) {
+                (Some(start), Some(end)) => (start, end),
+                _ => return false,
+            },
+        };
+        let naive = at.naive_utc();
+        naive >= start && naive < end
+    }
+
+    /// UTC offset in effect at an instant
+    pub fn offset_at(&self, at: DateTime<Utc>) -> FixedOffset {
+        let dst_minutes = if self.is_dst(at) { 60 } else { 0 };
+        FixedOffset::east_opt((self.standard_offset_minutes + dst_minutes) * 60)
+            .unwrap_or_else(|| FixedOffset::east_opt(0).expect("zero offset is valid"))
+    }
+
+    /// Wall-clock time at the beach for an instant
+    pub fn local(&self, at: DateTime<Utc>) -> NaiveDateTime {
+        at.with_timezone(&self.offset_at(at)).naive_local()
+    }
+
+    /// Current wall-clock time at the beach
+    pub fn now(&self) -> NaiveDateTime {
+        self.local(Utc::now())
+    }
+
+    /// Instant at which the beach's clock reads `local`
+    ///
+    /// Times skipped or repeated by a DST change resolve using the offset in
+    /// effect just before the change.
+    pub fn to_utc(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        let standard = Duration::minutes(self.standard_offset_minutes as i64);
+        let guess = Utc.from_utc_datetime(&(local - standard - Duration::hours(1)));
+        let offset = Duration::seconds(self.offset_at(guess).local_minus_utc() as i64);
+        Utc.from_utc_datetime(&(local - offset))
+    }
+}
+
+/// The `n`th Sunday (1-based) of a month
+fn nth_sunday(year: i32, month: u32, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, n)
+        .expect("every month has at least four Sundays")
+}
+
+/// The last Sunday of a month
+fn last_sunday(year: i32, month: u32) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, 5)
+        .unwrap_or_else(|| nth_sunday(year, month, 4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_vancouver_offsets_follow_daylight_saving() {
+        let tz = BeachTimezone::vancouver();
+
+        assert_eq!(
+            tz.offset_at(utc(2026, 1, 15, 12, 0)).local_minus_utc(),
+            -8 * 3600
+        );
+        assert_eq!(
+            tz.offset_at(utc(2026, 7, 15, 12, 0)).local_minus_utc(),
+            -7 * 3600
+        );
+
+        // 2026-03-08 02:00 PST is 10:00 UTC
+        assert!(!tz.is_dst(utc(2026, 3, 8, 9, 59)));
+        assert!(tz.is_dst(utc(2026, 3, 8, 10, 0)));
+        // 2026-11-01 02:00 PDT is 09:00 UTC
+        assert!(tz.is_dst(utc(2026, 11, 1, 8, 59)));
+        assert!(!tz.is_dst(utc(2026, 11, 1, 9, 0)));
+    }
+
+    #[test]
+    fn test_europe_rule_switches_at_one_utc() {
+        let tz = BeachTimezone::new("Europe/Lisbon", 0, DstRule::Europe);
+
+        // Last Sundays of 2026: March 29 and October 25
+        assert!(!tz.is_dst(utc(2026, 3, 29, 0, 59)));
+        assert!(tz.is_dst(utc(2026, 3, 29, 1, 0)));
+        assert!(!tz.is_dst(utc(2026, 10, 25, 1, 0)));
+        assert_eq!(tz.local(utc(2026, 7, 1, 12, 0)).hour(), 13);
+    }
+
+    #[test]
+    fn test_local_and_to_utc_round_trip() {
+        let tz = BeachTimezone::vancouver();
+        let at = utc(2026, 7, 15, 21, 0);
+
+        let local = tz.local(at);
+        assert_eq!(local.hour(), 14);
+        assert_eq!(tz.to_utc(local), at);
+
+        let winter = utc(2026, 12, 1, 3, 30);
+        assert_eq!(tz.to_utc(tz.local(winter)), winter);
+    }
+
+    #[test]
+    fn test_timezone_without_dst_deserializes_with_default_rule() {
+        let tz: BeachTimezone = serde_json::from_str(
+            r#"{"name": "Pacific/Honolulu", "standard_offset_minutes": -600}"#,
+        )
+        .unwrap();
+        assert_eq!(tz.dst, DstRule::None);
+        assert_eq!(tz.local(utc(2026, 7, 15, 22, 0)).hour(), 12);
+    }
+}
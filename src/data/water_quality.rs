@@ -1,17 +1,45 @@
 //! Water quality API client for Vancouver Open Data
 //!
 //! Fetches beach water quality data from Vancouver Open Data API and maps
-//! E. coli levels to water quality status.
-
-use super::{WaterQuality, WaterStatus};
-use crate::cache::CacheManager;
-use chrono::{NaiveDate, Utc};
+//! E. coli levels to water quality status. Samples imported from the city's
+//! CSV dumps fill in the sample history, and stand in for the API when it is
+//! unreachable and nothing is cached.
+
+use std::time::Instant;
+
+use super::debug_log::DebugLog;
+use super::metrics::Metrics;
+use super::rate_limit::RateLimiter;
+use super::recording;
+use super::water_quality_archive::{ArchivedSample, WaterQualityArchive};
+use super::{
+    SamplingHistory, WaterQuality, WaterStatus, LOW_BANDWIDTH_TTL_FACTOR, SAMPLING_WINDOW_DAYS,
+};
+use crate::cache::{query_key, query_string, CacheLookup, CacheManager, StalenessPolicy};
+use chrono::{Datelike, NaiveDate, Utc, Weekday};
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-/// Time-to-live for water quality cache entries in hours
-const CACHE_TTL_HOURS: u64 = 24;
+/// How long water quality cache entries stay fresh
+const CACHE_TTL: chrono::Duration = chrono::Duration::hours(24);
+
+/// How long cached "no recent samples" results stay fresh
+///
+/// Short enough to pick up a new sample the same day, long enough that a
+/// quiet station isn't queried on every refresh.
+const NEGATIVE_CACHE_TTL: chrono::Duration = chrono::Duration::minutes(60);
+
+/// How long a station found not reporting is left alone before it's asked
+/// again, doubling with each check in a row that finds it still quiet
+const OUTAGE_BACKOFF: chrono::Duration = chrono::Duration::hours(6);
+
+/// Longest a station found not reporting is left alone
+const OUTAGE_BACKOFF_MAX: chrono::Duration = chrono::Duration::hours(48);
+
+/// Most recent samples requested, enough to cover `SAMPLING_WINDOW_DAYS`
+/// at the summer sampling rate
+const SAMPLE_HISTORY_LIMIT: usize = 30;
 
 /// Number of days after which data is considered stale
 const STALE_DATA_DAYS: i64 = 7;
@@ -22,6 +50,9 @@ const ECOLI_SAFE_THRESHOLD: u32 = 200;
 /// E. coli threshold for advisory (CFU/100mL)
 const ECOLI_ADVISORY_THRESHOLD: u32 = 400;
 
+/// Source name water quality responses are recorded under in the debug log
+pub const WATER_QUALITY_SOURCE: &str = "Vancouver Open Data";
+
 /// Errors that can occur when fetching water quality data
 #[derive(Debug, Error)]
 pub enum WaterQualityError {
@@ -32,6 +63,10 @@ pub enum WaterQualityError {
     /// Failed to parse API response
     #[error("Failed to parse API response: {0}")]
     ParseError(String),
+
+    /// The client-side request budget for the API's host is used up
+    #[error("Request budget for {0} used up; try again shortly")]
+    Throttled(String),
 }
 
 /// Response from Vancouver Open Data API
@@ -55,6 +90,17 @@ struct WaterQualityRecord {
     advisory: Option<String>,
 }
 
+impl From<&ArchivedSample> for WaterQualityRecord {
+    fn from(sample: &ArchivedSample) -> Self {
+        Self {
+            beach_name: None,
+            e_coli: sample.ecoli_count.map(f64::from),
+            sample_date: Some(sample.sample_date.format("%Y-%m-%d").to_string()),
+            advisory: sample.advisory.clone(),
+        }
+    }
+}
+
 /// Client for fetching water quality data from Vancouver Open Data API
 #[derive(Debug, Clone)]
 pub struct WaterQualityClient {
@@ -64,6 +110,14 @@ pub struct WaterQualityClient {
     cache_manager: Option<CacheManager>,
     /// Base URL for the API (allows override for testing)
     base_url: String,
+    /// Where raw responses are recorded in verbose mode
+    debug_log: Option<DebugLog>,
+    /// Where requests and cache lookups are counted in serve mode
+    metrics: Option<Metrics>,
+    /// Request budgets shared with the other clients
+    rate_limiter: Option<RateLimiter>,
+    /// Whether cached results are kept longer to save requests
+    low_bandwidth: bool,
 }
 
 impl WaterQualityClient {
@@ -73,6 +127,10 @@ impl WaterQualityClient {
             http_client: Client::new(),
             cache_manager: CacheManager::new(),
             base_url: "https://opendata.vancouver.ca/api/explore/v2.1/catalog/datasets/beach-water-quality/records".to_string(),
+            debug_log: None,
+            metrics: None,
+            rate_limiter: None,
+            low_bandwidth: false,
         }
     }
 
@@ -82,9 +140,93 @@ impl WaterQualityClient {
             http_client: Client::new(),
             cache_manager: Some(cache_manager),
             base_url: "https://opendata.vancouver.ca/api/explore/v2.1/catalog/datasets/beach-water-quality/records".to_string(),
+            debug_log: None,
+            metrics: None,
+            rate_limiter: None,
+            low_bandwidth: false,
         }
     }
 
+    /// Always asks the API, never reading or writing the cache
+    pub fn without_cache(mut self) -> Self {
+        self.cache_manager = None;
+        self
+    }
+
+    /// Records raw response bodies to the given debug log
+    pub fn with_debug_log(mut self, debug_log: DebugLog) -> Self {
+        self.debug_log = Some(debug_log);
+        self
+    }
+
+    /// Counts requests and cache lookups in the given metrics registry
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Checks every request against the given shared rate limiter
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Keeps cached responses `LOW_BANDWIDTH_TTL_FACTOR` times longer
+    /// (`--low-bandwidth`)
+    pub fn with_low_bandwidth(mut self, low_bandwidth: bool) -> Self {
+        self.low_bandwidth = low_bandwidth;
+        self
+    }
+
+    /// How long a result stays fresh: real samples for a day, a station
+    /// with no recent samples for an hour
+    fn cache_ttl(&self, water_quality: &WaterQuality) -> chrono::Duration {
+        let ttl = if water_quality.no_samples_station.is_some() {
+            NEGATIVE_CACHE_TTL
+        } else {
+            CACHE_TTL
+        };
+        if self.low_bandwidth {
+            ttl * LOW_BANDWIDTH_TTL_FACTOR
+        } else {
+            ttl
+        }
+    }
+
+    /// Cache key of a station's outage record
+    fn outage_key(beach_name: &str) -> String {
+        format!(
+            "water_quality_outage_{}",
+            beach_name.replace([' ', '.'], "_").to_lowercase()
+        )
+    }
+
+    /// Notes whether a fetch found the station not reporting, returning how
+    /// long to leave it alone: zero once it reports again, otherwise a
+    /// backoff that grows with each check in a row
+    fn track_outage(
+        cache_manager: &CacheManager,
+        beach_name: &str,
+        water_quality: &WaterQuality,
+    ) -> chrono::Duration {
+        let key = Self::outage_key(beach_name);
+        let previous = cache_manager
+            .get_with_policy::<StationOutage>(&key, StalenessPolicy::AnyAge)
+            .data()
+            .unwrap_or_default();
+        let outage = StationOutage {
+            checks: if water_quality.is_not_reporting() {
+                previous.checks + 1
+            } else {
+                0
+            },
+        };
+        if outage != previous {
+            let _ = cache_manager.put(&key, &outage, chrono::Duration::days(SAMPLING_WINDOW_DAYS));
+        }
+        outage.backoff()
+    }
+
     /// Creates a new WaterQualityClient with a custom base URL (for testing)
     #[cfg(test)]
     #[allow(dead_code)]
@@ -93,15 +235,37 @@ impl WaterQualityClient {
             http_client: Client::new(),
             cache_manager: None,
             base_url,
+            debug_log: None,
+            metrics: None,
+            rate_limiter: None,
+            low_bandwidth: false,
         }
     }
 
-    /// Generates a cache key for a beach
-    fn cache_key(beach_name: &str) -> String {
-        format!(
+    /// Query parameters every sample request sends besides the beach
+    fn query_params() -> [(&'static str, String); 2] {
+        [
+            ("order_by", "sample_date desc".to_string()),
+            ("limit", SAMPLE_HISTORY_LIMIT.to_string()),
+        ]
+    }
+
+    /// Cache key for a beach, versioned by the query its samples are
+    /// fetched with
+    pub fn cache_key(beach_name: &str) -> String {
+        let name = format!(
             "water_quality_{}",
-            beach_name.replace(' ', "_").to_lowercase()
-        )
+            beach_name.replace([' ', '.'], "_").to_lowercase()
+        );
+        query_key(&name, &Self::query_params())
+    }
+
+    /// Marks a beach's cached samples stale, so the next fetch asks the
+    /// API again
+    pub fn expire_cache(&self, beach_name: &str) {
+        if let Some(cache) = &self.cache_manager {
+            let _ = cache.expire(&Self::cache_key(beach_name));
+        }
     }
 
     /// Fetches water quality data for a specific beach
@@ -117,7 +281,8 @@ impl WaterQualityClient {
     /// - First checks cache for fresh data
     /// - If cache is fresh, returns cached data
     /// - If cache is expired or missing, fetches from API
-    /// - On API failure, returns expired cache data if available
+    /// - On API failure, returns expired cache data if available, then the
+    ///   latest imported sample
     /// - Returns Unknown status if no data is available or data is older than 7 days
     pub async fn fetch_water_quality(
         &self,
@@ -126,28 +291,48 @@ impl WaterQualityClient {
         let cache_key = Self::cache_key(beach_name);
 
         // Check cache first
-        if let Some(ref cache_manager) = self.cache_manager {
-            if let Some(cached) = cache_manager.read::<WaterQuality>(&cache_key) {
-                if !cached.is_expired {
-                    return Ok(cached.data);
+        let cached = match self.cache_manager {
+            Some(ref cache_manager) => {
+                let lookup = cache_manager
+                    .get_with_policy::<WaterQuality>(&cache_key, StalenessPolicy::AnyAge);
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_cache(
+                        WATER_QUALITY_SOURCE,
+                        matches!(lookup, CacheLookup::Fresh(_)),
+                    );
                 }
+                lookup
             }
-        }
+            None => CacheLookup::Miss,
+        };
+        let stale = match cached {
+            CacheLookup::Fresh(water_quality) => return Ok(water_quality),
+            lookup => lookup.data(),
+        };
 
         // Try to fetch from API
         match self.fetch_from_api(beach_name).await {
             Ok(water_quality) => {
-                // Cache the result
+                // Cache the result, negative results for a shorter time and
+                // a station that stopped reporting for longer
                 if let Some(ref cache_manager) = self.cache_manager {
-                    let _ = cache_manager.write(&cache_key, &water_quality, CACHE_TTL_HOURS);
+                    let backoff = Self::track_outage(cache_manager, beach_name, &water_quality);
+                    let _ = cache_manager.put(
+                        &cache_key,
+                        &water_quality,
+                        self.cache_ttl(&water_quality).max(backoff),
+                    );
                 }
                 Ok(water_quality)
             }
             Err(api_error) => {
                 // Try to return expired cache data on API failure
+                if let Some(stale) = stale {
+                    return Ok(stale);
+                }
                 if let Some(ref cache_manager) = self.cache_manager {
-                    if let Some(cached) = cache_manager.read::<WaterQuality>(&cache_key) {
-                        return Ok(cached.data);
+                    if let Some(archived) = self.latest_archived(cache_manager, beach_name) {
+                        return Ok(archived);
                     }
                 }
                 Err(api_error)
@@ -158,25 +343,71 @@ impl WaterQualityClient {
     /// Fetches water quality data directly from the API
     async fn fetch_from_api(&self, beach_name: &str) -> Result<WaterQuality, WaterQualityError> {
         let url = format!(
-            "{}?where=beach_name='{}'&order_by=sample_date desc&limit=1",
+            "{}?where=beach_name='{}'&{}",
             self.base_url,
-            urlencoded(beach_name)
+            urlencoded(beach_name),
+            query_string(&Self::query_params())
         );
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire(&url)
+                .map_err(WaterQualityError::Throttled)?;
+        }
 
-        let response = self
-            .http_client
-            .get(&url)
-            .send()
-            .await?
-            .json::<ApiResponse>()
-            .await?;
+        let started = Instant::now();
+        let text = async {
+            recording::send(self.http_client.get(&url))
+                .await?
+                .text()
+                .await
+        }
+        .await;
+        if let Some(metrics) = &self.metrics {
+            metrics.record_request(WATER_QUALITY_SOURCE, text.is_ok(), started.elapsed());
+        }
+        let text = text?;
+        if let Some(debug_log) = &self.debug_log {
+            debug_log.record(WATER_QUALITY_SOURCE, beach_name, &url, &text);
+        }
+        let response: ApiResponse = serde_json::from_str(&text)
+            .map_err(|e| WaterQualityError::ParseError(e.to_string()))?;
 
         if response.results.is_empty() {
             return Ok(self.create_unknown_status(beach_name));
         }
 
+        // The latest sample sets the status; the rest, with any imported
+        // samples on other days, show how often the station is sampled
         let record = &response.results[0];
-        self.parse_record(record, beach_name)
+        let mut water_quality = self.parse_record(record, beach_name)?;
+        let mut records = response.results;
+        if let Some(ref cache_manager) = self.cache_manager {
+            let archived: Vec<WaterQualityRecord> = WaterQualityArchive::load(cache_manager)
+                .samples(beach_name)
+                .iter()
+                .map(WaterQualityRecord::from)
+                .filter(|a| !records.iter().any(|r| r.sample_date == a.sample_date))
+                .collect();
+            records.extend(archived);
+        }
+        water_quality.sampling = Some(sampling_history(&records, Utc::now().date_naive()));
+        Ok(water_quality)
+    }
+
+    /// The station's latest imported sample, with the imported sample history
+    fn latest_archived(
+        &self,
+        cache_manager: &CacheManager,
+        beach_name: &str,
+    ) -> Option<WaterQuality> {
+        let records: Vec<WaterQualityRecord> = WaterQualityArchive::load(cache_manager)
+            .samples(beach_name)
+            .iter()
+            .map(WaterQualityRecord::from)
+            .collect();
+        let mut water_quality = self.parse_record(records.first()?, beach_name).ok()?;
+        water_quality.sampling = Some(sampling_history(&records, Utc::now().date_naive()));
+        Some(water_quality)
     }
 
     /// Parses an API record into WaterQuality
@@ -197,7 +428,7 @@ impl WaterQualityClient {
         let today = Utc::now().date_naive();
         let days_old = (today - sample_date).num_days();
         if days_old > STALE_DATA_DAYS {
-            return Ok(self.create_unknown_status_with_date(sample_date));
+            return Ok(self.create_unknown_status_with_date(beach_name, sample_date));
         }
 
         // Parse E. coli count
@@ -219,6 +450,8 @@ impl WaterQualityClient {
             sample_date,
             advisory_reason,
             fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
         })
     }
 
@@ -241,26 +474,46 @@ impl WaterQualityClient {
         }
     }
 
-    /// Creates an Unknown status WaterQuality with today's date
-    fn create_unknown_status(&self, _beach_name: &str) -> WaterQuality {
-        WaterQuality {
-            status: WaterStatus::Unknown,
-            ecoli_count: None,
-            sample_date: Utc::now().date_naive(),
-            advisory_reason: None,
-            fetched_at: Utc::now(),
-        }
+    /// Creates an Unknown status WaterQuality with today's date, for a station
+    /// with no samples
+    fn create_unknown_status(&self, beach_name: &str) -> WaterQuality {
+        self.create_unknown_status_with_date(beach_name, Utc::now().date_naive())
     }
 
-    /// Creates an Unknown status WaterQuality with a specific date
-    fn create_unknown_status_with_date(&self, sample_date: NaiveDate) -> WaterQuality {
+    /// Creates an Unknown status WaterQuality with a specific date, for a
+    /// station whose last sample is too old to use
+    fn create_unknown_status_with_date(
+        &self,
+        beach_name: &str,
+        sample_date: NaiveDate,
+    ) -> WaterQuality {
         WaterQuality {
             status: WaterStatus::Unknown,
             ecoli_count: None,
             sample_date,
             advisory_reason: None,
             fetched_at: Utc::now(),
+            no_samples_station: Some(beach_name.to_string()),
+            sampling: None,
+        }
+    }
+}
+
+/// Checks in a row that found a station not reporting, kept between fetches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct StationOutage {
+    checks: u32,
+}
+
+impl StationOutage {
+    /// How long to leave the station alone: `OUTAGE_BACKOFF` after the first
+    /// check, doubling up to `OUTAGE_BACKOFF_MAX`, and zero when reporting
+    fn backoff(&self) -> chrono::Duration {
+        if self.checks == 0 {
+            return chrono::Duration::zero();
         }
+        let doublings = (self.checks - 1).min(8);
+        (OUTAGE_BACKOFF * 2_i32.pow(doublings)).min(OUTAGE_BACKOFF_MAX)
     }
 }
 
@@ -270,6 +523,77 @@ impl Default for WaterQualityClient {
     }
 }
 
+/// Summarizes the samples taken in the last `SAMPLING_WINDOW_DAYS` days
+///
+/// A weekday counts as usual when the station was sampled on it in at least
+/// half of the weeks with samples; at least two weeks are needed to call it
+/// a pattern. Records without a valid date are skipped.
+fn sampling_history(records: &[WaterQualityRecord], today: NaiveDate) -> SamplingHistory {
+    let all_dates: Vec<NaiveDate> = records
+        .iter()
+        .filter_map(|r| r.sample_date.as_deref())
+        .filter_map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+        .collect();
+    let dates: Vec<NaiveDate> = all_dates
+        .iter()
+        .copied()
+        .filter(|d| (0..SAMPLING_WINDOW_DAYS).contains(&(today - *d).num_days()))
+        .collect();
+
+    let week_of = |date: &NaiveDate| date.iso_week();
+    let mut weeks: Vec<_> = dates.iter().map(week_of).collect();
+    weeks.sort();
+    weeks.dedup();
+
+    let mut usual_days = Vec::new();
+    if weeks.len() >= 2 {
+        for day in [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ] {
+            let mut weeks_sampled: Vec<_> = dates
+                .iter()
+                .filter(|d| d.weekday() == day)
+                .map(week_of)
+                .collect();
+            weeks_sampled.dedup();
+            if weeks_sampled.len() * 2 >= weeks.len() {
+                usual_days.push(day);
+            }
+        }
+    }
+
+    SamplingHistory {
+        recent_samples: dates.len() as u32,
+        usual_days,
+        cadence_days: sampling_cadence(all_dates),
+    }
+}
+
+/// Usual days between samples: the median gap between the sample dates,
+/// which needs at least three dates to go on
+///
+/// Every date counts, not just recent ones, so a station that stopped
+/// reporting keeps the cadence it had before.
+fn sampling_cadence(mut dates: Vec<NaiveDate>) -> Option<u32> {
+    dates.sort();
+    dates.dedup();
+    if dates.len() < 3 {
+        return None;
+    }
+    let mut gaps: Vec<i64> = dates
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).num_days())
+        .collect();
+    gaps.sort();
+    Some(gaps[gaps.len() / 2] as u32)
+}
+
 /// URL-encodes a string for use in query parameters
 fn urlencoded(s: &str) -> String {
     s.replace(' ', "%20").replace('\'', "%27")
@@ -278,6 +602,7 @@ fn urlencoded(s: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data::rate_limit::Budget;
     use tempfile::TempDir;
 
     /// Helper to create a test cache manager
@@ -405,6 +730,91 @@ mod tests {
         );
     }
 
+    fn record_on(date: NaiveDate) -> WaterQualityRecord {
+        WaterQualityRecord {
+            beach_name: Some("Kitsilano Beach".to_string()),
+            e_coli: Some(50.0),
+            sample_date: Some(date.format("%Y-%m-%d").to_string()),
+            advisory: None,
+        }
+    }
+
+    #[test]
+    fn test_sampling_history_finds_usual_days() {
+        // Mondays and Wednesdays for three weeks, plus one Friday
+        let today = NaiveDate::from_ymd_opt(2026, 7, 24).unwrap();
+        let mut records: Vec<WaterQualityRecord> = [6, 8, 13, 15, 17, 20, 22]
+            .iter()
+            .map(|&day| record_on(NaiveDate::from_ymd_opt(2026, 7, day).unwrap()))
+            .collect();
+        // Too old to count, and an unparseable date
+        records.push(record_on(NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()));
+        records.push(WaterQualityRecord {
+            sample_date: Some("not a date".to_string()),
+            ..record_on(today)
+        });
+
+        let history = sampling_history(&records, today);
+
+        assert_eq!(history.recent_samples, 7);
+        assert_eq!(history.usual_days, vec![Weekday::Mon, Weekday::Wed]);
+        assert_eq!(history.cadence_days, Some(2));
+    }
+
+    #[test]
+    fn test_sampling_history_needs_two_weeks_for_a_pattern() {
+        let today = NaiveDate::from_ymd_opt(2026, 7, 24).unwrap();
+        let records = vec![
+            record_on(NaiveDate::from_ymd_opt(2026, 7, 20).unwrap()),
+            record_on(NaiveDate::from_ymd_opt(2026, 7, 22).unwrap()),
+        ];
+
+        let history = sampling_history(&records, today);
+
+        assert_eq!(history.recent_samples, 2);
+        assert!(history.usual_days.is_empty());
+        assert_eq!(history.cadence_days, None, "two samples are one gap");
+        assert_eq!(sampling_history(&[], today).recent_samples, 0);
+    }
+
+    #[test]
+    fn test_station_not_reporting_backs_off_until_it_reports() {
+        let (cache, _temp_dir) = create_test_cache();
+        let today = chrono::Local::now().date_naive();
+        let sampled = |days_ago: i64| WaterQuality {
+            status: WaterStatus::Safe,
+            ecoli_count: Some(50),
+            sample_date: today - chrono::Duration::days(days_ago),
+            advisory_reason: None,
+            fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: Some(SamplingHistory {
+                recent_samples: 3,
+                usual_days: Vec::new(),
+                cadence_days: Some(7),
+            }),
+        };
+        let quiet = sampled(20);
+        assert!(quiet.is_not_reporting());
+
+        let backoffs: Vec<i64> = (0..5)
+            .map(|_| WaterQualityClient::track_outage(&cache, "Kitsilano Beach", &quiet))
+            .map(|backoff| backoff.num_hours())
+            .collect();
+        assert_eq!(backoffs, vec![6, 12, 24, 48, 48]);
+
+        // Reporting again resets the count
+        let back = sampled(1);
+        assert_eq!(
+            WaterQualityClient::track_outage(&cache, "Kitsilano Beach", &back),
+            chrono::Duration::zero()
+        );
+        assert_eq!(
+            WaterQualityClient::track_outage(&cache, "Kitsilano Beach", &quiet).num_hours(),
+            6
+        );
+    }
+
     #[test]
     fn test_stale_data_returns_unknown() {
         let client = WaterQualityClient::new();
@@ -419,21 +829,60 @@ mod tests {
 
         let result = client.parse_record(&record, "Kitsilano Beach").unwrap();
         assert_eq!(result.status, WaterStatus::Unknown);
+        assert_eq!(result.sample_date, old_date);
+        assert_eq!(
+            result.no_samples_station.as_deref(),
+            Some("Kitsilano Beach")
+        );
+    }
+
+    #[test]
+    fn test_captured_fixture_parses() {
+        // Refreshed from the live API with `vanbeach gen-fixtures`
+        let response: ApiResponse =
+            serde_json::from_str(include_str!("../../tests/fixtures/water-quality.json")).unwrap();
+        let client = WaterQualityClient::with_base_url("http://localhost".to_string());
+
+        for record in &response.results {
+            client.parse_record(record, "English Bay").unwrap();
+        }
+        let sampling = sampling_history(&response.results, Utc::now().date_naive());
+        assert!(sampling.recent_samples as usize <= response.results.len());
+    }
+
+    #[test]
+    fn test_low_bandwidth_keeps_results_longer() {
+        let client = WaterQualityClient::with_base_url("http://localhost".to_string());
+        let mut sampled = client.create_unknown_status("Kitsilano Beach");
+        let no_samples = sampled.clone();
+        sampled.no_samples_station = None;
+
+        assert_eq!(client.cache_ttl(&sampled), chrono::Duration::hours(24));
+        assert_eq!(client.cache_ttl(&no_samples), chrono::Duration::hours(1));
+
+        let client = client.with_low_bandwidth(true);
+        assert_eq!(client.cache_ttl(&sampled), chrono::Duration::hours(96));
+        assert_eq!(client.cache_ttl(&no_samples), chrono::Duration::hours(4));
     }
 
     #[test]
     fn test_cache_key_generation() {
+        let version = crate::cache::query_key("", &WaterQualityClient::query_params());
         assert_eq!(
             WaterQualityClient::cache_key("Kitsilano Beach"),
-            "water_quality_kitsilano_beach"
+            format!("water_quality_kitsilano_beach{}", version)
         );
         assert_eq!(
             WaterQualityClient::cache_key("English Bay"),
-            "water_quality_english_bay"
+            format!("water_quality_english_bay{}", version)
         );
         assert_eq!(
             WaterQualityClient::cache_key("Spanish Banks East"),
-            "water_quality_spanish_banks_east"
+            format!("water_quality_spanish_banks_east{}", version)
+        );
+        assert_eq!(
+            WaterQualityClient::cache_key("St. Roch"),
+            format!("water_quality_st__roch{}", version)
         );
     }
 
@@ -454,17 +903,19 @@ mod tests {
             sample_date: Utc::now().date_naive(),
             advisory_reason: None,
             fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
         };
 
         let cache_key = WaterQualityClient::cache_key("test-beach");
-        cache
-            .write(&cache_key, &water_quality, CACHE_TTL_HOURS)
-            .unwrap();
+        cache.put(&cache_key, &water_quality, CACHE_TTL).unwrap();
 
-        let cached = cache.read::<WaterQuality>(&cache_key).unwrap();
-        assert_eq!(cached.data.status, WaterStatus::Safe);
-        assert_eq!(cached.data.ecoli_count, Some(50));
-        assert!(!cached.is_expired);
+        let cached = cache
+            .get_with_policy::<WaterQuality>(&cache_key, StalenessPolicy::FreshOnly)
+            .fresh()
+            .unwrap();
+        assert_eq!(cached.status, WaterStatus::Safe);
+        assert_eq!(cached.ecoli_count, Some(50));
     }
 
     #[test]
@@ -481,6 +932,51 @@ mod tests {
         assert_eq!(unknown.status, WaterStatus::Unknown);
         assert!(unknown.ecoli_count.is_none());
         assert!(unknown.advisory_reason.is_none());
+        assert_eq!(unknown.no_samples_station.as_deref(), Some("Test Beach"));
+    }
+
+    #[tokio::test]
+    async fn test_negative_result_is_cached_and_reused() {
+        let (cache, _temp_dir) = create_test_cache();
+        // Nothing listens here, so any API call would fail
+        let client = WaterQualityClient {
+            cache_manager: Some(cache.clone()),
+            ..WaterQualityClient::with_base_url("http://127.0.0.1:9".to_string())
+        };
+        let negative = client.create_unknown_status("quiet-station");
+        let cache_key = WaterQualityClient::cache_key("quiet-station");
+        cache
+            .put(&cache_key, &negative, NEGATIVE_CACHE_TTL)
+            .unwrap();
+
+        let result = client.fetch_water_quality("quiet-station").await.unwrap();
+        assert_eq!(result.status, WaterStatus::Unknown);
+        assert_eq!(result.no_samples_station.as_deref(), Some("quiet-station"));
+    }
+
+    #[tokio::test]
+    async fn test_expired_cache_is_used_when_the_api_fails() {
+        let (cache, _temp_dir) = create_test_cache();
+        let metrics = Metrics::default();
+        // Nothing listens here, so the API call fails
+        let client = WaterQualityClient {
+            cache_manager: Some(cache.clone()),
+            metrics: Some(metrics.clone()),
+            ..WaterQualityClient::with_base_url("http://127.0.0.1:9".to_string())
+        };
+        let mut expired = client.create_unknown_status("quiet-station");
+        expired.status = WaterStatus::Advisory;
+        let cache_key = WaterQualityClient::cache_key("quiet-station");
+        cache
+            .put(&cache_key, &expired, chrono::Duration::zero())
+            .unwrap();
+
+        let result = client.fetch_water_quality("quiet-station").await.unwrap();
+
+        assert_eq!(result.status, WaterStatus::Advisory);
+        assert!(metrics.render(&[]).contains(
+            "vanbeach_cache_lookups_total{source=\"Vancouver Open Data\",result=\"miss\"} 1"
+        ));
     }
 
     #[tokio::test]
@@ -494,18 +990,66 @@ mod tests {
             sample_date: Utc::now().date_naive(),
             advisory_reason: None,
             fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
         };
 
         let cache_key = WaterQualityClient::cache_key("cached-beach");
-        cache
-            .write(&cache_key, &water_quality, CACHE_TTL_HOURS)
-            .unwrap();
+        cache.put(&cache_key, &water_quality, CACHE_TTL).unwrap();
 
         // Create client with cache - it should return cached data without hitting API
-        let client = WaterQualityClient::with_cache(cache);
+        let metrics = Metrics::default();
+        let client = WaterQualityClient::with_cache(cache).with_metrics(metrics.clone());
         let result = client.fetch_water_quality("cached-beach").await.unwrap();
 
         assert_eq!(result.status, WaterStatus::Safe);
         assert_eq!(result.ecoli_count, Some(75));
+        let text = metrics.render(&[]);
+        assert!(text.contains(
+            "vanbeach_cache_lookups_total{source=\"Vancouver Open Data\",result=\"hit\"} 1"
+        ));
+        assert!(!text.contains("vanbeach_api_requests_total{"));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_api_falls_back_to_imported_samples() {
+        let (cache, _temp_dir) = create_test_cache();
+        let today = Utc::now().date_naive();
+        let mut archive = WaterQualityArchive::default();
+        archive
+            .import_csv(&format!(
+                "beach_name,sample_date,e_coli\nKitsilano Beach,{},260\nKitsilano Beach,{},30\n",
+                today - chrono::Duration::days(1),
+                today - chrono::Duration::days(8),
+            ))
+            .unwrap();
+        archive.save(&cache).unwrap();
+        // Nothing listens here, so the API call fails
+        let client = WaterQualityClient {
+            cache_manager: Some(cache),
+            ..WaterQualityClient::with_base_url("http://127.0.0.1:9".to_string())
+        };
+
+        let result = client.fetch_water_quality("Kitsilano Beach").await.unwrap();
+        assert_eq!(result.status, WaterStatus::Advisory);
+        assert_eq!(result.ecoli_count, Some(260));
+        assert_eq!(result.sampling.unwrap().recent_samples, 2);
+
+        // Stations without imported samples still fail
+        assert!(client.fetch_water_quality("Jericho Beach").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_request_over_budget_is_never_sent() {
+        let metrics = Metrics::default();
+        let limiter = RateLimiter::new().with_budget("127.0.0.1", Budget::per_minute(0));
+        let client = WaterQualityClient::with_base_url("http://127.0.0.1:9".to_string())
+            .with_metrics(metrics.clone())
+            .with_rate_limiter(limiter);
+
+        let result = client.fetch_water_quality("busy-station").await;
+
+        assert!(matches!(result, Err(WaterQualityError::Throttled(host)) if host == "127.0.0.1"));
+        assert!(!metrics.render(&[]).contains("vanbeach_api_requests_total{"));
     }
 }
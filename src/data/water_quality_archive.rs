@@ -0,0 +1,320 @@
+//! Water quality sample archive imported from the city's CSV dumps
+//!
+//! The city publishes each season's sample results as a CSV download of the
+//! beach water quality dataset. `vanbeach import-wq <file>` reads one into
+//! an archive kept in the cache directory, merging it with earlier imports.
+//! The archive backfills the sample history of each station, and when the
+//! API is unreachable and nothing is cached the water quality client falls
+//! back to the station's latest archived sample.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::cache::{CacheManager, StalenessPolicy};
+
+/// Cache key the archive is stored under
+const CACHE_KEY: &str = "water_quality_archive";
+
+/// The archive is only replaced by another import, so keep it for years
+const CACHE_TTL: Duration = Duration::days(365 * 10);
+
+/// Header names accepted for each column, after normalizing to snake_case
+const STATION_COLUMNS: [&str; 3] = ["beach_name", "beach", "station"];
+const DATE_COLUMNS: [&str; 3] = ["sample_date", "date", "sampled"];
+const ECOLI_COLUMNS: [&str; 4] = ["e_coli", "ecoli", "e_coli_cfu_100ml", "e_coli_cfu_100_ml"];
+const ADVISORY_COLUMNS: [&str; 2] = ["advisory", "advisory_reason"];
+
+/// Errors that can occur when importing a CSV dump
+#[derive(Debug, Error)]
+pub enum ArchiveError {
+    /// The file has no header row
+    #[error("CSV file is empty")]
+    Empty,
+
+    /// A column the importer needs isn't in the header
+    #[error("CSV header has no {0} column")]
+    MissingColumn(&'static str),
+}
+
+/// One archived sample result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchivedSample {
+    /// Day the sample was taken
+    pub sample_date: NaiveDate,
+    /// E. coli count (CFU per 100mL), if the row had one
+    pub ecoli_count: Option<u32>,
+    /// Advisory or closure text, if the row had one
+    pub advisory: Option<String>,
+}
+
+/// Archived samples per station, newest first
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WaterQualityArchive {
+    /// Samples keyed by the station's name in lowercase
+    stations: BTreeMap<String, Vec<ArchivedSample>>,
+}
+
+/// What an import added to the archive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Rows read as samples
+    pub rows: usize,
+    /// Rows skipped for a missing station or an unreadable date
+    pub skipped: usize,
+    /// Samples not already in the archive
+    pub added: usize,
+    /// Stations the file had samples for
+    pub stations: usize,
+    /// Earliest and latest sample dates in the file
+    pub dates: Option<(NaiveDate, NaiveDate)>,
+}
+
+impl fmt::Display for ImportSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Imported {} new of {} samples for {} stations",
+            self.added, self.rows, self.stations
+        )?;
+        if let Some((first, last)) = self.dates {
+            write!(f, " ({} to {})", first, last)?;
+        }
+        if self.skipped > 0 {
+            write!(f, "; skipped {} unreadable rows", self.skipped)?;
+        }
+        Ok(())
+    }
+}
+
+impl WaterQualityArchive {
+    /// Loads the archive from the cache, empty if nothing was imported
+    pub fn load(cache: &CacheManager) -> Self {
+        cache
+            .get_with_policy::<Self>(CACHE_KEY, StalenessPolicy::AnyAge)
+            .data()
+            .unwrap_or_default()
+    }
+
+    /// Saves the archive to the cache
+    pub fn save(&self, cache: &CacheManager) -> std::io::Result<()> {
+        cache.put(CACHE_KEY, self, CACHE_TTL)
+    }
+
+    /// Parses a CSV dump and merges its samples into the archive
+    ///
+    /// Both the comma-separated and the semicolon-separated exports are
+    /// read. A sample already archived for the station and day is replaced.
+    pub fn import_csv(&mut self, text: &str) -> Result<ImportSummary, ArchiveError> {
+        let (samples, skipped) = parse_csv(text)?;
+        let mut summary = ImportSummary {
+            rows: samples.len(),
+            skipped,
+            added: 0,
+            stations: 0,
+            dates: None,
+        };
+
+        let mut stations: Vec<&str> = Vec::new();
+        for (station, sample) in &samples {
+            if !stations.contains(&station.as_str()) {
+                stations.push(station);
+            }
+            summary.dates = Some(match summary.dates {
+                Some((first, last)) => {
+                    (first.min(sample.sample_date), last.max(sample.sample_date))
+                }
+                None => (sample.sample_date, sample.sample_date),
+            });
+
+            let archived = self.stations.entry(station.clone()).or_default();
+            match archived
+                .iter_mut()
+                .find(|a| a.sample_date == sample.sample_date)
+            {
+                Some(existing) => *existing = sample.clone(),
+                None => {
+                    archived.push(sample.clone());
+                    summary.added += 1;
+                }
+            }
+        }
+        summary.stations = stations.len();
+
+        for archived in self.stations.values_mut() {
+            archived.sort_by_key(|a| Reverse(a.sample_date));
+        }
+        Ok(summary)
+    }
+
+    /// A station's archived samples, newest first
+    pub fn samples(&self, station: &str) -> &[ArchivedSample] {
+        self.stations
+            .get(&station.to_lowercase())
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Reads the samples out of a CSV dump, with the number of rows skipped
+fn parse_csv(text: &str) -> Result<(Vec<(String, ArchivedSample)>, usize), ArchiveError> {
+    let mut lines = text
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .filter(|line| !line.trim().is_empty());
+    let header = lines.next().ok_or(ArchiveError::Empty)?;
+    let delimiter = if header.contains(';') { ';' } else { ',' };
+    let columns: Vec<String> = split_row(header, delimiter)
+        .iter()
+        .map(|name| normalize_header(name))
+        .collect();
+    let find = |names: &[&str]| columns.iter().position(|c| names.contains(&c.as_str()));
+
+    let station_column = find(&STATION_COLUMNS).ok_or(ArchiveError::MissingColumn("beach name"))?;
+    let date_column = find(&DATE_COLUMNS).ok_or(ArchiveError::MissingColumn("sample date"))?;
+    let ecoli_column = find(&ECOLI_COLUMNS).ok_or(ArchiveError::MissingColumn("E. coli"))?;
+    let advisory_column = find(&ADVISORY_COLUMNS);
+
+    let mut samples = Vec::new();
+    let mut skipped = 0;
+    for line in lines {
+        let fields = split_row(line, delimiter);
+        let field = |column: usize| {
+            fields
+                .get(column)
+                .map(|f| f.trim())
+                .filter(|f| !f.is_empty())
+        };
+
+        let station = field(station_column);
+        // Exports write dates either plain or as a timestamp
+        let sample_date = field(date_column)
+            .and_then(|d| NaiveDate::parse_from_str(d.get(..10).unwrap_or(d), "%Y-%m-%d").ok());
+        let (Some(station), Some(sample_date)) = (station, sample_date) else {
+            skipped += 1;
+            continue;
+        };
+
+        samples.push((
+            station.to_lowercase(),
+            ArchivedSample {
+                sample_date,
+                ecoli_count: field(ecoli_column)
+                    .and_then(|count| count.parse::<f64>().ok())
+                    .map(|count| count as u32),
+                advisory: advisory_column.and_then(field).map(str::to_string),
+            },
+        ));
+    }
+    Ok((samples, skipped))
+}
+
+/// Column name in snake_case, e.g. "E. coli (CFU/100mL)" -> "e_coli_cfu_100ml"
+fn normalize_header(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Splits a CSV row, honouring double-quoted fields and doubled quotes
+fn split_row(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn day(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_semicolon_export_is_imported_per_station() {
+        let csv = "\u{feff}Beach Name;Sample Date;E. coli (CFU/100mL);Advisory\n\
+                   Kitsilano Beach;2025-07-14;45;\n\
+                   Kitsilano Beach;2025-07-21T00:00:00-07:00;320;\"High bacteria; resample\"\n\
+                   English Bay Beach;2025-07-15;12.0;\n\
+                   ;2025-07-15;12;\n\
+                   Jericho Beach;last week;12;\n";
+        let mut archive = WaterQualityArchive::default();
+
+        let summary = archive.import_csv(csv).unwrap();
+
+        assert_eq!(summary.rows, 3);
+        assert_eq!(summary.skipped, 2);
+        assert_eq!(summary.added, 3);
+        assert_eq!(summary.stations, 2);
+        assert_eq!(summary.dates, Some((day("2025-07-14"), day("2025-07-21"))));
+        let kits = archive.samples("KITSILANO BEACH");
+        assert_eq!(kits.len(), 2);
+        assert_eq!(kits[0].sample_date, day("2025-07-21"));
+        assert_eq!(kits[0].ecoli_count, Some(320));
+        assert_eq!(kits[0].advisory.as_deref(), Some("High bacteria; resample"));
+        assert_eq!(kits[1].advisory, None);
+        assert!(archive.samples("Sunset Beach").is_empty());
+    }
+
+    #[test]
+    fn test_reimport_replaces_samples_and_survives_a_reload() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let mut archive = WaterQualityArchive::load(&cache);
+        assert_eq!(archive, WaterQualityArchive::default());
+
+        archive
+            .import_csv("beach_name,sample_date,e_coli\nSunset Beach,2025-08-01,40\n")
+            .unwrap();
+        let summary = archive
+            .import_csv(
+                "beach_name,sample_date,e_coli\n\
+                 Sunset Beach,2025-08-01,60\n\
+                 Sunset Beach,2025-08-04,20\n",
+            )
+            .unwrap();
+        assert_eq!(summary.added, 1);
+        archive.save(&cache).unwrap();
+
+        let reloaded = WaterQualityArchive::load(&cache);
+        let samples = reloaded.samples("Sunset Beach");
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[1].ecoli_count, Some(60));
+        assert_eq!(
+            summary.to_string(),
+            "Imported 1 new of 2 samples for 1 stations (2025-08-01 to 2025-08-04)"
+        );
+    }
+
+    #[test]
+    fn test_missing_columns_are_reported() {
+        let mut archive = WaterQualityArchive::default();
+        assert!(matches!(archive.import_csv(""), Err(ArchiveError::Empty)));
+        assert!(matches!(
+            archive.import_csv("beach_name,sample_date\nKits,2025-07-01\n"),
+            Err(ArchiveError::MissingColumn("E. coli"))
+        ));
+    }
+}
@@ -3,16 +3,38 @@
 //! This module provides functionality to fetch weather data from the Open-Meteo API
 //! and parse it into our Weather data structures.
 
+use std::time::Instant;
+
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use thiserror::Error;
 
-use super::{HourlyForecast, Weather, WeatherCondition};
+use super::debug_log::{coordinate_subject, DebugLog};
+use super::metrics::Metrics;
+use super::rate_limit::RateLimiter;
+use super::recording;
+use super::solar::sunrise_sunset;
+use super::{
+    BeachTimezone, CloudCover, DataQuality, HourlyForecast, Weather, WeatherCondition,
+    WeatherProvider,
+};
 
 /// Base URL for the Open-Meteo API
 const OPEN_METEO_BASE_URL: &str = "https://api.open-meteo.com/v1/forecast";
 
+/// Hourly variables requested for today and tomorrow
+const HOURLY_FIELDS: &str = "temperature_2m,apparent_temperature,weathercode,windspeed_10m,winddirection_10m,uv_index,precipitation_probability,dew_point_2m,wind_gusts_10m,cloud_cover,cloud_cover_low,cloud_cover_mid,cloud_cover_high";
+
+/// Hourly variables requested in low-bandwidth mode: only what scoring needs
+const LOW_BANDWIDTH_HOURLY_FIELDS: &str =
+    "temperature_2m,weathercode,windspeed_10m,winddirection_10m,uv_index";
+
+/// Longest run of missing hours filled in from the hours around it; longer
+/// holes are left alone rather than guessed across
+const MAX_FILL_HOURS: usize = 3;
+
 /// Hourly weather forecast data from Open-Meteo API (internal structure)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
@@ -57,6 +79,14 @@ pub enum WeatherError {
     /// Invalid time format in response
     #[error("Invalid time format: {0}")]
     InvalidTimeFormat(String),
+
+    /// The provider rejected the request because of rate limiting
+    #[error("Rate limited by weather provider")]
+    RateLimited,
+
+    /// The client-side request budget for the provider's host is used up
+    #[error("Request budget for {0} used up; try again shortly")]
+    Throttled(String),
 }
 
 /// Client for fetching weather data from Open-Meteo API
@@ -64,6 +94,11 @@ pub enum WeatherError {
 pub struct WeatherClient {
     client: Client,
     timezone: String,
+    debug_log: Option<DebugLog>,
+    metrics: Option<Metrics>,
+    rate_limiter: Option<RateLimiter>,
+    /// Ask for fewer hourly variables and today only (`--low-bandwidth`)
+    low_bandwidth: bool,
 }
 
 impl Default for WeatherClient {
@@ -78,6 +113,10 @@ impl WeatherClient {
         Self {
             client: Client::new(),
             timezone: "America/Vancouver".to_string(),
+            debug_log: None,
+            metrics: None,
+            rate_limiter: None,
+            low_bandwidth: false,
         }
     }
 
@@ -87,6 +126,10 @@ impl WeatherClient {
         Self {
             client,
             timezone: "America/Vancouver".to_string(),
+            debug_log: None,
+            metrics: None,
+            rate_limiter: None,
+            low_bandwidth: false,
         }
     }
 
@@ -97,26 +140,95 @@ impl WeatherClient {
         self
     }
 
+    /// Records raw response bodies to the given debug log
+    pub fn with_debug_log(mut self, debug_log: DebugLog) -> Self {
+        self.debug_log = Some(debug_log);
+        self
+    }
+
+    /// Counts and times requests in the given metrics registry
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Checks every request against the given shared rate limiter
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Requests only the hourly variables scoring needs, for today only,
+    /// leaving out feels-like, rain chance, dew point, gusts and tomorrow
+    pub fn with_low_bandwidth(mut self, low_bandwidth: bool) -> Self {
+        self.low_bandwidth = low_bandwidth;
+        self
+    }
+
+    /// Hourly variables and forecast days to request
+    fn forecast_query(&self) -> (&'static str, u8) {
+        if self.low_bandwidth {
+            (LOW_BANDWIDTH_HOURLY_FIELDS, 1)
+        } else {
+            (HOURLY_FIELDS, 2)
+        }
+    }
+
     /// Fetch weather data for the given coordinates
     ///
     /// # Arguments
     /// * `lat` - Latitude coordinate
     /// * `lon` - Longitude coordinate
+    /// * `timezone` - Timezone the location's hours and sun times are reported in
     ///
     /// # Returns
     /// * `Ok(Weather)` - Weather data for the location including hourly forecasts for today
     /// * `Err(WeatherError)` - If the request or parsing fails
-    pub async fn fetch_weather(&self, lat: f64, lon: f64) -> Result<Weather, WeatherError> {
+    pub async fn fetch_weather(
+        &self,
+        lat: f64,
+        lon: f64,
+        timezone: &BeachTimezone,
+    ) -> Result<Weather, WeatherError> {
+        let (hourly_fields, forecast_days) = self.forecast_query();
         let url = format!(
-            "{}?latitude={}&longitude={}&current=temperature_2m,relative_humidity_2m,apparent_temperature,weather_code,wind_speed_10m,wind_direction_10m&daily=sunrise,sunset,uv_index_max&hourly=temperature_2m,apparent_temperature,weathercode,windspeed_10m,winddirection_10m,uv_index,precipitation_probability&forecast_days=2&timezone={}",
-            OPEN_METEO_BASE_URL, lat, lon, self.timezone
+            "{}?latitude={}&longitude={}&current=temperature_2m,relative_humidity_2m,apparent_temperature,weather_code,wind_speed_10m,wind_direction_10m&daily=uv_index_max&hourly={}&forecast_days={}&timezone={}",
+            OPEN_METEO_BASE_URL, lat, lon, hourly_fields, forecast_days, timezone.name
         );
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire(&url)
+                .map_err(WeatherError::Throttled)?;
+        }
 
-        let response = self.client.get(&url).send().await?;
-        let text = response.text().await?;
+        let started = Instant::now();
+        let text = async {
+            let response = recording::send(self.client.get(&url)).await?;
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                return Err(WeatherError::RateLimited);
+            }
+            Ok(response.error_for_status()?.text().await?)
+        }
+        .await;
+        if let Some(metrics) = &self.metrics {
+            metrics.record_request(
+                WeatherProvider::OpenMeteo.name(),
+                text.is_ok(),
+                started.elapsed(),
+            );
+        }
+        let text = text?;
+        if let Some(debug_log) = &self.debug_log {
+            debug_log.record(
+                WeatherProvider::OpenMeteo.name(),
+                &coordinate_subject(lat, lon),
+                &url,
+                &text,
+            );
+        }
         let api_response: OpenMeteoResponseFull = serde_json::from_str(&text)?;
 
-        self.parse_response_full(api_response)
+        self.parse_response_full(api_response, lat, lon, timezone)
     }
 
     /// Fetch weather data with 48-hour hourly forecasts for the given coordinates
@@ -139,7 +251,7 @@ impl WeatherClient {
             OPEN_METEO_BASE_URL, lat, lon, self.timezone
         );
 
-        let response = self.client.get(&url).send().await?;
+        let response = recording::send(self.client.get(&url)).await?;
         let text = response.text().await?;
         let api_response: OpenMeteoResponseWithHourly = serde_json::from_str(&text)?;
 
@@ -188,11 +300,16 @@ impl WeatherClient {
             condition,
             humidity,
             wind,
+            wind_direction: Some(current.wind_direction_10m),
             uv,
             sunrise,
             sunset,
             fetched_at: Utc::now(),
             hourly: Vec::new(),
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+            weather_code: Some(current.weather_code),
         })
     }
 
@@ -241,11 +358,16 @@ impl WeatherClient {
             condition,
             humidity,
             wind,
+            wind_direction: Some(current.wind_direction_10m),
             uv,
             sunrise,
             sunset,
             fetched_at: Utc::now(),
             hourly: Vec::new(),
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+            weather_code: None,
         };
 
         // Parse hourly forecasts
@@ -292,57 +414,97 @@ impl WeatherClient {
     }
 
     /// Parse the Open-Meteo API response with full hourly data into a Weather struct
-    /// This populates the Weather.hourly field with today's hourly forecasts
+    /// This populates the Weather.hourly field with today's hourly forecasts.
+    /// Sunrise and sunset are computed for the coordinates rather than read from the API,
+    /// in the same timezone the hourly times were requested in.
+    ///
+    /// Only a missing current temperature is fatal. Other missing or malformed
+    /// values fall back to defaults and are recorded in `Weather.parse_warnings`.
     fn parse_response_full(
         &self,
         response: OpenMeteoResponseFull,
+        lat: f64,
+        lon: f64,
+        timezone: &BeachTimezone,
     ) -> Result<Weather, WeatherError> {
         let current = response.current;
         let daily = response.daily;
+        let mut warnings = Vec::new();
 
         // Extract temperature and weather data
-        let temperature = current.temperature_2m;
-        let feels_like = current.apparent_temperature;
-        let humidity = current.relative_humidity_2m as u8;
-        let wind = current.wind_speed_10m;
+        let temperature = current
+            .temperature_2m
+            .ok_or_else(|| WeatherError::MissingField("temperature_2m".to_string()))?;
+        let feels_like = current.apparent_temperature.unwrap_or_else(|| {
+            warnings.push("apparent temperature missing, using air temperature".to_string());
+            temperature
+        });
+        let humidity = current.relative_humidity_2m.unwrap_or_else(|| {
+            warnings.push("humidity missing".to_string());
+            0.0
+        }) as u8;
+        let wind = current.wind_speed_10m.unwrap_or_else(|| {
+            warnings.push("wind speed missing".to_string());
+            0.0
+        });
 
         // Map weather code to condition
-        let condition = weather_code_to_condition(current.weather_code);
-
-        // Extract UV index (first day's max)
-        let uv = daily
-            .uv_index_max
-            .first()
-            .copied()
-            .ok_or_else(|| WeatherError::MissingField("uv_index_max".to_string()))?;
-
-        // Extract sunrise time (first day)
-        let sunrise_str = daily
-            .sunrise
-            .first()
-            .ok_or_else(|| WeatherError::MissingField("sunrise".to_string()))?;
-        let sunrise = parse_time(sunrise_str)?;
-
-        // Extract sunset time (first day)
-        let sunset_str = daily
-            .sunset
-            .first()
-            .ok_or_else(|| WeatherError::MissingField("sunset".to_string()))?;
-        let sunset = parse_time(sunset_str)?;
+        let weather_code = current.weather_code.and_then(wmo_code);
+        let condition = match current.weather_code {
+            Some(code) => known_weather_condition(code).unwrap_or_else(|| {
+                warnings.push(format!("unknown weather code {}", code));
+                WeatherCondition::Cloudy
+            }),
+            None => {
+                warnings.push("weather code missing".to_string());
+                WeatherCondition::Cloudy
+            }
+        };
 
         // Extract today's date from the first daily time entry
         let today = daily
-            .sunrise
+            .time
             .first()
-            .and_then(|s| s.split('T').next())
+            .and_then(|date_str| date_str.as_deref())
             .and_then(|date_str| NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok())
-            .unwrap_or_else(|| chrono::Local::now().date_naive());
+            .unwrap_or_else(|| timezone.now().date());
+
+        let (sunrise, sunset) = sunrise_sunset(lat, lon, today, timezone)
+            .ok_or_else(|| WeatherError::MissingField("sunrise".to_string()))?;
+
+        // Parse hourly forecasts for today and tomorrow, defaulting to empty vecs if
+        // missing; only today's problems are worth a warning
+        let (hourly, tomorrow) = match response.hourly {
+            Some(hourly_data) => (
+                self.parse_hourly_data_full(&hourly_data, today, &mut warnings),
+                today
+                    .succ_opt()
+                    .map(|tomorrow| {
+                        self.parse_hourly_data_full(&hourly_data, tomorrow, &mut Vec::new())
+                    })
+                    .unwrap_or_default(),
+            ),
+            None => {
+                warnings.push("hourly forecast missing".to_string());
+                (Vec::new(), Vec::new())
+            }
+        };
 
-        // Parse hourly forecasts for today only, defaulting to empty vec if missing
-        let hourly = match response.hourly {
-            Some(hourly_data) => self.parse_hourly_data_full(&hourly_data, today),
-            None => Vec::new(),
+        // Current UV is the hourly value for the hour the current conditions
+        // are for, falling back to the day's max (or the hourly peak without one)
+        let daily_max = match daily.uv_index_max.first().copied().flatten() {
+            Some(uv) => uv,
+            None => {
+                warnings.push("daily UV index missing".to_string());
+                hourly.iter().map(|h| h.uv).fold(0.0, f64::max)
+            }
         };
+        let current_hour = current
+            .time
+            .as_deref()
+            .and_then(|time| parse_datetime(time).ok())
+            .map_or_else(|| timezone.now().hour(), |time| time.hour());
+        let uv = uv_for_hour(&hourly, current_hour as u8, daily_max);
 
         Ok(Weather {
             temperature,
@@ -350,80 +512,237 @@ impl WeatherClient {
             condition,
             humidity,
             wind,
+            wind_direction: current.wind_direction_10m,
             uv,
             sunrise,
             sunset,
             fetched_at: Utc::now(),
             hourly,
+            tomorrow,
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: warnings,
+            weather_code,
         })
     }
 
-    /// Parse full hourly weather data arrays into HourlyForecast structs, filtered to today only
+    /// Parse full hourly weather data arrays into HourlyForecast structs, filtered to one day
+    ///
+    /// Short holes in the arrays are filled first: numbers are interpolated
+    /// between the hours either side, and weather codes and wind directions
+    /// carried forward from the hour before, with the hour marked as
+    /// `DataQuality::Filled`. Hours still missing temperature, wind or UV
+    /// are skipped; unknown weather codes read as cloudy. All three are
+    /// counted into `warnings`.
     fn parse_hourly_data_full(
         &self,
         hourly: &HourlyWeatherFull,
-        today: NaiveDate,
+        day: NaiveDate,
+        warnings: &mut Vec<String>,
     ) -> Vec<HourlyForecast> {
-        let len = hourly.time.len();
-
-        // Validate that required arrays have the same length; if not, return empty
-        if hourly.temperature_2m.len() != len
-            || hourly.weathercode.len() != len
-            || hourly.windspeed_10m.len() != len
-            || hourly.uv_index.len() != len
-        {
-            return Vec::new();
-        }
-
         let mut forecasts = Vec::new();
+        let mut skipped = 0;
+        let mut unknown_codes = 0;
+        let mut filled = 0;
+
+        let temperatures = GapFilled::interpolated(&hourly.temperature_2m);
+        let feels_like = GapFilled::interpolated(&hourly.apparent_temperature);
+        let codes = GapFilled::carried_forward(&hourly.weathercode);
+        let winds = GapFilled::interpolated(&hourly.windspeed_10m);
+        let directions = GapFilled::carried_forward(&hourly.winddirection_10m);
+        let uvs = GapFilled::interpolated(&hourly.uv_index);
+        let precipitation = GapFilled::interpolated(&hourly.precipitation_probability);
+        let dew_points = GapFilled::interpolated(&hourly.dew_point_2m);
+        let gusts = GapFilled::interpolated(&hourly.wind_gusts_10m);
+        let clouds = GapFilled::interpolated(&hourly.cloud_cover);
+        let low_clouds = GapFilled::interpolated(&hourly.cloud_cover_low);
+        let mid_clouds = GapFilled::interpolated(&hourly.cloud_cover_mid);
+        let high_clouds = GapFilled::interpolated(&hourly.cloud_cover_high);
+        let series = [
+            &temperatures,
+            &feels_like,
+            &codes,
+            &winds,
+            &directions,
+            &uvs,
+            &precipitation,
+            &dew_points,
+            &gusts,
+            &clouds,
+            &low_clouds,
+            &mid_clouds,
+            &high_clouds,
+        ];
 
-        for i in 0..len {
+        for (i, time) in hourly.time.iter().enumerate() {
             // Parse datetime from time string
-            let time = match parse_datetime(&hourly.time[i]) {
-                Ok(dt) => dt,
-                Err(_) => continue, // Skip invalid times
+            let time = match time.as_deref().map(parse_datetime) {
+                Some(Ok(dt)) => dt,
+                _ => continue, // Skip invalid times
             };
 
-            // Filter to today's date only
-            if time.date() != today {
+            // Filter to the requested day only
+            if time.date() != day {
                 continue;
             }
 
-            // Get feels_like, defaulting to temperature if not available
-            let feels_like = hourly
-                .apparent_temperature
+            let (Some(temperature), Some(wind), Some(uv)) =
+                (temperatures.get(i), winds.get(i), uvs.get(i))
+            else {
+                skipped += 1;
+                continue;
+            };
+
+            let data_quality = if series.iter().any(|values| values.was_filled(i)) {
+                filled += 1;
+                DataQuality::Filled
+            } else {
+                DataQuality::Reported
+            };
+
+            let condition = codes
                 .get(i)
-                .copied()
-                .unwrap_or(hourly.temperature_2m[i]);
+                .and_then(known_weather_condition)
+                .unwrap_or_else(|| {
+                    unknown_codes += 1;
+                    WeatherCondition::Cloudy
+                });
+
+            // Get feels_like, defaulting to temperature if not available
+            let feels_like = feels_like.get(i).unwrap_or(temperature);
 
             // Get wind direction, defaulting to 0 (N) if not available
-            let wind_direction_degrees = hourly.winddirection_10m.get(i).copied().unwrap_or(0.0);
+            let wind_direction_degrees = directions.get(i).unwrap_or(0.0);
 
             // Get precipitation probability, defaulting to 0 if not available
-            let precipitation_chance = hourly
-                .precipitation_probability
-                .get(i)
-                .and_then(|v| *v)
-                .unwrap_or(0);
+            let precipitation_chance = precipitation.get(i).unwrap_or(0.0) as u8;
+
+            let percent = |value: f64| value.round().clamp(0.0, 100.0) as u8;
+            let cloud_cover = match (
+                clouds.get(i),
+                low_clouds.get(i),
+                mid_clouds.get(i),
+                high_clouds.get(i),
+            ) {
+                (Some(total), Some(low), Some(mid), Some(high)) => Some(CloudCover {
+                    total: percent(total),
+                    low: percent(low),
+                    mid: percent(mid),
+                    high: percent(high),
+                }),
+                _ => None,
+            };
 
             forecasts.push(HourlyForecast {
                 hour: time.hour() as u8,
-                temperature: hourly.temperature_2m[i],
+                temperature,
                 feels_like,
-                condition: weather_code_to_condition(hourly.weathercode[i]),
-                wind: hourly.windspeed_10m[i],
+                condition,
+                wind,
                 wind_direction: degrees_to_direction(wind_direction_degrees),
-                uv: hourly.uv_index[i],
+                uv,
                 precipitation_chance,
+                dew_point: dew_points.get(i),
+                wind_gusts: gusts.get(i),
+                cloud_cover,
+                data_quality,
             });
         }
 
+        if filled > 0 {
+            warnings.push(format!(
+                "filled in {} hours with gaps from the hours around them",
+                filled
+            ));
+        }
+        if skipped > 0 {
+            warnings.push(format!("skipped {} hours with missing values", skipped));
+        }
+        if unknown_codes > 0 {
+            warnings.push(format!(
+                "{} hours had missing or unknown weather codes",
+                unknown_codes
+            ));
+        }
+
         forecasts
     }
 }
 
+/// One hourly variable with its short holes filled in, remembering which
+/// hours the provider left empty
+struct GapFilled<'a> {
+    reported: &'a [Option<f64>],
+    values: Vec<Option<f64>>,
+}
+
+impl<'a> GapFilled<'a> {
+    /// Fills holes of up to `MAX_FILL_HOURS` between two reported values
+    /// along the straight line between them; holes at either end stay empty
+    fn interpolated(reported: &'a [Option<f64>]) -> Self {
+        let mut values = reported.to_vec();
+        let mut previous: Option<(usize, f64)> = None;
+        for (i, value) in reported.iter().enumerate() {
+            let Some(value) = *value else {
+                continue;
+            };
+            if let Some((start, from)) = previous {
+                if i - start - 1 <= MAX_FILL_HOURS {
+                    let span = (i - start) as f64;
+                    for (j, hole) in values.iter_mut().enumerate().take(i).skip(start + 1) {
+                        let t = (j - start) as f64 / span;
+                        *hole = Some(from + (value - from) * t);
+                    }
+                }
+            }
+            previous = Some((i, value));
+        }
+        Self { reported, values }
+    }
+
+    /// Carries the last reported value forward over up to `MAX_FILL_HOURS`
+    /// missing ones, for values that don't blend (weather codes) or wrap
+    /// around (wind directions)
+    fn carried_forward(reported: &'a [Option<f64>]) -> Self {
+        let mut previous: Option<(usize, f64)> = None;
+        let values = reported
+            .iter()
+            .enumerate()
+            .map(|(i, value)| match *value {
+                Some(value) => {
+                    previous = Some((i, value));
+                    Some(value)
+                }
+                None => previous
+                    .filter(|&(at, _)| i - at <= MAX_FILL_HOURS)
+                    .map(|(_, value)| value),
+            })
+            .collect();
+        Self { reported, values }
+    }
+
+    /// The value for hour index `i`, reported or filled
+    fn get(&self, i: usize) -> Option<f64> {
+        self.values.get(i).copied().flatten()
+    }
+
+    /// Whether hour index `i` was missing and has been filled in
+    fn was_filled(&self, i: usize) -> bool {
+        self.reported.get(i).copied().flatten().is_none() && self.get(i).is_some()
+    }
+}
+
+/// UV index for the current conditions: the hourly forecast's value for
+/// `hour`, or the day's max when that hour has no forecast, so the current
+/// UV and the hourly UV agree hour by hour
+pub(crate) fn uv_for_hour(hourly: &[HourlyForecast], hour: u8, daily_max: f64) -> f64 {
+    hourly
+        .iter()
+        .find(|forecast| forecast.hour == hour)
+        .map_or(daily_max, |forecast| forecast.uv)
+}
+
 /// Convert wind direction in degrees to compass direction string
-fn degrees_to_direction(degrees: f64) -> String {
+pub(crate) fn degrees_to_direction(degrees: f64) -> String {
     // Normalize to 0-360 range
     let deg = ((degrees % 360.0) + 360.0) % 360.0;
 
@@ -472,18 +791,31 @@ fn parse_time(time_str: &str) -> Result<NaiveTime, WeatherError> {
 /// - 85-86: Snow showers
 /// - 95-99: Thunderstorm
 pub fn weather_code_to_condition(code: u8) -> WeatherCondition {
-    match code {
-        0 => WeatherCondition::Clear,
-        1..=3 => WeatherCondition::PartlyCloudy,
-        45 | 48 => WeatherCondition::Fog,
-        51..=55 | 61..=65 | 80..=82 => WeatherCondition::Rain,
-        56..=57 | 66..=67 => WeatherCondition::Showers,
-        71..=77 | 85..=86 => WeatherCondition::Snow,
-        95..=99 => WeatherCondition::Thunderstorm,
-        _ => WeatherCondition::Cloudy, // Default for unknown codes
+    known_weather_condition(code as f64).unwrap_or(WeatherCondition::Cloudy)
+}
+
+/// Map a WMO weather code to a condition, or `None` if the code isn't one we know
+///
+/// Codes arrive as JSON numbers, so non-integer and out-of-range values are
+/// treated as unknown rather than truncated.
+fn known_weather_condition(code: f64) -> Option<WeatherCondition> {
+    match wmo_code(code)? {
+        0 => Some(WeatherCondition::Clear),
+        1..=3 => Some(WeatherCondition::PartlyCloudy),
+        45 | 48 => Some(WeatherCondition::Fog),
+        51..=55 | 61..=65 | 80..=82 => Some(WeatherCondition::Rain),
+        56..=57 | 66..=67 => Some(WeatherCondition::Showers),
+        71..=77 | 85..=86 => Some(WeatherCondition::Snow),
+        95..=99 => Some(WeatherCondition::Thunderstorm),
+        _ => None,
     }
 }
 
+/// A weather code as sent in JSON, or `None` if it isn't a whole number from 0 to 255
+fn wmo_code(code: f64) -> Option<u8> {
+    (code.fract() == 0.0 && (0.0..=255.0).contains(&code)).then_some(code as u8)
+}
+
 /// Open-Meteo API response structure (used in tests for backward compatibility)
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -500,7 +832,6 @@ struct CurrentWeather {
     apparent_temperature: f64,
     weather_code: u8,
     wind_speed_10m: f64,
-    #[allow(dead_code)]
     wind_direction_10m: f64,
 }
 
@@ -512,6 +843,42 @@ struct DailyWeather {
     uv_index_max: Vec<f64>,
 }
 
+/// Current weather data for fetch_weather
+///
+/// Every value is optional so a null or a renamed variable degrades to a
+/// warning instead of failing the whole response.
+#[derive(Debug, Default, Deserialize)]
+struct CurrentWeatherFull {
+    /// Local time the values are for, e.g. "2024-07-15T14:15"
+    #[serde(default)]
+    time: Option<String>,
+    #[serde(default, deserialize_with = "lenient_number")]
+    temperature_2m: Option<f64>,
+    #[serde(default, deserialize_with = "lenient_number")]
+    relative_humidity_2m: Option<f64>,
+    #[serde(default, deserialize_with = "lenient_number")]
+    apparent_temperature: Option<f64>,
+    #[serde(default, deserialize_with = "lenient_number", alias = "weathercode")]
+    weather_code: Option<f64>,
+    #[serde(default, deserialize_with = "lenient_number", alias = "windspeed_10m")]
+    wind_speed_10m: Option<f64>,
+    #[serde(
+        default,
+        deserialize_with = "lenient_number",
+        alias = "winddirection_10m"
+    )]
+    wind_direction_10m: Option<f64>,
+}
+
+/// Daily weather data for fetch_weather (sun times are computed locally)
+#[derive(Debug, Default, Deserialize)]
+struct DailyWeatherFull {
+    #[serde(default, deserialize_with = "lenient_strings")]
+    time: Vec<Option<String>>,
+    #[serde(default, deserialize_with = "lenient_numbers")]
+    uv_index_max: Vec<Option<f64>>,
+}
+
 /// Open-Meteo API response structure with hourly data
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -533,34 +900,126 @@ struct HourlyWeather {
 }
 
 /// Open-Meteo API response structure with full hourly data for fetch_weather
+///
+/// Unknown fields are ignored and missing sections default, so additions and
+/// removals on the API side don't stop us from showing what we do get.
 #[derive(Debug, Deserialize)]
 struct OpenMeteoResponseFull {
-    current: CurrentWeather,
-    daily: DailyWeather,
+    #[serde(default)]
+    current: CurrentWeatherFull,
+    #[serde(default)]
+    daily: DailyWeatherFull,
     #[serde(default)]
     hourly: Option<HourlyWeatherFull>,
 }
 
 /// Hourly weather data from Open-Meteo with all fields needed for HourlyForecast
+///
+/// Accepts both the legacy (`weathercode`) and current (`weather_code`)
+/// variable names.
 #[derive(Debug, Deserialize)]
 struct HourlyWeatherFull {
-    time: Vec<String>,
-    temperature_2m: Vec<f64>,
-    #[serde(default)]
-    apparent_temperature: Vec<f64>,
-    weathercode: Vec<u8>,
-    windspeed_10m: Vec<f64>,
-    #[serde(default)]
-    winddirection_10m: Vec<f64>,
-    uv_index: Vec<f64>,
-    #[serde(default)]
-    precipitation_probability: Vec<Option<u8>>,
+    #[serde(default, deserialize_with = "lenient_strings")]
+    time: Vec<Option<String>>,
+    #[serde(default, deserialize_with = "lenient_numbers")]
+    temperature_2m: Vec<Option<f64>>,
+    #[serde(default, deserialize_with = "lenient_numbers")]
+    apparent_temperature: Vec<Option<f64>>,
+    #[serde(default, deserialize_with = "lenient_numbers", alias = "weather_code")]
+    weathercode: Vec<Option<f64>>,
+    #[serde(
+        default,
+        deserialize_with = "lenient_numbers",
+        alias = "wind_speed_10m"
+    )]
+    windspeed_10m: Vec<Option<f64>>,
+    #[serde(
+        default,
+        deserialize_with = "lenient_numbers",
+        alias = "wind_direction_10m"
+    )]
+    winddirection_10m: Vec<Option<f64>>,
+    #[serde(default, deserialize_with = "lenient_numbers")]
+    uv_index: Vec<Option<f64>>,
+    #[serde(default, deserialize_with = "lenient_numbers")]
+    precipitation_probability: Vec<Option<f64>>,
+    #[serde(default, deserialize_with = "lenient_numbers")]
+    dew_point_2m: Vec<Option<f64>>,
+    #[serde(default, deserialize_with = "lenient_numbers")]
+    wind_gusts_10m: Vec<Option<f64>>,
+    #[serde(default, deserialize_with = "lenient_numbers", alias = "cloudcover")]
+    cloud_cover: Vec<Option<f64>>,
+    #[serde(
+        default,
+        deserialize_with = "lenient_numbers",
+        alias = "cloudcover_low"
+    )]
+    cloud_cover_low: Vec<Option<f64>>,
+    #[serde(
+        default,
+        deserialize_with = "lenient_numbers",
+        alias = "cloudcover_mid"
+    )]
+    cloud_cover_mid: Vec<Option<f64>>,
+    #[serde(
+        default,
+        deserialize_with = "lenient_numbers",
+        alias = "cloudcover_high"
+    )]
+    cloud_cover_high: Vec<Option<f64>>,
+}
+
+/// Reads any JSON value as a number, treating null and non-numbers as missing
+fn lenient_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    Ok(Value::deserialize(deserializer)?.as_f64())
+}
+
+/// Reads a JSON array of numbers, treating nulls and non-numbers as missing
+/// entries and anything other than an array as no entries
+fn lenient_numbers<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Option<f64>>, D::Error> {
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Array(items) => items.iter().map(Value::as_f64).collect(),
+        _ => Vec::new(),
+    })
+}
+
+/// Reads a JSON array of strings, treating nulls and non-strings as missing
+/// entries and anything other than an array as no entries
+fn lenient_strings<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Option<String>>, D::Error> {
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| match item {
+                Value::String(text) => Some(text),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_low_bandwidth_asks_for_less() {
+        let (fields, days) = WeatherClient::new().forecast_query();
+        let (lean_fields, lean_days) = WeatherClient::new()
+            .with_low_bandwidth(true)
+            .forecast_query();
+
+        assert_eq!((days, lean_days), (2, 1));
+        assert!(lean_fields.len() < fields.len());
+        for field in lean_fields.split(',') {
+            assert!(fields.split(',').any(|f| f == field), "{field}");
+        }
+    }
+
     /// Sample valid Open-Meteo API response
     const VALID_RESPONSE: &str = r#"{
         "latitude": 49.28,
@@ -619,6 +1078,7 @@ mod tests {
         assert_eq!(weather.condition, WeatherCondition::PartlyCloudy);
         assert_eq!(weather.humidity, 65);
         assert!((weather.wind - 12.5).abs() < 0.01);
+        assert_eq!(weather.wind_direction, Some(270.0));
         assert!((weather.uv - 7.5).abs() < 0.01);
         assert_eq!(weather.sunrise, NaiveTime::from_hms_opt(5, 30, 0).unwrap());
         assert_eq!(weather.sunset, NaiveTime::from_hms_opt(21, 15, 0).unwrap());
@@ -1025,11 +1485,16 @@ mod tests {
                 condition: WeatherCondition::PartlyCloudy,
                 humidity: 65,
                 wind: 12.5,
+                wind_direction: None,
                 uv: 6.0,
                 sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
                 sunset: NaiveTime::from_hms_opt(21, 15, 0).unwrap(),
                 fetched_at: Utc::now(),
                 hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
             },
             hourly: vec![ApiHourlyForecast {
                 time: NaiveDateTime::parse_from_str("2024-07-15T14:00", "%Y-%m-%dT%H:%M").unwrap(),
@@ -1208,19 +1673,44 @@ mod tests {
 
         let client = WeatherClient::new();
         let weather = client
-            .parse_response_full(response)
+            .parse_response_full(response, 49.28, -123.12, &BeachTimezone::vancouver())
             .expect("Failed to parse weather with full hourly");
 
         // Verify current weather
         assert!((weather.temperature - 22.5).abs() < 0.01);
         assert!((weather.feels_like - 23.8).abs() < 0.01);
         assert_eq!(weather.condition, WeatherCondition::PartlyCloudy);
+        assert_eq!(weather.weather_code, Some(2));
+        assert_eq!(weather.description(), "Partly cloudy");
         assert_eq!(weather.humidity, 65);
         assert!((weather.wind - 12.5).abs() < 0.01);
-        assert!((weather.uv - 7.5).abs() < 0.01);
+        // The 2 PM hour's UV, not the day's max of 7.5
+        assert!((weather.uv - 7.0).abs() < 0.01);
 
         // Verify hourly array - should only contain today's hours (24 hours)
         assert_eq!(weather.hourly.len(), 24);
+        // Tomorrow's hours in the response are kept separately
+        assert_eq!(weather.tomorrow.len(), 4);
+        assert_eq!(weather.tomorrow[0].hour, 0);
+    }
+
+    #[test]
+    fn test_parse_response_full_computes_sun_times_for_location() {
+        let response: OpenMeteoResponseFull =
+            serde_json::from_str(VALID_RESPONSE_FULL).expect("Failed to parse valid response full");
+
+        let client = WeatherClient::new();
+        let weather = client
+            .parse_response_full(response, 49.28, -123.12, &BeachTimezone::vancouver())
+            .expect("Failed to parse weather with full hourly");
+
+        // Sun times come from the solar calculation for the response's date,
+        // not from the API's daily strings
+        let date = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+        let (sunrise, sunset) =
+            sunrise_sunset(49.28, -123.12, date, &BeachTimezone::vancouver()).unwrap();
+        assert_eq!(weather.sunrise, sunrise);
+        assert_eq!(weather.sunset, sunset);
     }
 
     #[test]
@@ -1230,7 +1720,7 @@ mod tests {
 
         let client = WeatherClient::new();
         let weather = client
-            .parse_response_full(response)
+            .parse_response_full(response, 49.28, -123.12, &BeachTimezone::vancouver())
             .expect("Failed to parse weather with full hourly");
 
         // All hours should be from 0-23
@@ -1259,7 +1749,7 @@ mod tests {
 
         let client = WeatherClient::new();
         let weather = client
-            .parse_response_full(response)
+            .parse_response_full(response, 49.28, -123.12, &BeachTimezone::vancouver())
             .expect("Failed to parse weather with full hourly");
 
         // Check first hour (midnight)
@@ -1310,14 +1800,16 @@ mod tests {
 
         let client = WeatherClient::new();
         let weather = client
-            .parse_response_full(response)
+            .parse_response_full(response, 49.28, -123.12, &BeachTimezone::vancouver())
             .expect("Failed to parse weather without hourly");
 
         // Should have empty hourly vec
         assert!(weather.hourly.is_empty());
 
-        // Current weather should still be valid
+        // Current weather should still be valid, with the day's max UV for
+        // want of an hourly one
         assert!((weather.temperature - 22.5).abs() < 0.01);
+        assert!((weather.uv - 7.5).abs() < 0.01);
     }
 
     #[test]
@@ -1387,7 +1879,7 @@ mod tests {
 
         let client = WeatherClient::new();
         let weather = client
-            .parse_response_full(response)
+            .parse_response_full(response, 49.28, -123.12, &BeachTimezone::vancouver())
             .expect("Failed to parse weather with minimal hourly");
 
         // Should have 2 hourly forecasts
@@ -1419,6 +1911,10 @@ mod tests {
                 wind_direction: "NW".to_string(),
                 uv: 5.0,
                 precipitation_chance: 10,
+                dew_point: None,
+                wind_gusts: None,
+                cloud_cover: None,
+                data_quality: DataQuality::Reported,
             },
             HourlyForecast {
                 hour: 11,
@@ -1429,6 +1925,10 @@ mod tests {
                 wind_direction: "W".to_string(),
                 uv: 6.0,
                 precipitation_chance: 15,
+                dew_point: None,
+                wind_gusts: None,
+                cloud_cover: None,
+                data_quality: DataQuality::Reported,
             },
         ];
 
@@ -1438,11 +1938,16 @@ mod tests {
             condition: WeatherCondition::Clear,
             humidity: 60,
             wind: 10.0,
+            wind_direction: None,
             uv: 5.0,
             sunrise: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
             sunset: NaiveTime::from_hms_opt(20, 30, 0).unwrap(),
             fetched_at: Utc::now(),
             hourly,
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+            weather_code: None,
         };
 
         // Serialize to JSON (simulating cache write)
@@ -1462,4 +1967,276 @@ mod tests {
         assert_eq!(deserialized.hourly[1].wind_direction, "W");
         assert_eq!(deserialized.hourly[1].precipitation_chance, 15);
     }
+
+    /// Schema variant: nulls inside hourly arrays and a null current value
+    const SCHEMA_NULLS_IN_ARRAYS: &str = r#"{
+        "current": {
+            "temperature_2m": 18.0,
+            "relative_humidity_2m": null,
+            "apparent_temperature": 17.0,
+            "weather_code": 3,
+            "wind_speed_10m": 9.0,
+            "wind_direction_10m": null
+        },
+        "daily": {
+            "time": ["2024-07-15"],
+            "uv_index_max": [null]
+        },
+        "hourly": {
+            "time": ["2024-07-15T12:00", "2024-07-15T13:00", "2024-07-15T14:00"],
+            "temperature_2m": [20.0, null, 22.0],
+            "apparent_temperature": [null, 20.5, 21.5],
+            "weathercode": [2, 2, null],
+            "windspeed_10m": [10.0, 11.0, 12.0],
+            "winddirection_10m": [270, 270, null],
+            "uv_index": [5.0, 5.5, 6.0],
+            "precipitation_probability": [null, 10, 20],
+            "dew_point_2m": [19.5, 18.0, null],
+            "wind_gusts_10m": [24.0, null, 30.0],
+            "cloud_cover": [40, 60, 100],
+            "cloud_cover_low": [10, 30, 90],
+            "cloud_cover_mid": [20, 20, 20],
+            "cloud_cover_high": [30, null, 0]
+        }
+    }"#;
+
+    /// Schema variant: current variable names in the hourly block plus fields
+    /// and sections we don't request
+    const SCHEMA_RENAMED_AND_NEW_FIELDS: &str = r#"{
+        "latitude": 49.28,
+        "longitude": -123.12,
+        "current": {
+            "time": "2024-07-15T14:00",
+            "temperature_2m": 22.5,
+            "relative_humidity_2m": 65,
+            "apparent_temperature": 23.8,
+            "weather_code": 0,
+            "wind_speed_10m": 12.5,
+            "wind_direction_10m": 270,
+            "is_day": 1,
+            "cloud_cover": 5
+        },
+        "daily": {
+            "time": ["2024-07-15"],
+            "uv_index_max": [7.5],
+            "daylight_duration": [56700.0]
+        },
+        "hourly": {
+            "time": ["2024-07-15T12:00", "2024-07-15T13:00"],
+            "temperature_2m": [22.0, 23.0],
+            "weather_code": [0, 1],
+            "wind_speed_10m": [10.0, 11.0],
+            "wind_direction_10m": [90, 180],
+            "uv_index": [6.0, 6.5],
+            "visibility": [24140, 24140]
+        },
+        "minutely_15": {
+            "time": ["2024-07-15T14:00"],
+            "temperature_2m": [22.5]
+        }
+    }"#;
+
+    /// Schema variant: weather codes outside the WMO table we map
+    const SCHEMA_UNKNOWN_CODES: &str = r#"{
+        "current": {
+            "temperature_2m": 15.0,
+            "relative_humidity_2m": 80,
+            "apparent_temperature": 14.0,
+            "weather_code": 100,
+            "wind_speed_10m": 5.0,
+            "wind_direction_10m": 0
+        },
+        "daily": {
+            "time": ["2024-07-15"],
+            "uv_index_max": [3.0]
+        },
+        "hourly": {
+            "time": ["2024-07-15T12:00", "2024-07-15T13:00"],
+            "temperature_2m": [15.0, 15.5],
+            "weathercode": [100, 61],
+            "windspeed_10m": [5.0, 6.0],
+            "uv_index": [3.0, 2.5]
+        }
+    }"#;
+
+    /// Schema variant: no hourly section and no daily UV
+    const SCHEMA_MISSING_SECTIONS: &str = r#"{
+        "current": {
+            "temperature_2m": 12.0,
+            "apparent_temperature": 10.0,
+            "weather_code": 61,
+            "wind_speed_10m": 20.0
+        },
+        "daily": {
+            "time": ["2024-07-15"]
+        }
+    }"#;
+
+    fn parse_fixture(json: &str) -> Result<Weather, WeatherError> {
+        let response: OpenMeteoResponseFull =
+            serde_json::from_str(json).expect("fixture should deserialize");
+        WeatherClient::new().parse_response_full(
+            response,
+            49.28,
+            -123.12,
+            &BeachTimezone::vancouver(),
+        )
+    }
+
+    #[test]
+    fn test_captured_fixture_parses_cleanly() {
+        // Refreshed from the live API with `vanbeach gen-fixtures`
+        let weather = parse_fixture(include_str!("../../tests/fixtures/open-meteo.json")).unwrap();
+        assert!(
+            weather.parse_warnings.is_empty(),
+            "{:?}",
+            weather.parse_warnings
+        );
+        assert_ne!(weather.sunrise, weather.sunset);
+    }
+
+    #[test]
+    fn test_valid_response_has_no_parse_warnings() {
+        let weather = parse_fixture(VALID_RESPONSE_FULL).unwrap();
+        assert!(weather.parse_warnings.is_empty());
+    }
+
+    #[test]
+    fn test_nulls_in_arrays_are_filled_with_warnings() {
+        let weather = parse_fixture(SCHEMA_NULLS_IN_ARRAYS).unwrap();
+
+        assert_eq!(weather.humidity, 0);
+        assert_eq!(weather.wind_direction, None);
+        // Falls back to the hourly UV peak
+        assert!((weather.uv - 6.0).abs() < 0.01);
+
+        // 13:00's temperature and gusts are interpolated
+        let hours: Vec<u8> = weather.hourly.iter().map(|h| h.hour).collect();
+        assert_eq!(hours, vec![12, 13, 14]);
+        assert!((weather.hourly[1].temperature - 21.0).abs() < 0.01);
+        assert_eq!(weather.hourly[1].wind_gusts, Some(27.0));
+        assert_eq!(weather.hourly[0].data_quality, DataQuality::Reported);
+        assert_eq!(weather.hourly[1].data_quality, DataQuality::Filled);
+
+        // 14:00's weather code and wind direction carry on from 13:00
+        assert_eq!(weather.hourly[2].condition, WeatherCondition::PartlyCloudy);
+        assert_eq!(weather.hourly[2].wind_direction, "W");
+        assert_eq!(weather.hourly[2].data_quality, DataQuality::Filled);
+
+        // Nothing to interpolate from before the first hour or after the last
+        assert!((weather.hourly[0].feels_like - 20.0).abs() < 0.01);
+        assert_eq!(weather.hourly[0].precipitation_chance, 0);
+        assert_eq!(weather.hourly[0].dew_point, Some(19.5));
+        assert_eq!(weather.hourly[2].dew_point, None);
+
+        // Cloud layers fill in like the rest
+        assert_eq!(
+            weather.hourly[2].cloud_cover,
+            Some(CloudCover {
+                total: 100,
+                low: 90,
+                mid: 20,
+                high: 0
+            })
+        );
+        assert_eq!(weather.hourly[1].cloud_cover.map(|c| c.high), Some(15));
+
+        let warnings = weather.parse_warnings.join("; ");
+        assert!(warnings.contains("humidity missing"), "{}", warnings);
+        assert!(warnings.contains("daily UV index missing"), "{}", warnings);
+        assert!(warnings.contains("filled in 2 hours"), "{}", warnings);
+        assert!(!warnings.contains("skipped"), "{}", warnings);
+        assert!(!warnings.contains("weather codes"), "{}", warnings);
+    }
+
+    #[test]
+    fn test_gaps_are_only_filled_when_short() {
+        let reported = [
+            Some(10.0),
+            None,
+            None,
+            Some(16.0),
+            None,
+            None,
+            None,
+            None,
+            Some(0.0),
+            None,
+        ];
+        let interpolated = GapFilled::interpolated(&reported);
+        assert_eq!(interpolated.get(1), Some(12.0));
+        assert_eq!(interpolated.get(2), Some(14.0));
+        assert!(interpolated.was_filled(1));
+        assert!(!interpolated.was_filled(3));
+        // Four hours is too long a hole to draw a line across, and the
+        // last hour has nothing after it
+        assert_eq!(interpolated.get(5), None);
+        assert_eq!(interpolated.get(9), None);
+
+        let carried = GapFilled::carried_forward(&reported);
+        assert_eq!(carried.get(2), Some(10.0));
+        assert_eq!(carried.get(6), Some(16.0));
+        assert_eq!(carried.get(7), None);
+        assert_eq!(carried.get(9), Some(0.0));
+        assert!(!carried.was_filled(7));
+    }
+
+    #[test]
+    fn test_renamed_variables_and_new_fields_parse_cleanly() {
+        let weather = parse_fixture(SCHEMA_RENAMED_AND_NEW_FIELDS).unwrap();
+
+        assert!(
+            weather.parse_warnings.is_empty(),
+            "{:?}",
+            weather.parse_warnings
+        );
+        assert_eq!(weather.condition, WeatherCondition::Clear);
+        assert_eq!(weather.hourly.len(), 2);
+        assert_eq!(weather.hourly[1].condition, WeatherCondition::PartlyCloudy);
+        assert!((weather.hourly[1].wind - 11.0).abs() < 0.01);
+        assert_eq!(weather.hourly[1].wind_direction, "S");
+    }
+
+    #[test]
+    fn test_unknown_weather_codes_read_as_cloudy_with_warning() {
+        let weather = parse_fixture(SCHEMA_UNKNOWN_CODES).unwrap();
+
+        assert_eq!(weather.condition, WeatherCondition::Cloudy);
+        // Codes outside the WMO table fall back to the condition's label
+        assert_eq!(weather.description(), "Cloudy");
+        assert_eq!(weather.hourly[0].condition, WeatherCondition::Cloudy);
+        assert_eq!(weather.hourly[1].condition, WeatherCondition::Rain);
+        assert!(weather
+            .parse_warnings
+            .contains(&"unknown weather code 100".to_string()));
+    }
+
+    #[test]
+    fn test_missing_sections_degrade_to_defaults() {
+        let weather = parse_fixture(SCHEMA_MISSING_SECTIONS).unwrap();
+
+        assert!(weather.hourly.is_empty());
+        assert_eq!(weather.uv, 0.0);
+        assert_eq!(weather.condition, WeatherCondition::Rain);
+        let warnings = weather.parse_warnings.join("; ");
+        assert!(warnings.contains("hourly forecast missing"));
+        assert!(warnings.contains("humidity missing"));
+    }
+
+    #[test]
+    fn test_missing_current_temperature_is_still_an_error() {
+        let result = parse_fixture(r#"{"current": {"temperature_2m": null}}"#);
+        assert!(
+            matches!(result, Err(WeatherError::MissingField(field)) if field == "temperature_2m")
+        );
+    }
+
+    #[test]
+    fn test_known_weather_condition_rejects_out_of_table_codes() {
+        assert_eq!(known_weather_condition(0.0), Some(WeatherCondition::Clear));
+        assert_eq!(known_weather_condition(100.0), None);
+        assert_eq!(known_weather_condition(2.5), None);
+        assert_eq!(known_weather_condition(-1.0), None);
+        assert_eq!(weather_code_to_condition(200), WeatherCondition::Cloudy);
+    }
 }
@@ -0,0 +1,208 @@
+//! Open-Meteo historical weather (archive) API client
+//!
+//! Fetches the weather a beach actually had over past days, hour by hour, so
+//! it can be kept next to the recorded score history: enough to check later
+//! how the best windows we picked held up, and a local dataset to analyze.
+//! The archive lags a few days behind, so the latest days can come back
+//! empty or with some hours missing.
+
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, NaiveDateTime, Timelike};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{recording, Beach};
+
+/// Base URL for the Open-Meteo historical weather API
+const ARCHIVE_BASE_URL: &str = "https://archive-api.open-meteo.com/v1/archive";
+
+/// Hourly variables requested from the archive
+const ARCHIVE_HOURLY_FIELDS: &str =
+    "temperature_2m,weathercode,windspeed_10m,winddirection_10m,precipitation";
+
+/// Errors that can occur when fetching past weather
+#[derive(Debug, Error)]
+pub enum ArchiveError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The response isn't the expected JSON
+    #[error("Failed to parse weather archive: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// The weather observed in one hour
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObservedHour {
+    /// Air temperature in Celsius
+    pub temperature: f64,
+    /// WMO weather code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weather_code: Option<u8>,
+    /// Wind speed in km/h
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wind: Option<f64>,
+    /// Direction the wind blew from, in degrees
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wind_direction: Option<f64>,
+    /// Rain and snow over the hour, in millimetres
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precipitation: Option<f64>,
+}
+
+/// The weather observed over one day at a beach, keyed by local hour (0-23)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObservedDay {
+    pub date: NaiveDate,
+    pub hours: BTreeMap<u8, ObservedHour>,
+}
+
+/// Client for the Open-Meteo historical weather API
+#[derive(Debug, Clone)]
+pub struct ArchiveClient {
+    client: Client,
+    base_url: String,
+}
+
+impl Default for ArchiveClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArchiveClient {
+    /// Creates a client for the public API
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+            base_url: ARCHIVE_BASE_URL.to_string(),
+        }
+    }
+
+    /// Points the client at another server
+    #[allow(dead_code)]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Fetches the weather at a beach from `start` to `end`, inclusive, in
+    /// the beach's local time; days the archive has nothing for are left out
+    pub async fn fetch_days(
+        &self,
+        beach: &Beach,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<ObservedDay>, ArchiveError> {
+        let url = format!(
+            "{}?latitude={}&longitude={}&start_date={}&end_date={}&hourly={}&timezone={}",
+            self.base_url,
+            beach.latitude,
+            beach.longitude,
+            start,
+            end,
+            ARCHIVE_HOURLY_FIELDS,
+            beach.timezone.name
+        );
+        let text = recording::send(self.client.get(&url))
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        parse_days(&text)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ArchiveResponse {
+    hourly: ArchiveHourly,
+}
+
+#[derive(Debug, Deserialize)]
+struct ArchiveHourly {
+    time: Vec<String>,
+    temperature_2m: Vec<Option<f64>>,
+    #[serde(default)]
+    weathercode: Vec<Option<u8>>,
+    #[serde(default)]
+    windspeed_10m: Vec<Option<f64>>,
+    #[serde(default)]
+    winddirection_10m: Vec<Option<f64>>,
+    #[serde(default)]
+    precipitation: Vec<Option<f64>>,
+}
+
+/// Reads the hourly observations by day, skipping hours without a
+/// temperature (not yet in the archive)
+fn parse_days(text: &str) -> Result<Vec<ObservedDay>, ArchiveError> {
+    let hourly = serde_json::from_str::<ArchiveResponse>(text)?.hourly;
+    let at = |values: &[Option<f64>], i: usize| values.get(i).copied().flatten();
+
+    let mut days: BTreeMap<NaiveDate, BTreeMap<u8, ObservedHour>> = BTreeMap::new();
+    for (i, time) in hourly.time.iter().enumerate() {
+        let Ok(time) = NaiveDateTime::parse_from_str(time, "%Y-%m-%dT%H:%M") else {
+            continue;
+        };
+        let Some(temperature) = at(&hourly.temperature_2m, i) else {
+            continue;
+        };
+        days.entry(time.date()).or_default().insert(
+            time.hour() as u8,
+            ObservedHour {
+                temperature,
+                weather_code: hourly.weathercode.get(i).copied().flatten(),
+                wind: at(&hourly.windspeed_10m, i),
+                wind_direction: at(&hourly.winddirection_10m, i),
+                precipitation: at(&hourly.precipitation, i),
+            },
+        );
+    }
+    Ok(days
+        .into_iter()
+        .map(|(date, hours)| ObservedDay { date, hours })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days_groups_hours_and_skips_unarchived_ones() {
+        let days = parse_days(
+            r#"{
+                "latitude": 49.28,
+                "longitude": -123.16,
+                "hourly": {
+                    "time": ["2026-07-14T23:00", "2026-07-15T00:00", "2026-07-15T01:00", "bad"],
+                    "temperature_2m": [18.5, 17.9, null, 20.0],
+                    "weathercode": [0, 61, null, 0],
+                    "windspeed_10m": [8.2, 12.0, null, 5.0],
+                    "winddirection_10m": [270, 250, null, 90],
+                    "precipitation": [0.0, 1.2, null, 0.0]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date, NaiveDate::from_ymd_opt(2026, 7, 14).unwrap());
+        assert_eq!(days[0].hours[&23].temperature, 18.5);
+        assert_eq!(
+            days[1].hours.get(&0),
+            Some(&ObservedHour {
+                temperature: 17.9,
+                weather_code: Some(61),
+                wind: Some(12.0),
+                wind_direction: Some(250.0),
+                precipitation: Some(1.2),
+            })
+        );
+        // 1am isn't archived yet
+        assert_eq!(days[1].hours.len(), 1);
+        assert!(parse_days(r#"{"error": true}"#).is_err());
+    }
+}
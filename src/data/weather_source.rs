@@ -0,0 +1,316 @@
+//! Weather provider abstraction and failover
+//!
+//! This module defines the `WeatherSource` trait implemented by each weather
+//! client, and `WeatherFailover`, which tries a primary source and falls back
+//! to a secondary one when the primary is down or rate-limited. Nearby
+//! beaches share a forecast grid cell, so `fetch_weather_by_cell` fetches
+//! once per cell instead of once per beach.
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use super::met_norway::MetNorwayClient;
+use super::weather::{WeatherClient, WeatherError};
+use super::{BeachTimezone, Weather};
+
+/// A provider of current weather and today's hourly forecast
+pub trait WeatherSource {
+    /// Fetch weather for the given coordinates, with hours and sun times in
+    /// the location's timezone
+    fn fetch_weather(
+        &self,
+        lat: f64,
+        lon: f64,
+        timezone: &BeachTimezone,
+    ) -> impl Future<Output = Result<Weather, WeatherError>> + Send;
+}
+
+impl WeatherSource for WeatherClient {
+    fn fetch_weather(
+        &self,
+        lat: f64,
+        lon: f64,
+        timezone: &BeachTimezone,
+    ) -> impl Future<Output = Result<Weather, WeatherError>> + Send {
+        WeatherClient::fetch_weather(self, lat, lon, timezone)
+    }
+}
+
+impl WeatherSource for MetNorwayClient {
+    fn fetch_weather(
+        &self,
+        lat: f64,
+        lon: f64,
+        timezone: &BeachTimezone,
+    ) -> impl Future<Output = Result<Weather, WeatherError>> + Send {
+        MetNorwayClient::fetch_weather(self, lat, lon, timezone)
+    }
+}
+
+/// Weather source that falls back to a secondary provider on failure
+///
+/// The returned `Weather` records which provider actually served it in its
+/// `source` field. If both providers fail, the primary's error is returned.
+#[derive(Debug, Clone)]
+pub struct WeatherFailover<P = WeatherClient, F = MetNorwayClient> {
+    primary: P,
+    fallback: F,
+}
+
+impl Default for WeatherFailover {
+    fn default() -> Self {
+        Self::new(WeatherClient::new(), MetNorwayClient::new())
+    }
+}
+
+impl<P, F> WeatherFailover<P, F> {
+    /// Create a failover source from a primary and fallback source
+    pub fn new(primary: P, fallback: F) -> Self {
+        Self { primary, fallback }
+    }
+}
+
+impl<P, F> WeatherSource for WeatherFailover<P, F>
+where
+    P: WeatherSource + Sync,
+    F: WeatherSource + Sync,
+{
+    async fn fetch_weather(
+        &self,
+        lat: f64,
+        lon: f64,
+        timezone: &BeachTimezone,
+    ) -> Result<Weather, WeatherError> {
+        match self.primary.fetch_weather(lat, lon, timezone).await {
+            Ok(weather) => Ok(weather),
+            Err(primary_err) => self
+                .fallback
+                .fetch_weather(lat, lon, timezone)
+                .await
+                .map_err(|_| primary_err),
+        }
+    }
+}
+
+/// Size of the grid cells weather is shared across, in degrees
+///
+/// About 5.5 km north-south and 3.6 km east-west at Vancouver's latitude,
+/// which is finer than the weather models' own resolution.
+pub const WEATHER_GRID_DEGREES: f64 = 0.05;
+
+/// A weather grid cell: coordinates rounded to `WEATHER_GRID_DEGREES`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridCell {
+    lat: i64,
+    lon: i64,
+}
+
+impl GridCell {
+    /// The cell containing the given coordinates
+    pub fn containing(lat: f64, lon: f64) -> Self {
+        Self {
+            lat: (lat / WEATHER_GRID_DEGREES).round() as i64,
+            lon: (lon / WEATHER_GRID_DEGREES).round() as i64,
+        }
+    }
+
+    /// Coordinates of the cell's centre, rounded to 4 decimal places
+    pub fn center(&self) -> (f64, f64) {
+        let round = |v: f64| (v * 10_000.0).round() / 10_000.0;
+        (
+            round(self.lat as f64 * WEATHER_GRID_DEGREES),
+            round(self.lon as f64 * WEATHER_GRID_DEGREES),
+        )
+    }
+}
+
+/// Fetches weather for each location, making one request per grid cell
+///
+/// Locations in the same cell share the weather fetched at the cell's
+/// centre; the requests for different cells run concurrently. Results are
+/// in the order of `locations`, with `None` where the fetch failed.
+pub async fn fetch_weather_by_cell<S: WeatherSource>(
+    source: &S,
+    locations: &[(f64, f64, &BeachTimezone)],
+) -> Vec<Option<Weather>> {
+    let mut cells: Vec<(GridCell, &BeachTimezone)> = Vec::new();
+    for &(lat, lon, timezone) in locations {
+        let cell = GridCell::containing(lat, lon);
+        if !cells.iter().any(|(seen, _)| *seen == cell) {
+            cells.push((cell, timezone));
+        }
+    }
+
+    let results = futures::future::join_all(cells.iter().map(|(cell, timezone)| {
+        let (lat, lon) = cell.center();
+        source.fetch_weather(lat, lon, timezone)
+    }))
+    .await;
+    let by_cell: HashMap<GridCell, Weather> = cells
+        .iter()
+        .zip(results)
+        .filter_map(|((cell, _), result)| Some((*cell, result.ok()?)))
+        .collect();
+
+    locations
+        .iter()
+        .map(|&(lat, lon, _)| by_cell.get(&GridCell::containing(lat, lon)).cloned())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{WeatherCondition, WeatherProvider};
+    use chrono::{NaiveTime, Utc};
+    use std::sync::Mutex;
+
+    /// Source that returns a fixed result without touching the network
+    struct StubSource {
+        provider: WeatherProvider,
+        fail: bool,
+    }
+
+    impl WeatherSource for StubSource {
+        async fn fetch_weather(
+            &self,
+            _lat: f64,
+            _lon: f64,
+            _timezone: &BeachTimezone,
+        ) -> Result<Weather, WeatherError> {
+            if self.fail {
+                return Err(WeatherError::RateLimited);
+            }
+            Ok(Weather {
+                temperature: 20.0,
+                feels_like: 20.0,
+                condition: WeatherCondition::Clear,
+                humidity: 50,
+                wind: 5.0,
+                wind_direction: None,
+                uv: 4.0,
+                sunrise: NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(20, 0, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: self.provider,
+                parse_warnings: Vec::new(),
+                weather_code: None,
+            })
+        }
+    }
+
+    fn stub(provider: WeatherProvider, fail: bool) -> StubSource {
+        StubSource { provider, fail }
+    }
+
+    /// Source that records the coordinates it was asked for
+    #[derive(Default)]
+    struct RecordingSource {
+        requests: Mutex<Vec<(f64, f64)>>,
+    }
+
+    impl WeatherSource for RecordingSource {
+        async fn fetch_weather(
+            &self,
+            lat: f64,
+            lon: f64,
+            timezone: &BeachTimezone,
+        ) -> Result<Weather, WeatherError> {
+            self.requests.lock().unwrap().push((lat, lon));
+            let mut weather = stub(WeatherProvider::OpenMeteo, false)
+                .fetch_weather(lat, lon, timezone)
+                .await?;
+            weather.temperature = lat;
+            Ok(weather)
+        }
+    }
+
+    #[test]
+    fn test_grid_cell_groups_nearby_coordinates() {
+        // Second and Third Beach share a cell; Trout Lake is across town
+        let second = GridCell::containing(49.2912, -123.1513);
+        let third = GridCell::containing(49.2989, -123.1588);
+        let trout_lake = GridCell::containing(49.2555, -123.0644);
+
+        assert_eq!(second, third);
+        assert_ne!(second, trout_lake);
+        assert_eq!(second.center(), (49.3, -123.15));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_weather_by_cell_fetches_each_cell_once() {
+        let source = RecordingSource::default();
+        let timezone = BeachTimezone::vancouver();
+        let locations = [
+            (49.2912, -123.1513, &timezone),
+            (49.2555, -123.0644, &timezone),
+            (49.2989, -123.1588, &timezone),
+        ];
+
+        let weather = fetch_weather_by_cell(&source, &locations).await;
+
+        assert_eq!(
+            *source.requests.lock().unwrap(),
+            vec![(49.3, -123.15), (49.25, -123.05)]
+        );
+        let temperatures: Vec<f64> = weather
+            .iter()
+            .map(|w| w.as_ref().unwrap().temperature)
+            .collect();
+        assert_eq!(temperatures, vec![49.3, 49.25, 49.3]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_weather_by_cell_reports_failures_per_location() {
+        let source = stub(WeatherProvider::OpenMeteo, true);
+        let timezone = BeachTimezone::vancouver();
+
+        let weather = fetch_weather_by_cell(&source, &[(49.27, -123.15, &timezone)]).await;
+
+        assert_eq!(weather.len(), 1);
+        assert!(weather[0].is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failover_uses_primary_when_available() {
+        let source = WeatherFailover::new(
+            stub(WeatherProvider::OpenMeteo, false),
+            stub(WeatherProvider::MetNorway, false),
+        );
+
+        let weather = source
+            .fetch_weather(49.27, -123.15, &BeachTimezone::vancouver())
+            .await
+            .unwrap();
+        assert_eq!(weather.source, WeatherProvider::OpenMeteo);
+    }
+
+    #[tokio::test]
+    async fn test_failover_falls_back_when_primary_fails() {
+        let source = WeatherFailover::new(
+            stub(WeatherProvider::OpenMeteo, true),
+            stub(WeatherProvider::MetNorway, false),
+        );
+
+        let weather = source
+            .fetch_weather(49.27, -123.15, &BeachTimezone::vancouver())
+            .await
+            .unwrap();
+        assert_eq!(weather.source, WeatherProvider::MetNorway);
+    }
+
+    #[tokio::test]
+    async fn test_failover_returns_primary_error_when_both_fail() {
+        let source = WeatherFailover::new(
+            stub(WeatherProvider::OpenMeteo, true),
+            stub(WeatherProvider::MetNorway, true),
+        );
+
+        let result = source
+            .fetch_weather(49.27, -123.15, &BeachTimezone::vancouver())
+            .await;
+        assert!(matches!(result, Err(WeatherError::RateLimited)));
+    }
+}
@@ -0,0 +1,285 @@
+//! Multi-model wind forecasts from Open-Meteo
+//!
+//! With `--wind-models`, the wind forecast is also fetched from several
+//! weather models at once (Canada's GEM, the US GFS, ECMWF and Germany's
+//! ICON). Where they agree the forecast can be trusted; where they're far
+//! apart, a sailor should look at the water before rigging. The models are
+//! asked once for the middle of the shoreline, since their disagreement is
+//! regional rather than beach by beach.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{NaiveDate, NaiveDateTime, Timelike};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use super::rate_limit::RateLimiter;
+use super::recording;
+
+/// Base URL for the Open-Meteo forecast API
+const FORECAST_BASE_URL: &str = "https://api.open-meteo.com/v1/forecast";
+
+/// Models compared, as Open-Meteo names them
+const MODELS: [&str; 4] = [
+    "gem_seamless",
+    "gfs_seamless",
+    "ecmwf_ifs025",
+    "icon_seamless",
+];
+
+/// Prefix of each model's hourly wind speed field in the response
+const WIND_FIELD: &str = "wind_speed_10m";
+
+/// Spread between the fastest and slowest model wind (km/h) below which
+/// the models agree
+const AGREE_SPREAD_KMH: f64 = 6.0;
+
+/// Spread (km/h) from which the models disagree: the difference between a
+/// light breeze and a reefing wind
+const DISAGREE_SPREAD_KMH: f64 = 12.0;
+
+/// Errors that can occur when fetching multi-model winds
+#[derive(Debug, Error)]
+pub enum WindModelsError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The response isn't the expected JSON
+    #[error("Failed to parse model winds: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    /// The client-side request budget for the API's host is used up
+    #[error("Request budget for {0} used up; try again shortly")]
+    Throttled(String),
+}
+
+/// How far the models' winds for an hour are trusted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reliability {
+    /// The models are within a few km/h of each other
+    Agree,
+    /// The models differ noticeably
+    Mixed,
+    /// The models are far apart
+    Disagree,
+}
+
+impl Reliability {
+    /// Short badge text, e.g. "models disagree — verify before rigging"
+    pub fn badge(self) -> &'static str {
+        match self {
+            Reliability::Agree => "models agree",
+            Reliability::Mixed => "models differ",
+            Reliability::Disagree => "models disagree \u{2014} verify before rigging",
+        }
+    }
+}
+
+/// How the models' winds compare for one hour
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindAgreement {
+    /// Slowest model wind in km/h
+    pub lowest: f64,
+    /// Fastest model wind in km/h
+    pub highest: f64,
+    /// How many models forecast the hour
+    pub models: usize,
+}
+
+impl WindAgreement {
+    /// Fastest minus slowest model wind in km/h
+    pub fn spread(&self) -> f64 {
+        self.highest - self.lowest
+    }
+
+    /// How far the forecast is trusted, from the spread
+    pub fn reliability(&self) -> Reliability {
+        match self.spread() {
+            spread if spread < AGREE_SPREAD_KMH => Reliability::Agree,
+            spread if spread < DISAGREE_SPREAD_KMH => Reliability::Mixed,
+            _ => Reliability::Disagree,
+        }
+    }
+}
+
+/// Each model's hourly wind speed in km/h, by local date and hour
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModelWinds {
+    speeds: BTreeMap<(NaiveDate, u8), Vec<f64>>,
+}
+
+impl ModelWinds {
+    /// How the models compare for an hour, if at least two forecast it
+    pub fn agreement_at(&self, date: NaiveDate, hour: u8) -> Option<WindAgreement> {
+        let speeds = self.speeds.get(&(date, hour))?;
+        if speeds.len() < 2 {
+            return None;
+        }
+        Some(WindAgreement {
+            lowest: speeds.iter().copied().fold(f64::INFINITY, f64::min),
+            highest: speeds.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            models: speeds.len(),
+        })
+    }
+
+    /// Adds one model's wind for an hour
+    #[allow(dead_code)]
+    pub fn with_speed(mut self, date: NaiveDate, hour: u8, speed: f64) -> Self {
+        self.speeds.entry((date, hour)).or_default().push(speed);
+        self
+    }
+}
+
+/// Client for multi-model winds from the Open-Meteo forecast API
+#[derive(Debug, Clone)]
+pub struct WindModelsClient {
+    client: Client,
+    base_url: String,
+    /// Request budgets shared with the other clients
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl Default for WindModelsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindModelsClient {
+    /// Creates a client for the public API
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+            base_url: FORECAST_BASE_URL.to_string(),
+            rate_limiter: None,
+        }
+    }
+
+    /// Checks every request against the given shared rate limiter
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Points the client at another server
+    #[allow(dead_code)]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Fetches today's and tomorrow's hourly wind from every model at a
+    /// point, in the given timezone's local time
+    pub async fn fetch_wind_models(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        timezone: &str,
+    ) -> Result<ModelWinds, WindModelsError> {
+        let url = format!(
+            "{}?latitude={:.4}&longitude={:.4}&hourly={}&models={}&forecast_days=2&timezone={}",
+            self.base_url,
+            latitude,
+            longitude,
+            WIND_FIELD,
+            MODELS.join(","),
+            timezone
+        );
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire(&url)
+                .map_err(WindModelsError::Throttled)?;
+        }
+        let text = recording::send(self.client.get(&url))
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        parse_wind_models(&text)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelsResponse {
+    hourly: HashMap<String, Value>,
+}
+
+/// Reads every model's hourly wind, named `wind_speed_10m_<model>`,
+/// skipping hours a model leaves empty
+fn parse_wind_models(text: &str) -> Result<ModelWinds, WindModelsError> {
+    let response: ModelsResponse = serde_json::from_str(text)?;
+    let times: Vec<String> = response
+        .hourly
+        .get("time")
+        .cloned()
+        .map(serde_json::from_value)
+        .transpose()?
+        .unwrap_or_default();
+    let hours: Vec<Option<(NaiveDate, u8)>> = times
+        .iter()
+        .map(|time| {
+            let time = NaiveDateTime::parse_from_str(time, "%Y-%m-%dT%H:%M").ok()?;
+            Some((time.date(), time.hour() as u8))
+        })
+        .collect();
+
+    let mut winds = ModelWinds::default();
+    for (field, values) in &response.hourly {
+        if !field.starts_with(WIND_FIELD) {
+            continue;
+        }
+        let values: Vec<Option<f64>> = serde_json::from_value(values.clone())?;
+        for (hour, speed) in hours.iter().zip(values) {
+            if let (Some(hour), Some(speed)) = (hour, speed) {
+                winds.speeds.entry(*hour).or_default().push(speed);
+            }
+        }
+    }
+    Ok(winds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wind_models_and_rate_agreement() {
+        let winds = parse_wind_models(
+            r#"{
+                "latitude": 49.28,
+                "longitude": -123.17,
+                "hourly": {
+                    "time": ["2026-07-15T13:00", "2026-07-15T14:00", "2026-07-15T15:00"],
+                    "wind_speed_10m_gem_seamless": [12.0, 10.0, 8.0],
+                    "wind_speed_10m_gfs_seamless": [14.5, 25.0, null],
+                    "wind_speed_10m_ecmwf_ifs025": [11.0, 17.0, null]
+                }
+            }"#,
+        )
+        .unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 7, 15).unwrap();
+
+        let one = winds.agreement_at(date, 13).unwrap();
+        assert_eq!((one.lowest, one.highest, one.models), (11.0, 14.5, 3));
+        assert_eq!(one.reliability(), Reliability::Agree);
+        assert_eq!(
+            winds.agreement_at(date, 14).unwrap().reliability(),
+            Reliability::Disagree
+        );
+        // One model alone can't disagree with anything
+        assert_eq!(winds.agreement_at(date, 15), None);
+        assert_eq!(winds.agreement_at(date, 16), None);
+
+        let mixed = ModelWinds::default()
+            .with_speed(date, 9, 10.0)
+            .with_speed(date, 9, 18.0);
+        assert_eq!(
+            mixed.agreement_at(date, 9).unwrap().reliability(),
+            Reliability::Mixed
+        );
+        assert!(parse_wind_models(r#"{"error": true}"#).is_err());
+    }
+}
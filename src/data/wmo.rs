@@ -0,0 +1,36 @@
+//! WMO present weather codes
+//!
+//! Open-Meteo reports the current weather as a code from WMO code table 4677
+//! (`ww`, 0-99). Scoring only needs the coarse `WeatherCondition`, but the
+//! code says more: light drizzle versus heavy rain, snow grains versus snow
+//! showers. This module describes every code in the table. Codes 0-3 follow
+//! Open-Meteo's reading (clear to overcast) rather than the table's
+//! cloud-development wording.
+//!
+//! The descriptions are in `assets/wmo.json`, which can be overridden to
+//! reword or translate them (see `assets`).
+
+use super::assets;
+
+/// Describes a WMO weather code, e.g. 51 is "Light drizzle"
+///
+/// Returns `None` for codes outside the table.
+pub fn description(code: u8) -> Option<&'static str> {
+    assets::wmo().descriptions.get(&code).map(String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptions_tell_apart_codes_with_the_same_condition() {
+        assert_eq!(description(0), Some("Clear sky"));
+        assert_eq!(description(51), Some("Light drizzle"));
+        assert_eq!(description(65), Some("Heavy rain"));
+        assert_eq!(description(77), Some("Snow grains"));
+        assert_eq!(description(85), Some("Light snow showers"));
+        assert_eq!(description(99), Some("Thunderstorm with heavy hail"));
+        assert_eq!(description(100), None);
+    }
+}
@@ -0,0 +1,118 @@
+//! Maps links for directions to a beach
+//!
+//! `o` in a beach's detail view shows these links so directions can go
+//! straight to a phone. Built with `--features qr`, the Google Maps link is
+//! also drawn as a QR code to scan off the terminal; the link works in the
+//! browser and the Google Maps app on both iOS and Android, where the Apple
+//! Maps one only opens on Apple devices.
+
+use crate::data::Beach;
+
+/// Directions to a beach in Google Maps
+///
+/// Kept short, as the shorter the link the smaller its QR code.
+pub fn google_maps_url(beach: &Beach) -> String {
+    format!(
+        "https://maps.google.com/?daddr={:.5},{:.5}",
+        beach.latitude, beach.longitude
+    )
+}
+
+/// Directions to a beach in Apple Maps, labelled with its name
+pub fn apple_maps_url(beach: &Beach) -> String {
+    format!(
+        "https://maps.apple.com/?daddr={:.5},{:.5}&q={}",
+        beach.latitude,
+        beach.longitude,
+        query_encoded(&beach.name)
+    )
+}
+
+/// Percent-encodes everything but unreserved characters for a query value
+fn query_encoded(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Light modules around the code; the standard asks for 4 but phones read
+/// 2 fine, and the code then fits a 24-row terminal with the links
+#[cfg(feature = "qr")]
+const QUIET_ZONE: usize = 2;
+
+/// A QR code of `url` as rows of half-block characters, two modules per
+/// character cell, with a quiet zone of `QUIET_ZONE` modules around it
+///
+/// Light modules are drawn as blocks, so the code reads correctly as light
+/// blocks on a dark background. `None` if the url is too long to encode.
+#[cfg(feature = "qr")]
+pub fn qr_code(url: &str) -> Option<Vec<String>> {
+    use qrcode::render::unicode::Dense1x2;
+    use qrcode::{EcLevel, QrCode};
+
+    let code = QrCode::with_error_correction_level(url, EcLevel::L).ok()?;
+    let image = code
+        .render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .quiet_zone(false)
+        .build();
+
+    let mut rows: Vec<String> = image.lines().map(String::from).collect();
+    // Codes are an odd number of modules tall, so the last row's bottom half
+    // is the first of the quiet zone below
+    if let Some(last) = rows.last_mut() {
+        *last = last
+            .replace('\u{2580}', "\u{2588}")
+            .replace(' ', "\u{2584}");
+    }
+
+    let side = "\u{2588}".repeat(QUIET_ZONE);
+    let edge = "\u{2588}".repeat(code.width() + 2 * QUIET_ZONE);
+    let mut framed = vec![edge.clone(); QUIET_ZONE / 2];
+    framed.extend(rows.iter().map(|row| format!("{side}{row}{side}")));
+    framed.extend(vec![edge; QUIET_ZONE / 2]);
+    Some(framed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::get_beach_by_id;
+
+    #[test]
+    fn test_maps_links_point_at_the_beach() {
+        let beach = get_beach_by_id("spanish-banks-east").unwrap();
+        assert_eq!(
+            google_maps_url(beach),
+            "https://maps.google.com/?daddr=49.27560,-123.20890"
+        );
+        assert_eq!(
+            apple_maps_url(beach),
+            "https://maps.apple.com/?daddr=49.27560,-123.20890&q=Spanish%20Banks%20East"
+        );
+        assert_eq!(
+            query_encoded("Trout Lake & Co's"),
+            "Trout%20Lake%20%26%20Co%27s"
+        );
+    }
+
+    #[cfg(feature = "qr")]
+    #[test]
+    fn test_qr_code_is_square_in_half_blocks() {
+        let beach = get_beach_by_id("kitsilano").unwrap();
+        let rows = qr_code(&google_maps_url(beach)).unwrap();
+        let width = rows[0].chars().count();
+        assert!(rows.iter().all(|row| row.chars().count() == width));
+        // Two modules per row, so about half as many rows as columns
+        assert_eq!(rows.len(), width.div_ceil(2));
+        assert!(rows.len() <= 17, "{} rows", rows.len());
+        // The quiet zone is light, so drawn as blocks
+        assert!(rows[0].chars().all(|c| c == '\u{2588}'));
+    }
+}
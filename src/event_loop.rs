@@ -0,0 +1,813 @@
+//! The TUI event loop
+//!
+//! `run_app` draws, reads keys, fetches data and feeds everything to the app
+//! as `AppEvent`s until the user quits. Where keys and data come from is
+//! left to an `EventSource` and a `DataSource`, so the loop runs the same
+//! against a real terminal and the APIs as against a `TestBackend`, a
+//! scripted list of keys and canned data in the tests below.
+
+use std::future::Future;
+use std::io;
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{backend::Backend, Terminal};
+
+use crate::alerts::{self, AdvisoryWatch, AlertKind, Delivery};
+use crate::app::{App, AppState};
+use crate::cli::AlertStyle;
+use crate::clock::ClockMonitor;
+use crate::data::Beach;
+use crate::events::{AppEvent, BeachFetch, DataLoad, Feed};
+use crate::journal::{Journal, JournalWatch};
+use crate::linear::LinearOutput;
+use crate::power::PowerMonitor;
+use crate::rain_delay::RainDelayWatch;
+use crate::reminders::{self, WindowReminders};
+use crate::session::{SessionPlayer, SessionRecorder};
+use crate::ui::{self, theme::ColorSupport};
+
+/// How long the loop waits for a key before going round again
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Where the loop reads terminal events from
+pub trait EventSource {
+    /// Waits up to `timeout` for the next event; `None` if none arrived
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>>;
+}
+
+/// Events from the real terminal
+pub struct TerminalEvents;
+
+impl EventSource for TerminalEvents {
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        if event::poll(timeout)? {
+            event::read().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Where the app's beach data comes from
+pub trait DataSource {
+    /// Fetches conditions for `beaches`, using the app's clients and settings
+    fn fetch(&mut self, app: &App, beaches: &[&'static Beach]) -> impl Future<Output = BeachFetch>;
+
+    /// Refetches one feed for `beach`, past the cache
+    fn fetch_feed(
+        &mut self,
+        app: &App,
+        beach: &'static Beach,
+        feed: Feed,
+    ) -> impl Future<Output = BeachFetch>;
+}
+
+/// Data from the live APIs, through the app's clients
+pub struct LiveData;
+
+impl DataSource for LiveData {
+    async fn fetch(&mut self, app: &App, beaches: &[&'static Beach]) -> BeachFetch {
+        app.fetch_beaches(beaches).await
+    }
+
+    async fn fetch_feed(&mut self, app: &App, beach: &'static Beach, feed: Feed) -> BeachFetch {
+        app.fetch_feed(beach, feed).await
+    }
+}
+
+/// What the loop needs besides the terminal, the app and its sources
+pub struct RunOptions {
+    /// Colors the terminal can show
+    pub color_support: ColorSupport,
+    /// Whether frames are only drawn when something changed (`--slow-link`)
+    pub slow_link: bool,
+    /// Session being replayed (`--replay`), which supplies the data instead
+    pub player: Option<SessionPlayer>,
+    /// Session being recorded (`--record`)
+    pub recorder: Option<SessionRecorder>,
+    /// Journal that notable condition changes are appended to
+    pub journal: Option<Journal>,
+    /// Where frames are written as lines of text (`--linear`), in which case
+    /// the terminal draws off screen
+    pub linear: Option<LinearOutput>,
+    /// Socket that commands drive the TUI through (`--control`)
+    #[cfg(unix)]
+    pub control: Option<crate::control::ControlSocket>,
+    /// Draws webcam snapshots in place, if the terminal supports it
+    #[cfg(feature = "graphics")]
+    pub webcam_view: Option<crate::webcam::WebcamView>,
+    /// Broker that conditions and scores are published to after each
+    /// refresh
+    pub mqtt: Option<crate::mqtt::MqttPublisher>,
+    /// Terminal title kept in step with the screen, unless turned off
+    pub terminal_title: Option<crate::terminal_title::TerminalTitle>,
+    /// Feature usage counts, if the config opts in
+    pub usage: Option<crate::usage::UsageTracker>,
+    /// SIGTERM and SIGHUP, which quit as `q` does
+    #[cfg(unix)]
+    pub shutdown: Option<crate::signals::ShutdownSignals>,
+}
+
+/// Runs the app until it quits: loads data, then draws and handles events
+///
+/// The player and recorder are left in `options` so the caller can finish
+/// them off once the terminal is restored.
+pub async fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    events: &mut impl EventSource,
+    data: &mut impl DataSource,
+    options: &mut RunOptions,
+) -> io::Result<()> {
+    if let Some(recorder) = &mut options.recorder {
+        recorder.record_state(&app.state);
+    }
+
+    // Initial render to show loading state
+    draw(terminal, app, options)?;
+
+    // Trigger initial data load, unless the data comes from a recorded
+    // session; beaches off the first screen are deferred to the event loop
+    if let Some(player) = &mut options.player {
+        player.start(app);
+    } else {
+        let visible_rows = ui::beach_list::visible_rows(terminal.size()?.height);
+        app.load_first_paint(data, visible_rows).await;
+        if let Some(recorder) = &mut options.recorder {
+            recorder.record_data(&app.beach_conditions);
+        }
+    }
+
+    let mut clock = ClockMonitor::new();
+    let mut window_reminders = WindowReminders::default();
+    let mut advisory_watch = AdvisoryWatch::default();
+    let mut rain_delay_watch = RainDelayWatch::default();
+    let mut journal_watch = JournalWatch::resume(&app.journal);
+    let mut power_monitor = PowerMonitor::default();
+    // Linear output prints what changed, so only draws when something did
+    let mut redraw = RedrawGate::new(options.slow_link || options.linear.is_some());
+
+    loop {
+        if let Some(player) = &mut options.player {
+            // Replay drives the app; refreshes come from recorded data instead
+            if !player.step(app) {
+                draw(terminal, app, options)?;
+                break;
+            }
+            app.refresh_requested = false;
+        }
+        let replaying = options.player.is_some();
+
+        // After sleep/wake or a clock change, drop time-derived data and refetch
+        if clock.check() && !replaying {
+            app.handle_clock_jump(chrono::Local::now());
+        }
+
+        // Keep tide heights and next high/low current between refreshes, go
+        // ahead with a quick jump once no more digits are coming, remind
+        // about the best window for the selected activity before it starts,
+        // suggest a dry hour for pinned plans that rain is forecast for,
+        // call out advisories issued or lifted by a refresh, note them along
+        // with heat warnings and king tides in the journal, and refresh the
+        // dashboard once its data goes stale
+        if !replaying {
+            let now = chrono::Local::now();
+            app.update(AppEvent::Tick(now));
+            if let Some(reminder) = window_reminders.due(app, now) {
+                raise_alert(app, AlertKind::BestWindow, reminder.message(now), now);
+            }
+            if let Some((delays, new)) = rain_delay_watch.check(app, now) {
+                for delay in new {
+                    raise_alert(app, AlertKind::RainDelay, delay.message(), now);
+                }
+                if app.rain_delays != delays {
+                    app.rain_delays = delays;
+                    redraw.mark_dirty();
+                }
+            }
+            let changes = advisory_watch.changes(app);
+            if !changes.is_empty() {
+                raise_alert(app, AlertKind::AdvisoryChange, changes.join("; "), now);
+            }
+            if let Some(journal) = &options.journal {
+                let entries = journal_watch.changes(app, now.with_timezone(&chrono::Utc));
+                if !entries.is_empty() {
+                    // An entry that can't be saved still shows until exit
+                    let _ = journal.append(&entries);
+                    app.journal.extend(entries);
+                    redraw.mark_dirty();
+                }
+            }
+            // On battery the dashboard refreshes less often
+            if let Some(on_battery) = power_monitor.poll() {
+                app.update(AppEvent::OnBattery(on_battery));
+            }
+        }
+
+        // Deferred beaches arrive a batch per frame, so the ones on screen
+        // are already painted and keys still get through; each batch shows
+        // as in flight while it's fetched
+        if !app.deferred_load.is_empty() {
+            app.update(AppEvent::FetchStarted(app.next_deferred_batch()));
+            if redraw.should_draw(app, chrono::Local::now()) {
+                draw(terminal, app, options)?;
+            }
+            app.load_deferred(data).await;
+            redraw.mark_dirty();
+            if let Some(recorder) = &mut options.recorder {
+                recorder.record_data(&app.beach_conditions);
+            }
+        }
+
+        // Check if refresh was requested
+        if app.refresh_requested && app.state == AppState::BeachList {
+            // The list stays up, its rows marked until their data arrives a
+            // batch per frame
+            app.refresh_requested = false;
+            app.update(AppEvent::RefreshInBackground);
+            redraw.mark_dirty();
+        } else if app.refresh_requested {
+            app.refresh_requested = false;
+            if app.state == AppState::Dashboard {
+                // Keep the dashboard on screen while it refreshes in place
+                app.pending_dashboard = true;
+            } else {
+                // Show a brief "Refreshing..." state
+                app.state = AppState::Loading;
+                draw(terminal, app, options)?;
+            }
+            app.load_all_data(data).await;
+            redraw.mark_dirty();
+            if let Some(recorder) = &mut options.recorder {
+                recorder.record_data(&app.beach_conditions);
+            }
+        }
+
+        // One feed refetched for the beach on screen (`W`, `T`, `Q`)
+        if let Some((beach, feed)) = app.feed_refresh.take() {
+            let requested_at = chrono::Utc::now();
+            let fetch = data.fetch_feed(app, beach, feed).await;
+            app.update(AppEvent::DataLoaded(Box::new(DataLoad::Feed {
+                feed,
+                fetch,
+                requested_at,
+            })));
+            redraw.mark_dirty();
+            if let Some(recorder) = &mut options.recorder {
+                recorder.record_data(&app.beach_conditions);
+            }
+        }
+
+        if let Some(recorder) = &mut options.recorder {
+            recorder.record_state(&app.state);
+        }
+
+        // Publish whatever the refresh brought; replayed data isn't current
+        if let (Some(mqtt), false) = (&mut options.mqtt, replaying) {
+            mqtt.publish_changes(app, chrono::Local::now());
+        }
+
+        // Apply commands from the control socket; a screenshot waits for
+        // the frame drawn below
+        #[cfg(unix)]
+        if let Some(control) = &mut options.control {
+            if control.apply(app) {
+                redraw.mark_dirty();
+            }
+        }
+
+        // Render UI; a replay changes the app behind the gate's back
+        if replaying {
+            redraw.mark_dirty();
+        }
+        if redraw.should_draw(app, chrono::Local::now()) {
+            draw(terminal, app, options)?;
+        }
+
+        // Draw the webcam snapshot over the space the frame left for it
+        #[cfg(feature = "graphics")]
+        if let Some(webcam_view) = &mut options.webcam_view {
+            if webcam_view.update(app, &mut io::stdout()).await? {
+                terminal.clear()?;
+            }
+        }
+
+        let event = events.next_event(POLL_INTERVAL)?;
+        if event.is_some() {
+            redraw.mark_dirty();
+        }
+        match event {
+            Some(Event::Key(key)) => {
+                if replaying {
+                    // Only quitting is allowed while a session replays
+                    if key.code == KeyCode::Char('q') {
+                        app.should_quit = true;
+                    }
+                } else {
+                    app.update(AppEvent::Key(key));
+                    if let Some(recorder) = &mut options.recorder {
+                        recorder.record_key(&key);
+                    }
+                }
+            }
+            // Unfocused, the dashboard refreshes less often
+            Some(Event::FocusGained) => app.update(AppEvent::Focus(true)),
+            Some(Event::FocusLost) => app.update(AppEvent::Focus(false)),
+            _ => {}
+        }
+
+        // A closed terminal or a SIGTERM quits through the same exit path,
+        // so the caller still saves what the session gathered
+        #[cfg(unix)]
+        if options.shutdown.as_ref().is_some_and(|s| s.received()) {
+            app.should_quit = true;
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Draws a frame, writing it out as text too with `--linear`
+fn draw<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    options: &mut RunOptions,
+) -> io::Result<()> {
+    let color_support = options.color_support;
+    let frame = terminal.draw(|f| crate::render_ui(f, app, color_support))?;
+    crate::crash::note_state(app);
+    if let Some(terminal_title) = &mut options.terminal_title {
+        terminal_title.update(app, &mut io::stdout())?;
+    }
+    if let Some(usage) = &mut options.usage {
+        usage.observe(app, chrono::Local::now().date_naive());
+    }
+    if let Some(linear) = &mut options.linear {
+        linear.show((app.state.clone(), app.key_view()), frame.buffer)?;
+    }
+    #[cfg(unix)]
+    if let Some(control) = &mut options.control {
+        control.frame_drawn(frame.buffer);
+    }
+    Ok(())
+}
+
+/// What a frame shows that changes without an event
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FrameKey {
+    /// Minutes since the epoch: clocks, countdowns and time-derived scores
+    minute: i64,
+    state: AppState,
+    flashing: bool,
+    toast: bool,
+    load_progress: Option<(usize, usize)>,
+}
+
+impl FrameKey {
+    fn of(app: &App, now: chrono::DateTime<chrono::Local>) -> Self {
+        Self {
+            minute: now.timestamp() / 60,
+            state: app.state.clone(),
+            flashing: app.is_flashing(now),
+            toast: app.active_toast(now).is_some(),
+            load_progress: app.load_progress,
+        }
+    }
+}
+
+/// Decides which passes of the loop draw a frame
+///
+/// Ratatui already only sends the cells that changed since the last frame,
+/// but every draw still hides the cursor and flushes. With `--slow-link` a
+/// frame is only drawn after an event or a data load, or when what it shows
+/// changes on its own; anything counting down in seconds then moves once a
+/// minute.
+struct RedrawGate {
+    slow_link: bool,
+    dirty: bool,
+    last: Option<FrameKey>,
+}
+
+impl RedrawGate {
+    fn new(slow_link: bool) -> Self {
+        Self {
+            slow_link,
+            dirty: true,
+            last: None,
+        }
+    }
+
+    /// Makes the next pass draw, after an event or new data
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Whether this pass should draw a frame
+    fn should_draw(&mut self, app: &App, now: chrono::DateTime<chrono::Local>) -> bool {
+        if !self.slow_link {
+            return true;
+        }
+        let key = FrameKey::of(app, now);
+        let draw = self.dirty || self.last.as_ref() != Some(&key);
+        self.dirty = false;
+        self.last = Some(key);
+        draw
+    }
+}
+
+/// Shows an alert's toast, ringing the bell or flashing the screen as
+/// `--alert` asks and sending best window reminders and rain delays to the
+/// desktop as `--notify` asks
+///
+/// Alerts of a kind turned off in the config are dropped, and during quiet
+/// hours only the toast shows.
+fn raise_alert(
+    app: &mut App,
+    kind: AlertKind,
+    message: String,
+    now: chrono::DateTime<chrono::Local>,
+) {
+    let quiet = match alerts::delivery(&app.notifications, kind, now.time()) {
+        Delivery::Off => return,
+        Delivery::Quiet => true,
+        Delivery::Loud => false,
+    };
+    if !quiet {
+        if matches!(kind, AlertKind::BestWindow | AlertKind::RainDelay) && app.notify {
+            reminders::send_desktop_notification(&message);
+        }
+        if app.alert == Some(AlertStyle::Bell) {
+            alerts::ring_bell();
+        }
+    }
+    app.update(AppEvent::Alert {
+        message,
+        now,
+        quiet,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    use crossterm::event::{KeyEvent, KeyModifiers};
+    use ratatui::backend::{ClearType, CrosstermBackend, TestBackend, WindowSize};
+    use ratatui::buffer::Cell;
+    use ratatui::layout::{Position, Size};
+
+    use crate::data::{all_beaches, TidesClient, WaterQualityClient, WeatherClient};
+    use crate::events::FetchedBeach;
+
+    /// Keys typed in order, `None` for a poll that times out; running out is
+    /// an error so a script that never quits fails instead of hanging
+    struct ScriptedEvents(VecDeque<Option<Event>>);
+
+    impl ScriptedEvents {
+        fn keys(codes: &[KeyCode]) -> Self {
+            Self(
+                codes
+                    .iter()
+                    .map(|&code| Some(Event::Key(KeyEvent::new(code, KeyModifiers::NONE))))
+                    .collect(),
+            )
+        }
+
+        /// `polls` polls with nothing typed, then `q`
+        fn idle_then_quit(polls: usize) -> Self {
+            let mut script = Self::keys(&[KeyCode::Char('q')]);
+            for _ in 0..polls {
+                script.0.push_front(None);
+            }
+            script
+        }
+    }
+
+    impl EventSource for ScriptedEvents {
+        fn next_event(&mut self, _timeout: Duration) -> io::Result<Option<Event>> {
+            self.0
+                .pop_front()
+                .ok_or_else(|| io::Error::other("script ran out of keys"))
+        }
+    }
+
+    /// Counts the bytes written to it
+    #[derive(Clone, Default)]
+    struct ByteCounter(Rc<std::cell::Cell<usize>>);
+
+    impl io::Write for ByteCounter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.set(self.0.get() + buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// A terminal that also writes the escape sequences a real one would be
+    /// sent, to count the bytes a session costs
+    struct ByteCountingBackend {
+        screen: TestBackend,
+        wire: CrosstermBackend<ByteCounter>,
+        sent: ByteCounter,
+    }
+
+    impl ByteCountingBackend {
+        fn new(width: u16, height: u16) -> Self {
+            let sent = ByteCounter::default();
+            Self {
+                screen: TestBackend::new(width, height),
+                wire: CrosstermBackend::new(sent.clone()),
+                sent,
+            }
+        }
+
+        fn bytes_sent(&self) -> usize {
+            self.sent.0.get()
+        }
+    }
+
+    impl Backend for ByteCountingBackend {
+        fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+        where
+            I: Iterator<Item = (u16, u16, &'a Cell)>,
+        {
+            let content: Vec<_> = content.collect();
+            self.screen.draw(content.iter().copied())?;
+            self.wire.draw(content.into_iter())
+        }
+
+        fn hide_cursor(&mut self) -> io::Result<()> {
+            self.wire.hide_cursor()
+        }
+
+        fn show_cursor(&mut self) -> io::Result<()> {
+            self.wire.show_cursor()
+        }
+
+        fn get_cursor_position(&mut self) -> io::Result<Position> {
+            self.screen.get_cursor_position()
+        }
+
+        fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+            let position = position.into();
+            self.screen.set_cursor_position(position)?;
+            self.wire.set_cursor_position(position)
+        }
+
+        fn clear(&mut self) -> io::Result<()> {
+            self.screen.clear()?;
+            self.wire.clear()
+        }
+
+        fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+            self.screen.clear_region(clear_type)?;
+            self.wire.clear_region(clear_type)
+        }
+
+        fn size(&self) -> io::Result<Size> {
+            self.screen.size()
+        }
+
+        fn window_size(&mut self) -> io::Result<WindowSize> {
+            self.screen.window_size()
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.wire.flush()
+        }
+    }
+
+    /// Answers every fetch with no conditions, remembering how many beaches
+    /// each asked for and which feeds were refetched
+    #[derive(Default)]
+    struct CannedData {
+        fetches: Vec<usize>,
+        feeds: Vec<(String, Feed)>,
+    }
+
+    impl DataSource for CannedData {
+        async fn fetch(&mut self, _app: &App, beaches: &[&'static Beach]) -> BeachFetch {
+            self.fetches.push(beaches.len());
+            BeachFetch {
+                beaches: beaches
+                    .iter()
+                    .map(|&beach| FetchedBeach {
+                        beach,
+                        weather: None,
+                        water_quality: None,
+                        waves: None,
+                    })
+                    .collect(),
+                ..BeachFetch::default()
+            }
+        }
+
+        async fn fetch_feed(&mut self, app: &App, beach: &'static Beach, feed: Feed) -> BeachFetch {
+            self.feeds.push((beach.id.to_string(), feed));
+            self.fetch(app, &[beach]).await
+        }
+    }
+
+    /// An app that saves nothing; `CannedData` stands in for its clients
+    fn app() -> App {
+        App::with_clients(
+            WeatherClient::new(),
+            TidesClient::new(None),
+            WaterQualityClient::default(),
+        )
+    }
+
+    fn options() -> RunOptions {
+        RunOptions {
+            color_support: ColorSupport::TrueColor,
+            slow_link: false,
+            player: None,
+            recorder: None,
+            journal: None,
+            linear: None,
+            #[cfg(unix)]
+            control: None,
+            #[cfg(feature = "graphics")]
+            webcam_view: None,
+            mqtt: None,
+            terminal_title: None,
+            usage: None,
+            #[cfg(unix)]
+            shutdown: None,
+        }
+    }
+
+    async fn run(codes: &[KeyCode], app: &mut App, data: &mut CannedData) -> Terminal<TestBackend> {
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        let mut events = ScriptedEvents::keys(codes);
+        run_app(&mut terminal, app, &mut events, data, &mut options())
+            .await
+            .unwrap();
+        terminal
+    }
+
+    fn screen(terminal: &Terminal<TestBackend>) -> String {
+        let buffer = terminal.backend().buffer();
+        buffer.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_quit_ends_the_loop_after_loading_every_beach() {
+        let mut app = app();
+        let mut data = CannedData::default();
+
+        run(&[KeyCode::Char('q')], &mut app, &mut data).await;
+
+        assert!(app.should_quit);
+        assert_eq!(app.state, AppState::BeachList);
+        assert_eq!(
+            data.fetches.iter().sum::<usize>(),
+            all_beaches().len(),
+            "first paint and deferred batches cover every beach once"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_navigation_opens_the_selected_beach() {
+        let mut app = app();
+        let mut data = CannedData::default();
+
+        let terminal = run(
+            &[KeyCode::Char('j'), KeyCode::Enter, KeyCode::Char('q')],
+            &mut app,
+            &mut data,
+        )
+        .await;
+
+        let beach = &app.visible_beaches()[1];
+        assert_eq!(app.state, AppState::BeachDetail(beach.id.clone()));
+        assert!(screen(&terminal).contains(beach.name.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_detail_view_refetches_one_feed_for_its_beach() {
+        let mut app = app();
+        let mut data = CannedData::default();
+
+        run(
+            &[KeyCode::Enter, KeyCode::Char('T'), KeyCode::Char('q')],
+            &mut app,
+            &mut data,
+        )
+        .await;
+
+        let beach = &app.visible_beaches()[0];
+        assert_eq!(data.feeds, vec![(beach.id.to_string(), Feed::Tides)]);
+        assert!(app.feed_refresh.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_reloads_every_beach_in_the_background() {
+        let mut app = app();
+        app.refresh_cooldown = chrono::Duration::zero();
+        let mut data = CannedData::default();
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        let mut events = ScriptedEvents::keys(&[KeyCode::Char('r')]);
+        events
+            .0
+            .extend(ScriptedEvents::idle_then_quit(all_beaches().len()).0);
+
+        run_app(
+            &mut terminal,
+            &mut app,
+            &mut events,
+            &mut data,
+            &mut options(),
+        )
+        .await
+        .unwrap();
+
+        let first_load = data
+            .fetches
+            .iter()
+            .scan(0, |loaded, &count| {
+                *loaded += count;
+                Some(*loaded)
+            })
+            .position(|loaded| loaded == all_beaches().len())
+            .unwrap();
+        let refresh = &data.fetches[first_load + 1..];
+        assert_eq!(refresh.iter().sum::<usize>(), all_beaches().len());
+        assert!(refresh.iter().all(|&batch| batch < all_beaches().len()));
+        // The list stayed up throughout
+        assert_eq!(app.state, AppState::BeachList);
+        assert!(app.load_states.is_empty());
+        assert!(!app.refresh_requested);
+    }
+
+    /// Bytes a minute of polls with nothing happening sends, quitting after
+    async fn idle_minute_bytes(slow_link: bool) -> usize {
+        let mut terminal = Terminal::new(ByteCountingBackend::new(80, 24)).unwrap();
+        let mut options = options();
+        options.slow_link = slow_link;
+        if slow_link {
+            options.color_support = ColorSupport::Basic;
+        }
+        run_app(
+            &mut terminal,
+            &mut app(),
+            &mut ScriptedEvents::idle_then_quit(600),
+            &mut CannedData::default(),
+            &mut options,
+        )
+        .await
+        .unwrap();
+        terminal.backend().bytes_sent()
+    }
+
+    #[tokio::test]
+    async fn test_slow_link_sends_fewer_bytes_while_idle() {
+        let normal = idle_minute_bytes(false).await;
+        let slow = idle_minute_bytes(true).await;
+        assert!(slow * 2 < normal, "{} bytes vs {}", slow, normal);
+    }
+
+    #[test]
+    fn test_redraw_gate_draws_on_changes_only_on_a_slow_link() {
+        let app = app();
+        let now = chrono::Local::now();
+
+        let mut gate = RedrawGate::new(false);
+        assert!(gate.should_draw(&app, now));
+        assert!(gate.should_draw(&app, now));
+
+        let mut gate = RedrawGate::new(true);
+        assert!(gate.should_draw(&app, now), "the first frame");
+        assert!(!gate.should_draw(&app, now));
+        gate.mark_dirty();
+        assert!(gate.should_draw(&app, now));
+        assert!(gate.should_draw(&app, now + chrono::Duration::minutes(1)));
+    }
+
+    #[tokio::test]
+    async fn test_running_out_of_keys_is_an_error() {
+        let mut app = app();
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        let mut events = ScriptedEvents::keys(&[]);
+
+        let result = run_app(
+            &mut terminal,
+            &mut app,
+            &mut events,
+            &mut CannedData::default(),
+            &mut options(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,121 @@
+//! Events that drive the app
+//!
+//! Once the app is running, every change to `App` arrives as an `AppEvent`
+//! and goes through `App::update`. IO stays in the event loop: it reads the
+//! terminal, fetches data (`App::fetch_beaches` only reads the app), rings
+//! the bell and draws, then turns what happened into events. The state
+//! changes can be tested by feeding events to an `App`, without a terminal
+//! or a network.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Local, Utc};
+use crossterm::event::KeyEvent;
+
+use crate::data::{
+    AirQuality, Beach, BeachId, Closure, FireBans, Fountain, ModelWinds, TideInfo, WaterQuality,
+    WaveForecast, Weather, WindObservation,
+};
+
+/// Something that happened, for `App::update` to apply
+#[derive(Debug)]
+pub enum AppEvent {
+    /// A key was pressed
+    Key(KeyEvent),
+    /// A fetch finished; boxed, as fetches are much larger than other events
+    DataLoaded(Box<DataLoad>),
+    /// A refresh that loads every beach a batch per frame, keeping the
+    /// screen and the data shown until each beach arrives
+    RefreshInBackground,
+    /// These beaches are being fetched
+    FetchStarted(Vec<&'static Beach>),
+    /// The event loop came round again; time-based state follows the clock
+    Tick(DateTime<Local>),
+    /// How many beaches have loaded of all of them
+    RefreshProgress { loaded: usize, total: usize },
+    /// A best window reminder or an advisory change to call out; quiet
+    /// alerts (during quiet hours) don't flash
+    Alert {
+        message: String,
+        now: DateTime<Local>,
+        quiet: bool,
+    },
+    /// The terminal gained (true) or lost (false) focus
+    Focus(bool),
+    /// The machine switched to (true) or from (false) battery power
+    OnBattery(bool),
+}
+
+/// Fetched data, and which load it belongs to
+#[derive(Debug)]
+pub enum DataLoad {
+    /// Every beach, on startup or a refresh
+    Full(BeachFetch),
+    /// The beaches on the first screen, with the rest queued to load later
+    FirstPaint {
+        fetch: BeachFetch,
+        deferred: Vec<&'static Beach>,
+    },
+    /// A batch of the beaches queued after the first paint
+    Deferred(BeachFetch),
+    /// One feed refetched for one beach, past its cache, at `requested_at`
+    Feed {
+        feed: Feed,
+        fetch: BeachFetch,
+        requested_at: DateTime<Utc>,
+    },
+}
+
+/// A feed the detail view can refetch on its own (`W`, `T`, `Q`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feed {
+    Weather,
+    Tides,
+    WaterQuality,
+}
+
+impl Feed {
+    /// Name for the toast saying how the refetch went
+    pub fn label(self) -> &'static str {
+        match self {
+            Feed::Weather => "weather",
+            Feed::Tides => "tides",
+            Feed::WaterQuality => "water quality",
+        }
+    }
+}
+
+/// Results of fetching conditions for some beaches
+///
+/// A `None` is a fetch that failed; applying it keeps the last known value.
+#[derive(Debug, Default)]
+pub struct BeachFetch {
+    /// Tide predictions, shared by every beach
+    pub tides: Option<TideInfo>,
+    /// Park Board closures for every beach
+    pub closures: Option<HashMap<BeachId, Closure>>,
+    /// Park Board seawall closures, by the beach they're near
+    pub seawall_closures: Option<HashMap<BeachId, Closure>>,
+    /// Latest wind reported by the stations around the beaches
+    pub observations: Option<Vec<WindObservation>>,
+    /// Public drinking fountains, for heat warnings
+    pub fountains: Option<Vec<Fountain>>,
+    /// Campfire bans along the shoreline
+    pub fire_bans: Option<FireBans>,
+    /// Each weather model's wind, with `--wind-models`
+    pub wind_models: Option<ModelWinds>,
+    /// Hourly air quality along the shoreline
+    pub air_quality: Option<AirQuality>,
+    /// The beaches fetched, in the order they were asked for
+    pub beaches: Vec<FetchedBeach>,
+}
+
+/// Weather, water quality and (for beaches open to swell) waves fetched for
+/// one beach
+#[derive(Debug)]
+pub struct FetchedBeach {
+    pub beach: &'static Beach,
+    pub weather: Option<Weather>,
+    pub water_quality: Option<WaterQuality>,
+    pub waves: Option<WaveForecast>,
+}
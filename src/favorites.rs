@@ -0,0 +1,60 @@
+//! Favorite beaches
+//!
+//! Favorites are toggled from the beach list and decide which beaches the
+//! dashboard shows. They are saved through the cache manager so they survive
+//! restarts.
+
+use chrono::Duration;
+
+use crate::cache::{CacheManager, StalenessPolicy};
+use crate::data::{all_beaches, BeachId};
+
+/// Cache key under which favorite beaches are stored
+pub const FAVORITES_CACHE_KEY: &str = "favorites";
+
+/// Favorites are user settings rather than fetched data, so keep them for a year
+const FAVORITES_TTL: Duration = Duration::days(365);
+
+/// Loads saved favorites, dropping any IDs that are no longer known beaches
+pub fn load_favorites(cache: &CacheManager) -> Vec<BeachId> {
+    cache
+        .get_with_policy::<Vec<BeachId>>(FAVORITES_CACHE_KEY, StalenessPolicy::AnyAge)
+        .data()
+        .map(|data| {
+            data.into_iter()
+                .filter(|id| all_beaches().iter().any(|b| b.id == *id))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Saves favorites to the cache
+pub fn save_favorites(cache: &CacheManager, favorites: &[BeachId]) -> std::io::Result<()> {
+    cache.put(FAVORITES_CACHE_KEY, &favorites, FAVORITES_TTL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_save_and_load_drops_unknown_beaches() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let favorites = vec![BeachId::new("kitsilano"), BeachId::new("atlantis")];
+
+        save_favorites(&cache, &favorites).unwrap();
+        let loaded = load_favorites(&cache);
+
+        assert_eq!(loaded, vec![BeachId::new("kitsilano")]);
+    }
+
+    #[test]
+    fn test_load_without_saved_favorites_is_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+
+        assert!(load_favorites(&cache).is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+//! Parser test fixtures captured from the live APIs
+//!
+//! `vanbeach gen-fixtures` fetches one beach's data from every provider with
+//! a debug log attached, then writes each raw response, sanitized, to a file
+//! in `tests/fixtures/`. The parser tests load those files, so when a
+//! provider changes its responses, refreshing the fixtures is one command
+//! and the tests show straight away what no longer parses.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::data::closures::CLOSURES_SOURCE;
+use crate::data::water_quality::WATER_QUALITY_SOURCE;
+use crate::data::{
+    all_beaches, get_beach_by_id, ClosuresClient, DebugLog, MetNorwayClient, RawResponse,
+    WaterQualityClient, WeatherClient, WeatherProvider,
+};
+
+/// Beach whose responses are captured
+const FIXTURE_BEACH: &str = "english-bay";
+
+/// Fields that change on every request, zeroed so refreshed fixtures only
+/// differ where the data does
+const VOLATILE_FIELDS: [&str; 1] = ["generationtime_ms"];
+
+/// Errors that can occur while generating fixtures
+#[derive(Debug, Error)]
+pub enum FixtureError {
+    /// A fixture file couldn't be written
+    #[error("Failed to write fixture: {0}")]
+    Io(#[from] io::Error),
+
+    /// A source sent nothing back, usually because it was unreachable
+    #[error("No response from {0}")]
+    NoResponse(String),
+
+    /// A source's response isn't JSON, so it can't be a parser fixture
+    #[error("Response from {0} is not JSON")]
+    NotJson(String),
+}
+
+/// Fixture file each source's response is saved to
+fn fixture_files() -> [(&'static str, &'static str); 4] {
+    [
+        (WeatherProvider::OpenMeteo.name(), "open-meteo.json"),
+        (WeatherProvider::MetNorway.name(), "met-norway.json"),
+        (WATER_QUALITY_SOURCE, "water-quality.json"),
+        (CLOSURES_SOURCE, "closures.json"),
+    ]
+}
+
+/// Fetches fresh responses from every source and writes them to `out`
+///
+/// Caches are bypassed so every response is live. A response that fails to
+/// parse is still written: that's the schema change the fixture should
+/// capture. Returns the files written.
+pub async fn generate(out: &Path) -> Result<Vec<PathBuf>, FixtureError> {
+    let beach = get_beach_by_id(FIXTURE_BEACH).expect("fixture beach is built in");
+    let debug_log = DebugLog::default();
+
+    let _ = WeatherClient::new()
+        .with_debug_log(debug_log.clone())
+        .fetch_weather(beach.latitude, beach.longitude, &beach.timezone)
+        .await;
+    let _ = MetNorwayClient::new()
+        .with_debug_log(debug_log.clone())
+        .fetch_weather(beach.latitude, beach.longitude, &beach.timezone)
+        .await;
+    if let Some(station) = &beach.water_quality_id {
+        let _ = WaterQualityClient::new()
+            .without_cache()
+            .with_debug_log(debug_log.clone())
+            .fetch_water_quality(station)
+            .await;
+    }
+    let _ = ClosuresClient::new()
+        .without_cache()
+        .with_debug_log(debug_log.clone())
+        .fetch_closures(all_beaches())
+        .await;
+
+    write_fixtures(out, &debug_log.entries())
+}
+
+/// Writes the latest response from each source to its fixture file in `out`
+fn write_fixtures(out: &Path, responses: &[RawResponse]) -> Result<Vec<PathBuf>, FixtureError> {
+    fs::create_dir_all(out)?;
+    let mut written = Vec::new();
+    for (source, file) in fixture_files() {
+        let response = responses
+            .iter()
+            .rev()
+            .find(|response| response.source == source)
+            .ok_or_else(|| FixtureError::NoResponse(source.to_string()))?;
+        let body =
+            sanitize(&response.body).ok_or_else(|| FixtureError::NotJson(source.to_string()))?;
+        let path = out.join(file);
+        fs::write(&path, body)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// A response body as a fixture: volatile fields zeroed and pretty-printed
+/// with sorted keys, so a refresh diffs cleanly; None if it isn't JSON
+fn sanitize(body: &str) -> Option<String> {
+    let mut value: Value = serde_json::from_str(body).ok()?;
+    zero_volatile_fields(&mut value);
+    let mut pretty = serde_json::to_string_pretty(&value).ok()?;
+    pretty.push('\n');
+    Some(pretty)
+}
+
+/// Zeroes every `VOLATILE_FIELDS` entry, however deeply nested
+fn zero_volatile_fields(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if VOLATILE_FIELDS.contains(&key.as_str()) {
+                    *field = Value::from(0.0);
+                } else {
+                    zero_volatile_fields(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(zero_volatile_fields),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn response(source: &str, body: &str) -> RawResponse {
+        RawResponse {
+            source: source.to_string(),
+            subject: "49.2869,-123.1432".to_string(),
+            url: "http://localhost".to_string(),
+            body: body.to_string(),
+            received_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_sanitize_zeroes_volatile_fields_and_sorts_keys() {
+        let body = r#"{"latitude": 49.28, "generationtime_ms": 0.347,
+            "nested": [{"generationtime_ms": 1.2}], "current": {"b": 1, "a": 2}}"#;
+        let sanitized = sanitize(body).unwrap();
+
+        let value: Value = serde_json::from_str(&sanitized).unwrap();
+        assert_eq!(value["generationtime_ms"], 0.0);
+        assert_eq!(value["nested"][0]["generationtime_ms"], 0.0);
+        assert_eq!(value["latitude"], 49.28);
+        assert!(sanitized.find("\"a\"").unwrap() < sanitized.find("\"b\"").unwrap());
+        assert!(sanitized.ends_with("}\n"));
+        assert!(sanitize("<html>rate limited</html>").is_none());
+    }
+
+    #[test]
+    fn test_write_fixtures_saves_the_latest_response_per_source() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let out = dir.path().join("fixtures");
+        let mut responses: Vec<RawResponse> = fixture_files()
+            .iter()
+            .map(|(source, _)| response(source, r#"{"old": true}"#))
+            .collect();
+        responses.push(response(CLOSURES_SOURCE, r#"{"results": []}"#));
+
+        let written = write_fixtures(&out, &responses).unwrap();
+
+        assert_eq!(written.len(), 4);
+        let closures = fs::read_to_string(out.join("closures.json")).unwrap();
+        assert!(closures.contains("\"results\": []"));
+        assert!(fs::read_to_string(out.join("open-meteo.json"))
+            .unwrap()
+            .contains("\"old\": true"));
+    }
+
+    #[test]
+    fn test_write_fixtures_needs_every_source() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let responses = [response(CLOSURES_SOURCE, "{}")];
+
+        let result = write_fixtures(dir.path(), &responses);
+
+        assert!(matches!(result, Err(FixtureError::NoResponse(_))));
+    }
+
+    #[test]
+    fn test_checked_in_fixtures_are_sanitized() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        for (_, file) in fixture_files() {
+            let body = fs::read_to_string(dir.join(file)).unwrap();
+            assert_eq!(sanitize(&body).as_deref(), Some(body.as_str()), "{}", file);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Heat warnings
+//!
+//! A day whose forecast high reaches Environment Canada's heat warning
+//! criterion gets a HEAT WARNING advisory at the top of each beach's detail
+//! view, with where to find shade at that beach and the nearest drinking
+//! fountains from the city's open data. Scoring leans towards low UV and
+//! shaded sand on those days, and the journal logs them.
+
+use chrono::NaiveDate;
+
+use crate::activities::{get_profile, Activity, ActivityProfile};
+use crate::data::BeachConditions;
+
+/// Forecast high, in Celsius, from which a day is a heat warning
+/// (Environment Canada's daytime criterion for Metro Vancouver's coast)
+pub const HEAT_WARNING_C: f64 = 29.0;
+
+/// What to do about it, shown under the advisory's title
+pub const HYDRATION_ADVICE: &str =
+    "Drink water before you're thirsty, rest in the shade and avoid the sun from 11 AM to 3 PM";
+
+/// Today's forecast high at a beach, counting the current temperature
+pub fn forecast_high(conditions: &BeachConditions) -> Option<f64> {
+    let weather = conditions.weather.as_ref()?;
+    Some(
+        weather
+            .hourly
+            .iter()
+            .map(|hour| hour.temperature)
+            .fold(weather.temperature, f64::max),
+    )
+}
+
+/// Tomorrow's forecast high at a beach
+fn tomorrow_high(conditions: &BeachConditions) -> Option<f64> {
+    conditions
+        .weather
+        .as_ref()?
+        .tomorrow
+        .iter()
+        .map(|hour| hour.temperature)
+        .reduce(f64::max)
+}
+
+/// Today's forecast high if it makes today a heat warning
+pub fn warning_high(conditions: &BeachConditions) -> Option<f64> {
+    forecast_high(conditions).filter(|high| *high >= HEAT_WARNING_C)
+}
+
+/// Whether `date` is a heat warning at a beach, for today and tomorrow
+/// (the days there's a forecast for)
+pub fn warning_on(conditions: &BeachConditions, date: NaiveDate) -> bool {
+    let today = conditions.beach.timezone.now().date();
+    let high = if date == today {
+        forecast_high(conditions)
+    } else if today.succ_opt() == Some(date) {
+        tomorrow_high(conditions)
+    } else {
+        None
+    };
+    high.is_some_and(|high| high >= HEAT_WARNING_C)
+}
+
+/// The scoring profile for an activity, leaning towards shade and low UV
+/// in a heat warning
+pub fn profile_for(activity: Activity, heat_warning: bool) -> ActivityProfile {
+    let profile = get_profile(activity);
+    if heat_warning {
+        profile.in_heat()
+    } else {
+        profile
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{
+        all_beaches, DataQuality, HourlyForecast, Weather, WeatherCondition, WeatherProvider,
+    };
+    use chrono::{NaiveTime, Utc};
+
+    /// A beach at 22°C now with the given hourly highs from noon
+    fn conditions(today: &[f64], tomorrow: &[f64]) -> BeachConditions {
+        let forecast = |(hour, temperature): (usize, &f64)| HourlyForecast {
+            hour: hour as u8 + 12,
+            temperature: *temperature,
+            feels_like: *temperature,
+            condition: WeatherCondition::Clear,
+            wind: 5.0,
+            wind_direction: "W".to_string(),
+            uv: 7.0,
+            precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        };
+        BeachConditions {
+            beach: all_beaches()[0].clone(),
+            weather: Some(Weather {
+                temperature: 22.0,
+                feels_like: 22.0,
+                condition: WeatherCondition::Clear,
+                humidity: 40,
+                wind: 5.0,
+                wind_direction: None,
+                uv: 7.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: today.iter().enumerate().map(forecast).collect(),
+                tomorrow: tomorrow.iter().enumerate().map(forecast).collect(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
+            }),
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    #[test]
+    fn test_heat_warning_from_the_forecast_high() {
+        let hot_today = conditions(&[26.0, 31.5, 28.0], &[24.0]);
+        let today = hot_today.beach.timezone.now().date();
+        let tomorrow = today.succ_opt().unwrap();
+        assert_eq!(warning_high(&hot_today), Some(31.5));
+        assert!(warning_on(&hot_today, today));
+        assert!(!warning_on(&hot_today, tomorrow));
+
+        let hot_tomorrow = conditions(&[25.0], &[27.0, 29.0]);
+        assert_eq!(warning_high(&hot_tomorrow), None);
+        assert!(!warning_on(&hot_tomorrow, today));
+        assert!(warning_on(&hot_tomorrow, tomorrow));
+        assert!(!warning_on(&hot_tomorrow, tomorrow.succ_opt().unwrap()));
+    }
+}
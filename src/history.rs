@@ -0,0 +1,369 @@
+//! Conditions history for the "this time last week" comparison
+//!
+//! Each load records, per beach, the temperature, water quality and activity
+//! scores for the beach's current hour. Days are stored through the cache
+//! manager in eight rotating slots per beach, so a day overwrites the one
+//! eight days before it: last week's day survives today's recording, and the
+//! store never grows past eight days of history. The detail view compares the
+//! current hour against the same hour a week ago, and Plan Trip looks back
+//! over the week so far for its best day.
+//!
+//! Alongside it, `vanbeach backfill` keeps the weather each beach actually
+//! had, hour by hour, from the Open-Meteo archive: up to a year of days per
+//! beach in one cache entry, for checking forecasts and best windows against
+//! and for analysis outside the app.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::activities::Activity;
+use crate::cache::{CacheManager, StalenessPolicy};
+use crate::data::{BeachConditions, BeachId, ObservedDay, WaterStatus};
+use crate::scoring::score_now;
+
+/// Days kept per beach: a week back plus today
+const HISTORY_DAYS: i32 = 8;
+
+/// A day is read back a week later, so keep it as long as its slot lasts
+const HISTORY_TTL: Duration = Duration::days(HISTORY_DAYS as i64);
+
+/// Most days of observed weather kept per beach, the latest ones
+const MAX_OBSERVED_DAYS: usize = 366;
+
+/// Observed weather doesn't change, so keep it as long as it's kept at all
+const OBSERVED_TTL: Duration = Duration::days(MAX_OBSERVED_DAYS as i64);
+
+/// Conditions recorded for one hour at one beach
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HourSnapshot {
+    /// Air temperature in Celsius, if weather was available
+    pub temperature: Option<f64>,
+    /// Water quality status, if the beach is sampled
+    pub water_status: Option<WaterStatus>,
+    /// Score for each activity that could be scored
+    pub scores: Vec<(Activity, u8)>,
+}
+
+impl HourSnapshot {
+    /// Captures the conditions and every activity's score at `now`
+    pub fn capture(conditions: &BeachConditions, now: DateTime<Local>) -> Self {
+        Self {
+            temperature: conditions.weather.as_ref().map(|w| w.temperature),
+            water_status: conditions
+                .water_quality
+                .as_ref()
+                .map(|wq| wq.effective_status()),
+            scores: Activity::all()
+                .iter()
+                .filter_map(|&activity| {
+                    score_now(conditions, activity, now).map(|score| (activity, score))
+                })
+                .collect(),
+        }
+    }
+
+    /// Recorded score for an activity
+    pub fn score(&self, activity: Activity) -> Option<u8> {
+        self.scores
+            .iter()
+            .find(|(a, _)| *a == activity)
+            .map(|(_, score)| *score)
+    }
+}
+
+/// Hourly snapshots for one beach on one day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayHistory {
+    /// Day the snapshots were taken, on the beach's clock
+    pub date: NaiveDate,
+    /// Snapshots keyed by hour of the day (0-23); the latest load wins
+    pub hours: BTreeMap<u8, HourSnapshot>,
+}
+
+/// Cache key for the slot a beach's history on `date` is stored in
+fn cache_key(beach_id: &BeachId, date: NaiveDate) -> String {
+    format!(
+        "{}_{}",
+        beach_id.cache_key("history"),
+        date.num_days_from_ce().rem_euclid(HISTORY_DAYS)
+    )
+}
+
+/// Records the conditions for the beach's current hour
+///
+/// A day left over in the slot from eight days ago is replaced rather than
+/// added to.
+pub fn record(
+    cache: &CacheManager,
+    conditions: &BeachConditions,
+    now: DateTime<Local>,
+) -> std::io::Result<()> {
+    let beach_now = conditions.beach.timezone.local(now.with_timezone(&Utc));
+    let date = beach_now.date();
+    let key = cache_key(&conditions.beach.id, date);
+
+    let mut day = cache
+        .get_with_policy::<DayHistory>(&key, StalenessPolicy::AnyAge)
+        .data()
+        .filter(|day| day.date == date)
+        .unwrap_or_else(|| DayHistory {
+            date,
+            hours: BTreeMap::new(),
+        });
+    day.hours.insert(
+        beach_now.hour() as u8,
+        HourSnapshot::capture(conditions, now),
+    );
+    cache.put(&key, &day, HISTORY_TTL)
+}
+
+/// Loads the beach's history from exactly a week before `today`, if recorded
+pub fn load_last_week(
+    cache: &CacheManager,
+    beach_id: &BeachId,
+    today: NaiveDate,
+) -> Option<DayHistory> {
+    let last_week = today - Duration::days(7);
+    cache
+        .get_with_policy::<DayHistory>(&cache_key(beach_id, last_week), StalenessPolicy::AnyAge)
+        .data()
+        .filter(|day| day.date == last_week)
+}
+
+/// Loads the beach's history for the days of this week (from Monday) before
+/// `today`, earliest first
+pub fn load_week_so_far(
+    cache: &CacheManager,
+    beach_id: &BeachId,
+    today: NaiveDate,
+) -> Vec<DayHistory> {
+    let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    monday
+        .iter_days()
+        .take_while(|date| *date < today)
+        .filter_map(|date| {
+            cache
+                .get_with_policy::<DayHistory>(&cache_key(beach_id, date), StalenessPolicy::AnyAge)
+                .data()
+                .filter(|day| day.date == date)
+        })
+        .collect()
+}
+
+/// Merges days of observed weather into the beach's stored ones, a day
+/// fetched again replacing the stored one, and returns how many days are
+/// stored
+pub fn record_observed(
+    cache: &CacheManager,
+    beach_id: &BeachId,
+    days: Vec<ObservedDay>,
+) -> std::io::Result<usize> {
+    let mut stored: BTreeMap<NaiveDate, ObservedDay> = load_observed(cache, beach_id)
+        .into_iter()
+        .map(|day| (day.date, day))
+        .collect();
+    stored.extend(days.into_iter().map(|day| (day.date, day)));
+    let stored: Vec<ObservedDay> = stored.into_values().collect();
+    let stored = &stored[stored.len().saturating_sub(MAX_OBSERVED_DAYS)..];
+    cache.put(
+        &beach_id.cache_key("observed_weather"),
+        &stored,
+        OBSERVED_TTL,
+    )?;
+    Ok(stored.len())
+}
+
+/// The beach's stored days of observed weather, earliest first
+pub fn load_observed(cache: &CacheManager, beach_id: &BeachId) -> Vec<ObservedDay> {
+    cache
+        .get_with_policy::<Vec<ObservedDay>>(
+            &beach_id.cache_key("observed_weather"),
+            StalenessPolicy::AnyAge,
+        )
+        .data()
+        .unwrap_or_default()
+}
+
+/// The day with the highest recorded score for an activity, and that score
+///
+/// Ties go to the earlier day.
+pub fn best_day(days: &[DayHistory], activity: Activity) -> Option<(NaiveDate, u8)> {
+    days.iter()
+        .filter_map(|day| {
+            let score = day.hours.values().filter_map(|h| h.score(activity)).max()?;
+            Some((day.date, score))
+        })
+        .min_by_key(|&(date, score)| (std::cmp::Reverse(score), date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    use crate::data::weather_archive::ObservedHour;
+    use crate::data::{all_beaches, Weather, WeatherCondition, WeatherProvider};
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, day).unwrap()
+    }
+
+    /// `hour` on `day` on the beach's clock
+    fn at(day_of_month: u32, hour: u32) -> DateTime<Local> {
+        let naive = day(day_of_month).and_hms_opt(hour, 0, 0).unwrap();
+        Local.from_utc_datetime(&all_beaches()[0].timezone.to_utc(naive).naive_utc())
+    }
+
+    fn conditions(temperature: f64) -> BeachConditions {
+        BeachConditions {
+            beach: all_beaches()[0].clone(),
+            weather: Some(Weather {
+                temperature,
+                feels_like: temperature,
+                condition: WeatherCondition::Clear,
+                weather_code: None,
+                humidity: 40,
+                wind: 5.0,
+                wind_direction: Some(270.0),
+                uv: 6.0,
+                sunrise: chrono::NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: chrono::NaiveTime::from_hms_opt(21, 15, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+            }),
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    #[test]
+    fn test_recorded_hour_is_read_back_a_week_later() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let beach_id = all_beaches()[0].id.clone();
+
+        record(&cache, &conditions(19.0), at(8, 14)).unwrap();
+        record(&cache, &conditions(21.0), at(8, 15)).unwrap();
+
+        let history = load_last_week(&cache, &beach_id, day(15)).unwrap();
+        assert_eq!(history.hours.len(), 2);
+        let snapshot = &history.hours[&14];
+        assert_eq!(snapshot.temperature, Some(19.0));
+        assert_eq!(snapshot.water_status, None);
+        assert!(snapshot.score(Activity::Sunbathing).is_some());
+
+        // Not a week ago
+        assert_eq!(load_last_week(&cache, &beach_id, day(8)), None);
+    }
+
+    #[test]
+    fn test_recording_today_keeps_last_week_and_replaces_older_days() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let beach_id = all_beaches()[0].id.clone();
+
+        record(&cache, &conditions(19.0), at(8, 10)).unwrap();
+        record(&cache, &conditions(23.0), at(16, 14)).unwrap();
+        record(&cache, &conditions(21.0), at(9, 14)).unwrap();
+
+        // Day 16 took day 8's slot
+        assert_eq!(load_last_week(&cache, &beach_id, day(15)), None);
+        let history = load_last_week(&cache, &beach_id, day(16)).unwrap();
+        assert_eq!(history.date, day(9));
+        assert_eq!(history.hours.keys().collect::<Vec<_>>(), vec![&14]);
+        assert_eq!(history.hours[&14].temperature, Some(21.0));
+    }
+
+    #[test]
+    fn test_week_so_far_starts_on_monday_and_skips_today() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let beach_id = all_beaches()[0].id.clone();
+
+        // July 13 2026 is a Monday
+        record(&cache, &conditions(25.0), at(12, 14)).unwrap();
+        record(&cache, &conditions(19.0), at(13, 14)).unwrap();
+        record(&cache, &conditions(23.0), at(14, 14)).unwrap();
+        record(&cache, &conditions(27.0), at(16, 14)).unwrap();
+
+        let days = load_week_so_far(&cache, &beach_id, day(16));
+        let dates: Vec<_> = days.iter().map(|d| d.date).collect();
+        assert_eq!(dates, vec![day(13), day(14)]);
+        assert!(load_week_so_far(&cache, &beach_id, day(13)).is_empty());
+    }
+
+    #[test]
+    fn test_best_day_takes_the_top_hour_and_the_earlier_day_on_ties() {
+        let snapshot = |score| HourSnapshot {
+            temperature: None,
+            water_status: None,
+            scores: vec![(Activity::Swimming, score)],
+        };
+        let history = |date, scores: &[(u8, u8)]| DayHistory {
+            date,
+            hours: scores.iter().map(|&(h, s)| (h, snapshot(s))).collect(),
+        };
+        let days = vec![
+            history(day(13), &[(10, 60), (14, 91)]),
+            history(day(14), &[(14, 91)]),
+            history(day(15), &[(14, 70)]),
+        ];
+
+        assert_eq!(best_day(&days, Activity::Swimming), Some((day(13), 91)));
+        assert_eq!(best_day(&days, Activity::Sailing), None);
+        assert_eq!(best_day(&[], Activity::Swimming), None);
+    }
+
+    #[test]
+    fn test_observed_days_merge_with_refetched_days_replacing_stored_ones() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let beach_id = all_beaches()[0].id.clone();
+        let observed = |day_of_month: u32, temperature: f64| ObservedDay {
+            date: day(day_of_month),
+            hours: BTreeMap::from([(
+                14,
+                ObservedHour {
+                    temperature,
+                    weather_code: Some(0),
+                    wind: None,
+                    wind_direction: None,
+                    precipitation: None,
+                },
+            )]),
+        };
+
+        assert!(load_observed(&cache, &beach_id).is_empty());
+        let stored = record_observed(
+            &cache,
+            &beach_id,
+            vec![observed(2, 18.0), observed(3, 19.0)],
+        )
+        .unwrap();
+        assert_eq!(stored, 2);
+        let stored = record_observed(
+            &cache,
+            &beach_id,
+            vec![observed(1, 17.0), observed(3, 22.0)],
+        )
+        .unwrap();
+        assert_eq!(stored, 3);
+
+        let days = load_observed(&cache, &beach_id);
+        assert_eq!(
+            days.iter().map(|day| day.date).collect::<Vec<_>>(),
+            vec![day(1), day(2), day(3)]
+        );
+        assert_eq!(days[2].hours[&14].temperature, 22.0);
+    }
+}
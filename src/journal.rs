@@ -0,0 +1,471 @@
+//! Journal of notable condition changes
+//!
+//! While the TUI runs, water quality advisories issued or lifted, heat
+//! warnings and king tides are appended to `journal.jsonl` in the data
+//! directory, one JSON entry per line, so the season builds up into a diary.
+//! `vanbeach log` prints the latest entries and `L` opens the JOURNAL screen.
+//!
+//! Entries are only ever appended. The watch that spots changes starts from
+//! what the journal already holds, so a restart doesn't log the same change
+//! twice: a beach's advisory status picks up from its last entry (or from
+//! what it first shows, if it has none), and a heat warning or king tide is
+//! logged once a day for the whole shoreline.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+
+use crate::app::App;
+use crate::data::{all_beaches, BeachConditions, BeachId, WaterStatus, KING_TIDE_NOTE};
+use crate::heat;
+use crate::time_format::format_time;
+
+/// Name of the journal file in the data directory
+const JOURNAL_FILE_NAME: &str = "journal.jsonl";
+
+/// What changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalKind {
+    AdvisoryIssued,
+    AdvisoryLifted,
+    Closed,
+    Reopened,
+    HeatWarning,
+    KingTide,
+}
+
+impl JournalKind {
+    /// Short tag for the journal's kind column
+    pub fn label(self) -> &'static str {
+        match self {
+            JournalKind::AdvisoryIssued => "Advisory",
+            JournalKind::AdvisoryLifted => "Lifted",
+            JournalKind::Closed => "Closed",
+            JournalKind::Reopened => "Reopened",
+            JournalKind::HeatWarning => "Heat",
+            JournalKind::KingTide => "King tide",
+        }
+    }
+
+    /// How a beach's water quality change is described, e.g. "water quality
+    /// advisory issued"
+    pub fn change(self) -> &'static str {
+        match self {
+            JournalKind::AdvisoryIssued => "water quality advisory issued",
+            JournalKind::AdvisoryLifted => "water quality advisory lifted",
+            JournalKind::Closed => "closed for swimming",
+            JournalKind::Reopened => "reopened for swimming",
+            JournalKind::HeatWarning => "heat warning",
+            JournalKind::KingTide => "king tide",
+        }
+    }
+
+    /// Status a water quality change leaves its beach in
+    fn water_status(self) -> Option<WaterStatus> {
+        match self {
+            JournalKind::AdvisoryIssued => Some(WaterStatus::Advisory),
+            JournalKind::Closed => Some(WaterStatus::Closed),
+            JournalKind::AdvisoryLifted | JournalKind::Reopened => Some(WaterStatus::Safe),
+            JournalKind::HeatWarning | JournalKind::KingTide => None,
+        }
+    }
+}
+
+/// The kind of a water quality status change, or `None` if it isn't one
+/// worth noting
+pub fn advisory_change(previous: WaterStatus, status: WaterStatus) -> Option<JournalKind> {
+    match (previous, status) {
+        (previous, status) if previous == status => None,
+        (_, WaterStatus::Advisory) => Some(JournalKind::AdvisoryIssued),
+        (_, WaterStatus::Closed) => Some(JournalKind::Closed),
+        (WaterStatus::Closed, WaterStatus::Safe) => Some(JournalKind::Reopened),
+        (WaterStatus::Advisory, WaterStatus::Safe) => Some(JournalKind::AdvisoryLifted),
+        _ => None,
+    }
+}
+
+/// One notable change
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// When the change was noticed
+    pub at: DateTime<Utc>,
+    /// Local day the change is about, such as the day of a king tide
+    pub day: NaiveDate,
+    pub kind: JournalKind,
+    /// Beach the change is at; heat warnings and king tides cover the
+    /// whole shoreline
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beach_id: Option<BeachId>,
+    /// What happened, e.g. "Kitsilano Beach: water quality advisory issued"
+    pub message: String,
+}
+
+impl JournalEntry {
+    /// The entry as one line of `vanbeach log`, e.g.
+    /// "2026-07-15 2:30 PM  Advisory   Kitsilano Beach: water quality advisory issued"
+    pub fn log_line(&self) -> String {
+        let local = self.at.with_timezone(&Local);
+        format!(
+            "{} {:>8}  {:<9}  {}",
+            local.format("%Y-%m-%d"),
+            format_time(&local),
+            self.kind.label(),
+            self.message
+        )
+    }
+}
+
+/// The append-only journal file
+#[derive(Debug, Clone)]
+pub struct Journal {
+    path: PathBuf,
+}
+
+impl Journal {
+    /// Journal at the default location, if a home directory is available
+    pub fn open_default() -> Option<Self> {
+        let project_dirs = ProjectDirs::from("", "", "vanbeach")?;
+        Some(Self::new(project_dirs.data_dir().join(JOURNAL_FILE_NAME)))
+    }
+
+    /// Journal kept at `path`
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Every entry, oldest first; none if there's no journal yet
+    ///
+    /// Lines that can't be read (say, half-written when the machine lost
+    /// power) are skipped rather than losing the rest of the journal.
+    pub fn load(&self) -> Vec<JournalEntry> {
+        fs::read_to_string(&self.path)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()
+    }
+
+    /// Appends entries, creating the journal and its directory if needed
+    pub fn append(&self, entries: &[JournalEntry]) -> io::Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut text = String::new();
+        for entry in entries {
+            text.push_str(&serde_json::to_string(entry)?);
+            text.push('\n');
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?
+            .write_all(text.as_bytes())
+    }
+}
+
+/// Spots changes worth a journal entry between refreshes
+#[derive(Debug, Default)]
+pub struct JournalWatch {
+    /// Each beach's last known water quality status
+    statuses: HashMap<BeachId, WaterStatus>,
+    /// Shoreline-wide changes already logged, by day
+    logged: HashSet<(JournalKind, NaiveDate)>,
+}
+
+impl JournalWatch {
+    /// A watch that carries on from the entries already in the journal
+    pub fn resume(entries: &[JournalEntry]) -> Self {
+        let mut watch = Self::default();
+        for entry in entries {
+            match (entry.kind.water_status(), &entry.beach_id) {
+                (Some(status), Some(beach_id)) => {
+                    watch.statuses.insert(beach_id.clone(), status);
+                }
+                _ => {
+                    watch.logged.insert((entry.kind, entry.day));
+                }
+            }
+        }
+        watch
+    }
+
+    /// Entries for what changed since the last check
+    pub fn changes(&mut self, app: &App, now: DateTime<Utc>) -> Vec<JournalEntry> {
+        let mut entries = self.advisory_changes(app, now);
+        entries.extend(self.heat_warning(app, now));
+        entries.extend(self.king_tide(app, now));
+        entries
+    }
+
+    /// Advisories issued or lifted, and beaches closed or reopened
+    ///
+    /// A beach's first known status is its baseline rather than a change, and
+    /// a status turning unknown (a stale or missing sample) isn't logged.
+    fn advisory_changes(&mut self, app: &App, now: DateTime<Utc>) -> Vec<JournalEntry> {
+        all_beaches()
+            .iter()
+            .filter_map(|beach| {
+                let status = app
+                    .get_conditions(&beach.id)?
+                    .water_quality
+                    .as_ref()?
+                    .effective_status();
+                if status == WaterStatus::Unknown {
+                    return None;
+                }
+                let previous = self.statuses.insert(beach.id.clone(), status)?;
+                let kind = advisory_change(previous, status)?;
+                Some(JournalEntry {
+                    at: now,
+                    day: beach.timezone.local(now).date(),
+                    kind,
+                    beach_id: Some(beach.id.clone()),
+                    message: format!("{}: {}", beach.name, kind.change()),
+                })
+            })
+            .collect()
+    }
+
+    /// A heat warning for the day, at the beach forecast to be hottest
+    fn heat_warning(&mut self, app: &App, now: DateTime<Utc>) -> Option<JournalEntry> {
+        let (conditions, high) = loaded(app)
+            .filter_map(|conditions| Some((conditions, heat::warning_high(conditions)?)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))?;
+        let day = conditions.beach.timezone.local(now).date();
+        if !self.logged.insert((JournalKind::HeatWarning, day)) {
+            return None;
+        }
+        Some(JournalEntry {
+            at: now,
+            day,
+            kind: JournalKind::HeatWarning,
+            beach_id: None,
+            message: format!(
+                "Heat warning: forecast high of {:.0}°C at {}",
+                high, conditions.beach.name
+            ),
+        })
+    }
+
+    /// The next high tide, once, if it's a king tide
+    fn king_tide(&mut self, app: &App, now: DateTime<Utc>) -> Option<JournalEntry> {
+        let tide = loaded(app).find_map(BeachConditions::king_tide)?;
+        let day = tide.time.date_naive();
+        if !self.logged.insert((JournalKind::KingTide, day)) {
+            return None;
+        }
+        Some(JournalEntry {
+            at: now,
+            day,
+            kind: JournalKind::KingTide,
+            beach_id: None,
+            message: format!(
+                "King tide: {:.1}m high at {} {} · {}",
+                tide.height,
+                format_time(&tide.time),
+                tide.time.format("%b %-d"),
+                KING_TIDE_NOTE
+            ),
+        })
+    }
+}
+
+/// Loaded beaches, in beach list order
+fn loaded(app: &App) -> impl Iterator<Item = &BeachConditions> {
+    all_beaches()
+        .iter()
+        .filter_map(|beach| app.get_conditions(&beach.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, NaiveTime, TimeZone};
+
+    use crate::data::{
+        TideEvent, TideInfo, TideState, WaterQuality, Weather, WeatherCondition, WeatherProvider,
+    };
+
+    fn noon() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, 15, 19, 0, 0).unwrap()
+    }
+
+    fn conditions(beach_index: usize) -> BeachConditions {
+        BeachConditions {
+            beach: all_beaches()[beach_index].clone(),
+            weather: None,
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    fn set_status(app: &mut App, beach_index: usize, status: WaterStatus) {
+        let mut conditions = conditions(beach_index);
+        conditions.water_quality = Some(WaterQuality {
+            status,
+            ecoli_count: None,
+            sample_date: Local::now().date_naive(),
+            advisory_reason: None,
+            fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
+        });
+        app.beach_conditions
+            .insert(conditions.beach.id.clone(), conditions);
+    }
+
+    fn set_temperature(app: &mut App, beach_index: usize, temperature: f64) {
+        let mut conditions = conditions(beach_index);
+        conditions.weather = Some(Weather {
+            temperature,
+            feels_like: temperature,
+            condition: WeatherCondition::Clear,
+            weather_code: None,
+            humidity: 40,
+            wind: 5.0,
+            wind_direction: None,
+            uv: 8.0,
+            sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+            sunset: NaiveTime::from_hms_opt(21, 10, 0).unwrap(),
+            fetched_at: Utc::now(),
+            hourly: Vec::new(),
+            tomorrow: Vec::new(),
+            source: WeatherProvider::default(),
+            parse_warnings: Vec::new(),
+        });
+        app.beach_conditions
+            .insert(conditions.beach.id.clone(), conditions);
+    }
+
+    #[test]
+    fn test_advisory_changes_resume_from_the_journal() {
+        let mut app = App::new();
+        set_status(&mut app, 0, WaterStatus::Advisory);
+        let mut watch = JournalWatch::default();
+        assert!(watch.changes(&app, noon()).is_empty(), "baseline");
+
+        set_status(&mut app, 0, WaterStatus::Safe);
+        let entries = watch.changes(&app, noon());
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].kind, JournalKind::AdvisoryLifted);
+        assert_eq!(
+            entries[0].message,
+            format!("{}: water quality advisory lifted", all_beaches()[0].name)
+        );
+
+        // After a restart, the journal's last entry is the baseline, so an
+        // advisory issued while the app was closed is still logged
+        set_status(&mut app, 0, WaterStatus::Advisory);
+        let mut resumed = JournalWatch::resume(&entries);
+        let entries = resumed.changes(&app, noon());
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].kind, JournalKind::AdvisoryIssued);
+        assert!(resumed.changes(&app, noon()).is_empty());
+    }
+
+    #[test]
+    fn test_heat_warning_is_logged_once_a_day() {
+        let mut app = App::new();
+        set_temperature(&mut app, 0, 27.0);
+        let mut watch = JournalWatch::default();
+        assert!(watch.changes(&app, noon()).is_empty());
+
+        set_temperature(&mut app, 1, 31.4);
+        let entries = watch.changes(&app, noon());
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].kind, JournalKind::HeatWarning);
+        assert_eq!(entries[0].beach_id, None);
+        assert_eq!(
+            entries[0].message,
+            format!(
+                "Heat warning: forecast high of 31°C at {}",
+                all_beaches()[1].name
+            )
+        );
+        assert!(watch.changes(&app, noon()).is_empty());
+        assert!(JournalWatch::resume(&entries)
+            .changes(&app, noon())
+            .is_empty());
+
+        let tomorrow = noon() + chrono::Duration::days(1);
+        assert_eq!(watch.changes(&app, tomorrow).len(), 1);
+    }
+
+    #[test]
+    fn test_king_tide_is_logged_once() {
+        let mut app = App::new();
+        let index = all_beaches()
+            .iter()
+            .position(|beach| beach.water_body == crate::data::WaterBody::Ocean)
+            .unwrap();
+        let mut conditions = conditions(index);
+        let high = TideEvent {
+            time: Local.with_ymd_and_hms(2026, 12, 14, 9, 12, 0).unwrap(),
+            height: 4.9,
+        };
+        conditions.tides = Some(TideInfo {
+            current_height: 3.0,
+            tide_state: TideState::Rising,
+            next_high: Some(high),
+            next_low: None,
+            fetched_at: Utc::now(),
+        });
+        app.beach_conditions
+            .insert(conditions.beach.id.clone(), conditions);
+
+        let mut watch = JournalWatch::default();
+        let entries = watch.changes(&app, noon());
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].kind, JournalKind::KingTide);
+        assert_eq!(
+            entries[0].day,
+            NaiveDate::from_ymd_opt(2026, 12, 14).unwrap()
+        );
+        assert!(entries[0].message.starts_with("King tide: 4.9m high at "));
+        assert!(watch.changes(&app, noon()).is_empty());
+    }
+
+    #[test]
+    fn test_journal_appends_and_skips_unreadable_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = Journal::new(dir.path().join("nested").join(JOURNAL_FILE_NAME));
+        assert!(journal.load().is_empty());
+
+        let entry = |message: &str| JournalEntry {
+            at: noon(),
+            day: NaiveDate::from_ymd_opt(2026, 7, 15).unwrap(),
+            kind: JournalKind::HeatWarning,
+            beach_id: None,
+            message: message.to_string(),
+        };
+        journal.append(&[entry("first")]).unwrap();
+        fs::write(
+            &journal.path,
+            fs::read_to_string(&journal.path).unwrap() + "{\"at\": \"trunc",
+        )
+        .unwrap();
+        journal.append(&[]).unwrap();
+        fs::write(
+            &journal.path,
+            fs::read_to_string(&journal.path).unwrap() + "\n",
+        )
+        .unwrap();
+        journal.append(&[entry("second")]).unwrap();
+
+        let messages: Vec<_> = journal.load().into_iter().map(|e| e.message).collect();
+        assert_eq!(messages, vec!["first", "second"]);
+    }
+}
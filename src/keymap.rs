@@ -0,0 +1,587 @@
+//! Key bindings for every view
+//!
+//! `BINDINGS` is the single list of what each key does where: `App::handle_key`
+//! looks keys up here to decide what to do, and the help overlay is rendered
+//! from the same list, so the reference can't drift from the real bindings.
+//! Typing a beach number after `g` and a help search query are text entry
+//! rather than bindings and are handled by the app directly.
+
+use crossterm::event::KeyCode;
+
+use crate::activities::Activity;
+use crate::events::Feed;
+
+/// Where a key is pressed; the same key can do different things in each
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    Loading,
+    List,
+    Detail,
+    PlanTrip,
+    Dashboard,
+    /// The water quality overview of every station
+    WaterQuality,
+    /// The journal of notable condition changes
+    Journal,
+    /// The directions overlay over a beach's details
+    Directions,
+    /// The raw API response viewer (`--verbose`)
+    Debug,
+    /// The help overlay
+    Help,
+}
+
+/// Section a binding is listed under in the help overlay
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCategory {
+    Navigation,
+    Activities,
+    Data,
+    Debugging,
+}
+
+impl KeyCategory {
+    /// Sections in the order the help overlay lists them
+    pub const ALL: [KeyCategory; 4] = [
+        KeyCategory::Navigation,
+        KeyCategory::Activities,
+        KeyCategory::Data,
+        KeyCategory::Debugging,
+    ];
+
+    /// Section heading
+    pub fn label(self) -> &'static str {
+        match self {
+            KeyCategory::Navigation => "Navigation",
+            KeyCategory::Activities => "Activities",
+            KeyCategory::Data => "Data",
+            KeyCategory::Debugging => "Debugging",
+        }
+    }
+}
+
+/// What a key asks the app to do; `App::handle_key` interprets it for the
+/// current view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    /// Close an overlay or go back to the list (quits from the list)
+    Back,
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    Open,
+    StartJump,
+    OpenPlanTrip,
+    OpenDashboard,
+    OpenWaterQuality,
+    OpenJournal,
+    SelectActivity(Activity),
+    CombineActivities,
+    NextActivity,
+    PinPlan,
+    ToggleSunSession,
+    Refresh,
+    /// Refetch one feed for the beach on screen, whatever its cache says
+    RefreshFeed(Feed),
+    ToggleFavorite,
+    NextComparisonSet,
+    SaveComparisonSet,
+    DeleteComparisonSet,
+    /// Move the selected beach in the custom list order
+    MoveBeachUp,
+    MoveBeachDown,
+    ToggleCustomOrder,
+    HideClosed,
+    HideUnsuitable,
+    SortByStatus,
+    /// Show or hide the predicted crowd rows in the PlanTrip grid
+    ToggleCrowds,
+    ToggleTideChart,
+    ToggleDirections,
+    ToggleHelp,
+    SearchHelp,
+    ToggleDebugView,
+}
+
+/// One entry in the reference: keys, what they do and where they work
+#[derive(Debug)]
+pub struct KeyBinding {
+    /// Keys as shown in the help overlay
+    pub label: &'static str,
+    pub description: &'static str,
+    pub category: KeyCategory,
+    /// Views the keys work in
+    pub views: &'static [View],
+    /// Each key and the action it triggers
+    pub keys: &'static [(KeyCode, Action)],
+}
+
+use Action::*;
+use View::*;
+
+/// Every binding, in the order the help overlay lists them
+///
+/// Earlier entries win when two bind the same key in the same view.
+pub const BINDINGS: &[KeyBinding] = &[
+    KeyBinding {
+        label: "↑/k, ↓/j",
+        description: "Move up/down (scrolls in details)",
+        category: KeyCategory::Navigation,
+        views: &[List, Detail, PlanTrip, Journal, Debug, Help],
+        keys: &[
+            (KeyCode::Up, Up),
+            (KeyCode::Char('k'), Up),
+            (KeyCode::Down, Down),
+            (KeyCode::Char('j'), Down),
+        ],
+    },
+    KeyBinding {
+        label: "←/h, →/l",
+        description: "Move between hours (Plan trip)",
+        category: KeyCategory::Navigation,
+        views: &[PlanTrip],
+        keys: &[
+            (KeyCode::Left, Left),
+            (KeyCode::Char('h'), Left),
+            (KeyCode::Right, Right),
+            (KeyCode::Char('l'), Right),
+        ],
+    },
+    KeyBinding {
+        label: "H, L / PgUp, PgDn",
+        description: "Pan the hours a screen (Plan trip)",
+        category: KeyCategory::Navigation,
+        views: &[PlanTrip],
+        keys: &[
+            (KeyCode::Char('H'), PageUp),
+            (KeyCode::Char('L'), PageDown),
+            (KeyCode::PageUp, PageUp),
+            (KeyCode::PageDown, PageDown),
+        ],
+    },
+    KeyBinding {
+        label: "Home, End",
+        description: "First/last hour (Plan trip)",
+        category: KeyCategory::Navigation,
+        views: &[PlanTrip],
+        keys: &[(KeyCode::Home, Top), (KeyCode::End, Bottom)],
+    },
+    KeyBinding {
+        label: "PgUp, PgDn",
+        description: "Scroll a page",
+        category: KeyCategory::Navigation,
+        views: &[Debug, Help],
+        keys: &[(KeyCode::PageUp, PageUp), (KeyCode::PageDown, PageDown)],
+    },
+    KeyBinding {
+        label: "g, G",
+        description: "Scroll to top/bottom",
+        category: KeyCategory::Navigation,
+        views: &[Detail, Journal, Help],
+        keys: &[(KeyCode::Char('g'), Top), (KeyCode::Char('G'), Bottom)],
+    },
+    KeyBinding {
+        label: "Enter",
+        description: "Open beach details",
+        category: KeyCategory::Navigation,
+        views: &[List, PlanTrip],
+        keys: &[(KeyCode::Enter, Open)],
+    },
+    KeyBinding {
+        label: "g <number>",
+        description: "Jump to beach by number",
+        category: KeyCategory::Navigation,
+        views: &[List],
+        keys: &[(KeyCode::Char('g'), StartJump)],
+    },
+    KeyBinding {
+        label: "p",
+        description: "Plan trip grid",
+        category: KeyCategory::Navigation,
+        views: &[List, Detail],
+        keys: &[(KeyCode::Char('p'), OpenPlanTrip)],
+    },
+    KeyBinding {
+        label: "d",
+        description: "Dashboard of favorites",
+        category: KeyCategory::Navigation,
+        views: &[List],
+        keys: &[(KeyCode::Char('d'), OpenDashboard)],
+    },
+    KeyBinding {
+        label: "w",
+        description: "Water quality of every station",
+        category: KeyCategory::Navigation,
+        views: &[List],
+        keys: &[(KeyCode::Char('w'), OpenWaterQuality)],
+    },
+    KeyBinding {
+        label: "L",
+        description: "Journal of condition changes",
+        category: KeyCategory::Navigation,
+        views: &[List],
+        keys: &[(KeyCode::Char('L'), OpenJournal)],
+    },
+    KeyBinding {
+        label: "Esc, q",
+        description: "Close help / raw responses / directions",
+        category: KeyCategory::Navigation,
+        views: &[Help, Debug, Directions],
+        keys: &[(KeyCode::Esc, Back), (KeyCode::Char('q'), Back)],
+    },
+    KeyBinding {
+        label: "Esc",
+        description: "Go back (quits from the list)",
+        category: KeyCategory::Navigation,
+        views: &[List, Detail, PlanTrip, Dashboard, WaterQuality, Journal],
+        keys: &[(KeyCode::Esc, Back)],
+    },
+    KeyBinding {
+        label: "q",
+        description: "Quit application",
+        category: KeyCategory::Navigation,
+        views: &[
+            Loading,
+            List,
+            Detail,
+            PlanTrip,
+            Dashboard,
+            WaterQuality,
+            Journal,
+        ],
+        keys: &[(KeyCode::Char('q'), Quit)],
+    },
+    KeyBinding {
+        label: "?",
+        description: "Toggle this help",
+        category: KeyCategory::Navigation,
+        views: &[List, Detail, Dashboard, WaterQuality, Journal, Help],
+        keys: &[(KeyCode::Char('?'), ToggleHelp)],
+    },
+    KeyBinding {
+        label: "/",
+        description: "Search this help",
+        category: KeyCategory::Navigation,
+        views: &[Help],
+        keys: &[(KeyCode::Char('/'), SearchHelp)],
+    },
+    KeyBinding {
+        label: "1",
+        description: "Swimming",
+        category: KeyCategory::Activities,
+        views: &[List, Detail, PlanTrip],
+        keys: &[(KeyCode::Char('1'), SelectActivity(Activity::Swimming))],
+    },
+    KeyBinding {
+        label: "2",
+        description: "Sunbathing",
+        category: KeyCategory::Activities,
+        views: &[List, Detail, PlanTrip],
+        keys: &[(KeyCode::Char('2'), SelectActivity(Activity::Sunbathing))],
+    },
+    KeyBinding {
+        label: "3",
+        description: "Sailing",
+        category: KeyCategory::Activities,
+        views: &[List, Detail, PlanTrip],
+        keys: &[(KeyCode::Char('3'), SelectActivity(Activity::Sailing))],
+    },
+    KeyBinding {
+        label: "4",
+        description: "Sunset viewing",
+        category: KeyCategory::Activities,
+        views: &[List, Detail, PlanTrip],
+        keys: &[(KeyCode::Char('4'), SelectActivity(Activity::Sunset))],
+    },
+    KeyBinding {
+        label: "5",
+        description: "Peace & quiet",
+        category: KeyCategory::Activities,
+        views: &[List, Detail, PlanTrip],
+        keys: &[(KeyCode::Char('5'), SelectActivity(Activity::Peace))],
+    },
+    KeyBinding {
+        label: "6",
+        description: "Dog walk",
+        category: KeyCategory::Activities,
+        views: &[List, Detail, PlanTrip],
+        keys: &[(KeyCode::Char('6'), SelectActivity(Activity::DogWalk))],
+    },
+    KeyBinding {
+        label: "7",
+        description: "Surf/bodyboard (beaches open to swell)",
+        category: KeyCategory::Activities,
+        views: &[List, Detail, PlanTrip],
+        keys: &[(KeyCode::Char('7'), SelectActivity(Activity::Surf))],
+    },
+    KeyBinding {
+        label: "8",
+        description: "Run/walk the seawall and paths",
+        category: KeyCategory::Activities,
+        views: &[List, Detail, PlanTrip],
+        keys: &[(KeyCode::Char('8'), SelectActivity(Activity::RunWalk))],
+    },
+    KeyBinding {
+        label: "m",
+        description: "Combine two activities",
+        category: KeyCategory::Activities,
+        views: &[Detail],
+        keys: &[(KeyCode::Char('m'), CombineActivities)],
+    },
+    KeyBinding {
+        label: "Tab",
+        description: "Next activity (Plan trip)",
+        category: KeyCategory::Activities,
+        views: &[PlanTrip],
+        keys: &[(KeyCode::Tab, NextActivity)],
+    },
+    KeyBinding {
+        label: "Space",
+        description: "Pin plan (Plan trip)",
+        category: KeyCategory::Activities,
+        views: &[PlanTrip],
+        keys: &[(KeyCode::Char(' '), PinPlan)],
+    },
+    KeyBinding {
+        label: "u",
+        description: "Start/stop sun session (UV dose)",
+        category: KeyCategory::Activities,
+        views: &[Detail],
+        keys: &[(KeyCode::Char('u'), ToggleSunSession)],
+    },
+    KeyBinding {
+        label: "r",
+        description: "Refresh data",
+        category: KeyCategory::Data,
+        views: &[List, Detail, Dashboard, WaterQuality],
+        keys: &[(KeyCode::Char('r'), Refresh)],
+    },
+    KeyBinding {
+        label: "W, T, Q",
+        description: "Refetch weather, tides or water quality (details)",
+        category: KeyCategory::Data,
+        views: &[Detail],
+        keys: &[
+            (KeyCode::Char('W'), RefreshFeed(Feed::Weather)),
+            (KeyCode::Char('T'), RefreshFeed(Feed::Tides)),
+            (KeyCode::Char('Q'), RefreshFeed(Feed::WaterQuality)),
+        ],
+    },
+    KeyBinding {
+        label: "f",
+        description: "Toggle favorite beach",
+        category: KeyCategory::Data,
+        views: &[List],
+        keys: &[(KeyCode::Char('f'), ToggleFavorite)],
+    },
+    KeyBinding {
+        label: "Tab",
+        description: "Next comparison set (dashboard)",
+        category: KeyCategory::Data,
+        views: &[Dashboard],
+        keys: &[(KeyCode::Tab, NextComparisonSet)],
+    },
+    KeyBinding {
+        label: "S",
+        description: "Save the dashboard's beaches as a named set",
+        category: KeyCategory::Data,
+        views: &[Dashboard],
+        keys: &[(KeyCode::Char('S'), SaveComparisonSet)],
+    },
+    KeyBinding {
+        label: "x",
+        description: "Delete the saved set shown (dashboard)",
+        category: KeyCategory::Data,
+        views: &[Dashboard],
+        keys: &[(KeyCode::Char('x'), DeleteComparisonSet)],
+    },
+    KeyBinding {
+        label: "K, J",
+        description: "Move beach up/down (custom order)",
+        category: KeyCategory::Data,
+        views: &[List],
+        keys: &[
+            (KeyCode::Char('K'), MoveBeachUp),
+            (KeyCode::Char('J'), MoveBeachDown),
+        ],
+    },
+    KeyBinding {
+        label: "o",
+        description: "Custom beach order on/off",
+        category: KeyCategory::Data,
+        views: &[List],
+        keys: &[(KeyCode::Char('o'), ToggleCustomOrder)],
+    },
+    KeyBinding {
+        label: "c",
+        description: "Hide closed beaches",
+        category: KeyCategory::Data,
+        views: &[List],
+        keys: &[(KeyCode::Char('c'), HideClosed)],
+    },
+    KeyBinding {
+        label: "s",
+        description: "Hide beaches unsuitable for activity",
+        category: KeyCategory::Data,
+        views: &[List],
+        keys: &[(KeyCode::Char('s'), HideUnsuitable)],
+    },
+    KeyBinding {
+        label: "s",
+        description: "Sort stations by status (water quality)",
+        category: KeyCategory::Data,
+        views: &[WaterQuality],
+        keys: &[(KeyCode::Char('s'), SortByStatus)],
+    },
+    KeyBinding {
+        label: "c",
+        description: "Predicted crowds under each beach (Plan trip)",
+        category: KeyCategory::Data,
+        views: &[PlanTrip],
+        keys: &[(KeyCode::Char('c'), ToggleCrowds)],
+    },
+    KeyBinding {
+        label: "t",
+        description: "Expand tide chart (details)",
+        category: KeyCategory::Data,
+        views: &[Detail],
+        keys: &[(KeyCode::Char('t'), ToggleTideChart)],
+    },
+    KeyBinding {
+        label: "o",
+        description: "Directions link for your phone (details)",
+        category: KeyCategory::Data,
+        views: &[Detail, Directions],
+        keys: &[(KeyCode::Char('o'), ToggleDirections)],
+    },
+    KeyBinding {
+        label: "D",
+        description: "Raw API responses (with --verbose)",
+        category: KeyCategory::Debugging,
+        views: &[
+            List,
+            Detail,
+            PlanTrip,
+            Dashboard,
+            WaterQuality,
+            Journal,
+            Debug,
+        ],
+        keys: &[(KeyCode::Char('D'), ToggleDebugView)],
+    },
+    KeyBinding {
+        label: "g",
+        description: "Back to the top of raw responses",
+        category: KeyCategory::Debugging,
+        views: &[Debug],
+        keys: &[(KeyCode::Char('g'), Top)],
+    },
+];
+
+/// The action `code` triggers in `view`, if it's bound there
+pub fn action_for(view: View, code: KeyCode) -> Option<Action> {
+    BINDINGS
+        .iter()
+        .filter(|binding| binding.views.contains(&view))
+        .flat_map(|binding| binding.keys)
+        .find(|(key, _)| *key == code)
+        .map(|&(_, action)| action)
+}
+
+/// Bindings whose keys, description or section contain `query`, ignoring
+/// case; every binding for an empty query
+pub fn search(query: &str) -> impl Iterator<Item = &'static KeyBinding> + '_ {
+    let query = query.to_lowercase();
+    BINDINGS.iter().filter(move |binding| {
+        query.is_empty()
+            || binding.label.to_lowercase().contains(&query)
+            || binding.description.to_lowercase().contains(&query)
+            || binding.category.label().to_lowercase().contains(&query)
+    })
+}
+
+/// Matching bindings grouped by section, in the order the help overlay
+/// lists them; sections with no match are left out
+pub fn sections(query: &str) -> Vec<(KeyCategory, Vec<&'static KeyBinding>)> {
+    let matches: Vec<_> = search(query).collect();
+    KeyCategory::ALL
+        .into_iter()
+        .map(|category| {
+            let bindings = matches
+                .iter()
+                .copied()
+                .filter(|binding| binding.category == category)
+                .collect::<Vec<_>>();
+            (category, bindings)
+        })
+        .filter(|(_, bindings)| !bindings.is_empty())
+        .collect()
+}
+
+/// Lines the help overlay's reference takes for `query`: a heading, the
+/// bindings and a blank line per section
+pub fn reference_len(query: &str) -> usize {
+    sections(query)
+        .iter()
+        .map(|(_, bindings)| bindings.len() + 2)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_for_depends_on_the_view() {
+        assert_eq!(action_for(List, KeyCode::Char('g')), Some(StartJump));
+        assert_eq!(action_for(Detail, KeyCode::Char('g')), Some(Top));
+        assert_eq!(action_for(Debug, KeyCode::Char('q')), Some(Back));
+        assert_eq!(action_for(Detail, KeyCode::Char('q')), Some(Quit));
+        assert_eq!(action_for(Loading, KeyCode::Char('r')), None);
+        assert_eq!(action_for(PlanTrip, KeyCode::Char('?')), None);
+    }
+
+    #[test]
+    fn test_no_key_is_bound_twice_in_a_view() {
+        let views = [
+            Loading,
+            List,
+            Detail,
+            PlanTrip,
+            Dashboard,
+            WaterQuality,
+            Journal,
+            Debug,
+            Help,
+        ];
+        for view in views {
+            let mut seen = Vec::new();
+            for binding in BINDINGS.iter().filter(|b| b.views.contains(&view)) {
+                for (key, _) in binding.keys {
+                    assert!(
+                        !seen.contains(key),
+                        "{:?} is bound twice in {:?}",
+                        key,
+                        view
+                    );
+                    seen.push(*key);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_matches_keys_descriptions_and_sections() {
+        let labels = |query| search(query).map(|b| b.label).collect::<Vec<_>>();
+        assert_eq!(labels("tide"), vec!["W, T, Q", "t"]);
+        assert_eq!(labels("DEBUGGING"), vec!["D", "g"]);
+        assert_eq!(labels("").len(), BINDINGS.len());
+        assert!(labels("no such key").is_empty());
+    }
+}
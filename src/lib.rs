@@ -1,8 +1,12 @@
 //! Vancouver Beach CLI Library
 //!
-//! This module exposes the CLI and activities modules for use in integration tests.
+//! This module exposes the CLI, config and activities modules for use in integration tests.
 
 pub mod activities;
 pub mod cache;
 pub mod cli;
+pub mod config;
 pub mod data;
+pub mod power;
+pub mod sun;
+pub mod time_format;
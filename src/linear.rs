@@ -0,0 +1,146 @@
+//! Linear output for screen readers (`--linear`)
+//!
+//! Frames are drawn into an off-screen buffer as usual, then written out as
+//! plain lines of text: box-drawing and chart characters become spaces, the
+//! runs of spaces that line up columns become ", ", and blank lines are
+//! dropped. Nothing is redrawn in place and there's no alternate screen, so
+//! everything printed stays in the scrollback for a screen reader to read.
+//!
+//! A new screen (another view, or an overlay opening or closing) prints in
+//! full after a blank line. After that only the lines that changed print,
+//! as they change.
+
+use std::io::{self, Write};
+
+use ratatui::buffer::Buffer;
+
+use crate::app::AppState;
+use crate::keymap::View;
+
+/// Writes frames to the terminal as lines of text
+pub struct LinearOutput {
+    out: Box<dyn Write>,
+    /// Screen the last frame showed
+    screen: Option<(AppState, View)>,
+    /// Lines the last frame showed
+    lines: Vec<String>,
+}
+
+impl LinearOutput {
+    pub fn new(out: Box<dyn Write>) -> Self {
+        Self {
+            out,
+            screen: None,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Prints what's new in a frame of `screen`
+    pub fn show(&mut self, screen: (AppState, View), buffer: &Buffer) -> io::Result<()> {
+        let lines = screen_lines(buffer);
+        let printed = self.changes(screen, lines);
+        if printed.is_empty() {
+            return Ok(());
+        }
+        // Raw mode leaves newlines alone, so each line returns the carriage
+        for line in printed {
+            write!(self.out, "{}\r\n", line)?;
+        }
+        self.out.flush()
+    }
+
+    /// The lines to print for a frame, remembering it for the next one
+    fn changes(&mut self, screen: (AppState, View), lines: Vec<String>) -> Vec<String> {
+        let printed = if self.screen.as_ref() != Some(&screen) {
+            let mut printed = vec![String::new()];
+            printed.extend(lines.iter().cloned());
+            printed
+        } else {
+            lines
+                .iter()
+                .filter(|line| !self.lines.contains(line))
+                .cloned()
+                .collect()
+        };
+        self.screen = Some(screen);
+        self.lines = lines;
+        printed
+    }
+}
+
+/// A frame's text, top to bottom, without drawing characters or alignment
+pub fn screen_lines(buffer: &Buffer) -> Vec<String> {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .filter_map(|y| {
+            let row: String = (area.left()..area.right())
+                .map(|x| {
+                    let symbol = buffer[(x, y)].symbol();
+                    if symbol.chars().any(is_drawing) {
+                        " "
+                    } else {
+                        symbol
+                    }
+                })
+                .collect();
+            let fields: Vec<&str> = row
+                .split("  ")
+                .map(str::trim)
+                .filter(|field| !field.is_empty())
+                .collect();
+            (!fields.is_empty()).then(|| fields.join(", "))
+        })
+        .collect()
+}
+
+/// Box-drawing, block and braille characters, which only mean something
+/// laid out on screen
+fn is_drawing(c: char) -> bool {
+    matches!(c, '\u{2500}'..='\u{259F}' | '\u{2800}'..='\u{28FF}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::{Block, Borders, Paragraph, Widget};
+
+    #[test]
+    fn test_screen_lines_drop_borders_and_alignment() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 40, 5));
+        Paragraph::new("Kitsilano Beach      82   Good\n\u{2582}\u{2585}\u{2587}")
+            .block(Block::default().borders(Borders::ALL).title("Beaches"))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(
+            screen_lines(&buffer),
+            vec!["Beaches", "Kitsilano Beach, 82, Good"]
+        );
+    }
+
+    #[test]
+    fn test_only_changed_lines_print_until_the_screen_changes() {
+        let mut output = LinearOutput::new(Box::new(io::sink()));
+        let list = (AppState::BeachList, View::List);
+        let lines = |selected: &str| {
+            vec![
+                "Beaches".to_string(),
+                format!("{}Kitsilano Beach", selected),
+            ]
+        };
+
+        assert_eq!(
+            output.changes(list.clone(), lines("")),
+            vec!["", "Beaches", "Kitsilano Beach"]
+        );
+        assert!(output.changes(list.clone(), lines("")).is_empty());
+        assert_eq!(
+            output.changes(list.clone(), lines("\u{25B8} ")),
+            vec!["\u{25B8} Kitsilano Beach"]
+        );
+        // Help opening over the list is a new screen
+        assert_eq!(
+            output.changes((AppState::BeachList, View::Help), lines("")),
+            vec!["", "Beaches", "Kitsilano Beach"]
+        );
+    }
+}
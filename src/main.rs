@@ -4,44 +4,116 @@
 //! information for beaches in Vancouver, BC.
 
 mod activities;
+mod alerts;
 mod app;
+mod beach_events;
+mod beach_order;
 mod cache;
+mod calendar;
 pub mod cli;
+mod clock;
+mod comparison_sets;
+mod condition_table;
+mod config;
+#[cfg(unix)]
+mod control;
+mod crash;
+mod crawl;
 mod crowd;
 mod data;
+mod directions;
+mod event_loop;
+mod events;
+mod favorites;
+mod fixtures;
+mod heat;
+mod history;
+mod journal;
+mod keymap;
+mod linear;
+mod mqtt;
+mod plan_scores;
+mod plans;
+mod post;
+mod power;
+mod rain_delay;
 mod refresh;
+mod reminders;
+mod schema;
+mod scoring;
+mod serve;
+mod session;
+#[cfg(unix)]
+mod signals;
+mod site;
+mod statusline;
+mod sun;
+mod swim_safety;
+mod terminal_title;
+mod time_format;
 mod ui;
+mod usage;
+mod uv_exposure;
+#[cfg(feature = "graphics")]
+mod webcam;
 
 use std::io;
 use std::panic;
-use std::time::Duration;
 
+use chrono::Timelike;
 use clap::Parser;
 use crossterm::{
-    event::{self, Event},
+    event::{DisableFocusChange, EnableFocusChange},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{backend::CrosstermBackend, Terminal};
+use ratatui::{
+    backend::{CrosstermBackend, TestBackend},
+    Terminal,
+};
 
 use app::{App, AppState};
 use cli::{Cli, StartupConfig};
+use config::Config;
+use data::beach_registry::BeachRegistryFile;
+use event_loop::{run_app, LiveData, RunOptions, TerminalEvents};
+use session::{SessionPlayer, SessionRecorder};
+use ui::theme::ColorSupport;
 
 /// Sets up a panic hook that restores the terminal before printing the panic message.
-/// This ensures the terminal is usable even if the application panics.
+/// This ensures the terminal is usable even if the application panics. A crash
+/// report is written to the cache directory for attaching to a bug report.
 fn setup_panic_hook() {
     let original_hook = panic::take_hook();
     panic::set_hook(Box::new(move |panic_info| {
         // Attempt to restore the terminal
         let _ = disable_raw_mode();
-        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        let _ = execute!(io::stdout(), DisableFocusChange, LeaveAlternateScreen);
+        let _ = terminal_title::restore(&mut io::stdout());
         // Call the original panic hook
         original_hook(panic_info);
+        if let Some(path) = crash::report(panic_info) {
+            eprintln!(
+                "Crash report written to {} - please attach it to a bug report",
+                path.display()
+            );
+        }
     }));
 }
 
-/// Renders the UI based on the current application state
-fn render_ui(frame: &mut ratatui::Frame, app: &mut App) {
+/// Renders the UI based on the current application state, then adapts the
+/// frame to the terminal's color support
+fn render_ui(frame: &mut ratatui::Frame, app: &mut App, color_support: ColorSupport) {
+    // Only the detail view makes room for a webcam snapshot
+    app.webcam_area = None;
+
+    // Tiny terminals get a minimal screen instead of a squeezed layout
+    if ui::compact::is_too_small(frame.area()) {
+        ui::compact::render(frame, app);
+        adapt_frame(frame, app, color_support);
+        return;
+    }
+
     // Render the main view
     match &app.state.clone() {
         AppState::Loading => {
@@ -56,12 +128,59 @@ fn render_ui(frame: &mut ratatui::Frame, app: &mut App) {
         AppState::PlanTrip => {
             ui::render_plan_trip(frame, app);
         }
+        AppState::Dashboard => {
+            ui::render_dashboard(frame, app);
+        }
+        AppState::WaterQualityOverview => {
+            ui::render_water_quality_overview(frame, app);
+        }
+        AppState::Journal => {
+            ui::render_journal(frame, app);
+        }
+    }
+
+    // Reminders and other toasts sit above the view, below the overlays
+    ui::render_toast(frame, app, chrono::Local::now());
+
+    // Maps links (and QR code) for the open beach
+    if app.show_directions {
+        ui::render_directions(frame, app);
     }
 
     // Render help overlay on top if active
     if app.show_help {
-        ui::render_help_overlay(frame);
+        ui::render_help_overlay(frame, &app.help);
+    }
+
+    // Raw API responses (--verbose only)
+    if app.show_debug_view {
+        ui::render_debug_view(frame, app);
+    }
+
+    adapt_frame(frame, app, color_support);
+}
+
+/// Applies the palette (`--palette`), the ambient accent (`--ambient`), the
+/// night palette and any alert flash, then fits the frame to the terminal's
+/// color support
+fn adapt_frame(frame: &mut ratatui::Frame, app: &App, color_support: ColorSupport) {
+    ui::theme::apply_palette(frame.buffer_mut(), ui::theme::palette());
+    if app.ambient {
+        let accent = app
+            .focused_beach()
+            .and_then(|beach| app.get_conditions(&beach.id))
+            .and_then(|conditions| ui::theme::ambient_accent(conditions, chrono::Utc::now()));
+        if let Some(accent) = accent {
+            ui::theme::apply_accent(frame.buffer_mut(), accent);
+        }
     }
+    if app.dims_for_night() {
+        ui::theme::apply_night(frame.buffer_mut());
+    }
+    if app.is_flashing(chrono::Local::now()) {
+        ui::theme::flash(frame.buffer_mut());
+    }
+    ui::theme::adapt_buffer(frame.buffer_mut(), color_support);
 }
 
 /// Renders a loading message while data is being fetched
@@ -93,11 +212,46 @@ fn render_loading(frame: &mut ratatui::Frame) {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Asset overrides are layered over the built-in beach list, themes and
+    // WMO descriptions before anything reads them, the CLI's beach IDs included
+    let assets = data::assets::install(&data::assets::search_dirs());
+
     // Parse CLI arguments
     let cli = Cli::parse();
 
+    // A broken override stops everything but assets, so the built-in files
+    // can be dumped to start over from
+    if let Err(e) = assets {
+        if !matches!(cli.command, Some(cli::Command::Assets { .. })) {
+            eprintln!("Error: {}", e);
+            eprintln!(
+                "Fix or remove that file; `vanbeach assets dump` writes out the built-in ones"
+            );
+            std::process::exit(1);
+        }
+    }
+
+    // A downloaded beach registry replaces the built-in beaches; it has to be
+    // installed before anything looks a beach up. update-beaches skips it so a
+    // damaged file can be replaced.
+    if !matches!(cli.command, Some(cli::Command::UpdateBeaches)) {
+        if let Some(path) = BeachRegistryFile::default_path() {
+            match BeachRegistryFile::load(&path) {
+                Ok(Some(file)) => {
+                    data::beach::set_beach_overrides(file.beaches);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    eprintln!("Error: Failed to load {}: {}", path.display(), e);
+                    eprintln!("Run `vanbeach update-beaches` to download it again");
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+
     // Validate and create startup config
-    let startup_config = match StartupConfig::from_cli(&cli) {
+    let mut startup_config = match StartupConfig::from_cli(&cli) {
         Ok(config) => config,
         Err(e) => {
             eprintln!("Error: {}", e);
@@ -105,55 +259,1010 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
 
+    ui::theme::set_palette(startup_config.palette);
+    ui::theme::set_rank_markers(startup_config.rank_markers);
+    cache::set_policy(startup_config.cache_policy.clone());
+    if let Some(mode) = startup_config.http_recording.clone() {
+        data::recording::set_mode(mode);
+    }
+
+    // assets dump writes the built-in data files out for customizing
+    if let Some(cli::AssetsAction::Dump { dir, force }) = &startup_config.assets {
+        let Some(dir) = dir.clone().or_else(data::assets::user_dir) else {
+            eprintln!("Error: No config directory available; pass --dir");
+            std::process::exit(1);
+        };
+        match data::assets::dump(&dir, *force) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("Wrote {}", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    // schema prints the output JSON Schema; it needs no data or config
+    if startup_config.print_schema {
+        println!("{:#}", schema::output_schema());
+        return Ok(());
+    }
+
+    // completions and man print generated CLI docs; they need no data or config
+    if let Some(shell) = startup_config.completions {
+        cli::write_completions(shell, &mut io::stdout());
+        return Ok(());
+    }
+    if startup_config.print_man {
+        cli::write_man_page(&mut io::stdout())?;
+        return Ok(());
+    }
+
+    // gen-fixtures saves live API responses for the parser tests
+    if let Some(out) = &startup_config.gen_fixtures_dir {
+        match fixtures::generate(out).await {
+            Ok(files) => {
+                for file in files {
+                    println!("Wrote {}", file.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: Failed to generate fixtures: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    // Load user config, install any scoring weight overrides, comfort
+    // breakpoints, clock format, beach events and good score, and fall back to the configured default activity if none
+    // was given on the command line; comparison sets go to the app, and the
+    // MQTT broker, terminal title, usage metrics and night settings to the
+    // TUI or serve mode
+    let config = Config::load().and_then(|config| {
+        Ok((
+            config.weight_overrides()?,
+            config.comfort_overrides()?,
+            config.default_activity()?,
+            config.webcam_urls()?,
+            config.refresh_cooldown(),
+            config.hour_range()?,
+            config.power_saving(),
+            config.uv_dose_limit()?,
+            config.notifications()?,
+            config.clock_format(),
+            config.beach_events()?,
+            config.comparison_sets()?,
+            config.mqtt()?,
+            config.terminal_title(),
+            config.usage_metrics(),
+            config.good_score()?,
+            config.night_mode()?,
+        ))
+    });
+    let (
+        webcams,
+        refresh_cooldown,
+        power_saving,
+        uv_dose_limit,
+        notifications,
+        comparison_sets,
+        mqtt_settings,
+        set_terminal_title,
+        count_usage,
+        night_mode,
+    ) = match config {
+        Ok((
+            overrides,
+            comfort,
+            default_activity,
+            webcams,
+            refresh_cooldown,
+            hour_range,
+            power_saving,
+            uv_dose_limit,
+            notifications,
+            clock_format,
+            events,
+            comparison_sets,
+            mqtt_settings,
+            set_terminal_title,
+            count_usage,
+            good_score,
+            night_mode,
+        )) => {
+            activities::set_weight_overrides(overrides);
+            activities::set_comfort_overrides(comfort);
+            config::set_hour_range(hour_range);
+            time_format::set_clock_format(clock_format);
+            beach_events::set_events(events);
+            if let Some(score) = good_score {
+                config::set_good_score(score);
+            }
+            if startup_config.initial_activity.is_none() {
+                startup_config.initial_activity = default_activity;
+            }
+            (
+                webcams,
+                refresh_cooldown,
+                power_saving,
+                uv_dose_limit,
+                notifications,
+                comparison_sets,
+                mqtt_settings,
+                set_terminal_title,
+                count_usage,
+                night_mode,
+            )
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // metrics shows or exports the local usage counts
+    if let Some(action) = startup_config.usage_metrics {
+        let counts = cache::CacheManager::new()
+            .map(|cache| usage::UsageCounts::load(&cache))
+            .unwrap_or_default();
+        match action {
+            cli::MetricsAction::Export => println!("{}", serde_json::to_string_pretty(&counts)?),
+            cli::MetricsAction::Show => {
+                if count_usage {
+                    println!("Usage counting is on; the counts stay on this machine");
+                } else {
+                    println!("Usage counting is off; set \"usage_metrics\": true in the config to count which screens and activities are used, on this machine only");
+                }
+                if counts.counts.is_empty() {
+                    println!("Nothing counted yet");
+                } else {
+                    print!("{}", counts.report());
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    // --score-dump prints weights and current scores without starting the TUI
+    if startup_config.score_dump {
+        let mut app = App::new();
+        app.load_all_data(&mut LiveData).await;
+        print!(
+            "{}",
+            scoring::score_dump(
+                |id| app.get_conditions(id),
+                startup_config.initial_activity,
+                chrono::Local::now()
+            )
+        );
+        return Ok(());
+    }
+
+    // update-beaches downloads the city's beach list for the next start
+    if startup_config.update_beaches {
+        let Some(path) = BeachRegistryFile::default_path() else {
+            eprintln!("Error: No data directory available");
+            std::process::exit(1);
+        };
+        match BeachRegistryFile::fetch().await {
+            Ok(file) => {
+                if let Err(e) = file.save(&path) {
+                    eprintln!("Error: Failed to save {}: {}", path.display(), e);
+                    std::process::exit(1);
+                }
+                let known = file
+                    .beaches
+                    .iter()
+                    .filter(|record| data::beach::builtin_id_for_name(&record.name).is_some())
+                    .count();
+                println!(
+                    "Saved {} beaches ({} new) to {}",
+                    file.beaches.len(),
+                    file.beaches.len() - known,
+                    path.display()
+                );
+            }
+            Err(e) => {
+                eprintln!("Error: Failed to update beaches: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    // import-wq merges a water quality CSV dump into the local archive
+    if let Some(path) = &startup_config.import_wq_path {
+        let imported = std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| {
+                let cache = cache::CacheManager::new()
+                    .ok_or_else(|| "No cache directory available".to_string())?;
+                if !cache.is_persistent() {
+                    return Err("--no-cache leaves nowhere to save the archive".to_string());
+                }
+                let mut archive = data::WaterQualityArchive::load(&cache);
+                let summary = archive.import_csv(&text).map_err(|e| e.to_string())?;
+                archive.save(&cache).map_err(|e| e.to_string())?;
+                Ok(summary)
+            });
+        match imported {
+            Ok(summary) => println!("{}", summary),
+            Err(e) => {
+                eprintln!("Error: Failed to import {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    // table prints one beach's conditions hour by hour, Windguru-style
+    if let Some(export) = &startup_config.table_export {
+        let mut app = App::new();
+        app.refresh_beach(&export.beach_id).await;
+        let Some(conditions) = app.get_conditions(&export.beach_id) else {
+            eprintln!("Error: Unknown beach '{}'", export.beach_id);
+            std::process::exit(1);
+        };
+        // Waves are a bonus; the table shows "-" if they can't be fetched
+        let waves = if conditions.beach.water_body == data::WaterBody::Ocean {
+            data::MarineClient::new()
+                .fetch_waves(&conditions.beach)
+                .await
+                .unwrap_or_default()
+        } else {
+            data::WaveForecast::default()
+        };
+        let now = conditions.beach.timezone.now();
+        print!(
+            "{}",
+            condition_table::export_table(
+                conditions,
+                startup_config
+                    .initial_activity
+                    .unwrap_or(activities::Activity::Swimming),
+                &waves,
+                |date, hour| app.tide_height_at(date, hour),
+                now.date(),
+                now.hour() as u8,
+                export.format,
+            )
+        );
+        return Ok(());
+    }
+
+    // statusline prints one short line for a status bar
+    if let Some(export) = &startup_config.statusline {
+        let mut app = App::new();
+        app.refresh_beach(&export.beach_id).await;
+        let Some(conditions) = app.get_conditions(&export.beach_id) else {
+            eprintln!("Error: Unknown beach '{}'", export.beach_id);
+            std::process::exit(1);
+        };
+        let line = statusline::status_line(conditions, chrono::Utc::now(), export.staleness);
+        println!("{}", line.text);
+        if export.staleness && line.staleness == statusline::Staleness::VeryStale {
+            std::process::exit(statusline::VERY_STALE_EXIT_CODE);
+        }
+        return Ok(());
+    }
+
+    // log prints the latest journal entries, oldest first
+    if let Some(export) = &startup_config.log {
+        let entries: Vec<_> = journal::Journal::open_default()
+            .map(|journal| journal.load())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|entry| {
+                export.beach_id.is_none()
+                    || entry.beach_id.is_none()
+                    || entry.beach_id == export.beach_id
+            })
+            .collect();
+        if entries.is_empty() {
+            println!("Nothing logged yet: the TUI notes advisories, heat warnings and king tides as it spots them");
+        }
+        for entry in &entries[entries.len().saturating_sub(export.limit)..] {
+            println!("{}", entry.log_line());
+        }
+        return Ok(());
+    }
+
+    // post sends the day's summary to the bot's accounts
+    if let Some(export) = &startup_config.post {
+        let mut app = App::new();
+        app.load_all_data(&mut LiveData).await;
+        let beaches: Vec<_> = data::all_beaches()
+            .iter()
+            .filter_map(|beach| app.get_conditions(&beach.id))
+            .collect();
+        let text = post::daily_summary(
+            &beaches,
+            startup_config
+                .initial_activity
+                .unwrap_or(activities::Activity::Swimming),
+            chrono::Utc::now(),
+        );
+        if export.dry_run {
+            println!("{}", text);
+            return Ok(());
+        }
+        let mut failed = false;
+        for &target in &export.targets {
+            match post::post(target, &text).await {
+                Ok(url) => println!("Posted to {}: {}", post::service_name(target), url),
+                Err(e) => {
+                    eprintln!(
+                        "Error: Failed to post to {}: {}",
+                        post::service_name(target),
+                        e
+                    );
+                    failed = true;
+                }
+            }
+        }
+        if failed {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // conditions prints one or more beaches, fetched together
+    if let Some(export) = &startup_config.conditions {
+        let beaches: Vec<_> = export
+            .beach_ids
+            .iter()
+            .filter_map(|id| data::get_beach_by_id(id))
+            .collect();
+        let mut app = App::new();
+        app.load_beaches(&mut LiveData, &beaches).await;
+        let conditions: Vec<_> = beaches
+            .iter()
+            .filter_map(|beach| app.get_conditions(&beach.id))
+            .collect();
+        if export.json {
+            println!("{}", serde_json::to_string_pretty(&conditions)?);
+        } else {
+            let now = chrono::Utc::now();
+            for conditions in conditions {
+                println!("{}", statusline::status_line(conditions, now, false).text);
+            }
+        }
+        return Ok(());
+    }
+
+    // calendar prints an iCalendar file of good windows at favorite beaches
+    if let Some(export) = &startup_config.calendar {
+        let favorites = cache::CacheManager::new()
+            .map(|cache| favorites::load_favorites(&cache))
+            .unwrap_or_default();
+        if favorites.is_empty() {
+            eprintln!("Error: No favorite beaches yet: press f on a beach to add one");
+            std::process::exit(1);
+        }
+        let mut app = App::new();
+        for id in &favorites {
+            app.refresh_beach(id).await;
+        }
+        let beaches: Vec<_> = favorites
+            .iter()
+            .filter_map(|id| app.get_conditions(id))
+            .collect();
+        let activities = match startup_config.initial_activity {
+            Some(activity) => vec![activity],
+            None => activities::Activity::all().to_vec(),
+        };
+        print!(
+            "{}",
+            calendar::export_calendar(&beaches, &activities, export.min_score, chrono::Utc::now())
+        );
+        return Ok(());
+    }
+
+    // backfill fetches past weather into the history, beach by beach
+    if let Some(export) = &startup_config.backfill {
+        let Some(cache) = cache::CacheManager::new().filter(|cache| cache.is_persistent()) else {
+            eprintln!("Error: Nowhere to keep the weather: backfill needs a cache directory");
+            std::process::exit(1);
+        };
+        let beaches: Vec<_> = if export.beach_ids.is_empty() {
+            data::all_beaches().iter().collect()
+        } else {
+            export
+                .beach_ids
+                .iter()
+                .filter_map(|id| data::get_beach_by_id(id))
+                .collect()
+        };
+        let client = data::ArchiveClient::new();
+        let mut failed = false;
+        for beach in beaches {
+            let yesterday = beach.timezone.now().date() - chrono::Duration::days(1);
+            let start = yesterday - chrono::Duration::days(export.days as i64 - 1);
+            let fetched = match client.fetch_days(beach, start, yesterday).await {
+                Ok(days) => days,
+                Err(e) => {
+                    eprintln!("{}: {}", beach.name, e);
+                    failed = true;
+                    continue;
+                }
+            };
+            let hours: usize = fetched.iter().map(|day| day.hours.len()).sum();
+            let days = fetched.len();
+            match history::record_observed(&cache, &beach.id, fetched) {
+                Ok(stored) => println!(
+                    "{}: {} days ({} hours) fetched, {} days stored",
+                    beach.name, days, hours, stored
+                ),
+                Err(e) => {
+                    eprintln!("{}: Failed to store the weather: {}", beach.name, e);
+                    failed = true;
+                }
+            }
+        }
+        if failed {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // crawl plans a day visiting two or three beaches
+    if let Some(export) = &startup_config.crawl {
+        let beaches: Vec<_> = export
+            .stops
+            .iter()
+            .filter_map(|stop| data::get_beach_by_id(&stop.beach_id))
+            .collect();
+        let mut app = App::new();
+        app.load_beaches(&mut LiveData, &beaches).await;
+        let stops: Vec<_> = export
+            .stops
+            .iter()
+            .filter_map(|stop| Some((app.get_conditions(&stop.beach_id)?, stop.activity)))
+            .collect();
+        let now = chrono::Utc::now();
+        let Some(plan) = crawl::plan_crawl(&stops, export.stay_hours, export.tomorrow, now) else {
+            eprintln!(
+                "Error: Can't fit {} hours at each beach into the {}: try a shorter --stay{}",
+                export.stay_hours,
+                if export.tomorrow {
+                    "day"
+                } else {
+                    "rest of the day"
+                },
+                if export.tomorrow {
+                    ""
+                } else {
+                    " or --tomorrow"
+                }
+            );
+            std::process::exit(1);
+        };
+        if export.ics {
+            print!("{}", crawl::export_ics(&plan, now));
+        } else {
+            print!("{}", crawl::itinerary(&plan));
+        }
+        return Ok(());
+    }
+
+    // crowd prints one beach's hourly crowd predictions for one day
+    if let Some(export) = &startup_config.crowd_export {
+        let mut app = App::new();
+        app.refresh_beach(&export.beach_id).await;
+        let Some(conditions) = app.get_conditions(&export.beach_id) else {
+            eprintln!("Error: Unknown beach '{}'", export.beach_id);
+            std::process::exit(1);
+        };
+        print!(
+            "{}",
+            crowd::export_predictions(conditions, export.date, export.format)
+        );
+        return Ok(());
+    }
+
+    // export-site writes the static site and exits without starting the TUI
+    if let Some(out) = &startup_config.export_site_dir {
+        let mut app = App::new();
+        app.load_all_data(&mut LiveData).await;
+        let exported = site::export_site(
+            out,
+            |id| app.get_conditions(id),
+            startup_config.initial_activity,
+            chrono::Local::now(),
+        );
+        match exported {
+            Ok(pages) => println!("Wrote {} pages to {}", pages, out.display()),
+            Err(e) => {
+                eprintln!("Error: Failed to export site: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    // serve keeps data refreshed behind a metrics endpoint instead of the TUI
+    if let Some(addr) = startup_config.serve_addr {
+        let mut app = App::new();
+        app.enable_metrics();
+        println!("Serving metrics on http://{}/metrics", addr);
+        let mqtt = mqtt_settings.as_ref().map(mqtt::MqttPublisher::connect);
+        if let Err(e) = serve::serve(&mut app, addr, mqtt).await {
+            eprintln!("Error: Failed to serve metrics: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Read the session to replay up front so a bad file fails before the TUI starts
+    let player = match &startup_config.replay_path {
+        Some(path) => match SessionPlayer::load(path) {
+            Ok(player) => Some(player),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+    // Open the control socket before the TUI starts, so a bad path fails cleanly
+    #[cfg(unix)]
+    let control = match &startup_config.control_path {
+        Some(path) => match control::ControlSocket::bind(path) {
+            Ok(control) => Some(control),
+            Err(e) => {
+                eprintln!(
+                    "Error: Failed to open control socket {}: {}",
+                    path.display(),
+                    e
+                );
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+    #[cfg(not(unix))]
+    if startup_config.control_path.is_some() {
+        eprintln!("Error: --control needs Unix domain sockets");
+        std::process::exit(1);
+    }
+    let record_path = startup_config.record_path.clone();
+    let slow_link = startup_config.slow_link;
+    let linear = startup_config.linear;
+
     // Set up panic hook to restore terminal on crash
     setup_panic_hook();
 
     // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    // Over a slow link the eight basic colors take the fewest bytes
+    let color_support = if slow_link {
+        ColorSupport::Basic
+    } else {
+        ColorSupport::detect()
+    };
 
     // Create app instance with startup config
     let mut app = App::with_startup_config(startup_config);
+    app.webcams = webcams.into_iter().collect();
+    app.refresh_cooldown = refresh_cooldown;
+    app.power_saving = power_saving;
+    app.uv_dose_limit = uv_dose_limit;
+    app.notifications = notifications;
+    (app.night_dim, app.night_activity) = night_mode;
+    app.add_configured_sets(comparison_sets);
+    let journal = journal::Journal::open_default();
+    if let Some(journal) = &journal {
+        app.journal = journal.load();
+    }
 
-    // Initial render to show loading state
-    terminal.draw(|f| render_ui(f, &mut app))?;
+    let mut options = RunOptions {
+        color_support,
+        slow_link,
+        recorder: record_path.map(|path| SessionRecorder::new(path, &app)),
+        player,
+        journal,
+        linear: linear.then(|| linear::LinearOutput::new(Box::new(io::stdout()))),
+        #[cfg(unix)]
+        control,
+        // Draw webcam snapshots in place where the terminal supports it
+        #[cfg(feature = "graphics")]
+        webcam_view: webcam::GraphicsProtocol::detect()
+            .filter(|_| !linear)
+            .map(webcam::WebcamView::new),
+        mqtt: mqtt_settings.as_ref().map(mqtt::MqttPublisher::connect),
+        // Off screen frames aren't in the terminal the title would name
+        terminal_title: if set_terminal_title && !linear {
+            Some(terminal_title::TerminalTitle::start(&mut io::stdout())?)
+        } else {
+            None
+        },
+        usage: count_usage
+            .then(cache::CacheManager::new)
+            .flatten()
+            .map(usage::UsageTracker::start),
+        // Without them a closed tab or a SIGTERM still quits, just unsaved
+        #[cfg(unix)]
+        shutdown: signals::ShutdownSignals::listen().ok(),
+    };
+    #[cfg(feature = "graphics")]
+    {
+        app.webcam_graphics = options.webcam_view.is_some();
+    }
 
-    // Trigger initial data load
-    app.load_all_data().await;
+    enable_raw_mode()?;
+    let result = if linear {
+        // Frames are drawn off screen and printed as text, one line after
+        // another, with no alternate screen to take them away on exit
+        let (width, height) = crossterm::terminal::size()?;
+        let mut terminal = Terminal::new(TestBackend::new(width, height))?;
+        run_app(
+            &mut terminal,
+            &mut app,
+            &mut TerminalEvents,
+            &mut LiveData,
+            &mut options,
+        )
+        .await
+    } else {
+        let mut stdout = io::stdout();
+        // Focus changes let the dashboard refresh less often in the background
+        execute!(stdout, EnterAlternateScreen, EnableFocusChange)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+        let result = run_app(
+            &mut terminal,
+            &mut app,
+            &mut TerminalEvents,
+            &mut LiveData,
+            &mut options,
+        )
+        .await;
+        // After a hangup there's no terminal left to restore, which mustn't
+        // stop what the session gathered from being saved below
+        let restored = execute!(
+            terminal.backend_mut(),
+            DisableFocusChange,
+            LeaveAlternateScreen
+        )
+        .and_then(|()| match options.terminal_title {
+            Some(_) => terminal_title::restore(terminal.backend_mut()),
+            None => Ok(()),
+        });
+        result.and(restored)
+    };
 
-    // Main event loop
-    loop {
-        // Check if refresh was requested
-        if app.refresh_requested {
-            app.refresh_requested = false;
-            // Show a brief "Refreshing..." state
-            app.state = AppState::Loading;
-            terminal.draw(|f| render_ui(f, &mut app))?;
-            app.load_all_data().await;
+    // Restore terminal
+    let restored = disable_raw_mode();
+
+    if let Some(usage) = &options.usage {
+        if let Err(e) = usage.save() {
+            eprintln!("Error: Failed to save usage counts: {}", e);
+        }
+    }
+    if let Some(recorder) = &mut options.recorder {
+        recorder.record_state(&app.state);
+        if let Err(e) = recorder.save() {
+            eprintln!("Error: {}", e);
         }
+    }
+    result.and(restored)?;
+    if let Some(player) = &options.player {
+        println!("{}", player.summary());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use activities::Activity;
+    use chrono::{Duration as ChronoDuration, Local, NaiveTime, Utc};
+    use crossterm::event::KeyCode;
+    use data::{
+        all_beaches, BeachConditions, BeachId, DataQuality, DebugLog, HourlyForecast, TideEvent,
+        TideInfo, TideState, WaterQuality, WaterStatus, Weather, WeatherCondition, WeatherProvider,
+    };
+    use proptest::prelude::*;
+    use proptest::sample::select;
+    use ratatui::backend::TestBackend;
+
+    fn condition() -> impl Strategy<Value = WeatherCondition> {
+        select(vec![
+            WeatherCondition::Clear,
+            WeatherCondition::PartlyCloudy,
+            WeatherCondition::Cloudy,
+            WeatherCondition::Rain,
+            WeatherCondition::Showers,
+            WeatherCondition::Thunderstorm,
+            WeatherCondition::Snow,
+            WeatherCondition::Fog,
+        ])
+    }
 
-        // Render UI
-        terminal.draw(|f| render_ui(f, &mut app))?;
+    fn activity() -> impl Strategy<Value = Option<Activity>> {
+        proptest::option::of(select(Activity::all().to_vec()))
+    }
 
-        // Poll for keyboard events with 100ms timeout
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                app.handle_key(key);
+    fn hourly_forecast() -> impl Strategy<Value = HourlyForecast> {
+        (
+            0u8..24,
+            -40.0..45.0f64,
+            condition(),
+            0.0..150.0f64,
+            0.0..14.0f64,
+            0u8..=100,
+        )
+            .prop_map(
+                |(hour, temperature, condition, wind, uv, precipitation_chance)| HourlyForecast {
+                    hour,
+                    temperature,
+                    feels_like: temperature - 2.0,
+                    condition,
+                    wind,
+                    wind_direction: "SW".to_string(),
+                    uv,
+                    precipitation_chance,
+                    dew_point: None,
+                    wind_gusts: None,
+                    cloud_cover: None,
+                    data_quality: DataQuality::Reported,
+                },
+            )
+    }
+
+    prop_compose! {
+        fn weather()(
+            temperature in -40.0..45.0f64,
+            condition in condition(),
+            humidity in 0u8..=100,
+            wind in 0.0..150.0f64,
+            wind_direction in proptest::option::of(0.0..360.0f64),
+            uv in 0.0..14.0f64,
+            sunrise_minutes in 0u32..720,
+            sunset_minutes in 720u32..1440,
+            age_minutes in 0i64..(3 * 24 * 60),
+            hourly in proptest::collection::vec(hourly_forecast(), 0..30),
+        ) -> Weather {
+            let time = |minutes: u32| NaiveTime::from_hms_opt(minutes / 60, minutes % 60, 0).unwrap();
+            Weather {
+                temperature,
+                feels_like: temperature - 2.0,
+                condition,
+                humidity,
+                wind,
+                wind_direction,
+                uv,
+                sunrise: time(sunrise_minutes),
+                sunset: time(sunset_minutes),
+                fetched_at: Utc::now() - ChronoDuration::minutes(age_minutes),
+                hourly,
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
             }
         }
+    }
+
+    fn tide_event() -> impl Strategy<Value = TideEvent> {
+        (-24 * 60..48 * 60i64, -1.0..6.0f64).prop_map(|(offset_minutes, height)| TideEvent {
+            time: Local::now() + ChronoDuration::minutes(offset_minutes),
+            height,
+        })
+    }
 
-        // Check if we should quit
-        if app.should_quit {
-            break;
+    prop_compose! {
+        fn tides()(
+            current_height in -1.0..6.0f64,
+            tide_state in select(vec![TideState::Rising, TideState::Falling, TideState::High, TideState::Low]),
+            next_high in proptest::option::of(tide_event()),
+            next_low in proptest::option::of(tide_event()),
+            age_minutes in 0i64..(3 * 24 * 60),
+        ) -> TideInfo {
+            TideInfo {
+                current_height,
+                tide_state,
+                next_high,
+                next_low,
+                fetched_at: Utc::now() - ChronoDuration::minutes(age_minutes),
+            }
         }
     }
 
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    prop_compose! {
+        fn water_quality()(
+            status in select(vec![WaterStatus::Safe, WaterStatus::Advisory, WaterStatus::Closed, WaterStatus::Unknown]),
+            ecoli_count in proptest::option::of(0u32..5000),
+            days_ago in 0i64..60,
+            advisory_reason in proptest::option::of(".{0,80}"),
+            no_samples_station in proptest::option::of("[a-z ]{0,30}"),
+        ) -> WaterQuality {
+            WaterQuality {
+                status,
+                ecoli_count,
+                sample_date: Local::now().date_naive() - ChronoDuration::days(days_ago),
+                advisory_reason,
+                fetched_at: Utc::now(),
+                no_samples_station,
+                sampling: None,
+            }
+        }
+    }
 
-    Ok(())
+    /// Any combination of data, including none at all, for each beach
+    fn beach_conditions() -> impl Strategy<Value = Vec<Option<BeachConditions>>> {
+        let beaches = all_beaches();
+        proptest::collection::vec(
+            proptest::option::of((
+                proptest::option::of(weather()),
+                proptest::option::of(tides()),
+                proptest::option::of(water_quality()),
+            )),
+            beaches.len(),
+        )
+        .prop_map(move |entries| {
+            entries
+                .into_iter()
+                .zip(beaches)
+                .map(|(entry, beach)| {
+                    entry.map(|(weather, tides, water_quality)| BeachConditions {
+                        beach: beach.clone(),
+                        weather,
+                        tides,
+                        water_quality,
+                        closure: None,
+                        waves: None,
+                        air_quality: None,
+                        seawall_closure: None,
+                    })
+                })
+                .collect()
+        })
+    }
+
+    fn app_state() -> impl Strategy<Value = AppState> {
+        let beach_ids: Vec<BeachId> = all_beaches().iter().map(|b| b.id.clone()).collect();
+        prop_oneof![
+            Just(AppState::Loading),
+            Just(AppState::BeachList),
+            Just(AppState::PlanTrip),
+            Just(AppState::Dashboard),
+            Just(AppState::WaterQualityOverview),
+            Just(AppState::Journal),
+            select(beach_ids).prop_map(AppState::BeachDetail),
+            // A beach that doesn't exist, e.g. from an old recorded session
+            Just(AppState::BeachDetail(BeachId::new("no-such-beach"))),
+        ]
+    }
+
+    /// The parts of an `App` the renderers read, picked at random
+    #[derive(Debug)]
+    struct AppSpec {
+        state: AppState,
+        conditions: Vec<Option<BeachConditions>>,
+        selected_index: usize,
+        activities: (Option<Activity>, Option<Activity>),
+        plan_cursor: (usize, usize),
+        plan_time_range: (u8, u8),
+        detail_scroll_offset: u16,
+        debug_scroll_offset: u16,
+        flags: [bool; 6],
+        debug_entries: usize,
+    }
+
+    impl AppSpec {
+        fn build(self) -> App {
+            let mut app = App::new();
+            app.state = self.state;
+            for entry in self.conditions.into_iter().flatten() {
+                app.beach_conditions.insert(entry.beach.id.clone(), entry);
+            }
+            app.selected_index = self.selected_index;
+            (app.current_activity, app.secondary_activity) = self.activities;
+            app.plan_cursor = self.plan_cursor;
+            app.plan_time_range = self.plan_time_range;
+            app.detail_scroll_offset = self.detail_scroll_offset;
+            app.debug_scroll_offset = self.debug_scroll_offset;
+            let [show_help, show_debug, expanded, hide_closed, hide_unsuitable, refreshed] =
+                self.flags;
+            app.show_help = show_help;
+            app.tide_chart_expanded = expanded;
+            app.hide_closed = hide_closed;
+            app.hide_unsuitable = hide_unsuitable;
+            if refreshed {
+                app.last_refresh = Some(Local::now());
+            }
+            if show_debug {
+                let log = DebugLog::new(8);
+                for i in 0..self.debug_entries {
+                    log.record(
+                        "Open-Meteo",
+                        &format!("beach {}", i),
+                        "https://example.com",
+                        "{\"a\": [1, 2]}",
+                    );
+                }
+                app.debug_log = Some(log);
+                app.show_debug_view = true;
+            }
+            app
+        }
+    }
+
+    prop_compose! {
+        fn app_spec()(
+            state in app_state(),
+            conditions in beach_conditions(),
+            selected_index in 0usize..20,
+            activities in (activity(), activity()),
+            plan_cursor in (0usize..20, 0usize..24),
+            plan_start in 0u8..23,
+            plan_length in 1u8..24,
+            detail_scroll_offset in any::<u16>(),
+            debug_scroll_offset in any::<u16>(),
+            flags in proptest::array::uniform6(any::<bool>()),
+            debug_entries in 0usize..4,
+        ) -> AppSpec {
+            AppSpec {
+                state,
+                conditions,
+                selected_index,
+                activities,
+                plan_cursor,
+                plan_time_range: (plan_start, (plan_start + plan_length).min(23)),
+                detail_scroll_offset,
+                debug_scroll_offset,
+                flags,
+                debug_entries,
+            }
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(200))]
+
+        /// Every screen renders any app state at any terminal size without panicking
+        #[test]
+        fn test_render_never_panics(
+            spec in app_spec(),
+            width in 1u16..=160,
+            height in 1u16..=60,
+        ) {
+            let mut app = spec.build();
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            terminal
+                .draw(|frame| render_ui(frame, &mut app, ColorSupport::TrueColor))
+                .unwrap();
+
+            // Room left for a webcam snapshot must stay on screen
+            if let Some(area) = app.webcam_area {
+                prop_assert!(area.right() <= width && area.bottom() <= height);
+            }
+        }
+
+        /// Scrolling the detail view never panics, however far it goes
+        #[test]
+        fn test_detail_scrolling_never_panics(
+            spec in app_spec(),
+            keys in proptest::collection::vec(select(vec![KeyCode::Down, KeyCode::Up, KeyCode::Char('j'), KeyCode::Char('k'), KeyCode::Char('G'), KeyCode::Char('g'), KeyCode::Char('t')]), 0..40),
+            height in 1u16..=60,
+        ) {
+            let mut app = spec.build();
+            app.state = AppState::BeachDetail(all_beaches()[0].id.clone());
+            app.show_help = false;
+            app.show_debug_view = false;
+            let mut terminal = Terminal::new(TestBackend::new(80, height)).unwrap();
+            for code in keys {
+                app.handle_key(crossterm::event::KeyEvent::new(code, crossterm::event::KeyModifiers::NONE));
+                terminal
+                    .draw(|frame| render_ui(frame, &mut app, ColorSupport::TrueColor))
+                    .unwrap();
+            }
+        }
+    }
 }
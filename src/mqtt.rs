@@ -0,0 +1,182 @@
+//! Publishing conditions to an MQTT broker for home automation
+//!
+//! With an `mqtt` section in the config, every refresh publishes each
+//! beach's conditions and current scores as retained JSON messages, so Home
+//! Assistant and the like can show them or automate on them without polling:
+//!
+//! - `beachcli/kitsilano/conditions`: the conditions, as `conditions --json`
+//!   prints them
+//! - `beachcli/kitsilano/score/swimming`: the score for the current hour,
+//!   `{"activity": "swimming", "score": 82, "updated": "..."}`, with a null
+//!   score when there's no weather to score against
+//!
+//! Messages are queued without waiting for the broker; a background task
+//! connects, sends them and reconnects after losing the broker. When the
+//! broker stays away long enough for the queue to fill, later messages are
+//! dropped until it's back, since the next refresh brings newer ones.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Local, SecondsFormat};
+use rumqttc::{AsyncClient, MqttOptions, QoS};
+use serde::Serialize;
+use tokio::task::JoinHandle;
+
+use crate::activities::Activity;
+use crate::app::App;
+use crate::config::MqttSettings;
+use crate::data::{all_beaches, BeachConditions};
+use crate::scoring::score_now;
+
+/// How often the connection is kept alive with a ping
+const KEEP_ALIVE: Duration = Duration::from_secs(30);
+
+/// How long to wait before reconnecting to a broker that went away
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Message published on a beach's score topic
+#[derive(Debug, Serialize)]
+struct ScoreMessage<'a> {
+    activity: &'a str,
+    score: Option<u8>,
+    updated: String,
+}
+
+/// Publishes each beach's conditions and scores whenever the data changes
+pub struct MqttPublisher {
+    client: AsyncClient,
+    topic_prefix: String,
+    /// Data version last published, if any
+    published: Option<u64>,
+    /// Task driving the connection, stopped when the publisher is dropped
+    connection: JoinHandle<()>,
+}
+
+impl MqttPublisher {
+    /// Starts connecting to the configured broker in the background
+    pub fn connect(settings: &MqttSettings) -> Self {
+        let mut options = MqttOptions::new(
+            format!("vanbeach-{}", std::process::id()),
+            settings.host.clone(),
+            settings.port,
+        );
+        options.set_keep_alive(KEEP_ALIVE);
+        if let Some(username) = &settings.username {
+            options.set_credentials(
+                username.clone(),
+                settings.password.clone().unwrap_or_default(),
+            );
+        }
+        // Room for two refreshes' worth of messages
+        let capacity = 2 * all_beaches().len() * (Activity::all().len() + 1);
+        let (client, mut event_loop) = AsyncClient::new(options, capacity);
+        let connection = tokio::spawn(async move {
+            loop {
+                if event_loop.poll().await.is_err() {
+                    tokio::time::sleep(RECONNECT_DELAY).await;
+                }
+            }
+        });
+        Self {
+            client,
+            topic_prefix: settings.topic_prefix.clone(),
+            published: None,
+            connection,
+        }
+    }
+
+    /// Publishes every beach with data if it changed since the last call
+    pub fn publish_changes(&mut self, app: &App, now: DateTime<Local>) {
+        if self.published == Some(app.data_version()) {
+            return;
+        }
+        self.published = Some(app.data_version());
+        for conditions in all_beaches()
+            .iter()
+            .filter_map(|beach| app.get_conditions(&beach.id))
+        {
+            for (topic, payload) in messages(&self.topic_prefix, conditions, now) {
+                // A full queue means the broker is away; newer data follows
+                let _ = self
+                    .client
+                    .try_publish(topic, QoS::AtLeastOnce, true, payload);
+            }
+        }
+    }
+}
+
+impl Drop for MqttPublisher {
+    fn drop(&mut self) {
+        self.connection.abort();
+    }
+}
+
+/// Topics and JSON payloads for one beach: its conditions, then its score
+/// for each activity offered there
+fn messages(
+    prefix: &str,
+    conditions: &BeachConditions,
+    now: DateTime<Local>,
+) -> Vec<(String, String)> {
+    let beach_prefix = format!("{}/{}", prefix, conditions.beach.id);
+    let updated = now.to_rfc3339_opts(SecondsFormat::Secs, false);
+    let mut messages = Vec::new();
+    if let Ok(payload) = serde_json::to_string(conditions) {
+        messages.push((format!("{}/conditions", beach_prefix), payload));
+    }
+    for activity in Activity::all()
+        .iter()
+        .filter(|activity| activity.offered_at(&conditions.beach.id))
+    {
+        let message = ScoreMessage {
+            activity: activity.slug(),
+            score: score_now(conditions, *activity, now),
+            updated: updated.clone(),
+        };
+        if let Ok(payload) = serde_json::to_string(&message) {
+            messages.push((
+                format!("{}/score/{}", beach_prefix, activity.slug()),
+                payload,
+            ));
+        }
+    }
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_messages_cover_conditions_and_offered_activities() {
+        let beach = crate::data::get_beach_by_id("kitsilano").unwrap().clone();
+        let conditions = BeachConditions {
+            beach,
+            weather: None,
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        };
+        let now = Local.with_ymd_and_hms(2026, 7, 15, 14, 0, 0).unwrap();
+
+        let messages = messages("beachcli", &conditions, now);
+
+        assert_eq!(messages[0].0, "beachcli/kitsilano/conditions");
+        let published: BeachConditions = serde_json::from_str(&messages[0].1).unwrap();
+        assert_eq!(published.beach.id, conditions.beach.id);
+        let (topic, payload) = messages
+            .iter()
+            .find(|(topic, _)| topic.ends_with("/swimming"))
+            .unwrap();
+        assert_eq!(topic, "beachcli/kitsilano/score/swimming");
+        let payload: serde_json::Value = serde_json::from_str(payload).unwrap();
+        assert_eq!(payload["activity"], "swimming");
+        assert!(payload["score"].is_null());
+        // Kitsilano faces no swell, so there's no surf score to publish
+        assert!(!messages.iter().any(|(topic, _)| topic.ends_with("/surf")));
+    }
+}
@@ -0,0 +1,253 @@
+//! Score matrix for the PlanTrip grid
+//!
+//! The grid scores every beach at every visible hour, and the recommendations
+//! below it look at all of those scores again. Computing them on each frame
+//! made rendering lag, so the matrix is computed once, in parallel across
+//! beaches, and kept until the data, the activity, the visible hours or the
+//! day change (see `App::plan_scores`). Each cell keeps what it was scored
+//! from too, for the readout under the grid, and the predicted crowd for the
+//! optional crowd rows.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use rayon::prelude::*;
+
+use crate::activities::Activity;
+use crate::crowd;
+use crate::data::{all_beaches, BeachConditions, BeachId};
+use crate::scoring::{ActivityScorer, ScoreInputs};
+
+/// Score used when a cell can't be scored (no activity or no weather)
+pub const NEUTRAL_SCORE: u8 = 50;
+
+/// What a score matrix was computed from; a matrix is reused while it matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatrixKey {
+    /// Activity the cells were scored for
+    pub activity: Option<Activity>,
+    /// Visible hour range (start_hour, end_hour)
+    pub hours: (u8, u8),
+    /// `App::data_version` at the time of computing
+    pub data_version: u64,
+    /// Day the shade was computed for
+    pub date: NaiveDate,
+}
+
+/// Scores for every beach (rows, in registry order) at every visible hour
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreMatrix {
+    /// Inputs the scores were computed from
+    pub key: MatrixKey,
+    scores: Vec<Vec<u8>>,
+    /// What each cell was scored from, where it could be scored
+    inputs: Vec<Vec<Option<ScoreInputs>>>,
+    /// Predicted crowd level (0.0-1.0) at each cell
+    crowds: Vec<Vec<f32>>,
+}
+
+impl ScoreMatrix {
+    /// Scores every beach at every hour of the key's range in parallel
+    pub fn compute(conditions: &HashMap<BeachId, BeachConditions>, key: MatrixKey) -> Self {
+        let (start_hour, end_hour) = key.hours;
+        let (scores, (inputs, crowds)) = all_beaches()
+            .par_iter()
+            .map(|beach| {
+                let conditions = conditions.get(beach.id.as_str());
+                let scorer = key
+                    .activity
+                    .zip(conditions)
+                    .map(|(activity, conditions)| ActivityScorer::new(conditions, activity));
+                let predictions = crowd::predict_day(
+                    &beach.id,
+                    key.date,
+                    key.date,
+                    conditions.and_then(|c| c.weather.as_ref()),
+                );
+                let (scores, (inputs, crowds)) = (start_hour..=end_hour)
+                    .map(|hour| {
+                        let inputs = scorer.as_ref().and_then(|scorer| scorer.inputs_at(hour));
+                        let crowd = predictions.get(hour as usize).map_or(0.0, |p| p.level);
+                        (score_cell(scorer.as_ref(), hour), (inputs, crowd))
+                    })
+                    .unzip::<_, _, Vec<_>, (Vec<_>, Vec<_>)>();
+                (scores, (inputs, crowds))
+            })
+            .unzip::<_, _, Vec<_>, (Vec<_>, Vec<_>)>();
+        Self {
+            key,
+            scores,
+            inputs,
+            crowds,
+        }
+    }
+
+    /// Score of the beach at `beach_index` at `hour`, or the neutral score
+    /// outside the matrix
+    pub fn score(&self, beach_index: usize, hour: u8) -> u8 {
+        hour.checked_sub(self.key.hours.0)
+            .and_then(|offset| self.scores.get(beach_index)?.get(offset as usize))
+            .copied()
+            .unwrap_or(NEUTRAL_SCORE)
+    }
+
+    /// What the beach at `beach_index` at `hour` was scored from; `None`
+    /// outside the matrix or for a cell without an activity or weather
+    pub fn inputs(&self, beach_index: usize, hour: u8) -> Option<ScoreInputs> {
+        let offset = hour.checked_sub(self.key.hours.0)?;
+        *self.inputs.get(beach_index)?.get(offset as usize)?
+    }
+
+    /// Predicted crowd level of the beach at `beach_index` at `hour`; `None`
+    /// outside the matrix
+    pub fn crowd(&self, beach_index: usize, hour: u8) -> Option<f32> {
+        let offset = hour.checked_sub(self.key.hours.0)?;
+        self.crowds.get(beach_index)?.get(offset as usize).copied()
+    }
+
+    /// Highest-scoring (beach_index, hour, score), the earliest cell winning
+    /// ties; `None` without an activity
+    pub fn best(&self) -> Option<(usize, u8, u8)> {
+        self.key.activity?;
+        let mut best: Option<(usize, u8, u8)> = None;
+        for (beach_index, row) in self.scores.iter().enumerate() {
+            for (offset, &score) in row.iter().enumerate() {
+                if score > best.map_or(0, |(_, _, s)| s) {
+                    best = Some((beach_index, self.key.hours.0 + offset as u8, score));
+                }
+            }
+        }
+        best
+    }
+}
+
+/// Scores one beach at one hour, or the neutral score without an activity
+/// or weather to score against
+fn score_cell(scorer: Option<&ActivityScorer>, hour: u8) -> u8 {
+    scorer
+        .and_then(|scorer| scorer.score_hour(hour))
+        .map_or(NEUTRAL_SCORE, |slot| slot.score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, Utc};
+
+    use crate::data::{Weather, WeatherCondition, WeatherProvider};
+
+    fn key(activity: Option<Activity>) -> MatrixKey {
+        MatrixKey {
+            activity,
+            hours: (6, 21),
+            data_version: 0,
+            date: Local::now().date_naive(),
+        }
+    }
+
+    fn sunny(beach_index: usize) -> (BeachId, BeachConditions) {
+        let beach = all_beaches()[beach_index].clone();
+        let weather = Weather {
+            temperature: 26.0,
+            feels_like: 27.0,
+            condition: WeatherCondition::Clear,
+            weather_code: None,
+            humidity: 40,
+            wind: 5.0,
+            wind_direction: Some(270.0),
+            uv: 6.0,
+            sunrise: chrono::NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+            sunset: chrono::NaiveTime::from_hms_opt(21, 15, 0).unwrap(),
+            fetched_at: Utc::now(),
+            hourly: Vec::new(),
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+        };
+        (
+            beach.id.clone(),
+            BeachConditions {
+                beach,
+                weather: Some(weather),
+                tides: None,
+                water_quality: None,
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_matrix_matches_cell_by_cell_scoring() {
+        let conditions: HashMap<_, _> = [sunny(0), sunny(2)].into_iter().collect();
+        let matrix = ScoreMatrix::compute(&conditions, key(Some(Activity::Sunbathing)));
+
+        for (index, beach) in all_beaches().iter().enumerate() {
+            for hour in 6..=21 {
+                let scorer = conditions
+                    .get(beach.id.as_str())
+                    .map(|c| ActivityScorer::new(c, Activity::Sunbathing));
+                assert_eq!(matrix.score(index, hour), score_cell(scorer.as_ref(), hour));
+            }
+        }
+        // Beaches without data score neutral
+        assert_eq!(matrix.score(1, 12), NEUTRAL_SCORE);
+        // Outside the matrix
+        assert_eq!(matrix.score(0, 5), NEUTRAL_SCORE);
+        assert_eq!(matrix.score(all_beaches().len(), 12), NEUTRAL_SCORE);
+    }
+
+    #[test]
+    fn test_matrix_keeps_what_each_cell_was_scored_from() {
+        let conditions: HashMap<_, _> = [sunny(0)].into_iter().collect();
+        let matrix = ScoreMatrix::compute(&conditions, key(Some(Activity::Swimming)));
+
+        let scorer = ActivityScorer::new(&conditions[&all_beaches()[0].id], Activity::Swimming);
+        let inputs = matrix.inputs(0, 14).unwrap();
+        assert_eq!(Some(inputs), scorer.inputs_at(14));
+        assert_eq!(
+            (inputs.temperature, inputs.wind, inputs.uv),
+            (26.0, 5.0, 6.0)
+        );
+        // Beaches without data, hours outside the matrix and an unset
+        // activity have nothing to show
+        assert_eq!(matrix.inputs(1, 14), None);
+        assert_eq!(matrix.inputs(0, 22), None);
+        let matrix = ScoreMatrix::compute(&conditions, key(None));
+        assert_eq!(matrix.inputs(0, 14), None);
+    }
+
+    #[test]
+    fn test_matrix_keeps_predicted_crowds_for_every_beach() {
+        let conditions: HashMap<_, _> = [sunny(0)].into_iter().collect();
+        let key = key(None);
+        let matrix = ScoreMatrix::compute(&conditions, key);
+
+        for (index, beach) in all_beaches().iter().enumerate() {
+            let weather = conditions
+                .get(beach.id.as_str())
+                .and_then(|c| c.weather.as_ref());
+            let predictions = crowd::predict_day(&beach.id, key.date, key.date, weather);
+            assert_eq!(matrix.crowd(index, 14), Some(predictions[14].level));
+        }
+        // Crowds are predicted without an activity, but not outside the matrix
+        assert_eq!(matrix.crowd(0, 22), None);
+        assert_eq!(matrix.crowd(all_beaches().len(), 14), None);
+    }
+
+    #[test]
+    fn test_best_cell_needs_an_activity() {
+        let conditions: HashMap<_, _> = [sunny(0)].into_iter().collect();
+
+        let matrix = ScoreMatrix::compute(&conditions, key(None));
+        assert_eq!(matrix.best(), None);
+
+        let matrix = ScoreMatrix::compute(&conditions, key(Some(Activity::Sunbathing)));
+        let (beach_index, hour, score) = matrix.best().unwrap();
+        assert_eq!(beach_index, 0);
+        assert_eq!(score, matrix.score(0, hour));
+        assert!(score > NEUTRAL_SCORE);
+    }
+}
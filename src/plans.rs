@@ -0,0 +1,164 @@
+//! Pinned trip plans
+//!
+//! A plan is a beach and hour pinned from the PlanTrip grid. Plans are saved
+//! through the cache manager so they survive restarts, and are dropped once
+//! their day has passed.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::activities::Activity;
+use crate::cache::{CacheManager, StalenessPolicy};
+use crate::data::BeachId;
+
+/// Cache key under which pinned plans are stored
+pub const PLANS_CACHE_KEY: &str = "pinned_plans";
+
+/// Plans only ever cover today, so a day is plenty
+const PLANS_TTL: Duration = Duration::days(1);
+
+/// A beach and hour the user has pinned as a plan
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PinnedPlan {
+    /// Beach the plan is for
+    pub beach_id: BeachId,
+    /// Day the plan is for
+    pub date: NaiveDate,
+    /// Hour of the day the plan starts (0-23)
+    pub hour: u8,
+    /// Activity that was selected when the plan was pinned
+    pub activity: Option<Activity>,
+}
+
+impl PinnedPlan {
+    /// Creates a new plan
+    pub fn new(beach_id: BeachId, date: NaiveDate, hour: u8, activity: Option<Activity>) -> Self {
+        Self {
+            beach_id,
+            date,
+            hour,
+            activity,
+        }
+    }
+
+    /// Returns true if this plan is for the given beach, day and hour
+    pub fn matches(&self, beach_id: &str, date: NaiveDate, hour: u8) -> bool {
+        self.beach_id == beach_id && self.date == date && self.hour == hour
+    }
+
+    /// Local time at which the plan starts
+    pub fn starts_at(&self) -> Option<DateTime<Local>> {
+        let naive = self.date.and_hms_opt(self.hour as u32, 0, 0)?;
+        Local.from_local_datetime(&naive).earliest()
+    }
+
+    /// Time remaining until the plan starts, or `None` if it has already started
+    pub fn time_until(&self, now: DateTime<Local>) -> Option<Duration> {
+        let remaining = self.starts_at()? - now;
+        (remaining > Duration::zero()).then_some(remaining)
+    }
+}
+
+/// Loads saved plans, dropping any for days before `today`
+pub fn load_plans(cache: &CacheManager, today: NaiveDate) -> Vec<PinnedPlan> {
+    cache
+        .get_with_policy::<Vec<PinnedPlan>>(PLANS_CACHE_KEY, StalenessPolicy::AnyAge)
+        .data()
+        .map(|data| data.into_iter().filter(|plan| plan.date >= today).collect())
+        .unwrap_or_default()
+}
+
+/// Saves plans to the cache
+pub fn save_plans(cache: &CacheManager, plans: &[PinnedPlan]) -> std::io::Result<()> {
+    cache.put(PLANS_CACHE_KEY, &plans, PLANS_TTL)
+}
+
+/// Returns the soonest plan that hasn't started yet
+pub fn next_upcoming(plans: &[PinnedPlan], now: DateTime<Local>) -> Option<&PinnedPlan> {
+    plans
+        .iter()
+        .filter_map(|plan| plan.time_until(now).map(|d| (plan, d)))
+        .min_by_key(|(_, d)| *d)
+        .map(|(plan, _)| plan)
+}
+
+/// Formats a countdown like "in 2h 15m" or "in 40m"
+pub fn format_countdown(remaining: Duration) -> String {
+    let total_minutes = remaining.num_minutes().max(0);
+    let hours = total_minutes / 60;
+    let minutes = total_minutes % 60;
+    if hours > 0 {
+        format!("in {}h {}m", hours, minutes)
+    } else {
+        format!("in {}m", minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn at(date: NaiveDate, hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .from_local_datetime(&date.and_hms_opt(hour, minute, 0).unwrap())
+            .earliest()
+            .unwrap()
+    }
+
+    fn day() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, 15).unwrap()
+    }
+
+    #[test]
+    fn test_time_until_future_plan() {
+        let plan = PinnedPlan::new(
+            BeachId::new("kitsilano"),
+            day(),
+            15,
+            Some(Activity::Swimming),
+        );
+        let remaining = plan.time_until(at(day(), 12, 45)).unwrap();
+        assert_eq!(remaining.num_minutes(), 135);
+    }
+
+    #[test]
+    fn test_time_until_started_plan_is_none() {
+        let plan = PinnedPlan::new(BeachId::new("kitsilano"), day(), 15, None);
+        assert!(plan.time_until(at(day(), 15, 30)).is_none());
+    }
+
+    #[test]
+    fn test_next_upcoming_picks_soonest() {
+        let plans = vec![
+            PinnedPlan::new(BeachId::new("kitsilano"), day(), 18, None),
+            PinnedPlan::new(BeachId::new("english-bay"), day(), 14, None),
+            PinnedPlan::new(BeachId::new("jericho"), day(), 9, None),
+        ];
+        let next = next_upcoming(&plans, at(day(), 10, 0)).unwrap();
+        assert_eq!(next.beach_id, "english-bay");
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::minutes(135)), "in 2h 15m");
+        assert_eq!(format_countdown(Duration::minutes(40)), "in 40m");
+    }
+
+    #[test]
+    fn test_save_and_load_drops_past_days() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let yesterday = day().pred_opt().unwrap();
+        let plans = vec![
+            PinnedPlan::new(BeachId::new("kitsilano"), yesterday, 15, None),
+            PinnedPlan::new(BeachId::new("jericho"), day(), 16, Some(Activity::Sunset)),
+        ];
+
+        save_plans(&cache, &plans).unwrap();
+        let loaded = load_plans(&cache, day());
+
+        assert_eq!(loaded, vec![plans[1].clone()]);
+    }
+}
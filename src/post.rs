@@ -0,0 +1,448 @@
+//! Posting a daily summary to Mastodon and Bluesky
+//!
+//! `vanbeach post --to mastodon --to bluesky` fetches every beach, writes a
+//! short summary of the day (the best beach and window for the activity,
+//! water quality advisories and closures) and posts it, so a community beach
+//! bot can run from cron with nothing but this crate:
+//!
+//! ```text
+//! Vancouver beaches, Saturday, July 18
+//! Best for swimming: Kitsilano Beach, 1:00 PM to 5:00 PM (score 86)
+//! Water quality advisory: Sunset Beach, Trout Lake Beach
+//! ```
+//!
+//! Credentials come from the environment rather than the config file, so
+//! they can live in the cron job's secrets. Posts go straight out instead of
+//! through the HTTP recorder, which would write the credentials to disk and
+//! replay a post instead of making it.
+
+use chrono::{DateTime, Local, SecondsFormat, Timelike, Utc};
+use reqwest::{Client, Response};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::activities::Activity;
+use crate::calendar::{best_run, Run};
+use crate::cli::PostTarget;
+use crate::data::{BeachConditions, WaterStatus};
+use crate::scoring::ActivityScorer;
+use crate::time_format::format_hour;
+
+/// Longest post, in characters: Bluesky's limit, which also fits Mastodon's
+pub const POST_MAX_CHARS: usize = 300;
+
+/// Base URL of the Mastodon instance, e.g. `https://mastodon.social`
+pub const MASTODON_URL_ENV: &str = "BEACH_CLI_MASTODON_URL";
+
+/// Access token of a Mastodon application with the `write:statuses` scope
+pub const MASTODON_TOKEN_ENV: &str = "BEACH_CLI_MASTODON_TOKEN";
+
+/// Bluesky handle to post as, e.g. `vanbeach.bsky.social`
+pub const BLUESKY_HANDLE_ENV: &str = "BEACH_CLI_BLUESKY_HANDLE";
+
+/// App password for the Bluesky handle
+pub const BLUESKY_PASSWORD_ENV: &str = "BEACH_CLI_BLUESKY_APP_PASSWORD";
+
+/// PDS the Bluesky account lives on, if not `DEFAULT_BLUESKY_PDS`
+pub const BLUESKY_PDS_ENV: &str = "BEACH_CLI_BLUESKY_PDS";
+
+/// PDS of accounts hosted by Bluesky itself
+pub const DEFAULT_BLUESKY_PDS: &str = "https://bsky.social";
+
+/// How far below the day's best score an hour can be and still count toward
+/// the best window
+const WINDOW_MARGIN: u8 = 10;
+
+/// Errors that can occur when posting
+#[derive(Debug, Error)]
+pub enum PostError {
+    /// A credential isn't in the environment
+    #[error("{0} is not set")]
+    MissingCredential(&'static str),
+
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The service turned the request down
+    #[error("{service} answered {status}: {message}")]
+    Rejected {
+        service: &'static str,
+        status: u16,
+        message: String,
+    },
+
+    /// The service accepted the request but its answer lacks what's needed
+    #[error("Unexpected response from {0}")]
+    UnexpectedResponse(&'static str),
+}
+
+/// Display name of a service
+pub fn service_name(target: PostTarget) -> &'static str {
+    match target {
+        PostTarget::Mastodon => "Mastodon",
+        PostTarget::Bluesky => "Bluesky",
+    }
+}
+
+/// Writes the day's summary for `activity` across `beaches`
+///
+/// Names the beach with the best window from now on (the earliest listed
+/// wins a tie) and every beach under a water quality advisory or closed.
+/// When naming them all would run past `POST_MAX_CHARS`, the advisories and
+/// closures are counted instead.
+pub fn daily_summary(
+    beaches: &[&BeachConditions],
+    activity: Activity,
+    now: DateTime<Utc>,
+) -> String {
+    let date = match beaches.first() {
+        Some(conditions) => conditions.beach.timezone.local(now).date(),
+        None => now.with_timezone(&Local).date_naive(),
+    };
+    let header = format!("Vancouver beaches, {}", date.format("%A, %B %-d"));
+
+    let activity_name = activity.label().to_lowercase();
+    let best = beaches
+        .iter()
+        .filter(|conditions| activity.offered_at(&conditions.beach.id))
+        .filter_map(|conditions| best_window(conditions, activity, now).map(|w| (conditions, w)))
+        // max_by_key keeps the last of equals, so search from the end
+        .rev()
+        .max_by_key(|(_, window)| window.peak);
+    let best_line = match best {
+        Some((conditions, window)) => format!(
+            "Best for {}: {}, {} to {} (score {})",
+            activity_name,
+            conditions.beach.name,
+            format_hour(window.start_hour),
+            format_hour(window.end_hour % 24),
+            window.peak
+        ),
+        None => format!("Best for {}: no forecast to score", activity_name),
+    };
+
+    let named = |status: WaterStatus| -> Vec<&str> {
+        beaches
+            .iter()
+            .filter(|conditions| {
+                conditions
+                    .water_quality
+                    .as_ref()
+                    .is_some_and(|wq| wq.effective_status() == status)
+            })
+            .map(|conditions| conditions.beach.name.as_str())
+            .collect()
+    };
+    let advisories = named(WaterStatus::Advisory);
+    let closed = named(WaterStatus::Closed);
+
+    let render = |counted: bool| {
+        let mut lines = vec![header.clone(), best_line.clone()];
+        if advisories.is_empty() && closed.is_empty() {
+            lines.push("Water quality: no advisories".to_string());
+        }
+        for (label, names) in [
+            ("Water quality advisory", &advisories),
+            ("Closed to swimming", &closed),
+        ] {
+            match names.len() {
+                0 => {}
+                n if counted => lines.push(format!(
+                    "{} at {} beach{}",
+                    label,
+                    n,
+                    if n == 1 { "" } else { "es" }
+                )),
+                _ => lines.push(format!("{}: {}", label, names.join(", "))),
+            }
+        }
+        lines.join("\n")
+    };
+    let text = render(false);
+    if text.chars().count() > POST_MAX_CHARS {
+        render(true)
+    } else {
+        text
+    }
+}
+
+/// The beach's best run of hours from now to the end of its day: hours
+/// within `WINDOW_MARGIN` of the day's best score, around the best hour
+fn best_window(
+    conditions: &BeachConditions,
+    activity: Activity,
+    now: DateTime<Utc>,
+) -> Option<Run> {
+    let hour = conditions.beach.timezone.local(now).hour() as u8;
+    let scores: Vec<(u8, u8)> = ActivityScorer::hourly_from(conditions, activity, hour)
+        .map(|(hour, slot)| (hour, slot.score))
+        .collect();
+    let peak = scores.iter().map(|&(_, score)| score).max()?;
+    best_run(&scores, peak.saturating_sub(WINDOW_MARGIN))
+}
+
+/// Posts `text` to the service's account, returning the post's link
+pub async fn post(target: PostTarget, text: &str) -> Result<String, PostError> {
+    match target {
+        PostTarget::Mastodon => post_to_mastodon(text).await,
+        PostTarget::Bluesky => post_to_bluesky(text).await,
+    }
+}
+
+/// A credential from the environment; empty counts as unset
+fn credential(name: &'static str) -> Result<String, PostError> {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.is_empty())
+        .ok_or(PostError::MissingCredential(name))
+}
+
+async fn post_to_mastodon(text: &str) -> Result<String, PostError> {
+    let instance = credential(MASTODON_URL_ENV)?;
+    let token = credential(MASTODON_TOKEN_ENV)?;
+    let response = Client::new()
+        .post(format!(
+            "{}/api/v1/statuses",
+            instance.trim_end_matches('/')
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "status": text }))
+        .send()
+        .await?;
+    let status = accepted("Mastodon", response).await?;
+    status["url"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or(PostError::UnexpectedResponse("Mastodon"))
+}
+
+async fn post_to_bluesky(text: &str) -> Result<String, PostError> {
+    let handle = credential(BLUESKY_HANDLE_ENV)?;
+    let password = credential(BLUESKY_PASSWORD_ENV)?;
+    let pds = credential(BLUESKY_PDS_ENV).unwrap_or_else(|_| DEFAULT_BLUESKY_PDS.to_string());
+    let pds = pds.trim_end_matches('/');
+    let client = Client::new();
+
+    let response = client
+        .post(format!("{}/xrpc/com.atproto.server.createSession", pds))
+        .json(&json!({ "identifier": handle, "password": password }))
+        .send()
+        .await?;
+    let session = accepted("Bluesky", response).await?;
+    let (Some(token), Some(did)) = (session["accessJwt"].as_str(), session["did"].as_str()) else {
+        return Err(PostError::UnexpectedResponse("Bluesky"));
+    };
+
+    let response = client
+        .post(format!("{}/xrpc/com.atproto.repo.createRecord", pds))
+        .bearer_auth(token)
+        .json(&json!({
+            "repo": did,
+            "collection": "app.bsky.feed.post",
+            "record": {
+                "$type": "app.bsky.feed.post",
+                "text": text,
+                "createdAt": Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            },
+        }))
+        .send()
+        .await?;
+    let record = accepted("Bluesky", response).await?;
+    record["uri"]
+        .as_str()
+        .and_then(|uri| bluesky_post_url(&handle, uri))
+        .ok_or(PostError::UnexpectedResponse("Bluesky"))
+}
+
+/// The response's JSON body if the service accepted the request, otherwise
+/// the error it gave
+async fn accepted(service: &'static str, response: Response) -> Result<Value, PostError> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if status.is_success() {
+        return Ok(body);
+    }
+    // Bluesky explains in "message", Mastodon in "error"
+    let message = body["message"]
+        .as_str()
+        .or(body["error"].as_str())
+        .unwrap_or("no details given");
+    Err(PostError::Rejected {
+        service,
+        status: status.as_u16(),
+        message: message.to_string(),
+    })
+}
+
+/// Link to a Bluesky post from its record URI,
+/// `at://<did>/app.bsky.feed.post/<key>`
+fn bluesky_post_url(handle: &str, uri: &str) -> Option<String> {
+    let key = uri
+        .strip_prefix("at://")?
+        .split('/')
+        .nth(2)
+        .filter(|key| !key.is_empty())?;
+    Some(format!("https://bsky.app/profile/{}/post/{}", handle, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{
+        get_beach_by_id, DataQuality, HourlyForecast, WaterQuality, Weather, WeatherCondition,
+        WeatherProvider,
+    };
+    use chrono::{NaiveTime, TimeZone};
+
+    fn forecast(hour: u8, temperature: f64) -> HourlyForecast {
+        HourlyForecast {
+            hour,
+            temperature,
+            feels_like: temperature,
+            condition: WeatherCondition::Clear,
+            wind: 5.0,
+            wind_direction: "W".to_string(),
+            uv: 4.0,
+            precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        }
+    }
+
+    /// A beach at `temperature` all day
+    fn conditions(
+        id: &str,
+        temperature: f64,
+        water: Option<WaterStatus>,
+        now: DateTime<Utc>,
+    ) -> BeachConditions {
+        let hourly: Vec<_> = (0..24).map(|hour| forecast(hour, temperature)).collect();
+        let beach = get_beach_by_id(id).unwrap().clone();
+        BeachConditions {
+            beach,
+            weather: Some(Weather {
+                temperature,
+                feels_like: temperature,
+                condition: WeatherCondition::Clear,
+                weather_code: None,
+                humidity: 50,
+                wind: 5.0,
+                wind_direction: Some(270.0),
+                uv: 4.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 10, 0).unwrap(),
+                fetched_at: now,
+                hourly: hourly.clone(),
+                tomorrow: hourly,
+                source: WeatherProvider::default(),
+                parse_warnings: Vec::new(),
+            }),
+            tides: None,
+            water_quality: water.map(|status| WaterQuality {
+                status,
+                ecoli_count: None,
+                // Staleness goes by the real clock
+                sample_date: Local::now().date_naive(),
+                advisory_reason: None,
+                fetched_at: now,
+                no_samples_station: None,
+                sampling: None,
+            }),
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    /// 10am in Vancouver in July
+    fn morning() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, 18, 17, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_summary_names_the_best_window_and_water_quality_alerts() {
+        let now = morning();
+        let sunset = conditions("sunset", 12.0, Some(WaterStatus::Advisory), now);
+        let kits = conditions("kitsilano", 27.0, Some(WaterStatus::Safe), now);
+        let trout = conditions("trout-lake", 27.0, Some(WaterStatus::Closed), now);
+
+        let summary = daily_summary(&[&sunset, &kits, &trout], Activity::Swimming, now);
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines[0], "Vancouver beaches, Saturday, July 18");
+        let window = best_window(&kits, Activity::Swimming, now).unwrap();
+        assert!(window.start_hour >= 10, "the window starts from now on");
+        assert_eq!(
+            lines[1],
+            format!(
+                "Best for swimming: Kitsilano Beach, {} to {} (score {})",
+                format_hour(window.start_hour),
+                format_hour(window.end_hour % 24),
+                window.peak
+            )
+        );
+        assert_eq!(lines[2], "Water quality advisory: Sunset Beach");
+        assert_eq!(lines[3], "Closed to swimming: Trout Lake Beach");
+
+        let quiet = daily_summary(&[&kits], Activity::Swimming, now);
+        assert!(quiet.ends_with("\nWater quality: no advisories"));
+    }
+
+    #[test]
+    fn test_summary_counts_alerts_rather_than_run_past_the_limit() {
+        let now = morning();
+        let beaches: Vec<_> = crate::data::all_beaches()
+            .iter()
+            .map(|beach| conditions(&beach.id, 20.0, Some(WaterStatus::Advisory), now))
+            .collect();
+        // A downloaded registry can list more beaches than the built-in one
+        let beaches: Vec<_> = beaches.iter().chain(&beaches).collect();
+        let full: usize = beaches.iter().map(|c| c.beach.name.len() + 2).sum();
+        assert!(full > POST_MAX_CHARS, "every beach's name fills a post");
+
+        let summary = daily_summary(&beaches, Activity::Swimming, now);
+        assert!(summary.chars().count() <= POST_MAX_CHARS);
+        assert!(summary.ends_with(&format!(
+            "\nWater quality advisory at {} beaches",
+            beaches.len()
+        )));
+    }
+
+    #[test]
+    fn test_summary_without_weather_says_so() {
+        let now = morning();
+        let mut kits = conditions("kitsilano", 20.0, None, now);
+        kits.weather = None;
+        let summary = daily_summary(&[&kits], Activity::Sunset, now);
+        assert_eq!(
+            summary.lines().nth(1),
+            Some("Best for sunset: no forecast to score")
+        );
+    }
+
+    #[test]
+    fn test_bluesky_post_url_from_record_uri() {
+        assert_eq!(
+            bluesky_post_url(
+                "vanbeach.bsky.social",
+                "at://did:plc:abc123/app.bsky.feed.post/3kxyz"
+            )
+            .as_deref(),
+            Some("https://bsky.app/profile/vanbeach.bsky.social/post/3kxyz")
+        );
+        assert_eq!(
+            bluesky_post_url("vanbeach.bsky.social", "https://example.com"),
+            None
+        );
+        assert_eq!(
+            bluesky_post_url(
+                "vanbeach.bsky.social",
+                "at://did:plc:abc123/app.bsky.feed.post/"
+            ),
+            None
+        );
+    }
+}
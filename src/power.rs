@@ -0,0 +1,171 @@
+//! Power-aware background refresh
+//!
+//! The dashboard refreshes itself while it's left running, which on an
+//! unplugged laptop with the TUI parked in a tmux pane is a steady drain for
+//! data nobody is looking at. On battery, or while the terminal doesn't have
+//! focus, the refresh interval is stretched `POWER_SAVING_STRETCH` times.
+//!
+//! Whether the machine is on battery is probed from `/sys/class/power_supply`
+//! on Linux and `pmset` on macOS; elsewhere it's assumed to be plugged in.
+//! Focus comes from terminals that report focus changes; ones that don't are
+//! treated as always focused.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// How many times longer background refreshes wait while saving power
+pub const POWER_SAVING_STRETCH: i64 = 4;
+
+/// How often the power source is probed
+const PROBE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Where Linux lists power supplies
+const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+
+/// When background refreshes are stretched (`power_saving` in the config)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PowerSaving {
+    /// On battery or while the terminal is unfocused
+    #[default]
+    Auto,
+    /// All the time
+    Always,
+    /// Never
+    Never,
+}
+
+/// Whether the machine is running on battery, if that can be told
+pub fn on_battery() -> Option<bool> {
+    if cfg!(target_os = "linux") {
+        battery_from_sysfs(Path::new(POWER_SUPPLY_DIR))
+    } else if cfg!(target_os = "macos") {
+        let output = std::process::Command::new("pmset")
+            .args(["-g", "batt"])
+            .output()
+            .ok()?;
+        battery_from_pmset(&String::from_utf8_lossy(&output.stdout))
+    } else {
+        None
+    }
+}
+
+/// Reads the power source from a sysfs `power_supply` directory
+///
+/// Plugged-in mains wins; otherwise a discharging battery means on battery.
+/// None where there's no battery to speak of, as on a desktop.
+fn battery_from_sysfs(dir: &Path) -> Option<bool> {
+    let read = |supply: &Path, file: &str| {
+        fs::read_to_string(supply.join(file))
+            .map(|value| value.trim().to_string())
+            .unwrap_or_default()
+    };
+    let mut battery = None;
+    for entry in fs::read_dir(dir).ok()?.flatten() {
+        let supply = entry.path();
+        match read(&supply, "type").as_str() {
+            "Mains" if read(&supply, "online") == "1" => return Some(false),
+            "Battery" => {
+                let discharging = read(&supply, "status") == "Discharging";
+                battery = Some(battery.unwrap_or(false) || discharging);
+            }
+            _ => {}
+        }
+    }
+    battery
+}
+
+/// Reads the power source from `pmset -g batt` output, e.g.
+/// "Now drawing from 'Battery Power'"
+fn battery_from_pmset(output: &str) -> Option<bool> {
+    if output.contains("'Battery Power'") {
+        Some(true)
+    } else if output.contains("'AC Power'") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Probes the power source at most once every `PROBE_INTERVAL`
+#[derive(Debug, Default)]
+pub struct PowerMonitor {
+    last_probe: Option<Instant>,
+}
+
+impl PowerMonitor {
+    /// Whether the machine is on battery, when a probe is due; None between
+    /// probes
+    pub fn poll(&mut self) -> Option<bool> {
+        let now = Instant::now();
+        if self
+            .last_probe
+            .is_some_and(|last| now.duration_since(last) < PROBE_INTERVAL)
+        {
+            return None;
+        }
+        self.last_probe = Some(now);
+        Some(on_battery().unwrap_or(false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn supply(dir: &Path, name: &str, files: &[(&str, &str)]) {
+        let path = dir.join(name);
+        fs::create_dir(&path).unwrap();
+        for (file, value) in files {
+            fs::write(path.join(file), format!("{}\n", value)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_sysfs_power_source() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(battery_from_sysfs(dir.path()), None, "desktop");
+
+        supply(
+            dir.path(),
+            "BAT0",
+            &[("type", "Battery"), ("status", "Discharging")],
+        );
+        assert_eq!(battery_from_sysfs(dir.path()), Some(true));
+
+        supply(dir.path(), "AC", &[("type", "Mains"), ("online", "1")]);
+        assert_eq!(battery_from_sysfs(dir.path()), Some(false));
+
+        assert_eq!(battery_from_sysfs(&dir.path().join("missing")), None);
+    }
+
+    #[test]
+    fn test_pmset_power_source() {
+        let battery =
+            "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=1)\t82%; discharging";
+        assert_eq!(battery_from_pmset(battery), Some(true));
+        assert_eq!(
+            battery_from_pmset("Now drawing from 'AC Power'"),
+            Some(false)
+        );
+        assert_eq!(battery_from_pmset(""), None);
+    }
+
+    #[test]
+    fn test_monitor_probes_once_per_interval() {
+        let mut monitor = PowerMonitor::default();
+        assert!(monitor.poll().is_some());
+        assert!(monitor.poll().is_none());
+    }
+
+    #[test]
+    fn test_power_saving_setting_names() {
+        let setting: PowerSaving = serde_json::from_str(r#""never""#).unwrap();
+        assert_eq!(setting, PowerSaving::Never);
+        assert_eq!(PowerSaving::default(), PowerSaving::Auto);
+    }
+}
@@ -0,0 +1,359 @@
+//! Rain-delay suggestions for pinned plans
+//!
+//! Once a minute the event loop asks `RainDelayWatch` whether rain is
+//! forecast for the hour of any upcoming pinned plan. For each plan that's
+//! rained out it looks for the best dry hour left that day at any beach,
+//! scored for the plan's activity (or the selected one), and suggests it
+//! instead. A suggestion is announced once as an alert, with a desktop
+//! notification under `--notify`, and the dashboard lists the rained-out
+//! plans until the forecast clears or they pass.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, Timelike, Utc};
+
+use crate::activities::Activity;
+use crate::app::App;
+use crate::data::{all_beaches, BeachConditions, BeachId, HourlyForecast, WeatherCondition};
+use crate::plans::PinnedPlan;
+use crate::scoring::ActivityScorer;
+use crate::time_format::format_hour;
+
+/// Chance of precipitation, in percent, at which an hour counts as rained out
+pub const RAIN_CHANCE_THRESHOLD: u8 = 60;
+
+/// A dry hour to go instead
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alternative {
+    pub beach_id: BeachId,
+    pub beach_name: String,
+    pub hour: u8,
+    pub score: u8,
+}
+
+/// A pinned plan with rain forecast for its hour
+#[derive(Debug, Clone, PartialEq)]
+pub struct RainDelay {
+    pub plan: PinnedPlan,
+    pub beach_name: String,
+    /// Best dry hour the same day, if there's one and an activity to score
+    pub alternative: Option<Alternative>,
+}
+
+impl RainDelay {
+    /// Text for the alert and dashboard, e.g. "Rain forecast for your
+    /// 2:00 PM plan at Kitsilano Beach: try Jericho Beach at 4:00 PM
+    /// instead (score 78)"
+    pub fn message(&self) -> String {
+        let rained_out = format!(
+            "Rain forecast for your {} plan at {}",
+            format_hour(self.plan.hour),
+            self.beach_name
+        );
+        match &self.alternative {
+            Some(alternative) if alternative.beach_id == self.plan.beach_id => format!(
+                "{}: try {} instead (score {})",
+                rained_out,
+                format_hour(alternative.hour),
+                alternative.score
+            ),
+            Some(alternative) => format!(
+                "{}: try {} at {} instead (score {})",
+                rained_out,
+                alternative.beach_name,
+                format_hour(alternative.hour),
+                alternative.score
+            ),
+            None => format!("{}, and no dry hour to move it to", rained_out),
+        }
+    }
+
+    /// What an announcement is remembered by: the plan and where it moves to,
+    /// so a score shifting between refreshes isn't announced again
+    fn key(&self) -> (PinnedPlan, Option<(BeachId, u8)>) {
+        (
+            self.plan.clone(),
+            self.alternative
+                .as_ref()
+                .map(|alternative| (alternative.beach_id.clone(), alternative.hour)),
+        )
+    }
+}
+
+/// Checks the pinned plans once a minute, remembering which rain delays
+/// were already announced
+#[derive(Debug, Default)]
+pub struct RainDelayWatch {
+    /// Minute of the last check
+    last_check: Option<NaiveDateTime>,
+    /// Rain delays already announced, dropped once no longer forecast
+    announced: Vec<(PinnedPlan, Option<(BeachId, u8)>)>,
+}
+
+impl RainDelayWatch {
+    /// The rain delays for upcoming plans, if a minute has passed since the
+    /// last check, and the ones among them not announced before
+    pub fn check(
+        &mut self,
+        app: &App,
+        now: DateTime<Local>,
+    ) -> Option<(Vec<RainDelay>, Vec<RainDelay>)> {
+        let minute = now.date_naive().and_hms_opt(now.hour(), now.minute(), 0);
+        if minute.is_none() || minute == self.last_check {
+            return None;
+        }
+        self.last_check = minute;
+
+        let delays = rain_delays(app, now);
+        let new = delays
+            .iter()
+            .filter(|delay| !self.announced.contains(&delay.key()))
+            .cloned()
+            .collect();
+        self.announced = delays.iter().map(RainDelay::key).collect();
+        Some((delays, new))
+    }
+}
+
+/// Upcoming plans with rain forecast for their hour, with what to do instead
+pub fn rain_delays(app: &App, now: DateTime<Local>) -> Vec<RainDelay> {
+    app.pinned_plans
+        .iter()
+        .filter(|plan| plan.time_until(now).is_some())
+        .filter_map(|plan| {
+            let conditions = app.get_conditions(&plan.beach_id)?;
+            let forecast = forecast_at(conditions, plan.date, plan.hour, now)?;
+            if !is_rainy(forecast) {
+                return None;
+            }
+            let alternative = plan
+                .activity
+                .or(app.current_activity)
+                .and_then(|activity| best_dry_hour(app, plan, activity, now));
+            Some(RainDelay {
+                plan: plan.clone(),
+                beach_name: conditions.beach.name.clone(),
+                alternative,
+            })
+        })
+        .collect()
+}
+
+/// Whether an hour's forecast is wet enough to spoil a beach trip
+fn is_rainy(forecast: &HourlyForecast) -> bool {
+    matches!(
+        forecast.condition,
+        WeatherCondition::Rain | WeatherCondition::Showers | WeatherCondition::Thunderstorm
+    ) || forecast.precipitation_chance >= RAIN_CHANCE_THRESHOLD
+}
+
+/// A beach's hourly forecast for an hour today or tomorrow
+fn forecast_at(
+    conditions: &BeachConditions,
+    date: NaiveDate,
+    hour: u8,
+    now: DateTime<Local>,
+) -> Option<&HourlyForecast> {
+    let weather = conditions.weather.as_ref()?;
+    let today = beach_today(conditions, now);
+    let hours = if date == today {
+        &weather.hourly
+    } else if date == today + Duration::days(1) {
+        &weather.tomorrow
+    } else {
+        return None;
+    };
+    hours.iter().find(|forecast| forecast.hour == hour)
+}
+
+/// The best-scoring dry hour still to come on the plan's day, across every
+/// beach; ties go to the plan's beach, then the hour nearest the plan's
+fn best_dry_hour(
+    app: &App,
+    plan: &PinnedPlan,
+    activity: Activity,
+    now: DateTime<Local>,
+) -> Option<Alternative> {
+    all_beaches()
+        .iter()
+        .filter(|beach| activity.offered_at(&beach.id))
+        .filter_map(|beach| app.get_conditions(&beach.id))
+        .flat_map(|conditions| {
+            let today = beach_today(conditions, now);
+            let scores: Vec<(u8, u8)> = if plan.date == today {
+                // Hours that haven't started yet
+                ActivityScorer::hourly_from(conditions, activity, now.hour() as u8 + 1)
+                    .map(|(hour, slot)| (hour, slot.score))
+                    .collect()
+            } else if plan.date == today + Duration::days(1) {
+                ActivityScorer::tomorrow(conditions, activity)
+                    .map(|(hour, slot)| (hour, slot.score))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            scores
+                .into_iter()
+                .filter(|&(hour, score)| {
+                    score > 0
+                        && forecast_at(conditions, plan.date, hour, now)
+                            .is_some_and(|forecast| !is_rainy(forecast))
+                })
+                .map(|(hour, score)| Alternative {
+                    beach_id: conditions.beach.id.clone(),
+                    beach_name: conditions.beach.name.clone(),
+                    hour,
+                    score,
+                })
+        })
+        .max_by_key(|alternative| {
+            (
+                alternative.score,
+                alternative.beach_id == plan.beach_id,
+                std::cmp::Reverse(alternative.hour.abs_diff(plan.hour)),
+            )
+        })
+}
+
+/// The beach's local date at `now`
+fn beach_today(conditions: &BeachConditions, now: DateTime<Local>) -> NaiveDate {
+    conditions
+        .beach
+        .timezone
+        .local(now.with_timezone(&Utc))
+        .date()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{get_beach_by_id, DataQuality, Weather, WeatherProvider};
+    use chrono::NaiveTime;
+
+    fn forecast(hour: u8, condition: WeatherCondition) -> HourlyForecast {
+        HourlyForecast {
+            hour,
+            temperature: 26.0,
+            feels_like: 26.0,
+            condition,
+            wind: 5.0,
+            wind_direction: "W".to_string(),
+            uv: 4.0,
+            precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        }
+    }
+
+    /// A beach whose forecast for tomorrow is rain in `wet_hours`, clear
+    /// otherwise
+    fn add_beach(app: &mut App, id: &str, wet_hours: &[u8]) {
+        let tomorrow = (0..24)
+            .map(|hour| {
+                let condition = if wet_hours.contains(&hour) {
+                    WeatherCondition::Rain
+                } else {
+                    WeatherCondition::Clear
+                };
+                forecast(hour, condition)
+            })
+            .collect();
+        let beach = get_beach_by_id(id).unwrap().clone();
+        app.beach_conditions.insert(
+            beach.id.clone(),
+            BeachConditions {
+                beach,
+                weather: Some(Weather {
+                    temperature: 26.0,
+                    feels_like: 26.0,
+                    condition: WeatherCondition::Clear,
+                    weather_code: None,
+                    humidity: 50,
+                    wind: 5.0,
+                    wind_direction: Some(270.0),
+                    uv: 4.0,
+                    sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                    sunset: NaiveTime::from_hms_opt(21, 10, 0).unwrap(),
+                    fetched_at: Utc::now(),
+                    hourly: Vec::new(),
+                    tomorrow,
+                    source: WeatherProvider::default(),
+                    parse_warnings: Vec::new(),
+                }),
+                tides: None,
+                water_quality: None,
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+    }
+
+    /// An app with a sunbathing plan at Kitsilano at 2 PM tomorrow
+    fn app_with_plan() -> (App, DateTime<Local>) {
+        let now = Local::now();
+        let mut app = App::new();
+        let tomorrow = get_beach_by_id("kitsilano")
+            .unwrap()
+            .timezone
+            .local(now.with_timezone(&Utc))
+            .date()
+            + Duration::days(1);
+        app.pinned_plans = vec![PinnedPlan::new(
+            BeachId::new("kitsilano"),
+            tomorrow,
+            14,
+            Some(Activity::Sunbathing),
+        )];
+        (app, now)
+    }
+
+    #[test]
+    fn test_rained_out_plan_moves_to_the_best_dry_hour() {
+        let (mut app, now) = app_with_plan();
+        add_beach(&mut app, "kitsilano", &[13, 14, 15]);
+        add_beach(&mut app, "jericho", &(0..24).collect::<Vec<_>>());
+
+        let delays = rain_delays(&app, now);
+        assert_eq!(delays.len(), 1);
+        let alternative = delays[0].alternative.as_ref().unwrap();
+        assert_eq!(alternative.beach_id, "kitsilano");
+        assert!(![13, 14, 15].contains(&alternative.hour));
+        assert!(delays[0]
+            .message()
+            .starts_with("Rain forecast for your 2:00 PM plan at Kitsilano Beach: try "));
+
+        // A dry forecast at the plan's hour leaves it be
+        add_beach(&mut app, "kitsilano", &[]);
+        assert!(rain_delays(&app, now).is_empty());
+    }
+
+    #[test]
+    fn test_each_rain_delay_is_announced_once() {
+        let (mut app, now) = app_with_plan();
+        add_beach(&mut app, "kitsilano", &[14]);
+        let mut watch = RainDelayWatch::default();
+
+        let (delays, new) = watch.check(&app, now).unwrap();
+        assert_eq!((delays.len(), new.len()), (1, 1));
+        // Checked once a minute
+        assert!(watch.check(&app, now).is_none());
+        let (delays, new) = watch.check(&app, now + Duration::minutes(1)).unwrap();
+        assert_eq!((delays.len(), new.len()), (1, 0));
+    }
+
+    #[test]
+    fn test_message_without_an_alternative() {
+        let (app, _) = app_with_plan();
+        let delay = RainDelay {
+            plan: app.pinned_plans[0].clone(),
+            beach_name: "Kitsilano Beach".to_string(),
+            alternative: None,
+        };
+        assert_eq!(
+            delay.message(),
+            "Rain forecast for your 2:00 PM plan at Kitsilano Beach, and no dry hour to move it to"
+        );
+    }
+}
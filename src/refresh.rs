@@ -10,16 +10,16 @@
 use std::time::Duration;
 use tokio::sync::mpsc;
 
-use crate::data::{TideInfo, WaterQuality, Weather};
+use crate::data::{BeachId, TideInfo, WaterQuality, Weather};
 
 /// Messages sent from background refresh to main app
 #[derive(Debug, Clone)]
 pub enum RefreshMessage {
     /// Weather data updated for a beach
-    WeatherUpdated { beach_id: String, weather: Weather },
+    WeatherUpdated { beach_id: BeachId, weather: Weather },
     /// Water quality data updated for a beach
     WaterQualityUpdated {
-        beach_id: String,
+        beach_id: BeachId,
         water_quality: WaterQuality,
     },
     /// Tide data updated (shared across all beaches)
@@ -0,0 +1,202 @@
+//! Reminders before the best window for the selected activity
+//!
+//! While the app is left open, the event loop asks `WindowReminders` once a
+//! minute whether the top-ranked window for the selected activity, across
+//! every beach, starts within `REMINDER_LEAD_MINUTES`. Each window is
+//! announced once, as an in-app toast and, with `--notify`, a desktop
+//! notification.
+
+use std::process::Command;
+
+use chrono::{DateTime, Duration, Local, NaiveDateTime, Timelike, Utc};
+
+use crate::activities::Activity;
+use crate::app::App;
+use crate::data::{all_beaches, BeachId};
+use crate::scoring::best_hour_today;
+
+/// How long before a window starts the reminder fires
+pub const REMINDER_LEAD_MINUTES: i64 = 30;
+
+/// The top-ranked window for an activity
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reminder {
+    /// Beach id
+    pub beach_id: BeachId,
+    /// Beach display name
+    pub beach_name: String,
+    /// Activity the window was ranked for
+    pub activity: Activity,
+    /// When the window starts
+    pub start: DateTime<Local>,
+    /// Score of the window's best hour
+    pub score: u8,
+}
+
+impl Reminder {
+    /// Text for the toast and desktop notification, e.g.
+    /// "Sunset at English Bay in 30 min (score 86)"
+    pub fn message(&self, now: DateTime<Local>) -> String {
+        let seconds = (self.start - now).num_seconds().max(0);
+        format!(
+            "{} at {} in {} min (score {})",
+            self.activity.label(),
+            self.beach_name,
+            (seconds + 59) / 60,
+            self.score
+        )
+    }
+}
+
+/// Schedules reminders, remembering which windows were already announced
+#[derive(Debug, Default)]
+pub struct WindowReminders {
+    /// Minute of the last check, so the windows are ranked once a minute
+    last_check: Option<NaiveDateTime>,
+    /// Windows already announced, dropped once they've started
+    announced: Vec<(BeachId, Activity, DateTime<Local>)>,
+}
+
+impl WindowReminders {
+    /// Returns a reminder if the top-ranked window for the selected activity
+    /// starts within `REMINDER_LEAD_MINUTES` and hasn't been announced yet
+    pub fn due(&mut self, app: &App, now: DateTime<Local>) -> Option<Reminder> {
+        let minute = now.date_naive().and_hms_opt(now.hour(), now.minute(), 0);
+        if minute.is_none() || minute == self.last_check {
+            return None;
+        }
+        self.last_check = minute;
+
+        let window = top_window(app, app.current_activity?, now);
+        self.announce(window, now)
+    }
+
+    /// Decides whether `window` should be announced at `now`
+    fn announce(&mut self, window: Option<Reminder>, now: DateTime<Local>) -> Option<Reminder> {
+        self.announced.retain(|(_, _, start)| *start > now);
+
+        let window = window?;
+        let until = window.start - now;
+        if until <= Duration::zero() || until > Duration::minutes(REMINDER_LEAD_MINUTES) {
+            return None;
+        }
+        let key = (window.beach_id.clone(), window.activity, window.start);
+        if self.announced.contains(&key) {
+            return None;
+        }
+        self.announced.push(key);
+        Some(window)
+    }
+}
+
+/// The best-scoring hour left today for an activity across every beach
+///
+/// Ties go to the earlier beach in the registry.
+fn top_window(app: &App, activity: Activity, now: DateTime<Local>) -> Option<Reminder> {
+    all_beaches()
+        .iter()
+        .filter_map(|beach| {
+            let conditions = app.get_conditions(&beach.id)?;
+            let (hour, score) = best_hour_today(conditions, activity, now)?;
+            let beach_today = beach.timezone.local(now.with_timezone(&Utc)).date();
+            let start = beach
+                .timezone
+                .to_utc(beach_today.and_hms_opt(hour as u32, 0, 0)?)
+                .with_timezone(&Local);
+            Some(Reminder {
+                beach_id: beach.id.clone(),
+                beach_name: beach.name.to_string(),
+                activity,
+                start,
+                score,
+            })
+        })
+        .rev()
+        .max_by_key(|reminder| reminder.score)
+}
+
+/// Shows a desktop notification with `notify-send` (Linux) or `osascript`
+/// (macOS), without waiting for it; failures are ignored
+pub fn send_desktop_notification(message: &str) {
+    let mut command = if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification \"{}\" with title \"vanbeach\"",
+            message.replace('\\', "\\\\").replace('"', "\\\"")
+        );
+        let mut command = Command::new("osascript");
+        command.arg("-e").arg(script);
+        command
+    } else {
+        let mut command = Command::new("notify-send");
+        command.arg("vanbeach").arg(message);
+        command
+    };
+    // Wait on a thread so the child is reaped without blocking the UI
+    std::thread::spawn(move || {
+        let _ = command.status();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(2026, 7, 15, hour, minute, 0)
+            .earliest()
+            .unwrap()
+    }
+
+    fn window(start: DateTime<Local>) -> Reminder {
+        Reminder {
+            beach_id: BeachId::new("english-bay"),
+            beach_name: "English Bay".to_string(),
+            activity: Activity::Sunset,
+            start,
+            score: 86,
+        }
+    }
+
+    #[test]
+    fn test_window_is_announced_once_within_the_lead_time() {
+        let mut reminders = WindowReminders::default();
+        let start = at(20, 0);
+
+        assert_eq!(reminders.announce(Some(window(start)), at(19, 15)), None);
+        assert_eq!(
+            reminders.announce(Some(window(start)), at(19, 30)),
+            Some(window(start))
+        );
+        assert_eq!(reminders.announce(Some(window(start)), at(19, 45)), None);
+        assert_eq!(reminders.announce(Some(window(start)), at(20, 0)), None);
+
+        // A different window is announced on its own
+        let later = at(20, 15);
+        assert_eq!(
+            reminders.announce(Some(window(later)), at(19, 50)),
+            Some(window(later))
+        );
+    }
+
+    #[test]
+    fn test_message_rounds_minutes_up() {
+        let reminder = window(at(20, 0));
+        assert_eq!(
+            reminder.message(at(19, 30)),
+            "Sunset at English Bay in 30 min (score 86)"
+        );
+        assert_eq!(
+            reminder.message(at(19, 59) + Duration::seconds(30)),
+            "Sunset at English Bay in 1 min (score 86)"
+        );
+    }
+
+    #[test]
+    fn test_no_reminder_without_an_activity() {
+        let mut reminders = WindowReminders::default();
+        let app = App::new();
+        assert_eq!(reminders.due(&app, at(19, 30)), None);
+    }
+}
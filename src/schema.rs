@@ -0,0 +1,84 @@
+//! JSON Schema for the serialized output types
+//!
+//! `vanbeach schema` prints one JSON Schema document whose `definitions`
+//! cover every type the app writes out: beach conditions (as cached and
+//! recorded), session recordings and pinned plans. Integrators can validate
+//! against `#/definitions/<Type>`; the roots are listed under `roots`.
+
+use schemars::gen::SchemaSettings;
+use serde_json::{json, Value};
+
+use crate::data::BeachConditions;
+use crate::plans::PinnedPlan;
+use crate::session::Session;
+
+/// Types written out by the app, in the order they're listed
+pub const ROOT_TYPES: [&str; 3] = ["BeachConditions", "Session", "PinnedPlan"];
+
+/// Builds the schema document for every serialized output type
+pub fn output_schema() -> Value {
+    let mut generator = SchemaSettings::draft07().into_generator();
+    generator.subschema_for::<BeachConditions>();
+    generator.subschema_for::<Session>();
+    generator.subschema_for::<PinnedPlan>();
+    let meta_schema = generator.settings().meta_schema.clone();
+
+    json!({
+        "$schema": meta_schema,
+        "title": "vanbeach output",
+        "description": format!("Serialized output types of vanbeach {}", env!("CARGO_PKG_VERSION")),
+        "roots": ROOT_TYPES
+            .iter()
+            .map(|name| format!("#/definitions/{}", name))
+            .collect::<Vec<_>>(),
+        "definitions": generator.take_definitions(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::all_beaches;
+
+    #[test]
+    fn test_every_root_type_has_a_definition() {
+        let schema = output_schema();
+        for name in ROOT_TYPES {
+            assert!(
+                schema["definitions"][name].is_object(),
+                "missing definition for {}",
+                name
+            );
+        }
+        assert!(schema["definitions"]["Weather"].is_object());
+        assert!(schema["definitions"]["TideInfo"].is_object());
+    }
+
+    #[test]
+    fn test_serialized_conditions_only_use_schema_properties() {
+        let conditions = BeachConditions {
+            beach: all_beaches()[0].clone(),
+            weather: None,
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        };
+        let value = serde_json::to_value(&conditions).unwrap();
+        let schema = output_schema();
+        let definitions = &schema["definitions"];
+
+        let properties = definitions["BeachConditions"]["properties"]
+            .as_object()
+            .unwrap();
+        for key in value.as_object().unwrap().keys() {
+            assert!(properties.contains_key(key), "{} not in schema", key);
+        }
+        let beach_properties = definitions["Beach"]["properties"].as_object().unwrap();
+        for key in value["beach"].as_object().unwrap().keys() {
+            assert!(beach_properties.contains_key(key), "{} not in schema", key);
+        }
+    }
+}
@@ -0,0 +1,1165 @@
+//! Activity scoring
+//!
+//! Scores a beach's current conditions for an activity right now. Used by the
+//! dashboard cards and the `--score-dump` report, which prints the effective
+//! scoring weights next to the scores they produce.
+//!
+//! `ActivityScorer` scores hour by hour across the beach's day instead, for
+//! the best windows, score sparklines, the beach list's best time and the
+//! PlanTrip grid.
+
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+use chrono::{DateTime, Local, NaiveDate, Timelike, Utc};
+
+use crate::activities::{
+    air_quality_score, get_profile, preset_profile, rain_score, sunset_sky_score,
+    sunset_time_scorer_dynamic, surf_quality, Activity, ActivityProfile, TimeSlotScore,
+};
+use crate::config::{good_score, hour_range, is_good_score, GOOD_SCORE_MARKER};
+use crate::crowd;
+use crate::data::{
+    all_beaches, dog_rule, wave_facing, Beach, BeachConditions, Chop, HourlyForecast, WaterStatus,
+};
+use crate::heat;
+use crate::sun::{sun_exposure, sun_exposure_for_hour};
+use crate::time_format::format_time;
+use crate::ui::text;
+
+/// Extra Sunset points when a tide extreme lines up with today's sunset,
+/// scaled by how close the scored hour is to sunset
+const SUNSET_TIDE_BONUS: f32 = 10.0;
+
+/// Points Peace and Sunset lose in an hour fog is likely: it hides the view
+/// and leaves the sand damp and chilly
+const FOG_PENALTY: u8 = 8;
+
+/// Points Swimming loses when the wind is raising whitecaps off the beach
+const WHITECAP_PENALTY: u8 = 25;
+
+/// Share of a Surf score left when the water is flat: fine weather alone
+/// isn't worth paddling out for
+const FLAT_SURF_SHARE: f32 = 0.2;
+
+/// Scores an activity at a beach for the hour containing `now`
+///
+/// Time-of-day factors use the beach's clock, not the machine's. Returns
+/// `None` if there is no weather data to score against.
+pub fn score_now(
+    conditions: &BeachConditions,
+    activity: Activity,
+    now: DateTime<Local>,
+) -> Option<u8> {
+    let weather = conditions.weather.as_ref()?;
+    let beach_now = conditions.beach.timezone.local(now.with_timezone(&Utc));
+    let hour = beach_now.hour() as u8;
+    let profile = heat::profile_for(activity, heat::warning_high(conditions).is_some());
+
+    let water_status = conditions
+        .water_quality
+        .as_ref()
+        .map(|wq| wq.effective_status())
+        .unwrap_or(WaterStatus::Unknown);
+
+    let (tide_height, max_tide) = conditions
+        .tides
+        .as_ref()
+        .map(|t| (t.current_height as f32, 4.8f32))
+        .unwrap_or((2.4, 4.8));
+
+    let crowd = crowd::predict_at(
+        &conditions.beach.id,
+        beach_now.date(),
+        hour,
+        Some((weather.condition, weather.temperature)),
+    )
+    .level;
+
+    let mut result = profile.score_time_slot(
+        hour,
+        &conditions.beach.id,
+        weather.temperature as f32,
+        conditions
+            .beach
+            .effective_wind(weather.wind, weather.wind_direction) as f32,
+        weather.uv as f32,
+        water_status,
+        tide_height,
+        max_tide,
+        crowd,
+    );
+
+    if profile.shade_weight > 0.0 {
+        let exposure = sun_exposure(&conditions.beach, now.with_timezone(&Utc));
+        profile.apply_sun_exposure(&mut result, exposure);
+    }
+
+    if activity == Activity::Sunset {
+        let time_score = sunset_time_scorer_dynamic(hour, weather.sunset.hour() as u8);
+        let mut adjusted = result.score as f32 * (0.3 + 0.7 * time_score);
+        let tide_at_sunset_today = conditions
+            .sunset_tide()
+            .is_some_and(|tide| tide.local_time.date() == beach_now.date());
+        if tide_at_sunset_today {
+            adjusted += SUNSET_TIDE_BONUS * time_score;
+        }
+        result.score = adjusted.clamp(0.0, 100.0) as u8;
+        let sunset_hour = weather.sunset.hour() as u8;
+        apply_sunset_sky(conditions, &mut result, beach_now.date(), sunset_hour);
+    }
+
+    if activity == Activity::Surf {
+        let wind = (weather.wind as f32, weather.wind_direction);
+        apply_surf(conditions, &mut result, beach_now.date(), hour, wind);
+    }
+
+    if activity == Activity::RunWalk {
+        apply_run_walk(conditions, &mut result, beach_now.date(), hour);
+    }
+
+    if fog_dampens(conditions, activity, beach_now.date(), hour) {
+        result.score = result.score.saturating_sub(FOG_PENALTY);
+    }
+
+    if whitecaps_spoil(conditions, activity, beach_now.date()) {
+        result.score = result.score.saturating_sub(WHITECAP_PENALTY);
+    }
+
+    // A closed beach is no good for anything, nor a dog walk where dogs
+    // are banned, nor surfing where swell never reaches, nor a run along
+    // a closed seawall
+    if conditions.closure.is_some()
+        || bans_dogs(&conditions.beach, activity, beach_now.date())
+        || !activity.offered_at(&conditions.beach.id)
+        || closes_seawall(conditions, activity)
+    {
+        result.score = 0;
+    }
+
+    Some(result.score)
+}
+
+/// Finds the best-scoring hour from `now` until the end of the beach's day
+///
+/// Returns `(hour, score)` with the hour on the beach's clock, or `None` if
+/// there is no weather data.
+pub fn best_hour_today(
+    conditions: &BeachConditions,
+    activity: Activity,
+    now: DateTime<Local>,
+) -> Option<(u8, u8)> {
+    let timezone = &conditions.beach.timezone;
+    let beach_now = timezone.local(now.with_timezone(&Utc));
+    (beach_now.hour()..24)
+        .filter_map(|hour| {
+            let at = timezone
+                .to_utc(beach_now.date().and_hms_opt(hour, 0, 0)?)
+                .with_timezone(&Local);
+            score_now(conditions, activity, at).map(|score| (hour as u8, score))
+        })
+        // Earliest hour wins ties
+        .rev()
+        .max_by_key(|(_, score)| *score)
+}
+
+/// Weather an hour is scored against: (temperature, wind felt on the beach, UV)
+type HourWeather = (f32, f32, f32);
+
+/// What an hour is scored from, as measured rather than as factor scores
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreInputs {
+    /// Temperature in Celsius
+    pub temperature: f32,
+    /// Wind felt on the beach in km/h
+    pub wind: f32,
+    /// UV index
+    pub uv: f32,
+    /// Tide height in metres
+    pub tide_height: f32,
+    /// Estimated crowd (0.0 = empty, 1.0 = packed)
+    pub crowd_level: f32,
+}
+
+/// Scores an activity at a beach hour by hour
+///
+/// Every hour is scored against the same water quality and tide; the weather
+/// is the current conditions for today's hours, or the hourly forecast for
+/// tomorrow's. Crowds are a simple estimate by hour of day. A day with a heat
+/// warning is scored for shade and low UV.
+pub struct ActivityScorer<'a> {
+    conditions: &'a BeachConditions,
+    activity: Activity,
+    profile: ActivityProfile,
+    /// The profile for a day with a heat warning
+    heat_profile: ActivityProfile,
+    water_status: WaterStatus,
+    /// (current height, height of the next high) in metres
+    tide: (f32, f32),
+    sunset_hour: u8,
+}
+
+impl<'a> ActivityScorer<'a> {
+    /// Creates a scorer for an activity at a beach
+    pub fn new(conditions: &'a BeachConditions, activity: Activity) -> Self {
+        let water_status = conditions
+            .water_quality
+            .as_ref()
+            .map(|wq| wq.effective_status())
+            .unwrap_or(WaterStatus::Unknown);
+        let tide = match &conditions.tides {
+            Some(t) => {
+                let max_h = t.next_high.as_ref().map(|h| h.height).unwrap_or(4.8);
+                (t.current_height as f32, max_h as f32)
+            }
+            None => (2.4, 4.8), // Default mid-tide
+        };
+        let sunset_hour = conditions
+            .weather
+            .as_ref()
+            .map(|w| w.sunset.hour() as u8)
+            .unwrap_or(20); // Default to 8 PM if no data
+
+        Self {
+            conditions,
+            activity,
+            profile: get_profile(activity),
+            heat_profile: heat::profile_for(activity, true),
+            water_status,
+            tide,
+            sunset_hour,
+        }
+    }
+
+    /// Lazily scores the rest of today's hours from the beach's current hour
+    pub fn hourly(
+        conditions: &'a BeachConditions,
+        activity: Activity,
+    ) -> impl Iterator<Item = (u8, TimeSlotScore)> + 'a {
+        let current_hour = conditions.beach.timezone.now().hour() as u8;
+        Self::hourly_from(conditions, activity, current_hour)
+    }
+
+    /// Lazily scores today's hours from `current_hour` to the end of the
+    /// configured range (sunset for the Sunset activity)
+    ///
+    /// Yields nothing without weather data.
+    pub fn hourly_from(
+        conditions: &'a BeachConditions,
+        activity: Activity,
+        current_hour: u8,
+    ) -> impl Iterator<Item = (u8, TimeSlotScore)> + 'a {
+        let scorer = Self::new(conditions, activity);
+        let today = conditions.beach.timezone.now().date();
+        let weather = scorer.current_weather();
+        scorer
+            .hours(current_hour)
+            .filter_map(move |hour| Some((hour, scorer.score(hour, weather?, today))))
+    }
+
+    /// Lazily scores tomorrow's hours from tomorrow's hourly forecast
+    ///
+    /// The forecast only gives a compass point for the wind, so it's scored
+    /// at full strength rather than adjusted for the beach's shelter.
+    pub fn tomorrow(
+        conditions: &'a BeachConditions,
+        activity: Activity,
+    ) -> impl Iterator<Item = (u8, TimeSlotScore)> + 'a {
+        let scorer = Self::new(conditions, activity);
+        let tomorrow = conditions.beach.timezone.now().date().succ_opt();
+        let hours = scorer.hours(0);
+        let forecasts = conditions
+            .weather
+            .as_ref()
+            .map(|w| w.tomorrow.as_slice())
+            .unwrap_or_default();
+        forecasts
+            .iter()
+            .filter(move |forecast| hours.contains(&forecast.hour))
+            .filter_map(move |forecast| {
+                let weather = (
+                    forecast.temperature as f32,
+                    forecast.wind as f32,
+                    forecast.uv as f32,
+                );
+                Some((
+                    forecast.hour,
+                    scorer.score(forecast.hour, weather, tomorrow?),
+                ))
+            })
+    }
+
+    /// Scores one hour today against the current weather, whether or not
+    /// it's in the scored range
+    pub fn score_hour(&self, hour: u8) -> Option<TimeSlotScore> {
+        let today = self.conditions.beach.timezone.now().date();
+        Some(self.score(hour, self.current_weather()?, today))
+    }
+
+    /// The weather, tide and crowd that `score_hour` scores an hour from
+    pub fn inputs_at(&self, hour: u8) -> Option<ScoreInputs> {
+        let (temperature, wind, uv) = self.current_weather()?;
+        let today = self.conditions.beach.timezone.now().date();
+        Some(ScoreInputs {
+            temperature,
+            wind,
+            uv,
+            tide_height: self.tide.0,
+            crowd_level: estimate_crowd_level(hour, &self.conditions.beach.id, today),
+        })
+    }
+
+    /// Current weather, with wind adjusted for the beach's shelter
+    fn current_weather(&self) -> Option<HourWeather> {
+        let w = self.conditions.weather.as_ref()?;
+        Some((
+            w.temperature as f32,
+            self.conditions
+                .beach
+                .effective_wind(w.wind, w.wind_direction) as f32,
+            w.uv as f32,
+        ))
+    }
+
+    /// Hours worth scoring from `current_hour` on: the configured hour range,
+    /// ending at sunset for the Sunset activity
+    fn hours(&self, current_hour: u8) -> RangeInclusive<u8> {
+        // Viewing a sunset after sunset is nonsensical
+        let (first_hour, last_hour) = hour_range();
+        let end_hour = if self.activity == Activity::Sunset {
+            self.sunset_hour.min(last_hour)
+        } else {
+            last_hour
+        };
+        current_hour.max(first_hour)..=end_hour
+    }
+
+    /// Scores one hour on `date` against the given weather
+    fn score(&self, hour: u8, (temp, wind, uv): HourWeather, date: NaiveDate) -> TimeSlotScore {
+        let (tide_height, max_tide) = self.tide;
+        let profile = if heat::warning_on(self.conditions, date) {
+            &self.heat_profile
+        } else {
+            &self.profile
+        };
+        let mut score = profile.score_time_slot(
+            hour,
+            &self.conditions.beach.id,
+            temp,
+            wind,
+            uv,
+            self.water_status,
+            tide_height,
+            max_tide,
+            estimate_crowd_level(hour, &self.conditions.beach.id, date),
+        );
+
+        // Account for cliffs/trees shading the sand (only weighted for
+        // sunbathing, and for everything in a heat warning)
+        if profile.shade_weight > 0.0 {
+            let exposure = sun_exposure_for_hour(&self.conditions.beach, date, hour);
+            profile.apply_sun_exposure(&mut score, exposure);
+        }
+
+        // The profile's time-of-day scorer only nudges the score; sunset
+        // timing needs a much stronger pull towards the actual sunset
+        if self.activity == Activity::Sunset {
+            let time_score = sunset_time_scorer_dynamic(hour, self.sunset_hour);
+            let adjusted = score.score as f32 * (0.3 + 0.7 * time_score);
+            score.score = adjusted.clamp(0.0, 100.0) as u8;
+            apply_sunset_sky(self.conditions, &mut score, date, self.sunset_hour);
+        }
+
+        // Today's wind direction is the current one; tomorrow's forecast
+        // only gives a compass point, so it counts as cross-shore
+        if self.activity == Activity::Surf {
+            let today = self.conditions.beach.timezone.now().date();
+            let wind_from = self
+                .conditions
+                .weather
+                .as_ref()
+                .filter(|_| date == today)
+                .and_then(|w| w.wind_direction);
+            apply_surf(self.conditions, &mut score, date, hour, (wind, wind_from));
+        }
+
+        if self.activity == Activity::RunWalk {
+            apply_run_walk(self.conditions, &mut score, date, hour);
+        }
+
+        if fog_dampens(self.conditions, self.activity, date, hour) {
+            score.score = score.score.saturating_sub(FOG_PENALTY);
+        }
+
+        if whitecaps_spoil(self.conditions, self.activity, date) {
+            score.score = score.score.saturating_sub(WHITECAP_PENALTY);
+        }
+
+        // A closed beach is no good for anything, nor a dog walk where dogs
+        // are banned, nor surfing where swell never reaches, nor a run
+        // along a closed seawall
+        if self.conditions.closure.is_some()
+            || bans_dogs(&self.conditions.beach, self.activity, date)
+            || !self.activity.offered_at(&self.conditions.beach.id)
+            || closes_seawall(self.conditions, self.activity)
+        {
+            score.score = 0;
+        }
+
+        score
+    }
+}
+
+/// Whether fog is likely to take the edge off the activity at `hour` on
+/// `date`, from that day's hourly forecast
+///
+/// Only Peace and Sunset lose out; fog over English Bay on a still morning
+/// can hang around until midday.
+fn fog_dampens(
+    conditions: &BeachConditions,
+    activity: Activity,
+    date: NaiveDate,
+    hour: u8,
+) -> bool {
+    matches!(activity, Activity::Peace | Activity::Sunset)
+        && hourly_forecast(conditions, date, hour).is_some_and(HourlyForecast::fog_likely)
+}
+
+/// The hourly forecast for `hour` on `date`, if the fetched forecast
+/// reaches it
+fn hourly_forecast(
+    conditions: &BeachConditions,
+    date: NaiveDate,
+    hour: u8,
+) -> Option<&HourlyForecast> {
+    let weather = conditions.weather.as_ref()?;
+    // The hourly forecast covers the day it was fetched on
+    let today = conditions.beach.timezone.local(weather.fetched_at).date();
+    let forecasts = if date == today {
+        &weather.hourly
+    } else if today.succ_opt() == Some(date) {
+        &weather.tomorrow
+    } else {
+        return None;
+    };
+    forecasts.iter().find(|forecast| forecast.hour == hour)
+}
+
+/// Scales a Run/Walk score by how dry the hour is likely to stay and how
+/// clean the air is at `hour` on `date`
+///
+/// Each is left at 1.0 where its forecast doesn't reach.
+fn apply_run_walk(
+    conditions: &BeachConditions,
+    score: &mut TimeSlotScore,
+    date: NaiveDate,
+    hour: u8,
+) {
+    if let Some(forecast) = hourly_forecast(conditions, date, hour) {
+        score.factors.rain = rain_score(forecast.precipitation_chance);
+    }
+    if let Some(aqi) = conditions
+        .air_quality
+        .as_ref()
+        .and_then(|air_quality| air_quality.aqi_at(date, hour))
+    {
+        score.factors.air_quality = air_quality_score(aqi);
+    }
+    let scaled = score.score as f32 * score.factors.rain * score.factors.air_quality;
+    score.score = scaled.clamp(0.0, 100.0) as u8;
+}
+
+/// Scales a Sunset score by how clear the horizon is likely to be at
+/// `sunset_hour` on `date`, from the low cloud forecast for that hour
+///
+/// Every hour of the evening shares the one sunset, so each is scaled the
+/// same; scores are left alone where the forecast doesn't reach.
+fn apply_sunset_sky(
+    conditions: &BeachConditions,
+    score: &mut TimeSlotScore,
+    date: NaiveDate,
+    sunset_hour: u8,
+) {
+    let Some(clouds) =
+        hourly_forecast(conditions, date, sunset_hour).and_then(|forecast| forecast.cloud_cover)
+    else {
+        return;
+    };
+    score.factors.sky = sunset_sky_score(clouds.low);
+    let scaled = score.score as f32 * score.factors.sky;
+    score.score = scaled.clamp(0.0, 100.0) as u8;
+}
+
+/// Whether the activity is a run or walk where the seawall near the beach
+/// is closed
+fn closes_seawall(conditions: &BeachConditions, activity: Activity) -> bool {
+    activity == Activity::RunWalk && conditions.seawall_closure.is_some()
+}
+
+/// Whether the current wind is raising whitecaps that make for a rough swim
+/// on `date`
+///
+/// Only the current wind has a direction to work out the fetch from, so
+/// only the day it was fetched on is judged.
+fn whitecaps_spoil(conditions: &BeachConditions, activity: Activity, date: NaiveDate) -> bool {
+    if activity != Activity::Swimming {
+        return false;
+    }
+    let Some(weather) = &conditions.weather else {
+        return false;
+    };
+    conditions.beach.timezone.local(weather.fetched_at).date() == date
+        && conditions.chop() == Some(Chop::Whitecaps)
+}
+
+/// Scales a Surf score by how surfable the waves are at `hour` on `date`,
+/// given the wind's speed (km/h) and the direction it blows from
+///
+/// Scores are left alone where the wave forecast doesn't reach.
+fn apply_surf(
+    conditions: &BeachConditions,
+    score: &mut TimeSlotScore,
+    date: NaiveDate,
+    hour: u8,
+    (wind, wind_from): (f32, Option<f64>),
+) {
+    let (Some(facing), Some(waves)) = (wave_facing(&conditions.beach.id), &conditions.waves) else {
+        return;
+    };
+    let Some(height) = waves.height_at(date, hour) else {
+        return;
+    };
+    let quality = surf_quality(
+        height as f32,
+        waves.period_at(date, hour).map(|period| period as f32),
+        wind,
+        wind_from.map(|degrees| degrees as f32),
+        facing as f32,
+    );
+    score.factors.waves = quality;
+    let scaled = score.score as f32 * (FLAT_SURF_SHARE + (1.0 - FLAT_SURF_SHARE) * quality);
+    score.score = scaled.clamp(0.0, 100.0) as u8;
+}
+
+/// Whether the activity is a dog walk on a day the beach bans dogs
+fn bans_dogs(beach: &Beach, activity: Activity, date: NaiveDate) -> bool {
+    activity == Activity::DogWalk && !dog_rule(&beach.id, date).access.allows_dogs()
+}
+
+/// Estimates crowd level based on time of day and the beach's events on
+/// `date` (0.0 = empty, 1.0 = packed)
+fn estimate_crowd_level(hour: u8, beach_id: &str, date: NaiveDate) -> f32 {
+    let (event_crowd, _) = crate::beach_events::crowd_during(beach_id, date, hour);
+    let level = match hour {
+        6..=7 => 0.1,   // Early morning - very quiet
+        8..=9 => 0.2,   // Morning - light
+        10..=11 => 0.4, // Late morning - moderate
+        12..=14 => 0.8, // Midday - busy
+        15..=17 => 0.6, // Afternoon - moderate to busy
+        18..=19 => 0.4, // Early evening - moderate
+        20..=21 => 0.2, // Evening - light
+        _ => 0.5,       // Default
+    };
+    (level + event_crowd).min(1.0)
+}
+
+/// How far to trust a score computed from a forecast
+///
+/// Open-Meteo's deterministic forecast carries no spread, so confidence
+/// falls off with lead time instead: scores under 12 hours out are taken at
+/// face value, scores for later today or tomorrow are moderate, and anything
+/// further out is low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForecastConfidence {
+    Moderate,
+    Low,
+}
+
+impl ForecastConfidence {
+    /// Confidence in a score `lead_hours` ahead, `None` when it's close
+    /// enough not to need a qualifier
+    pub fn from_lead_hours(lead_hours: u32) -> Option<Self> {
+        match lead_hours {
+            0..12 => None,
+            12..36 => Some(ForecastConfidence::Moderate),
+            _ => Some(ForecastConfidence::Low),
+        }
+    }
+
+    /// Qualifier shown after the score, e.g. "moderate confidence"
+    pub fn label(&self) -> &'static str {
+        match self {
+            ForecastConfidence::Moderate => "moderate confidence",
+            ForecastConfidence::Low => "low confidence",
+        }
+    }
+}
+
+/// Builds the `--score-dump` report: effective weights and ideal temperature
+/// and wind ranges per activity, then each beach's current score for every
+/// activity
+///
+/// Weights and ranges that differ from the built-in preset are marked with
+/// `*`. With an
+/// `only` activity the scores cover just that activity, best beach first.
+pub fn score_dump<'a>(
+    conditions: impl Fn(&str) -> Option<&'a BeachConditions>,
+    only: Option<Activity>,
+    now: DateTime<Local>,
+) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "Scoring weights (* = set in config)");
+    let _ = writeln!(
+        out,
+        "{:<14} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6}",
+        "Activity", "Temp", "Water", "Wind", "UV", "Tide", "Crowd", "Shade"
+    );
+    for &activity in Activity::all() {
+        let preset = preset_profile(activity);
+        let profile = get_profile(activity);
+        let weights = [
+            (profile.temp_weight, preset.temp_weight),
+            (profile.water_quality_weight, preset.water_quality_weight),
+            (profile.wind_weight, preset.wind_weight),
+            (profile.uv_weight, preset.uv_weight),
+            (profile.tide_weight, preset.tide_weight),
+            (profile.crowd_weight, preset.crowd_weight),
+            (profile.shade_weight, preset.shade_weight),
+        ];
+
+        let _ = write!(out, "{:<14}", activity.label());
+        for (weight, preset_weight) in weights {
+            let marker = if weight != preset_weight { "*" } else { " " };
+            let _ = write!(out, " {:>5.2}{}", weight, marker);
+        }
+        out.push('\n');
+    }
+
+    let _ = writeln!(out, "\nComfort ranges (* = set in config)");
+    let _ = writeln!(
+        out,
+        "{:<14} {:>12} {:>12}",
+        "Activity", "Temp C", "Wind km/h"
+    );
+    for &activity in Activity::all() {
+        let preset = preset_profile(activity);
+        let profile = get_profile(activity);
+        let ranges = [
+            (profile.temp_ideal_range, preset.temp_ideal_range),
+            (profile.wind_ideal_range, preset.wind_ideal_range),
+        ];
+
+        let _ = write!(out, "{:<14}", activity.label());
+        for ((min, max), preset_range) in ranges {
+            let marker = if (min, max) != preset_range { "*" } else { " " };
+            let _ = write!(out, " {:>11}{}", format!("{:.0}-{:.0}", min, max), marker);
+        }
+        out.push('\n');
+    }
+
+    let activities: Vec<Activity> = match only {
+        Some(activity) => vec![activity],
+        None => Activity::all().to_vec(),
+    };
+    let mut beaches: Vec<_> = all_beaches().iter().collect();
+    if let Some(activity) = only {
+        // Unscored beaches sort last; the sort is stable so ties keep list order
+        beaches.sort_by_key(|beach| {
+            std::cmp::Reverse(conditions(&beach.id).and_then(|c| score_now(c, activity, now)))
+        });
+    }
+
+    match good_score() {
+        Some(good) => {
+            let _ = writeln!(
+                out,
+                "\nScores at {} ({} = {} or more)",
+                format_time(&now),
+                GOOD_SCORE_MARKER,
+                good
+            );
+        }
+        None => {
+            let _ = writeln!(out, "\nScores at {}", format_time(&now));
+        }
+    }
+    let _ = write!(out, "{:<20}", "Beach");
+    for activity in &activities {
+        let _ = write!(out, " {:>13}", activity.label());
+    }
+    out.push('\n');
+
+    for beach in beaches {
+        let _ = write!(out, "{}", text::pad_right(&beach.name, 20));
+        for &activity in &activities {
+            let score = conditions(&beach.id).and_then(|c| score_now(c, activity, now));
+            match score {
+                Some(score) => {
+                    let marker = if is_good_score(score) {
+                        GOOD_SCORE_MARKER
+                    } else {
+                        " "
+                    };
+                    let _ = write!(out, " {:>12}{}", score, marker);
+                }
+                None => {
+                    let _ = write!(out, " {:>12} ", "--");
+                }
+            }
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{DataQuality, HourlyForecast, Weather, WeatherCondition, WeatherProvider};
+    use chrono::{NaiveTime, TimeZone};
+
+    /// 14:00 on the beaches' clock (PDT), whatever the machine's timezone
+    fn afternoon() -> DateTime<Local> {
+        Utc.with_ymd_and_hms(2026, 7, 15, 21, 0, 0)
+            .unwrap()
+            .with_timezone(&Local)
+    }
+
+    fn create_conditions() -> BeachConditions {
+        BeachConditions {
+            beach: all_beaches()[0].clone(),
+            weather: Some(Weather {
+                temperature: 24.0,
+                feels_like: 25.0,
+                condition: WeatherCondition::Clear,
+                humidity: 55,
+                wind: 8.0,
+                wind_direction: None,
+                uv: 6.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
+            }),
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    #[test]
+    fn test_score_now_needs_weather() {
+        let mut conditions = create_conditions();
+        assert!(score_now(&conditions, Activity::Swimming, afternoon()).is_some());
+
+        conditions.weather = None;
+        assert!(score_now(&conditions, Activity::Swimming, afternoon()).is_none());
+    }
+
+    #[test]
+    fn test_closed_beach_scores_zero_for_every_activity() {
+        let mut conditions = create_conditions();
+        conditions.closure = Some(crate::data::Closure {
+            reason: "Construction".to_string(),
+            until: None,
+            fetched_at: Utc::now(),
+        });
+        for activity in Activity::all() {
+            assert_eq!(score_now(&conditions, *activity, afternoon()), Some(0));
+        }
+    }
+
+    #[test]
+    fn test_surf_scales_with_waves_and_needs_swell() {
+        let mut conditions = create_conditions();
+        conditions.beach = crate::data::get_beach_by_id("wreck").unwrap().clone();
+        let unknown = score_now(&conditions, Activity::Surf, afternoon()).unwrap();
+
+        // Afternoon on the beach's clock is 14:00
+        let date = NaiveDate::from_ymd_opt(2026, 7, 15).unwrap();
+        let waves = crate::data::WaveForecast::default();
+        conditions.waves = Some(waves.clone().with_hour(date, 14, 1.0, Some(9.0)));
+        let surfable = score_now(&conditions, Activity::Surf, afternoon()).unwrap();
+        conditions.waves = Some(waves.with_hour(date, 14, 0.1, Some(3.0)));
+        let flat = score_now(&conditions, Activity::Surf, afternoon()).unwrap();
+
+        assert!(
+            surfable >= unknown.saturating_sub(1),
+            "{surfable} vs {unknown}"
+        );
+        assert!(flat < surfable / 4, "{flat} vs {surfable}");
+
+        // Kitsilano never gets swell
+        let kitsilano = create_conditions();
+        assert_eq!(score_now(&kitsilano, Activity::Surf, afternoon()), Some(0));
+    }
+
+    #[test]
+    fn test_fog_takes_points_off_peace_and_sunset() {
+        let mut conditions = create_conditions();
+        let clear = |activity| score_now(&conditions, activity, afternoon()).unwrap();
+        let (peace, swim) = (clear(Activity::Peace), clear(Activity::Swimming));
+
+        // Afternoon on the beach's clock is 14:00, still in the fog
+        let weather = conditions.weather.as_mut().unwrap();
+        weather.fetched_at = afternoon().with_timezone(&Utc);
+        weather.hourly = vec![crate::data::HourlyForecast {
+            hour: 14,
+            temperature: 12.0,
+            feels_like: 12.0,
+            condition: WeatherCondition::Clear,
+            wind: 4.0,
+            wind_direction: "W".to_string(),
+            uv: 1.0,
+            precipitation_chance: 0,
+            dew_point: Some(11.5),
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        }];
+        let foggy = |activity| score_now(&conditions, activity, afternoon()).unwrap();
+        assert_eq!(foggy(Activity::Peace), peace.saturating_sub(FOG_PENALTY));
+        assert_eq!(foggy(Activity::Swimming), swim);
+    }
+
+    #[test]
+    fn test_low_cloud_at_sunset_dims_the_sunset_score() {
+        let mut conditions = create_conditions();
+        let clear = |activity| score_now(&conditions, activity, afternoon()).unwrap();
+        let (sunset, swim) = (clear(Activity::Sunset), clear(Activity::Swimming));
+
+        // Sunset is at 21:00; a low deck rolls in for it
+        let weather = conditions.weather.as_mut().unwrap();
+        weather.fetched_at = afternoon().with_timezone(&Utc);
+        weather.hourly = vec![crate::data::HourlyForecast {
+            hour: 21,
+            temperature: 18.0,
+            feels_like: 18.0,
+            condition: WeatherCondition::Cloudy,
+            wind: 8.0,
+            wind_direction: "W".to_string(),
+            uv: 0.0,
+            precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: Some(crate::data::CloudCover {
+                total: 100,
+                low: 100,
+                mid: 20,
+                high: 0,
+            }),
+            data_quality: DataQuality::Reported,
+        }];
+        let overcast = |activity| score_now(&conditions, activity, afternoon()).unwrap();
+        assert_eq!(overcast(Activity::Sunset), (sunset as f32 * 0.3) as u8);
+        assert_eq!(overcast(Activity::Swimming), swim);
+    }
+
+    #[test]
+    fn test_whitecaps_take_points_off_swimming() {
+        // 25 km/h onshore at Kitsilano, from across English Bay, then from
+        // behind the beach
+        let mut conditions = create_conditions();
+        let weather = conditions.weather.as_mut().unwrap();
+        weather.fetched_at = afternoon().with_timezone(&Utc);
+        weather.wind = 25.0;
+        weather.wind_direction = Some(300.0);
+        assert_eq!(conditions.chop(), Some(Chop::Whitecaps));
+        let rough = |c: &BeachConditions, activity| score_now(c, activity, afternoon()).unwrap();
+        let (swim, peace) = (
+            rough(&conditions, Activity::Swimming),
+            rough(&conditions, Activity::Peace),
+        );
+
+        conditions.weather.as_mut().unwrap().wind_direction = Some(90.0);
+        assert_eq!(conditions.chop(), Some(Chop::Calm));
+        let calm_swim = rough(&conditions, Activity::Swimming);
+        assert!(calm_swim > WHITECAP_PENALTY, "{calm_swim}");
+        assert_eq!(swim, calm_swim - WHITECAP_PENALTY);
+        assert_eq!(rough(&conditions, Activity::Peace), peace);
+
+        // The hour-by-hour scores follow suit today
+        let today = NaiveDate::from_ymd_opt(2026, 7, 15).unwrap();
+        let scorer = ActivityScorer::new(&conditions, Activity::Swimming);
+        let calm_hour = scorer.score(14, (24.0, 25.0, 6.0), today);
+        conditions.weather.as_mut().unwrap().wind_direction = Some(300.0);
+        let scorer = ActivityScorer::new(&conditions, Activity::Swimming);
+        let rough_hour = scorer.score(14, (24.0, 25.0, 6.0), today);
+        assert_eq!(
+            rough_hour.score,
+            calm_hour.score.saturating_sub(WHITECAP_PENALTY)
+        );
+    }
+
+    #[test]
+    fn test_run_walk_scales_with_rain_and_air_and_needs_the_seawall() {
+        let mut conditions = create_conditions();
+        let run = |c: &BeachConditions| score_now(c, Activity::RunWalk, afternoon()).unwrap();
+        let dry = run(&conditions);
+        assert!(dry > 0);
+
+        // Afternoon on the beach's clock is 14:00
+        let date = NaiveDate::from_ymd_opt(2026, 7, 15).unwrap();
+        let weather = conditions.weather.as_mut().unwrap();
+        weather.fetched_at = afternoon().with_timezone(&Utc);
+        weather.hourly = vec![crate::data::HourlyForecast {
+            hour: 14,
+            temperature: 24.0,
+            feels_like: 25.0,
+            condition: WeatherCondition::Clear,
+            wind: 8.0,
+            wind_direction: "W".to_string(),
+            uv: 6.0,
+            precipitation_chance: 100,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        }];
+        let rainy = run(&conditions);
+        assert!(rainy < dry / 3, "{rainy} vs {dry}");
+
+        conditions.weather.as_mut().unwrap().hourly.clear();
+        conditions.air_quality = Some(crate::data::AirQuality::default().with_aqi(date, 14, 250));
+        assert_eq!(run(&conditions), 0);
+        conditions.air_quality = Some(crate::data::AirQuality::default().with_aqi(date, 14, 30));
+        assert_eq!(run(&conditions), dry);
+
+        // A closed seawall stops a run but not a swim
+        let swim = score_now(&conditions, Activity::Swimming, afternoon());
+        conditions.seawall_closure = Some(crate::data::Closure {
+            reason: "Storm damage".to_string(),
+            until: None,
+            fetched_at: Utc::now(),
+        });
+        assert_eq!(run(&conditions), 0);
+        assert_eq!(
+            score_now(&conditions, Activity::Swimming, afternoon()),
+            swim
+        );
+    }
+
+    #[test]
+    fn test_dog_walk_scores_zero_where_dogs_are_banned() {
+        let mut conditions = create_conditions();
+        conditions.beach = crate::data::get_beach_by_id("trout-lake").unwrap().clone();
+        assert_eq!(
+            score_now(&conditions, Activity::DogWalk, afternoon()),
+            Some(0)
+        );
+        assert!(score_now(&conditions, Activity::Swimming, afternoon()).unwrap() > 0);
+
+        conditions.beach = crate::data::get_beach_by_id("sunset").unwrap().clone();
+        assert!(score_now(&conditions, Activity::DogWalk, afternoon()).unwrap() > 0);
+    }
+
+    #[test]
+    fn test_confidence_falls_off_with_lead_time() {
+        assert_eq!(ForecastConfidence::from_lead_hours(0), None);
+        assert_eq!(ForecastConfidence::from_lead_hours(11), None);
+        assert_eq!(
+            ForecastConfidence::from_lead_hours(12),
+            Some(ForecastConfidence::Moderate)
+        );
+        assert_eq!(
+            ForecastConfidence::from_lead_hours(35),
+            Some(ForecastConfidence::Moderate)
+        );
+        assert_eq!(
+            ForecastConfidence::from_lead_hours(36),
+            Some(ForecastConfidence::Low)
+        );
+        assert_eq!(
+            ForecastConfidence::from_lead_hours(144).map(|c| c.label()),
+            Some("low confidence")
+        );
+    }
+
+    #[test]
+    fn test_best_hour_today_stays_within_the_day() {
+        let conditions = create_conditions();
+        let (hour, score) = best_hour_today(&conditions, Activity::Sunset, afternoon()).unwrap();
+        assert!((14..24).contains(&hour));
+        assert!(score > 0);
+
+        let mut no_weather = create_conditions();
+        no_weather.weather = None;
+        assert!(best_hour_today(&no_weather, Activity::Sunset, afternoon()).is_none());
+    }
+
+    #[test]
+    fn test_best_hour_today_follows_beach_clock_not_machine_clock() {
+        let mut conditions = create_conditions();
+
+        // 23:30 in Vancouver is already tomorrow morning in UTC, but the
+        // beach's day only has its last hour left
+        let late = Utc
+            .with_ymd_and_hms(2026, 7, 16, 6, 30, 0)
+            .unwrap()
+            .with_timezone(&Local);
+        let (hour, _) = best_hour_today(&conditions, Activity::Swimming, late).unwrap();
+        assert_eq!(hour, 23);
+
+        // The same instant at a beach on UTC+1 is early morning with the
+        // whole day ahead
+        conditions.beach = conditions
+            .beach
+            .with_timezone(crate::data::BeachTimezone::new(
+                "Europe/Lisbon",
+                0,
+                crate::data::DstRule::Europe,
+            ));
+        let (hour, _) = best_hour_today(&conditions, Activity::Swimming, late).unwrap();
+        assert!(
+            hour >= 7,
+            "best hour {} should be after 07:30 Lisbon time",
+            hour
+        );
+    }
+
+    #[test]
+    fn test_sunset_score_gets_bonus_for_tide_at_sunset() {
+        use crate::data::{TideEvent, TideInfo, TideState};
+
+        let mut conditions = create_conditions();
+        // 20:00 PDT, an hour before the 21:00 sunset
+        let evening = Utc
+            .with_ymd_and_hms(2026, 7, 16, 3, 0, 0)
+            .unwrap()
+            .with_timezone(&Local);
+        let without_tide = score_now(&conditions, Activity::Sunset, evening).unwrap();
+
+        // Low tide at 21:30 PDT; the current height matches the no-data default
+        conditions.tides = Some(TideInfo {
+            current_height: 2.4,
+            tide_state: TideState::Falling,
+            next_high: None,
+            next_low: Some(TideEvent {
+                time: Utc
+                    .with_ymd_and_hms(2026, 7, 16, 4, 30, 0)
+                    .unwrap()
+                    .with_timezone(&Local),
+                height: 0.5,
+            }),
+            fetched_at: Utc::now(),
+        });
+        let with_tide = score_now(&conditions, Activity::Sunset, evening).unwrap();
+
+        assert!(
+            with_tide > without_tide,
+            "golden low tide should raise the sunset score ({} vs {})",
+            with_tide,
+            without_tide
+        );
+    }
+
+    #[test]
+    fn test_scorer_yields_hours_from_the_current_hour() {
+        let conditions = create_conditions();
+        let (_, last_hour) = hour_range();
+
+        let hours: Vec<u8> = ActivityScorer::hourly_from(&conditions, Activity::Swimming, 10)
+            .map(|(hour, _)| hour)
+            .collect();
+        assert_eq!(hours, (10..=last_hour).collect::<Vec<_>>());
+
+        // Sunset stops at sunset
+        let last_sunset_hour = ActivityScorer::hourly_from(&conditions, Activity::Sunset, 10)
+            .last()
+            .map(|(hour, _)| hour);
+        assert_eq!(last_sunset_hour, Some(21.min(last_hour)));
+
+        // Nothing to score without weather
+        let mut conditions = conditions;
+        conditions.weather = None;
+        assert_eq!(
+            ActivityScorer::hourly_from(&conditions, Activity::Swimming, 10).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_scorer_scores_tomorrow_from_the_forecast() {
+        let mut conditions = create_conditions();
+        let forecast = |hour: u8, temperature: f64| HourlyForecast {
+            hour,
+            temperature,
+            feels_like: temperature,
+            condition: WeatherCondition::Clear,
+            wind: 5.0,
+            wind_direction: "W".to_string(),
+            uv: 5.0,
+            precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        };
+        conditions.weather.as_mut().unwrap().tomorrow =
+            vec![forecast(3, 12.0), forecast(13, 14.0), forecast(14, 27.0)];
+
+        let scores: Vec<(u8, u8)> = ActivityScorer::tomorrow(&conditions, Activity::Swimming)
+            .map(|(hour, slot)| (hour, slot.score))
+            .collect();
+        // 03:00 is outside the scored hours
+        assert_eq!(
+            scores.iter().map(|(hour, _)| *hour).collect::<Vec<_>>(),
+            vec![13, 14]
+        );
+        assert!(scores[1].1 > scores[0].1);
+    }
+
+    #[test]
+    fn test_score_dump_lists_weights_and_beaches() {
+        let conditions = create_conditions();
+        let first_id = conditions.beach.id.clone();
+        let dump = score_dump(
+            |id| (id == first_id.as_str()).then_some(&conditions),
+            None,
+            afternoon(),
+        );
+
+        assert!(dump.contains("Scoring weights"));
+        assert!(dump.contains("Sailing"));
+        assert!(dump.contains(" 0.60 "), "preset sailing wind weight");
+        assert!(dump.contains("Comfort ranges"));
+        assert!(dump.contains("20-28 "), "preset swimming temperature range");
+        assert!(dump.contains(&format!("Scores at {}", format_time(&afternoon()))));
+        for beach in all_beaches() {
+            assert!(dump.contains(beach.name.as_str()));
+        }
+        // Beaches without data show placeholders
+        assert!(dump.contains("--"));
+    }
+
+    #[test]
+    fn test_score_dump_for_one_activity_ranks_beaches() {
+        // Data for a beach further down the list, so ranking moves it up
+        let mut conditions = create_conditions();
+        conditions.beach = all_beaches()[3].clone();
+        let scored_id = conditions.beach.id.clone();
+        let dump = score_dump(
+            |id| (id == scored_id.as_str()).then_some(&conditions),
+            Some(Activity::Swimming),
+            afternoon(),
+        );
+
+        let scores = dump.split("Scores at").nth(1).unwrap();
+        let rows: Vec<&str> = scores.lines().collect();
+        assert_eq!(
+            rows[1].trim_end(),
+            format!("{:<20} {:>13}", "Beach", "Swimming")
+        );
+        assert!(rows[2].starts_with(conditions.beach.name.as_str()));
+        assert!(!scores.contains("Sailing"));
+    }
+}
@@ -0,0 +1,228 @@
+//! `serve` mode: refreshed data behind a Prometheus metrics endpoint
+//!
+//! Keeps every beach's data refreshed on the dashboard schedule and answers
+//! `GET /metrics` with the data pipeline metrics, so a self-hosted setup can
+//! be monitored with existing tooling. Requests are handled one at a time; a
+//! scrape that arrives during a refresh waits for it to finish. With an MQTT
+//! broker configured, each refresh is published to it as well.
+
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::MissedTickBehavior;
+
+use crate::app::{App, DASHBOARD_REFRESH_MINUTES};
+use crate::data::{all_beaches, render_memory_cache, DataAge};
+use crate::event_loop::LiveData;
+use crate::mqtt::MqttPublisher;
+
+/// How long a client gets to send its request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Serves `/metrics` on `addr`, refreshing the app's data in between
+///
+/// The app should have metrics enabled; without them only data ages are
+/// reported. Runs until binding or accepting fails.
+pub async fn serve(
+    app: &mut App,
+    addr: SocketAddr,
+    mut mqtt: Option<MqttPublisher>,
+) -> io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    let mut refresh =
+        tokio::time::interval(Duration::from_secs(DASHBOARD_REFRESH_MINUTES as u64 * 60));
+    refresh.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            // The first tick fires at once, loading data before the first scrape
+            _ = refresh.tick() => {
+                app.load_all_data(&mut LiveData).await;
+                if let Some(mqtt) = &mut mqtt {
+                    mqtt.publish_changes(app, chrono::Local::now());
+                }
+            }
+            accepted = listener.accept() => {
+                // A failed connection shouldn't stop the server
+                if let Ok((stream, _)) = accepted {
+                    let _ = handle_connection(stream, app).await;
+                }
+            }
+        }
+    }
+}
+
+/// Reads one request from a connection and writes the response
+async fn handle_connection(mut stream: TcpStream, app: &App) -> io::Result<()> {
+    let mut buffer = [0u8; 1024];
+    let read = tokio::time::timeout(REQUEST_TIMEOUT, stream.read(&mut buffer))
+        .await
+        .unwrap_or(Ok(0))?;
+    let request = String::from_utf8_lossy(&buffer[..read]);
+    let response = respond(request.lines().next().unwrap_or(""), app, Utc::now());
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Builds the HTTP response for a request line such as `GET /metrics HTTP/1.1`
+fn respond(request_line: &str, app: &App, now: DateTime<Utc>) -> String {
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next();
+    let path = parts.next().and_then(|path| path.split('?').next());
+
+    let (status, content_type, body) = match (method, path) {
+        (Some("GET"), Some("/metrics")) => (
+            "200 OK",
+            "text/plain; version=0.0.4",
+            render_metrics(app, now),
+        ),
+        (Some("GET"), _) => ("404 Not Found", "text/plain", "Not found\n".to_string()),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain",
+            "Method not allowed\n".to_string(),
+        ),
+    };
+
+    format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )
+}
+
+/// The metrics page: client counters, per-beach data ages and the in-memory
+/// cache layer's counters
+fn render_metrics(app: &App, now: DateTime<Utc>) -> String {
+    let ages = data_ages(app, now);
+    let mut text = app.metrics.clone().unwrap_or_default().render(&ages);
+    if let Some(stats) = app.cache_stats() {
+        text.push_str(&render_memory_cache(&stats));
+    }
+    text
+}
+
+/// How old each beach's weather, tides and water quality are
+fn data_ages(app: &App, now: DateTime<Utc>) -> Vec<DataAge> {
+    let mut ages = Vec::new();
+    for beach in all_beaches() {
+        let Some(conditions) = app.get_conditions(&beach.id) else {
+            continue;
+        };
+        let fetched = [
+            ("weather", conditions.weather.as_ref().map(|w| w.fetched_at)),
+            ("tides", conditions.tides.as_ref().map(|t| t.fetched_at)),
+            (
+                "water_quality",
+                conditions.water_quality.as_ref().map(|wq| wq.fetched_at),
+            ),
+        ];
+        for (source, fetched_at) in fetched {
+            if let Some(fetched_at) = fetched_at {
+                ages.push(DataAge {
+                    beach: beach.id.to_string(),
+                    source,
+                    seconds: (now - fetched_at).num_seconds().max(0),
+                });
+            }
+        }
+    }
+    ages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{BeachConditions, TideInfo, TideState};
+    use chrono::TimeZone;
+
+    fn create_app(now: DateTime<Utc>) -> App {
+        let beach = all_beaches()[0].clone();
+        let mut app = App::new();
+        app.enable_metrics();
+        app.beach_conditions.insert(
+            beach.id.clone(),
+            BeachConditions {
+                beach,
+                weather: None,
+                tides: Some(TideInfo {
+                    current_height: 2.0,
+                    tide_state: TideState::Rising,
+                    next_high: None,
+                    next_low: None,
+                    fetched_at: now - chrono::Duration::minutes(10),
+                }),
+                water_quality: None,
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+        app
+    }
+
+    #[test]
+    fn test_metrics_route_reports_data_ages() {
+        let now = Utc.with_ymd_and_hms(2026, 7, 15, 21, 0, 0).unwrap();
+        let app = create_app(now);
+        app.metrics
+            .as_ref()
+            .unwrap()
+            .record_cache("Tide predictions", true);
+
+        let response = respond("GET /metrics HTTP/1.1", &app, now);
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: text/plain; version=0.0.4"));
+        assert!(response.contains(&format!(
+            "vanbeach_data_age_seconds{{beach=\"{}\",source=\"tides\"}} 600",
+            all_beaches()[0].id
+        )));
+        assert!(response.contains(
+            "vanbeach_cache_lookups_total{source=\"Tide predictions\",result=\"hit\"} 1"
+        ));
+        assert!(!response.contains("source=\"weather\"}"));
+    }
+
+    #[test]
+    fn test_other_routes_and_methods_are_rejected() {
+        let now = Utc.with_ymd_and_hms(2026, 7, 15, 21, 0, 0).unwrap();
+        let app = App::new();
+
+        assert!(respond("GET /metrics?x=1 HTTP/1.1", &app, now).starts_with("HTTP/1.1 200"));
+        assert!(respond("GET / HTTP/1.1", &app, now).starts_with("HTTP/1.1 404"));
+        assert!(respond("POST /metrics HTTP/1.1", &app, now).starts_with("HTTP/1.1 405"));
+        assert!(respond("", &app, now).starts_with("HTTP/1.1 405"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_connection_answers_over_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = App::new();
+
+        let client = tokio::spawn(async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            stream
+                .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+                .await
+                .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        });
+        let (stream, _) = listener.accept().await.unwrap();
+        handle_connection(stream, &app).await.unwrap();
+
+        let response = client.await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("# TYPE vanbeach_api_requests_total counter"));
+    }
+}
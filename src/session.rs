@@ -0,0 +1,525 @@
+//! Session recording and replay
+//!
+//! `--record <FILE>` captures key presses, beach data snapshots and state
+//! transitions to a JSON file. `--replay <FILE>` feeds them back into the UI
+//! without touching the network, so a user's bug report can be reproduced
+//! step by step. Recorded states are compared against the replayed ones and
+//! any divergence is reported when the replay ends.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::activities::Activity;
+use crate::app::{App, AppState};
+use crate::data::{BeachConditions, BeachId};
+
+/// Version of the session file format
+const SESSION_VERSION: u32 = 1;
+
+/// Longest pause replayed between two events; longer idle stretches are shortened
+const MAX_REPLAY_GAP_MS: u64 = 1000;
+
+/// Error types for reading and writing session files
+#[derive(Debug, Error)]
+pub enum SessionError {
+    /// The session file couldn't be read or written
+    #[error("Session file error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The session file isn't valid JSON
+    #[error("Failed to parse session: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    /// The session was written by an incompatible version
+    #[error("Unsupported session version {0} (expected {SESSION_VERSION})")]
+    UnsupportedVersion(u32),
+}
+
+/// A key press in a form that can be saved and replayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedKey {
+    Char(char),
+    Enter,
+    Esc,
+    Tab,
+    Backspace,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl RecordedKey {
+    /// Converts a terminal key event, or `None` for keys the app never handles
+    pub fn from_key_event(key: &KeyEvent) -> Option<Self> {
+        match key.code {
+            KeyCode::Char(c) => Some(Self::Char(c)),
+            KeyCode::Enter => Some(Self::Enter),
+            KeyCode::Esc => Some(Self::Esc),
+            KeyCode::Tab => Some(Self::Tab),
+            KeyCode::Backspace => Some(Self::Backspace),
+            KeyCode::Up => Some(Self::Up),
+            KeyCode::Down => Some(Self::Down),
+            KeyCode::Left => Some(Self::Left),
+            KeyCode::Right => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    /// Converts back into a key event for the app's key handler
+    pub fn to_key_event(self) -> KeyEvent {
+        let code = match self {
+            Self::Char(c) => KeyCode::Char(c),
+            Self::Enter => KeyCode::Enter,
+            Self::Esc => KeyCode::Esc,
+            Self::Tab => KeyCode::Tab,
+            Self::Backspace => KeyCode::Backspace,
+            Self::Up => KeyCode::Up,
+            Self::Down => KeyCode::Down,
+            Self::Left => KeyCode::Left,
+            Self::Right => KeyCode::Right,
+        };
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+}
+
+/// One recorded event, timestamped in milliseconds from the start of the session
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SessionEvent {
+    /// A key press handled by the app
+    Key { at_ms: u64, key: RecordedKey },
+    /// Beach data as it was after a load or refresh
+    Data {
+        at_ms: u64,
+        conditions: HashMap<BeachId, BeachConditions>,
+    },
+    /// The app moved to a new state
+    State { at_ms: u64, state: String },
+}
+
+impl SessionEvent {
+    /// Milliseconds from the start of the session
+    fn at_ms(&self) -> u64 {
+        match self {
+            Self::Key { at_ms, .. } | Self::Data { at_ms, .. } | Self::State { at_ms, .. } => {
+                *at_ms
+            }
+        }
+    }
+
+    fn set_at_ms(&mut self, value: u64) {
+        match self {
+            Self::Key { at_ms, .. } | Self::Data { at_ms, .. } | Self::State { at_ms, .. } => {
+                *at_ms = value
+            }
+        }
+    }
+}
+
+/// Startup options that decide where the app lands after the first load
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RecordedStartup {
+    /// Started with --plan
+    pub plan_trip: bool,
+    /// Started with --watch
+    pub dashboard: bool,
+    /// Activity selected at startup
+    pub activity: Option<Activity>,
+    /// Started with --beach; missing in sessions recorded before the flag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beach: Option<BeachId>,
+}
+
+impl RecordedStartup {
+    fn from_app(app: &App) -> Self {
+        Self {
+            plan_trip: app.pending_plan_trip,
+            dashboard: app.pending_dashboard,
+            activity: app.current_activity,
+            beach: app.pending_beach.clone(),
+        }
+    }
+
+    fn apply(&self, app: &mut App) {
+        app.pending_plan_trip = self.plan_trip;
+        app.pending_dashboard = self.dashboard;
+        app.current_activity = self.activity;
+        app.pending_beach = self.beach.clone();
+    }
+}
+
+/// A recorded session as stored on disk
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Session {
+    /// File format version
+    pub version: u32,
+    /// When recording started
+    pub recorded_at: DateTime<Utc>,
+    /// Startup options the session was recorded with
+    #[serde(default)]
+    pub startup: RecordedStartup,
+    /// Events in the order they happened
+    pub events: Vec<SessionEvent>,
+}
+
+impl Session {
+    /// Reads a session file
+    pub fn load(path: &Path) -> Result<Self, SessionError> {
+        let contents = fs::read_to_string(path)?;
+        let session: Session = serde_json::from_str(&contents)?;
+        if session.version != SESSION_VERSION {
+            return Err(SessionError::UnsupportedVersion(session.version));
+        }
+        Ok(session)
+    }
+}
+
+/// Describes a state the way it is written to session files
+fn describe_state(state: &AppState) -> String {
+    format!("{:?}", state)
+}
+
+/// Captures a session while the app runs
+pub struct SessionRecorder {
+    path: PathBuf,
+    started: Instant,
+    last_state: Option<String>,
+    session: Session,
+}
+
+impl SessionRecorder {
+    /// Starts recording a session that will be saved to `path`
+    ///
+    /// Call this before the first data load so the startup options are captured.
+    pub fn new(path: PathBuf, app: &App) -> Self {
+        Self {
+            path,
+            started: Instant::now(),
+            last_state: None,
+            session: Session {
+                version: SESSION_VERSION,
+                recorded_at: Utc::now(),
+                startup: RecordedStartup::from_app(app),
+                events: Vec::new(),
+            },
+        }
+    }
+
+    fn elapsed_ms(&self) -> u64 {
+        self.started.elapsed().as_millis() as u64
+    }
+
+    /// Records a key press the app handled
+    pub fn record_key(&mut self, key: &KeyEvent) {
+        if let Some(key) = RecordedKey::from_key_event(key) {
+            let at_ms = self.elapsed_ms();
+            self.session.events.push(SessionEvent::Key { at_ms, key });
+        }
+    }
+
+    /// Records a snapshot of all beach data
+    pub fn record_data(&mut self, conditions: &HashMap<BeachId, BeachConditions>) {
+        let at_ms = self.elapsed_ms();
+        self.session.events.push(SessionEvent::Data {
+            at_ms,
+            conditions: conditions.clone(),
+        });
+    }
+
+    /// Records the current state if it changed since the last call
+    pub fn record_state(&mut self, state: &AppState) {
+        let state = describe_state(state);
+        if self.last_state.as_ref() == Some(&state) {
+            return;
+        }
+        self.last_state = Some(state.clone());
+        let at_ms = self.elapsed_ms();
+        self.session
+            .events
+            .push(SessionEvent::State { at_ms, state });
+    }
+
+    /// Writes the session to its file
+    pub fn save(&self) -> Result<(), SessionError> {
+        let json = serde_json::to_string_pretty(&self.session)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+/// Feeds a recorded session back into the app
+pub struct SessionPlayer {
+    startup: RecordedStartup,
+    started: Instant,
+    events: VecDeque<SessionEvent>,
+    replayed: usize,
+    mismatches: Vec<String>,
+}
+
+impl SessionPlayer {
+    /// Prepares a session for replay, shortening long idle gaps
+    pub fn new(session: Session) -> Self {
+        let startup = session.startup;
+        let mut events: VecDeque<SessionEvent> = session.events.into();
+        let mut previous_recorded = 0;
+        let mut replay_at = 0;
+        for event in events.iter_mut() {
+            let recorded = event.at_ms();
+            replay_at += recorded
+                .saturating_sub(previous_recorded)
+                .min(MAX_REPLAY_GAP_MS);
+            previous_recorded = recorded;
+            event.set_at_ms(replay_at);
+        }
+
+        Self {
+            startup,
+            started: Instant::now(),
+            events,
+            replayed: 0,
+            mismatches: Vec::new(),
+        }
+    }
+
+    /// Reads and prepares a session file for replay
+    pub fn load(path: &Path) -> Result<Self, SessionError> {
+        Session::load(path).map(Self::new)
+    }
+
+    /// Puts the app in the startup configuration the session was recorded with
+    /// and starts the replay clock
+    pub fn start(&mut self, app: &mut App) {
+        self.startup.apply(app);
+        self.started = Instant::now();
+    }
+
+    /// Applies every event due since replay started. Returns false once all
+    /// events have been replayed.
+    pub fn step(&mut self, app: &mut App) -> bool {
+        let elapsed_ms = self.started.elapsed().as_millis() as u64;
+        self.apply_until(app, elapsed_ms)
+    }
+
+    /// Applies events with a timestamp up to `elapsed_ms`
+    fn apply_until(&mut self, app: &mut App, elapsed_ms: u64) -> bool {
+        while self
+            .events
+            .front()
+            .is_some_and(|event| event.at_ms() <= elapsed_ms)
+        {
+            let Some(event) = self.events.pop_front() else {
+                break;
+            };
+            self.replayed += 1;
+            match event {
+                SessionEvent::Key { key, .. } => app.handle_key(key.to_key_event()),
+                SessionEvent::Data { conditions, .. } => {
+                    // A dashboard refresh keeps the dashboard on screen
+                    if app.state == AppState::Dashboard {
+                        app.pending_dashboard = true;
+                    }
+                    app.apply_snapshot(conditions);
+                }
+                SessionEvent::State { at_ms, state } => {
+                    let actual = describe_state(&app.state);
+                    if actual != state {
+                        self.mismatches.push(format!(
+                            "at {}ms: recorded {}, replayed {}",
+                            at_ms, state, actual
+                        ));
+                    }
+                }
+            }
+        }
+        !self.events.is_empty()
+    }
+
+    /// One-line summary printed when the replay ends
+    pub fn summary(&self) -> String {
+        if self.mismatches.is_empty() {
+            format!("Replayed {} events, all states matched", self.replayed)
+        } else {
+            format!(
+                "Replayed {} events, {} state mismatches:\n  {}",
+                self.replayed,
+                self.mismatches.len(),
+                self.mismatches.join("\n  ")
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn session(events: Vec<SessionEvent>) -> Session {
+        Session {
+            version: SESSION_VERSION,
+            recorded_at: Utc::now(),
+            startup: RecordedStartup::default(),
+            events,
+        }
+    }
+
+    #[test]
+    fn test_recorded_key_round_trip() {
+        for code in [
+            KeyCode::Char('j'),
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+            KeyCode::Esc,
+            KeyCode::Tab,
+            KeyCode::Left,
+        ] {
+            let recorded = RecordedKey::from_key_event(&key(code)).unwrap();
+            assert_eq!(recorded.to_key_event().code, code);
+        }
+        assert!(RecordedKey::from_key_event(&key(KeyCode::F(5))).is_none());
+    }
+
+    #[test]
+    fn test_recorder_skips_repeated_states_and_saves() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("session.json");
+        let mut app = App::new();
+        app.pending_dashboard = true;
+        let mut recorder = SessionRecorder::new(path.clone(), &app);
+
+        recorder.record_state(&AppState::Loading);
+        recorder.record_data(&HashMap::new());
+        recorder.record_state(&AppState::BeachList);
+        recorder.record_state(&AppState::BeachList);
+        recorder.record_key(&key(KeyCode::Char('p')));
+        recorder.record_state(&AppState::PlanTrip);
+        recorder.save().unwrap();
+
+        let loaded = Session::load(&path).unwrap();
+        assert!(loaded.startup.dashboard);
+        assert_eq!(loaded.events.len(), 5);
+        assert!(matches!(
+            loaded.events[3],
+            SessionEvent::Key {
+                key: RecordedKey::Char('p'),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_load_rejects_other_versions() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("session.json");
+        let mut old = session(Vec::new());
+        old.version = 99;
+        fs::write(&path, serde_json::to_string(&old).unwrap()).unwrap();
+
+        assert!(matches!(
+            Session::load(&path),
+            Err(SessionError::UnsupportedVersion(99))
+        ));
+    }
+
+    #[test]
+    fn test_player_shortens_long_gaps() {
+        let player = SessionPlayer::new(session(vec![
+            SessionEvent::State {
+                at_ms: 0,
+                state: "Loading".to_string(),
+            },
+            SessionEvent::Key {
+                at_ms: 60_000,
+                key: RecordedKey::Char('j'),
+            },
+            SessionEvent::Key {
+                at_ms: 60_200,
+                key: RecordedKey::Char('k'),
+            },
+        ]));
+
+        let times: Vec<u64> = player.events.iter().map(|e| e.at_ms()).collect();
+        assert_eq!(times, vec![0, MAX_REPLAY_GAP_MS, MAX_REPLAY_GAP_MS + 200]);
+    }
+
+    #[test]
+    fn test_player_replays_data_and_keys() {
+        let mut app = App::new();
+        let mut player = SessionPlayer::new(session(vec![
+            SessionEvent::Data {
+                at_ms: 0,
+                conditions: HashMap::new(),
+            },
+            SessionEvent::State {
+                at_ms: 0,
+                state: "BeachList".to_string(),
+            },
+            SessionEvent::Key {
+                at_ms: 10,
+                key: RecordedKey::Char('p'),
+            },
+            SessionEvent::State {
+                at_ms: 10,
+                state: "PlanTrip".to_string(),
+            },
+        ]));
+
+        assert!(player.apply_until(&mut app, 0));
+        assert_eq!(app.state, AppState::BeachList);
+        assert!(!player.apply_until(&mut app, 10));
+        assert_eq!(app.state, AppState::PlanTrip);
+        assert!(player.summary().contains("all states matched"));
+    }
+
+    #[test]
+    fn test_player_restores_startup_options() {
+        let mut recorded = session(vec![SessionEvent::Data {
+            at_ms: 0,
+            conditions: HashMap::new(),
+        }]);
+        recorded.startup.plan_trip = true;
+        recorded.startup.activity = Some(Activity::Sailing);
+
+        let mut app = App::new();
+        let mut player = SessionPlayer::new(recorded);
+        player.start(&mut app);
+        player.apply_until(&mut app, 0);
+
+        assert_eq!(app.state, AppState::PlanTrip);
+        assert_eq!(app.current_activity, Some(Activity::Sailing));
+    }
+
+    #[test]
+    fn test_player_reports_state_mismatch() {
+        let mut app = App::new();
+        let mut player = SessionPlayer::new(session(vec![
+            SessionEvent::Data {
+                at_ms: 0,
+                conditions: HashMap::new(),
+            },
+            SessionEvent::State {
+                at_ms: 0,
+                state: "PlanTrip".to_string(),
+            },
+        ]));
+
+        player.apply_until(&mut app, 0);
+        let summary = player.summary();
+        assert!(summary.contains("1 state mismatches"));
+        assert!(summary.contains("recorded PlanTrip, replayed BeachList"));
+    }
+}
@@ -0,0 +1,77 @@
+//! Quitting cleanly when the terminal goes away or the process is stopped
+//!
+//! Closing the terminal tab or logging out sends SIGHUP, and `kill` or a
+//! service manager sends SIGTERM. Either one ends the TUI the way `q` does,
+//! so the terminal and its title are put back and usage counts and a
+//! `--record` session are saved instead of lost. Pinned plans, favorites,
+//! sun sessions, cached data and the journal are written as they change, so
+//! there's nothing of theirs left to flush.
+
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::task::JoinHandle;
+
+/// Notes a SIGTERM or SIGHUP for the event loop to quit on
+pub struct ShutdownSignals {
+    received: Arc<AtomicBool>,
+    /// Task waiting for the signals, stopped when this is dropped
+    listener: JoinHandle<()>,
+}
+
+impl ShutdownSignals {
+    /// Starts listening; from here on the signals no longer kill the process
+    pub fn listen() -> io::Result<Self> {
+        let mut terminate = signal(SignalKind::terminate())?;
+        let mut hangup = signal(SignalKind::hangup())?;
+        let received = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&received);
+        let listener = tokio::spawn(async move {
+            tokio::select! {
+                _ = terminate.recv() => {}
+                _ = hangup.recv() => {}
+            }
+            flag.store(true, Ordering::SeqCst);
+        });
+        Ok(Self { received, listener })
+    }
+
+    /// Whether either signal has arrived
+    pub fn received(&self) -> bool {
+        self.received.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for ShutdownSignals {
+    fn drop(&mut self) {
+        self.listener.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_hangup_is_noted_instead_of_killing() {
+        let signals = ShutdownSignals::listen().unwrap();
+        assert!(!signals.received());
+
+        let status = std::process::Command::new("kill")
+            .args(["-HUP", &std::process::id().to_string()])
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        for _ in 0..100 {
+            if signals.received() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("SIGHUP wasn't noted");
+    }
+}
@@ -0,0 +1,427 @@
+//! Static site export
+//!
+//! `vanbeach export-site --out ./site` writes an `index.html` summarizing every
+//! beach plus one page per beach with its current conditions, today's best
+//! window for each activity and the beach's known hazards. The pages are plain HTML with inline styles so the
+//! directory can be served as-is and regenerated from cron.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use chrono::{DateTime, Local};
+
+use crate::activities::Activity;
+use crate::config::{is_good_score, GOOD_SCORE_MARKER};
+use crate::data::{all_beaches, hazards, Beach, BeachConditions, TideState, WaterStatus};
+use crate::scoring::{best_hour_today, score_now};
+use crate::time_format::{format_hour, format_time};
+
+/// Shared stylesheet embedded in every page
+const STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:52rem;margin:2rem auto;\
+padding:0 1rem;color:#1d2a33}table{border-collapse:collapse;width:100%}\
+th,td{text-align:left;padding:.35rem .6rem;border-bottom:1px solid #dde4ea}\
+.safe{color:#1a7f37}.advisory{color:#9a6700}.closed{color:#cf222e}.unknown{color:#6e7781}\
+footer{margin-top:2rem;color:#6e7781;font-size:.85rem}";
+
+/// Writes the index and one page per beach into `out`, creating it if needed
+///
+/// With an `activity`, the index shows each beach's score for it instead of
+/// the best activity right now. Returns the number of pages written.
+pub fn export_site<'a>(
+    out: &Path,
+    conditions: impl Fn(&str) -> Option<&'a BeachConditions>,
+    activity: Option<Activity>,
+    now: DateTime<Local>,
+) -> io::Result<usize> {
+    fs::create_dir_all(out)?;
+
+    let beaches = all_beaches();
+    fs::write(
+        out.join("index.html"),
+        render_index(&conditions, activity, now),
+    )?;
+    for beach in beaches {
+        fs::write(
+            out.join(beach_page_name(beach)),
+            render_beach_page(beach, conditions(&beach.id), now),
+        )?;
+    }
+
+    Ok(beaches.len() + 1)
+}
+
+/// File name of a beach's page
+fn beach_page_name(beach: &Beach) -> String {
+    format!("{}.html", beach.id)
+}
+
+/// Renders the index page with one summary row per beach
+fn render_index<'a>(
+    conditions: &impl Fn(&str) -> Option<&'a BeachConditions>,
+    only: Option<Activity>,
+    now: DateTime<Local>,
+) -> String {
+    let mut body = String::new();
+    let _ = writeln!(body, "<h1>Vancouver Beaches</h1>");
+    let score_header = match only {
+        Some(activity) => format!("{} right now", escape_html(activity.label())),
+        None => "Best right now".to_string(),
+    };
+    let _ = writeln!(
+        body,
+        "<table>\n<tr><th>Beach</th><th>Weather</th><th>Wind</th><th>Tide</th>\
+         <th>Water</th><th>{}</th></tr>",
+        score_header
+    );
+
+    for beach in all_beaches() {
+        let beach_conditions = conditions(&beach.id);
+        let activities = match only {
+            Some(activity) => vec![activity],
+            None => Activity::all().to_vec(),
+        };
+        let best_now = beach_conditions.and_then(|c| {
+            activities
+                .iter()
+                .filter_map(|&activity| score_now(c, activity, now).map(|s| (activity, s)))
+                .max_by_key(|(_, score)| *score)
+        });
+
+        let _ = writeln!(
+            body,
+            "<tr><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            beach_page_name(beach),
+            escape_html(&beach.name),
+            weather_summary(beach_conditions),
+            wind_summary(beach_conditions),
+            tide_summary(beach_conditions),
+            water_badge(beach_conditions),
+            best_now
+                .map(|(activity, score)| format!(
+                    "{} ({}){}",
+                    escape_html(activity.label()),
+                    score,
+                    good_marker(score)
+                ))
+                .unwrap_or_else(|| "&ndash;".to_string()),
+        );
+    }
+    body.push_str("</table>\n");
+
+    page("Vancouver Beaches", &body, now)
+}
+
+/// " ✓" after a score that reaches the configured good score, else nothing
+fn good_marker(score: u8) -> String {
+    if is_good_score(score) {
+        format!(" {}", GOOD_SCORE_MARKER)
+    } else {
+        String::new()
+    }
+}
+
+/// Renders a beach page with current conditions and today's best windows
+fn render_beach_page(
+    beach: &Beach,
+    conditions: Option<&BeachConditions>,
+    now: DateTime<Local>,
+) -> String {
+    let mut body = String::new();
+    let _ = writeln!(body, "<p><a href=\"index.html\">&larr; All beaches</a></p>");
+    let _ = writeln!(body, "<h1>{}</h1>", escape_html(&beach.name));
+
+    let _ = writeln!(body, "<h2>Conditions</h2>\n<table>");
+    let _ = writeln!(
+        body,
+        "<tr><th>Weather</th><td>{}</td></tr>",
+        weather_summary(conditions)
+    );
+    let _ = writeln!(
+        body,
+        "<tr><th>Wind</th><td>{}</td></tr>",
+        wind_summary(conditions)
+    );
+    let _ = writeln!(
+        body,
+        "<tr><th>Tide</th><td>{}</td></tr>",
+        tide_summary(conditions)
+    );
+    let _ = writeln!(
+        body,
+        "<tr><th>Water quality</th><td>{}</td></tr>",
+        water_badge(conditions)
+    );
+    if let Some(weather) = conditions.and_then(|c| c.weather.as_ref()) {
+        let _ = writeln!(
+            body,
+            "<tr><th>Sun</th><td>{} &ndash; {}</td></tr>",
+            format_time(&weather.sunrise),
+            format_time(&weather.sunset)
+        );
+    }
+    body.push_str("</table>\n");
+
+    let _ = writeln!(
+        body,
+        "<h2>Best windows today</h2>\n<table>\n<tr><th>Activity</th><th>Best hour</th><th>Score</th></tr>"
+    );
+    for &activity in Activity::all() {
+        let best = conditions.and_then(|c| best_hour_today(c, activity, now));
+        let (hour, score) = match best {
+            Some((hour, score)) => (
+                format_hour(hour),
+                format!("{}{}", score, good_marker(score)),
+            ),
+            None => ("&ndash;".to_string(), "&ndash;".to_string()),
+        };
+        let _ = writeln!(
+            body,
+            "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+            escape_html(activity.label()),
+            hour,
+            score
+        );
+    }
+    body.push_str("</table>\n");
+
+    let beach_hazards = hazards(&beach.id);
+    if !beach_hazards.is_empty() {
+        let _ = writeln!(body, "<h2>Hazards</h2>\n<ul>");
+        for hazard in beach_hazards {
+            let _ = writeln!(
+                body,
+                "<li><strong>{}</strong>: {}</li>",
+                escape_html(hazard.kind.label()),
+                escape_html(hazard.description)
+            );
+        }
+        body.push_str("</ul>\n");
+    }
+
+    page(&beach.name, &body, now)
+}
+
+/// Wraps page content in the shared HTML skeleton
+fn page(title: &str, body: &str, now: DateTime<Local>) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}\
+         <footer>Generated {} {} by vanbeach</footer>\n</body>\n</html>\n",
+        escape_html(title),
+        STYLE,
+        body,
+        now.format("%Y-%m-%d"),
+        format_time(&now)
+    )
+}
+
+fn weather_summary(conditions: Option<&BeachConditions>) -> String {
+    match conditions.and_then(|c| c.weather.as_ref()) {
+        Some(weather) => format!(
+            "{:.0}&deg;C, {}",
+            weather.temperature,
+            weather.description().to_lowercase()
+        ),
+        None => "&ndash;".to_string(),
+    }
+}
+
+fn wind_summary(conditions: Option<&BeachConditions>) -> String {
+    match conditions.and_then(|c| c.effective_wind()) {
+        Some(wind) => format!("{:.0} km/h", wind),
+        None => "&ndash;".to_string(),
+    }
+}
+
+fn tide_summary(conditions: Option<&BeachConditions>) -> String {
+    match conditions.and_then(|c| c.tides.as_ref()) {
+        Some(tides) => {
+            let state = match tides.tide_state {
+                TideState::Rising => "rising",
+                TideState::Falling => "falling",
+                TideState::High => "high",
+                TideState::Low => "low",
+            };
+            format!("{:.1}m, {}", tides.current_height, state)
+        }
+        None => "&ndash;".to_string(),
+    }
+}
+
+fn water_badge(conditions: Option<&BeachConditions>) -> String {
+    let status = conditions
+        .and_then(|c| c.water_quality.as_ref())
+        .map(|wq| wq.effective_status())
+        .unwrap_or(WaterStatus::Unknown);
+    let (class, label) = match status {
+        WaterStatus::Safe => ("safe", "Safe"),
+        WaterStatus::Advisory => ("advisory", "Advisory"),
+        WaterStatus::Closed => ("closed", "Closed"),
+        WaterStatus::Unknown => ("unknown", "Unknown"),
+    };
+    format!("<span class=\"{}\">{}</span>", class, label)
+}
+
+/// Escapes text for use in HTML element content and attribute values
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{get_beach_by_id, WaterQuality, Weather, WeatherCondition, WeatherProvider};
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+    use tempfile::TempDir;
+
+    fn afternoon() -> DateTime<Local> {
+        let day = NaiveDate::from_ymd_opt(2026, 7, 15).unwrap();
+        Local
+            .from_local_datetime(&day.and_hms_opt(14, 0, 0).unwrap())
+            .earliest()
+            .unwrap()
+    }
+
+    fn create_conditions() -> BeachConditions {
+        BeachConditions {
+            beach: all_beaches()[0].clone(),
+            weather: Some(Weather {
+                temperature: 24.0,
+                feels_like: 25.0,
+                condition: WeatherCondition::Clear,
+                humidity: 55,
+                wind: 8.0,
+                wind_direction: None,
+                uv: 6.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
+            }),
+            tides: None,
+            water_quality: Some(WaterQuality {
+                status: WaterStatus::Advisory,
+                ecoli_count: Some(300),
+                sample_date: Local::now().date_naive(),
+                advisory_reason: None,
+                fetched_at: Utc::now(),
+                no_samples_station: None,
+                sampling: None,
+            }),
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    #[test]
+    fn test_export_writes_index_and_beach_pages() {
+        let temp_dir = TempDir::new().unwrap();
+        let out = temp_dir.path().join("site");
+        let conditions = create_conditions();
+        let first_id = conditions.beach.id.clone();
+
+        let pages = export_site(
+            &out,
+            |id| (id == first_id.as_str()).then_some(&conditions),
+            None,
+            afternoon(),
+        )
+        .unwrap();
+
+        assert_eq!(pages, all_beaches().len() + 1);
+        let index = fs::read_to_string(out.join("index.html")).unwrap();
+        for beach in all_beaches() {
+            assert!(index.contains(&format!("href=\"{}.html\"", beach.id)));
+            assert!(out.join(format!("{}.html", beach.id)).exists());
+        }
+        assert!(index.contains("24&deg;C, clear"));
+        assert!(index.contains("<span class=\"advisory\">Advisory</span>"));
+        assert!(index.contains("Generated 2026-07-15 2:00 PM"));
+        assert!(index.contains("<th>Best right now</th>"));
+    }
+
+    #[test]
+    fn test_weather_summary_uses_the_wmo_description() {
+        let mut conditions = create_conditions();
+        let weather = conditions.weather.as_mut().unwrap();
+        weather.condition = WeatherCondition::Rain;
+        weather.weather_code = Some(51);
+
+        assert_eq!(
+            weather_summary(Some(&conditions)),
+            "24&deg;C, light drizzle"
+        );
+    }
+
+    #[test]
+    fn test_index_for_one_activity_scores_only_that_activity() {
+        let conditions = create_conditions();
+        let first_id = conditions.beach.id.clone();
+        let index = render_index(
+            &|id: &str| (id == first_id.as_str()).then_some(&conditions),
+            Some(Activity::Sailing),
+            afternoon(),
+        );
+
+        assert!(index.contains("<th>Sailing right now</th>"));
+        let score = score_now(&conditions, Activity::Sailing, afternoon()).unwrap();
+        assert!(index.contains(&format!("<td>Sailing ({})</td>", score)));
+    }
+
+    #[test]
+    fn test_beach_page_lists_best_windows() {
+        let conditions = create_conditions();
+        let html = render_beach_page(&conditions.beach, Some(&conditions), afternoon());
+
+        assert!(html.contains("<h1>Kitsilano Beach</h1>"));
+        assert!(html.contains("Best windows today"));
+        for activity in Activity::all() {
+            assert!(html.contains(&escape_html(activity.label())));
+        }
+        assert!(html.contains("Peace &amp; Quiet"));
+        assert!(html.contains("5:30 AM &ndash; 9:00 PM"));
+
+        let empty = render_beach_page(&conditions.beach, None, afternoon());
+        assert!(!empty.contains("Sun</th>"));
+    }
+
+    #[test]
+    fn test_beach_page_lists_known_hazards() {
+        let conditions = create_conditions();
+        let html = render_beach_page(&conditions.beach, Some(&conditions), afternoon());
+        assert!(html.contains("<h2>Hazards</h2>"));
+        assert!(html.contains("<li><strong>Currents</strong>: Outflow currents"));
+
+        let trout_lake = get_beach_by_id("trout-lake").unwrap();
+        let html = render_beach_page(trout_lake, None, afternoon());
+        assert!(!html.contains("Hazards"));
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<Tom & \"Jerry's\">"),
+            "&lt;Tom &amp; &quot;Jerry&#39;s&quot;&gt;"
+        );
+    }
+}
@@ -0,0 +1,254 @@
+//! One-line beach status for terminal status bars
+//!
+//! `vanbeach statusline --beach kitsilano` prints a single short line, e.g.
+//! "Kitsilano Beach 22°C W 12km/h Q:Safe T:2.5m↑", for tmux, polybar and
+//! the like. With `--staleness` each field from data past its source's
+//! stale age gets a `*` (a `!` past its very stale age), and the command
+//! exits with `VERY_STALE_EXIT_CODE` if any source is very stale, so a status
+//! bar can tell at a glance whether to trust what it shows.
+//!
+//! Weather and tides age from when they were fetched; water quality ages
+//! from when the sample was taken, as in the detail view.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::data::weather::degrees_to_direction;
+use crate::data::{BeachConditions, TideState};
+
+/// Exit code when `--staleness` finds a very stale source
+pub const VERY_STALE_EXIT_CODE: i32 = 3;
+
+/// Ages past which fetched data is stale and very stale
+struct SourceTtl {
+    stale: Duration,
+    very_stale: Duration,
+}
+
+/// Weather is refreshed every 15 minutes in the TUI, so hours is a lot
+const WEATHER_TTL: SourceTtl = SourceTtl {
+    stale: Duration::hours(3),
+    very_stale: Duration::hours(12),
+};
+
+/// Tide predictions are cached for a day and cover several
+const TIDES_TTL: SourceTtl = SourceTtl {
+    stale: Duration::hours(24),
+    very_stale: Duration::hours(72),
+};
+
+/// How far a field's data can be trusted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Staleness {
+    #[default]
+    Fresh,
+    Stale,
+    VeryStale,
+}
+
+impl Staleness {
+    /// Staleness of data fetched at `fetched_at` under `ttl`
+    fn of(fetched_at: DateTime<Utc>, now: DateTime<Utc>, ttl: &SourceTtl) -> Self {
+        let age = now - fetched_at;
+        if age > ttl.very_stale {
+            Staleness::VeryStale
+        } else if age > ttl.stale {
+            Staleness::Stale
+        } else {
+            Staleness::Fresh
+        }
+    }
+
+    /// Marker appended to a field: nothing, `*` or `!`
+    fn marker(self) -> &'static str {
+        match self {
+            Staleness::Fresh => "",
+            Staleness::Stale => "*",
+            Staleness::VeryStale => "!",
+        }
+    }
+}
+
+/// A status line and the stalest source behind it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusLine {
+    /// The line to print
+    pub text: String,
+    /// Stalest of the sources shown
+    pub staleness: Staleness,
+}
+
+/// Builds the status line for a beach's conditions
+///
+/// Sources that haven't loaded show as "--". With `staleness`, fields get
+/// their source's marker.
+pub fn status_line(
+    conditions: &BeachConditions,
+    now: DateTime<Utc>,
+    staleness: bool,
+) -> StatusLine {
+    let mut fields = vec![conditions.beach.name.to_string()];
+    let mut stalest = Staleness::Fresh;
+    let mut push = |field: String, age: Staleness| {
+        stalest = stalest.max(age);
+        if staleness {
+            fields.push(format!("{}{}", field, age.marker()));
+        } else {
+            fields.push(field);
+        }
+    };
+
+    match &conditions.weather {
+        Some(weather) => {
+            let age = Staleness::of(weather.fetched_at, now, &WEATHER_TTL);
+            push(format!("{:.0}°C", weather.temperature), age);
+            let direction = weather
+                .wind_direction
+                .map(|degrees| format!("{} ", degrees_to_direction(degrees)))
+                .unwrap_or_default();
+            push(format!("{}{:.0}km/h", direction, weather.wind), age);
+        }
+        None => push("--".to_string(), Staleness::Fresh),
+    }
+
+    match &conditions.water_quality {
+        Some(wq) => {
+            let age = if wq.is_very_stale() {
+                Staleness::VeryStale
+            } else if wq.is_stale() {
+                Staleness::Stale
+            } else {
+                Staleness::Fresh
+            };
+            // The raw status, so the marker says why it can't be trusted
+            push(format!("Q:{:?}", wq.status), age);
+        }
+        None => push("Q:--".to_string(), Staleness::Fresh),
+    }
+
+    match &conditions.tides {
+        Some(tides) => {
+            let arrow = match tides.tide_state {
+                TideState::Rising => "↑",
+                TideState::Falling => "↓",
+                _ => "",
+            };
+            push(
+                format!("T:{:.1}m{}", tides.current_height, arrow),
+                Staleness::of(tides.fetched_at, now, &TIDES_TTL),
+            );
+        }
+        None => push("T:--".to_string(), Staleness::Fresh),
+    }
+
+    StatusLine {
+        text: fields.join(" "),
+        staleness: stalest,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{
+        all_beaches, TideInfo, WaterQuality, WaterStatus, Weather, WeatherCondition,
+        WeatherProvider,
+    };
+    use chrono::{Local, NaiveTime, TimeZone};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, 15, 20, 0, 0).unwrap()
+    }
+
+    fn conditions(weather_age: Duration, tides_age: Duration, sample_days: i64) -> BeachConditions {
+        BeachConditions {
+            beach: all_beaches()[0].clone(),
+            weather: Some(Weather {
+                temperature: 22.4,
+                feels_like: 23.0,
+                condition: WeatherCondition::Clear,
+                humidity: 60,
+                wind: 12.0,
+                wind_direction: Some(270.0),
+                uv: 6.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 15, 0).unwrap(),
+                fetched_at: now() - weather_age,
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
+            }),
+            tides: Some(TideInfo {
+                current_height: 2.54,
+                tide_state: TideState::Rising,
+                next_high: None,
+                next_low: None,
+                fetched_at: now() - tides_age,
+            }),
+            water_quality: Some(WaterQuality {
+                status: WaterStatus::Safe,
+                ecoli_count: Some(20),
+                sample_date: Local::now().date_naive() - Duration::days(sample_days),
+                advisory_reason: None,
+                fetched_at: now(),
+                no_samples_station: None,
+                sampling: None,
+            }),
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    #[test]
+    fn test_fresh_data_has_no_markers() {
+        let conditions = conditions(Duration::minutes(10), Duration::hours(1), 1);
+        let line = status_line(&conditions, now(), true);
+        assert_eq!(
+            line.text,
+            format!("{} 22°C W 12km/h Q:Safe T:2.5m↑", conditions.beach.name)
+        );
+        assert_eq!(line.staleness, Staleness::Fresh);
+    }
+
+    #[test]
+    fn test_each_source_is_marked_by_its_own_age() {
+        let conditions = conditions(Duration::hours(4), Duration::hours(80), 4);
+        let line = status_line(&conditions, now(), true);
+        assert!(
+            line.text.ends_with(" 22°C* W 12km/h* Q:Safe* T:2.5m↑!"),
+            "{}",
+            line.text
+        );
+        assert_eq!(line.staleness, Staleness::VeryStale);
+
+        let line = status_line(&conditions, now(), false);
+        assert!(
+            line.text.ends_with(" 22°C W 12km/h Q:Safe T:2.5m↑"),
+            "{}",
+            line.text
+        );
+        assert_eq!(line.staleness, Staleness::VeryStale, "tracked either way");
+    }
+
+    #[test]
+    fn test_old_sample_is_very_stale() {
+        let conditions = conditions(Duration::zero(), Duration::zero(), 10);
+        let line = status_line(&conditions, now(), true);
+        assert!(line.text.contains("Q:Safe!"), "{}", line.text);
+        assert_eq!(line.staleness, Staleness::VeryStale);
+    }
+
+    #[test]
+    fn test_missing_sources_show_dashes() {
+        let mut conditions = conditions(Duration::zero(), Duration::zero(), 0);
+        conditions.weather = None;
+        conditions.tides = None;
+        conditions.water_quality = None;
+        let line = status_line(&conditions, now(), true);
+        assert_eq!(line.text, format!("{} -- Q:-- T:--", conditions.beach.name));
+        assert_eq!(line.staleness, Staleness::Fresh);
+    }
+}
@@ -0,0 +1,308 @@
+//! Solar position and shade calculations
+//!
+//! This module computes the sun's elevation and azimuth for a location and time
+//! using the NOAA low-precision solar position formulas, and combines them with
+//! per-beach shade profiles to estimate when the sand falls into shade.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, Timelike, Utc};
+
+use crate::data::Beach;
+
+/// Step used when scanning forward in time for shade changes
+const SHADE_SCAN_STEP_MINUTES: i64 = 5;
+
+/// Maximum lookahead when scanning for shade (the rest of the day)
+const SHADE_SCAN_HORIZON_HOURS: i64 = 18;
+
+/// Position of the sun in the sky
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarPosition {
+    /// Angle above the horizon in degrees (negative when the sun has set)
+    pub elevation: f64,
+    /// Compass bearing in degrees clockwise from north
+    pub azimuth: f64,
+}
+
+/// What the shade situation looks like for the rest of the day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShadeOutlook {
+    /// The beach is in sun now and shade arrives at the given time
+    Arrives(DateTime<Local>),
+    /// The beach is currently shaded while the sun is still up
+    ShadedNow,
+}
+
+/// Computes the sun's position for a latitude/longitude at the given instant
+///
+/// # Arguments
+/// * `latitude` - Latitude in degrees (north positive)
+/// * `longitude` - Longitude in degrees (east positive)
+/// * `when` - The instant to compute the position for
+pub fn solar_position(latitude: f64, longitude: f64, when: DateTime<Utc>) -> SolarPosition {
+    // Days since the J2000.0 epoch
+    let julian_day = when.timestamp() as f64 / 86_400.0 + 2_440_587.5;
+    let n = julian_day - 2_451_545.0;
+
+    // Mean longitude and mean anomaly of the sun
+    let mean_longitude = (280.460 + 0.985_647_4 * n).rem_euclid(360.0);
+    let mean_anomaly = (357.528 + 0.985_600_3 * n).rem_euclid(360.0).to_radians();
+
+    // Ecliptic longitude and obliquity of the ecliptic
+    let ecliptic_longitude =
+        (mean_longitude + 1.915 * mean_anomaly.sin() + 0.020 * (2.0 * mean_anomaly).sin())
+            .to_radians();
+    let obliquity = (23.439 - 0.000_000_4 * n).to_radians();
+
+    // Right ascension and declination
+    let right_ascension = (obliquity.cos() * ecliptic_longitude.sin())
+        .atan2(ecliptic_longitude.cos())
+        .to_degrees();
+    let declination = (obliquity.sin() * ecliptic_longitude.sin()).asin();
+
+    // Local sidereal time and hour angle
+    let gmst_hours = (18.697_374_558 + 24.065_709_824_419_08 * n).rem_euclid(24.0);
+    let hour_angle = (gmst_hours * 15.0 + longitude - right_ascension).to_radians();
+
+    let lat = latitude.to_radians();
+    let elevation =
+        (lat.sin() * declination.sin() + lat.cos() * declination.cos() * hour_angle.cos()).asin();
+    let azimuth = (-hour_angle.sin())
+        .atan2(lat.cos() * declination.tan() - lat.sin() * hour_angle.cos())
+        .to_degrees()
+        .rem_euclid(360.0);
+
+    SolarPosition {
+        elevation: elevation.to_degrees(),
+        azimuth,
+    }
+}
+
+/// Returns true if the beach is shaded at the given instant
+///
+/// Beaches without a shade profile are never shaded. A sun below the horizon
+/// is treated as "no sun" rather than shade.
+#[allow(dead_code)]
+pub fn is_shaded(beach: &Beach, when: DateTime<Utc>) -> bool {
+    let Some(profile) = &beach.shade_profile else {
+        return false;
+    };
+    let pos = solar_position(beach.latitude, beach.longitude, when);
+    profile.blocks(pos.azimuth, pos.elevation)
+}
+
+/// Returns how much direct sun reaches the sand at an instant (0.0 or 1.0)
+pub fn sun_exposure(beach: &Beach, when: DateTime<Utc>) -> f32 {
+    let pos = solar_position(beach.latitude, beach.longitude, when);
+    if pos.elevation <= 0.0 {
+        return 0.0;
+    }
+    match &beach.shade_profile {
+        Some(profile) if profile.blocks(pos.azimuth, pos.elevation) => 0.0,
+        _ => 1.0,
+    }
+}
+
+/// Returns the fraction of the given hour, on the beach's clock, that the beach
+/// is in direct sun
+///
+/// Samples the sun at 15-minute intervals through the hour. Returns 0.0 when
+/// the sun is down or hidden for the whole hour, 1.0 when it is fully exposed.
+pub fn sun_exposure_for_hour(beach: &Beach, date: NaiveDate, hour: u8) -> f32 {
+    let samples: Vec<f32> = [0, 15, 30, 45]
+        .iter()
+        .filter_map(|minute| date.and_hms_opt(hour as u32, *minute, 0))
+        .map(|naive| sun_exposure(beach, beach.timezone.to_utc(naive)))
+        .collect();
+
+    if samples.is_empty() {
+        return 1.0;
+    }
+    samples.iter().sum::<f32>() / samples.len() as f32
+}
+
+/// Works out when shade next reaches the beach, starting from `now`
+///
+/// Returns `None` if the beach has no shade profile, the sun is already down,
+/// or the sun sets before any obstruction blocks it.
+pub fn shade_outlook(beach: &Beach, now: DateTime<Local>) -> Option<ShadeOutlook> {
+    let profile = beach.shade_profile.as_ref()?;
+
+    let position_at =
+        |t: DateTime<Local>| solar_position(beach.latitude, beach.longitude, t.with_timezone(&Utc));
+
+    let current = position_at(now);
+    if current.elevation <= 0.0 {
+        return None;
+    }
+    if profile.blocks(current.azimuth, current.elevation) {
+        return Some(ShadeOutlook::ShadedNow);
+    }
+
+    let steps = SHADE_SCAN_HORIZON_HOURS * 60 / SHADE_SCAN_STEP_MINUTES;
+    for step in 1..=steps {
+        let t = now + Duration::minutes(step * SHADE_SCAN_STEP_MINUTES);
+        let pos = position_at(t);
+        if pos.elevation <= 0.0 {
+            // Sun set before anything blocked it
+            return None;
+        }
+        if profile.blocks(pos.azimuth, pos.elevation) {
+            return Some(ShadeOutlook::Arrives(round_to_five_minutes(t)));
+        }
+    }
+
+    None
+}
+
+/// Rounds a time to the nearest five minutes for "approximately" displays
+fn round_to_five_minutes(t: DateTime<Local>) -> DateTime<Local> {
+    let minute = t.minute() as i64;
+    let rounded = ((minute + 2) / 5) * 5;
+    let base = t
+        .with_second(0)
+        .and_then(|t| t.with_nanosecond(0))
+        .unwrap_or(t);
+    base + Duration::minutes(rounded - minute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{BeachId, ShadeObstruction, ShadeProfile};
+    use chrono::TimeZone;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn test_beach(profile: Option<ShadeProfile>) -> Beach {
+        let beach = Beach::new(BeachId::new("test"), "Test Beach", 49.2743, -123.1544, None);
+        match profile {
+            Some(p) => beach.with_shade_profile(p),
+            None => beach,
+        }
+    }
+
+    #[test]
+    fn test_solar_noon_summer_vancouver_is_high_and_south() {
+        // Solar noon in Vancouver is around 20:15 UTC in late June
+        let pos = solar_position(49.2743, -123.1544, utc(2026, 6, 21, 20, 15));
+        assert!(
+            (pos.elevation - 64.2).abs() < 1.5,
+            "Expected ~64° elevation, got {}",
+            pos.elevation
+        );
+        assert!(
+            (pos.azimuth - 180.0).abs() < 10.0,
+            "Expected sun roughly south, got {}",
+            pos.azimuth
+        );
+    }
+
+    #[test]
+    fn test_winter_noon_sun_is_low() {
+        let pos = solar_position(49.2743, -123.1544, utc(2026, 12, 21, 20, 10));
+        assert!(
+            (pos.elevation - 17.3).abs() < 1.5,
+            "Expected ~17° elevation, got {}",
+            pos.elevation
+        );
+    }
+
+    #[test]
+    fn test_sun_below_horizon_at_night() {
+        // Local midnight in summer
+        let pos = solar_position(49.2743, -123.1544, utc(2026, 6, 21, 7, 0));
+        assert!(pos.elevation < 0.0);
+    }
+
+    #[test]
+    fn test_morning_sun_is_in_the_east() {
+        let pos = solar_position(49.2743, -123.1544, utc(2026, 6, 21, 15, 0));
+        assert!(pos.elevation > 0.0);
+        assert!(
+            pos.azimuth > 45.0 && pos.azimuth < 135.0,
+            "Morning sun should be easterly, got {}",
+            pos.azimuth
+        );
+    }
+
+    #[test]
+    fn test_beach_without_profile_is_never_shaded() {
+        let beach = test_beach(None);
+        assert!(!is_shaded(&beach, utc(2026, 6, 21, 20, 15)));
+        assert_eq!(sun_exposure(&beach, utc(2026, 6, 21, 20, 15)), 1.0);
+    }
+
+    #[test]
+    fn test_exposure_zero_when_sun_is_down() {
+        let beach = test_beach(None);
+        assert_eq!(sun_exposure(&beach, utc(2026, 6, 21, 7, 0)), 0.0);
+    }
+
+    #[test]
+    fn test_full_sky_obstruction_shades_whenever_sun_is_up() {
+        let beach = test_beach(Some(ShadeProfile {
+            obstructions: vec![ShadeObstruction {
+                azimuth_from: 0.0,
+                azimuth_to: 360.0,
+                elevation: 90.0,
+            }],
+        }));
+        assert!(is_shaded(&beach, utc(2026, 6, 21, 20, 15)));
+        assert_eq!(sun_exposure(&beach, utc(2026, 6, 21, 20, 15)), 0.0);
+    }
+
+    #[test]
+    fn test_shade_outlook_finds_evening_shade() {
+        // Trees to the west block the sun once it drops below 20°
+        let beach = test_beach(Some(ShadeProfile {
+            obstructions: vec![ShadeObstruction {
+                azimuth_from: 200.0,
+                azimuth_to: 340.0,
+                elevation: 20.0,
+            }],
+        }));
+        let now = utc(2026, 6, 21, 20, 0).with_timezone(&Local);
+
+        match shade_outlook(&beach, now) {
+            Some(ShadeOutlook::Arrives(t)) => {
+                assert!(t > now, "Shade should arrive after now");
+                assert_eq!(t.minute() % 5, 0, "Arrival should be rounded");
+                let pos = solar_position(beach.latitude, beach.longitude, t.with_timezone(&Utc));
+                assert!(pos.elevation < 22.0 && pos.elevation > 0.0);
+            }
+            other => panic!("Expected shade arrival, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_shade_outlook_none_without_profile() {
+        let beach = test_beach(None);
+        let now = utc(2026, 6, 21, 20, 0).with_timezone(&Local);
+        assert!(shade_outlook(&beach, now).is_none());
+    }
+
+    #[test]
+    fn test_shade_outlook_shaded_now() {
+        let beach = test_beach(Some(ShadeProfile {
+            obstructions: vec![ShadeObstruction {
+                azimuth_from: 0.0,
+                azimuth_to: 360.0,
+                elevation: 90.0,
+            }],
+        }));
+        let now = utc(2026, 6, 21, 20, 0).with_timezone(&Local);
+        assert_eq!(shade_outlook(&beach, now), Some(ShadeOutlook::ShadedNow));
+    }
+
+    #[test]
+    fn test_sun_exposure_for_hour_is_fractional_range() {
+        let beach = test_beach(None);
+        let date = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+        for hour in 0..24u8 {
+            let exposure = sun_exposure_for_hour(&beach, date, hour);
+            assert!((0.0..=1.0).contains(&exposure));
+        }
+    }
+}
@@ -0,0 +1,416 @@
+//! Swim Safety Index
+//!
+//! Combines water quality, water temperature, tidal currents, wind chop and
+//! lifeguard coverage into a single 0-100 index for swimming at a beach. Each
+//! factor is scored separately and kept in the result so the detail view can
+//! show exactly how the index was reached. A closed beach caps the index no
+//! matter how good everything else is.
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+
+use crate::data::{BeachConditions, TideState, WaterBody, WaterStatus};
+
+/// Highest index a beach closed for swimming can get
+const CLOSED_CAP: u8 = 20;
+
+/// Lifeguards go on duty at 11:30 in season
+const LIFEGUARD_START: (u32, u32) = (11, 30);
+
+/// Lifeguards go off duty at 20:30 in season
+const LIFEGUARD_END: (u32, u32) = (20, 30);
+
+/// One factor of the index
+#[derive(Debug, Clone, PartialEq)]
+pub struct SafetyFactor {
+    /// Short name shown in the breakdown
+    pub name: &'static str,
+    /// Factor score from 0 (unsafe) to 100 (safe)
+    pub score: u8,
+    /// Share of the index this factor carries (weights sum to 1.0)
+    pub weight: f32,
+    /// What the score is based on, e.g. "~17°C (seasonal estimate)"
+    pub detail: String,
+}
+
+/// The combined index and the factors it was built from
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwimSafetyIndex {
+    /// Weighted index from 0 to 100
+    pub score: u8,
+    /// Per-factor breakdown, in display order
+    pub factors: Vec<SafetyFactor>,
+    /// Whether the index was capped because the beach is closed for swimming
+    pub capped: bool,
+}
+
+impl SwimSafetyIndex {
+    /// One-word rating for the index
+    pub fn label(&self) -> &'static str {
+        match self.score {
+            80..=100 => "Good",
+            60..=79 => "Fair",
+            40..=59 => "Caution",
+            _ => "Unsafe",
+        }
+    }
+}
+
+/// Computes the Swim Safety Index for a beach at `now`
+///
+/// Missing data scores in the middle of the range so it neither hides nor
+/// invents a hazard; the breakdown says which factors were unknown.
+pub fn swim_safety_index(conditions: &BeachConditions, now: DateTime<Local>) -> SwimSafetyIndex {
+    let beach_now = conditions.beach.timezone.local(now.with_timezone(&Utc));
+    let status = conditions
+        .water_quality
+        .as_ref()
+        .map(|wq| wq.effective_status())
+        .unwrap_or(WaterStatus::Unknown);
+
+    let factors = vec![
+        water_quality_factor(status),
+        water_temperature_factor(conditions.beach.water_body, beach_now.month()),
+        current_factor(conditions),
+        wind_chop_factor(conditions.effective_wind()),
+        lifeguard_factor(conditions.beach.lifeguarded, beach_now),
+    ];
+
+    let weighted: f32 = factors.iter().map(|f| f.score as f32 * f.weight).sum();
+    let mut score = weighted.round().clamp(0.0, 100.0) as u8;
+    let capped = status == WaterStatus::Closed && score > CLOSED_CAP;
+    if capped {
+        score = CLOSED_CAP;
+    }
+
+    SwimSafetyIndex {
+        score,
+        factors,
+        capped,
+    }
+}
+
+fn water_quality_factor(status: WaterStatus) -> SafetyFactor {
+    let (score, detail) = match status {
+        WaterStatus::Safe => (100, "Safe to swim"),
+        WaterStatus::Advisory => (40, "Advisory in effect"),
+        WaterStatus::Closed => (0, "Closed to swimming"),
+        WaterStatus::Unknown => (50, "No recent sample"),
+    };
+    SafetyFactor {
+        name: "Water quality",
+        score,
+        weight: 0.35,
+        detail: detail.to_string(),
+    }
+}
+
+/// Scores cold-shock risk from the typical water temperature for the month
+fn water_temperature_factor(water_body: WaterBody, month: u32) -> SafetyFactor {
+    let temperature = estimated_water_temperature(water_body, month);
+    // 10°C and below is cold-shock territory; 18°C and up is comfortable
+    let comfort = ((temperature - 10.0) / 8.0).clamp(0.0, 1.0);
+    SafetyFactor {
+        name: "Water temp",
+        score: (20.0 + comfort * 80.0).round() as u8,
+        weight: 0.20,
+        detail: format!("~{:.0}\u{00B0}C (seasonal estimate)", temperature),
+    }
+}
+
+/// Typical surface water temperature (°C) for a month
+///
+/// English Bay monthly means; Trout Lake is shallow and runs colder in winter
+/// and warmer in summer.
+pub fn estimated_water_temperature(water_body: WaterBody, month: u32) -> f64 {
+    const OCEAN: [f64; 12] = [
+        7.5, 7.5, 8.0, 9.5, 12.0, 15.0, 17.0, 17.5, 15.0, 12.0, 10.0, 8.5,
+    ];
+    const LAKE: [f64; 12] = [
+        4.0, 4.5, 7.0, 11.0, 16.0, 20.0, 23.0, 22.5, 18.5, 13.0, 8.0, 5.0,
+    ];
+    let index = (month.clamp(1, 12) - 1) as usize;
+    match water_body {
+        WaterBody::Ocean => OCEAN[index],
+        WaterBody::Lake => LAKE[index],
+    }
+}
+
+/// Scores tidal current from the range of the tide currently running
+///
+/// Currents are weakest at slack water (high or low) and strongest mid-tide
+/// on a big range.
+fn current_factor(conditions: &BeachConditions) -> SafetyFactor {
+    let (score, detail) = if conditions.beach.water_body == WaterBody::Lake {
+        (100, "No tidal current".to_string())
+    } else {
+        match &conditions.tides {
+            None => (60, "Tide data unavailable".to_string()),
+            Some(tides) => match tides.tide_state {
+                TideState::High | TideState::Low => (100, "Slack water".to_string()),
+                TideState::Rising | TideState::Falling => {
+                    let range = match (&tides.next_high, &tides.next_low) {
+                        (Some(high), Some(low)) => (high.height - low.height).abs(),
+                        _ => 3.0,
+                    };
+                    let direction = if tides.tide_state == TideState::Rising {
+                        "Flooding"
+                    } else {
+                        "Ebbing"
+                    };
+                    (
+                        (100.0 - range * 15.0).clamp(20.0, 100.0).round() as u8,
+                        format!("{}, {:.1}m range", direction, range),
+                    )
+                }
+            },
+        }
+    };
+    SafetyFactor {
+        name: "Currents",
+        score,
+        weight: 0.15,
+        detail,
+    }
+}
+
+/// Scores wind-driven chop from the wind felt on the beach (km/h)
+fn wind_chop_factor(wind: Option<f64>) -> SafetyFactor {
+    let (score, detail) = match wind {
+        None => (60, "Wind unknown".to_string()),
+        Some(wind) => {
+            let label = match wind {
+                w if w < 10.0 => "Calm",
+                w if w < 20.0 => "Light chop",
+                w if w < 30.0 => "Choppy",
+                _ => "Rough",
+            };
+            (
+                (100.0 - (wind - 10.0).max(0.0) * 100.0 / 30.0)
+                    .clamp(0.0, 100.0)
+                    .round() as u8,
+                format!("{} ({:.0} km/h)", label, wind),
+            )
+        }
+    };
+    SafetyFactor {
+        name: "Wind chop",
+        score,
+        weight: 0.15,
+        detail,
+    }
+}
+
+fn lifeguard_factor(lifeguarded: bool, beach_now: NaiveDateTime) -> SafetyFactor {
+    let (score, detail) = if !lifeguarded {
+        (20, "No lifeguard service".to_string())
+    } else if lifeguards_on_duty(beach_now) {
+        (
+            100,
+            format!(
+                "On duty until {:02}:{:02}",
+                LIFEGUARD_END.0, LIFEGUARD_END.1
+            ),
+        )
+    } else if in_lifeguard_season(beach_now.date()) {
+        (
+            40,
+            format!(
+                "Off duty (on {:02}:{:02}\u{2013}{:02}:{:02})",
+                LIFEGUARD_START.0, LIFEGUARD_START.1, LIFEGUARD_END.0, LIFEGUARD_END.1
+            ),
+        )
+    } else {
+        (40, "Out of season".to_string())
+    };
+    SafetyFactor {
+        name: "Lifeguards",
+        score,
+        weight: 0.15,
+        detail,
+    }
+}
+
+/// Whether lifeguards are on duty at a beach-local time in season
+fn lifeguards_on_duty(beach_now: NaiveDateTime) -> bool {
+    let start = NaiveTime::from_hms_opt(LIFEGUARD_START.0, LIFEGUARD_START.1, 0);
+    let end = NaiveTime::from_hms_opt(LIFEGUARD_END.0, LIFEGUARD_END.1, 0);
+    match (start, end) {
+        (Some(start), Some(end)) => {
+            in_lifeguard_season(beach_now.date())
+                && beach_now.time() >= start
+                && beach_now.time() < end
+        }
+        _ => false,
+    }
+}
+
+/// The lifeguard season runs from Victoria Day to Labour Day inclusive
+fn in_lifeguard_season(date: NaiveDate) -> bool {
+    let year = date.year();
+    // Victoria Day is the last Monday before May 25
+    let victoria_day = NaiveDate::from_ymd_opt(year, 5, 24).map(|may_24| {
+        may_24 - chrono::Duration::days(may_24.weekday().num_days_from_monday() as i64)
+    });
+    let labour_day = NaiveDate::from_weekday_of_month_opt(year, 9, Weekday::Mon, 1);
+    match (victoria_day, labour_day) {
+        (Some(start), Some(end)) => date >= start && date <= end,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{
+        get_beach_by_id, TideEvent, TideInfo, WaterQuality, Weather, WeatherCondition,
+        WeatherProvider,
+    };
+    use chrono::TimeZone;
+
+    /// 14:00 on a July Wednesday at the beach (PDT)
+    fn july_afternoon() -> DateTime<Local> {
+        Utc.with_ymd_and_hms(2026, 7, 15, 21, 0, 0)
+            .unwrap()
+            .with_timezone(&Local)
+    }
+
+    fn create_conditions(beach_id: &str, status: WaterStatus) -> BeachConditions {
+        BeachConditions {
+            beach: get_beach_by_id(beach_id).unwrap().clone(),
+            weather: Some(Weather {
+                temperature: 24.0,
+                feels_like: 25.0,
+                condition: WeatherCondition::Clear,
+                humidity: 55,
+                wind: 8.0,
+                wind_direction: None,
+                uv: 6.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
+            }),
+            tides: Some(TideInfo {
+                current_height: 2.5,
+                tide_state: TideState::High,
+                next_high: None,
+                next_low: None,
+                fetched_at: Utc::now(),
+            }),
+            water_quality: Some(WaterQuality {
+                status,
+                ecoli_count: Some(20),
+                sample_date: Local::now().date_naive(),
+                advisory_reason: None,
+                fetched_at: Utc::now(),
+                no_samples_station: None,
+                sampling: None,
+            }),
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    #[test]
+    fn test_summer_guarded_beach_scores_well() {
+        let index = swim_safety_index(
+            &create_conditions("kitsilano", WaterStatus::Safe),
+            july_afternoon(),
+        );
+
+        assert_eq!(index.factors.len(), 5);
+        let total: f32 = index.factors.iter().map(|f| f.weight).sum();
+        assert!((total - 1.0).abs() < 0.001);
+        assert!(index.score >= 80, "score {}", index.score);
+        assert_eq!(index.label(), "Good");
+        assert!(!index.capped);
+    }
+
+    #[test]
+    fn test_closed_beach_is_capped() {
+        let index = swim_safety_index(
+            &create_conditions("kitsilano", WaterStatus::Closed),
+            july_afternoon(),
+        );
+        assert_eq!(index.score, CLOSED_CAP);
+        assert!(index.capped);
+        assert_eq!(index.label(), "Unsafe");
+    }
+
+    #[test]
+    fn test_unguarded_beach_scores_lower() {
+        let guarded = swim_safety_index(
+            &create_conditions("kitsilano", WaterStatus::Safe),
+            july_afternoon(),
+        );
+        let unguarded = swim_safety_index(
+            &create_conditions("wreck", WaterStatus::Safe),
+            july_afternoon(),
+        );
+        assert!(unguarded.score < guarded.score);
+        assert_eq!(unguarded.factors[4].detail, "No lifeguard service");
+    }
+
+    #[test]
+    fn test_big_running_tide_and_wind_lower_their_factors() {
+        let mut conditions = create_conditions("jericho", WaterStatus::Safe);
+        let tides = conditions.tides.as_mut().unwrap();
+        tides.tide_state = TideState::Falling;
+        tides.next_high = Some(TideEvent {
+            time: Local::now(),
+            height: 4.6,
+        });
+        tides.next_low = Some(TideEvent {
+            time: Local::now(),
+            height: 0.4,
+        });
+        conditions.weather.as_mut().unwrap().wind = 35.0;
+
+        let index = swim_safety_index(&conditions, july_afternoon());
+        assert_eq!(index.factors[2].detail, "Ebbing, 4.2m range");
+        assert!(index.factors[2].score < 50);
+        assert!(index.factors[3].detail.starts_with("Rough"));
+        assert!(index.factors[3].score < 20);
+    }
+
+    #[test]
+    fn test_lake_has_no_tidal_current_and_warmer_summer_water() {
+        let index = swim_safety_index(
+            &create_conditions("trout-lake", WaterStatus::Safe),
+            july_afternoon(),
+        );
+        assert_eq!(index.factors[2].detail, "No tidal current");
+        assert!(
+            estimated_water_temperature(WaterBody::Lake, 7)
+                > estimated_water_temperature(WaterBody::Ocean, 7)
+        );
+    }
+
+    #[test]
+    fn test_lifeguard_season_and_hours() {
+        // Victoria Day 2026 is May 18, Labour Day is September 7
+        assert!(!in_lifeguard_season(
+            NaiveDate::from_ymd_opt(2026, 5, 17).unwrap()
+        ));
+        assert!(in_lifeguard_season(
+            NaiveDate::from_ymd_opt(2026, 5, 18).unwrap()
+        ));
+        assert!(in_lifeguard_season(
+            NaiveDate::from_ymd_opt(2026, 9, 7).unwrap()
+        ));
+        assert!(!in_lifeguard_season(
+            NaiveDate::from_ymd_opt(2026, 9, 8).unwrap()
+        ));
+
+        let day = NaiveDate::from_ymd_opt(2026, 7, 15).unwrap();
+        assert!(!lifeguards_on_duty(day.and_hms_opt(11, 0, 0).unwrap()));
+        assert!(lifeguards_on_duty(day.and_hms_opt(11, 30, 0).unwrap()));
+        assert!(!lifeguards_on_duty(day.and_hms_opt(20, 30, 0).unwrap()));
+    }
+}
@@ -0,0 +1,148 @@
+//! Terminal title that follows the screen
+//!
+//! While the TUI runs, the terminal (or tab) title names the screen and, on
+//! the beach list and detail view, the beach with its temperature and water
+//! status, e.g. "vanbeach — Kitsilano Beach 22° Safe", so a background tab
+//! still says how the beach is doing. The title the terminal had is pushed
+//! on start and popped on exit, on terminals that keep a title stack.
+//! `"terminal_title": false` in the config leaves the title alone.
+
+use std::io::{self, Write};
+
+use crossterm::terminal::SetTitle;
+use crossterm::QueueableCommand;
+
+use crate::app::{App, AppState};
+use crate::data::{BeachConditions, WaterStatus};
+
+/// Name every title starts with
+const APP_NAME: &str = "vanbeach";
+
+/// Saves the current title on the terminal's title stack (XTWINOPS)
+const PUSH_TITLE: &[u8] = b"\x1b[22;0t";
+
+/// Restores the title saved by `PUSH_TITLE`
+const POP_TITLE: &[u8] = b"\x1b[23;0t";
+
+/// Sets the terminal title when what it should say changes
+#[derive(Debug, Default)]
+pub struct TerminalTitle {
+    /// Title last set, if any
+    shown: Option<String>,
+}
+
+impl TerminalTitle {
+    /// Saves the terminal's own title so `restore` can put it back
+    pub fn start(out: &mut impl Write) -> io::Result<Self> {
+        out.write_all(PUSH_TITLE)?;
+        out.flush()?;
+        Ok(Self::default())
+    }
+
+    /// Sets the title for the app's screen, if it changed
+    pub fn update(&mut self, app: &App, out: &mut impl Write) -> io::Result<()> {
+        let title = title(app);
+        if self.shown.as_ref() == Some(&title) {
+            return Ok(());
+        }
+        out.queue(SetTitle(&title))?;
+        out.flush()?;
+        self.shown = Some(title);
+        Ok(())
+    }
+}
+
+/// Puts back the title the terminal had before `TerminalTitle::start`
+pub fn restore(out: &mut impl Write) -> io::Result<()> {
+    out.write_all(POP_TITLE)?;
+    out.flush()
+}
+
+/// The title for the app's current screen
+fn title(app: &App) -> String {
+    let screen = match &app.state {
+        AppState::Loading => return APP_NAME.to_string(),
+        AppState::BeachList => match app.selected_beach() {
+            Some(beach) => match app.get_conditions(&beach.id) {
+                Some(conditions) => beach_summary(conditions),
+                None => beach.name.clone(),
+            },
+            None => "Beaches".to_string(),
+        },
+        AppState::BeachDetail(id) => match app.get_conditions(id) {
+            Some(conditions) => beach_summary(conditions),
+            None => crate::data::get_beach_by_id(id)
+                .map(|beach| beach.name.clone())
+                .unwrap_or_else(|| id.to_string()),
+        },
+        AppState::PlanTrip => match app.current_activity {
+            Some(activity) => format!("Plan Trip ({})", activity.label()),
+            None => "Plan Trip".to_string(),
+        },
+        AppState::Dashboard => match app.active_comparison_set() {
+            Some(set) => format!("Dashboard ({})", set.name),
+            None => "Dashboard".to_string(),
+        },
+        AppState::WaterQualityOverview => "Water Quality".to_string(),
+        AppState::Journal => "Journal".to_string(),
+    };
+    format!("{} — {}", APP_NAME, screen)
+}
+
+/// "Kitsilano Beach 22° Safe", leaving out what hasn't loaded
+fn beach_summary(conditions: &BeachConditions) -> String {
+    let mut summary = conditions.beach.name.clone();
+    if let Some(weather) = &conditions.weather {
+        summary.push_str(&format!(" {:.0}°", weather.temperature));
+    }
+    let status = if conditions.closure.is_some() {
+        Some(WaterStatus::Closed)
+    } else {
+        conditions
+            .water_quality
+            .as_ref()
+            .map(|wq| wq.effective_status())
+    };
+    match status {
+        Some(WaterStatus::Safe) => summary.push_str(" Safe"),
+        Some(WaterStatus::Advisory) => summary.push_str(" Advisory"),
+        Some(WaterStatus::Closed) => summary.push_str(" Closed"),
+        Some(WaterStatus::Unknown) | None => {}
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activities::Activity;
+    use crate::data::BeachId;
+
+    #[test]
+    fn test_title_follows_screen() {
+        let mut app = App::new();
+        assert_eq!(title(&app), "vanbeach");
+
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+        assert_eq!(title(&app), "vanbeach — Kitsilano Beach");
+
+        app.state = AppState::PlanTrip;
+        app.current_activity = Some(Activity::Sailing);
+        assert_eq!(title(&app), "vanbeach — Plan Trip (Sailing)");
+    }
+
+    #[test]
+    fn test_update_only_writes_changes() {
+        let mut app = App::new();
+        app.state = AppState::Journal;
+        let mut out = Vec::new();
+        let mut terminal_title = TerminalTitle::start(&mut out).unwrap();
+
+        terminal_title.update(&app, &mut out).unwrap();
+        terminal_title.update(&app, &mut out).unwrap();
+
+        let written = String::from_utf8(out).unwrap();
+        assert!(written.starts_with("\x1b[22;0t"));
+        assert_eq!(written.matches("vanbeach — Journal").count(), 1);
+    }
+}
@@ -0,0 +1,197 @@
+//! Clock format for times shown and exported
+//!
+//! Hours and times are written either on a 12-hour clock ("6AM", "2:05 PM")
+//! or a 24-hour one ("06:00", "14:05"). The format comes from the config's
+//! `clock` setting, or failing that the locale (`LC_ALL`, `LC_TIME`, `LANG`),
+//! and is installed once at startup with `set_clock_format`.
+
+use std::sync::OnceLock;
+
+use chrono::Timelike;
+use serde::{Deserialize, Serialize};
+
+/// Regions whose locales write times on a 12-hour clock
+const TWELVE_HOUR_REGIONS: &[&str] = &["US", "AU", "NZ", "IN", "PH", "PK", "BD", "EG", "SA"];
+
+/// Whether times are written on a 12-hour or 24-hour clock
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClockFormat {
+    /// "6AM", "2:05 PM"
+    #[default]
+    #[serde(rename = "12h")]
+    TwelveHour,
+    /// "06:00", "14:05"
+    #[serde(rename = "24h")]
+    TwentyFourHour,
+}
+
+impl ClockFormat {
+    /// Clock format of the user's locale, 12-hour if it can't be told
+    pub fn from_locale() -> Self {
+        ["LC_ALL", "LC_TIME", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|locale| Self::for_locale(&locale))
+            .unwrap_or_default()
+    }
+
+    /// Clock format of a locale such as "en_CA.UTF-8", if it names a region
+    ///
+    /// English Canada writes 12-hour times and French Canada 24-hour ones.
+    fn for_locale(locale: &str) -> Option<Self> {
+        let name = locale.split(['.', '@']).next()?;
+        let (language, region) = name.split_once('_')?;
+        let twelve_hour =
+            TWELVE_HOUR_REGIONS.contains(&region) || (region == "CA" && language == "en");
+        Some(if twelve_hour {
+            ClockFormat::TwelveHour
+        } else {
+            ClockFormat::TwentyFourHour
+        })
+    }
+
+    /// A time of day, e.g. "2:05 PM" or "14:05"
+    pub fn time<T: Timelike>(self, time: &T) -> String {
+        let (hour, minute) = (time.hour(), time.minute());
+        match self {
+            ClockFormat::TwelveHour => {
+                let (pm, hour) = time.hour12();
+                format!("{}:{:02} {}", hour, minute, if pm { "PM" } else { "AM" })
+            }
+            ClockFormat::TwentyFourHour => format!("{:02}:{:02}", hour, minute),
+        }
+    }
+
+    /// An hour (0-23) as a time, e.g. "3:00 PM" or "15:00"
+    pub fn hour(self, hour: u8) -> String {
+        match self {
+            ClockFormat::TwelveHour => {
+                let (h, period) = twelve_hour(hour);
+                format!("{}:00 {}", h, period)
+            }
+            ClockFormat::TwentyFourHour => format!("{:02}:00", hour),
+        }
+    }
+
+    /// An hour as a short label for axes and headings, e.g. "3PM" or "15:00"
+    pub fn hour_label(self, hour: u8) -> String {
+        match self {
+            ClockFormat::TwelveHour => {
+                let (h, period) = twelve_hour(hour);
+                format!("{}{}", h, period)
+            }
+            ClockFormat::TwentyFourHour => format!("{:02}:00", hour),
+        }
+    }
+
+    /// An hour in at most three characters for dense tables, e.g. "3p",
+    /// "12a" or "15"
+    pub fn hour_compact(self, hour: u8) -> String {
+        match self {
+            ClockFormat::TwelveHour => {
+                let (h, period) = twelve_hour(hour);
+                format!("{}{}", h, &period[..1].to_lowercase())
+            }
+            ClockFormat::TwentyFourHour => hour.to_string(),
+        }
+    }
+}
+
+/// An hour (0-23) on a 12-hour clock with its period, e.g. (3, "PM")
+fn twelve_hour(hour: u8) -> (u8, &'static str) {
+    let period = if hour < 12 { "AM" } else { "PM" };
+    match hour % 12 {
+        0 => (12, period),
+        h => (h, period),
+    }
+}
+
+/// Clock format installed from the config or locale by `set_clock_format`
+static CLOCK_FORMAT: OnceLock<ClockFormat> = OnceLock::new();
+
+/// Installs the clock format for everything shown and exported.
+///
+/// The format can only be installed once; returns false if it was already set.
+pub fn set_clock_format(format: ClockFormat) -> bool {
+    CLOCK_FORMAT.set(format).is_ok()
+}
+
+/// The installed clock format, 12-hour unless one was installed
+pub fn clock_format() -> ClockFormat {
+    CLOCK_FORMAT.get().copied().unwrap_or_default()
+}
+
+/// A time of day on the installed clock, e.g. "2:05 PM" or "14:05"
+pub fn format_time<T: Timelike>(time: &T) -> String {
+    clock_format().time(time)
+}
+
+/// An hour (0-23) on the installed clock, e.g. "3:00 PM" or "15:00"
+pub fn format_hour(hour: u8) -> String {
+    clock_format().hour(hour)
+}
+
+/// A short hour label on the installed clock, e.g. "3PM" or "15:00"
+pub fn hour_label(hour: u8) -> String {
+    clock_format().hour_label(hour)
+}
+
+/// An hour in at most three characters on the installed clock, e.g. "3p" or
+/// "15"
+pub fn hour_compact(hour: u8) -> String {
+    clock_format().hour_compact(hour)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+
+    #[test]
+    fn test_formats_on_each_clock() {
+        let twelve = ClockFormat::TwelveHour;
+        let twenty_four = ClockFormat::TwentyFourHour;
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        assert_eq!(twelve.time(&at(14, 5)), "2:05 PM");
+        assert_eq!(twelve.time(&at(0, 30)), "12:30 AM");
+        assert_eq!(twenty_four.time(&at(14, 5)), "14:05");
+        assert_eq!(twenty_four.time(&at(6, 0)), "06:00");
+
+        assert_eq!(twelve.hour(0), "12:00 AM");
+        assert_eq!(twelve.hour(12), "12:00 PM");
+        assert_eq!(twelve.hour(15), "3:00 PM");
+        assert_eq!(twenty_four.hour(15), "15:00");
+
+        assert_eq!(twelve.hour_label(6), "6AM");
+        assert_eq!(twelve.hour_label(12), "12PM");
+        assert_eq!(twenty_four.hour_label(6), "06:00");
+
+        assert_eq!(twelve.hour_compact(0), "12a");
+        assert_eq!(twelve.hour_compact(15), "3p");
+        assert_eq!(twenty_four.hour_compact(15), "15");
+    }
+
+    #[test]
+    fn test_clock_format_for_locale() {
+        assert_eq!(
+            ClockFormat::for_locale("en_US.UTF-8"),
+            Some(ClockFormat::TwelveHour)
+        );
+        assert_eq!(
+            ClockFormat::for_locale("en_CA.UTF-8"),
+            Some(ClockFormat::TwelveHour)
+        );
+        assert_eq!(
+            ClockFormat::for_locale("fr_CA.UTF-8"),
+            Some(ClockFormat::TwentyFourHour)
+        );
+        assert_eq!(
+            ClockFormat::for_locale("de_DE@euro"),
+            Some(ClockFormat::TwentyFourHour)
+        );
+        assert_eq!(ClockFormat::for_locale("C.UTF-8"), None);
+        assert_eq!(ClockFormat::for_locale("POSIX"), None);
+    }
+}
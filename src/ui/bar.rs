@@ -0,0 +1,51 @@
+//! Small horizontal bars for scores
+//!
+//! The beach list's score column and the detail view's factor breakdown draw
+//! the same kind of bar, filled ▰ cells against empty ▱ ones, so a score
+//! reads the same at a glance wherever it's shown.
+
+use ratatui::style::{Color, Style};
+use ratatui::text::Span;
+
+/// Color of the empty part of a bar
+const EMPTY_COLOR: Color = Color::Gray;
+
+/// A `width`-cell bar filled in proportion to `fraction` (0.0 to 1.0)
+///
+/// Fractions out of range are clamped, so a bar is always `width` cells.
+pub fn bar(fraction: f32, width: usize, color: Color) -> Vec<Span<'static>> {
+    let filled = ((fraction.clamp(0.0, 1.0) * width as f32).round() as usize).min(width);
+    vec![
+        Span::styled("▰".repeat(filled), Style::default().fg(color)),
+        Span::styled("▱".repeat(width - filled), Style::default().fg(EMPTY_COLOR)),
+    ]
+}
+
+/// A `width`-cell bar for a 0-100 score
+pub fn score_bar(score: u8, width: usize, color: Color) -> Vec<Span<'static>> {
+    bar(f32::from(score) / 100.0, width, color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells(spans: &[Span]) -> String {
+        spans.iter().map(|span| span.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_bar_fills_in_proportion() {
+        assert_eq!(cells(&bar(0.0, 5, Color::Green)), "▱▱▱▱▱");
+        assert_eq!(cells(&bar(0.6, 5, Color::Green)), "▰▰▰▱▱");
+        assert_eq!(cells(&bar(1.0, 5, Color::Green)), "▰▰▰▰▰");
+        assert_eq!(cells(&score_bar(78, 5, Color::Green)), "▰▰▰▰▱");
+    }
+
+    #[test]
+    fn test_bar_is_always_full_width() {
+        assert_eq!(cells(&bar(1.7, 5, Color::Green)), "▰▰▰▰▰");
+        assert_eq!(cells(&bar(-0.3, 5, Color::Green)), "▱▱▱▱▱");
+        assert_eq!(cells(&score_bar(100, 8, Color::Green)).chars().count(), 8);
+    }
+}
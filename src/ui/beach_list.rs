@@ -3,7 +3,7 @@
 //! Renders the main beach list view showing all Vancouver beaches with their
 //! current conditions including temperature, weather, and water quality status.
 
-use chrono::{Datelike, Local, Timelike};
+use chrono::{DateTime, Datelike, Local, Timelike};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -12,12 +12,21 @@ use ratatui::{
     Frame,
 };
 
-use crate::activities::{get_profile, sunset_time_scorer_dynamic, Activity};
-use crate::app::App;
-use crate::data::{all_beaches, BeachConditions, WaterStatus, WeatherCondition};
+use crate::activities::Activity;
+use crate::app::{App, LoadState};
+use crate::config::{hour_range, is_good_score, OutsideHours, GOOD_SCORE_MARKER};
+use crate::data::{
+    all_beaches, dog_rule, get_beach_by_id, BeachConditions, TideInfo, TideState, WaterQuality,
+    WaterStatus, WeatherCondition,
+};
+use crate::plans;
+use crate::scoring::ActivityScorer;
+use crate::time_format::{format_time, hour_label};
+
+use super::{bar, text, theme};
 
 /// Weather condition to icon mapping
-fn weather_icon(condition: &WeatherCondition) -> &'static str {
+pub(super) fn weather_icon(condition: &WeatherCondition) -> &'static str {
     match condition {
         WeatherCondition::Clear => "\u{2600}",        // ☀
         WeatherCondition::PartlyCloudy => "\u{26C5}", // ⛅
@@ -31,7 +40,13 @@ fn weather_icon(condition: &WeatherCondition) -> &'static str {
 }
 
 /// Water status to icon mapping
-fn water_status_icon(status: &WaterStatus) -> &'static str {
+///
+/// The colored circle emoji can't be recolored, so colorblind palettes get a
+/// letter badge instead.
+pub(super) fn water_status_icon(status: &WaterStatus) -> &'static str {
+    if theme::palette().is_colorblind() {
+        return water_status_letter(status);
+    }
     match status {
         WaterStatus::Safe => "\u{1F7E2}",     // 🟢
         WaterStatus::Advisory => "\u{1F7E1}", // 🟡
@@ -40,8 +55,18 @@ fn water_status_icon(status: &WaterStatus) -> &'static str {
     }
 }
 
+/// Letter badge for water status, for colorblind palettes
+fn water_status_letter(status: &WaterStatus) -> &'static str {
+    match status {
+        WaterStatus::Safe => "[S]",
+        WaterStatus::Advisory => "[A]",
+        WaterStatus::Closed => "[C]",
+        WaterStatus::Unknown => "[?]",
+    }
+}
+
 /// Color for water status
-fn water_status_color(status: &WaterStatus) -> Color {
+pub(super) fn water_status_color(status: &WaterStatus) -> Color {
     match status {
         WaterStatus::Safe => Color::Green,
         WaterStatus::Advisory => Color::Yellow,
@@ -50,8 +75,44 @@ fn water_status_color(status: &WaterStatus) -> Color {
     }
 }
 
+/// Badge for a station that has missed its usual sampling
+pub(super) const NOT_REPORTING_BADGE: &str = "\u{2298}"; // ⊘
+
+/// One-character water quality badge for the list, based on the effective status
+///
+/// Stale samples get their own dimmed badge so "unknown because old" can be
+/// told apart from "no data", and a station that stopped reporting gets
+/// another so an outage isn't mistaken for the gap between visits.
+fn water_badge(water_quality: Option<&WaterQuality>) -> (&'static str, Style) {
+    let Some(wq) = water_quality else {
+        return ("\u{25CB}", Style::default().fg(Color::Gray)); // ○
+    };
+    if wq.is_not_reporting() {
+        return (NOT_REPORTING_BADGE, Style::default().fg(Color::DarkGray));
+    }
+    if wq.is_stale() {
+        return (
+            "\u{25CC}", // ◌
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::DIM),
+        );
+    }
+    let status = wq.effective_status();
+    let symbol = match status {
+        WaterStatus::Safe => "\u{25CF}",     // ●
+        WaterStatus::Advisory => "\u{25B2}", // ▲
+        WaterStatus::Closed => "\u{2716}",   // ✖
+        WaterStatus::Unknown => "\u{25CB}",  // ○
+    };
+    let style = Style::default()
+        .fg(water_status_color(&status))
+        .add_modifier(Modifier::BOLD);
+    (symbol, style)
+}
+
 /// Color for temperature (warmer = more red, cooler = more blue)
-fn temperature_color(temp: f64) -> Color {
+pub(super) fn temperature_color(temp: f64) -> Color {
     if temp >= 30.0 {
         Color::Red
     } else if temp >= 25.0 {
@@ -98,22 +159,42 @@ fn generate_tide_sparkline(
     spans
 }
 
+/// The tide state in 16 columns or fewer, e.g. "Rising 1.2m", standing in
+/// for the sparkline in plain mode
+fn plain_tide_state(tides: &TideInfo) -> String {
+    let state = match tides.tide_state {
+        TideState::Rising => "Rising",
+        TideState::Falling => "Falling",
+        TideState::High => "High",
+        TideState::Low => "Low",
+    };
+    format!("{} {:.1}m", state, tides.current_height)
+}
+
 /// Generates a contextual hint for a beach based on current conditions.
 ///
 /// Hints are prioritized in the following order:
 /// 1. Water quality issue -> "Water advisory"
-/// 2. Within 2h of sunset -> "Sunset in Xh Ym"
-/// 3. High wind (>15 km/h) -> "Windy - good sailing"
-/// 4. Early morning (6-9am) -> "Good for peace" or "Warming up"
-/// 5. Peak hours (12-4pm) + weekend -> "Crowded now"
-/// 6. Peak hours + good weather -> "Peak swimming" or "Peak sun hours"
-/// 7. Default based on temp/conditions
-fn generate_contextual_hint(conditions: Option<&BeachConditions>) -> Option<String> {
+/// 2. Outside the scored hours -> "Starts at 6AM" (or "... tomorrow")
+/// 3. Within 2h of sunset -> "Sunset in Xh Ym"
+/// 4. High wind (>15 km/h) -> "Windy - good sailing"
+/// 5. Early morning (6-9am) -> "Good for peace" or "Warming up"
+/// 6. Peak hours (12-4pm) + weekend -> "Crowded now"
+/// 7. Peak hours + good weather -> "Peak swimming" or "Peak sun hours"
+/// 8. Default based on temp/conditions
+fn generate_contextual_hint(
+    conditions: Option<&BeachConditions>,
+    now: DateTime<Local>,
+) -> Option<String> {
     let conditions = conditions?;
-    let now = Local::now();
     let current_hour = now.hour() as u8;
     let is_weekend = matches!(now.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun);
 
+    // A Park Board closure trumps everything else
+    if let Some(ref closure) = conditions.closure {
+        return Some(format!("CLOSED: {}", closure.summary()));
+    }
+
     // Priority 1: Water quality issue
     if let Some(ref wq) = conditions.water_quality {
         if wq.status == WaterStatus::Advisory {
@@ -124,10 +205,16 @@ fn generate_contextual_hint(conditions: Option<&BeachConditions>) -> Option<Stri
         }
     }
 
+    // Priority 2: Overnight the rest would describe the dark; say when the
+    // day starts
+    if let Some(outside) = OutsideHours::of(current_hour) {
+        return Some(format!("Starts {}", day_starts(outside)));
+    }
+
     // Get weather data for remaining checks
     let weather = conditions.weather.as_ref();
 
-    // Priority 2: Within 2h of sunset
+    // Priority 3: Within 2h of sunset
     if let Some(w) = weather {
         let current_time = now.time();
         let sunset_time = w.sunset;
@@ -150,14 +237,14 @@ fn generate_contextual_hint(conditions: Option<&BeachConditions>) -> Option<Stri
         }
     }
 
-    // Priority 3: High wind (>15 km/h)
-    if let Some(w) = weather {
-        if w.wind > 15.0 {
+    // Priority 4: High wind (>15 km/h) on the sand
+    if let Some(wind) = conditions.effective_wind() {
+        if wind > 15.0 {
             return Some("Windy - good sailing".to_string());
         }
     }
 
-    // Priority 4: Early morning (6-9am)
+    // Priority 5: Early morning (6-9am)
     if (6..9).contains(&current_hour) {
         if let Some(w) = weather {
             if w.temperature < 18.0 {
@@ -167,14 +254,14 @@ fn generate_contextual_hint(conditions: Option<&BeachConditions>) -> Option<Stri
         return Some("Good for peace".to_string());
     }
 
-    // Priority 5 & 6: Peak hours (12-4pm)
+    // Priority 6 & 7: Peak hours (12-4pm)
     if (12..16).contains(&current_hour) {
-        // Priority 5: Weekend crowds
+        // Priority 6: Weekend crowds
         if is_weekend {
             return Some("Crowded now".to_string());
         }
 
-        // Priority 6: Good weather during peak hours
+        // Priority 7: Good weather during peak hours
         if let Some(w) = weather {
             let is_good_weather = matches!(
                 w.condition,
@@ -190,7 +277,7 @@ fn generate_contextual_hint(conditions: Option<&BeachConditions>) -> Option<Stri
         }
     }
 
-    // Priority 7: Default based on conditions
+    // Priority 8: Default based on conditions
     if let Some(w) = weather {
         // Evening hints
         if (17..21).contains(&current_hour) {
@@ -215,6 +302,16 @@ fn generate_contextual_hint(conditions: Option<&BeachConditions>) -> Option<Stri
     None
 }
 
+/// When the scored hours start again, seen from outside them: "at 6AM"
+/// before dawn, "at 6AM tomorrow" late in the evening
+fn day_starts(outside: OutsideHours) -> String {
+    let first_hour = hour_label(hour_range().0);
+    match outside {
+        OutsideHours::BeforeStart => format!("at {}", first_hour),
+        OutsideHours::AfterEnd => format!("at {} tomorrow", first_hour),
+    }
+}
+
 /// Computes the best time today for a given beach and activity.
 /// Returns (hour, score) or None if no data available.
 fn compute_best_time_for_beach(
@@ -222,91 +319,32 @@ fn compute_best_time_for_beach(
     activity: Activity,
 ) -> Option<(u8, u8)> {
     let conditions = conditions?;
-    let weather = conditions.weather.as_ref()?;
-    let profile = get_profile(activity);
-
-    let temp = weather.temperature as f32;
-    let wind = weather.wind as f32;
-    let uv = weather.uv as f32;
-    let sunset_hour = weather.sunset.hour() as u8;
-
-    let water_status = conditions
-        .water_quality
-        .as_ref()
-        .map(|wq| wq.effective_status())
-        .unwrap_or(WaterStatus::Unknown);
-
-    let (tide_height, max_tide) = conditions
-        .tides
-        .as_ref()
-        .map(|t| (t.current_height as f32, 4.8f32))
-        .unwrap_or((2.4, 4.8));
-
-    let current_hour = Local::now().hour() as u8;
-    let start_hour = current_hour.max(6);
-
-    // For sunset, cap at sunset hour
-    let end_hour = if activity == Activity::Sunset {
-        sunset_hour
-    } else {
-        21
-    };
-
-    if start_hour > end_hour {
+    // A closed beach scores zero all day, so it has no best time
+    if conditions.closure.is_some() {
         return None;
     }
+    // The first of equally good hours wins
+    ActivityScorer::hourly(conditions, activity)
+        .map(|(hour, slot)| (hour, slot.score))
+        .fold(None, |best: Option<(u8, u8)>, (hour, score)| {
+            if score > best.map_or(0, |(_, best_score)| best_score) {
+                Some((hour, score))
+            } else {
+                best
+            }
+        })
+}
 
-    let mut best_hour = start_hour;
-    let mut best_score: u8 = 0;
-
-    for hour in start_hour..=end_hour {
-        // Estimate crowd level
-        let crowd = match hour {
-            6..=7 => 0.1,
-            8..=9 => 0.2,
-            10..=11 => 0.4,
-            12..=14 => 0.8,
-            15..=17 => 0.6,
-            18..=19 => 0.4,
-            20..=21 => 0.2,
-            _ => 0.5,
-        };
-
-        let mut score_result = profile.score_time_slot(
-            hour,
-            conditions.beach.id,
-            temp,
-            wind,
-            uv,
-            water_status,
-            tide_height,
-            max_tide,
-            crowd,
-        );
-
-        // Apply dynamic sunset scoring
-        if activity == Activity::Sunset {
-            let time_score = sunset_time_scorer_dynamic(hour, sunset_hour);
-            let adjusted = score_result.score as f32 * (0.3 + 0.7 * time_score);
-            score_result.score = adjusted.clamp(0.0, 100.0) as u8;
-        }
+/// Cells in the score bar ahead of each beach's best time
+const SCORE_BAR_WIDTH: usize = 5;
 
-        if score_result.score > best_score {
-            best_score = score_result.score;
-            best_hour = hour;
-        }
-    }
+/// Rows the beach list screen spends around the list: the smart header,
+/// the list's borders and the help line
+const LIST_CHROME_ROWS: u16 = 4 + 2 + 1;
 
-    if best_score > 0 {
-        Some((best_hour, best_score))
-    } else {
-        None
-    }
-}
-
-/// Formats an hour as a time string (e.g., "15:00")
-fn format_hour_short(hour: u8) -> String {
-    format!("{:02}:00", hour)
+/// How many beaches the list shows on a terminal `height` rows tall
+pub fn visible_rows(height: u16) -> usize {
+    height.saturating_sub(LIST_CHROME_ROWS) as usize
 }
 
 /// Renders the beach list screen
@@ -350,7 +388,7 @@ pub fn render_beach_list(frame: &mut Frame, app: &App) {
 /// Renders the smart header with time, weather, recommendation, and sunset info
 fn render_smart_header(frame: &mut Frame, app: &App, area: Rect) {
     let now = Local::now();
-    let time_str = now.format("%a %b %d, %H:%M").to_string();
+    let time_str = format!("{}, {}", now.format("%a %b %d"), format_time(&now));
 
     // Get current weather from first beach with data
     let current_temp = app
@@ -388,19 +426,28 @@ fn render_smart_header(frame: &mut Frame, app: &App, area: Rect) {
     let width = area.width as usize;
     let separator = "─".repeat(width.saturating_sub(2));
 
+    let mut title_spans = vec![
+        Span::styled(
+            "VANBEACH",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  "),
+        Span::styled(time_str, Style::default().fg(Color::White)),
+        Span::raw("  "),
+        Span::styled(current_temp, Style::default().fg(Color::Yellow)),
+    ];
+    // A spinner while beaches are still loading in the background
+    if !app.load_states.is_empty() {
+        title_spans.push(Span::styled(
+            format!("  {} Refreshing", spinner_frame(now)),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
     let mut lines = vec![
-        Line::from(vec![
-            Span::styled(
-                "VANBEACH",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw("  "),
-            Span::styled(time_str, Style::default().fg(Color::White)),
-            Span::raw("  "),
-            Span::styled(current_temp, Style::default().fg(Color::Yellow)),
-        ]),
+        Line::from(title_spans),
         Line::from(Span::styled(
             separator,
             Style::default().fg(Color::DarkGray),
@@ -423,6 +470,13 @@ fn render_smart_header(frame: &mut Frame, app: &App, area: Rect) {
                 Style::default().fg(Color::Gray),
             ),
         ]));
+    } else if let (Some(_), Some(outside)) =
+        (app.current_activity, OutsideHours::of(now.hour() as u8))
+    {
+        lines.push(Line::from(Span::styled(
+            format!("Recommendations start {}", day_starts(outside)),
+            Style::default().fg(Color::DarkGray),
+        )));
     } else if app.current_activity.is_some() {
         lines.push(Line::from(Span::styled(
             "No great options right now — check back later",
@@ -430,41 +484,71 @@ fn render_smart_header(frame: &mut Frame, app: &App, area: Rect) {
         )));
     } else {
         lines.push(Line::from(Span::styled(
-            "Press 1-5 to select an activity for recommendations",
+            "Press 1-8 to select an activity for recommendations",
             Style::default().fg(Color::DarkGray),
         )));
     }
 
-    // Sunset info
+    // Sunset info, followed by a countdown to the next pinned plan
+    let mut info_spans = Vec::new();
     if !sunset_info.is_empty() {
-        lines.push(Line::from(Span::styled(
+        info_spans.push(Span::styled(
             format!("  {}", sunset_info),
             Style::default().fg(Color::DarkGray),
-        )));
+        ));
+    }
+    if let Some(plan) = plans::next_upcoming(&app.pinned_plans, now) {
+        let beach_name = get_beach_by_id(&plan.beach_id)
+            .map(|b| b.name.clone())
+            .unwrap_or_else(|| plan.beach_id.to_string());
+        let countdown = plan
+            .time_until(now)
+            .map(plans::format_countdown)
+            .unwrap_or_default();
+        info_spans.push(Span::styled(
+            format!(
+                "  \u{25C6} {} at {}:00 {}",
+                beach_name, plan.hour, countdown
+            ),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if !info_spans.is_empty() {
+        lines.push(Line::from(info_spans));
     }
 
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, area);
 }
 
+/// Frames of the loading spinner
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// The spinner frame for `now`, turning ten frames a second
+fn spinner_frame(now: DateTime<Local>) -> &'static str {
+    let tenths = now.timestamp_millis() / 100;
+    SPINNER[tenths.rem_euclid(SPINNER.len() as i64) as usize]
+}
+
 /// Renders the beach list content
 fn render_list(frame: &mut Frame, app: &App, area: Rect) {
-    let beaches = all_beaches();
+    let beaches = app.visible_beaches();
     let mut lines: Vec<Line> = Vec::with_capacity(beaches.len());
 
-    // Calculate current hour index for sparkline highlighting (6am = 0, 7am = 1, etc.)
-    let current_hour = Local::now().hour() as usize;
-    let sparkline_pos = if (6..=21).contains(&current_hour) {
-        Some(current_hour - 6)
-    } else {
-        None
-    };
+    // Calculate current hour index for sparkline highlighting (first hour = 0, etc.)
+    let (first_hour, last_hour) = hour_range();
+    let sparkline_width = (last_hour - first_hour + 1) as usize;
+    let current_hour = Local::now().hour() as u8;
+    let sparkline_pos = (first_hour..=last_hour)
+        .contains(&current_hour)
+        .then(|| (current_hour - first_hour) as usize);
+    let tide_column = sparkline_width.max(16);
 
     for (index, beach) in beaches.iter().enumerate() {
         let is_selected = index == app.selected_index;
 
         // Get conditions for this beach
-        let conditions = app.get_conditions(beach.id);
+        let conditions = app.get_conditions(&beach.id);
 
         // Build the line content
         let cursor = if is_selected { "\u{25B8} " } else { "  " }; // ▸ or space
@@ -487,30 +571,36 @@ fn render_list(frame: &mut Frame, app: &App, area: Rect) {
             None => "?",
         };
 
-        // Get water status icon and color
-        let (water_icon_str, water_color) = match conditions.and_then(|c| c.water_quality.as_ref())
-        {
-            Some(wq) => (
-                water_status_icon(&wq.status),
-                water_status_color(&wq.status),
-            ),
-            None => ("\u{26AA}", Color::Gray), // ⚪
-        };
+        // Get water quality badge
+        let (water_badge_str, water_badge_style) =
+            water_badge(conditions.and_then(|c| c.water_quality.as_ref()));
 
-        // Generate tide sparkline
-        let tide_sparkline_spans = match conditions.and_then(|c| c.tides.as_ref()) {
+        // Generate tide sparkline, or the tide state in words in plain mode
+        let mut tide_sparkline_spans = match conditions.and_then(|c| c.tides.as_ref()) {
+            Some(tides) if app.plain => vec![Span::styled(
+                text::pad_right(&plain_tide_state(tides), tide_column),
+                Style::default().fg(Color::Cyan),
+            )],
             Some(tides) => {
                 let heights = tides.hourly_heights(4.8);
                 generate_tide_sparkline(&heights, 4.8, sparkline_pos)
             }
             None => vec![Span::styled(
-                "────────────────",
+                "─".repeat(sparkline_width),
                 Style::default().fg(Color::DarkGray),
             )],
         };
+        // Short hour ranges still leave room for the plain tide state
+        let used: usize = tide_sparkline_spans
+            .iter()
+            .map(|span| text::width(&span.content))
+            .sum();
+        if used < tide_column {
+            tide_sparkline_spans.push(Span::raw(" ".repeat(tide_column - used)));
+        }
 
         // Generate contextual hint
-        let hint = generate_contextual_hint(conditions);
+        let hint = generate_contextual_hint(conditions, Local::now());
 
         // Build the line with spans
         let name_style = if is_selected {
@@ -527,19 +617,49 @@ fn render_list(frame: &mut Frame, app: &App, area: Rect) {
             Style::default()
         };
 
-        // Format: " ▸ Beach Name              22°C ☀ 🟢 ▁▂▃▄▅▆▇█▇▆▅▄▃▂▁▁  Hint"
-        // Pad beach name to fixed width for alignment
-        let name_padded = format!("{:<18}", beach.name);
+        // Format: " ▸  1 Beach Name              22°C ☀ ● 🐾 ▁▂▃▄▅▆▇█▇▆▅▄▃▂▁▁  Hint"
+        // The number is what `g` jumps to; pad beach name to fixed width for alignment
+        let name_padded = text::pad_right(&beach.name, 18);
+
+        let favorite_marker = if app.is_favorite(&beach.id) {
+            "\u{2605}" // ★
+        } else {
+            " "
+        };
+
+        // Paw badge while dogs are allowed on the beach
+        let dog_badge = if dog_rule(&beach.id, beach.timezone.now().date())
+            .access
+            .allows_dogs()
+        {
+            "\u{1F43E}" // 🐾
+        } else {
+            "  "
+        };
+
+        // A subtle mark ahead of the conditions while they're reloading
+        let load_marker = match app.load_states.get(&beach.id) {
+            Some(LoadState::InFlight) => spinner_frame(Local::now()),
+            Some(LoadState::Queued) => "\u{00B7}", // ·
+            None => " ",
+        };
 
         let mut spans = vec![
             Span::styled(cursor, cursor_style),
+            Span::styled(favorite_marker, Style::default().fg(Color::Yellow)),
+            Span::styled(
+                format!("{:>2} ", index + 1),
+                Style::default().fg(Color::DarkGray),
+            ),
             Span::styled(name_padded, name_style),
-            Span::raw(" "),
+            Span::styled(load_marker, Style::default().fg(Color::DarkGray)),
             Span::styled(temp_str, Style::default().fg(temp_color)),
             Span::raw(" "),
             Span::raw(weather_icon_str),
             Span::raw(" "),
-            Span::styled(water_icon_str, Style::default().fg(water_color)),
+            Span::styled(water_badge_str, water_badge_style),
+            Span::raw(" "),
+            Span::raw(dog_badge),
             Span::raw(" "),
         ];
 
@@ -549,7 +669,17 @@ fn render_list(frame: &mut Frame, app: &App, area: Rect) {
         // Add best time column if an activity is selected
         if let Some(activity) = app.current_activity {
             spans.push(Span::raw(" "));
-            if let Some((best_hour, score)) = compute_best_time_for_beach(conditions, activity) {
+            if conditions.is_some_and(|c| c.closure.is_some()) {
+                // A closed beach scores zero for every activity
+                spans.extend(bar::score_bar(0, SCORE_BAR_WIDTH, Color::Red));
+                spans.push(Span::raw(" "));
+                spans.push(Span::styled(
+                    format!("{:<10}", "CLOSED"),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ));
+            } else if let Some((best_hour, score)) =
+                compute_best_time_for_beach(conditions, activity)
+            {
                 let score_color = if score >= 80 {
                     Color::Green
                 } else if score >= 60 {
@@ -557,15 +687,24 @@ fn render_list(frame: &mut Frame, app: &App, area: Rect) {
                 } else {
                     Color::Red
                 };
+                spans.extend(bar::score_bar(score, SCORE_BAR_WIDTH, score_color));
+                spans.push(Span::raw(" "));
                 spans.push(Span::styled(
-                    format_hour_short(best_hour),
+                    hour_label(best_hour),
                     Style::default().fg(Color::White),
                 ));
                 spans.push(Span::styled(
                     format!(" ({})", score),
                     Style::default().fg(score_color),
                 ));
+                if is_good_score(score) {
+                    spans.push(Span::styled(
+                        format!(" {}", GOOD_SCORE_MARKER),
+                        Style::default().fg(score_color),
+                    ));
+                }
             } else {
+                spans.push(Span::raw(" ".repeat(SCORE_BAR_WIDTH + 1)));
                 spans.push(Span::styled(
                     "--:-- (--)",
                     Style::default().fg(Color::DarkGray),
@@ -589,8 +728,22 @@ fn render_list(frame: &mut Frame, app: &App, area: Rect) {
         lines.push(line);
     }
 
+    let mut notes = Vec::new();
+    if app.beach_order.custom {
+        notes.push("custom order".to_string());
+    }
+    if app.hide_closed {
+        let hidden = all_beaches().len() - beaches.len();
+        notes.push(format!("{} closed hidden", hidden));
+    }
+    let title = if notes.is_empty() {
+        " Vancouver Beaches ".to_string()
+    } else {
+        format!(" Vancouver Beaches ({}) ", notes.join(", "))
+    };
+
     let block = Block::default()
-        .title(" Vancouver Beaches ")
+        .title(title)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan));
 
@@ -601,20 +754,71 @@ fn render_list(frame: &mut Frame, app: &App, area: Rect) {
 
 /// Renders the help text at the bottom of the screen with data freshness
 fn render_help(frame: &mut Frame, area: Rect, app: &App) {
-    let mut help_spans = vec![
+    let mut help_spans = Vec::new();
+
+    // A quick jump replaces the usual keys until it goes ahead
+    if let Some(jump) = &app.jump {
+        help_spans.push(Span::styled(
+            format!(" Go to beach: {}_", jump.digits),
+            Style::default().fg(Color::Yellow),
+        ));
+        help_spans.push(Span::raw("  Enter Go  Esc Cancel"));
+        let paragraph =
+            Paragraph::new(Line::from(help_spans)).style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Off-screen beaches still arriving after the first paint
+    if let Some((loaded, total)) = app.load_progress {
+        help_spans.push(Span::styled(
+            format!("Loading {}/{} │ ", loaded, total),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
+    // Count of beaches the activity filter hides, up front so it isn't cut off
+    if let (true, Some(activity)) = (app.hide_unsuitable, app.current_activity) {
+        help_spans.push(Span::styled(
+            format!(
+                "{} hidden (unsuitable for {}) │ ",
+                app.unsuitable_hidden_count(),
+                activity.label()
+            ),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
+    // Count down the cooldown after a refresh, during which `r` is ignored
+    let refresh_label = match app.refresh_cooldown_remaining(Local::now()) {
+        Some(secs) => format!(" Refresh in {}s  ", secs),
+        None => " Refresh  ".to_string(),
+    };
+
+    help_spans.extend([
         Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
         Span::raw(" Navigate  "),
         Span::styled("Enter", Style::default().fg(Color::Yellow)),
         Span::raw(" Select  "),
-        Span::styled("1-5", Style::default().fg(Color::Yellow)),
+        Span::styled("g#", Style::default().fg(Color::Yellow)),
+        Span::raw(" Go to  "),
+        Span::styled("1-8", Style::default().fg(Color::Yellow)),
         Span::raw(" Activity  "),
+        Span::styled("f", Style::default().fg(Color::Yellow)),
+        Span::raw(" Fav  "),
+        Span::styled("d", Style::default().fg(Color::Yellow)),
+        Span::raw(" Dashboard  "),
+        Span::styled("c", Style::default().fg(Color::Yellow)),
+        Span::raw(" Hide closed  "),
+        Span::styled("s", Style::default().fg(Color::Yellow)),
+        Span::raw(" Suitable only  "),
         Span::styled("r", Style::default().fg(Color::Yellow)),
-        Span::raw(" Refresh  "),
+        Span::raw(refresh_label),
         Span::styled("?", Style::default().fg(Color::Yellow)),
         Span::raw(" Help  "),
         Span::styled("q", Style::default().fg(Color::Yellow)),
         Span::raw(" Quit"),
-    ];
+    ]);
 
     // Add data freshness indicator
     if let Some(last_refresh) = app.last_refresh {
@@ -649,8 +853,8 @@ pub fn render(frame: &mut Frame, app: &App) {
 mod tests {
     use super::*;
     use crate::app::{App, AppState};
-    use crate::data::{WaterQuality, WaterStatus, Weather, WeatherCondition};
-    use chrono::{NaiveDate, NaiveTime, Utc};
+    use crate::data::{WaterQuality, WaterStatus, Weather, WeatherCondition, WeatherProvider};
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
     use ratatui::{backend::TestBackend, Terminal};
 
     /// Helper to create a test app with some beach conditions
@@ -669,11 +873,16 @@ mod tests {
             condition,
             humidity: 65,
             wind: 10.0,
+            wind_direction: None,
             uv: 5.0,
             sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
             sunset: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
             fetched_at: Utc::now(),
             hourly: Vec::new(),
+            tomorrow: Vec::new(),
+            source: WeatherProvider::OpenMeteo,
+            parse_warnings: Vec::new(),
+            weather_code: None,
         }
     }
 
@@ -686,9 +895,45 @@ mod tests {
             sample_date: NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
             advisory_reason: None,
             fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
         }
     }
 
+    #[test]
+    fn test_hints_say_when_the_day_starts_outside_the_scored_hours() {
+        let mut conditions = BeachConditions {
+            beach: all_beaches()[0].clone(),
+            weather: Some(create_mock_weather(22.0, WeatherCondition::Clear)),
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        };
+        let at = |hour: u32| {
+            Local
+                .with_ymd_and_hms(2026, 7, 15, hour, 0, 0)
+                .single()
+                .unwrap()
+        };
+        let hint = |conditions: &BeachConditions, hour: u32| {
+            generate_contextual_hint(Some(conditions), at(hour)).unwrap()
+        };
+
+        assert_eq!(hint(&conditions, 0), "Starts at 6AM");
+        assert_eq!(hint(&conditions, 5), "Starts at 6AM");
+        assert_eq!(hint(&conditions, 6), "Good for peace");
+        assert_eq!(hint(&conditions, 21), "Good for swimming");
+        assert_eq!(hint(&conditions, 22), "Starts at 6AM tomorrow");
+        assert_eq!(hint(&conditions, 23), "Starts at 6AM tomorrow");
+
+        // An advisory still matters overnight
+        conditions.water_quality = Some(create_mock_water_quality(WaterStatus::Advisory));
+        assert_eq!(hint(&conditions, 23), "Water advisory");
+    }
+
     #[test]
     fn test_render_produces_non_empty_buffer() {
         let app = create_test_app();
@@ -806,6 +1051,249 @@ mod tests {
         assert_eq!(water_status_icon(&WaterStatus::Unknown), "\u{26AA}");
     }
 
+    #[test]
+    fn test_water_status_letters_are_distinct() {
+        let letters = [
+            WaterStatus::Safe,
+            WaterStatus::Advisory,
+            WaterStatus::Closed,
+            WaterStatus::Unknown,
+        ]
+        .map(|status| water_status_letter(&status));
+        assert_eq!(letters, ["[S]", "[A]", "[C]", "[?]"]);
+    }
+
+    #[test]
+    fn test_water_badge_uses_effective_status() {
+        let mut fresh = create_mock_water_quality(WaterStatus::Closed);
+        fresh.sample_date = Local::now().date_naive();
+        let (closed, closed_style) = water_badge(Some(&fresh));
+        assert_eq!(closed, "\u{2716}");
+        assert_eq!(closed_style.fg, Some(Color::Red));
+
+        // An old Closed sample is Unknown-because-stale, not Closed
+        let stale = create_mock_water_quality(WaterStatus::Closed);
+        let (stale_badge, stale_style) = water_badge(Some(&stale));
+        assert_eq!(stale_badge, "\u{25CC}");
+        assert!(stale_style.add_modifier.contains(Modifier::DIM));
+
+        let (missing, _) = water_badge(None);
+        assert_ne!(missing, stale_badge);
+
+        // A station past its usual cadence is not reporting, not just stale
+        let mut quiet = create_mock_water_quality(WaterStatus::Safe);
+        quiet.sample_date = Local::now().date_naive() - chrono::Duration::days(10);
+        quiet.sampling = Some(crate::data::SamplingHistory {
+            recent_samples: 3,
+            usual_days: Vec::new(),
+            cadence_days: Some(2),
+        });
+        let (quiet_badge, _) = water_badge(Some(&quiet));
+        assert_eq!(quiet_badge, NOT_REPORTING_BADGE);
+        assert_ne!(quiet_badge, stale_badge);
+    }
+
+    #[test]
+    fn test_hidden_closed_beaches_are_not_rendered() {
+        let mut app = create_test_app();
+        let closed_beach = &all_beaches()[1];
+        let mut closed = create_mock_water_quality(WaterStatus::Closed);
+        closed.sample_date = Local::now().date_naive();
+        app.beach_conditions.insert(
+            closed_beach.id.clone(),
+            BeachConditions {
+                beach: closed_beach.clone(),
+                weather: None,
+                tides: None,
+                water_quality: Some(closed),
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+        app.hide_closed = true;
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+        terminal
+            .draw(|frame| render_beach_list(frame, &app))
+            .unwrap();
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(!content.contains(closed_beach.name.as_str()));
+        assert!(content.contains("1 closed hidden"));
+        assert!(content.contains(all_beaches()[0].name.as_str()));
+    }
+
+    #[test]
+    fn test_footer_counts_beaches_hidden_by_activity_filter() {
+        let mut app = create_test_app();
+        let advisory_beach = &all_beaches()[1];
+        let mut advisory = create_mock_water_quality(WaterStatus::Advisory);
+        advisory.sample_date = Local::now().date_naive();
+        app.beach_conditions.insert(
+            advisory_beach.id.clone(),
+            BeachConditions {
+                beach: advisory_beach.clone(),
+                weather: None,
+                tides: None,
+                water_quality: Some(advisory),
+                closure: None,
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+        app.current_activity = Some(Activity::Swimming);
+        app.hide_unsuitable = true;
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+        terminal
+            .draw(|frame| render_beach_list(frame, &app))
+            .unwrap();
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(!content.contains(advisory_beach.name.as_str()));
+        assert!(content.contains("1 hidden (unsuitable for Swimming)"));
+    }
+
+    #[test]
+    fn test_score_bar_sits_ahead_of_the_best_time() {
+        let mut app = create_test_app();
+        let closed_beach = &all_beaches()[0];
+        app.beach_conditions.insert(
+            closed_beach.id.clone(),
+            BeachConditions {
+                beach: closed_beach.clone(),
+                weather: None,
+                tides: None,
+                water_quality: None,
+                closure: Some(crate::data::Closure {
+                    reason: "Oil sheen".to_string(),
+                    until: None,
+                    fetched_at: Utc::now(),
+                }),
+                waves: None,
+                air_quality: None,
+                seawall_closure: None,
+            },
+        );
+        app.current_activity = Some(Activity::Swimming);
+
+        let mut terminal = Terminal::new(TestBackend::new(140, 24)).unwrap();
+        terminal
+            .draw(|frame| render_beach_list(frame, &app))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect()
+            })
+            .collect();
+        let row = |name: &str| rows.iter().find(|row| row.contains(name)).unwrap();
+
+        // A closed beach scores zero: an empty bar
+        assert!(row(&closed_beach.name).contains("▱▱▱▱▱ CLOSED"));
+        // No data, no bar, with the time column kept in line
+        let unloaded = row(&all_beaches()[1].name);
+        assert!(unloaded.contains("      --:-- (--)"));
+        assert!(!unloaded.contains('▱'));
+    }
+
+    #[test]
+    fn test_rows_and_header_show_a_background_refresh() {
+        let mut app = create_test_app();
+        let beaches = all_beaches();
+        app.load_states
+            .insert(beaches[0].id.clone(), LoadState::InFlight);
+        app.load_states
+            .insert(beaches[1].id.clone(), LoadState::Queued);
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 24)).unwrap();
+        terminal
+            .draw(|frame| render_beach_list(frame, &app))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect()
+            })
+            .collect();
+        let row = |name: &str| rows.iter().find(|row| row.contains(name)).unwrap().clone();
+        let marker = |name: &str| {
+            let row = row(name);
+            let after = &row[row.find(name).unwrap()..];
+            after.chars().nth(18).unwrap()
+        };
+
+        assert!(rows[0].contains("Refreshing"));
+        assert!(SPINNER.iter().any(|frame| rows[0].contains(frame)));
+        assert!(SPINNER.contains(&marker(&beaches[0].name).to_string().as_str()));
+        assert_eq!(marker(&beaches[1].name), '\u{00B7}');
+        assert_eq!(marker(&beaches[2].name), ' ');
+
+        // Nothing in flight, no spinner
+        app.load_states.clear();
+        terminal
+            .draw(|frame| render_beach_list(frame, &app))
+            .unwrap();
+        let first_row: String = (0..120)
+            .map(|x| terminal.backend().buffer()[(x, 0)].symbol().to_string())
+            .collect();
+        assert!(!first_row.contains("Refreshing"));
+    }
+
+    #[test]
+    fn test_footer_counts_down_the_refresh_cooldown() {
+        let mut app = create_test_app();
+        app.last_refresh = Some(Local::now() - chrono::Duration::seconds(5));
+
+        let mut terminal = Terminal::new(TestBackend::new(200, 24)).unwrap();
+        terminal
+            .draw(|frame| render_beach_list(frame, &app))
+            .unwrap();
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(content.contains("r Refresh in 25s"));
+    }
+
+    #[test]
+    fn test_plain_tide_state_fits_the_sparkline_column() {
+        let mut tides = TideInfo {
+            current_height: 2.46,
+            tide_state: TideState::Falling,
+            next_high: None,
+            next_low: None,
+            fetched_at: Utc::now(),
+        };
+        assert_eq!(plain_tide_state(&tides), "Falling 2.5m");
+
+        tides.current_height = 10.0;
+        assert!(plain_tide_state(&tides).chars().count() <= 16);
+    }
+
     #[test]
     fn test_water_status_colors() {
         assert_eq!(water_status_color(&WaterStatus::Safe), Color::Green);
@@ -0,0 +1,273 @@
+//! Minimal screen for tiny terminals
+//!
+//! Below 40x10 the regular layouts have no room to work with, so instead of
+//! squeezing them this renders just the essentials for the focused beach:
+//! name, temperature, water quality and the next tide.
+
+use chrono::Utc;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use super::beach_list::{temperature_color, water_status_color, water_status_icon, weather_icon};
+use super::dashboard::water_status_label;
+use crate::app::{App, AppState};
+use crate::data::{BeachConditions, TideEvent};
+use crate::time_format::format_time;
+
+/// Narrowest terminal that gets the full layout
+pub const MIN_WIDTH: u16 = 40;
+
+/// Shortest terminal that gets the full layout
+pub const MIN_HEIGHT: u16 = 10;
+
+/// Returns true if the area is too small for the full layout
+pub fn is_too_small(area: Rect) -> bool {
+    area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+}
+
+/// Renders the minimal screen
+pub fn render(frame: &mut Frame, app: &App) {
+    let lines = build_compact_lines(app);
+    frame.render_widget(Paragraph::new(lines), frame.area());
+}
+
+/// Builds the minimal screen, most important line first so the bottom can be
+/// cut off
+fn build_compact_lines(app: &App) -> Vec<Line<'static>> {
+    if app.state == AppState::Loading {
+        return vec![Line::from(Span::styled(
+            "Loading...",
+            Style::default().fg(Color::Cyan),
+        ))];
+    }
+    let Some(beach) = app.focused_beach() else {
+        return vec![Line::from(Span::styled(
+            "No beach selected",
+            Style::default().fg(Color::DarkGray),
+        ))];
+    };
+
+    let mut lines = vec![Line::from(Span::styled(
+        beach.name.to_string(),
+        Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    match app.get_conditions(&beach.id) {
+        Some(conditions) => lines.extend(condition_lines(conditions)),
+        None => lines.push(Line::from(Span::styled(
+            "No data",
+            Style::default().fg(Color::DarkGray),
+        ))),
+    }
+    lines.push(Line::from(Span::styled(
+        "Enlarge for full view",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines
+}
+
+/// Temperature, water quality and next tide lines for a beach
+fn condition_lines(conditions: &BeachConditions) -> Vec<Line<'static>> {
+    let unknown = Style::default().fg(Color::DarkGray);
+
+    let temperature = match &conditions.weather {
+        Some(weather) => Line::from(vec![
+            Span::styled(
+                format!("{:.0}\u{00B0}C ", weather.temperature),
+                Style::default().fg(temperature_color(weather.temperature)),
+            ),
+            Span::raw(weather_icon(&weather.condition)),
+        ]),
+        None => Line::from(Span::styled("--\u{00B0}C", unknown)),
+    };
+
+    let water = match &conditions.water_quality {
+        Some(wq) => {
+            let status = wq.effective_status();
+            Line::from(vec![
+                Span::raw(water_status_icon(&status)),
+                Span::raw(" "),
+                Span::styled(
+                    water_status_label(status),
+                    Style::default().fg(water_status_color(&status)),
+                ),
+            ])
+        }
+        None => Line::from(Span::styled("Water --", unknown)),
+    };
+
+    let tide = conditions
+        .tides
+        .as_ref()
+        .and_then(|tides| {
+            let high = tides.next_high.as_ref().map(|event| ("High", event));
+            let low = tides.next_low.as_ref().map(|event| ("Low", event));
+            [high, low]
+                .into_iter()
+                .flatten()
+                .min_by_key(|(_, event)| event.time)
+        })
+        .map(|(label, event)| next_tide_line(conditions, label, event))
+        .unwrap_or_else(|| Line::from(Span::styled("Tide --", unknown)));
+
+    vec![temperature, water, tide]
+}
+
+/// "High 14:32 4.2m", with the time on the beach's clock
+fn next_tide_line(conditions: &BeachConditions, label: &str, event: &TideEvent) -> Line<'static> {
+    let local = conditions
+        .beach
+        .timezone
+        .local(event.time.with_timezone(&Utc));
+    Line::from(Span::styled(
+        format!("{} {} {:.1}m", label, format_time(&local), event.height),
+        Style::default().fg(Color::Cyan),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{
+        all_beaches, TideInfo, TideState, WaterQuality, WaterStatus, Weather, WeatherCondition,
+        WeatherProvider,
+    };
+    use chrono::{Local, NaiveTime, TimeZone};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn create_app() -> App {
+        let beach = all_beaches()[0].clone();
+        let at = |hour| {
+            Utc.with_ymd_and_hms(2026, 7, 15, hour, 32, 0)
+                .unwrap()
+                .with_timezone(&Local)
+        };
+        let conditions = BeachConditions {
+            beach: beach.clone(),
+            weather: Some(Weather {
+                temperature: 22.4,
+                feels_like: 23.0,
+                condition: WeatherCondition::Clear,
+                humidity: 60,
+                wind: 8.0,
+                wind_direction: None,
+                uv: 5.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 10, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
+            }),
+            tides: Some(TideInfo {
+                current_height: 2.1,
+                tide_state: TideState::Rising,
+                // 14:32 and 20:32 PDT
+                next_high: Some(TideEvent {
+                    time: at(21),
+                    height: 4.2,
+                }),
+                next_low: Some(TideEvent {
+                    time: at(3),
+                    height: 0.9,
+                }),
+                fetched_at: Utc::now(),
+            }),
+            water_quality: Some(WaterQuality {
+                status: WaterStatus::Safe,
+                ecoli_count: Some(20),
+                sample_date: Local::now().date_naive(),
+                advisory_reason: None,
+                fetched_at: Utc::now(),
+                no_samples_station: None,
+                sampling: None,
+            }),
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        };
+        let mut app = App::new();
+        app.state = AppState::BeachList;
+        app.beach_conditions.insert(beach.id.clone(), conditions);
+        app
+    }
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_threshold() {
+        assert!(is_too_small(Rect::new(0, 0, 39, 24)));
+        assert!(is_too_small(Rect::new(0, 0, 80, 9)));
+        assert!(!is_too_small(Rect::new(0, 0, 40, 10)));
+    }
+
+    #[test]
+    fn test_compact_lines_show_the_essentials() {
+        let mut app = create_app();
+        let text: Vec<String> = build_compact_lines(&app).iter().map(line_text).collect();
+
+        assert_eq!(text[0], all_beaches()[0].name.as_str());
+        assert!(text[1].starts_with("22\u{00B0}C"));
+        assert!(text[2].ends_with("Safe"));
+        assert_eq!(text[3], "Low 8:32 PM 0.9m", "earliest tide first");
+
+        app.beach_conditions.clear();
+        let text: Vec<String> = build_compact_lines(&app).iter().map(line_text).collect();
+        assert_eq!(text[1], "No data");
+
+        app.state = AppState::Loading;
+        assert_eq!(line_text(&build_compact_lines(&app)[0]), "Loading...");
+    }
+
+    #[test]
+    fn test_renders_at_extreme_sizes_without_panicking() {
+        let mut app = create_app();
+        let states = [
+            AppState::Loading,
+            AppState::BeachList,
+            AppState::BeachDetail(all_beaches()[0].id.clone()),
+            AppState::PlanTrip,
+            AppState::Dashboard,
+            AppState::WaterQualityOverview,
+            AppState::Journal,
+        ];
+        for state in states {
+            app.state = state;
+            for (width, height) in [(1, 1), (2, 1), (39, 9), (39, 24), (80, 1), (1, 30)] {
+                let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+                terminal.draw(|frame| render(frame, &app)).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_full_layouts_survive_the_smallest_full_size() {
+        let mut app = create_app();
+        let beach_id = all_beaches()[0].id.to_string();
+        let mut terminal = Terminal::new(TestBackend::new(MIN_WIDTH, MIN_HEIGHT)).unwrap();
+
+        terminal
+            .draw(|frame| crate::ui::render_beach_list(frame, &app))
+            .unwrap();
+        terminal
+            .draw(|frame| crate::ui::render_beach_detail(frame, &mut app, &beach_id))
+            .unwrap();
+        terminal
+            .draw(|frame| crate::ui::render_plan_trip(frame, &mut app))
+            .unwrap();
+        terminal
+            .draw(|frame| crate::ui::render_dashboard(frame, &app))
+            .unwrap();
+    }
+}
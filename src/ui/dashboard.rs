@@ -0,0 +1,483 @@
+//! Dashboard screen rendering
+//!
+//! Renders a grid of compact condition cards for the favorite beaches (or every
+//! beach when none are marked). Each card shows temperature, tide direction,
+//! water quality and the best-scoring activity right now. A saved comparison
+//! set can be shown in place of the favorites. The view refreshes itself so
+//! it can be left running.
+
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::beach_list::{temperature_color, water_status_color, water_status_icon, weather_icon};
+use crate::activities::Activity;
+use crate::app::App;
+use crate::data::{BeachConditions, TideState, WaterStatus};
+use crate::scoring::score_now;
+
+/// Color scheme for the dashboard screen
+mod colors {
+    use ratatui::style::Color;
+
+    /// Borders and headers
+    pub const HEADER: Color = Color::Cyan;
+    /// Primary text
+    pub const PRIMARY: Color = Color::White;
+    /// Secondary/dimmed text
+    pub const SECONDARY: Color = Color::Gray;
+    /// Missing data
+    pub const UNKNOWN: Color = Color::DarkGray;
+    /// Rising tide indicator
+    pub const RISING: Color = Color::Cyan;
+    /// Falling tide indicator
+    pub const FALLING: Color = Color::Blue;
+    /// Rain delays for pinned plans
+    pub const RAIN_DELAY: Color = Color::Yellow;
+}
+
+/// Width of a single condition card including its border
+const CARD_WIDTH: u16 = 28;
+
+/// Height of a single condition card including its border
+const CARD_HEIGHT: u16 = 5;
+
+/// Renders the dashboard screen
+///
+/// # Arguments
+/// * `frame` - The ratatui frame to render into
+/// * `app` - The application state
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+
+    let title = match app.active_comparison_set() {
+        Some(set) => format!(" Beach Dashboard: {} ", set.name),
+        None => " Beach Dashboard ".to_string(),
+    };
+    let main_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::HEADER))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(colors::PRIMARY)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let inner_area = main_block.inner(area);
+    frame.render_widget(main_block, area);
+
+    let rain_delay_lines = build_rain_delay_lines(app);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(CARD_HEIGHT),                      // Card grid
+            Constraint::Length(rain_delay_lines.len() as u16), // Rain delays
+            Constraint::Length(1),                             // Help bar
+        ])
+        .split(inner_area);
+
+    render_cards(frame, chunks[0], app);
+    frame.render_widget(Paragraph::new(rain_delay_lines), chunks[1]);
+    render_help_bar(frame, chunks[2], app);
+}
+
+/// A line per pinned plan that rain is forecast for, with where to go instead
+fn build_rain_delay_lines(app: &App) -> Vec<Line<'static>> {
+    app.rain_delays
+        .iter()
+        .map(|delay| {
+            Line::from(Span::styled(
+                format!("\u{2602} {}", delay.message()),
+                Style::default().fg(colors::RAIN_DELAY),
+            ))
+        })
+        .collect()
+}
+
+/// Lays out one card per dashboard beach, left to right and top to bottom.
+/// Cards that don't fit in the area are skipped.
+fn render_cards(frame: &mut Frame, area: Rect, app: &App) {
+    let columns = (area.width / CARD_WIDTH).max(1);
+    let rows = area.height / CARD_HEIGHT;
+    let now = Local::now();
+
+    for (index, beach) in app.dashboard_beaches().into_iter().enumerate() {
+        let index = index as u16;
+        let (row, column) = (index / columns, index % columns);
+        if row >= rows {
+            break;
+        }
+
+        let card_area = Rect {
+            x: area.x + column * CARD_WIDTH,
+            y: area.y + row * CARD_HEIGHT,
+            width: CARD_WIDTH.min(area.width),
+            height: CARD_HEIGHT,
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::SECONDARY))
+            .title(Span::styled(
+                format!(" {} ", beach.name),
+                Style::default()
+                    .fg(colors::PRIMARY)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        let lines = build_card_lines(app.get_conditions(&beach.id), now);
+        frame.render_widget(Paragraph::new(lines).block(block), card_area);
+    }
+}
+
+/// Builds the three lines of a condition card
+fn build_card_lines(
+    conditions: Option<&BeachConditions>,
+    now: DateTime<Local>,
+) -> Vec<Line<'static>> {
+    let mut first = Vec::new();
+
+    // Temperature and weather icon
+    match conditions.and_then(|c| c.weather.as_ref()) {
+        Some(weather) => {
+            first.push(Span::styled(
+                format!("{:.0}\u{00B0}C ", weather.temperature),
+                Style::default().fg(temperature_color(weather.temperature)),
+            ));
+            first.push(Span::raw(weather_icon(&weather.condition)));
+        }
+        None => first.push(Span::styled(
+            "--\u{00B0}C",
+            Style::default().fg(colors::UNKNOWN),
+        )),
+    }
+
+    // Tide arrow and height
+    first.push(Span::raw("  "));
+    match conditions.and_then(|c| c.tides.as_ref()) {
+        Some(tides) => {
+            let (arrow, color) = match tides.tide_state {
+                TideState::Rising => ("\u{2191}", colors::RISING),
+                TideState::Falling => ("\u{2193}", colors::FALLING),
+                TideState::High | TideState::Low => ("\u{2500}", colors::SECONDARY),
+            };
+            first.push(Span::styled(
+                format!("{} {:.1}m", arrow, tides.current_height),
+                Style::default().fg(color),
+            ));
+        }
+        None => first.push(Span::styled(
+            "tide --",
+            Style::default().fg(colors::UNKNOWN),
+        )),
+    }
+
+    // Water quality badge, replaced by the reason while the beach is closed
+    let closure = conditions.and_then(|c| c.closure.as_ref());
+    let water_line = match (closure, conditions.and_then(|c| c.water_quality.as_ref())) {
+        (Some(closure), _) => Line::from(Span::styled(
+            format!("CLOSED: {}", closure.reason),
+            Style::default()
+                .fg(water_status_color(&WaterStatus::Closed))
+                .add_modifier(Modifier::BOLD),
+        )),
+        (None, Some(wq)) => {
+            let status = wq.effective_status();
+            Line::from(vec![
+                Span::raw(water_status_icon(&status)),
+                Span::raw(" "),
+                Span::styled(
+                    water_status_label(status),
+                    Style::default().fg(water_status_color(&status)),
+                ),
+            ])
+        }
+        (None, None) => Line::from(Span::styled(
+            "\u{26AA} No water data",
+            Style::default().fg(colors::UNKNOWN),
+        )),
+    };
+
+    // Best activity right now
+    let activity_line = match conditions.and_then(|c| top_activity_now(c, now)) {
+        Some((activity, score)) => Line::from(vec![
+            Span::styled(
+                format!("{} ", activity.label()),
+                Style::default().fg(colors::SECONDARY),
+            ),
+            Span::styled(
+                score.to_string(),
+                Style::default()
+                    .fg(score_color(score))
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        None => Line::from(Span::styled(
+            "No score yet",
+            Style::default().fg(colors::UNKNOWN),
+        )),
+    };
+
+    vec![Line::from(first), water_line, activity_line]
+}
+
+/// Short label for a water quality badge
+pub(super) fn water_status_label(status: WaterStatus) -> &'static str {
+    match status {
+        WaterStatus::Safe => "Safe",
+        WaterStatus::Advisory => "Advisory",
+        WaterStatus::Closed => "Closed",
+        WaterStatus::Unknown => "Unknown",
+    }
+}
+
+/// Color for an activity score
+fn score_color(score: u8) -> Color {
+    if score >= 80 {
+        Color::Green
+    } else if score >= 60 {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
+}
+
+/// Scores every activity for the current hour and returns the best one
+///
+/// Returns `None` if there is no weather data to score against.
+fn top_activity_now(conditions: &BeachConditions, now: DateTime<Local>) -> Option<(Activity, u8)> {
+    Activity::all()
+        .iter()
+        .filter_map(|&activity| score_now(conditions, activity, now).map(|s| (activity, s)))
+        .max_by_key(|(_, score)| *score)
+}
+
+/// Renders the help bar with the auto-refresh status, or the prompt for a
+/// comparison set's name while one is typed
+fn render_help_bar(frame: &mut Frame, area: Rect, app: &App) {
+    if let Some(name) = &app.set_name {
+        let prompt = Line::from(vec![
+            Span::styled("Save set as: ", Style::default().fg(colors::HEADER)),
+            Span::styled(format!("{}_  ", name), Style::default().fg(colors::PRIMARY)),
+            Span::styled("Enter", Style::default().fg(colors::HEADER)),
+            Span::styled(" Save  ", Style::default().fg(colors::SECONDARY)),
+            Span::styled("Esc", Style::default().fg(colors::HEADER)),
+            Span::styled(" Cancel", Style::default().fg(colors::SECONDARY)),
+        ]);
+        frame.render_widget(Paragraph::new(prompt), area);
+        return;
+    }
+
+    // Count down the cooldown after a refresh, during which `r` is ignored
+    let refresh_label = match app.refresh_cooldown_remaining(Local::now()) {
+        Some(secs) => format!(" Refresh in {}s  ", secs),
+        None => " Refresh  ".to_string(),
+    };
+    let mut spans = vec![
+        Span::styled("r", Style::default().fg(colors::HEADER)),
+        Span::styled(refresh_label, Style::default().fg(colors::SECONDARY)),
+        Span::styled("Tab", Style::default().fg(colors::HEADER)),
+        Span::styled(" Sets  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("S", Style::default().fg(colors::HEADER)),
+        Span::styled(" Save set  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("Esc", Style::default().fg(colors::HEADER)),
+        Span::styled(" Back  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("?", Style::default().fg(colors::HEADER)),
+        Span::styled(" Help  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("q", Style::default().fg(colors::HEADER)),
+        Span::styled(" Quit", Style::default().fg(colors::SECONDARY)),
+    ];
+
+    let updated = match app.last_refresh {
+        Some(last_refresh) => {
+            let mins_ago = (Local::now() - last_refresh).num_minutes();
+            if mins_ago < 1 {
+                "updated just now".to_string()
+            } else {
+                format!("updated {}m ago", mins_ago)
+            }
+        }
+        None => "not loaded yet".to_string(),
+    };
+    let saving = if app.saving_power() {
+        " (saving power)"
+    } else {
+        ""
+    };
+    spans.push(Span::styled(
+        format!(
+            " \u{2502} Auto-refresh every {}m{}, {}",
+            app.auto_refresh_minutes(),
+            saving,
+            updated
+        ),
+        Style::default().fg(colors::UNKNOWN),
+    ));
+
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::AppState;
+    use crate::data::{
+        all_beaches, BeachId, TideInfo, WaterQuality, Weather, WeatherCondition, WeatherProvider,
+    };
+    use crate::plans::PinnedPlan;
+    use crate::rain_delay::{Alternative, RainDelay};
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn create_conditions() -> BeachConditions {
+        BeachConditions {
+            beach: all_beaches()[0].clone(),
+            weather: Some(Weather {
+                temperature: 24.0,
+                feels_like: 25.0,
+                condition: WeatherCondition::Clear,
+                humidity: 55,
+                wind: 8.0,
+                wind_direction: None,
+                uv: 6.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+                weather_code: None,
+            }),
+            tides: Some(TideInfo {
+                current_height: 3.2,
+                tide_state: TideState::Rising,
+                next_high: None,
+                next_low: None,
+                fetched_at: Utc::now(),
+            }),
+            water_quality: Some(WaterQuality {
+                status: WaterStatus::Safe,
+                ecoli_count: Some(20),
+                sample_date: Local::now().date_naive(),
+                advisory_reason: None,
+                fetched_at: Utc::now(),
+                no_samples_station: None,
+                sampling: None,
+            }),
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn buffer_text(terminal: &Terminal<TestBackend>) -> String {
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_card_lines_show_temp_tide_and_water_badge() {
+        let now = Local::now();
+        let lines = build_card_lines(Some(&create_conditions()), now);
+
+        let first = line_text(&lines[0]);
+        assert!(first.contains("24\u{00B0}C"));
+        assert!(
+            first.contains("\u{2191} 3.2m"),
+            "rising tide arrow: {}",
+            first
+        );
+        assert!(line_text(&lines[1]).contains("Safe"));
+    }
+
+    #[test]
+    fn test_card_lines_without_data_show_placeholders() {
+        let lines = build_card_lines(None, Local::now());
+
+        assert!(line_text(&lines[0]).contains("--"));
+        assert!(line_text(&lines[1]).contains("No water data"));
+        assert_eq!(line_text(&lines[2]), "No score yet");
+    }
+
+    #[test]
+    fn test_top_activity_needs_weather() {
+        let mut conditions = create_conditions();
+        let day = NaiveDate::from_ymd_opt(2026, 7, 15).unwrap();
+        let afternoon = Local
+            .from_local_datetime(&day.and_hms_opt(14, 0, 0).unwrap())
+            .earliest()
+            .unwrap();
+
+        let (_, score) = top_activity_now(&conditions, afternoon).unwrap();
+        assert!(score > 0);
+
+        conditions.weather = None;
+        assert!(top_activity_now(&conditions, afternoon).is_none());
+    }
+
+    #[test]
+    fn test_render_shows_only_favorite_cards() {
+        let mut app = App::new();
+        app.state = AppState::Dashboard;
+        let favorite = all_beaches()[2].clone();
+        app.favorites = vec![BeachId::new(favorite.id.as_str())];
+
+        let backend = TestBackend::new(100, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Beach Dashboard"));
+        assert!(text.contains(favorite.name.as_str()));
+        assert!(!text.contains(all_beaches()[0].name.as_str()));
+        assert!(text.contains("Auto-refresh"));
+    }
+
+    #[test]
+    fn test_render_lists_rain_delays_above_the_help_bar() {
+        let mut app = App::new();
+        app.state = AppState::Dashboard;
+        app.rain_delays = vec![RainDelay {
+            plan: PinnedPlan::new(
+                BeachId::new("kitsilano"),
+                NaiveDate::from_ymd_opt(2026, 7, 15).unwrap(),
+                14,
+                None,
+            ),
+            beach_name: "Kitsilano Beach".to_string(),
+            alternative: Some(Alternative {
+                beach_id: BeachId::new("jericho"),
+                beach_name: "Jericho Beach".to_string(),
+                hour: 16,
+                score: 78,
+            }),
+        }];
+
+        let backend = TestBackend::new(120, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        let text = buffer_text(&terminal);
+        assert!(text.contains(
+            "Rain forecast for your 2:00 PM plan at Kitsilano Beach: try Jericho Beach at 4:00 PM instead (score 78)"
+        ));
+        assert!(text.contains("Auto-refresh"));
+    }
+}
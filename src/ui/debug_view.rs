@@ -0,0 +1,242 @@
+//! Raw API response viewer
+//!
+//! A hidden overlay, opened with `D` when running with `--verbose`, that shows
+//! the most recent raw response from each data source for the current beach,
+//! pretty-printed and scrollable, for diagnosing parsing and field issues.
+//! Values that failed their sanity checks and got a response quarantined are
+//! listed after the responses.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::data::water_quality::WATER_QUALITY_SOURCE;
+use crate::data::{coordinate_subject, Anomaly, Beach, DebugLog, WeatherProvider};
+
+/// Margin between the overlay and the edge of the screen
+const MARGIN: u16 = 2;
+
+/// Renders the debug view on top of the current view
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let area = frame.area();
+    let overlay_area = Rect {
+        x: area.x + MARGIN,
+        y: area.y + MARGIN / 2,
+        width: area.width.saturating_sub(MARGIN * 2),
+        height: area.height.saturating_sub(MARGIN),
+    };
+
+    let beach = app.focused_beach();
+    let lines = match (&app.debug_log, beach) {
+        (Some(debug_log), Some(beach)) => build_debug_lines(debug_log, &app.anomalies, beach),
+        _ => vec![Line::from(Span::styled(
+            "No beach selected",
+            Style::default().fg(Color::DarkGray),
+        ))],
+    };
+
+    // Keep the offset within the content so scrolling back up responds at once
+    let visible_height = overlay_area.height.saturating_sub(2);
+    let max_scroll = (lines.len() as u16).saturating_sub(visible_height);
+    app.debug_scroll_offset = app.debug_scroll_offset.min(max_scroll);
+
+    let title = match beach {
+        Some(beach) => format!(" Raw API responses: {} ", beach.name),
+        None => " Raw API responses ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .title_bottom(Line::from(Span::styled(
+            " j/k scroll  g top  Esc/D close ",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta));
+
+    frame.render_widget(Clear, overlay_area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .scroll((app.debug_scroll_offset, 0)),
+        overlay_area,
+    );
+}
+
+/// Builds one section per data source with the latest response for a beach,
+/// then the beach's quarantined values, shared tide ones included
+fn build_debug_lines(
+    debug_log: &DebugLog,
+    anomalies: &[Anomaly],
+    beach: &Beach,
+) -> Vec<Line<'static>> {
+    let coordinates = coordinate_subject(beach.latitude, beach.longitude);
+    let mut sources = vec![
+        (WeatherProvider::OpenMeteo.name(), coordinates.clone()),
+        (WeatherProvider::MetNorway.name(), coordinates),
+    ];
+    if let Some(wq_id) = &beach.water_quality_id {
+        sources.push((WATER_QUALITY_SOURCE, wq_id.clone()));
+    }
+
+    let mut lines = Vec::new();
+    for (source, subject) in sources {
+        let mut header = vec![Span::styled(
+            source.to_string(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )];
+        match debug_log.latest(source, &subject) {
+            Some(response) => {
+                header.push(Span::styled(
+                    format!(
+                        "  received {} UTC, {} bytes",
+                        response.received_at.format("%H:%M:%S"),
+                        response.body.len()
+                    ),
+                    Style::default().fg(Color::Gray),
+                ));
+                lines.push(Line::from(header));
+                lines.push(Line::from(Span::styled(
+                    response.url.clone(),
+                    Style::default().fg(Color::DarkGray),
+                )));
+                lines.extend(
+                    response
+                        .pretty_body()
+                        .lines()
+                        .map(|l| Line::from(l.to_string())),
+                );
+            }
+            None => {
+                lines.push(Line::from(header));
+                lines.push(Line::from(Span::styled(
+                    "No response recorded yet",
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(Span::styled(
+        "Tides come from built-in predictions, with no API response",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let quarantined: Vec<&Anomaly> = anomalies
+        .iter()
+        .filter(|a| a.beach.as_ref().is_none_or(|id| beach.id == id.as_str()))
+        .collect();
+    if !quarantined.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Quarantined",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )));
+        for anomaly in quarantined {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} UTC  ", anomaly.detected_at.format("%H:%M:%S")),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::raw(format!("{}  {}", anomaly.source, anomaly.summary())),
+            ]));
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::AppState;
+    use crate::data::all_beaches;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_debug_lines_show_latest_response_per_source() {
+        let beach = all_beaches()[0].clone();
+        let subject = coordinate_subject(beach.latitude, beach.longitude);
+        let debug_log = DebugLog::default();
+        debug_log.record(
+            "Open-Meteo",
+            &subject,
+            "https://api.open-meteo.com/v1/forecast",
+            r#"{"current":{"temperature_2m":21.5}}"#,
+        );
+
+        let text: Vec<String> = build_debug_lines(&debug_log, &[], &beach)
+            .iter()
+            .map(line_text)
+            .collect();
+
+        assert!(text[0].starts_with("Open-Meteo  received"));
+        assert!(text.iter().any(|l| l.trim() == "\"temperature_2m\": 21.5"));
+        let met = text.iter().position(|l| l == "MET Norway").unwrap();
+        assert_eq!(text[met + 1], "No response recorded yet");
+        assert!(!text.iter().any(|l| l == "Quarantined"));
+    }
+
+    #[test]
+    fn test_debug_lines_list_quarantined_values_for_the_beach() {
+        let beach = all_beaches()[0].clone();
+        let other = all_beaches()[1].id.to_string();
+        let anomaly = |beach: Option<String>, field: &str| Anomaly {
+            detected_at: chrono::Utc::now(),
+            source: "Open-Meteo".to_string(),
+            beach,
+            field: field.to_string(),
+            hour: None,
+            value: 61.0,
+            expected: "-30 to 45".to_string(),
+        };
+        let anomalies = [
+            anomaly(Some(beach.id.to_string()), "temperature"),
+            anomaly(Some(other), "feels_like"),
+            anomaly(None, "current_height"),
+        ];
+
+        let text: Vec<String> = build_debug_lines(&DebugLog::default(), &anomalies, &beach)
+            .iter()
+            .map(line_text)
+            .collect();
+
+        let start = text.iter().position(|l| l == "Quarantined").unwrap();
+        let listed = &text[start + 1..];
+        assert_eq!(listed.len(), 2);
+        assert!(listed[0].ends_with("Open-Meteo  temperature: 61.0 (expected -30 to 45)"));
+        assert!(listed[1].contains("current_height"));
+    }
+
+    #[test]
+    fn test_render_shows_selected_beach_and_clamps_scroll() {
+        let mut app = App::new();
+        app.state = AppState::BeachList;
+        app.enable_debug_log();
+        app.show_debug_view = true;
+        app.debug_scroll_offset = 500;
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| render(frame, &mut app)).unwrap();
+
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(text.contains(all_beaches()[0].name.as_str()));
+        assert!(app.debug_scroll_offset < 500);
+    }
+}
@@ -0,0 +1,138 @@
+//! Directions overlay
+//!
+//! A centered box over a beach's details with Google and Apple Maps links
+//! to it, and with the `qr` feature a QR code of the Google Maps link to
+//! scan with a phone.
+
+use ratatui::{
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::help_overlay::centered_rect;
+use crate::app::{App, AppState};
+use crate::data::Beach;
+use crate::directions::{apple_maps_url, google_maps_url};
+
+/// Renders the directions overlay for the beach whose details are open
+pub fn render(frame: &mut Frame, app: &App) {
+    let AppState::BeachDetail(beach_id) = &app.state else {
+        return;
+    };
+    let Some(conditions) = app.get_conditions(beach_id) else {
+        return;
+    };
+    let lines = build_lines(&conditions.beach);
+
+    let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 4;
+    let height = lines.len() as u16 + 2;
+    let area = centered_rect(width, height, frame.area());
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(Span::styled(
+            format!(" Directions to {} ", conditions.beach.name),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_bottom(Line::from(Span::styled(
+            " o/Esc close ",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(block),
+        area,
+    );
+}
+
+/// The two links, then the QR code or how to get one
+fn build_lines(beach: &Beach) -> Vec<Line<'static>> {
+    let link = |label: &'static str, url: String| {
+        Line::from(vec![
+            Span::styled(
+                label,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(url),
+        ])
+    };
+    let google = google_maps_url(beach);
+    let mut lines = vec![
+        link("Google  ", google.clone()),
+        link("Apple   ", apple_maps_url(beach)),
+        Line::default(),
+    ];
+    lines.extend(qr_lines(&google));
+    lines
+}
+
+/// The QR code, white on black whatever the terminal's colors, so it scans
+/// on light and dark themes alike
+#[cfg(feature = "qr")]
+fn qr_lines(url: &str) -> Vec<Line<'static>> {
+    let Some(rows) = crate::directions::qr_code(url) else {
+        return Vec::new();
+    };
+    let mut lines = vec![Line::from(Span::styled(
+        "Scan with your phone's camera",
+        Style::default().fg(Color::Gray),
+    ))];
+    lines.extend(rows.into_iter().map(|row| {
+        Line::from(Span::styled(
+            row,
+            Style::default().fg(Color::White).bg(Color::Black),
+        ))
+    }));
+    lines
+}
+
+/// Without the `qr` feature, a note on how to get the QR code
+#[cfg(not(feature = "qr"))]
+fn qr_lines(_url: &str) -> Vec<Line<'static>> {
+    vec![Line::from(Span::styled(
+        "Build with --features qr for a QR code to scan",
+        Style::default().fg(Color::DarkGray),
+    ))]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::get_beach_by_id;
+
+    fn line_text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_overlay_lists_both_links() {
+        let beach = get_beach_by_id("kitsilano").unwrap();
+        let lines = build_lines(beach);
+        assert_eq!(
+            line_text(&lines[0]),
+            format!("Google  {}", google_maps_url(beach))
+        );
+        assert_eq!(
+            line_text(&lines[1]),
+            format!("Apple   {}", apple_maps_url(beach))
+        );
+        #[cfg(not(feature = "qr"))]
+        assert!(line_text(&lines[3]).contains("--features qr"));
+        #[cfg(feature = "qr")]
+        assert!(line_text(&lines[3]).starts_with("Scan"));
+    }
+}
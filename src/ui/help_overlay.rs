@@ -1,6 +1,8 @@
 //! Help overlay showing all keybindings
 //!
-//! Renders a centered modal overlay with keyboard shortcuts.
+//! Renders a centered modal overlay with the key reference from
+//! `keymap::BINDINGS`, grouped by section. `/` filters it and it scrolls
+//! when it doesn't fit.
 
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -10,64 +12,64 @@ use ratatui::{
     Frame,
 };
 
+use crate::app::HelpView;
+use crate::keymap;
+
 /// Renders the help overlay on top of the current view
-pub fn render(frame: &mut Frame) {
+pub fn render(frame: &mut Frame, help: &HelpView) {
     let area = frame.area();
 
     // Calculate centered overlay area
-    let overlay_width = 50;
-    let overlay_height = 20;
+    let overlay_width = 56;
+    let overlay_height = 30;
     let overlay_area = centered_rect(overlay_width, overlay_height, area);
 
     // Clear the area behind the overlay
     frame.render_widget(Clear, overlay_area);
 
-    // Build help content
-    let lines = vec![
+    let reference = reference_lines(&help.query);
+    let total = reference.len();
+    // Title, search line and a blank line above the reference
+    let visible = overlay_area.height.saturating_sub(2 + 3) as usize;
+    let scroll = (help.scroll as usize).min(total.saturating_sub(visible));
+
+    let mut lines = vec![
         Line::from(Span::styled(
             "Keyboard Shortcuts",
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )),
+        search_line(help),
         Line::from(""),
-        Line::from(vec![Span::styled(
-            "Navigation",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        help_line("↑/k, ↓/j", "Move selection up/down"),
-        help_line("Enter", "Open beach details"),
-        help_line("Esc", "Go back / Close"),
-        help_line("q", "Quit application"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Activities",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        help_line("1", "Swimming"),
-        help_line("2", "Sunbathing"),
-        help_line("3", "Sailing"),
-        help_line("4", "Sunset viewing"),
-        help_line("5", "Peace & quiet"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Other",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        help_line("p", "Plan trip grid"),
-        help_line("r", "Refresh data"),
-        help_line("?", "Toggle this help"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Press Esc or ? to close",
-            Style::default().fg(Color::DarkGray),
-        )),
     ];
+    if total == 0 {
+        lines.push(Line::from(Span::styled(
+            "  No keys match",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines.extend(reference.into_iter().skip(scroll).take(visible));
 
-    let block = Block::default()
+    let mut block = Block::default()
         .title(" Help ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(Style::default().fg(Color::Cyan))
+        .title_bottom(Line::from(Span::styled(
+            " / Search  j/k Scroll  Esc Close ",
+            Style::default().fg(Color::DarkGray),
+        )));
+    if total > visible {
+        block = block.title(
+            Line::from(format!(
+                " lines {}-{} of {} ",
+                scroll + 1,
+                (scroll + visible).min(total),
+                total
+            ))
+            .right_aligned(),
+        );
+    }
 
     let paragraph = Paragraph::new(lines)
         .block(block)
@@ -76,6 +78,45 @@ pub fn render(frame: &mut Frame) {
     frame.render_widget(paragraph, overlay_area);
 }
 
+/// The search box: the query being typed, the filter applied, or a hint
+fn search_line(help: &HelpView) -> Line<'static> {
+    if help.searching {
+        Line::from(vec![
+            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::raw(format!("{}_", help.query)),
+        ])
+    } else if !help.query.is_empty() {
+        Line::from(Span::styled(
+            format!("Showing keys matching \"{}\"", help.query),
+            Style::default().fg(Color::DarkGray),
+        ))
+    } else {
+        Line::from(Span::styled(
+            "Press / to search",
+            Style::default().fg(Color::DarkGray),
+        ))
+    }
+}
+
+/// The key reference for `query`, one heading per section followed by its
+/// bindings and a blank line
+fn reference_lines(query: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for (category, bindings) in keymap::sections(query) {
+        lines.push(Line::from(vec![Span::styled(
+            category.label(),
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+        lines.extend(
+            bindings
+                .iter()
+                .map(|binding| help_line(binding.label, binding.description)),
+        );
+        lines.push(Line::from(""));
+    }
+    lines
+}
+
 /// Creates a help line with key and description
 fn help_line(key: &str, description: &str) -> Line<'static> {
     Line::from(vec![
@@ -85,7 +126,7 @@ fn help_line(key: &str, description: &str) -> Line<'static> {
 }
 
 /// Helper function to create a centered rect
-fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+pub(super) fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
     let vertical = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -112,24 +153,47 @@ mod tests {
     use super::*;
     use ratatui::{backend::TestBackend, Terminal};
 
-    #[test]
-    fn test_help_overlay_renders() {
+    fn render_to_string(help: &HelpView) -> String {
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
 
         terminal
             .draw(|frame| {
-                render(frame);
+                render(frame, help);
             })
             .unwrap();
 
         let buffer = terminal.backend().buffer();
-        let content: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        buffer.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn test_help_overlay_renders() {
+        let content = render_to_string(&HelpView::default());
 
         assert!(content.contains("Help"), "Should render help title");
         assert!(
             content.contains("Navigation"),
             "Should show navigation section"
         );
+        assert!(content.contains("lines 1-"), "Should show scroll position");
+    }
+
+    #[test]
+    fn test_help_overlay_filters_and_scrolls() {
+        let filtered = render_to_string(&HelpView {
+            query: "tide".to_string(),
+            ..HelpView::default()
+        });
+        assert!(filtered.contains("Expand tide chart"));
+        assert!(!filtered.contains("Navigation"));
+        assert!(!filtered.contains("lines 1-"), "Everything fits");
+
+        let scrolled = render_to_string(&HelpView {
+            scroll: 100,
+            ..HelpView::default()
+        });
+        assert!(!scrolled.contains("Navigation"));
+        assert!(scrolled.contains("Debugging"), "Scroll stops at the end");
     }
 }
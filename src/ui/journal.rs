@@ -0,0 +1,196 @@
+//! Journal screen rendering
+//!
+//! Lists the notable condition changes logged so far, newest first: water
+//! quality advisories issued or lifted, heat warnings and king tides, one
+//! line each with when it was noticed.
+
+use chrono::Local;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::text;
+use crate::app::App;
+use crate::journal::{JournalEntry, JournalKind};
+use crate::time_format::format_time;
+
+/// Color scheme for the journal
+mod colors {
+    use ratatui::style::Color;
+
+    /// Borders and headers
+    pub const HEADER: Color = Color::Cyan;
+    /// Primary text
+    pub const PRIMARY: Color = Color::White;
+    /// Secondary/dimmed text
+    pub const SECONDARY: Color = Color::Gray;
+    /// Nothing logged yet
+    pub const UNKNOWN: Color = Color::DarkGray;
+}
+
+/// Width of the date column, e.g. "Wed Jul 15"
+const DATE_WIDTH: usize = 12;
+
+/// Width of the time column, e.g. "12:45 PM"
+const TIME_WIDTH: usize = 10;
+
+/// Width of the kind column, e.g. "King tide"
+const KIND_WIDTH: usize = 11;
+
+/// Renders the journal screen
+///
+/// # Arguments
+/// * `frame` - The ratatui frame to render into
+/// * `app` - The application state
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+
+    let main_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::HEADER))
+        .title(Span::styled(
+            " Journal ",
+            Style::default()
+                .fg(colors::PRIMARY)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let inner_area = main_block.inner(area);
+    frame.render_widget(main_block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),    // Entries
+            Constraint::Length(1), // Help bar
+        ])
+        .split(inner_area);
+
+    frame.render_widget(
+        Paragraph::new(build_lines(&app.journal, app.journal_scroll as usize)),
+        chunks[0],
+    );
+    render_help_bar(frame, chunks[1]);
+}
+
+/// The header and one line per entry, newest first, skipping the first
+/// `scroll` entries
+fn build_lines(entries: &[JournalEntry], scroll: usize) -> Vec<Line<'static>> {
+    if entries.is_empty() {
+        return vec![Line::from(Span::styled(
+            "Nothing logged yet: advisories, heat warnings and king tides are noted here as they come up",
+            Style::default().fg(colors::UNKNOWN),
+        ))];
+    }
+    let header = format!(
+        "{}{}{}{}",
+        text::pad_right("Date", DATE_WIDTH),
+        text::pad_right("Time", TIME_WIDTH),
+        text::pad_right("Change", KIND_WIDTH),
+        "What happened"
+    );
+    let mut lines = vec![Line::from(Span::styled(
+        header,
+        Style::default()
+            .fg(colors::HEADER)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    lines.extend(entries.iter().rev().skip(scroll).map(build_entry_line));
+    lines
+}
+
+/// One entry's line, e.g. "Wed Jul 15  2:30 PM   Advisory   Kitsilano Beach: …"
+fn build_entry_line(entry: &JournalEntry) -> Line<'static> {
+    let local = entry.at.with_timezone(&Local);
+    Line::from(vec![
+        Span::styled(
+            text::pad_right(&local.format("%a %b %-d").to_string(), DATE_WIDTH),
+            Style::default().fg(colors::SECONDARY),
+        ),
+        Span::styled(
+            text::pad_right(&format_time(&local), TIME_WIDTH),
+            Style::default().fg(colors::SECONDARY),
+        ),
+        Span::styled(
+            text::pad_right(entry.kind.label(), KIND_WIDTH),
+            Style::default()
+                .fg(kind_color(entry.kind))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(entry.message.clone(), Style::default().fg(colors::PRIMARY)),
+    ])
+}
+
+/// Red for bad news, green for good, yellow for heat and blue for tides
+fn kind_color(kind: JournalKind) -> Color {
+    match kind {
+        JournalKind::AdvisoryIssued | JournalKind::Closed => Color::Red,
+        JournalKind::AdvisoryLifted | JournalKind::Reopened => Color::Green,
+        JournalKind::HeatWarning => Color::Yellow,
+        JournalKind::KingTide => Color::Blue,
+    }
+}
+
+/// Renders the key hints at the bottom of the screen
+fn render_help_bar(frame: &mut Frame, area: Rect) {
+    let spans = vec![
+        Span::styled("↑/↓", Style::default().fg(colors::HEADER)),
+        Span::styled(" Scroll  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("Esc", Style::default().fg(colors::HEADER)),
+        Span::styled(" Back  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("q", Style::default().fg(colors::HEADER)),
+        Span::styled(" Quit", Style::default().fg(colors::SECONDARY)),
+    ];
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    fn entry(hour: u32, kind: JournalKind, message: &str) -> JournalEntry {
+        JournalEntry {
+            at: Utc.with_ymd_and_hms(2026, 7, 15, hour, 0, 0).unwrap(),
+            day: NaiveDate::from_ymd_opt(2026, 7, 15).unwrap(),
+            kind,
+            beach_id: None,
+            message: message.to_string(),
+        }
+    }
+
+    fn line_text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_entries_are_listed_newest_first() {
+        let entries = [
+            entry(17, JournalKind::AdvisoryIssued, "Sunset Beach: advisory"),
+            entry(20, JournalKind::HeatWarning, "Heat warning: 31°C"),
+        ];
+        let lines = build_lines(&entries, 0);
+        assert_eq!(lines.len(), 3);
+        assert!(line_text(&lines[0]).starts_with("Date"));
+        assert!(line_text(&lines[1]).ends_with("Heat warning: 31°C"));
+        assert!(line_text(&lines[2]).ends_with("Sunset Beach: advisory"));
+
+        let scrolled = build_lines(&entries, 1);
+        assert_eq!(scrolled.len(), 2);
+        assert!(line_text(&scrolled[1]).ends_with("Sunset Beach: advisory"));
+    }
+
+    #[test]
+    fn test_empty_journal_says_what_it_will_hold() {
+        let lines = build_lines(&[], 0);
+        assert_eq!(lines.len(), 1);
+        assert!(line_text(&lines[0]).starts_with("Nothing logged yet"));
+    }
+}
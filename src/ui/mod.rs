@@ -3,12 +3,32 @@
 //! This module contains all the rendering logic for the terminal user interface,
 //! using the ratatui library for TUI components.
 
+pub mod bar;
 pub mod beach_detail;
 pub mod beach_list;
+pub mod compact;
+pub mod dashboard;
+pub mod debug_view;
+pub mod directions;
 pub mod help_overlay;
+pub mod journal;
 pub mod plan_trip;
+pub mod text;
+pub mod theme;
+pub mod toast;
+pub mod water_quality_overview;
+pub mod wind_rose;
+
+#[cfg(test)]
+mod snapshot_tests;
 
 pub use beach_detail::render as render_beach_detail;
 pub use beach_list::render_beach_list;
+pub use dashboard::render as render_dashboard;
+pub use debug_view::render as render_debug_view;
+pub use directions::render as render_directions;
 pub use help_overlay::render as render_help_overlay;
+pub use journal::render as render_journal;
 pub use plan_trip::render as render_plan_trip;
+pub use toast::render as render_toast;
+pub use water_quality_overview::render as render_water_quality_overview;
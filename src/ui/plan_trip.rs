@@ -3,6 +3,7 @@
 //! Renders the Plan Trip view showing a heatmap grid of beaches (rows) vs hours (columns)
 //! with activity scores, cursor navigation, and best recommendation section.
 
+use chrono::Local;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -11,9 +12,16 @@ use ratatui::{
     Frame,
 };
 
-use crate::activities::{get_profile, Activity};
+use crate::activities::Activity;
 use crate::app::App;
-use crate::data::{all_beaches, WaterStatus};
+use crate::config::{good_score, hour_range, is_good_score, GOOD_SCORE_MARKER};
+use crate::crowd::CrowdBucket;
+use crate::data::{all_beaches, connections, get_beach_by_id};
+use crate::plan_scores::ScoreMatrix;
+use crate::plans;
+use crate::time_format::{format_hour, hour_label};
+
+use super::{text, theme};
 
 /// Color scheme for the plan trip screen
 mod colors {
@@ -48,15 +56,29 @@ const BLOCK_POOR: &str = "\u{2591}\u{2591}"; // ░░
 /// Tide block characters (8 levels)
 const TIDE_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
 
+/// Cells with a letter grade in front of the pattern, for colorblind
+/// palettes; every bucket gets its own letter and pattern
+const GRADED_EXCELLENT: &str = "A\u{2588}"; // A█
+const GRADED_GOOD: &str = "B\u{2593}"; // B▓
+const GRADED_FAIR: &str = "C\u{2592}"; // C▒
+const GRADED_POOR: &str = "D\u{2591}"; // D░
+const GRADED_BAD: &str = "F "; // F
+
 /// Returns the block character and color for a given score
 fn score_to_block(score: u8) -> (&'static str, Color) {
-    match score {
-        80..=100 => (BLOCK_EXCELLENT, colors::EXCELLENT),
-        60..=79 => (BLOCK_GOOD, colors::GOOD),
-        40..=59 => (BLOCK_FAIR, colors::FAIR),
-        20..=39 => (BLOCK_POOR, colors::POOR),
-        _ => (BLOCK_POOR, colors::BAD),
-    }
+    score_cell(score, theme::palette().is_colorblind())
+}
+
+/// Returns the cell and color for a score, letter-graded if `graded`
+fn score_cell(score: u8, graded: bool) -> (&'static str, Color) {
+    let (block, graded_block, color) = match score {
+        80..=100 => (BLOCK_EXCELLENT, GRADED_EXCELLENT, colors::EXCELLENT),
+        60..=79 => (BLOCK_GOOD, GRADED_GOOD, colors::GOOD),
+        40..=59 => (BLOCK_FAIR, GRADED_FAIR, colors::FAIR),
+        20..=39 => (BLOCK_POOR, GRADED_POOR, colors::POOR),
+        _ => (BLOCK_POOR, GRADED_BAD, colors::BAD),
+    };
+    (if graded { graded_block } else { block }, color)
 }
 
 /// Converts a tide height to a block character
@@ -72,136 +94,18 @@ fn get_tide_height_at_hour(app: &App, beach_id: &str, hour: u8) -> Option<f64> {
     let tides = conditions.tides.as_ref()?;
     let heights = tides.hourly_heights(4.8);
 
-    // Map hour (6-21) to index (0-15)
-    if (6..=21).contains(&hour) {
-        let index = (hour - 6) as usize;
-        heights.get(index).copied()
-    } else {
-        None
-    }
-}
-
-/// Computes the score for a beach at a given hour
-fn compute_score(app: &App, beach_id: &str, hour: u8) -> u8 {
-    let activity = match app.current_activity {
-        Some(a) => a,
-        None => return 50, // Default score when no activity selected
-    };
-
-    let conditions = match app.get_conditions(beach_id) {
-        Some(c) => c,
-        None => return 50, // Default when no conditions available
-    };
-
-    let profile = get_profile(activity);
-
-    // Get weather data for scoring
-    let (temp, wind, uv) = match &conditions.weather {
-        Some(w) => (w.temperature as f32, w.wind as f32, w.uv as f32),
-        None => return 50, // Can't score without weather
-    };
-
-    // Get water status
-    let water_status = conditions
-        .water_quality
-        .as_ref()
-        .map(|wq| wq.status)
-        .unwrap_or(WaterStatus::Unknown);
-
-    // Get tide info
-    let (tide_height, max_tide) = match &conditions.tides {
-        Some(t) => {
-            let max_h = t.next_high.as_ref().map(|h| h.height).unwrap_or(4.8);
-            (t.current_height as f32, max_h as f32)
-        }
-        None => (2.4, 4.8), // Default mid-tide
-    };
-
-    // Estimate crowd level based on time of day
-    let crowd_level = estimate_crowd_level(hour);
-
-    let score = profile.score_time_slot(
-        hour,
-        beach_id,
-        temp,
-        wind,
-        uv,
-        water_status,
-        tide_height,
-        max_tide,
-        crowd_level,
-    );
-
-    score.score
-}
-
-/// Estimates crowd level based on time of day (0.0 = empty, 1.0 = packed)
-fn estimate_crowd_level(hour: u8) -> f32 {
-    match hour {
-        6..=7 => 0.1,   // Early morning - very quiet
-        8..=9 => 0.2,   // Morning - light
-        10..=11 => 0.4, // Late morning - moderate
-        12..=14 => 0.8, // Midday - busy
-        15..=17 => 0.6, // Afternoon - moderate to busy
-        18..=19 => 0.4, // Early evening - moderate
-        20..=21 => 0.2, // Evening - light
-        _ => 0.5,       // Default
-    }
-}
-
-/// Finds the best beach/hour combination across all beaches and hours
-fn find_best_recommendation(app: &App) -> Option<(String, String, u8, u8)> {
-    app.current_activity?;
-
-    let beaches = all_beaches();
-    let (start_hour, end_hour) = app.plan_time_range;
-
-    let mut best: Option<(String, String, u8, u8)> = None;
-    let mut best_score: u8 = 0;
-
-    for beach in beaches {
-        for hour in start_hour..=end_hour {
-            let score = compute_score(app, beach.id, hour);
-            if score > best_score {
-                best_score = score;
-                best = Some((beach.name.to_string(), beach.id.to_string(), hour, score));
-            }
-        }
-    }
-
-    best
-}
-
-/// Format hour as display string (e.g., "6am", "12pm")
-fn format_hour_short(hour: u8) -> String {
-    match hour {
-        0 => "12am".to_string(),
-        1..=11 => format!("{}am", hour),
-        12 => "12pm".to_string(),
-        13..=23 => format!("{}pm", hour - 12),
-        _ => format!("{}h", hour),
-    }
-}
-
-/// Format hour as longer display string (e.g., "6:00 AM", "12:00 PM")
-fn format_hour_long(hour: u8) -> String {
-    match hour {
-        0 => "12:00 AM".to_string(),
-        1..=11 => format!("{}:00 AM", hour),
-        12 => "12:00 PM".to_string(),
-        13..=23 => format!("{}:00 PM", hour - 12),
-        _ => format!("{}:00", hour),
-    }
+    // Heights start at the first hour of the configured range
+    let index = hour.checked_sub(hour_range().0)? as usize;
+    heights.get(index).copied()
 }
 
 /// Truncate a beach name to fit in the grid
 fn truncate_name(name: &str, max_len: usize) -> String {
-    if name.len() <= max_len {
-        format!("{:<width$}", name, width = max_len)
+    if text::width(name) <= max_len {
+        text::pad_right(name, max_len)
     } else {
         // Truncate to max_len - 1 and pad to ensure fixed width
-        let truncated: String = name.chars().take(max_len - 1).collect();
-        format!("{:<width$}", truncated, width = max_len)
+        text::pad_right(text::truncate(name, max_len - 1), max_len)
     }
 }
 
@@ -209,8 +113,9 @@ fn truncate_name(name: &str, max_len: usize) -> String {
 ///
 /// # Arguments
 /// * `frame` - The ratatui frame to render into
-/// * `app` - The application state
-pub fn render(frame: &mut Frame, app: &App) {
+/// * `app` - The application state; the grid scrolls its hours to keep the
+///   cursor in view
+pub fn render(frame: &mut Frame, app: &mut App) {
     let area = frame.area();
 
     // Create main bordered block
@@ -230,6 +135,7 @@ pub fn render(frame: &mut Frame, app: &App) {
     // Create layout:
     // - Activity selector row
     // - Heatmap grid
+    // - Readout of what the cursor cell was scored from
     // - Legend
     // - Best recommendation / Selected cell
     // - Help bar
@@ -238,8 +144,9 @@ pub fn render(frame: &mut Frame, app: &App) {
         .constraints([
             Constraint::Length(1), // Activity selector
             Constraint::Min(8),    // Heatmap grid
+            Constraint::Length(1), // Cursor cell readout
             Constraint::Length(2), // Legend
-            Constraint::Length(3), // Best recommendation + selected
+            Constraint::Length(4), // Best recommendation + selected + best day so far
             Constraint::Length(1), // Help bar
         ])
         .split(inner_area);
@@ -247,9 +154,10 @@ pub fn render(frame: &mut Frame, app: &App) {
     // Render each section
     render_activity_selector(frame, chunks[0], app.current_activity);
     render_heatmap_grid(frame, chunks[1], app);
-    render_legend(frame, chunks[2]);
-    render_recommendations(frame, chunks[3], app);
-    render_help_bar(frame, chunks[4]);
+    frame.render_widget(Paragraph::new(readout_line(app)), chunks[2]);
+    render_legend(frame, chunks[3], app.plan_show_crowds);
+    render_recommendations(frame, chunks[4], app);
+    render_help_bar(frame, chunks[5]);
 }
 
 /// Renders the activity selector row
@@ -269,6 +177,9 @@ fn render_activity_selector(frame: &mut Frame, area: Rect, current_activity: Opt
             Activity::Sailing => "Sailing",
             Activity::Sunset => "Sunset",
             Activity::Peace => "Peace",
+            Activity::DogWalk => "Dog Walk",
+            Activity::Surf => "Surf",
+            Activity::RunWalk => "Run/Walk",
         };
 
         let style = if is_selected {
@@ -294,44 +205,68 @@ fn render_activity_selector(frame: &mut Frame, area: Rect, current_activity: Opt
     frame.render_widget(paragraph, area);
 }
 
+/// Width of the beach name column, names truncated to fit
+const BEACH_NAME_WIDTH: usize = 12;
+
+/// Width of each hour cell: tide char, score blocks, pin marker and a gap
+/// that holds the good score marker
+const CELL_WIDTH: usize = 6;
+
+/// How many hour columns fit across `width`, after the beach names and a
+/// column for the more-hours marker
+fn visible_hour_columns(width: u16) -> usize {
+    (width as usize).saturating_sub(BEACH_NAME_WIDTH + 2) / CELL_WIDTH
+}
+
 /// Renders the heatmap grid with beaches as rows and hours as columns
-fn render_heatmap_grid(frame: &mut Frame, area: Rect, app: &App) {
+///
+/// When the terminal is too narrow for every hour, the hour columns scroll
+/// under the beach names, with a marker on the side that has more.
+fn render_heatmap_grid(frame: &mut Frame, area: Rect, app: &mut App) {
+    app.scroll_plan_hours(visible_hour_columns(area.width));
+    let app = &*app;
     let beaches = all_beaches();
     let (start_hour, end_hour) = app.plan_time_range;
-    let hours: Vec<u8> = (start_hour..=end_hour).collect();
-
-    // Calculate column widths
-    let beach_name_width = 12; // Truncate beach names to fit
-    let cell_width = 6; // Width for each hour cell (tide char + score blocks + space)
+    let all_hours: Vec<u8> = (start_hour..=end_hour).collect();
+    let first = app.plan_column_offset;
+    let last = (first + app.plan_visible_hours).min(all_hours.len());
+    let hours = &all_hours[first..last];
 
+    let scores = app.plan_scores();
     let mut lines: Vec<Line> = Vec::new();
 
-    // Header row with hour labels
-    let mut header_spans = vec![Span::raw(format!(
-        "{:width$}",
-        "",
-        width = beach_name_width + 2
-    ))];
+    // Header row with hour labels, between markers for hours out of view
+    let more_left = if first > 0 { "\u{25C2}" } else { " " }; // ◂
+    let mut header_spans = vec![Span::styled(
+        format!("{:width$}{}", "", more_left, width = BEACH_NAME_WIDTH),
+        Style::default().fg(colors::HEADER),
+    )];
 
-    for hour in &hours {
-        let hour_str = format!("{:^width$}", format_hour_short(*hour), width = cell_width);
+    for hour in hours {
+        let hour_str = format!("{:^width$}", hour_label(*hour), width = CELL_WIDTH);
         header_spans.push(Span::styled(
             hour_str,
             Style::default().fg(colors::SECONDARY),
         ));
     }
+    if last < all_hours.len() {
+        header_spans.push(Span::styled(
+            "\u{25B8}", // ▸
+            Style::default().fg(colors::HEADER),
+        ));
+    }
     lines.push(Line::from(header_spans));
 
     // Empty line after header
     lines.push(Line::from(Span::styled(
-        format!("{:width$}", "", width = beach_name_width + 1),
+        format!("{:width$}", "", width = BEACH_NAME_WIDTH + 1),
         Style::default().fg(colors::SECONDARY),
     )));
 
     // Beach rows
     for (beach_idx, beach) in beaches.iter().enumerate() {
         let is_selected_beach = beach_idx == app.plan_cursor.0;
-        let beach_name = truncate_name(beach.name, beach_name_width);
+        let beach_name = truncate_name(&beach.name, BEACH_NAME_WIDTH);
 
         let name_style = if is_selected_beach {
             Style::default()
@@ -344,20 +279,30 @@ fn render_heatmap_grid(frame: &mut Frame, area: Rect, app: &App) {
         let mut row_spans = vec![Span::styled(format!("{} ", beach_name), name_style)];
 
         for (hour_idx, hour) in hours.iter().enumerate() {
-            let is_cursor = beach_idx == app.plan_cursor.0 && hour_idx == app.plan_cursor.1;
-            let score = compute_score(app, beach.id, *hour);
+            let is_cursor = beach_idx == app.plan_cursor.0 && first + hour_idx == app.plan_cursor.1;
+            let score = scores.score(beach_idx, *hour);
             let (block_char, block_color) = score_to_block(score);
 
             // Get tide indicator for this hour
-            let tide_char = get_tide_height_at_hour(app, beach.id, *hour)
+            let tide_char = get_tide_height_at_hour(app, &beach.id, *hour)
                 .map(|h| height_to_tide_char(h, 4.8))
                 .unwrap_or(' ');
 
-            // Include tide indicator in cell
+            // Include tide indicator in cell, with a pin marker for planned cells
+            let pin_char = if app.is_pinned(&beach.id, *hour) {
+                '\u{25C6}' // ◆
+            } else {
+                ' '
+            };
+            let good = if is_good_score(score) {
+                GOOD_SCORE_MARKER
+            } else {
+                " "
+            };
             let cell_content = if is_cursor {
-                format!("[{}{}]", tide_char, block_char)
+                format!("[{}{}]{}", tide_char, block_char, good)
             } else {
-                format!("{}{} ", tide_char, block_char)
+                format!(" {}{}{}{}", tide_char, block_char, pin_char, good)
             };
 
             let cell_style = if is_cursor {
@@ -372,33 +317,142 @@ fn render_heatmap_grid(frame: &mut Frame, area: Rect, app: &App) {
         }
 
         lines.push(Line::from(row_spans));
+
+        if app.plan_show_crowds {
+            lines.push(crowd_row(&scores, beach_idx, hours));
+        }
     }
 
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, area);
 }
 
-/// Renders the legend showing score ranges
-fn render_legend(frame: &mut Frame, area: Rect) {
-    let legend_line = Line::from(vec![
-        Span::styled("Legend: ", Style::default().fg(colors::SECONDARY)),
-        Span::styled(BLOCK_EXCELLENT, Style::default().fg(colors::EXCELLENT)),
-        Span::styled(" 80+  ", Style::default().fg(colors::SECONDARY)),
-        Span::styled(BLOCK_GOOD, Style::default().fg(colors::GOOD)),
-        Span::styled(" 60-79  ", Style::default().fg(colors::SECONDARY)),
-        Span::styled(BLOCK_FAIR, Style::default().fg(colors::FAIR)),
-        Span::styled(" 40-59  ", Style::default().fg(colors::SECONDARY)),
-        Span::styled(BLOCK_POOR, Style::default().fg(colors::POOR)),
-        Span::styled(" <40", Style::default().fg(colors::SECONDARY)),
-    ]);
+/// Row of predicted crowd buckets under a beach, one symbol per hour
+fn crowd_row(scores: &ScoreMatrix, beach_index: usize, hours: &[u8]) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        format!("{:<width$}", "  crowd", width = BEACH_NAME_WIDTH + 1),
+        Style::default().fg(colors::SECONDARY),
+    )];
+    for hour in hours {
+        let cell = match scores
+            .crowd(beach_index, *hour)
+            .map(CrowdBucket::from_level)
+        {
+            Some(bucket) => Span::styled(
+                format!("{:^width$}", bucket.symbol(), width = CELL_WIDTH),
+                Style::default().fg(crowd_color(bucket)),
+            ),
+            None => Span::raw(" ".repeat(CELL_WIDTH)),
+        };
+        spans.push(cell);
+    }
+    Line::from(spans)
+}
+
+/// Color of a crowd bucket, quieter hours in the colors of better scores
+fn crowd_color(bucket: CrowdBucket) -> Color {
+    match bucket {
+        CrowdBucket::Quiet => colors::EXCELLENT,
+        CrowdBucket::Moderate => colors::GOOD,
+        CrowdBucket::Busy => colors::FAIR,
+        CrowdBucket::Packed => colors::BAD,
+    }
+}
+
+/// What the cell under the cursor was scored from, read from the score
+/// matrix, e.g. "Scored from  24°C  wind 12 km/h  UV 6  tide 2.1 m  crowd 40%"
+fn readout_line(app: &App) -> Line<'static> {
+    // Without an activity nothing is scored
+    if app.current_activity.is_none() {
+        return Line::default();
+    }
+    let (beach_index, column) = app.plan_cursor;
+    let hour = app.plan_time_range.0 + column as u8;
+    let Some(inputs) = app.plan_scores().inputs(beach_index, hour) else {
+        return Line::from(Span::styled(
+            "No weather to score this hour from yet",
+            Style::default().fg(colors::SECONDARY),
+        ));
+    };
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(colors::SECONDARY));
+    let value = |text: String| Span::styled(text, Style::default().fg(colors::PRIMARY));
+    Line::from(vec![
+        label("Scored from  "),
+        value(format!("{:.0}\u{00B0}C", inputs.temperature)),
+        label("  wind "),
+        value(format!("{:.0} km/h", inputs.wind)),
+        label("  UV "),
+        value(format!("{:.0}", inputs.uv)),
+        label("  tide "),
+        value(format!("{:.1} m", inputs.tide_height)),
+        label("  crowd "),
+        value(format!("{:.0}%", inputs.crowd_level * 100.0)),
+    ])
+}
 
-    let cursor_line = Line::from(vec![
+/// Renders the legend showing score ranges, and the crowd buckets when the
+/// crowd rows are shown
+fn render_legend(frame: &mut Frame, area: Rect, show_crowds: bool) {
+    let legend_line = if theme::palette().is_colorblind() {
+        Line::from(vec![
+            Span::styled("Legend: ", Style::default().fg(colors::SECONDARY)),
+            Span::styled(GRADED_EXCELLENT, Style::default().fg(colors::EXCELLENT)),
+            Span::styled(" 80+  ", Style::default().fg(colors::SECONDARY)),
+            Span::styled(GRADED_GOOD, Style::default().fg(colors::GOOD)),
+            Span::styled(" 60-79  ", Style::default().fg(colors::SECONDARY)),
+            Span::styled(GRADED_FAIR, Style::default().fg(colors::FAIR)),
+            Span::styled(" 40-59  ", Style::default().fg(colors::SECONDARY)),
+            Span::styled(GRADED_POOR, Style::default().fg(colors::POOR)),
+            Span::styled(" 20-39  ", Style::default().fg(colors::SECONDARY)),
+            Span::styled(GRADED_BAD, Style::default().fg(colors::BAD)),
+            Span::styled(" <20", Style::default().fg(colors::SECONDARY)),
+        ])
+    } else {
+        Line::from(vec![
+            Span::styled("Legend: ", Style::default().fg(colors::SECONDARY)),
+            Span::styled(BLOCK_EXCELLENT, Style::default().fg(colors::EXCELLENT)),
+            Span::styled(" 80+  ", Style::default().fg(colors::SECONDARY)),
+            Span::styled(BLOCK_GOOD, Style::default().fg(colors::GOOD)),
+            Span::styled(" 60-79  ", Style::default().fg(colors::SECONDARY)),
+            Span::styled(BLOCK_FAIR, Style::default().fg(colors::FAIR)),
+            Span::styled(" 40-59  ", Style::default().fg(colors::SECONDARY)),
+            Span::styled(BLOCK_POOR, Style::default().fg(colors::POOR)),
+            Span::styled(" <40", Style::default().fg(colors::SECONDARY)),
+        ])
+    };
+
+    let mut cursor_spans = vec![
         Span::styled("        ", Style::default()),
         Span::styled("[ ]", Style::default().fg(colors::PRIMARY)),
         Span::styled(" Cursor  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("\u{25C6}", Style::default().fg(colors::PRIMARY)),
+        Span::styled(" Pinned  ", Style::default().fg(colors::SECONDARY)),
         Span::styled("▁▃▅▇", Style::default().fg(Color::Cyan)),
         Span::styled(" Tide height", Style::default().fg(colors::SECONDARY)),
-    ]);
+    ];
+    if let Some(score) = good_score() {
+        cursor_spans.push(Span::styled(
+            format!("  {}", GOOD_SCORE_MARKER),
+            Style::default().fg(colors::PRIMARY),
+        ));
+        cursor_spans.push(Span::styled(
+            format!(" {}+", score),
+            Style::default().fg(colors::SECONDARY),
+        ));
+    }
+    if show_crowds {
+        cursor_spans.push(Span::raw("  "));
+        cursor_spans.extend(
+            CrowdBucket::all().into_iter().map(|bucket| {
+                Span::styled(bucket.symbol(), Style::default().fg(crowd_color(bucket)))
+            }),
+        );
+        cursor_spans.push(Span::styled(
+            " Crowd",
+            Style::default().fg(colors::SECONDARY),
+        ));
+    }
+    let cursor_line = Line::from(cursor_spans);
 
     let paragraph = Paragraph::new(vec![legend_line, cursor_line]);
     frame.render_widget(paragraph, area);
@@ -406,11 +460,14 @@ fn render_legend(frame: &mut Frame, area: Rect) {
 
 /// Renders the best recommendation and selected cell sections
 fn render_recommendations(frame: &mut Frame, area: Rect, app: &App) {
+    let scores = app.plan_scores();
+    let beaches = all_beaches();
     let mut lines: Vec<Line> = Vec::new();
 
     // Best recommendation
-    if let Some((beach_name, _beach_id, hour, score)) = find_best_recommendation(app) {
-        let time_str = format_hour_long(hour);
+    if let Some((beach_index, hour, score)) = scores.best() {
+        let beach_name = &beaches[beach_index].name;
+        let time_str = format_hour(hour);
         lines.push(Line::from(vec![
             Span::styled(
                 "BEST: ",
@@ -432,22 +489,21 @@ fn render_recommendations(frame: &mut Frame, area: Rect, app: &App) {
         ]));
     } else {
         lines.push(Line::from(Span::styled(
-            "Select an activity (1-5) to see recommendations",
+            "Select an activity (1-8) to see recommendations",
             Style::default().fg(colors::SECONDARY),
         )));
     }
 
     // Selected cell info
-    let beaches = all_beaches();
     let (start_hour, _end_hour) = app.plan_time_range;
     let hours: Vec<u8> = (start_hour..=_end_hour).collect();
 
     if let Some(beach) = beaches.get(app.plan_cursor.0) {
         if let Some(hour) = hours.get(app.plan_cursor.1) {
-            let score = compute_score(app, beach.id, *hour);
-            let time_str = format_hour_long(*hour);
+            let score = scores.score(app.plan_cursor.0, *hour);
+            let time_str = format_hour(*hour);
 
-            lines.push(Line::from(vec![
+            let mut spans = vec![
                 Span::styled("SELECTED: ", Style::default().fg(colors::SECONDARY)),
                 Span::styled(
                     format!("{} @ {}  ", beach.name, time_str),
@@ -458,14 +514,77 @@ fn render_recommendations(frame: &mut Frame, area: Rect, app: &App) {
                     format!("{}/100", score),
                     Style::default().fg(score_to_block(score).1),
                 ),
-            ]));
+            ];
+            // The nearest beach along the path, for moving on to another
+            // beach later in the afternoon
+            if let Some(route) = connections(&beach.id).first() {
+                let name = get_beach_by_id(route.to).map_or(route.to, |b| b.name.as_str());
+                spans.push(Span::styled(
+                    format!("  {} min walk to {}", route.walk_minutes(), name),
+                    Style::default().fg(colors::SECONDARY),
+                ));
+            }
+            lines.push(Line::from(spans));
         }
     }
 
+    // Best day earlier this week, to check the scores against how it felt
+    if let Some(line) = app
+        .current_activity
+        .and_then(|activity| best_day_line(app, activity))
+    {
+        lines.push(line);
+    }
+
+    // Next pinned plan reminder
+    if let Some(plan) = plans::next_upcoming(&app.pinned_plans, Local::now()) {
+        let beach_name = get_beach_by_id(&plan.beach_id)
+            .map(|b| b.name.clone())
+            .unwrap_or_else(|| plan.beach_id.to_string());
+        let countdown = plan
+            .time_until(Local::now())
+            .map(plans::format_countdown)
+            .unwrap_or_default();
+        lines.push(Line::from(vec![
+            Span::styled("PINNED: ", Style::default().fg(colors::SELECTED)),
+            Span::styled(
+                format!("{} @ {}  ", beach_name, format_hour(plan.hour)),
+                Style::default().fg(colors::PRIMARY),
+            ),
+            Span::styled(countdown, Style::default().fg(colors::SECONDARY)),
+        ]));
+    }
+
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, area);
 }
 
+/// Retrospective line for the best day so far this week, e.g.
+/// "Tuesday was the best swimming day so far: 91 at Kitsilano Beach"
+fn best_day_line(app: &App, activity: Activity) -> Option<Line<'static>> {
+    let (beach, date, score) = app.best_day_this_week(activity)?;
+    Some(Line::from(vec![
+        Span::styled(
+            format!(
+                "{} was the best {} day so far: ",
+                date.format("%A"),
+                activity.label().to_lowercase()
+            ),
+            Style::default().fg(colors::SECONDARY),
+        ),
+        Span::styled(
+            score.to_string(),
+            Style::default()
+                .fg(score_to_block(score).1)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" at {}", beach.name),
+            Style::default().fg(colors::SECONDARY),
+        ),
+    ]))
+}
+
 /// Renders the help bar at the bottom
 fn render_help_bar(frame: &mut Frame, area: Rect) {
     let help_line = Line::from(vec![
@@ -473,8 +592,12 @@ fn render_help_bar(frame: &mut Frame, area: Rect) {
         Span::styled(" Hours  ", Style::default().fg(colors::SECONDARY)),
         Span::styled("\u{2191}/k \u{2193}/j", Style::default().fg(colors::HEADER)),
         Span::styled(" Beaches  ", Style::default().fg(colors::SECONDARY)),
-        Span::styled("1-5", Style::default().fg(colors::HEADER)),
+        Span::styled("1-8/Tab", Style::default().fg(colors::HEADER)),
         Span::styled(" Activity  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("Space", Style::default().fg(colors::HEADER)),
+        Span::styled(" Pin  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("c", Style::default().fg(colors::HEADER)),
+        Span::styled(" Crowds  ", Style::default().fg(colors::SECONDARY)),
         Span::styled("Enter", Style::default().fg(colors::HEADER)),
         Span::styled(" Go  ", Style::default().fg(colors::SECONDARY)),
         Span::styled("Esc", Style::default().fg(colors::HEADER)),
@@ -0,0 +1,355 @@
+//! Snapshot tests for every screen
+//!
+//! Each screen is rendered into a test backend at three canonical terminal
+//! sizes and its text compared against the snapshots in `snapshots/`, so a
+//! layout change shows up as a reviewable diff. Run `make snapshots`
+//! after an intended change to accept the new snapshots.
+//!
+//! Only the text is compared, not colors. Wall-clock readings are masked
+//! with same-width placeholders so the layout still lines up; the tide
+//! chart's note on when it starts or ended, shown only overnight, is cut
+//! from the tide state line along with the padding after it.
+
+use chrono::{DateTime, Local, NaiveTime, Utc};
+use insta::assert_snapshot;
+use ratatui::{backend::TestBackend, Frame, Terminal};
+
+use crate::activities::Activity;
+use crate::app::{App, AppState};
+use crate::data::{
+    all_beaches, BeachConditions, Closure, TideEvent, TideInfo, TideState, WaterQuality,
+    WaterStatus, Weather, WeatherCondition, WeatherProvider,
+};
+
+/// Narrow, standard and large terminals (width, height)
+const SIZES: [(u16, u16); 3] = [(60, 20), (80, 24), (120, 40)];
+
+/// Beach the fixtures are for: English Bay, which has no shade profile, so
+/// no shade outlook that changes through the day
+const BEACH_INDEX: usize = 1;
+
+/// Masks for text that depends on when the test runs
+const CLOCK_FILTERS: [(&str, &str); 6] = [
+    (
+        r"\b(Mon|Tue|Wed|Thu|Fri|Sat|Sun) [A-Z][a-z]{2} \d{2}\b",
+        "Ddd Mmm DD",
+    ),
+    (r"\b[A-Z][a-z]{2} \d{2}\b", "Mmm DD"),
+    (r"\b\d{1,2}:\d{2} [AP]M\b", "hh:mm"),
+    (r"\b\d{2}:\d{2}\b", "hh:mm"),
+    (r"\b\d{4}-\d{2}-\d{2}\b", "YYYY-MM-DD"),
+    (r"(of sand exposed)(?:  · chart (?:from|to) \S+)? *", "$1"),
+];
+
+/// Renders a screen and returns its text, one line per row with trailing
+/// spaces trimmed
+fn render_text(width: u16, height: u16, app: &mut App, draw: fn(&mut Frame, &mut App)) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|frame| draw(frame, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| {
+            let row: String = (0..width).map(|x| buffer[(x, y)].symbol()).collect();
+            row.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Snapshots a screen at every canonical size, as `<name>@<width>x<height>`
+fn assert_screen(name: &str, app: &mut App, draw: fn(&mut Frame, &mut App)) {
+    for (width, height) in SIZES {
+        let text = render_text(width, height, app, draw);
+        insta::with_settings!({
+            snapshot_suffix => format!("{}x{}", width, height),
+            filters => CLOCK_FILTERS.to_vec(),
+            prepend_module_to_snapshot => false,
+            omit_expression => true,
+        }, {
+            assert_snapshot!(name, text);
+        });
+    }
+}
+
+fn weather() -> Weather {
+    Weather {
+        temperature: 24.0,
+        feels_like: 25.0,
+        condition: WeatherCondition::Clear,
+        weather_code: None,
+        humidity: 55,
+        wind: 8.0,
+        wind_direction: Some(270.0),
+        uv: 6.0,
+        sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+        sunset: NaiveTime::from_hms_opt(21, 15, 0).unwrap(),
+        fetched_at: Utc::now(),
+        hourly: Vec::new(),
+        tomorrow: Vec::new(),
+        source: WeatherProvider::OpenMeteo,
+        parse_warnings: Vec::new(),
+    }
+}
+
+/// Today at `hour` on the machine's clock, so the tide chart's phase doesn't
+/// depend on when the test runs
+fn today_at(hour: u32) -> DateTime<Local> {
+    Local::now()
+        .date_naive()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+        .and_local_timezone(Local)
+        .earliest()
+        .unwrap()
+}
+
+fn tides() -> TideInfo {
+    TideInfo {
+        current_height: 2.5,
+        tide_state: TideState::Rising,
+        next_high: Some(TideEvent {
+            time: today_at(15),
+            height: 4.2,
+        }),
+        next_low: Some(TideEvent {
+            time: today_at(21),
+            height: 0.8,
+        }),
+        fetched_at: Utc::now(),
+    }
+}
+
+fn water_quality(status: WaterStatus) -> WaterQuality {
+    WaterQuality {
+        status,
+        ecoli_count: Some(45),
+        sample_date: Local::now().date_naive(),
+        advisory_reason: None,
+        fetched_at: Utc::now(),
+        no_samples_station: None,
+        sampling: None,
+    }
+}
+
+/// An app on `state`, with the given conditions for their beach
+fn app_with(state: AppState, conditions: Option<BeachConditions>) -> App {
+    let mut app = App::new();
+    app.state = state;
+    // Keep the user's own favorites and plans out of the snapshots
+    app.favorites.clear();
+    app.pinned_plans.clear();
+    if let Some(conditions) = conditions {
+        app.beach_conditions
+            .insert(conditions.beach.id.clone(), conditions);
+    }
+    app
+}
+
+/// The fixture beach's conditions with any combination of data
+fn conditions(
+    weather: Option<Weather>,
+    tides: Option<TideInfo>,
+    water_quality: Option<WaterQuality>,
+) -> BeachConditions {
+    BeachConditions {
+        beach: all_beaches()[BEACH_INDEX].clone(),
+        weather,
+        tides,
+        water_quality,
+        closure: None,
+        waves: None,
+        air_quality: None,
+        seawall_closure: None,
+    }
+}
+
+fn draw_beach_list(frame: &mut Frame, app: &mut App) {
+    super::render_beach_list(frame, app);
+}
+
+fn draw_beach_detail(frame: &mut Frame, app: &mut App) {
+    let beach_id = all_beaches()[BEACH_INDEX].id.clone();
+    super::render_beach_detail(frame, app, &beach_id);
+}
+
+fn draw_plan_trip(frame: &mut Frame, app: &mut App) {
+    super::render_plan_trip(frame, app);
+}
+
+fn draw_dashboard(frame: &mut Frame, app: &mut App) {
+    super::render_dashboard(frame, app);
+}
+
+fn draw_water_quality_overview(frame: &mut Frame, app: &mut App) {
+    super::render_water_quality_overview(frame, app);
+}
+
+fn draw_help_overlay(frame: &mut Frame, app: &mut App) {
+    super::render_beach_list(frame, app);
+    super::render_help_overlay(frame, &app.help);
+}
+
+#[test]
+fn snapshot_beach_list_without_data() {
+    let mut app = app_with(AppState::BeachList, None);
+    assert_screen("beach_list_without_data", &mut app, draw_beach_list);
+}
+
+#[test]
+fn snapshot_beach_list_with_water_quality() {
+    let mut app = app_with(
+        AppState::BeachList,
+        Some(conditions(
+            None,
+            None,
+            Some(water_quality(WaterStatus::Advisory)),
+        )),
+    );
+    assert_screen("beach_list_with_water_quality", &mut app, draw_beach_list);
+}
+
+#[test]
+fn snapshot_beach_detail_without_data() {
+    let beach_id = all_beaches()[BEACH_INDEX].id.clone();
+    let mut app = app_with(AppState::BeachDetail(beach_id), None);
+    assert_screen("beach_detail_without_data", &mut app, draw_beach_detail);
+}
+
+#[test]
+fn snapshot_beach_detail_data_permutations() {
+    let beach_id = all_beaches()[BEACH_INDEX].id.clone();
+    let permutations = [
+        ("weather_only", conditions(Some(weather()), None, None)),
+        ("tides_only", conditions(None, Some(tides()), None)),
+        (
+            "water_quality_only",
+            conditions(None, None, Some(water_quality(WaterStatus::Safe))),
+        ),
+        (
+            "advisory",
+            conditions(None, None, Some(water_quality(WaterStatus::Advisory))),
+        ),
+        (
+            "all_data",
+            conditions(
+                Some(weather()),
+                Some(tides()),
+                Some(water_quality(WaterStatus::Safe)),
+            ),
+        ),
+        (
+            "closed",
+            BeachConditions {
+                closure: Some(Closure {
+                    reason: "Oil sheen on the water".to_string(),
+                    until: None,
+                    fetched_at: Utc::now(),
+                }),
+                ..conditions(None, None, Some(water_quality(WaterStatus::Safe)))
+            },
+        ),
+    ];
+
+    for (name, conditions) in permutations {
+        let mut app = app_with(AppState::BeachDetail(beach_id.clone()), Some(conditions));
+        assert_screen(
+            &format!("beach_detail_{}", name),
+            &mut app,
+            draw_beach_detail,
+        );
+    }
+}
+
+#[test]
+fn snapshot_plan_trip() {
+    let mut app = app_with(AppState::PlanTrip, None);
+    assert_screen("plan_trip_without_activity", &mut app, draw_plan_trip);
+
+    app.current_activity = Some(Activity::Swimming);
+    assert_screen("plan_trip_swimming", &mut app, draw_plan_trip);
+}
+
+#[test]
+fn test_plan_trip_reads_out_what_the_cursor_cell_was_scored_from() {
+    let mut app = app_with(
+        AppState::PlanTrip,
+        Some(conditions(Some(weather()), Some(tides()), None)),
+    );
+    app.current_activity = Some(Activity::Swimming);
+    app.plan_cursor = (BEACH_INDEX, 4);
+    let readout = |app: &mut App| {
+        render_text(120, 40, app, draw_plan_trip)
+            .lines()
+            .find(|line| line.contains("Scored from") || line.contains("No weather"))
+            .map(str::to_string)
+            .unwrap()
+    };
+
+    // The crowd estimate depends on the day, so stop short of it
+    let line = readout(&mut app);
+    assert!(line.contains("Scored from  24\u{00B0}C  wind "), "{}", line);
+    assert!(line.contains("UV 6  tide 2.5 m  crowd "), "{}", line);
+
+    app.plan_cursor = (0, 4);
+    assert!(readout(&mut app).contains("No weather to score this hour from yet"));
+}
+
+#[test]
+fn test_plan_trip_shows_a_crowd_row_under_each_beach_when_toggled() {
+    let mut app = app_with(AppState::PlanTrip, None);
+    // The predicted buckets depend on the day, so count the rows instead
+    let crowd_rows = |app: &mut App| {
+        render_text(120, 60, app, draw_plan_trip)
+            .lines()
+            .filter(|line| line.contains("  crowd "))
+            .count()
+    };
+    assert_eq!(crowd_rows(&mut app), 0);
+
+    app.plan_show_crowds = true;
+    assert_eq!(crowd_rows(&mut app), all_beaches().len());
+    assert!(render_text(120, 60, &mut app, draw_plan_trip).contains(" Crowd"));
+}
+
+#[test]
+fn snapshot_dashboard() {
+    let mut app = app_with(
+        AppState::Dashboard,
+        Some(conditions(
+            None,
+            None,
+            Some(water_quality(WaterStatus::Safe)),
+        )),
+    );
+    assert_screen("dashboard", &mut app, draw_dashboard);
+}
+
+#[test]
+fn snapshot_water_quality_overview() {
+    let mut app = app_with(
+        AppState::WaterQualityOverview,
+        Some(conditions(
+            None,
+            None,
+            Some(water_quality(WaterStatus::Advisory)),
+        )),
+    );
+    assert_screen(
+        "water_quality_overview",
+        &mut app,
+        draw_water_quality_overview,
+    );
+
+    app.water_quality_by_status = true;
+    assert_screen(
+        "water_quality_overview_by_status",
+        &mut app,
+        draw_water_quality_overview,
+    );
+}
+
+#[test]
+fn snapshot_help_overlay() {
+    let mut app = app_with(AppState::BeachList, None);
+    assert_screen("help_overlay", &mut app, draw_help_overlay);
+}
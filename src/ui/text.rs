@@ -0,0 +1,67 @@
+//! Text measurement for layout math
+//!
+//! Columns are laid out in terminal cells, not chars: an emoji or CJK glyph
+//! takes two cells and a combining mark none, so padding a name counted in
+//! chars pushes every column after it out of line. Layout code measures,
+//! pads and truncates through these helpers instead.
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Display width of `text` in terminal cells
+pub fn width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// `text` padded with spaces on the right to `width` cells; text that is
+/// already as wide is returned unchanged
+pub fn pad_right(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(self::width(text));
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+/// The longest prefix of `text` that fits in `width` cells
+///
+/// A wide glyph that would straddle the edge is left out rather than split,
+/// so the result can be a cell short of `width`.
+pub fn truncate(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (index, c) in text.char_indices() {
+        used += c.width().unwrap_or(0);
+        if used > width {
+            return &text[..index];
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wide_glyphs_take_two_cells() {
+        assert_eq!(width("Kitsilano"), 9);
+        assert_eq!(width("海滩"), 4);
+        assert_eq!(width("🌊 Beach"), 8);
+        // A combining accent takes no cell of its own
+        assert_eq!(width("Cafe\u{301}"), 4);
+
+        assert_eq!(pad_right("海滩", 6), "海滩  ");
+        assert_eq!(pad_right("Kitsilano", 4), "Kitsilano");
+    }
+
+    #[test]
+    fn test_truncation_never_splits_a_wide_glyph() {
+        assert_eq!(truncate("海滩公园", 5), "海滩");
+        assert_eq!(truncate("海滩公园", 4), "海滩");
+        assert_eq!(truncate("Cafe\u{301} Beach", 4), "Cafe\u{301}");
+        assert_eq!(truncate("Jericho", 20), "Jericho");
+
+        for name in ["海滩公园", "🌊🌊🌊", "Spanish Banks"] {
+            for cells in 0..10 {
+                let fitted = pad_right(truncate(name, cells), cells);
+                assert_eq!(width(&fitted), cells, "{:?} in {}", name, cells);
+            }
+        }
+    }
+}
@@ -0,0 +1,613 @@
+//! Terminal color support and palette fallback
+//!
+//! Screens pick colors from their own `colors` modules assuming a modern
+//! terminal. This module decides what the terminal can actually show and
+//! rewrites each finished frame to fit, so a bare `screen` session over SSH
+//! gets a readable 8-color rendering without every screen knowing about it.
+//!
+//! Colorblind palettes (`--palette`) work the same way: the greens, reds and
+//! yellows screens use for statuses and scores are swapped for hues that stay
+//! apart with that deficiency. Screens that would otherwise tell statuses
+//! apart by color alone check `palette()` to add letters as well. The
+//! replacement colors, like the ambient accents below, come from the themes
+//! asset, so they can be changed without a rebuild.
+//!
+//! Ambient mode (`--ambient`) works the same way: the border and header
+//! accent of a finished frame is recolored to reflect the selected beach's
+//! conditions, blue-grey in the rain and warm gold around sunset.
+//!
+//! So does the night palette: between sunset and sunrise at the beach on
+//! screen, bright colors step down to their plain ones and plain ones dim,
+//! so the screen isn't glaring in a dark room.
+//!
+//! Rankings mark their top three with `rank_marker`: medal emoji, or with
+//! `--ranks numerals` colored "1." to "3." for fonts that draw the medals
+//! double-width.
+
+use std::env;
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+use crate::cli::{Palette, RankMarkers};
+use crate::data::assets::{self, AssetColor};
+use crate::data::{BeachConditions, WeatherCondition};
+
+/// Accent every screen draws its borders and section headers in
+const ACCENT: Color = Color::Cyan;
+
+/// Ambient accent while it's raining at the beach, from the themes asset
+fn rain_accent() -> Color {
+    assets::themes().ambient.rain.0
+}
+
+/// Ambient accent around sunset, from the themes asset
+fn sunset_accent() -> Color {
+    assets::themes().ambient.sunset.0
+}
+
+/// How close to sunset, either side, the sunset accent is used
+const SUNSET_WINDOW_MINUTES: i64 = 60;
+
+/// Palette chosen at startup
+static PALETTE: OnceLock<Palette> = OnceLock::new();
+
+/// Installs the palette screens are drawn in
+///
+/// Returns `false` if a palette was already installed.
+pub fn set_palette(palette: Palette) -> bool {
+    PALETTE.set(palette).is_ok()
+}
+
+/// The palette screens are drawn in; the standard one unless another was
+/// installed
+pub fn palette() -> Palette {
+    PALETTE.get().copied().unwrap_or_default()
+}
+
+/// Rank markers chosen at startup
+static RANK_MARKERS: OnceLock<RankMarkers> = OnceLock::new();
+
+/// Medal emoji and numeral for first, second and third place, with their
+/// gold, silver and bronze
+const RANKS: [(&str, &str, Color); 3] = [
+    ("\u{1F947}", "1.", Color::Yellow),
+    ("\u{1F948}", "2.", Color::Gray),
+    ("\u{1F949}", "3.", Color::Rgb(205, 127, 50)),
+];
+
+/// Installs how the top three of a ranking are marked
+///
+/// Returns `false` if markers were already installed.
+pub fn set_rank_markers(markers: RankMarkers) -> bool {
+    RANK_MARKERS.set(markers).is_ok()
+}
+
+/// How the top three of a ranking are marked; medals unless numerals were
+/// installed
+pub fn rank_markers() -> RankMarkers {
+    RANK_MARKERS.get().copied().unwrap_or_default()
+}
+
+/// The two-column marker for place `index` (0 for first) of a ranking,
+/// blank after third
+pub fn rank_marker(index: usize) -> Span<'static> {
+    rank_marker_in(rank_markers(), index)
+}
+
+fn rank_marker_in(markers: RankMarkers, index: usize) -> Span<'static> {
+    match (RANKS.get(index), markers) {
+        (Some(&(medal, _, _)), RankMarkers::Medals) => Span::raw(medal),
+        (Some(&(_, numeral, color)), RankMarkers::Numerals) => Span::styled(
+            numeral,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        (None, _) => Span::raw("  "),
+    }
+}
+
+/// How many colors the terminal can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    /// 24-bit RGB
+    TrueColor,
+    /// The xterm 256-color palette
+    Ansi256,
+    /// The eight basic ANSI colors, with bold and dim for emphasis
+    Basic,
+}
+
+impl ColorSupport {
+    /// Detects color support from `COLORTERM` and `TERM`
+    pub fn detect() -> Self {
+        Self::from_env(
+            env::var("COLORTERM").ok().as_deref(),
+            env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    /// Works out color support from the values of `COLORTERM` and `TERM`
+    ///
+    /// Without a `TERM` (e.g. Windows consoles) full color is assumed.
+    pub fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return ColorSupport::TrueColor;
+        }
+        match term {
+            None => ColorSupport::TrueColor,
+            Some(term) if term.contains("truecolor") || term.contains("direct") => {
+                ColorSupport::TrueColor
+            }
+            Some(term) if term.contains("256color") => ColorSupport::Ansi256,
+            Some(_) => ColorSupport::Basic,
+        }
+    }
+}
+
+/// Rewrites every cell of a rendered frame to colors the terminal supports
+pub fn adapt_buffer(buffer: &mut Buffer, support: ColorSupport) {
+    if support == ColorSupport::TrueColor {
+        return;
+    }
+    for cell in buffer.content.iter_mut() {
+        let (fg, fg_modifier) = adapt_color(cell.fg, support);
+        let (bg, _) = adapt_color(cell.bg, support);
+        cell.fg = fg;
+        cell.bg = bg;
+        cell.modifier |= fg_modifier;
+    }
+}
+
+/// Recolors a rendered frame for a palette
+///
+/// The replacements are the palette's in the themes asset. The built-in
+/// colorblind palettes use the Okabe-Ito colors, which stay distinct under
+/// the common color vision deficiencies; the standard palette replaces
+/// nothing unless overridden. Run before `adapt_buffer` so the new colors
+/// are fitted to the terminal too.
+pub fn apply_palette(buffer: &mut Buffer, palette: Palette) {
+    let Some(replacements) = palette
+        .to_possible_value()
+        .and_then(|value| assets::themes().palettes.get(value.get_name()))
+        .filter(|replacements| !replacements.is_empty())
+    else {
+        return;
+    };
+    let replace = |color: Color| {
+        replacements
+            .get(&AssetColor(color))
+            .map_or(color, |replacement| replacement.0)
+    };
+    for cell in buffer.content.iter_mut() {
+        cell.fg = replace(cell.fg);
+        cell.bg = replace(cell.bg);
+    }
+}
+
+/// Ambient accent for a beach's conditions at `now`, or `None` to keep the
+/// regular accent
+///
+/// Rain wins over sunset: a wet evening isn't golden.
+pub fn ambient_accent(conditions: &BeachConditions, now: DateTime<Utc>) -> Option<Color> {
+    let weather = conditions.weather.as_ref()?;
+    if matches!(
+        weather.condition,
+        WeatherCondition::Rain | WeatherCondition::Showers | WeatherCondition::Thunderstorm
+    ) {
+        return Some(rain_accent());
+    }
+
+    let local = conditions.beach.timezone.local(now);
+    let sunset = local.date().and_time(weather.sunset);
+    let from_sunset = (local - sunset).num_minutes().abs();
+    (from_sunset <= SUNSET_WINDOW_MINUTES).then_some(sunset_accent())
+}
+
+/// Whether it's dark at a beach at `now`, between its sunset and sunrise,
+/// or `None` without weather to say when those are
+pub fn is_night(conditions: &BeachConditions, now: DateTime<Utc>) -> Option<bool> {
+    let weather = conditions.weather.as_ref()?;
+    let time = conditions.beach.timezone.local(now).time();
+    Some(time >= weather.sunset || time < weather.sunrise)
+}
+
+/// Redraws a rendered frame in the dimmer night palette
+///
+/// Run before `adapt_buffer` so the dimmed colors are fitted to the
+/// terminal too.
+pub fn apply_night(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        cell.fg = night_color(cell.fg);
+        cell.bg = night_color(cell.bg);
+    }
+}
+
+/// The color the night palette draws `color` in: one step down for named
+/// colors, two thirds as bright for RGB ones
+fn night_color(color: Color) -> Color {
+    let dim = |level: u8| (level as u16 * 2 / 3) as u8;
+    match color {
+        Color::White => Color::Gray,
+        Color::Gray => Color::DarkGray,
+        Color::LightRed => Color::Red,
+        Color::LightGreen => Color::Green,
+        Color::LightYellow => Color::Yellow,
+        Color::LightBlue => Color::Blue,
+        Color::LightMagenta => Color::Magenta,
+        Color::LightCyan => Color::Cyan,
+        Color::Rgb(r, g, b) => Color::Rgb(dim(r), dim(g), dim(b)),
+        other => other,
+    }
+}
+
+/// Recolors the accent of a rendered frame's borders and bold headers
+///
+/// Other accent-colored cells, such as rising tide arrows, keep their color.
+/// Run before `adapt_buffer` so the new accent is fitted to the terminal too.
+pub fn apply_accent(buffer: &mut Buffer, accent: Color) {
+    for cell in buffer.content.iter_mut() {
+        let is_border = cell
+            .symbol()
+            .chars()
+            .next()
+            .is_some_and(|c| ('\u{2500}'..='\u{257F}').contains(&c));
+        if cell.fg == ACCENT && (is_border || cell.modifier.contains(Modifier::BOLD)) {
+            cell.fg = accent;
+        }
+    }
+}
+
+/// Inverts every cell of a rendered frame, for an alert's screen flash
+pub fn flash(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        cell.modifier.toggle(Modifier::REVERSED);
+    }
+}
+
+/// Maps a color to the nearest one the terminal supports, plus any modifier
+/// needed to keep it distinct from its neighbours
+pub fn adapt_color(color: Color, support: ColorSupport) -> (Color, Modifier) {
+    match support {
+        ColorSupport::TrueColor => (color, Modifier::empty()),
+        ColorSupport::Ansi256 => match color {
+            Color::Rgb(r, g, b) => (Color::Indexed(rgb_to_ansi256(r, g, b)), Modifier::empty()),
+            other => (other, Modifier::empty()),
+        },
+        ColorSupport::Basic => to_basic(color),
+    }
+}
+
+/// Maps any color onto the eight basic ANSI colors
+///
+/// Bright variants become their base color in bold, and dark gray (which most
+/// 8-color terminals can't show) becomes dimmed gray.
+fn to_basic(color: Color) -> (Color, Modifier) {
+    match color {
+        Color::Reset
+        | Color::Black
+        | Color::Red
+        | Color::Green
+        | Color::Yellow
+        | Color::Blue
+        | Color::Magenta
+        | Color::Cyan
+        | Color::Gray => (color, Modifier::empty()),
+        Color::White => (Color::Gray, Modifier::empty()),
+        Color::DarkGray => (Color::Gray, Modifier::DIM),
+        Color::LightRed => (Color::Red, Modifier::BOLD),
+        Color::LightGreen => (Color::Green, Modifier::BOLD),
+        Color::LightYellow => (Color::Yellow, Modifier::BOLD),
+        Color::LightBlue => (Color::Blue, Modifier::BOLD),
+        Color::LightMagenta => (Color::Magenta, Modifier::BOLD),
+        Color::LightCyan => (Color::Cyan, Modifier::BOLD),
+        Color::Indexed(index) => to_basic(indexed_to_color(index)),
+        Color::Rgb(r, g, b) => (nearest_basic(r, g, b), Modifier::empty()),
+    }
+}
+
+/// Resolves a 256-color palette index to a named or RGB color
+fn indexed_to_color(index: u8) -> Color {
+    const NAMED: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+        Color::DarkGray,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ];
+    match index {
+        0..=15 => NAMED[index as usize],
+        16..=231 => {
+            let level = |step: u8| if step == 0 { 0 } else { 55 + step * 40 };
+            let cube = index - 16;
+            Color::Rgb(level(cube / 36), level((cube / 6) % 6), level(cube % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            Color::Rgb(gray, gray, gray)
+        }
+    }
+}
+
+/// Nearest of the eight basic colors to an RGB value
+fn nearest_basic(r: u8, g: u8, b: u8) -> Color {
+    const BASIC: [(Color, (i32, i32, i32)); 8] = [
+        (Color::Black, (0, 0, 0)),
+        (Color::Red, (205, 0, 0)),
+        (Color::Green, (0, 205, 0)),
+        (Color::Yellow, (205, 205, 0)),
+        (Color::Blue, (0, 0, 238)),
+        (Color::Magenta, (205, 0, 205)),
+        (Color::Cyan, (0, 205, 205)),
+        (Color::Gray, (229, 229, 229)),
+    ];
+    let (r, g, b) = (r as i32, g as i32, b as i32);
+    BASIC
+        .iter()
+        .min_by_key(|(_, (br, bg, bb))| (r - br).pow(2) + (g - bg).pow(2) + (b - bb).pow(2))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+/// Nearest entry in the 6x6x6 color cube of the 256-color palette
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let step = |value: u8| -> u8 {
+        if value < 48 {
+            0
+        } else if value < 115 {
+            1
+        } else {
+            (value - 35) / 40
+        }
+    };
+    16 + 36 * step(r) + 6 * step(g) + step(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{buffer::Cell, layout::Rect};
+
+    #[test]
+    fn test_rank_markers_are_two_columns_wide() {
+        use unicode_width::UnicodeWidthStr;
+
+        for markers in [RankMarkers::Medals, RankMarkers::Numerals] {
+            for index in 0..4 {
+                assert_eq!(rank_marker_in(markers, index).content.width(), 2);
+            }
+        }
+        let second = rank_marker_in(RankMarkers::Numerals, 1);
+        assert_eq!(second.content, "2.");
+        assert_eq!(second.style.fg, Some(Color::Gray));
+        assert_eq!(rank_marker_in(RankMarkers::Medals, 0).content, "\u{1F947}");
+    }
+
+    #[test]
+    fn test_detects_support_from_environment() {
+        assert_eq!(
+            ColorSupport::from_env(Some("truecolor"), Some("screen")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, Some("xterm-256color")),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, Some("tmux-256color")),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, Some("screen")),
+            ColorSupport::Basic
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, Some("linux")),
+            ColorSupport::Basic
+        );
+        assert_eq!(ColorSupport::from_env(None, None), ColorSupport::TrueColor);
+    }
+
+    #[test]
+    fn test_basic_palette_keeps_distinctions_with_modifiers() {
+        assert_eq!(
+            adapt_color(Color::DarkGray, ColorSupport::Basic),
+            (Color::Gray, Modifier::DIM)
+        );
+        assert_eq!(
+            adapt_color(Color::LightCyan, ColorSupport::Basic),
+            (Color::Cyan, Modifier::BOLD)
+        );
+        assert_eq!(
+            adapt_color(Color::Green, ColorSupport::Basic),
+            (Color::Green, Modifier::empty())
+        );
+        // Bronze medal
+        assert_eq!(
+            adapt_color(Color::Rgb(205, 127, 50), ColorSupport::Basic).0,
+            Color::Yellow
+        );
+        assert_eq!(
+            adapt_color(Color::Indexed(196), ColorSupport::Basic).0,
+            Color::Red
+        );
+    }
+
+    #[test]
+    fn test_ansi256_only_converts_rgb() {
+        assert_eq!(
+            adapt_color(Color::Rgb(255, 0, 0), ColorSupport::Ansi256).0,
+            Color::Indexed(196)
+        );
+        assert_eq!(
+            adapt_color(Color::DarkGray, ColorSupport::Ansi256).0,
+            Color::DarkGray
+        );
+    }
+
+    #[test]
+    fn test_adapt_buffer_rewrites_cells() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buffer[(0, 0)]
+            .set_fg(Color::DarkGray)
+            .set_bg(Color::LightBlue);
+        buffer[(1, 0)].set_fg(Color::White);
+
+        adapt_buffer(&mut buffer, ColorSupport::Basic);
+
+        let first: &Cell = &buffer[(0, 0)];
+        assert_eq!(first.fg, Color::Gray);
+        assert_eq!(first.bg, Color::Blue);
+        assert!(first.modifier.contains(Modifier::DIM));
+        assert_eq!(buffer[(1, 0)].fg, Color::Gray);
+    }
+
+    #[test]
+    fn test_colorblind_palettes_replace_status_hues() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buffer[(0, 0)].set_fg(Color::Green);
+        buffer[(1, 0)].set_fg(Color::Red).set_bg(Color::LightRed);
+        buffer[(2, 0)].set_fg(Color::Cyan);
+
+        apply_palette(&mut buffer, Palette::RedGreen);
+
+        assert_eq!(buffer[(0, 0)].fg, Color::Rgb(0, 114, 178));
+        assert_eq!(buffer[(1, 0)].fg, Color::Rgb(213, 94, 0));
+        assert_eq!(buffer[(1, 0)].bg, Color::Rgb(230, 159, 0));
+        assert_eq!(buffer[(2, 0)].fg, Color::Cyan, "the accent is left alone");
+
+        // Safe and closed stay apart on an 8-color terminal too
+        adapt_buffer(&mut buffer, ColorSupport::Basic);
+        assert_ne!(buffer[(0, 0)].fg, buffer[(1, 0)].fg);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        buffer[(0, 0)].set_fg(Color::Green);
+        apply_palette(&mut buffer, Palette::Standard);
+        assert_eq!(buffer[(0, 0)].fg, Color::Green);
+    }
+
+    fn conditions(condition: WeatherCondition) -> BeachConditions {
+        use crate::data::{all_beaches, Weather, WeatherProvider};
+        use chrono::NaiveTime;
+
+        BeachConditions {
+            beach: all_beaches()[0].clone(),
+            weather: Some(Weather {
+                temperature: 18.0,
+                feels_like: 18.0,
+                condition,
+                weather_code: None,
+                humidity: 60,
+                wind: 10.0,
+                wind_direction: Some(270.0),
+                uv: 2.0,
+                sunrise: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+                sunset: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+                fetched_at: Utc::now(),
+                hourly: Vec::new(),
+                tomorrow: Vec::new(),
+                source: WeatherProvider::OpenMeteo,
+                parse_warnings: Vec::new(),
+            }),
+            tides: None,
+            water_quality: None,
+            closure: None,
+            waves: None,
+            air_quality: None,
+            seawall_closure: None,
+        }
+    }
+
+    #[test]
+    fn test_ambient_accent_follows_rain_and_sunset() {
+        use chrono::TimeZone;
+        // 20:30 and 14:00 PDT
+        let evening = Utc.with_ymd_and_hms(2026, 7, 16, 3, 30, 0).unwrap();
+        let afternoon = Utc.with_ymd_and_hms(2026, 7, 15, 21, 0, 0).unwrap();
+
+        let clear = conditions(WeatherCondition::Clear);
+        assert_eq!(ambient_accent(&clear, evening), Some(sunset_accent()));
+        assert_eq!(ambient_accent(&clear, afternoon), None);
+        let rain = conditions(WeatherCondition::Showers);
+        assert_eq!(ambient_accent(&rain, evening), Some(rain_accent()));
+        assert_eq!(ambient_accent(&rain, afternoon), Some(rain_accent()));
+    }
+
+    #[test]
+    fn test_night_runs_from_sunset_to_sunrise() {
+        use chrono::TimeZone;
+        // 21:30, 04:00 and 14:00 PDT against a 05:30 sunrise and 21:00 sunset
+        let after_sunset = Utc.with_ymd_and_hms(2026, 7, 16, 4, 30, 0).unwrap();
+        let before_sunrise = Utc.with_ymd_and_hms(2026, 7, 16, 11, 0, 0).unwrap();
+        let afternoon = Utc.with_ymd_and_hms(2026, 7, 15, 21, 0, 0).unwrap();
+
+        let clear = conditions(WeatherCondition::Clear);
+        assert_eq!(is_night(&clear, after_sunset), Some(true));
+        assert_eq!(is_night(&clear, before_sunrise), Some(true));
+        assert_eq!(is_night(&clear, afternoon), Some(false));
+
+        let mut unknown = clear;
+        unknown.weather = None;
+        assert_eq!(is_night(&unknown, after_sunset), None);
+    }
+
+    #[test]
+    fn test_night_palette_steps_colors_down() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buffer[(0, 0)].set_fg(Color::White);
+        buffer[(1, 0)].set_fg(Color::LightGreen);
+        buffer[(2, 0)].set_fg(Color::Rgb(230, 170, 90));
+        buffer[(3, 0)].set_fg(Color::Red);
+
+        apply_night(&mut buffer);
+
+        assert_eq!(buffer[(0, 0)].fg, Color::Gray);
+        assert_eq!(buffer[(1, 0)].fg, Color::Green);
+        assert_eq!(buffer[(2, 0)].fg, Color::Rgb(153, 113, 60));
+        assert_eq!(buffer[(3, 0)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_apply_accent_only_recolors_borders_and_headers() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buffer[(0, 0)].set_symbol("┌").set_fg(ACCENT);
+        buffer[(1, 0)]
+            .set_symbol("T")
+            .set_fg(ACCENT)
+            .set_style(ratatui::style::Style::default().add_modifier(Modifier::BOLD));
+        buffer[(2, 0)].set_symbol("↑").set_fg(ACCENT);
+
+        apply_accent(&mut buffer, sunset_accent());
+
+        assert_eq!(buffer[(0, 0)].fg, sunset_accent());
+        assert_eq!(buffer[(1, 0)].fg, sunset_accent());
+        assert_eq!(buffer[(2, 0)].fg, ACCENT);
+    }
+
+    #[test]
+    fn test_flash_inverts_every_cell() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buffer[(1, 0)].modifier = Modifier::REVERSED | Modifier::BOLD;
+
+        flash(&mut buffer);
+
+        assert_eq!(buffer[(0, 0)].modifier, Modifier::REVERSED);
+        assert_eq!(buffer[(1, 0)].modifier, Modifier::BOLD);
+    }
+}
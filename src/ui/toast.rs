@@ -0,0 +1,117 @@
+//! Toast notifications
+//!
+//! Draws the app's toast, such as a best window reminder, in a small box at
+//! the top right of the current view until it expires or a key is pressed.
+
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+
+use super::text;
+
+/// Widest the toast gets, including borders
+const MAX_WIDTH: u16 = 50;
+
+/// Renders the active toast, if any, on top of the current view
+pub fn render(frame: &mut Frame, app: &App, now: DateTime<Local>) {
+    let Some(message) = app.active_toast(now) else {
+        return;
+    };
+    let area = toast_area(frame.area(), message);
+    if area.width < 3 || area.height < 3 {
+        return;
+    }
+
+    let block = Block::default()
+        .title(" Reminder ")
+        .title_bottom(Line::from(Span::styled(
+            " any key ",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(message.to_string())
+            .wrap(Wrap { trim: true })
+            .block(block),
+        area,
+    );
+}
+
+/// Top-right box sized to the message, wrapping it at `MAX_WIDTH`
+fn toast_area(screen: Rect, message: &str) -> Rect {
+    let cells = text::width(message) as u16;
+    let width = (cells + 4).min(MAX_WIDTH).min(screen.width);
+    let text_width = width.saturating_sub(4).max(1);
+    let height = (cells.div_ceil(text_width) + 2).min(screen.height);
+    Rect {
+        x: screen.x + screen.width - width,
+        y: screen.y,
+        width,
+        height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn test_toast_is_drawn_top_right_and_wraps_long_messages() {
+        let mut app = App::new();
+        let now = Local::now();
+        app.show_toast(
+            "Sunset at Spanish Banks East in 30 min (score 86), bring a jacket".to_string(),
+            now,
+        );
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| render(frame, &app, now)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..80).map(|x| buffer[(x, y)].symbol()).collect() };
+
+        assert!(row(0).ends_with("┐"));
+        assert!(row(0).contains("Reminder"));
+        assert!(row(1).contains("Sunset at Spanish Banks"));
+        assert!(row(2).contains("jacket") || row(3).contains("jacket"));
+        assert!(row(0).starts_with(&" ".repeat(80 - MAX_WIDTH as usize)));
+    }
+
+    #[test]
+    fn test_toast_is_sized_to_the_message_cells() {
+        let screen = Rect::new(0, 0, 80, 24);
+        // Six wide glyphs take twelve cells, not six chars
+        let area = toast_area(screen, "海滩已开放🌊");
+        assert_eq!(area.width, 12 + 4);
+        assert_eq!(area.height, 3);
+        assert_eq!(area.x, 80 - area.width);
+    }
+
+    #[test]
+    fn test_expired_toast_is_not_drawn() {
+        let mut app = App::new();
+        let now = Local::now();
+        app.show_toast("Sunset soon".to_string(), now - chrono::Duration::hours(1));
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+        terminal.draw(|frame| render(frame, &app, now)).unwrap();
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(!content.contains("Sunset"));
+    }
+}
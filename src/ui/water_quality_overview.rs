@@ -0,0 +1,384 @@
+//! Water quality overview screen rendering
+//!
+//! Lists every monitoring station the beaches are sampled at, one row each
+//! with its status, last sample date and E. coli count, so the whole
+//! shoreline can be checked at once. Rows follow the beach list, or put the
+//! worst water first when sorted by status.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::beach_list::{water_status_color, water_status_icon, NOT_REPORTING_BADGE};
+use super::dashboard::water_status_label;
+use super::text;
+use crate::app::App;
+use crate::data::{all_beaches, Beach, WaterQuality, WaterStatus};
+
+/// Color scheme for the water quality overview
+mod colors {
+    use ratatui::style::Color;
+
+    /// Borders and headers
+    pub const HEADER: Color = Color::Cyan;
+    /// Primary text
+    pub const PRIMARY: Color = Color::White;
+    /// Secondary/dimmed text
+    pub const SECONDARY: Color = Color::Gray;
+    /// Missing data
+    pub const UNKNOWN: Color = Color::DarkGray;
+}
+
+/// Width of the station column
+const STATION_WIDTH: usize = 20;
+
+/// Width of the beach column
+const BEACH_WIDTH: usize = 20;
+
+/// Width of the status column, icon included
+const STATUS_WIDTH: usize = 16;
+
+/// Width of the sample date column
+const SAMPLED_WIDTH: usize = 12;
+
+/// Narrowest table that still has room for the beach column next to the
+/// station, status, date and a count
+const BEACH_COLUMN_MIN_WIDTH: u16 = 76;
+
+/// A monitoring station and its latest result, if loaded
+#[derive(Debug)]
+struct StationRow<'a> {
+    /// Station ID in the city's open data, e.g. "kitsilano-beach"
+    station: &'a str,
+    /// Beach the station samples
+    beach: &'a Beach,
+    /// Latest result, None until the beach's data has loaded
+    water_quality: Option<&'a WaterQuality>,
+}
+
+impl StationRow<'_> {
+    /// Status the row shows: stale samples count as unknown
+    fn status(&self) -> WaterStatus {
+        self.water_quality
+            .map_or(WaterStatus::Unknown, WaterQuality::effective_status)
+    }
+}
+
+/// Where a status sorts, worst water first and unknown last
+fn status_rank(status: WaterStatus) -> u8 {
+    match status {
+        WaterStatus::Closed => 0,
+        WaterStatus::Advisory => 1,
+        WaterStatus::Safe => 2,
+        WaterStatus::Unknown => 3,
+    }
+}
+
+/// One row per monitored station, in beach list order or by status
+///
+/// Sorting is stable, so stations with the same status keep list order.
+fn station_rows(app: &App, by_status: bool) -> Vec<StationRow<'_>> {
+    let mut rows: Vec<StationRow> = all_beaches()
+        .iter()
+        .filter_map(|beach| {
+            Some(StationRow {
+                station: beach.water_quality_id.as_deref()?,
+                beach,
+                water_quality: app
+                    .get_conditions(&beach.id)
+                    .and_then(|conditions| conditions.water_quality.as_ref()),
+            })
+        })
+        .collect();
+    if by_status {
+        rows.sort_by_key(|row| status_rank(row.status()));
+    }
+    rows
+}
+
+/// Renders the water quality overview screen
+///
+/// # Arguments
+/// * `frame` - The ratatui frame to render into
+/// * `app` - The application state
+pub fn render(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+
+    let main_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::HEADER))
+        .title(Span::styled(
+            " Water Quality ",
+            Style::default()
+                .fg(colors::PRIMARY)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let inner_area = main_block.inner(area);
+    frame.render_widget(main_block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),    // Station table
+            Constraint::Length(1), // Help bar
+        ])
+        .split(inner_area);
+
+    let rows = station_rows(app, app.water_quality_by_status);
+    let show_beach = chunks[0].width >= BEACH_COLUMN_MIN_WIDTH;
+    frame.render_widget(
+        Paragraph::new(build_table_lines(&rows, show_beach)),
+        chunks[0],
+    );
+    render_help_bar(frame, chunks[1], app.water_quality_by_status);
+}
+
+/// Builds the header and one line per station; the beach column is left
+/// out on narrow terminals
+fn build_table_lines(rows: &[StationRow], show_beach: bool) -> Vec<Line<'static>> {
+    let beach = if show_beach {
+        text::pad_right("Beach", BEACH_WIDTH)
+    } else {
+        String::new()
+    };
+    let header = format!(
+        "{}{}{}{}{}",
+        text::pad_right("Station", STATION_WIDTH),
+        beach,
+        text::pad_right("Status", STATUS_WIDTH),
+        text::pad_right("Sampled", SAMPLED_WIDTH),
+        "E. coli"
+    );
+    let mut lines = vec![Line::from(Span::styled(
+        header,
+        Style::default()
+            .fg(colors::HEADER)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    lines.extend(rows.iter().map(|row| build_station_line(row, show_beach)));
+    lines
+}
+
+/// One station's line, e.g. "kitsilano-beach  Kitsilano Beach  🟢 Safe  2026-07-14  20 CFU"
+fn build_station_line(row: &StationRow, show_beach: bool) -> Line<'static> {
+    let status = row.status();
+    let mut spans = vec![Span::styled(
+        text::pad_right(row.station, STATION_WIDTH),
+        Style::default().fg(colors::PRIMARY),
+    )];
+    if show_beach {
+        spans.push(Span::styled(
+            text::pad_right(&row.beach.name, BEACH_WIDTH),
+            Style::default().fg(colors::SECONDARY),
+        ));
+    }
+
+    let Some(wq) = row.water_quality else {
+        spans.push(Span::styled(
+            "Not loaded yet",
+            Style::default().fg(colors::UNKNOWN),
+        ));
+        return Line::from(spans);
+    };
+
+    // A station that missed its usual sampling says so rather than unknown
+    let (status_text, status_color) = if wq.is_not_reporting() {
+        (
+            format!("{} Not reporting", NOT_REPORTING_BADGE),
+            colors::UNKNOWN,
+        )
+    } else {
+        (
+            format!(
+                "{} {}",
+                water_status_icon(&status),
+                water_status_label(status)
+            ),
+            water_status_color(&status),
+        )
+    };
+    spans.push(Span::styled(
+        text::pad_right(&status_text, STATUS_WIDTH),
+        Style::default().fg(status_color),
+    ));
+    // Stale samples are dimmed: that's why their status reads unknown
+    let sampled_color = if wq.is_stale() {
+        colors::UNKNOWN
+    } else {
+        colors::SECONDARY
+    };
+    spans.push(Span::styled(
+        text::pad_right(
+            &wq.sample_date.format("%Y-%m-%d").to_string(),
+            SAMPLED_WIDTH,
+        ),
+        Style::default().fg(sampled_color),
+    ));
+    spans.push(match wq.ecoli_count {
+        Some(count) => Span::styled(
+            format!("{} CFU", count),
+            Style::default().fg(water_status_color(&wq.status)),
+        ),
+        None => Span::styled("--", Style::default().fg(colors::UNKNOWN)),
+    });
+    Line::from(spans)
+}
+
+/// Renders the key hints at the bottom of the screen
+fn render_help_bar(frame: &mut Frame, area: Rect, by_status: bool) {
+    let sort_label = if by_status {
+        " List order  "
+    } else {
+        " Sort by status  "
+    };
+    let spans = vec![
+        Span::styled("s", Style::default().fg(colors::HEADER)),
+        Span::styled(sort_label, Style::default().fg(colors::SECONDARY)),
+        Span::styled("r", Style::default().fg(colors::HEADER)),
+        Span::styled(" Refresh  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("Esc", Style::default().fg(colors::HEADER)),
+        Span::styled(" Back  ", Style::default().fg(colors::SECONDARY)),
+        Span::styled("q", Style::default().fg(colors::HEADER)),
+        Span::styled(" Quit", Style::default().fg(colors::SECONDARY)),
+    ];
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::AppState;
+    use crate::data::BeachConditions;
+    use chrono::{Duration, Local, Utc};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn water_quality(status: WaterStatus, ecoli: u32, days_old: i64) -> WaterQuality {
+        WaterQuality {
+            status,
+            ecoli_count: Some(ecoli),
+            sample_date: Local::now().date_naive() - Duration::days(days_old),
+            advisory_reason: None,
+            fetched_at: Utc::now(),
+            no_samples_station: None,
+            sampling: None,
+        }
+    }
+
+    fn app_with_water(results: &[(usize, WaterQuality)]) -> App {
+        let mut app = App::new();
+        app.state = AppState::WaterQualityOverview;
+        for (index, wq) in results {
+            let beach = all_beaches()[*index].clone();
+            app.beach_conditions.insert(
+                beach.id.clone(),
+                BeachConditions {
+                    beach,
+                    weather: None,
+                    tides: None,
+                    water_quality: Some(wq.clone()),
+                    closure: None,
+                    waves: None,
+                    air_quality: None,
+                    seawall_closure: None,
+                },
+            );
+        }
+        app
+    }
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_rows_cover_every_station_in_list_order() {
+        let app = app_with_water(&[]);
+        let rows = station_rows(&app, false);
+
+        let stations = all_beaches()
+            .iter()
+            .filter(|beach| beach.water_quality_id.is_some())
+            .count();
+        assert_eq!(rows.len(), stations);
+        assert_eq!(rows[0].beach.id, all_beaches()[0].id);
+        assert!(rows.iter().all(|row| row.water_quality.is_none()));
+    }
+
+    #[test]
+    fn test_sort_by_status_puts_the_worst_water_first() {
+        let app = app_with_water(&[
+            (0, water_quality(WaterStatus::Safe, 20, 1)),
+            (3, water_quality(WaterStatus::Advisory, 250, 1)),
+            (5, water_quality(WaterStatus::Closed, 800, 0)),
+            // Stale, so it reads unknown despite the safe sample
+            (1, water_quality(WaterStatus::Safe, 10, 10)),
+        ]);
+        let rows = station_rows(&app, true);
+
+        let statuses: Vec<WaterStatus> = rows.iter().take(3).map(StationRow::status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                WaterStatus::Closed,
+                WaterStatus::Advisory,
+                WaterStatus::Safe
+            ]
+        );
+        assert_eq!(rows[0].beach.id, all_beaches()[5].id);
+        assert!(rows[3..]
+            .iter()
+            .all(|row| row.status() == WaterStatus::Unknown));
+        assert_eq!(rows[3].beach.id, all_beaches()[1].id);
+    }
+
+    #[test]
+    fn test_station_line_shows_status_date_and_count() {
+        let app = app_with_water(&[(0, water_quality(WaterStatus::Advisory, 250, 1))]);
+        let rows = station_rows(&app, false);
+
+        let text = line_text(&build_station_line(&rows[0], true));
+        assert!(text.starts_with(rows[0].station), "{}", text);
+        assert!(text.contains(rows[0].beach.name.as_str()));
+        assert!(text.contains("Advisory"), "{}", text);
+        assert!(text.contains(&rows[0].water_quality.unwrap().sample_date.to_string()));
+        assert!(text.ends_with("250 CFU"), "{}", text);
+
+        assert!(line_text(&build_station_line(&rows[1], true)).ends_with("Not loaded yet"));
+
+        let narrow = line_text(&build_station_line(&rows[0], false));
+        assert!(!narrow.contains(rows[0].beach.name.as_str()));
+        assert!(narrow.ends_with("250 CFU"), "{}", narrow);
+    }
+
+    #[test]
+    fn test_render_shows_the_sort_hint() {
+        let mut app = app_with_water(&[]);
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(text.contains("Water Quality"));
+        assert!(text.contains("Sort by status"));
+
+        app.water_quality_by_status = true;
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(text.contains("List order"));
+    }
+}
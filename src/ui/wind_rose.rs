@@ -0,0 +1,470 @@
+//! ASCII wind rose
+//!
+//! Summarizes a day of hourly winds on a compass: each of the eight arms is
+//! as long as the share of hours the wind blows from that way, and colored by
+//! how hard it blows from there on average. Terminal cells are about twice as
+//! tall as they are wide, so east-west arms take two cells per step to keep
+//! the rose round. The rose shrinks to fit narrow areas, and a summary and
+//! color key sit beside it where there's room.
+//!
+//! Only plain ASCII is drawn, so the rose reads the same in `--plain` mode.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use super::text;
+use crate::data::HourlyForecast;
+
+/// Compass points the hourly wind direction is given in, clockwise from N
+const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+
+/// The rose's eight arms, clockwise from N
+const SECTORS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+
+/// Step of each arm in cells (columns, rows), clockwise from N
+const STEPS: [(i32, i32); 8] = [
+    (0, -1),
+    (2, -1),
+    (2, 0),
+    (2, 1),
+    (0, 1),
+    (-2, 1),
+    (-2, 0),
+    (-2, -1),
+];
+
+/// Character each arm is drawn with, clockwise from N
+const ARM_CHARS: [char; 8] = ['|', '/', '-', '\\', '|', '/', '-', '\\'];
+
+/// Longest arm, in steps
+const MAX_RADIUS: u16 = 3;
+
+/// Columns kept for the summary and color key beside the rose
+const LEGEND_MIN_WIDTH: u16 = 24;
+
+/// Gap between the rose and its legend
+const LEGEND_GAP: usize = 2;
+
+/// Upper bounds (km/h) of the calm, light and fresh bands; anything above
+/// is strong. Roughly Beaufort 0-1, 2-3 and 4
+const SPEED_BANDS: [(f64, &str, Color); 3] = [
+    (6.0, "calm", Color::DarkGray),
+    (20.0, "light", Color::Green),
+    (29.0, "fresh", Color::Yellow),
+];
+
+/// Band above the last of `SPEED_BANDS`
+const STRONG_BAND: (&str, Color) = ("strong", Color::Red);
+
+/// Compass labels and the center mark
+const LABEL_COLOR: Color = Color::Cyan;
+
+/// Summary text
+const SUMMARY_COLOR: Color = Color::Gray;
+
+/// Hours of wind from one of the eight directions
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Sector {
+    /// Hours from this direction; halves come from in-between points
+    hours: f64,
+    /// Sum of speed × hours, for the average
+    speed_hours: f64,
+}
+
+impl Sector {
+    /// Average speed from this direction in km/h
+    fn mean_speed(&self) -> f64 {
+        if self.hours > 0.0 {
+            self.speed_hours / self.hours
+        } else {
+            0.0
+        }
+    }
+}
+
+/// A day's hourly winds summarized by direction
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindRose {
+    sectors: [Sector; 8],
+    /// Strongest hour's speed (km/h) and compass point
+    peak: (f64, String),
+}
+
+impl WindRose {
+    /// Rows the rose takes, whatever the width
+    pub const HEIGHT: u16 = 2 * MAX_RADIUS + 3;
+
+    /// Builds a rose from hourly forecasts, or None if no hour has a known
+    /// direction
+    ///
+    /// Directions between two arms (NNE, ENE, ...) count half an hour to each.
+    pub fn from_hourly(hourly: &[HourlyForecast]) -> Option<Self> {
+        let mut sectors = [Sector::default(); 8];
+        let mut peak: Option<(f64, String)> = None;
+        for forecast in hourly {
+            let Some(point) = COMPASS_POINTS
+                .iter()
+                .position(|&p| p == forecast.wind_direction)
+            else {
+                continue;
+            };
+            let shares: &[(usize, f64)] = if point % 2 == 0 {
+                &[(point / 2, 1.0)]
+            } else {
+                &[(point / 2, 0.5), ((point / 2 + 1) % 8, 0.5)]
+            };
+            for &(sector, share) in shares {
+                sectors[sector].hours += share;
+                sectors[sector].speed_hours += share * forecast.wind;
+            }
+            if peak
+                .as_ref()
+                .is_none_or(|(speed, _)| forecast.wind > *speed)
+            {
+                peak = Some((forecast.wind, forecast.wind_direction.clone()));
+            }
+        }
+        Some(Self {
+            sectors,
+            peak: peak?,
+        })
+    }
+
+    /// Hours counted, i.e. hours with a known direction
+    fn hours(&self) -> f64 {
+        self.sectors.iter().map(|s| s.hours).sum()
+    }
+
+    /// Index of the direction the wind blows from most; the first on ties
+    fn prevailing(&self) -> usize {
+        (0..8).fold(0, |best, i| {
+            if self.sectors[i].hours > self.sectors[best].hours {
+                i
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Average speed over all hours in km/h
+    fn mean_speed(&self) -> f64 {
+        let hours = self.hours();
+        if hours > 0.0 {
+            self.sectors.iter().map(|s| s.speed_hours).sum::<f64>() / hours
+        } else {
+            0.0
+        }
+    }
+
+    /// Summary lines, e.g. "Mostly from W, 9 of 14 h" and
+    /// "Avg 16 km/h, peak 26 from WSW"
+    pub fn summary(&self) -> [String; 2] {
+        let prevailing = self.prevailing();
+        [
+            format!(
+                "Mostly from {}, {:.0} of {:.0} h",
+                SECTORS[prevailing],
+                self.sectors[prevailing].hours,
+                self.hours()
+            ),
+            format!(
+                "Avg {:.0} km/h, peak {:.0} from {}",
+                self.mean_speed(),
+                self.peak.0,
+                self.peak.1
+            ),
+        ]
+    }
+
+    /// The rose with its legend, fitted to `width` and always `HEIGHT` lines
+    ///
+    /// The rose shrinks to fit; below the smallest rose only the summary is
+    /// shown. The legend is left out when there's no room for it.
+    pub fn lines(&self, width: u16) -> Vec<Line<'static>> {
+        let radius = (1..=MAX_RADIUS)
+            .rev()
+            .find(|&radius| rose_width(radius) <= width);
+        let Some(radius) = radius else {
+            let mut lines: Vec<Line> = self
+                .summary()
+                .iter()
+                .map(|line| summary_line(line, width as usize))
+                .collect();
+            lines.resize(Self::HEIGHT as usize, Line::default());
+            return lines;
+        };
+
+        let mut rows = self.grid(radius);
+        rows.resize(Self::HEIGHT as usize, Vec::new());
+        let legend_width = width.saturating_sub(rose_width(radius) + LEGEND_GAP as u16);
+        let legend = if legend_width >= LEGEND_MIN_WIDTH {
+            self.legend(legend_width as usize)
+        } else {
+            Vec::new()
+        };
+
+        rows.into_iter()
+            .enumerate()
+            .map(|(row, mut spans)| {
+                if let Some(legend) = legend.get(row) {
+                    let pad = rose_width(radius) as usize + LEGEND_GAP - line_width(&spans);
+                    spans.push(Span::raw(" ".repeat(pad)));
+                    spans.extend(legend.spans.iter().cloned());
+                }
+                Line::from(spans)
+            })
+            .collect()
+    }
+
+    /// The rose of `radius` steps, one span list per row
+    fn grid(&self, radius: u16) -> Vec<Vec<Span<'static>>> {
+        let radius = radius as i32;
+        let (cols, rows) = (rose_width(radius as u16) as i32, 2 * radius + 3);
+        let (cx, cy) = (2 * radius + 1, radius + 1);
+        let mut cells: Vec<Vec<(char, Style)>> =
+            vec![vec![(' ', Style::default()); cols as usize]; rows as usize];
+        let mut put = |x: i32, y: i32, ch: char, style: Style| {
+            cells[y as usize][x as usize] = (ch, style);
+        };
+
+        let most = self.sectors.iter().map(|s| s.hours).fold(0.0_f64, f64::max);
+        for (i, sector) in self.sectors.iter().enumerate() {
+            if sector.hours <= 0.0 {
+                continue;
+            }
+            let length = ((sector.hours / most * radius as f64).ceil() as i32).clamp(1, radius);
+            let style = Style::default().fg(speed_color(sector.mean_speed()));
+            let (dx, dy) = STEPS[i];
+            for step in 1..=length {
+                put(cx + dx * step, cy + dy * step, ARM_CHARS[i], style);
+                // East-west arms fill the cell between steps too
+                if dy == 0 {
+                    put(cx + dx * step - dx / 2, cy, ARM_CHARS[i], style);
+                }
+            }
+        }
+
+        let label = Style::default()
+            .fg(LABEL_COLOR)
+            .add_modifier(Modifier::BOLD);
+        put(cx, cy, '+', Style::default().fg(LABEL_COLOR));
+        put(cx, 0, 'N', label);
+        put(cx, rows - 1, 'S', label);
+        put(0, cy, 'W', label);
+        put(cols - 1, cy, 'E', label);
+
+        cells
+            .into_iter()
+            .map(|row| {
+                let mut spans: Vec<Span<'static>> = Vec::new();
+                for (ch, style) in row {
+                    match spans.last_mut() {
+                        Some(span) if span.style == style => span.content.to_mut().push(ch),
+                        _ => spans.push(Span::styled(ch.to_string(), style)),
+                    }
+                }
+                spans
+            })
+            .collect()
+    }
+
+    /// Summary lines, a gap and the color key, fitted to `width`
+    fn legend(&self, width: usize) -> Vec<Line<'static>> {
+        let mut lines: Vec<Line> = self
+            .summary()
+            .iter()
+            .map(|line| summary_line(line, width))
+            .collect();
+        lines.push(Line::default());
+        let mut lower = 0.0;
+        for (upper, name, color) in SPEED_BANDS {
+            lines.push(key_line(
+                &format!("{} {:.0}-{:.0} km/h", name, lower, upper),
+                color,
+                width,
+            ));
+            lower = upper;
+        }
+        let (name, color) = STRONG_BAND;
+        lines.push(key_line(
+            &format!("{} {:.0}+ km/h", name, lower),
+            color,
+            width,
+        ));
+        lines
+    }
+}
+
+impl Widget for &WindRose {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Paragraph::new(self.lines(area.width)).render(area, buf);
+    }
+}
+
+/// Columns a rose of `radius` steps takes, labels included
+fn rose_width(radius: u16) -> u16 {
+    4 * radius + 3
+}
+
+/// Color for an average speed in km/h
+fn speed_color(speed: f64) -> Color {
+    SPEED_BANDS
+        .iter()
+        .find(|(upper, _, _)| speed < *upper)
+        .map_or(STRONG_BAND.1, |(_, _, color)| *color)
+}
+
+/// Display width of a row of spans
+fn line_width(spans: &[Span]) -> usize {
+    spans.iter().map(|span| text::width(&span.content)).sum()
+}
+
+/// A summary line truncated to `width`
+fn summary_line(line: &str, width: usize) -> Line<'static> {
+    Line::from(Span::styled(
+        text::truncate(line, width).to_string(),
+        Style::default().fg(SUMMARY_COLOR),
+    ))
+}
+
+/// A color key entry: a dash in the band's color, then its name and range
+fn key_line(label: &str, color: Color, width: usize) -> Line<'static> {
+    Line::from(vec![
+        Span::styled("--", Style::default().fg(color)),
+        Span::styled(
+            format!(" {}", text::truncate(label, width.saturating_sub(3))),
+            Style::default().fg(SUMMARY_COLOR),
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{DataQuality, WeatherCondition};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn hour(hour: u8, direction: &str, wind: f64) -> HourlyForecast {
+        HourlyForecast {
+            hour,
+            temperature: 20.0,
+            feels_like: 20.0,
+            condition: WeatherCondition::Clear,
+            wind,
+            wind_direction: direction.to_string(),
+            uv: 3.0,
+            precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        }
+    }
+
+    /// A westerly afternoon after a calm northerly morning
+    fn day() -> Vec<HourlyForecast> {
+        let mut hourly: Vec<HourlyForecast> = (7..10).map(|h| hour(h, "N", 4.0)).collect();
+        hourly.extend((10..18).map(|h| hour(h, "W", 18.0)));
+        hourly.push(hour(18, "WSW", 26.0));
+        hourly.push(hour(19, "WSW", 24.0));
+        hourly.push(hour(20, "SW", 22.0));
+        hourly
+    }
+
+    fn render_rows(rose: &WindRose, width: u16) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(width, WindRose::HEIGHT)).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget(rose, frame.area()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_in_between_points_split_across_arms() {
+        let rose = WindRose::from_hourly(&day()).unwrap();
+        assert_eq!(rose.sectors[6].hours, 9.0, "W gets half of each WSW hour");
+        assert_eq!(rose.sectors[5].hours, 2.0, "SW gets the other halves");
+        assert_eq!(rose.sectors[0].hours, 3.0);
+        assert_eq!(rose.hours(), 14.0);
+        assert_eq!(rose.prevailing(), 6);
+        assert_eq!(
+            rose.summary(),
+            [
+                "Mostly from W, 9 of 14 h".to_string(),
+                "Avg 16 km/h, peak 26 from WSW".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_known_directions_means_no_rose() {
+        assert_eq!(WindRose::from_hourly(&[]), None);
+        assert_eq!(WindRose::from_hourly(&[hour(12, "variable", 5.0)]), None);
+    }
+
+    #[test]
+    fn test_wide_rose_has_full_arms_and_legend() {
+        let rows = render_rows(&WindRose::from_hourly(&day()).unwrap(), 60);
+        assert_eq!(rows.len(), WindRose::HEIGHT as usize);
+        assert_eq!(rows[0], "       N         Mostly from W, 9 of 14 h");
+        // The prevailing westerly is the longest arm, out to the W label
+        assert!(rows[4].starts_with("W------+      E"), "{:?}", rows[4]);
+        assert!(rows[4].ends_with("light 6-20 km/h"), "{:?}", rows[4]);
+        // The calm northerly was a third as often, so gets one step of three
+        assert_eq!(&rows[3][7..8], "|");
+        assert_eq!(rows[2], "");
+        assert!(rows[5].starts_with("     /    "), "{:?}", rows[5]);
+    }
+
+    #[test]
+    fn test_rose_shrinks_and_drops_legend_when_narrow() {
+        let rose = WindRose::from_hourly(&day()).unwrap();
+
+        let rows = render_rows(&rose, 20);
+        assert_eq!(rows[4], "W------+      E");
+        assert!(rows.iter().all(|row| !row.contains("Mostly")));
+
+        let rows = render_rows(&rose, 14);
+        assert_eq!(rows[3], "W----+    E");
+
+        let rows = render_rows(&rose, 9);
+        assert_eq!(rows[2], "W--+  E");
+
+        let rows = render_rows(&rose, 6);
+        assert_eq!(rows[0], "Mostly");
+        assert_eq!(rows[1], "Avg 16");
+    }
+
+    #[test]
+    fn test_arms_are_colored_by_average_speed() {
+        let rose = WindRose::from_hourly(&day()).unwrap();
+        let lines = rose.lines(15);
+        let color_of = |row: usize, text: &str| {
+            lines[row]
+                .spans
+                .iter()
+                .find(|span| span.content.contains(text))
+                .and_then(|span| span.style.fg)
+        };
+        assert_eq!(color_of(4, "------"), Some(Color::Green));
+        assert_eq!(color_of(3, "|"), Some(Color::DarkGray));
+        assert_eq!(speed_color(35.0), Color::Red);
+    }
+}
@@ -0,0 +1,187 @@
+//! Opt-in local counts of which features get used
+//!
+//! With `"usage_metrics": true` in the config, the TUI counts how often each
+//! screen and overlay is opened and each activity picked, so it's known
+//! whether the likes of Plan Trip or the dashboard are worth more work.
+//! Only those counts are kept: no beaches, times or anything else about the
+//! user, and nothing leaves the machine. `vanbeach metrics show` prints them
+//! and `vanbeach metrics export` prints them as JSON to share by hand.
+//! Counts are saved through the cache manager when the TUI exits.
+
+use std::collections::BTreeMap;
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::activities::Activity;
+use crate::app::{App, AppState};
+use crate::cache::{CacheManager, StalenessPolicy};
+
+/// Cache key under which the counts are stored
+pub const USAGE_CACHE_KEY: &str = "usage_metrics";
+
+/// Counts are kept until deleted, so keep them for ten years
+const USAGE_TTL: Duration = Duration::days(365 * 10);
+
+/// How often each feature was used
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageCounts {
+    /// Day the first use was counted
+    pub since: Option<NaiveDate>,
+    /// Uses by feature, e.g. "screen.plan_trip" or "activity.swimming"
+    pub counts: BTreeMap<String, u64>,
+}
+
+impl UsageCounts {
+    /// Loads the saved counts, none if nothing was counted yet
+    pub fn load(cache: &CacheManager) -> Self {
+        cache
+            .get_with_policy::<UsageCounts>(USAGE_CACHE_KEY, StalenessPolicy::AnyAge)
+            .data()
+            .unwrap_or_default()
+    }
+
+    /// Saves the counts to the cache
+    pub fn save(&self, cache: &CacheManager) -> std::io::Result<()> {
+        cache.put(USAGE_CACHE_KEY, self, USAGE_TTL)
+    }
+
+    /// Counts one use of a feature
+    pub fn record(&mut self, feature: &str, today: NaiveDate) {
+        self.since.get_or_insert(today);
+        *self.counts.entry(feature.to_string()).or_default() += 1;
+    }
+
+    /// The counts as `vanbeach metrics show` prints them, most used first
+    pub fn report(&self) -> String {
+        let mut counts: Vec<_> = self.counts.iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        let width = counts.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        let mut report = String::new();
+        if let Some(since) = self.since {
+            report.push_str(&format!("Counted since {}\n", since));
+        }
+        for (name, count) in counts {
+            report.push_str(&format!("{:<width$}  {}\n", name, count, width = width));
+        }
+        report
+    }
+}
+
+/// Counts features as the TUI moves between them
+#[derive(Debug)]
+pub struct UsageTracker {
+    counts: UsageCounts,
+    cache: CacheManager,
+    /// Screen, overlays and activity seen last, so only changes count
+    last: Option<Seen>,
+}
+
+/// What the app showed when last observed
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Seen {
+    screen: &'static str,
+    help: bool,
+    directions: bool,
+    activity: Option<Activity>,
+}
+
+impl Seen {
+    fn of(app: &App) -> Self {
+        Self {
+            screen: screen_name(&app.state),
+            help: app.show_help,
+            directions: app.show_directions,
+            activity: app.current_activity,
+        }
+    }
+}
+
+impl UsageTracker {
+    /// Picks up counting where the saved counts left off
+    pub fn start(cache: CacheManager) -> Self {
+        Self {
+            counts: UsageCounts::load(&cache),
+            cache,
+            last: None,
+        }
+    }
+
+    /// Counts whatever the app opened or picked since the last call
+    pub fn observe(&mut self, app: &App, today: NaiveDate) {
+        let seen = Seen::of(app);
+        let last = self.last.as_ref();
+        if seen.screen != "loading" && last.map(|last| last.screen) != Some(seen.screen) {
+            self.counts
+                .record(&format!("screen.{}", seen.screen), today);
+        }
+        if seen.help && !last.is_some_and(|last| last.help) {
+            self.counts.record("overlay.help", today);
+        }
+        if seen.directions && !last.is_some_and(|last| last.directions) {
+            self.counts.record("overlay.directions", today);
+        }
+        if let Some(activity) = seen.activity {
+            if last.and_then(|last| last.activity) != Some(activity) {
+                self.counts
+                    .record(&format!("activity.{}", activity.slug()), today);
+            }
+        }
+        self.last = Some(seen);
+    }
+
+    /// Saves the counts so far
+    pub fn save(&self) -> std::io::Result<()> {
+        self.counts.save(&self.cache)
+    }
+}
+
+/// Name a screen is counted under
+fn screen_name(state: &AppState) -> &'static str {
+    match state {
+        AppState::Loading => "loading",
+        AppState::BeachList => "beach_list",
+        AppState::BeachDetail(_) => "beach_detail",
+        AppState::PlanTrip => "plan_trip",
+        AppState::Dashboard => "dashboard",
+        AppState::WaterQualityOverview => "water_quality",
+        AppState::Journal => "journal",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::BeachId;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_tracker_counts_changes_and_survives_restarts() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let today = NaiveDate::from_ymd_opt(2026, 7, 15).unwrap();
+        let mut app = App::new();
+        let mut tracker = UsageTracker::start(cache.clone());
+
+        tracker.observe(&app, today);
+        app.state = AppState::BeachList;
+        tracker.observe(&app, today);
+        tracker.observe(&app, today);
+        app.state = AppState::BeachDetail(BeachId::new("kitsilano"));
+        app.show_help = true;
+        tracker.observe(&app, today);
+        app.state = AppState::PlanTrip;
+        app.current_activity = Some(Activity::Sailing);
+        tracker.observe(&app, today);
+        tracker.save().unwrap();
+
+        let counts = UsageCounts::load(&cache);
+        assert_eq!(counts.since, Some(today));
+        assert_eq!(counts.counts.get("screen.beach_list"), Some(&1));
+        assert_eq!(counts.counts.get("screen.plan_trip"), Some(&1));
+        assert_eq!(counts.counts.get("overlay.help"), Some(&1));
+        assert_eq!(counts.counts.get("activity.sailing"), Some(&1));
+        assert!(!counts.counts.contains_key("screen.loading"));
+        assert!(counts.report().starts_with("Counted since 2026-07-15\n"));
+    }
+}
@@ -0,0 +1,276 @@
+//! UV exposure tracking
+//!
+//! A sun session is started from a beach's detail view and runs until it's
+//! stopped. The UV dose soaked up is estimated from the beach's hourly UV
+//! index: an hour in the open at UV index 1 is about 0.9 standard erythemal
+//! doses (SED, 100 J/m² of sunburn-weighted UV). The day's sessions are saved
+//! through the cache manager so the total survives restarts, and are dropped
+//! once their day has passed.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::{CacheManager, StalenessPolicy};
+use crate::data::{BeachId, HourlyForecast};
+
+/// Cache key under which the day's sun sessions are stored
+pub const SUN_SESSIONS_CACHE_KEY: &str = "sun_sessions";
+
+/// Sessions only ever cover today, so a day is plenty
+const SUN_SESSIONS_TTL: Duration = Duration::days(1);
+
+/// Standard erythemal doses from an hour at UV index 1
+pub const SED_PER_UV_INDEX_HOUR: f64 = 0.9;
+
+/// Share of the limit at which the dose counts as approaching it
+const APPROACHING_FRACTION: f64 = 0.8;
+
+/// A stretch of time spent in the sun at a beach
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SunSession {
+    /// Beach whose UV forecast the dose is estimated from
+    pub beach_id: BeachId,
+    /// When the session was started
+    pub started_at: DateTime<Local>,
+    /// When the session was stopped, None while it's running
+    pub ended_at: Option<DateTime<Local>>,
+}
+
+impl SunSession {
+    /// Starts a session at `now`
+    pub fn start(beach_id: BeachId, now: DateTime<Local>) -> Self {
+        Self {
+            beach_id,
+            started_at: now,
+            ended_at: None,
+        }
+    }
+
+    /// Returns true until the session is stopped
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Time spent in the sun so far
+    pub fn elapsed(&self, now: DateTime<Local>) -> Duration {
+        (self.ended_at.unwrap_or(now) - self.started_at).max(Duration::zero())
+    }
+
+    /// Estimated dose in SED up to `now`, from the beach's hourly UV index
+    ///
+    /// Each forecast hour contributes for the part of it the session covers.
+    /// Hours without a forecast contribute nothing.
+    pub fn dose(&self, hourly: &[HourlyForecast], now: DateTime<Local>) -> f64 {
+        let end = self.ended_at.unwrap_or(now);
+        let date = self.started_at.date_naive();
+        hourly
+            .iter()
+            .filter_map(|forecast| {
+                let start = hour_start(date, forecast.hour)?;
+                let from = start.max(self.started_at);
+                let to = (start + Duration::hours(1)).min(end);
+                let minutes = (to - from).num_seconds() as f64 / 60.0;
+                (minutes > 0.0).then(|| forecast.uv * minutes / 60.0 * SED_PER_UV_INDEX_HOUR)
+            })
+            .sum()
+    }
+}
+
+/// Local time at which an hour of `date` starts
+fn hour_start(date: NaiveDate, hour: u8) -> Option<DateTime<Local>> {
+    let naive = date.and_hms_opt(hour as u32, 0, 0)?;
+    Local.from_local_datetime(&naive).earliest()
+}
+
+/// How close the day's dose is to the limit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UvLevel {
+    #[default]
+    Safe,
+    /// At `APPROACHING_FRACTION` of the limit or more
+    Approaching,
+    /// At the limit or past it
+    Reached,
+}
+
+impl UvLevel {
+    /// Level of `dose` against `limit`
+    pub fn of(dose: f64, limit: f64) -> Self {
+        if dose >= limit {
+            UvLevel::Reached
+        } else if dose >= limit * APPROACHING_FRACTION {
+            UvLevel::Approaching
+        } else {
+            UvLevel::Safe
+        }
+    }
+}
+
+/// The day's UV dose, for the detail view and warnings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UvExposure {
+    /// Total dose in SED over today's sessions
+    pub dose: f64,
+    /// Dose warned about, in SED
+    pub limit: f64,
+    /// How long the running session has gone, if one is running
+    pub running_for: Option<Duration>,
+}
+
+impl UvExposure {
+    /// How close the dose is to the limit
+    pub fn level(&self) -> UvLevel {
+        UvLevel::of(self.dose, self.limit)
+    }
+
+    /// One-line summary, e.g. "Sun session 45m · UV dose 1.2 of 2.5 SED"
+    pub fn summary(&self) -> String {
+        let dose = format!("UV dose {:.1} of {:.1} SED", self.dose, self.limit);
+        match self.running_for {
+            Some(elapsed) => format!("Sun session {} · {}", format_elapsed(elapsed), dose),
+            None => format!("{} today", dose),
+        }
+    }
+
+    /// Warning for the dose's level, None while it's well under the limit
+    pub fn warning(&self) -> Option<String> {
+        match self.level() {
+            UvLevel::Safe => None,
+            UvLevel::Approaching => Some(format!(
+                "UV dose {:.1} of {:.1} SED: time for shade or more sunscreen",
+                self.dose, self.limit
+            )),
+            UvLevel::Reached => Some(format!(
+                "UV dose limit of {:.1} SED reached: get out of the sun",
+                self.limit
+            )),
+        }
+    }
+}
+
+/// Formats a session length like "1h 05m" or "45m"
+fn format_elapsed(elapsed: Duration) -> String {
+    let total_minutes = elapsed.num_minutes().max(0);
+    match total_minutes / 60 {
+        0 => format!("{}m", total_minutes),
+        hours => format!("{}h {:02}m", hours, total_minutes % 60),
+    }
+}
+
+/// Loads saved sessions, dropping any started before `today`
+pub fn load_sessions(cache: &CacheManager, today: NaiveDate) -> Vec<SunSession> {
+    cache
+        .get_with_policy::<Vec<SunSession>>(SUN_SESSIONS_CACHE_KEY, StalenessPolicy::AnyAge)
+        .data()
+        .map(|data| {
+            data.into_iter()
+                .filter(|session| session.started_at.date_naive() >= today)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Saves sessions to the cache
+pub fn save_sessions(cache: &CacheManager, sessions: &[SunSession]) -> std::io::Result<()> {
+    cache.put(SUN_SESSIONS_CACHE_KEY, &sessions, SUN_SESSIONS_TTL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{DataQuality, WeatherCondition};
+    use tempfile::TempDir;
+
+    fn day() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, 15).unwrap()
+    }
+
+    fn at(hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .from_local_datetime(&day().and_hms_opt(hour, minute, 0).unwrap())
+            .earliest()
+            .unwrap()
+    }
+
+    fn forecast(hour: u8, uv: f64) -> HourlyForecast {
+        HourlyForecast {
+            hour,
+            temperature: 24.0,
+            feels_like: 24.0,
+            condition: WeatherCondition::Clear,
+            wind: 5.0,
+            wind_direction: "W".to_string(),
+            uv,
+            precipitation_chance: 0,
+            dew_point: None,
+            wind_gusts: None,
+            cloud_cover: None,
+            data_quality: DataQuality::Reported,
+        }
+    }
+
+    #[test]
+    fn test_dose_covers_parts_of_hours() {
+        let hourly = vec![forecast(11, 6.0), forecast(12, 8.0), forecast(13, 4.0)];
+        let session = SunSession::start(BeachId::new("kitsilano"), at(11, 30));
+
+        // Half an hour at UV 6, then half an hour at UV 8
+        let dose = session.dose(&hourly, at(12, 30));
+        assert!(
+            (dose - 7.0 * SED_PER_UV_INDEX_HOUR).abs() < 1e-9,
+            "{}",
+            dose
+        );
+
+        let stopped = SunSession {
+            ended_at: Some(at(12, 0)),
+            ..session
+        };
+        let dose = stopped.dose(&hourly, at(14, 0));
+        assert!(
+            (dose - 3.0 * SED_PER_UV_INDEX_HOUR).abs() < 1e-9,
+            "{}",
+            dose
+        );
+    }
+
+    #[test]
+    fn test_levels_and_warnings() {
+        let exposure = |dose| UvExposure {
+            dose,
+            limit: 2.5,
+            running_for: Some(Duration::minutes(65)),
+        };
+        assert_eq!(exposure(1.0).level(), UvLevel::Safe);
+        assert_eq!(exposure(1.0).warning(), None);
+        assert_eq!(exposure(2.1).level(), UvLevel::Approaching);
+        assert_eq!(exposure(2.5).level(), UvLevel::Reached);
+        assert!(exposure(3.0).warning().unwrap().contains("reached"));
+
+        assert_eq!(
+            exposure(1.2).summary(),
+            "Sun session 1h 05m · UV dose 1.2 of 2.5 SED"
+        );
+        let stopped = UvExposure {
+            running_for: None,
+            ..exposure(1.2)
+        };
+        assert_eq!(stopped.summary(), "UV dose 1.2 of 2.5 SED today");
+    }
+
+    #[test]
+    fn test_save_and_load_drops_past_days() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheManager::with_dir(temp_dir.path().to_path_buf());
+        let yesterday = at(12, 0) - Duration::days(1);
+        let sessions = vec![
+            SunSession::start(BeachId::new("jericho"), yesterday),
+            SunSession::start(BeachId::new("kitsilano"), at(12, 0)),
+        ];
+
+        save_sessions(&cache, &sessions).unwrap();
+        let loaded = load_sessions(&cache, day());
+
+        assert_eq!(loaded, vec![sessions[1].clone()]);
+    }
+}
@@ -0,0 +1,413 @@
+//! Webcam snapshots drawn with terminal graphics
+//!
+//! Only built with the `graphics` feature. On a terminal that speaks the kitty
+//! or sixel graphics protocol, the open beach's webcam snapshot is fetched,
+//! downscaled to the space the detail view left for it and drawn over that
+//! space after each frame. Everywhere else the detail view shows the URL.
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use image::{imageops::FilterType, RgbImage};
+use ratatui::layout::Rect;
+use reqwest::Client;
+use thiserror::Error;
+
+use crate::app::{App, AppState};
+use crate::data::{recording, BeachId};
+
+/// Assumed width of a terminal cell in pixels, for downscaling
+const CELL_WIDTH_PX: u32 = 8;
+
+/// Assumed height of a terminal cell in pixels, for downscaling
+const CELL_HEIGHT_PX: u32 = 16;
+
+/// Snapshots older than this are fetched again when a beach is opened
+const SNAPSHOT_MAX_AGE: Duration = Duration::from_secs(5 * 60);
+
+/// Base64 characters sent per kitty graphics escape sequence
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+/// Removes every kitty image placement from the screen
+const KITTY_DELETE_ALL: &str = "\x1b_Ga=d,q=2\x1b\\";
+
+/// Errors that can occur when fetching a webcam snapshot
+#[derive(Debug, Error)]
+pub enum WebcamError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    HttpError(#[from] reqwest::Error),
+
+    /// The response isn't an image we can decode
+    #[error("Failed to decode snapshot: {0}")]
+    DecodeError(#[from] image::ImageError),
+}
+
+/// Terminal graphics protocol used to draw snapshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    /// The kitty graphics protocol (kitty, WezTerm, Ghostty)
+    Kitty,
+    /// DEC sixel graphics (foot, mlterm, xterm built with sixel)
+    Sixel,
+}
+
+impl GraphicsProtocol {
+    /// Detects a supported protocol from `TERM`, `TERM_PROGRAM` and
+    /// `KITTY_WINDOW_ID`
+    pub fn detect() -> Option<Self> {
+        Self::from_env(
+            env::var("TERM").ok().as_deref(),
+            env::var("TERM_PROGRAM").ok().as_deref(),
+            env::var_os("KITTY_WINDOW_ID").is_some(),
+        )
+    }
+
+    /// Works out the protocol from environment values; `None` means text only
+    pub fn from_env(
+        term: Option<&str>,
+        term_program: Option<&str>,
+        in_kitty: bool,
+    ) -> Option<Self> {
+        let term = term.unwrap_or("");
+        if in_kitty || term.contains("kitty") || matches!(term_program, Some("WezTerm" | "ghostty"))
+        {
+            return Some(GraphicsProtocol::Kitty);
+        }
+        if term.contains("sixel") || term.starts_with("foot") || term.starts_with("mlterm") {
+            return Some(GraphicsProtocol::Sixel);
+        }
+        None
+    }
+}
+
+/// Fetches a snapshot and decodes it
+pub async fn fetch_snapshot(client: &Client, url: &str) -> Result<RgbImage, WebcamError> {
+    let bytes = recording::send(client.get(url))
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    Ok(image::load_from_memory(&bytes)?.to_rgb8())
+}
+
+/// Downscales a snapshot to fit a cell area, keeping its aspect ratio
+pub fn fit_to_area(snapshot: &RgbImage, area: Rect) -> RgbImage {
+    let max_width = area.width as u32 * CELL_WIDTH_PX;
+    let max_height = area.height as u32 * CELL_HEIGHT_PX;
+    let (width, height) = snapshot.dimensions();
+    if width == 0 || height == 0 {
+        return snapshot.clone();
+    }
+
+    let scale = (max_width as f64 / width as f64)
+        .min(max_height as f64 / height as f64)
+        .min(1.0);
+    let width = ((width as f64 * scale).round() as u32).max(1);
+    let height = ((height as f64 * scale).round() as u32).max(1);
+    image::imageops::resize(snapshot, width, height, FilterType::Triangle)
+}
+
+/// Escape sequence drawing an image at the top-left cell of `area`, leaving
+/// the cursor where it was
+pub fn encode(image: &RgbImage, protocol: GraphicsProtocol, area: Rect) -> String {
+    let mut out = format!("\x1b7\x1b[{};{}H", area.y + 1, area.x + 1);
+    match protocol {
+        GraphicsProtocol::Kitty => out.push_str(&kitty_sequence(image)),
+        GraphicsProtocol::Sixel => out.push_str(&sixel_sequence(image)),
+    }
+    out.push_str("\x1b8");
+    out
+}
+
+/// Kitty graphics: raw RGB pixels, base64-encoded and sent in chunks
+fn kitty_sequence(image: &RgbImage) -> String {
+    let (width, height) = image.dimensions();
+    let payload = base64(image.as_raw());
+    let chunks: Vec<&str> = payload
+        .as_bytes()
+        .chunks(KITTY_CHUNK_SIZE)
+        // Base64 output is ASCII, so every chunk is valid UTF-8
+        .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+        .collect();
+
+    let mut out = String::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 < chunks.len());
+        if i == 0 {
+            let _ = write!(
+                out,
+                "\x1b_Ga=T,f=24,s={},v={},c={},r={},C=1,q=2,m={};{}\x1b\\",
+                width,
+                height,
+                width.div_ceil(CELL_WIDTH_PX),
+                height.div_ceil(CELL_HEIGHT_PX),
+                more,
+                chunk
+            );
+        } else {
+            let _ = write!(out, "\x1b_Gm={};{}\x1b\\", more, chunk);
+        }
+    }
+    out
+}
+
+/// Sixel graphics: pixels mapped onto a 6x6x6 color cube, six rows per band
+fn sixel_sequence(image: &RgbImage) -> String {
+    let (width, height) = image.dimensions();
+    let level = |value: u8| (value as u16 * 5 + 127) / 255;
+    let color_index = |x: u32, y: u32| {
+        let [r, g, b] = image.get_pixel(x, y).0;
+        level(r) * 36 + level(g) * 6 + level(b)
+    };
+
+    let mut out = format!("\x1bP0;1;0q\"1;1;{};{}", width, height);
+    for index in 0..216u16 {
+        let percent = |step: u16| step * 20;
+        let _ = write!(
+            out,
+            "#{};2;{};{};{}",
+            index,
+            percent(index / 36),
+            percent((index / 6) % 6),
+            percent(index % 6)
+        );
+    }
+
+    for band_top in (0..height).step_by(6) {
+        // Which of the band's six rows each color covers, per column
+        let mut band: HashMap<u16, Vec<u8>> = HashMap::new();
+        for y in band_top..(band_top + 6).min(height) {
+            let bit = 1 << (y - band_top);
+            for x in 0..width {
+                band.entry(color_index(x, y))
+                    .or_insert_with(|| vec![0; width as usize])[x as usize] |= bit;
+            }
+        }
+
+        let mut colors: Vec<_> = band.into_iter().collect();
+        colors.sort_by_key(|(index, _)| *index);
+        for (i, (index, columns)) in colors.iter().enumerate() {
+            if i > 0 {
+                // Back to the start of the band for the next color
+                out.push('$');
+            }
+            let _ = write!(out, "#{}", index);
+            push_sixel_runs(&mut out, columns);
+        }
+        out.push('-');
+    }
+
+    out.push_str("\x1b\\");
+    out
+}
+
+/// Appends sixel data characters, run-length encoding repeats
+fn push_sixel_runs(out: &mut String, columns: &[u8]) {
+    let mut i = 0;
+    while i < columns.len() {
+        let bits = columns[i];
+        let run = columns[i..].iter().take_while(|&&b| b == bits).count();
+        let ch = char::from(63 + bits);
+        if run > 3 {
+            let _ = write!(out, "!{}{}", run, ch);
+        } else {
+            out.extend(std::iter::repeat_n(ch, run));
+        }
+        i += run;
+    }
+}
+
+/// Standard base64 with padding
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// A fetched snapshot, or `None` if fetching or decoding failed
+struct Snapshot {
+    image: Option<RgbImage>,
+    fetched_at: Instant,
+}
+
+/// Draws the open beach's webcam snapshot over the space the detail view
+/// leaves for it, and removes it again when the view changes
+pub struct WebcamView {
+    protocol: GraphicsProtocol,
+    client: Client,
+    /// Snapshots by beach ID
+    snapshots: HashMap<BeachId, Snapshot>,
+    /// Beach and area of the snapshot currently on screen
+    drawn: Option<(BeachId, Rect)>,
+}
+
+impl WebcamView {
+    /// Creates a view drawing with the given protocol
+    pub fn new(protocol: GraphicsProtocol) -> Self {
+        Self {
+            protocol,
+            client: Client::new(),
+            snapshots: HashMap::new(),
+            drawn: None,
+        }
+    }
+
+    /// Fetches the open beach's snapshot if needed, then draws or removes it
+    ///
+    /// Returns true if the screen must be redrawn from scratch, which is how
+    /// sixel images are removed.
+    pub async fn update(&mut self, app: &App, out: &mut impl Write) -> io::Result<bool> {
+        let target = match (&app.state, app.webcam_area) {
+            (AppState::BeachDetail(id), Some(area)) if !app.show_help && !app.show_debug_view => {
+                app.webcams
+                    .get(id)
+                    .map(|url| (id.clone(), url.clone(), area))
+            }
+            _ => None,
+        };
+
+        if let Some((id, url, _)) = &target {
+            let on_screen = self.drawn.as_ref().is_some_and(|(drawn, _)| drawn == id);
+            let stale = self
+                .snapshots
+                .get(id)
+                .is_none_or(|s| s.fetched_at.elapsed() > SNAPSHOT_MAX_AGE);
+            if stale && !on_screen {
+                let image = fetch_snapshot(&self.client, url).await.ok();
+                self.snapshots.insert(
+                    id.clone(),
+                    Snapshot {
+                        image,
+                        fetched_at: Instant::now(),
+                    },
+                );
+            }
+        }
+
+        let wanted = target.filter(|(id, _, _)| {
+            self.snapshots
+                .get(id)
+                .is_some_and(|snapshot| snapshot.image.is_some())
+        });
+        let wanted = wanted.map(|(id, _, area)| (id, area));
+        if wanted == self.drawn {
+            return Ok(false);
+        }
+
+        if self.drawn.take().is_some() {
+            match self.protocol {
+                GraphicsProtocol::Kitty => out.write_all(KITTY_DELETE_ALL.as_bytes())?,
+                // Drawn again (if still wanted) after the full redraw
+                GraphicsProtocol::Sixel => return Ok(true),
+            }
+        }
+
+        if let Some((id, area)) = wanted {
+            if let Some(image) = self.snapshots.get(&id).and_then(|s| s.image.as_ref()) {
+                let fitted = fit_to_area(image, area);
+                out.write_all(encode(&fitted, self.protocol, area).as_bytes())?;
+                self.drawn = Some((id, area));
+            }
+        }
+        out.flush()?;
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgb;
+
+    #[test]
+    fn test_detects_protocol_from_environment() {
+        assert_eq!(
+            GraphicsProtocol::from_env(Some("xterm-kitty"), None, false),
+            Some(GraphicsProtocol::Kitty)
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(Some("xterm-256color"), Some("WezTerm"), false),
+            Some(GraphicsProtocol::Kitty)
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(Some("foot"), None, false),
+            Some(GraphicsProtocol::Sixel)
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(Some("xterm-256color"), None, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fit_to_area_keeps_aspect_ratio() {
+        let snapshot = RgbImage::new(1280, 720);
+        let fitted = fit_to_area(&snapshot, Rect::new(0, 0, 40, 10));
+
+        // 10 rows of 16px limit the height; the width follows
+        assert_eq!(fitted.dimensions(), (284, 160));
+
+        // Small images are never enlarged
+        let small = RgbImage::new(16, 16);
+        assert_eq!(
+            fit_to_area(&small, Rect::new(0, 0, 40, 10)).dimensions(),
+            (16, 16)
+        );
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_encode_positions_and_frames_image() {
+        let image = RgbImage::from_pixel(4, 2, Rgb([255, 0, 0]));
+        let area = Rect::new(5, 3, 10, 4);
+
+        let kitty = encode(&image, GraphicsProtocol::Kitty, area);
+        assert!(kitty.starts_with("\x1b7\x1b[4;6H\x1b_Ga=T,f=24,s=4,v=2,c=1,r=1,"));
+        assert!(kitty.ends_with("\x1b\\\x1b8"));
+
+        let sixel = encode(&image, GraphicsProtocol::Sixel, area);
+        assert!(sixel.contains("\x1bP0;1;0q\"1;1;4;2"));
+        // Pure red is cube index 180; both rows set for all four columns
+        assert!(sixel.contains("#180!4B-"));
+        assert!(sixel.ends_with("\x1b\\\x1b8"));
+    }
+
+    #[test]
+    fn test_kitty_splits_large_payloads() {
+        let image = RgbImage::new(64, 64);
+        let sequence = kitty_sequence(&image);
+
+        // 64 * 64 * 3 bytes is 16384 base64 characters: four chunks
+        assert_eq!(sequence.matches("\x1b_G").count(), 4);
+        assert!(sequence.contains("m=1;"));
+        assert_eq!(sequence.matches("m=0;").count(), 1);
+    }
+}
@@ -1,6 +1,7 @@
 //! Integration tests for CLI argument handling
 //!
-//! Tests the --plan flag and activity parsing from command line.
+//! Tests the --plan and --watch flags, activity parsing and the subcommands
+//! that print and exit.
 
 use std::process::Command;
 
@@ -55,6 +56,85 @@ fn test_plan_with_sunset_is_valid() {
     assert!(output.status.success());
 }
 
+#[test]
+fn test_watch_flag_is_valid() {
+    let output = run_cli(&["--watch", "--help"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("watch"), "Help should mention --watch flag");
+}
+
+#[test]
+fn test_help_mentions_score_dump() {
+    let output = run_cli(&["--help"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("--score-dump"));
+}
+
+#[test]
+fn test_help_lists_export_site_subcommand() {
+    let output = run_cli(&["--help"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("export-site"));
+
+    let output = run_cli(&["export-site", "--help"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("--out"));
+}
+
+#[test]
+fn test_crowd_subcommand_rejects_unknown_beach_and_format() {
+    let output = run_cli(&["crowd", "--beach", "atlantis"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("atlantis"));
+
+    let output = run_cli(&["crowd", "--beach", "kitsilano", "--format", "xml"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("csv"));
+}
+
+#[test]
+fn test_schema_subcommand_prints_output_schema() {
+    let output = run_cli(&["schema"]);
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    for name in ["BeachConditions", "Session", "PinnedPlan"] {
+        assert!(schema["definitions"][name].is_object(), "{} missing", name);
+    }
+}
+
+#[test]
+fn test_completions_subcommand_prints_script() {
+    let output = run_cli(&["completions", "fish"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("complete -c vanbeach"));
+    assert!(stdout.contains("kitsilano"));
+}
+
+#[test]
+fn test_man_subcommand_prints_man_page() {
+    let output = run_cli(&["man"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(".TH vanbeach"));
+}
+
+#[test]
+fn test_record_and_replay_conflict() {
+    let output = run_cli(&["--record", "a.json", "--replay", "b.json"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_replay_missing_file_fails_before_tui() {
+    let output = run_cli(&["--replay", "/nonexistent/vanbeach-session.json"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Session file error"));
+}
+
 #[cfg(test)]
 mod unit_tests {
     //! Unit tests for CLI parsing that don't require running the binary
@@ -148,9 +228,8 @@ mod unit_tests {
     }
 
     #[test]
-    fn test_startup_config_from_cli_plan_with_invalid_activity() {
-        let cli = Cli::parse_from(["vanbeach", "--plan", "invalid"]);
-        let config = StartupConfig::from_cli(&cli);
-        assert!(config.is_err());
+    fn test_cli_plan_with_invalid_activity_is_rejected() {
+        let result = Cli::try_parse_from(["vanbeach", "--plan", "invalid"]);
+        assert!(result.is_err());
     }
 }